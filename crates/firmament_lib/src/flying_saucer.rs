@@ -38,11 +38,10 @@ impl FlyingSaucer {
         }
     }
 
-    pub fn update(&mut self, dt: f64, window_width: f64, window_height: f64, player_pos: [f64; 2]) -> Option<Vec<SaucerProjectile>> {
+    pub fn update<R: Rng>(&mut self, dt: f64, window_width: f64, window_height: f64, player_pos: [f64; 2], rng: &mut R) -> Option<Vec<SaucerProjectile>> {
         // Erratic movement
         self.direction_change_timer -= dt;
         if self.direction_change_timer <= 0.0 {
-            let mut rng = rand::rng();
             self.vel_x = rng.gen_range(-300.0..300.0);
             self.vel_y = rng.gen_range(-200.0..200.0);
             self.direction_change_timer = rng.gen_range(0.5..2.0);
@@ -80,6 +80,8 @@ use flying_saucer::{FlyingSaucer, SaucerProjectile};
 
 // --- Constants ---
 // Public constants that might be useful for the library consumer
+// Logical playfield size. `render` draws in these units; the host scales the context to
+// the real window and maps mouse coordinates back, so these don't follow the window size.
 pub const WINDOW_WIDTH: f64 = 1920.0;
 pub const WINDOW_HEIGHT: f64 = 1080.0;
 
@@ -202,6 +204,9 @@ struct Player {
 	is_braking: bool,
     rotating_left: bool,
     rotating_right: bool,
+    // Analog steering from a gamepad: turn in -1..1, thrust in 0..1. Zero when unused.
+    analog_turn: f64,
+    analog_thrust: f64,
     shields: u32,
     score: u32,
     shoot_cooldown: f64,
@@ -211,6 +216,13 @@ struct Player {
     rush_cooldown: f64,
 }
 
+impl Player {
+    /// Thrust strength this frame: full when the thrust control is held, else the analog amount.
+    fn thrust_amount(&self) -> f64 {
+        if self.is_thrusting { 1.0 } else { self.analog_thrust }
+    }
+}
+
 struct Asteroid {
     obj: GameObject,
     size: AsteroidSize,
@@ -466,7 +478,8 @@ pub struct Game {
 	speed_lines: Vec<SpeedLine>,
 //    stars: Vec<Star>,
     window_size: [f64; 2],
-    rng: rand::rngs::ThreadRng, // Specific RNG type from `rand` crate
+    rng: rand::rngs::StdRng, // Supplied by the host game so rolls follow its seed
+    cosmetic_rng: rand::rngs::StdRng, // Particles and flame flicker only; never touches spawns
     game_over: bool,
     waiting_to_start: bool,
     assets: Assets,
@@ -519,7 +532,7 @@ impl Game {
 	
     /// Creates a new game instance.
     /// Requires a mutable reference to a PistonWindow for asset loading and context.
-    pub fn new(window: &mut PistonWindow, start_field_id_override: Option<FieldId3D>, boss_already_defeated: bool, fort_silo_already_landed: bool) -> Result<Self, String> {
+    pub fn new(window: &mut PistonWindow, start_field_id_override: Option<FieldId3D>, boss_already_defeated: bool, fort_silo_already_landed: bool, rng: rand::rngs::StdRng, cosmetic_rng: rand::rngs::StdRng) -> Result<Self, String> {
         debug_print("Initializing game library...");
         let assets = Assets::load(window)?;
 //
@@ -560,6 +573,8 @@ impl Game {
 				is_braking: false,
                 rotating_left: false,
                 rotating_right: false,
+                analog_turn: 0.0,
+                analog_thrust: 0.0,
                 shields: STARTING_SHIELDS,
                 score: 0,
                 shoot_cooldown: 0.0,
@@ -576,7 +591,8 @@ impl Game {
 			speed_lines: Vec::new(),
 //            stars: Vec::new(),
             window_size: [WINDOW_WIDTH, WINDOW_HEIGHT],
-            rng: rand::rng(),
+            rng,
+            cosmetic_rng,
             game_over: false,
             waiting_to_start: false,
             assets,
@@ -734,17 +750,17 @@ impl Game {
 
     fn spawn_particles(&mut self, pos: [f64; 2], count: usize, base_color: [f32; 4], speed_range: (f64, f64), lifetime_range: (f64, f64), base_size: f64) {
         for _ in 0..count {
-            let angle = self.rng.gen_range(0.0..std::f64::consts::TAU);
-            let speed = self.rng.gen_range(speed_range.0..speed_range.1);
-            let lifetime = self.rng.gen_range(lifetime_range.0..lifetime_range.1);
-            let color_variance = self.rng.gen_range(-0.2..0.2);
+            let angle = self.cosmetic_rng.gen_range(0.0..std::f64::consts::TAU);
+            let speed = self.cosmetic_rng.gen_range(speed_range.0..speed_range.1);
+            let lifetime = self.cosmetic_rng.gen_range(lifetime_range.0..lifetime_range.1);
+            let color_variance = self.cosmetic_rng.gen_range(-0.2..0.2);
             let particle_color = [
                 (base_color[0] + color_variance).clamp(0.0, 1.0),
                 (base_color[1] + color_variance).clamp(0.0, 1.0),
                 (base_color[2] + color_variance).clamp(0.0, 1.0),
-                base_color[3] * self.rng.gen_range(0.7..1.0),
+                base_color[3] * self.cosmetic_rng.gen_range(0.7..1.0),
             ];
-            let size_variance = self.rng.gen_range(0.5..1.5);
+            let size_variance = self.cosmetic_rng.gen_range(0.5..1.5);
 
             self.particles.push(Particle {
                 pos,
@@ -963,13 +979,17 @@ impl Game {
 
         if self.player.rotating_left { self.player.obj.rot -= SHIP_ROTATION_SPEED * dt; }
         if self.player.rotating_right { self.player.obj.rot += SHIP_ROTATION_SPEED * dt; }
+        if !self.player.rotating_left && !self.player.rotating_right {
+            self.player.obj.rot += SHIP_ROTATION_SPEED * self.player.analog_turn * dt;
+        }
 
-        if self.player.is_thrusting {
+        let thrust_amount = self.player.thrust_amount();
+        if thrust_amount > 0.0 {
             if self.thrust_sink.is_paused() { self.thrust_sink.play(); }
             if !self.is_paused { // Only apply physics if not paused
                 let accel_vec = [
-                    self.player.obj.rot.cos() * SHIP_ACCELERATION * dt,
-                    self.player.obj.rot.sin() * SHIP_ACCELERATION * dt,
+                    self.player.obj.rot.cos() * SHIP_ACCELERATION * thrust_amount * dt,
+                    self.player.obj.rot.sin() * SHIP_ACCELERATION * thrust_amount * dt,
                 ];
                 self.player.obj.vel[0] += accel_vec[0];
                 self.player.obj.vel[1] += accel_vec[1];
@@ -1487,7 +1507,7 @@ impl Game {
             self.update_bullets(dt);
             self.update_enemy_bullets(dt);
             // Ensure thrust sound stops if not thrusting, as the main loop won't handle it.
-            if !self.player.is_thrusting && !self.thrust_sink.is_paused() {
+            if self.player.thrust_amount() <= 0.0 && !self.thrust_sink.is_paused() {
                 self.thrust_sink.pause();
             }
             return;
@@ -1507,7 +1527,7 @@ impl Game {
         // Update flying saucer
         if let Some(ref mut saucer) = self.flying_saucer {
             let player_pos = self.player.obj.pos;
-            if let Some(new_projectiles) = saucer.update(dt, WINDOW_WIDTH, WINDOW_HEIGHT, player_pos) {
+            if let Some(new_projectiles) = saucer.update(dt, WINDOW_WIDTH, WINDOW_HEIGHT, player_pos, &mut self.rng) {
                 // Safety: Cap projectiles to prevent GPU/RAM exhaustion
                 if self.saucer_projectiles.len() < 500 {
                     self.saucer_projectiles.extend(new_projectiles);
@@ -1768,10 +1788,10 @@ impl Game {
                 piston_window::polygon(player_color, &points, c.transform.trans(self.player.obj.pos[0], self.player.obj.pos[1]).rot_rad(self.player.obj.rot), g);
             }
 
-            if self.player.is_thrusting {
-                let flame_color = [1.0, self.rng.gen_range(0.3..0.7), 0.0, 0.9];
+            if self.player.thrust_amount() > 0.0 {
+                let flame_color = [1.0, self.cosmetic_rng.gen_range(0.3..0.7), 0.0, 0.9];
                 let radius = self.player.obj.radius;
-                let flame_length = radius * self.rng.gen_range(1.2..1.8);
+                let flame_length = radius * self.cosmetic_rng.gen_range(1.2..1.8);
                 let flame_width = radius * 0.7;
                 let flame_points = [ [-radius * 0.7, -flame_width * 0.5], [-radius * 0.7 - flame_length, 0.0], [-radius * 0.7, flame_width * 0.5]];
                 piston_window::polygon(flame_color, &flame_points, c.transform.trans(self.player.obj.pos[0], self.player.obj.pos[1]).rot_rad(self.player.obj.rot), g);
@@ -1920,15 +1940,29 @@ impl Game {
 
     /// Handles key press events.
     pub fn key_pressed(&mut self, key: Key) { // piston_window::Key
+        if let Some(control) = JetControl::from_key(key) {
+            self.control_pressed(control);
+        }
+    }
+
+    /// Handles key release events.
+    pub fn key_released(&mut self, key: Key) { // piston_window::Key
+        if let Some(control) = JetControl::from_key(key) {
+            self.control_released(control);
+        }
+    }
+
+    /// Starts a jet control, whichever device it came from.
+    pub fn control_pressed(&mut self, control: JetControl) {
         if self.is_paused {
             return;
         }
-        match key {
-            Key::Up | Key::W => self.player.is_thrusting = true,
-			Key::LShift | Key::RShift => self.player.is_braking = true,
-            Key::Left | Key::A => self.player.rotating_left = true,
-            Key::Right | Key::D => self.player.rotating_right = true,
-            Key::Space => {
+        match control {
+            JetControl::Thrust => self.player.is_thrusting = true,
+            JetControl::Brake => self.player.is_braking = true,
+            JetControl::RotateLeft => self.player.rotating_left = true,
+            JetControl::RotateRight => self.player.rotating_right = true,
+            JetControl::Rush => {
                 if self.player.rush_cooldown <= 0.0 && !self.player.rush_active {
                     self.player.rush_active = true;
                     self.player.rush_timer = SHIP_RUSH_DURATION;
@@ -1936,32 +1970,44 @@ impl Game {
                     self.assets.play_sound(&self.mixer, "shoot"); // Re-using thrust or add rush sound
                 }
             },
-            Key::Return => {
+            JetControl::Restart => {
                 // Restarting the game only works if not paused.
                 if self.game_over && !self.is_paused {
                     self.reset_game_state();
                 }
             },
-			Key::T => self.task_bar_open = !self.task_bar_open,
-            _ => {}
+            JetControl::ToggleTasks => self.task_bar_open = !self.task_bar_open,
+            JetControl::Fire => self.shoot_player_bullet(),
         }
     }
 
-    /// Handles key release events.
-    pub fn key_released(&mut self, key: Key) { // piston_window::Key
+    /// Ends a held jet control.
+    pub fn control_released(&mut self, control: JetControl) {
        if self.is_paused {
             return;
         }	
 	
-        match key {
-            Key::Up | Key::W => self.player.is_thrusting = false,
-			Key::LShift | Key::RShift => self.player.is_braking = false,
-            Key::Left | Key::A => self.player.rotating_left = false,
-            Key::Right | Key::D => self.player.rotating_right = false,
+        match control {
+            JetControl::Thrust => self.player.is_thrusting = false,
+            JetControl::Brake => self.player.is_braking = false,
+            JetControl::RotateLeft => self.player.rotating_left = false,
+            JetControl::RotateRight => self.player.rotating_right = false,
             _ => {}
         }
     }
 
+    /// Analog steering (e.g. a gamepad stick): `turn` in -1..1, `thrust` in 0..1.
+    /// Digital controls take priority while they are held.
+    pub fn set_analog_controls(&mut self, turn: f64, thrust: f64) {
+        if self.is_paused {
+            self.player.analog_turn = 0.0;
+            self.player.analog_thrust = 0.0;
+            return;
+        }
+        self.player.analog_turn = turn.clamp(-1.0, 1.0);
+        self.player.analog_thrust = thrust.clamp(0.0, 1.0);
+    }
+
     // --- Public Getters for Game State ---
     pub fn get_score(&self) -> u32 { self.player.score }
     pub fn get_shields(&self) -> u32 { self.player.shields }
@@ -1975,12 +2021,37 @@ impl Game {
 	}	
 	
     pub fn mouse_pressed(&mut self, button: MouseButton) {
-       if self.is_paused {
-            return;
-        }		
-		
         if button == MouseButton::Left {
-            self.shoot_player_bullet();
+            self.control_pressed(JetControl::Fire);
         }
     }	
+}
+
+/// Device-independent jet controls. Keyboard, mouse and gamepad input all map onto these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JetControl {
+    Thrust,
+    Brake,
+    RotateLeft,
+    RotateRight,
+    Rush,
+    Restart,
+    ToggleTasks,
+    Fire,
+}
+
+impl JetControl {
+    /// Default keyboard layout for the jet.
+    pub fn from_key(key: Key) -> Option<JetControl> {
+        match key {
+            Key::Up | Key::W => Some(JetControl::Thrust),
+            Key::LShift | Key::RShift => Some(JetControl::Brake),
+            Key::Left | Key::A => Some(JetControl::RotateLeft),
+            Key::Right | Key::D => Some(JetControl::RotateRight),
+            Key::Space => Some(JetControl::Rush),
+            Key::Return => Some(JetControl::Restart),
+            Key::T => Some(JetControl::ToggleTasks),
+            _ => None,
+        }
+    }
 }
\ No newline at end of file
@@ -67,7 +67,7 @@ impl AreaState {
             ),
         };
 
-        let mut rng = rand::rng();
+        let mut rng = crate::utils::rng::gameplay_rng();
         let mut spawn_night_reavers = false;
 
         let exit_line_thickness = 5.0;
@@ -0,0 +1,418 @@
+// File: src/asset_manifest.rs
+
+use crate::entities::ground_assets::GroundAssetManager;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+/// Fighter texture folders under `assets/player/`.
+pub const FIGHTER_TEXTURE_SETS: [&str; 4] = ["racer", "racer2", "soldier", "raptor"];
+
+/// Folders under `assets/entity/` read by `load_cpu_textures`.
+pub const CPU_TEXTURE_FOLDERS: [&str; 10] = [
+    "giant_mantis",
+    "blood_idol",
+    "rattlesnake",
+    "giant_rattlesnake",
+    "raptor",
+    "t-rex",
+    "void_tempest",
+    "light_reaver",
+    "night_reaver",
+    "razor_fiend",
+];
+
+/// Sound effect names and their files under `sfx/`.
+pub const SOUND_EFFECTS: &[(&str, &str)] = &[
+    ("boost", "boost.wav"),
+    ("melee", "slash.wav"),
+    ("ranged", "racer_ranged.wav"),
+    ("block", "block.wav"),
+    ("raise_shield", "guard.wav"),
+    ("block_break", "block_break.wav"),
+    ("rush", "rush.wav"),
+    ("bike_start", "SbrxBike_start.wav"),
+    ("title", "title.ogg"),
+    ("reload", "reload.wav"),
+    ("bike_accelerate", "sbrxBike_accelerate.ogg"),
+    ("bike_idle", "sbrxBike_idle.ogg"),
+    ("death", "death.wav"),
+    ("firearm", "firearm.wav"),
+    ("aim", "aim.wav"),
+    ("hit", "hit.wav"),
+    ("mantis_attack", "SlashSwipe.wav"),
+    ("slash_combo", "slashCombo.wav"),
+    ("crickets", "crickets.ogg"),
+    ("sdtrk1", "background_tracks/sdtrk1.ogg"),
+    ("sdtrk2", "background_tracks/sdtrk2.ogg"),
+    ("sdtrk3", "background_tracks/sdtrk3.ogg"),
+    ("sdtrk4", "background_tracks/sdtrk4.ogg"),
+    ("sdtrk5", "background_tracks/sdtrk5.ogg"),
+    ("sdtrk6", "background_tracks/sdtrk6.ogg"),
+    ("sdtrk7", "background_tracks/sdtrk7.ogg"),
+    ("sdtrk8", "background_tracks/sdtrk8.ogg"),
+    ("sdtrk9", "background_tracks/sdtrk9.ogg"),
+    ("sdtrk10", "background_tracks/sdtrk10.ogg"),
+    ("sdtrk11", "background_tracks/sdtrk11.ogg"),
+    ("sdtrk12", "background_tracks/sdtrk12.ogg"),
+    ("sdtrk13", "background_tracks/sdtrk13.ogg"),
+    ("sdtrk14", "background_tracks/sdtrk14.ogg"),
+    ("sdtrk15", "background_tracks/sdtrk15.ogg"),
+];
+
+/// Sound effects the game can do without; `play_sound_effect` callers fall back to an
+/// existing sound when these are missing (see `AudioManager::has_sound`).
+pub const OPTIONAL_SOUND_EFFECTS: &[(&str, &str)] = &[
+    ("parry", "parry.wav"),
+    ("level_up", "level_up.wav"),
+];
+
+/// Background music, in play order. Every entry must also be in SOUND_EFFECTS.
+pub const SOUNDTRACK_PLAYLIST: [&str; 15] = [
+    "sdtrk1", "sdtrk2", "sdtrk3", "sdtrk4", "sdtrk5",
+    "sdtrk6", "sdtrk7", "sdtrk8", "sdtrk9", "sdtrk10",
+    "sdtrk11", "sdtrk12", "sdtrk13", "sdtrk14", "sdtrk15",
+];
+
+/// Single textures loaded at startup (screens, effects, vehicles, props, chatbox icons),
+/// keyed by the name main and the chatbox load them by. See `game_texture`.
+pub const GAME_TEXTURES: &[(&str, &str)] = &[
+    ("title_screen", "sabercrossTITLE.png"),
+    ("pause_screen", "pause_screen1.png"),
+    ("inputs_display", "inputs.png"),
+    ("gear", "gear.png"),
+    ("block_fatigue", "block_fatigue.png"),
+    ("flicker_strike_effect", "effects/FlickerStrikeEffect.png"),
+    ("atomic_state", "effects/atomic_state.png"),
+    ("kinetic_strike_effect1", "effects/KineticStrikeEffect1.png"),
+    ("kinetic_strike_effect2", "effects/KineticStrikeEffect2.png"),
+    ("kinetic_strike_effect3", "effects/KineticStrikeEffect3.png"),
+    ("aim", "aim.png"),
+    ("strike", "strike.png"),
+    ("track", "racetrack.png"),
+    ("rocketbay", "rocketbay.png"),
+    ("fort_silo2", "fort_silo2.png"),
+    ("info_post", "info_post.png"),
+    ("fort_silo", "fort_silo.png"),
+    ("remains", "remains.png"),
+    ("raptor_block_break_nest", "player/raptor/block_break.png"),
+    ("ground", "ground/ground_texture.png"),
+    ("sbrx_bike", "player/racer/sbrx_bike.png"),
+    ("sbrx_bike_crashed", "player/racer/sbrx_bike_crashed.png"),
+    ("sbrx_quad", "player/soldier/quad.png"),
+    ("sbrx_quad_crashed", "player/soldier/quad_crashed.png"),
+    ("pulse_orb", "projectile/pulse_orb.png"),
+    ("set_boost", "set_boost.png"),
+    ("set_ranged", "set_ranged.png"),
+    ("racer_group_icon", "player/racer/group_icon.png"),
+    ("racer_group_icon_selected", "player/racer/group_icon_selected.png"),
+    ("racer2_group_icon", "player/racer2/group_icon.png"),
+    ("racer2_group_icon_selected", "player/racer2/group_icon_selected.png"),
+    ("soldier_group_icon", "player/soldier/group_icon.png"),
+    ("soldier_group_icon_selected", "player/soldier/group_icon_selected.png"),
+    ("raptor_group_icon", "player/raptor/group_icon.png"),
+    ("raptor_group_icon_selected", "player/raptor/group_icon_selected.png"),
+    ("grand_commander_down", "grand_commanderDown.png"),
+    ("grand_commander", "grand_commander.png"),
+    ("fighter_jet", "vehicle/fighter_jet.png"),
+    ("fuel_pump", "FuelPump.png"),
+    ("crashed_fighter_jet", "vehicle/crashed_fighter_jet.png"),
+    ("raptor_nest", "raptor_nest.png"),
+    ("loading_screen", "loading_screen.png"),
+    ("racer_lineup", "RacerLineup.png"),
+    ("race_spectators", "RaceSpectators.png"),
+    ("race_spectators2", "RaceSpectators2.png"),
+    // ChatBox icons
+    ("enter_key_icon", "enter_key_icon.png"),
+    ("scroll_up", "scroll_up.png"),
+    ("scroll_down", "scroll_down.png"),
+];
+
+/// Path of the GAME_TEXTURES entry called `name`. Panics on unknown names, so a texture
+/// can't be loaded without being in the manifest.
+pub fn game_texture(name: &str) -> &'static str {
+    GAME_TEXTURES
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, path)| *path)
+        .unwrap_or_else(|| panic!("texture '{}' is not in the asset manifest", name))
+}
+
+pub const FONT: &str = "fonts/Segment16A.ttf";
+
+/// Subfolder of `assets/` owned by the FirmamentMode library, which checks its own files.
+const FIRMAMENT_FOLDER: &str = "firmament_lib";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AssetKind {
+    Texture,
+    Sound,
+    Font,
+}
+
+/// One file the game expects. Paths use '/' and are relative to `assets/` (textures, fonts)
+/// or `sfx/` (sounds).
+#[derive(Debug, Clone)]
+pub struct ManifestEntry {
+    pub path: String,
+    pub kind: AssetKind,
+    // Optional files (boost poses, extra strike frames) have in-game fallbacks.
+    pub required: bool,
+}
+
+impl ManifestEntry {
+    fn new(path: impl Into<String>, kind: AssetKind, required: bool) -> Self {
+        ManifestEntry {
+            path: path.into(),
+            kind,
+            required,
+        }
+    }
+}
+
+/// One texture of a fighter set, keyed by its FighterTextures slot (`strike2`, `bike_idle`).
+#[derive(Debug, Clone)]
+pub struct FighterTexturePath {
+    pub key: String,
+    pub path: String,
+    pub required: bool,
+}
+
+/// Textures `load_fighter_textures` loads for one fighter type.
+pub fn fighter_texture_paths(fighter_type: &str) -> Vec<FighterTexturePath> {
+    let mut paths = Vec::new();
+    let mut push = |key: &str, path: String, required: bool| {
+        paths.push(FighterTexturePath { key: key.to_string(), path, required });
+    };
+    for name in ["idle", "fwd", "backpedal", "block", "ranged", "rush", "block_break"] {
+        push(name, format!("player/{}/{}.png", fighter_type, name), true);
+    }
+    for i in 1..=3 {
+        push(&format!("strike{}", i), format!("player/{}/strike{}.png", fighter_type, i), true);
+    }
+    let (marker, blur) = match fighter_type {
+        "soldier" => ("player/soldier/bullet_pen.png", "player/soldier/bullet_blur.png"),
+        _ => ("player/racer/ranged_racer_shield.png", "player/racer/racer_shield_blur.png"),
+    };
+    push("ranged_marker", marker.to_string(), true);
+    push("ranged_blur", blur.to_string(), true);
+    let bike = format!("player/{}/racer_onBike", fighter_type);
+    for (key, name) in [
+        ("bike_idle", "rcrBikeIdle"),
+        ("bike_accelerate1", "rcrBikeAccelerate"),
+        ("bike_accelerate2", "rcrBikeAccelerate2"),
+        ("bike_slide", "rcrBikeSlide"),
+        ("bike_block", "rcrBikeBlock"),
+        ("bike_ranged", "rcrBikeRanged"),
+        ("bike_rush", "rcrBikeRush"),
+        ("bike_strike1", "rcrBikeStrike1"),
+        ("bike_strike2", "rcrBikeStrike2"),
+        ("bike_strike3", "rcrBikeStrike3"),
+    ] {
+        push(key, format!("{}/{}.png", bike, name), true);
+    }
+    // Boost poses only exist for the racers
+    if fighter_type == "racer" || fighter_type == "racer2" {
+        push("fwd_boost", format!("player/{}/fwd_boost.png", fighter_type), false);
+        push("backpedal_boost", format!("player/{}/backpedal_boost.png", fighter_type), false);
+        for (key, name) in [
+            ("bike_accelerate_boost1", "rcrBikeAccelerate_boost"),
+            ("bike_accelerate_boost2", "rcrBikeAccelerate2_boost"),
+            ("bike_slide_boost", "rcrBikeSlide_boost"),
+        ] {
+            push(key, format!("{}/{}.png", bike, name), false);
+        }
+    }
+    push("ranged_aim", format!("player/{}/ranged_aim.png", fighter_type), false);
+    push("bike_ranged_aim", format!("{}/rcrBikeRangedAim.png", bike), false);
+    paths
+}
+
+/// Textures `load_cpu_textures` loads for one CPU folder: the base sprite, then the
+/// optional strike frames (missing ones reuse the base sprite).
+pub fn cpu_texture_paths(folder: &str) -> (String, [String; 3]) {
+    (
+        format!("entity/{}/{}.png", folder, folder),
+        [1, 2, 3].map(|i| format!("entity/{}/{}Strike{}.png", folder, folder, i)),
+    )
+}
+
+/// Every texture, font and sound the game loads. Shared paths (e.g. the racer shield used
+/// by several fighters) appear once.
+pub fn manifest() -> Vec<ManifestEntry> {
+    let mut entries: Vec<ManifestEntry> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut push = |entry: ManifestEntry| {
+        if seen.insert(entry.path.clone()) {
+            entries.push(entry);
+        }
+    };
+
+    for (_, path) in GAME_TEXTURES {
+        push(ManifestEntry::new(*path, AssetKind::Texture, true));
+    }
+    for fighter_type in FIGHTER_TEXTURE_SETS {
+        for texture in fighter_texture_paths(fighter_type) {
+            push(ManifestEntry::new(texture.path, AssetKind::Texture, texture.required));
+        }
+    }
+    for asset in GroundAssetManager::new().assets() {
+        push(ManifestEntry::new(asset.path, AssetKind::Texture, true));
+    }
+    for folder in CPU_TEXTURE_FOLDERS {
+        let (base, strikes) = cpu_texture_paths(folder);
+        push(ManifestEntry::new(base, AssetKind::Texture, true));
+        for strike in strikes {
+            push(ManifestEntry::new(strike, AssetKind::Texture, false));
+        }
+    }
+    push(ManifestEntry::new(FONT, AssetKind::Font, true));
+    for (_, file) in SOUND_EFFECTS {
+        push(ManifestEntry::new(*file, AssetKind::Sound, true));
+    }
+    for (_, file) in OPTIONAL_SOUND_EFFECTS {
+        push(ManifestEntry::new(*file, AssetKind::Sound, false));
+    }
+    entries
+}
+
+/// `--check-assets <dir>`: validates an asset drop laid out like the game folder
+/// (`<dir>/assets`, `<dir>/sfx`) without opening a window. Prints missing, undecodable and
+/// unused files; returns false if any required file is missing or any file fails to decode.
+pub fn check_assets(dir: &Path) -> bool {
+    let assets_dir = dir.join("assets");
+    let sfx_dir = dir.join("sfx");
+    println!("[ASSETS] Checking {:?} and {:?}", assets_dir, sfx_dir);
+
+    let entries = manifest();
+    let assets_files = list_files(&assets_dir, &[FIRMAMENT_FOLDER]);
+    let sfx_files = list_files(&sfx_dir, &[]);
+
+    let mut missing_required = Vec::new();
+    let mut missing_optional = Vec::new();
+    let mut undecodable = Vec::new();
+    let mut case_mismatches = Vec::new();
+    let mut referenced: HashSet<(bool, String)> = HashSet::new();
+    let mut found = 0;
+
+    for entry in &entries {
+        let in_sfx = entry.kind == AssetKind::Sound;
+        let (root, files) = if in_sfx { (&sfx_dir, &sfx_files) } else { (&assets_dir, &assets_files) };
+        // Windows finds files regardless of case, other platforms do not; report both.
+        let actual = if files.contains_key(&entry.path) {
+            Some(entry.path.clone())
+        } else {
+            let lower = entry.path.to_lowercase();
+            let matched = files.keys().find(|f| f.to_lowercase() == lower).cloned();
+            if let Some(actual) = &matched {
+                case_mismatches.push(format!("{} (found as {})", entry.path, actual));
+            }
+            matched
+        };
+        let Some(actual) = actual else {
+            if entry.required {
+                missing_required.push(entry.path.clone());
+            } else {
+                missing_optional.push(entry.path.clone());
+            }
+            continue;
+        };
+        found += 1;
+        referenced.insert((in_sfx, actual.clone()));
+        if let Err(e) = validate_file(&root.join(&actual), entry.kind) {
+            undecodable.push(format!("{}: {}", actual, e));
+        }
+    }
+
+    let mut unused: Vec<String> = assets_files
+        .keys()
+        .filter(|f| !referenced.contains(&(false, (*f).clone())))
+        .map(|f| format!("assets/{}", f))
+        .chain(
+            sfx_files
+                .keys()
+                .filter(|f| !referenced.contains(&(true, (*f).clone())))
+                .map(|f| format!("sfx/{}", f)),
+        )
+        .collect();
+    unused.sort();
+    missing_required.sort();
+    missing_optional.sort();
+
+    print_section("Missing (required)", &missing_required);
+    print_section("Missing (optional, fallback used)", &missing_optional);
+    print_section("Undecodable", &undecodable);
+    print_section("Case differs from manifest (fails outside Windows)", &case_mismatches);
+    print_section("Unused (not in manifest)", &unused);
+    println!(
+        "[ASSETS] {} of {} manifest files present; {} missing required, {} undecodable, {} unused.",
+        found,
+        entries.len(),
+        missing_required.len(),
+        undecodable.len(),
+        unused.len()
+    );
+
+    let ok = missing_required.is_empty() && undecodable.is_empty();
+    println!("[ASSETS] {}", if ok { "OK" } else { "FAILED" });
+    ok
+}
+
+fn print_section(title: &str, items: &[String]) {
+    if items.is_empty() {
+        return;
+    }
+    println!("[ASSETS] {} ({}):", title, items.len());
+    for item in items {
+        println!("  - {}", item);
+    }
+}
+
+/// Files under `root`, keyed by '/'-separated relative path. Top-level folders in `skip`
+/// are left out.
+fn list_files(root: &Path, skip: &[&str]) -> HashMap<String, PathBuf> {
+    let mut files = HashMap::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let Ok(read_dir) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in read_dir.flatten() {
+            let path = entry.path();
+            let Ok(relative) = path.strip_prefix(root) else {
+                continue;
+            };
+            let key = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+                .join("/");
+            if path.is_dir() {
+                if !skip.contains(&key.as_str()) {
+                    stack.push(path);
+                }
+            } else {
+                files.insert(key, path);
+            }
+        }
+    }
+    files
+}
+
+fn validate_file(path: &Path, kind: AssetKind) -> Result<(), String> {
+    match kind {
+        AssetKind::Texture => image::open(path).map(|_| ()).map_err(|e| e.to_string()),
+        AssetKind::Sound => {
+            let file = File::open(path).map_err(|e| e.to_string())?;
+            rodio::Decoder::try_from(file).map(|_| ()).map_err(|e| e.to_string())
+        }
+        AssetKind::Font => {
+            let len = fs::metadata(path).map_err(|e| e.to_string())?.len();
+            if len == 0 {
+                Err("empty file".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
@@ -2,17 +2,22 @@
 
 use rodio::{source::Source, Decoder, OutputStream, Sink};
 use rodio::mixer::Mixer;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Instant;
 
+use crate::asset_manifest::{OPTIONAL_SOUND_EFFECTS, SOUND_EFFECTS};
+use crate::game_event::{GameEvent, GameEventListener};
+
 
 pub struct AudioManager {
-    _stream: OutputStream,
-    mixer: Arc<Mixer>,
+    _stream: Option<OutputStream>,
+    mixer: Option<Arc<Mixer>>, // None when running without an audio device (headless)
     sound_effects: Arc<Mutex<HashMap<String, PathBuf>>>,
+    // Names requested but never loaded; they play as silence and are logged once.
+    missing_sounds: Arc<Mutex<HashSet<String>>>,
 }
 
 static LAST_PLAYED: OnceLock<Mutex<HashMap<String, Instant>>> = OnceLock::new();
@@ -29,12 +34,56 @@ impl AudioManager {
         //println!("[AudioManager] Audio system initialized successfully");
 
         Ok(AudioManager {
-            _stream: stream,
-            mixer: mixer.into(),
+            _stream: Some(stream),
+            mixer: Some(mixer.into()),
             sound_effects: Arc::new(Mutex::new(HashMap::new())),
+            missing_sounds: Arc::new(Mutex::new(HashSet::new())),
         })
     }
 
+    /// Creates an AudioManager with no output device. Every play call succeeds
+    /// without producing sound, so the simulation can run headless.
+    pub fn silent() -> Self {
+        AudioManager {
+            _stream: None,
+            mixer: None,
+            sound_effects: Arc::new(Mutex::new(HashMap::new())),
+            missing_sounds: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// Creates a sink on the output mixer, or a detached sink when silent.
+    fn new_sink(&self) -> Sink {
+        match &self.mixer {
+            Some(mixer) => Sink::connect_new(mixer),
+            None => Sink::new().0,
+        }
+    }
+
+    /// True when there is no output device and every play call is a no-op.
+    pub fn is_silent(&self) -> bool {
+        self.mixer.is_none()
+    }
+
+    /// True if `name` was loaded. Missing sounds still "play", silently.
+    pub fn has_sound(&self, name: &str) -> bool {
+        let effects = self.sound_effects.lock().unwrap_or_else(|e| e.into_inner());
+        effects.contains_key(name)
+    }
+
+    /// Path of a loaded sound, or None (logged the first time) if it is missing.
+    fn sound_path(&self, name: &str) -> Option<PathBuf> {
+        let effects = self.sound_effects.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(path) = effects.get(name) {
+            return Some(path.clone());
+        }
+        let mut missing = self.missing_sounds.lock().unwrap_or_else(|e| e.into_inner());
+        if missing.insert(name.to_string()) {
+            eprintln!("[ASSETS] Missing sound '{}'; playing silence.", name);
+        }
+        None
+    }
+
     /// Load a sound effect and associate it with a name
     pub fn load_sound_effect(&self, name: &str, path: &PathBuf) -> Result<(), String> {
         //println!("[AudioManager] Loading sound effect '{}' from {:?}", name, path);
@@ -69,6 +118,10 @@ impl AudioManager {
 	
 /// Play a sound effect by name
     pub fn play_sound_effect(&self, name: &str) -> Result<(), String> {
+        if self.mixer.is_none() {
+            return Ok(());
+        }
+
         // Debounce logic (thread-safe)
         let last_played = LAST_PLAYED.get_or_init(|| Mutex::new(HashMap::new()));
         {
@@ -85,13 +138,8 @@ impl AudioManager {
 
         // --- Original Audio Playback Logic Below ---
 
-        // Clone path and release lock before I/O
-        let path = {
-            let effects = self.sound_effects.lock().unwrap_or_else(|e| e.into_inner());
-            effects
-                .get(name)
-                .ok_or_else(|| format!("Sound effect '{}' not found", name))?
-                .clone()
+        let Some(path) = self.sound_path(name) else {
+            return Ok(());
         };
 
         let file = File::open(&path)
@@ -100,7 +148,7 @@ impl AudioManager {
         let source = Decoder::try_from(file)
             .map_err(|e| format!("Failed to decode sound effect file: {}", e))?;
 
-        let sink = Sink::connect_new(&self.mixer);
+        let sink = self.new_sink();
         sink.set_volume(1.0);
         sink.append(source);
         sink.detach();
@@ -132,44 +180,8 @@ impl AudioManager {
 
         println!("Found sound effects directory at: {:?}", sfx_dir.display());
 
-        let effects = [
-			("boost", "boost.wav"),
-            ("melee", "slash.wav"),
-            ("ranged", "racer_ranged.wav"),
-            ("block", "block.wav"),
-            ("raise_shield", "guard.wav"),
-            ("block_break", "block_break.wav"),
-            ("rush", "rush.wav"),
-            ("bike_start", "SbrxBike_start.wav"),
-            ("title", "title.ogg"),
-            ("reload", "reload.wav"),
-            ("bike_accelerate", "sbrxBike_accelerate.ogg"),
-            ("bike_idle", "sbrxBike_idle.ogg"),
-            ("death", "death.wav"),
-            ("firearm", "firearm.wav"),
-            ("aim", "aim.wav"),
-            ("hit", "hit.wav"),
-            ("mantis_attack", "SlashSwipe.wav"),
-            ("slash_combo", "slashCombo.wav"),
-            ("crickets", "crickets.ogg"),
-            ("sdtrk1", "background_tracks/sdtrk1.ogg"),
-            ("sdtrk2", "background_tracks/sdtrk2.ogg"),
-            ("sdtrk3", "background_tracks/sdtrk3.ogg"),
-            ("sdtrk4", "background_tracks/sdtrk4.ogg"),
-            ("sdtrk5", "background_tracks/sdtrk5.ogg"),
-            ("sdtrk6", "background_tracks/sdtrk6.ogg"),
-            ("sdtrk7", "background_tracks/sdtrk7.ogg"),
-            ("sdtrk8", "background_tracks/sdtrk8.ogg"),
-            ("sdtrk9", "background_tracks/sdtrk9.ogg"),
-            ("sdtrk10", "background_tracks/sdtrk10.ogg"),
-            ("sdtrk11", "background_tracks/sdtrk11.ogg"),
-            ("sdtrk12", "background_tracks/sdtrk12.ogg"),
-            ("sdtrk13", "background_tracks/sdtrk13.ogg"),
-            ("sdtrk14", "background_tracks/sdtrk14.ogg"),
-            ("sdtrk15", "background_tracks/sdtrk15.ogg"),
-        ];
-
-        for (name, filename) in effects.iter() {
+
+        for (name, filename) in SOUND_EFFECTS.iter().chain(OPTIONAL_SOUND_EFFECTS) {
             let path = sfx_dir.join(filename);
             match self.load_sound_effect(name, &path) {
                 Ok(_) => println!("✓ Loaded: {} from {}", name, path.display()),
@@ -183,14 +195,12 @@ impl AudioManager {
     /// Play a sound effect in a loop by name and return the Sink for control.
 	pub fn play_sfx_loop(&self, name: &str) -> Result<Sink, String> {
 		//println!("[AudioManager] play_sfx_loop called for: {}", name);
+		if self.mixer.is_none() {
+			return Ok(Sink::new().0);
+		}
 		
-		// Clone path and release lock before I/O
-		let path = {
-			let effects_guard = self.sound_effects.lock().unwrap_or_else(|e| e.into_inner());
-			effects_guard
-				.get(name)
-				.ok_or_else(|| format!("Looping sound effect '{}' not found", name))?
-				.clone()
+		let Some(path) = self.sound_path(name) else {
+			return Ok(Sink::new().0);
 		};
 
 		//println!("[AudioManager] Found path: {:?}", path);
@@ -206,7 +216,7 @@ impl AudioManager {
 
 		let looped_source = source.repeat_infinite();
 
-		let sink = Sink::connect_new(&self.mixer);
+		let sink = self.new_sink();
 		sink.set_volume(1.0);
 		sink.append(looped_source);
 		
@@ -223,12 +233,12 @@ impl AudioManager {
 	
     /// Play a sound effect once by name and return the Sink for control.
     pub fn play_sfx_with_sink(&self, name: &str) -> Result<Sink, String> {
-        let path = {
-            let effects_guard = self.sound_effects.lock().unwrap_or_else(|e| e.into_inner());
-            effects_guard
-                .get(name)
-                .ok_or_else(|| format!("Sound effect '{}' not found", name))?
-                .clone()
+        if self.mixer.is_none() {
+            return Ok(Sink::new().0);
+        }
+
+        let Some(path) = self.sound_path(name) else {
+            return Ok(Sink::new().0);
         };
  
         let file = File::open(&path)
@@ -237,11 +247,20 @@ impl AudioManager {
         let source = Decoder::try_from(file)
             .map_err(|e| format!("Failed to decode audio file {}: {}", name, e))?;
  
-        let sink = Sink::connect_new(&self.mixer);
+        let sink = self.new_sink();
         sink.set_volume(1.0);
         sink.append(source);
  
         Ok(sink)
     }
 }
- 
\ No newline at end of file
+ 
+
+impl GameEventListener for AudioManager {
+    fn on_game_event(&mut self, event: &GameEvent) {
+        if let GameEvent::LevelUp { .. } = event {
+            let sound = if self.has_sound("level_up") { "level_up" } else { "boost" };
+            self.play_sound_effect(sound).ok();
+        }
+    }
+}
@@ -2,9 +2,10 @@
 
 use piston_window::*;
 use std::collections::VecDeque;
-use std::path::PathBuf;
 
-use crate::config::gameplay::WARNING_MESSAGE_DURATION;
+use crate::config;
+use crate::graphics::assets::AssetRegistry;
+use crate::game_event::{fighter_name, GameEvent, GameEventListener};
 
 /// Enum to define the type of message for standardized color-coding.
 #[derive(Clone)]
@@ -61,16 +62,11 @@ pub struct ChatBox {
 
 impl ChatBox {
     /// Creates a new ChatBox instance and loads necessary icon assets.
-    pub fn new(window: &mut PistonWindow, assets_path: &PathBuf) -> Self {
-        let texture_context = &mut window.create_texture_context();
+    pub fn new(texture_context: &mut G2dTextureContext, assets: &mut AssetRegistry) -> Self {
         let settings = TextureSettings::new().filter(Filter::Nearest);
 
-        // Helper closure to load textures, panicking on failure as they are essential.
-        let mut load_texture = |name: &str| {
-            let path = assets_path.join(format!("{}.png", name));
-            Texture::from_path(texture_context, &path, Flip::None, &settings)
-                .unwrap_or_else(|e| panic!("Failed to load chatbox texture at {:?}: {}", path, e))
-        };
+        // Missing icons come back as placeholders, so the chatbox always has something to draw.
+        let mut load_texture = |name: &str| assets.game_texture(texture_context, name, &settings);
 
         ChatBox {
             messages: VecDeque::new(),
@@ -93,7 +89,7 @@ impl ChatBox {
             match message_type {
                 MessageType::Warning => {
                     self.warnings
-                        .push_back((text.to_string(), WARNING_MESSAGE_DURATION));
+                        .push_back((text.to_string(), config::get().gameplay.warning_message_duration));
                 }
                 _ => {
                     total_lines_added += self.add_message_internal(text, message_type);
@@ -333,3 +329,18 @@ impl ChatBox {
         }
     }
 }
+
+impl GameEventListener for ChatBox {
+    fn on_game_event(&mut self, event: &GameEvent) {
+        if let GameEvent::LevelUp { fighter_type, stat_points } = event {
+            self.add_interaction(vec![(
+                &format!(
+                    "!! [TAB] TO LVL UP [{}] +[{}] !!",
+                    fighter_name(*fighter_type),
+                    stat_points
+                ),
+                MessageType::Warning,
+            )]);
+        }
+    }
+}
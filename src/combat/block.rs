@@ -2,11 +2,15 @@
 
 use crate::CpuRacer;
 use crate::audio::AudioManager;
-use crate::config::gameplay::COLLISION_THRESHOLD;
+use crate::combat::damage::{self, Attack, DamageType, Defense};
+use crate::combat::hitbox::{self, Hitbox, Hurtbox};
+use crate::combat::resources::{self, Regen, ResourceAction, ResourceKind};
+use crate::combat::threat::ThreatTarget;
+use crate::config;
 use crate::entities::cpu_entity::CpuEntity;
 use crate::entities::fighter::Fighter;
 use crate::entities::strike::Strike; // For strike visual
-use crate::game_state::RacerState;
+use crate::game_state::{FighterType, RacerState};
 use crate::DamageText;
 use piston_window::*; // For strike collision check
 use crate::EntityState;
@@ -19,6 +23,14 @@ pub const KINETIC_STRIKE_BASE_KNOCKBACK: f64 = 1000.0;
 pub const KINETIC_STRIKE_DAMAGE_IMMUNITY_DURATION: f64 = 0.25;
 pub const KINETIC_RUSH_BASE_DISTANCE_MULTIPLIER: f64 = 0.5; // Base multiplier for rush distance
 
+// Perfect block: a hit landing within gameplay.parry_window of raising the block.
+pub const PARRY_STAGGER_DURATION: f64 = 0.75; // attacker stun
+pub const PARRY_KNOCKBACK: f64 = 400.0;
+pub const PARRY_BLOCK_REFUND: i32 = 2; // block points given back
+pub const PARRY_KINETIC_INTAKE: i32 = 2; // kinetic intake gained on top of the block count
+pub const PARRY_FLASH_DURATION: f64 = 0.3;
+const PARRY_RAISE_COOLDOWN: f64 = 0.5; // re-raising sooner than this opens no window
+
 // Effectiveness multipliers based on kinetic_intake_count (index i = kinetic_intake_count i)
 // Index 0 is unused as KI must be > 0. Max index is 20.
 pub const KINETIC_STRIKE_MULTIPLIERS: [f64; 21] = [
@@ -65,6 +77,16 @@ pub struct BlockSystem {
     pub kinetic_intake_count: i32,
     pub kinetic_strike_damage_immunity_timer: f64,
     pub last_kinetic_strike_timer: f64,
+    pub raised_for: f64,           // seconds since the block went up
+    pub parry_timer: f64,          // time left in the parry window
+    pub parry_raise_cooldown: f64, // keeps block mashing from always parrying
+    pub parry_flash_timer: f64,    // parry visual
+    pub parry_intake: i32,         // kinetic intake from parries, not backed by spent block points
+    // From the fighter's resource profile (see follow_profile)
+    pub regen_rate: f64,            // block points per second
+    pub regen_delay: f64,           // seconds after lowering the block before regen starts
+    pub block_cost: i32,            // block points per blocked hit
+    pub profile_for: Option<FighterType>,
 }
 
 impl BlockSystem {
@@ -90,12 +112,66 @@ impl BlockSystem {
             kinetic_intake_count: max_blocks - initial_block_count,
             kinetic_strike_damage_immunity_timer: 0.0,
             last_kinetic_strike_timer: 0.0,
+            raised_for: 0.0,
+            parry_timer: 0.0,
+            parry_raise_cooldown: 0.0,
+            parry_flash_timer: 0.0,
+            parry_intake: 0,
+            regen_rate: 5.0,
+            regen_delay: 1.25,
+            block_cost: 1,
+            profile_for: None,
+        }
+    }
+
+    /// Takes max block points, regen and block cost from the fighter type's resource
+    /// profile. Only does work when the fighter type changes.
+    pub fn follow_profile(&mut self, fighter_type: FighterType) {
+        if self.profile_for == Some(fighter_type) {
+            return;
+        }
+        self.profile_for = Some(fighter_type);
+        let profile = resources::get().profile(fighter_type);
+        // A fighter without block points can't hold a block: the first hit breaks it.
+        let (max, regen) = profile
+            .spec(ResourceKind::BlockPoints)
+            .map_or((0, Regen::None), |spec| (spec.max.round() as i32, spec.regen));
+        match regen {
+            Regen::Rate { per_second, delay } => {
+                self.regen_rate = per_second;
+                self.regen_delay = delay;
+            }
+            _ => self.regen_rate = 0.0,
+        }
+        self.block_cost = profile
+            .cost(ResourceAction::Block)
+            .filter(|cost| cost.kind == ResourceKind::BlockPoints)
+            .map_or(0, |cost| cost.amount.round() as i32);
+        self.max_block_count = max;
+        self.block_count = self.block_count.min(max);
+        self.block_count_float = self.block_count_float.min(max as f64);
+        self.parry_intake = self.parry_intake.min(max);
+        self.update_kinetic_intake_count();
+        println!(
+            "[BLOCK] {:?} profile: {} block points, regen {}/s, cost {}",
+            fighter_type, max, self.regen_rate, self.block_cost
+        );
+    }
+
+    /// Kinetic intake scaled onto the 0..=20 range of KINETIC_STRIKE_MULTIPLIERS, so the
+    /// strike tiers hold for any max block count.
+    pub fn kinetic_level(&self) -> usize {
+        if self.max_block_count <= 0 || self.kinetic_intake_count <= 0 {
+            return 0;
         }
+        let last = KINETIC_STRIKE_MULTIPLIERS.len() - 1;
+        let scaled = (self.kinetic_intake_count as f64 * last as f64 / self.max_block_count as f64).round();
+        (scaled as usize).clamp(1, last)
     }
 
     fn update_kinetic_intake_count(&mut self) {
-        self.kinetic_intake_count =
-            (self.max_block_count - self.block_count).clamp(0, self.max_block_count);
+        self.kinetic_intake_count = (self.max_block_count - self.block_count + self.parry_intake)
+            .clamp(0, self.max_block_count);
     }
 
     pub fn is_kinetic_strike_active(&self) -> bool {
@@ -116,6 +192,11 @@ impl BlockSystem {
             self.rmb_held = true;
             self.regen_timer = 0.0;
             self.block_count_float = self.block_count as f64;
+            self.raised_for = 0.0;
+            if self.parry_raise_cooldown <= 0.0 {
+                self.parry_timer = config::get().gameplay.parry_window;
+                self.parry_raise_cooldown = PARRY_RAISE_COOLDOWN;
+            }
 
             println!(
                 "[ACTIVATE_BLOCK] rmb_held: true, block_count: {}",
@@ -131,13 +212,14 @@ impl BlockSystem {
         let was_actively_blocking = self.active || self.rmb_held;
         self.rmb_held = false; // RMB released, even if block was not "active" due to fatigue
         self.active = false; // Stop active blocking state
+        self.parry_timer = 0.0;
 
         if was_actively_blocking // Only start regen delay if we were actually trying to block
             && !self.block_broken // and not if we are already broken
             && !self.block_fatigue // or fatigued
             && self.block_count < self.max_block_count
         {
-            self.regen_timer = 1.25;
+            self.regen_timer = self.regen_delay;
         }
     }
 
@@ -156,6 +238,18 @@ impl BlockSystem {
         if self.kinetic_strike_damage_immunity_timer > 0.0 {
             self.kinetic_strike_damage_immunity_timer -= dt;
         }
+        if self.active {
+            self.raised_for += dt;
+        }
+        if self.parry_timer > 0.0 {
+            self.parry_timer -= dt;
+        }
+        if self.parry_raise_cooldown > 0.0 {
+            self.parry_raise_cooldown -= dt;
+        }
+        if self.parry_flash_timer > 0.0 {
+            self.parry_flash_timer -= dt;
+        }
 
         if self.block_broken {
             // This state is set on block break, lasts through stun & vulnerability
@@ -190,13 +284,17 @@ impl BlockSystem {
                 self.regen_timer -= dt;
             } else {
                 let old_block_count_int = self.block_count;
-                self.block_count_float += 5.0 * dt;
+                self.block_count_float += self.regen_rate * dt;
                 self.block_count_float = self.block_count_float.min(self.max_block_count as f64);
                 let new_block_count_int = self.block_count_float.floor() as i32;
                 if new_block_count_int != old_block_count_int {
                     self.block_count = new_block_count_int;
                     block_count_changed = true;
                 }
+                // Parry intake lasts until the block has fully recovered.
+                if self.block_count >= self.max_block_count {
+                    self.parry_intake = 0;
+                }
             }
         }
 
@@ -234,10 +332,16 @@ impl BlockSystem {
         }
         cpu_entity.attack_was_blocked = true;
 
+        if self.try_parry(audio_manager) {
+            cpu_entity.stun_timer = cpu_entity.stun_timer.max(PARRY_STAGGER_DURATION);
+            cpu_entity.apply_knockback(fighter.x, fighter.y, PARRY_KNOCKBACK);
+            return true;
+        }
+
         let mut block_point_was_consumed_this_event = false;
         if current_time - self.last_block_consumption_time >= self.min_time_between_blocks {
             if self.block_count >= 0 {
-                self.block_count -= 1;
+                self.block_count -= self.block_cost;
                 block_point_was_consumed_this_event = true;
                 self.last_block_consumption_time = current_time;
             }
@@ -249,6 +353,7 @@ impl BlockSystem {
             self.block_count = 0;
             self.block_count_float = 0.0;
             self.kinetic_intake_count = 0;
+            self.parry_intake = 0;
 
             self.block_broken = true; // Enter broken state (stun/vulnerability)
             self.active = false;
@@ -257,7 +362,7 @@ impl BlockSystem {
             self.stun_lock_timer = 1.25;
             self.vulnerability_timer = self.stun_lock_timer + 2.5;
             self.fatigue_timer = 2.5; // This fatigue starts *after* vulnerability
-            self.regen_timer = 1.25; // Delay for block points regen *after* full recovery cycle
+            self.regen_timer = self.regen_delay; // Delay for block points regen *after* full recovery cycle
 
             if fighter.state == RacerState::OnBike {
                 self.needs_dismount = true;
@@ -290,6 +395,17 @@ impl BlockSystem {
         fighter: &mut Fighter,
         audio_manager: &AudioManager,
         current_time: f64,
+    ) -> bool {
+        self.process_block(fighter.state == RacerState::OnBike, audio_manager, current_time)
+    }
+
+    /// Spends block points on a hit, breaking the block when they run out. `on_bike`
+    /// flags a dismount on break. Used for projectiles and by CPU racers guarding.
+    pub fn process_block(
+        &mut self,
+        on_bike: bool,
+        audio_manager: &AudioManager,
+        current_time: f64,
     ) -> bool {
         if !self.active || self.block_broken || self.block_fatigue {
             return false;
@@ -298,7 +414,7 @@ impl BlockSystem {
         let mut block_point_was_consumed_this_event = false;
         if current_time - self.last_block_consumption_time >= self.min_time_between_blocks {
             if self.block_count >= 0 {
-                self.block_count -= 1;
+                self.block_count -= self.block_cost;
                 block_point_was_consumed_this_event = true;
                 self.last_block_consumption_time = current_time;
             }
@@ -310,6 +426,7 @@ impl BlockSystem {
             self.block_count = 0;
             self.block_count_float = 0.0;
             self.kinetic_intake_count = 0;
+            self.parry_intake = 0;
 
             self.block_broken = true;
             self.active = false;
@@ -318,9 +435,9 @@ impl BlockSystem {
             self.stun_lock_timer = 1.25;
             self.vulnerability_timer = self.stun_lock_timer + 2.5;
             self.fatigue_timer = 2.5;
-            self.regen_timer = 1.25;
+            self.regen_timer = self.regen_delay;
 
-            if fighter.state == RacerState::OnBike {
+            if on_bike {
                 self.needs_dismount = true;
             }
             audio_manager.play_sound_effect("block_break").ok();
@@ -343,9 +460,77 @@ impl BlockSystem {
         }
     }
 
+    /// Blocks a CpuRacer attack, parrying it (and staggering the racer) inside the parry window.
+    pub fn process_racer_attack(
+        &mut self,
+        fighter: &mut Fighter,
+        cpu_racer: &mut CpuRacer,
+        audio_manager: &AudioManager,
+        current_time: f64,
+    ) -> bool {
+        if !self.active || self.block_broken || self.block_fatigue {
+            return false;
+        }
+        if self.try_parry(audio_manager) {
+            cpu_racer.attack_was_blocked = true;
+            cpu_racer.is_attacking = false;
+            cpu_racer.rush_active = false;
+            cpu_racer.stun_timer = cpu_racer.stun_timer.max(PARRY_STAGGER_DURATION);
+            let dx = cpu_racer.x - fighter.x;
+            let dy = cpu_racer.y - fighter.y;
+            let dist = (dx * dx + dy * dy).sqrt();
+            if dist > 0.0 {
+                cpu_racer.knockback_velocity = Vec2d::new(dx / dist * PARRY_KNOCKBACK, dy / dist * PARRY_KNOCKBACK);
+                cpu_racer.knockback_duration = 0.1;
+            }
+            return true;
+        }
+        self.process_projectile_block(fighter, audio_manager, current_time)
+    }
+
+    /// Perfect block: if the parry window from the last raise is still open, the hit is
+    /// negated without spending a block point. Refunds block points and adds kinetic
+    /// intake; the caller staggers the attacker.
+    pub fn try_parry(&mut self, audio_manager: &AudioManager) -> bool {
+        if !self.active || self.parry_timer <= 0.0 || self.block_broken || self.block_fatigue {
+            return false;
+        }
+        self.parry_timer = 0.0; // one parry per raise
+        self.parry_flash_timer = PARRY_FLASH_DURATION;
+        self.block_count = (self.block_count + PARRY_BLOCK_REFUND).min(self.max_block_count);
+        self.block_count_float = self.block_count as f64;
+        self.parry_intake = (self.parry_intake + PARRY_KINETIC_INTAKE).min(self.max_block_count);
+        self.update_kinetic_intake_count();
+
+        let sound = if audio_manager.has_sound("parry") { "parry" } else { "slash_combo" };
+        audio_manager.play_sound_effect(sound).ok();
+        println!(
+            "[PARRY] BlockCount: {}, KineticIntake: {}",
+            self.block_count, self.kinetic_intake_count
+        );
+        true
+    }
+
+    /// Expanding ring around a blocker that just parried. `transform` is in world space.
+    pub fn draw_parry_flash(&self, transform: math::Matrix2d, g: &mut G2d, x: f64, y: f64) {
+        if self.parry_flash_timer <= 0.0 {
+            return;
+        }
+        let progress = 1.0 - self.parry_flash_timer / PARRY_FLASH_DURATION;
+        let radius = 60.0 + 60.0 * progress;
+        let alpha = (1.0 - progress) as f32;
+        Ellipse::new_border([0.6, 0.9, 1.0, alpha], 4.0).draw(
+            [x - radius, y - radius, radius * 2.0, radius * 2.0],
+            &DrawState::default(),
+            transform,
+            g,
+        );
+    }
+
     fn get_kinetic_strike_effectiveness_multiplier(&self) -> f64 {
-        if self.kinetic_intake_count >= 1 && self.kinetic_intake_count <= 20 {
-            KINETIC_STRIKE_MULTIPLIERS[self.kinetic_intake_count as usize]
+        let level = self.kinetic_level();
+        if level >= 1 {
+            KINETIC_STRIKE_MULTIPLIERS[level]
         } else {
             0.0 // Should not happen if check kinetic_intake_count > 0 is done before calling
         }
@@ -376,15 +561,15 @@ impl BlockSystem {
             return;
         }
 
-        let ki_level_for_strike = self.kinetic_intake_count; // Store before reset
+        let ki_level_for_strike = self.kinetic_level(); // Store before reset
         let effectiveness_multiplier = self.get_kinetic_strike_effectiveness_multiplier();
 
         let strike_radius = if ki_level_for_strike <= 10 {
-            COLLISION_THRESHOLD
+            config::get().gameplay.collision_threshold
         } else if ki_level_for_strike <= 17 {
-            COLLISION_THRESHOLD * 3.0
+            config::get().gameplay.collision_threshold * 3.0
         } else {
-            COLLISION_THRESHOLD * 5.0
+            config::get().gameplay.collision_threshold * 5.0
         };
 
         println!(
@@ -403,15 +588,18 @@ impl BlockSystem {
         audio_manager.play_sound_effect("death").ok(); // kinetic strike sounds effect
         strike_visual.trigger(world_x, world_y); // Use existing strike visual
 
+        let strike_hitbox = Hitbox::circle(world_x, world_y, strike_radius);
+        hitbox::record(strike_hitbox);
+
         // Apply damage and knockback to CPUs
-        if crate::config::CPU_ENABLED && !is_paused {
+        if config::get().cpu_enabled && !is_paused {
             for cpu in cpu_entities.iter_mut() {
                 if cpu.entity_state == EntityState::Friendly {
                     continue;
                 }				
-                let strike_dx = world_x - cpu.x;
-                let strike_dy = world_y - cpu.y;
-                if (strike_dx * strike_dx + strike_dy * strike_dy).sqrt() < strike_radius {
+                let hurtbox = Hurtbox::for_cpu(cpu);
+                if strike_hitbox.hits(&hurtbox) {
+                    hitbox::record_hit(&hurtbox);
                     let mut damage = fighter.melee_damage * effectiveness_multiplier;
                     let knockback_force = KINETIC_STRIKE_BASE_KNOCKBACK * effectiveness_multiplier;
 
@@ -420,26 +608,22 @@ impl BlockSystem {
                         damage *= 1.25;
                     }
 
-                    cpu.current_hp -= damage;
+                    let hit = damage::resolve(
+                        &Attack::with_stats(damage, DamageType::Kinetic, &fighter.stats.attack),
+                        &Defense::cpu(cpu),
+                    );
+                    // Add damage text for kinetic strike
+                    damage_texts.push(hit.text(cpu.x, cpu.y - 50.0));
+                    if !hit.landed() {
+                        continue;
+                    }
+                    cpu.current_hp -= hit.amount;
+                    cpu.threat.add(ThreatTarget::Player, hit.threat);
                     println!(
                         "[KINETIC_STRIKE_HIT] CPU HP: {:.1}, Damage: {:.1}",
-                        cpu.current_hp, damage
+                        cpu.current_hp, hit.amount
                     );
 
-                    // Add damage text for kinetic strike
-                    let ks_dmg_color = if fighter.invincible_timer > 1.0 {
-                        [0.0, 0.9, 1.0, 1.0] // Cyan — ATOMIC-STATE active
-                    } else {
-                        [0.0, 1.0, 0.0, 1.0] // Green
-                    };
-                    damage_texts.push(DamageText {
-                        text: format!("{:.0}", damage),
-                        x: cpu.x,
-                        y: cpu.y - 50.0,
-                        color: ks_dmg_color,
-                        lifetime: 0.25,
-                    });
-
                     // Knockback is applied only if the target survives.
                     // Death processing is handled in the main loop for consistency.
                     if !cpu.is_dead() {
@@ -452,9 +636,9 @@ impl BlockSystem {
                 for cr in cpu_racers.iter_mut() {
                     if cr.is_crashed { continue; }
 					if cr.entity_state == EntityState::Friendly { continue; }
-                    let strike_dx = world_x - cr.x;
-                    let strike_dy = world_y - cr.y;
-                    if (strike_dx * strike_dx + strike_dy * strike_dy).sqrt() < strike_radius {
+                    let hurtbox = Hurtbox::for_racer(cr);
+                    if strike_hitbox.hits(&hurtbox) {
+                        hitbox::record_hit(&hurtbox);
                         if cr.entity_state == EntityState::Neutral {
                             cr.entity_state = EntityState::Hostile;
                         }						
@@ -465,21 +649,15 @@ impl BlockSystem {
                             damage *= 1.25;
                         }
  
-                        cr.current_hp -= damage;
- 
-                        let ks_dmg_color = if fighter.invincible_timer > 1.0 {
-                            [0.0, 0.9, 1.0, 1.0]
-                        } else {
-                            [0.0, 1.0, 0.0, 1.0]
-                        };
- 
-                        damage_texts.push(DamageText {
-                            text: format!("{:.0}", damage),
-                            x: cr.x,
-                            y: cr.y - 50.0,
-                            color: ks_dmg_color,
-                            lifetime: 0.25,
-                        });
+                        let hit = damage::resolve(
+                            &Attack::with_stats(damage, DamageType::Kinetic, &fighter.stats.attack),
+                            &Defense::cpu_racer(cr),
+                        );
+                        damage_texts.push(hit.text(cr.x, cr.y - 50.0));
+                        if !hit.landed() {
+                            continue;
+                        }
+                        cr.current_hp -= hit.amount;
  
 						if cr.current_hp > 0.0 {
                             let kx = cr.x - world_x;
@@ -502,11 +680,12 @@ impl BlockSystem {
 
         // Consequences of Kinetic Strike:
         // 1. Start the combo system timer for the next combo sequence.
-        combo_system.start_timer_after_kinetic_strike();
+        combo_system.start_timer_after_kinetic_strike(fighter.fighter_type);
 
         // 2. Reset block/fatigue state.
         self.block_count = 0;
         self.block_count_float = 0.0;
+        self.parry_intake = 0;
         self.update_kinetic_intake_count(); // This will set KI to max_block_count (e.g., 20) for UI
 
         self.active = false;
@@ -514,7 +693,7 @@ impl BlockSystem {
         self.block_broken = false; // Not "broken" in stun/vuln sense, directly to fatigue
         self.block_fatigue = true;
         self.fatigue_timer = 2.5; // Standard fatigue duration
-        self.regen_timer = 1.25; // Regen delay starts after fatigue ends
+        self.regen_timer = self.regen_delay; // Regen delay starts after fatigue ends
 
         println!(
             "[KINETIC_STRIKE_POST] BlockCount: 0, KineticIntake (UI): {}. Fatigue started. Combo timer initiated.",
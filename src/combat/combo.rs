@@ -1,103 +1,97 @@
 // File: src/combat/combo.rs
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum StrikeTimerState {
-    Timer1,
-    Timer2,
-    Timer3,
-}
+use crate::combat::combo_data::{self, ComboChain};
+use crate::game_state::FighterType;
 
 pub struct StrikeResult {
     pub damage_multiplier: f64,
     pub knockback: bool,
     pub knockback_force: f64,
-    pub apply_stun: bool,
+    pub stun_duration: f64, // 0 = no stun
+    pub grants_atomic_state: bool,
     pub is_combo_finisher: bool,
     pub finisher_slash_count: u32,
-    pub finisher_hit_count: u32,
 }
 
+impl StrikeResult {
+    fn basic(damage_multiplier: f64, slash_count: u32) -> Self {
+        StrikeResult {
+            damage_multiplier,
+            knockback: false,
+            knockback_force: 0.0,
+            stun_duration: 0.0,
+            grants_atomic_state: false,
+            is_combo_finisher: false,
+            finisher_slash_count: slash_count,
+        }
+    }
+}
+
+/// Tracks progress through the fighter's combo chain (see combat::combo_data). Each tier
+/// opens a strike timer; landing the tier's strikes inside its strike zone and then resting
+/// until the timer runs out moves on to the next tier.
 pub struct ComboSystem {
-    state: StrikeTimerState,
-    pub timer: f64, // Time remaining in the 0.65s window
+    tier: usize,      // Index into the fighter's ComboChain
+    window: f64,      // Length of the running strike timer
+    pub timer: f64,   // Time remaining in the strike window
     strike_count: u32,
     last_combo_strike_timer: f64, // For visual effect duration
-    melee_cooldown: f64,          // After Timer3 combo
+    melee_cooldown: f64,          // After the last tier of the chain
     pub is_in_rest_period: bool,  // Flag to check if we are waiting for the timer to run out
-    pub is_combo3_stun_disabled: bool,
+    pub is_finisher_stun_disabled: bool,
 	pub racer_combo_hit_connected: bool,
-    // may need later
-    //pub is_combo5_stun_disabled: bool,
 }
 
 impl ComboSystem {
-    // --- Constants for Combo Tiers ---
-    const STRIKE_TIMER_DURATION: f64 = 0.65;
-    const POST_COMBO_3_COOLDOWN: f64 = 0.25;
     const COMBO_VISUAL_DURATION: f64 = 0.25; // How long the green slash appears
-    const ENHANCED_KNOCKBACK_MULTIPLIER: f64 = 2.25; // 125%
-
-    // Timer 1 (2-hit)
-    const T1_STRIKE_ZONE: f64 = 0.20;
-    const T1_STRIKES_ACCEPTED: u32 = 2;
-    const T1_BASIC_DMG_MULT: f64 = 1.0; // 100% damage
-    const T1_COMBO_DMG_MULT: f64 = 1.25;
-    const T1_DAMAGE_REDUCTION: f64 = 0.50;
-
-    // Timer 2 (3-hit)
-    const T2_STRIKE_ZONE: f64 = 0.40;
-    const T2_STRIKES_ACCEPTED: u32 = 3;
-    const T2_BASIC_DMG_MULT: f64 = 1.25;
-    const T2_COMBO_DMG_MULT: f64 = 1.75;
-    const T2_DAMAGE_REDUCTION: f64 = 0.75;
-
-    // Timer 3 (5-hit)
-    const T3_STRIKE_ZONE: f64 = 1.25;
-    const T3_STRIKES_ACCEPTED: u32 = 5;
-    const T3_BASIC_DMG_MULT: f64 = 2.0;
-    const T3_COMBO_DMG_MULT: f64 = 2.50;
-    const T3_DAMAGE_REDUCTION: f64 = 0.95;
 
     pub fn new() -> Self {
         Self {
-            state: StrikeTimerState::Timer1,
+            tier: 0,
+            window: 0.0,
             timer: 0.0,
             strike_count: 0,
             last_combo_strike_timer: 0.0,
             melee_cooldown: 0.0,
             is_in_rest_period: false,
-            is_combo3_stun_disabled: false,
+            is_finisher_stun_disabled: false,
 			racer_combo_hit_connected: false,
-            // may need later
-            //is_combo5_stun_disabled: false,
         }
     }
 
+    fn chain(fighter_type: FighterType) -> &'static ComboChain {
+        combo_data::get().chain(fighter_type)
+    }
+
     /// Called by a kinetic strike to begin the combo timer sequence.
-    /// This sets the system as if the 2-hit combo just finished, opening the window
-    /// for the 3-hit combo timer.
-    pub fn start_timer_after_kinetic_strike(&mut self) {
-        self.state = StrikeTimerState::Timer1; // We are "finishing" Timer1
-        self.timer = Self::STRIKE_TIMER_DURATION; // Start the full timer
-        self.strike_count = Self::T1_STRIKES_ACCEPTED; // Set strike count to max for the tier
-        self.is_in_rest_period = true; // Enter the rest period, which will transition to Timer2 on expiry
+    /// This sets the system as if the first tier's combo just finished, opening the
+    /// window for the second tier.
+    pub fn start_timer_after_kinetic_strike(&mut self, fighter_type: FighterType) {
+        let first = Self::chain(fighter_type).tier(0);
+        self.tier = 0; // We are "finishing" the first tier
+        self.window = first.window;
+        self.timer = first.window; // Start the full timer
+        self.strike_count = first.strikes; // Set strike count to max for the tier
+        self.is_in_rest_period = true; // Enter the rest period, which will progress on expiry
         self.melee_cooldown = 0.0; // Ensure no cooldown prevents the next combo
         self.last_combo_strike_timer = 0.0; // Don't show a green slash for this
     }
 
     /// Resets the combo system to its initial state. Called on pause/unpause.
     pub fn reset(&mut self) {
-        self.state = StrikeTimerState::Timer1;
+        self.tier = 0;
         self.timer = 0.0;
         self.strike_count = 0;
         self.last_combo_strike_timer = 0.0;
         self.melee_cooldown = 0.0;
         self.is_in_rest_period = false;
-        self.is_combo3_stun_disabled = false;
+        self.is_finisher_stun_disabled = false;
 		self.racer_combo_hit_connected = false;
     }
 
-    pub fn update(&mut self, dt: f64) {
+    /// Runs the timers. A completed rest period moves on to the next tier of
+    /// `fighter_type`'s chain.
+    pub fn update(&mut self, dt: f64, fighter_type: FighterType) {
         if self.melee_cooldown > 0.0 {
             self.melee_cooldown -= dt;
         }
@@ -109,250 +103,201 @@ impl ComboSystem {
             self.timer -= dt;
 
             if self.timer <= 0.0 {
-                // Timer expired. This means a successful rest period completed.
+                self.timer = 0.0;
                 if self.is_in_rest_period {
-                    self.is_in_rest_period = false;
-                    self.strike_count = 0;
-                    // Progress to the next state
-                    // Default progression - will be overridden by fighter-specific logic in main.rs
-                    self.state = match self.state {
-                        StrikeTimerState::Timer1 => StrikeTimerState::Timer2,
-                        StrikeTimerState::Timer2 => StrikeTimerState::Timer3,
-                        StrikeTimerState::Timer3 => {
-                            self.melee_cooldown = Self::POST_COMBO_3_COOLDOWN;
-                            StrikeTimerState::Timer1 // Loop back
-                        }
-                    };
+                    self.advance_tier(fighter_type);
                 } else {
                     // Timer expired but we weren't in a rest period (e.g., player hit once then stopped).
                     // This is a failed combo. Reset.
-                    self.reset_to_timer1();
+                    self.reset_to_first_tier();
                 }
             }
         }
     }
 
-    pub fn handle_strike(&mut self) -> Option<StrikeResult> {
+    // Strike handling that follows the fighter's combo chain
+    pub fn handle_strike_for_fighter(&mut self, fighter_type: FighterType) -> Option<StrikeResult> {
         if self.melee_cooldown > 0.0 {
             return None;
         }
 
-        let (zone_end, accepted, basic_mult, combo_mult) = match self.state {
-            StrikeTimerState::Timer1 => (
-                Self::T1_STRIKE_ZONE,
-                Self::T1_STRIKES_ACCEPTED,
-                Self::T1_BASIC_DMG_MULT,
-                Self::T1_COMBO_DMG_MULT,
-            ),
-            StrikeTimerState::Timer2 => (
-                Self::T2_STRIKE_ZONE,
-                Self::T2_STRIKES_ACCEPTED,
-                Self::T2_BASIC_DMG_MULT,
-                Self::T2_COMBO_DMG_MULT,
-            ),
-            StrikeTimerState::Timer3 => (
-                Self::T3_STRIKE_ZONE,
-                Self::T3_STRIKES_ACCEPTED,
-                Self::T3_BASIC_DMG_MULT,
-                Self::T3_COMBO_DMG_MULT,
-            ),
-        };
-
-        if self.timer > 0.0 {
-            let elapsed_time = Self::STRIKE_TIMER_DURATION - self.timer;
-            if elapsed_time <= zone_end {
-                // In Strike Zone
-                self.is_in_rest_period = false;
-                self.strike_count += 1;
-
-                if self.strike_count > accepted {
-                    // Exceeded strike count, RESET
-                    self.reset_to_timer1_and_strike();
-                    return Some(StrikeResult {
-                        damage_multiplier: Self::T1_BASIC_DMG_MULT,
-                        knockback: false,
-                        knockback_force: 1000.0,
-                        apply_stun: false,
-                        is_combo_finisher: false,
-                        finisher_slash_count: 1,
-                        finisher_hit_count: 0,
-                    });
-                }
+        let chain = Self::chain(fighter_type);
+        if self.tier >= chain.tiers.len() {
+            // Switched to a fighter with a shorter chain mid-combo
+            self.reset_to_first_tier_and_strike(chain);
+            return Some(StrikeResult::basic(chain.tier(0).basic_damage_multiplier, chain.basic_slash_count));
+        }
+        let tier = &chain.tiers[self.tier];
 
-                if self.strike_count == accepted {
-                    // COMBO FINISHER!
-                    self.is_in_rest_period = true; // Now we wait for the timer to expire naturally
-                    self.last_combo_strike_timer = Self::COMBO_VISUAL_DURATION;
-
-                    let (slash_count, knockback, knockback_force, apply_stun) = match self.state {
-                        StrikeTimerState::Timer1 => (1, true, 250.0, false), // 2-hit: knockback, no stun
-                        StrikeTimerState::Timer2 => {
-                            // 3-hit finisher: Always knockback, but only stun if allowed.
-                            let can_stun = !self.is_combo3_stun_disabled;
-                            (2, true, 1000.0, can_stun)
-                        }
-                        StrikeTimerState::Timer3 => {
-                            // 5-hit finisher
-                            self.is_combo3_stun_disabled = false;
-                            // Always knockback and stun.
-                            //(3, true, true)
-                            // Always knockback with enhanced force, but no stun.
-                            (3, true, 1000.0 * Self::ENHANCED_KNOCKBACK_MULTIPLIER, false)
-                        }
-                    };
-
-                    return Some(StrikeResult {
-                        damage_multiplier: combo_mult,
-                        knockback,
-                        knockback_force,
-                        apply_stun,
-                        is_combo_finisher: true,
-                        finisher_slash_count: slash_count,
-                        finisher_hit_count: accepted,
-                    });
-                } else {
-                    // Basic strike within a combo sequence
-                    return Some(StrikeResult {
-                        damage_multiplier: basic_mult,
-                        knockback: false,
-                        knockback_force: 1000.0,
-                        apply_stun: false,
-                        is_combo_finisher: false,
-                        finisher_slash_count: 1,
-                        finisher_hit_count: 0,
-                    });
-                }
-            } else {
-                // In Rest Zone
-                self.reset_to_timer1_and_strike();
-                return Some(StrikeResult {
-                    damage_multiplier: Self::T1_BASIC_DMG_MULT,
-                    knockback: false,
-                    knockback_force: 1000.0,
-                    apply_stun: false,
-                    is_combo_finisher: false,
-                    finisher_slash_count: 1,
-                    finisher_hit_count: 0,
-                });
-            }
-        } else {
+        if self.timer <= 0.0 {
             // Idle, this is the first strike
-            self.timer = Self::STRIKE_TIMER_DURATION;
+            self.window = tier.window;
+            self.timer = tier.window;
             self.strike_count = 1;
             self.is_in_rest_period = false;
-            return Some(StrikeResult {
-                damage_multiplier: basic_mult,
-                knockback: false,
-                knockback_force: 1000.0,
-                apply_stun: false,
-                is_combo_finisher: false,
-                finisher_slash_count: 1,
-                finisher_hit_count: 0,
-            });
+            return Some(StrikeResult::basic(tier.basic_damage_multiplier, chain.basic_slash_count));
         }
-    }
 
-    // Fighter-specific strike handling that respects their combo limitations
-    pub fn handle_strike_for_fighter(
-        &mut self,
-        fighter_type: crate::game_state::FighterType,
-    ) -> Option<StrikeResult> {
-        use crate::game_state::FighterType;
-
-        // Check if this fighter type can access the current timer state
-        let can_use_current_state = match (fighter_type, self.state) {
-            (FighterType::Raptor, StrikeTimerState::Timer2) => false, // raptor can't use 3-hit
-            (FighterType::Raptor, StrikeTimerState::Timer3) => false, // raptor can't use 5-hit
-            (FighterType::Soldier, StrikeTimerState::Timer3) => false, // Soldier can't use 5-hit
-            _ => true, // All other combinations are allowed
-        };
-
-        if !can_use_current_state {
-            // Force reset to Timer1 if trying to access forbidden combo
-            self.reset_to_timer1_and_strike();
-            return Some(StrikeResult {
-                damage_multiplier: Self::T1_BASIC_DMG_MULT,
-                knockback: false,
-                knockback_force: 1000.0,
-                apply_stun: false,
-                is_combo_finisher: false,
-                finisher_slash_count: if fighter_type == FighterType::Raptor {
-                    3
-                } else {
-                    1
-                },
-                finisher_hit_count: 0,
-            });
+        let elapsed_time = self.window - self.timer;
+        if elapsed_time > tier.strike_zone {
+            // In Rest Zone
+            self.reset_to_first_tier_and_strike(chain);
+            return Some(StrikeResult::basic(chain.tier(0).basic_damage_multiplier, chain.basic_slash_count));
         }
 
-        // Call regular handle_strike but modify result for raptor
-        if let Some(mut result) = self.handle_strike() {
-            if fighter_type == FighterType::Raptor {
-                // raptor always gets triple slash visual for basic and 2-hit combo strikes
-                if !result.is_combo_finisher || result.finisher_hit_count == 2 {
-                    result.finisher_slash_count = 3;
-                }
-            }
-            Some(result)
-        } else {
-            None
+        // In Strike Zone
+        self.is_in_rest_period = false;
+        self.strike_count += 1;
+
+        if self.strike_count > tier.strikes {
+            // Exceeded strike count, RESET
+            self.reset_to_first_tier_and_strike(chain);
+            return Some(StrikeResult::basic(chain.tier(0).basic_damage_multiplier, chain.basic_slash_count));
+        }
+
+        if self.strike_count < tier.strikes {
+            // Basic strike within a combo sequence
+            return Some(StrikeResult::basic(tier.basic_damage_multiplier, chain.basic_slash_count));
         }
+
+        // COMBO FINISHER!
+        self.is_in_rest_period = true; // Now we wait for the timer to expire naturally
+        self.last_combo_strike_timer = Self::COMBO_VISUAL_DURATION;
+        let finisher = &tier.finisher;
+        let stun_duration = if self.is_finisher_stun_disabled { 0.0 } else { finisher.stun_duration };
+        if self.tier + 1 == chain.tiers.len() {
+            self.is_finisher_stun_disabled = false;
+        }
+        Some(StrikeResult {
+            damage_multiplier: finisher.damage_multiplier,
+            knockback: finisher.knockback_force > 0.0,
+            knockback_force: finisher.knockback_force,
+            stun_duration,
+            grants_atomic_state: finisher.atomic_state,
+            is_combo_finisher: true,
+            finisher_slash_count: finisher.slash_count,
+        })
     }
 
-    fn reset_to_timer1(&mut self) {
-        self.state = StrikeTimerState::Timer1;
+    fn reset_to_first_tier(&mut self) {
+        self.tier = 0;
         self.timer = 0.0;
         self.strike_count = 0;
         self.is_in_rest_period = false;
     }
 
-    fn reset_to_timer1_and_strike(&mut self) {
-        self.state = StrikeTimerState::Timer1;
-        self.timer = Self::STRIKE_TIMER_DURATION;
+    fn reset_to_first_tier_and_strike(&mut self, chain: &ComboChain) {
+        self.tier = 0;
+        self.window = chain.tier(0).window;
+        self.timer = self.window;
         self.strike_count = 1;
         self.is_in_rest_period = false;
     }
 
+    /// Seconds until a strike would be taken as intended rather than resetting the chain:
+    /// the melee cooldown, or the rest period after a finisher.
+    pub fn strike_wait(&self) -> f64 {
+        if self.melee_cooldown > 0.0 {
+            self.melee_cooldown
+        } else if self.is_in_rest_period {
+            self.timer
+        } else {
+            0.0
+        }
+    }
+
+    /// Index of the current tier in the fighter's chain.
+    pub fn tier_index(&self) -> usize {
+        self.tier
+    }
+
+    /// Length of the running strike window; only meaningful while `timer` > 0.
+    pub fn window(&self) -> f64 {
+        self.window
+    }
+
+    pub fn strike_count(&self) -> u32 {
+        self.strike_count
+    }
+
     pub fn is_combo_strike_active(&self) -> bool {
         self.last_combo_strike_timer > 0.0
     }
 
-    pub fn get_damage_intake_multiplier(&self) -> f64 {
+    pub fn get_damage_intake_multiplier(&self, fighter_type: FighterType) -> f64 {
         // Damage reduction is only active if a combo is in progress (timer is running)
         // and we are not in a rest period waiting for the timer to expire.
         if self.timer > 0.0 && !self.is_in_rest_period {
-            match self.state {
-                StrikeTimerState::Timer1 => 1.0 - Self::T1_DAMAGE_REDUCTION, // 0.75
-                StrikeTimerState::Timer2 => 1.0 - Self::T2_DAMAGE_REDUCTION, // 0.50
-                StrikeTimerState::Timer3 => 1.0 - Self::T3_DAMAGE_REDUCTION, // 0.25
-            }
+            1.0 - Self::chain(fighter_type).tier(self.tier).damage_reduction
         } else {
             // No reduction if no combo is active
             1.0
         }
     }
 
-    // Fighter-specific state progression
-    pub fn progress_state_for_fighter(&mut self, fighter_type: crate::game_state::FighterType) {
-        use crate::game_state::FighterType;
+    // Moves to the next tier of the fighter's chain once a rest period has completed,
+    // or back to the first tier (after a cooldown) at the end of the chain.
+    fn advance_tier(&mut self, fighter_type: FighterType) {
+        let chain = Self::chain(fighter_type);
+        self.is_in_rest_period = false;
+        self.strike_count = 0;
+        if self.tier + 1 < chain.tiers.len() {
+            self.tier += 1;
+        } else {
+            self.tier = 0;
+            self.melee_cooldown = chain.cooldown;
+        }
+    }
+}
 
-        self.state = match (self.state, fighter_type) {
-            (StrikeTimerState::Timer1, FighterType::Raptor) => {
-                // raptor can only do 2-hit combos, so reset after Timer1
-                self.melee_cooldown = Self::POST_COMBO_3_COOLDOWN;
-                StrikeTimerState::Timer1
-            }
-            (StrikeTimerState::Timer1, _) => StrikeTimerState::Timer2,
-            (StrikeTimerState::Timer2, FighterType::Soldier) => {
-                // Soldier can't do 5-hit combos, so reset after Timer2
-                self.melee_cooldown = Self::POST_COMBO_3_COOLDOWN;
-                StrikeTimerState::Timer1
-            }
-            (StrikeTimerState::Timer2, _) => StrikeTimerState::Timer3,
-            (StrikeTimerState::Timer3, _) => {
-                self.melee_cooldown = Self::POST_COMBO_3_COOLDOWN;
-                StrikeTimerState::Timer1
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Lands every strike of the current tier inside its strike zone (and its window).
+    fn land_tier(combo: &mut ComboSystem, fighter_type: FighterType) -> StrikeResult {
+        let tier = ComboSystem::chain(fighter_type).tier(combo.tier_index()).clone();
+        let spacing = tier.strike_zone.min(tier.window) / (tier.strikes as f64 + 1.0);
+        let mut result = None;
+        for _ in 0..tier.strikes {
+            result = combo.handle_strike_for_fighter(fighter_type);
+            combo.update(spacing, fighter_type);
+        }
+        result.expect("strikes inside the zone are accepted")
+    }
+
+    #[test]
+    fn resting_out_each_finisher_walks_the_chain() {
+        for fighter_type in [FighterType::Racer, FighterType::Soldier, FighterType::Raptor] {
+            let chain = ComboSystem::chain(fighter_type);
+            let mut combo = ComboSystem::new();
+            for (index, tier) in chain.tiers.iter().enumerate() {
+                assert_eq!(combo.tier_index(), index);
+                let finisher = land_tier(&mut combo, fighter_type);
+                assert!(finisher.is_combo_finisher);
+                assert_eq!(finisher.damage_multiplier, tier.finisher.damage_multiplier);
+                assert!(combo.is_in_rest_period);
+                combo.update(tier.window, fighter_type);
             }
-        };
+            // The end of the chain loops back behind the chain's cooldown.
+            assert_eq!(combo.tier_index(), 0);
+            assert!(combo.handle_strike_for_fighter(fighter_type).is_none());
+            combo.update(chain.cooldown + 0.01, fighter_type);
+            assert!(combo.handle_strike_for_fighter(fighter_type).is_some());
+        }
+    }
+
+    #[test]
+    fn letting_the_window_lapse_mid_tier_resets_the_chain() {
+        let fighter_type = FighterType::Racer;
+        let mut combo = ComboSystem::new();
+        land_tier(&mut combo, fighter_type);
+        combo.update(ComboSystem::chain(fighter_type).tier(0).window, fighter_type);
+        assert_eq!(combo.tier_index(), 1);
+
+        combo.handle_strike_for_fighter(fighter_type);
+        combo.update(ComboSystem::chain(fighter_type).tier(1).window, fighter_type);
+        assert_eq!(combo.tier_index(), 0);
+        assert_eq!(combo.strike_count(), 0);
     }
 }
@@ -0,0 +1,348 @@
+// File: src/combat/combo_data.rs
+
+use crate::game_state::FighterType;
+use crate::config::{parse_bool, parse_ranged, read_ini, ConfigError, IniLine};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Name of the combo definition file looked up next to the executable.
+pub const COMBOS_FILE_NAME: &str = "combos.cfg";
+
+/// What a tier's last strike does to the target it point-hits.
+#[derive(Debug, Clone)]
+pub struct ComboFinisher {
+    pub damage_multiplier: f64,
+    pub knockback_force: f64, // 0 = no knockback
+    pub stun_duration: f64,   // seconds, 0 = no stun
+    pub slash_count: u32,     // slashes drawn for the finisher
+    pub atomic_state: bool,   // grants ATOMIC-STATE (see combat::melee)
+}
+
+/// One step of a combo chain: `strikes` strikes inside the strike zone, then a rest until
+/// the window runs out opens the next tier.
+#[derive(Debug, Clone)]
+pub struct ComboTier {
+    pub window: f64,      // length of the strike timer
+    pub strike_zone: f64, // strikes must land within this long after the timer starts
+    pub strikes: u32,     // the last accepted strike is the finisher
+    pub basic_damage_multiplier: f64,
+    pub damage_reduction: f64, // damage intake reduction while the tier is in progress
+    pub finisher: ComboFinisher,
+}
+
+/// A fighter type's full combo chain.
+#[derive(Debug, Clone)]
+pub struct ComboChain {
+    pub tiers: Vec<ComboTier>,
+    pub basic_slash_count: u32, // slashes drawn for strikes that aren't finishers
+    pub cooldown: f64,          // melee lockout after resting out the last tier
+}
+
+impl ComboChain {
+    /// The tier at `index`, or the first one past the end of the chain.
+    pub fn tier(&self, index: usize) -> &ComboTier {
+        self.tiers.get(index).unwrap_or(&self.tiers[0])
+    }
+}
+
+/// Combo chains for every fighter type.
+#[derive(Debug, Clone)]
+pub struct ComboDefinitions {
+    pub chains: HashMap<FighterType, ComboChain>,
+}
+
+// The 2-hit, 3-hit and 5-hit tiers every chain is built from.
+fn two_hit(slash_count: u32) -> ComboTier {
+    ComboTier {
+        window: 0.65,
+        strike_zone: 0.20,
+        strikes: 2,
+        basic_damage_multiplier: 1.0,
+        damage_reduction: 0.50,
+        finisher: ComboFinisher {
+            damage_multiplier: 1.25,
+            knockback_force: 250.0,
+            stun_duration: 0.0,
+            slash_count,
+            atomic_state: false,
+        },
+    }
+}
+
+fn three_hit() -> ComboTier {
+    ComboTier {
+        window: 0.65,
+        strike_zone: 0.40,
+        strikes: 3,
+        basic_damage_multiplier: 1.25,
+        damage_reduction: 0.75,
+        finisher: ComboFinisher {
+            damage_multiplier: 1.75,
+            knockback_force: 1000.0,
+            stun_duration: 1.0,
+            slash_count: 2,
+            atomic_state: false,
+        },
+    }
+}
+
+fn five_hit(atomic_state: bool) -> ComboTier {
+    ComboTier {
+        window: 0.65,
+        strike_zone: 1.25,
+        strikes: 5,
+        basic_damage_multiplier: 2.0,
+        damage_reduction: 0.95,
+        finisher: ComboFinisher {
+            damage_multiplier: 2.50,
+            knockback_force: 2250.0, // enhanced: 225% of the 3-hit knockback
+            stun_duration: 0.0,
+            slash_count: 3,
+            atomic_state,
+        },
+    }
+}
+
+impl Default for ComboDefinitions {
+    fn default() -> Self {
+        let chains = HashMap::from([
+            (
+                FighterType::Racer,
+                ComboChain {
+                    tiers: vec![two_hit(1), three_hit(), five_hit(true)],
+                    basic_slash_count: 1,
+                    cooldown: 0.25,
+                },
+            ),
+            (
+                FighterType::Soldier,
+                ComboChain {
+                    tiers: vec![two_hit(1), three_hit()],
+                    basic_slash_count: 1,
+                    cooldown: 0.25,
+                },
+            ),
+            (
+                FighterType::Raptor,
+                // Raptor only has the 2-hit combo, but every strike is a triple slash.
+                ComboChain {
+                    tiers: vec![two_hit(3)],
+                    basic_slash_count: 3,
+                    cooldown: 0.25,
+                },
+            ),
+        ]);
+        ComboDefinitions { chains }
+    }
+}
+
+impl ComboDefinitions {
+    pub fn chain(&self, fighter_type: FighterType) -> &ComboChain {
+        &self.chains[&fighter_type]
+    }
+
+    /// Applies a combo file. `[racer]` sections hold chain settings and `[racer.tier1]`,
+    /// `[racer.tier2]`, ... change tiers; naming the tier after the last one adds a tier,
+    /// starting as a copy of the last. `tiers = N` in the chain section shortens the chain.
+    pub fn apply_file(&mut self, path: &Path, errors: &mut Vec<ConfigError>) {
+        let mut tier_limits: Vec<(FighterType, usize)> = Vec::new();
+        // Current section: fighter and tier index (None for the chain section).
+        let mut section: Option<(FighterType, Option<usize>)> = None;
+        read_ini(path, errors, |line| match line {
+            IniLine::Section(name) => {
+                section = None;
+                let (fighter_type, tier) = parse_section(name)?;
+                if let Some(tier) = tier {
+                    let chain = self.chains.get_mut(&fighter_type).expect("every fighter type has a chain");
+                    if tier == chain.tiers.len() {
+                        let last = chain.tiers[tier - 1].clone();
+                        chain.tiers.push(last);
+                    } else if tier > chain.tiers.len() {
+                        return Err(format!("skips a tier; the chain has {} tier(s)", chain.tiers.len()));
+                    }
+                }
+                section = Some((fighter_type, tier));
+                Ok(())
+            }
+            IniLine::Entry { key, value, .. } => match section {
+                Some((fighter_type, None)) if key == "tiers" => {
+                    parse_ranged(value, 1.0, 20.0).map(|n| tier_limits.push((fighter_type, n as usize)))
+                }
+                Some((fighter_type, tier)) => {
+                    let chain = self.chains.get_mut(&fighter_type).expect("every fighter type has a chain");
+                    match tier {
+                        None => set_chain_value(chain, key, value),
+                        Some(tier) => set_tier_value(&mut chain.tiers[tier], key, value),
+                    }
+                }
+                None => Err("is outside a [fighter] or [fighter.tierN] section".to_string()),
+            },
+        });
+        for (fighter_type, limit) in tier_limits {
+            if let Some(chain) = self.chains.get_mut(&fighter_type) {
+                chain.tiers.truncate(limit);
+            }
+        }
+    }
+}
+
+/// `racer` -> (Racer, None), `racer.tier2` -> (Racer, Some(1)).
+fn parse_section(name: &str) -> Result<(FighterType, Option<usize>), String> {
+    let (fighter, tier) = match name.split_once('.') {
+        Some((fighter, tier)) => (fighter, Some(tier)),
+        None => (name, None),
+    };
+    let fighter_type = FighterType::from_config_name(fighter)?;
+    let tier = match tier {
+        None => None,
+        Some(tier) => match tier.strip_prefix("tier").and_then(|n| n.parse::<usize>().ok()) {
+            Some(n) if n >= 1 => Some(n - 1),
+            _ => return Err("expects a tier section named tier1, tier2, ...".to_string()),
+        },
+    };
+    Ok((fighter_type, tier))
+}
+
+fn set_chain_value(chain: &mut ComboChain, key: &str, value: &str) -> Result<(), String> {
+    match key {
+        "basic_slash_count" => chain.basic_slash_count = parse_ranged(value, 1.0, 5.0)? as u32,
+        "cooldown" => chain.cooldown = parse_ranged(value, 0.0, 5.0)?,
+        _ => return Err("is not a known chain setting".to_string()),
+    }
+    Ok(())
+}
+
+fn set_tier_value(tier: &mut ComboTier, key: &str, value: &str) -> Result<(), String> {
+    match key {
+        "window" => tier.window = parse_ranged(value, 0.05, 5.0)?,
+        "strike_zone" => tier.strike_zone = parse_ranged(value, 0.0, 5.0)?,
+        "strikes" => tier.strikes = parse_ranged(value, 1.0, 20.0)? as u32,
+        "basic_damage" => tier.basic_damage_multiplier = parse_ranged(value, 0.0, 10.0)?,
+        "damage_reduction" => tier.damage_reduction = parse_ranged(value, 0.0, 1.0)?,
+        "finisher_damage" => tier.finisher.damage_multiplier = parse_ranged(value, 0.0, 10.0)?,
+        "knockback" => tier.finisher.knockback_force = parse_ranged(value, 0.0, 10000.0)?,
+        "stun" => tier.finisher.stun_duration = parse_ranged(value, 0.0, 10.0)?,
+        "slash_count" => tier.finisher.slash_count = parse_ranged(value, 1.0, 5.0)? as u32,
+        "atomic_state" => tier.finisher.atomic_state = parse_bool(value)?,
+        _ => return Err("is not a known tier setting".to_string()),
+    }
+    Ok(())
+}
+
+static COMBO_DEFINITIONS: OnceLock<ComboDefinitions> = OnceLock::new();
+
+/// Loads combos.cfg from `dir` on top of the built-in chains, if the file exists.
+pub fn load(dir: &Path) -> Result<ComboDefinitions, Vec<ConfigError>> {
+    let mut definitions = ComboDefinitions::default();
+    let mut errors = Vec::new();
+    let path = dir.join(COMBOS_FILE_NAME);
+    if path.exists() {
+        definitions.apply_file(&path, &mut errors);
+        println!("[COMBO] Loaded combo definitions from {:?}", path);
+    }
+    if errors.is_empty() {
+        Ok(definitions)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Makes `definitions` the active combo chains. Only the first call has an effect.
+pub fn install(definitions: ComboDefinitions) {
+    if COMBO_DEFINITIONS.set(definitions).is_err() {
+        println!("[COMBO] Combo definitions already installed; ignoring reinstall.");
+    }
+}
+
+/// The active combo chains, or the built-in ones if none were installed.
+pub fn get() -> &'static ComboDefinitions {
+    COMBO_DEFINITIONS.get_or_init(ComboDefinitions::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::load_test_file;
+
+    fn load_combos(test_name: &str, contents: &str) -> Result<ComboDefinitions, Vec<ConfigError>> {
+        load_test_file(test_name, COMBOS_FILE_NAME, contents, load)
+    }
+
+    fn messages(errors: &[ConfigError]) -> Vec<(&str, &str)> {
+        errors.iter().map(|e| (e.key.as_str(), e.message.as_str())).collect()
+    }
+
+    #[test]
+    fn missing_file_keeps_the_built_in_chains() {
+        let dir = std::env::temp_dir().join(format!("sbrx_test_{}_combo_missing", std::process::id()));
+        let definitions = load(&dir).expect("no file is not an error");
+        assert_eq!(definitions.chain(FighterType::Racer).tiers.len(), 3);
+        assert_eq!(definitions.chain(FighterType::Raptor).basic_slash_count, 3);
+    }
+
+    #[test]
+    fn applies_chain_and_tier_settings() {
+        let definitions = load_combos(
+            "combo_apply",
+            "[racer]\ncooldown = 0.5\n[racer.tier1]\nstrikes = 3\nknockback = 0\n[soldier]\ntiers = 1\n",
+        )
+        .expect("combo file should load");
+        let racer = definitions.chain(FighterType::Racer);
+        assert_eq!(racer.cooldown, 0.5);
+        assert_eq!(racer.tiers[0].strikes, 3);
+        assert_eq!(racer.tiers[0].finisher.knockback_force, 0.0);
+        // Other tiers are untouched.
+        assert_eq!(racer.tiers[1].strikes, 3);
+        assert_eq!(racer.tiers[2].strikes, 5);
+        assert_eq!(definitions.chain(FighterType::Soldier).tiers.len(), 1);
+    }
+
+    #[test]
+    fn next_tier_is_added_as_a_copy_of_the_last() {
+        let definitions = load_combos("combo_add", "[raptor.tier2]\nstrikes = 4\n").expect("combo file should load");
+        let raptor = definitions.chain(FighterType::Raptor);
+        assert_eq!(raptor.tiers.len(), 2);
+        assert_eq!(raptor.tiers[1].strikes, 4);
+        assert_eq!(raptor.tiers[1].finisher.slash_count, raptor.tiers[0].finisher.slash_count);
+    }
+
+    #[test]
+    fn skipping_a_tier_is_refused() {
+        let errors = load_combos("combo_skip", "[raptor.tier3]\nstrikes = 4\n").expect_err("tier3 skips tier2");
+        assert_eq!(
+            messages(&errors),
+            vec![
+                ("raptor.tier3", "skips a tier; the chain has 1 tier(s)"),
+                ("raptor.tier3.strikes", "is outside a [fighter] or [fighter.tierN] section"),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_bad_section_names() {
+        let errors = load_combos("combo_sections", "[ninja]\n[racer.second]\n[racer.tier0]\n").expect_err("bad sections");
+        let keys: Vec<&str> = errors.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, vec!["ninja", "racer.second", "racer.tier0"]);
+        assert_eq!(errors[1].message, "expects a tier section named tier1, tier2, ...");
+    }
+
+    #[test]
+    fn keeps_chain_and_tier_keys_apart() {
+        let errors = load_combos(
+            "combo_keys",
+            "[racer]\nstrikes = 2\ntiers = 0\n[racer.tier1]\ncooldown = 1\nwindow = 9\natomic_state = maybe\n",
+        )
+        .expect_err("misplaced keys");
+        assert_eq!(
+            messages(&errors),
+            vec![
+                ("racer.strikes", "is not a known chain setting"),
+                ("racer.tiers", "must be between 1 and 20, found 0"),
+                ("racer.tier1.cooldown", "is not a known tier setting"),
+                ("racer.tier1.window", "must be between 0.05 and 5, found 9"),
+                ("racer.tier1.atomic_state", "expects true or false, found 'maybe'"),
+            ]
+        );
+    }
+}
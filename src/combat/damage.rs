@@ -0,0 +1,510 @@
+// File: src/combat/damage.rs
+
+use crate::combat::stats::AttackStats;
+use crate::entities::cpu_entity::{CpuEntity, CpuVariant};
+use crate::entities::cpu_racer::CpuRacer;
+use crate::entities::fighter::Fighter;
+use crate::game_state::FighterType;
+use crate::utils::rng;
+use crate::DamageText;
+use rand::Rng;
+
+// CPU entities have no attack stats; their hits all crit and vary alike.
+pub const CPU_CRIT_CHANCE: f64 = 0.05;
+pub const CPU_CRIT_MULTIPLIER: f64 = 1.5;
+pub const CPU_DAMAGE_VARIANCE: f64 = 0.1;
+
+/// What a hit is made of. Resistances scale damage per type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageType {
+    Slash,     // melee strikes, rushes, CPU claws and blades
+    Ballistic, // ranged shots
+    Kinetic,   // kinetic strike and kinetic rush
+    Void,      // void-touched CPUs and their skills
+}
+
+impl DamageType {
+    /// Damage text color at neutral resistance.
+    pub fn color(self) -> [f32; 4] {
+        match self {
+            DamageType::Slash => [1.0, 1.0, 0.0, 1.0],     // Yellow
+            DamageType::Ballistic => [1.0, 1.0, 1.0, 1.0], // White
+            DamageType::Kinetic => [0.0, 1.0, 0.0, 1.0],   // Green
+            DamageType::Void => [0.7, 0.3, 1.0, 1.0],      // Purple
+        }
+    }
+}
+
+/// Damage multipliers per type: below 1.0 resists, above 1.0 is a weakness.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Resistances {
+    pub slash: f64,
+    pub ballistic: f64,
+    pub kinetic: f64,
+    pub void: f64,
+}
+
+impl Resistances {
+    pub const NEUTRAL: Resistances = Resistances { slash: 1.0, ballistic: 1.0, kinetic: 1.0, void: 1.0 };
+
+    pub fn multiplier(&self, damage_type: DamageType) -> f64 {
+        match damage_type {
+            DamageType::Slash => self.slash,
+            DamageType::Ballistic => self.ballistic,
+            DamageType::Kinetic => self.kinetic,
+            DamageType::Void => self.void,
+        }
+    }
+}
+
+const fn res(slash: f64, ballistic: f64, kinetic: f64, void: f64) -> Resistances {
+    Resistances { slash, ballistic, kinetic, void }
+}
+
+pub fn cpu_resistances(variant: CpuVariant) -> Resistances {
+    match variant {
+        CpuVariant::GiantMantis => Resistances::NEUTRAL,
+        CpuVariant::BloodIdol => res(0.75, 1.25, 1.0, 0.5),
+        CpuVariant::Rattlesnake => res(1.25, 1.0, 1.0, 1.0),
+        CpuVariant::GiantRattlesnake => res(1.0, 0.75, 1.25, 1.0),
+        CpuVariant::Raptor => res(1.0, 1.0, 1.5, 1.0),
+        CpuVariant::TRex => res(0.75, 0.75, 1.25, 1.0),
+        CpuVariant::VoidTempest => res(1.0, 0.5, 1.0, 0.25),
+        CpuVariant::LightReaver => res(1.0, 1.0, 0.75, 1.5),
+        CpuVariant::NightReaver => res(1.0, 0.75, 1.0, 0.5),
+        CpuVariant::RazorFiend => res(0.75, 1.25, 1.0, 1.0),
+    }
+}
+
+/// Player fighters; CpuRacer uses the Racer row.
+pub fn fighter_resistances(fighter_type: FighterType) -> Resistances {
+    match fighter_type {
+        FighterType::Racer => Resistances::NEUTRAL,
+        FighterType::Soldier => res(1.0, 0.75, 1.0, 1.25),
+        FighterType::Raptor => res(0.9, 1.0, 1.25, 1.0),
+    }
+}
+
+/// Auto-dodge chance, auto-block chance and armor of each CPU type.
+pub fn cpu_avoidance(variant: CpuVariant) -> (f64, f64, f64) {
+    match variant {
+        CpuVariant::GiantMantis => (0.02, 0.02, 0.0),
+        CpuVariant::BloodIdol => (0.0, 0.05, 0.1),
+        CpuVariant::Rattlesnake => (0.05, 0.0, 0.0),
+        CpuVariant::GiantRattlesnake => (0.03, 0.0, 0.1),
+        CpuVariant::Raptor => (0.08, 0.0, 0.0),
+        CpuVariant::TRex => (0.0, 0.03, 0.2),
+        CpuVariant::VoidTempest => (0.05, 0.0, 0.0),
+        CpuVariant::LightReaver => (0.06, 0.02, 0.0),
+        CpuVariant::NightReaver => (0.06, 0.02, 0.0),
+        CpuVariant::RazorFiend => (0.05, 0.05, 0.05),
+    }
+}
+
+/// The type of a CPU's melee attack and skills.
+pub fn cpu_attack_type(variant: CpuVariant) -> DamageType {
+    match variant {
+        CpuVariant::BloodIdol | CpuVariant::VoidTempest | CpuVariant::NightReaver => DamageType::Void,
+        _ => DamageType::Slash,
+    }
+}
+
+/// Damage text color: the type's color, greyed out when resisted and washed toward white
+/// when it hits a weakness.
+pub fn text_color(damage_type: DamageType, multiplier: f64) -> [f32; 4] {
+    let [r, g, b, a] = damage_type.color();
+    let (target, amount) = if multiplier < 1.0 {
+        (0.45, 0.6)
+    } else if multiplier > 1.0 {
+        (1.0, 0.45)
+    } else {
+        return [r, g, b, a];
+    };
+    let mix = |c: f32| c + (target - c) * amount;
+    [mix(r), mix(g), mix(b), a]
+}
+
+/// Damage text for a hit: the amount, with "!" on a weakness.
+pub fn text(amount: f64, multiplier: f64) -> String {
+    if multiplier > 1.0 {
+        format!("{:.0}!", amount)
+    } else {
+        format!("{:.0}", amount)
+    }
+}
+
+/// A hit going into resolve(): base damage plus the attacker's crit and variance.
+#[derive(Debug, Clone, Copy)]
+pub struct Attack {
+    pub amount: f64,
+    pub damage_type: DamageType,
+    pub crit_chance: f64,
+    pub crit_multiplier: f64,
+    pub variance: f64,
+    pub avoidable: bool, // whether auto-dodge and auto-block can stop it
+    pub threat: f64,     // threat per point of landed damage, for hits on CPUs
+}
+
+impl Attack {
+    /// A hit from a fighter or CPU racer.
+    pub fn with_stats(amount: f64, damage_type: DamageType, stats: &AttackStats) -> Self {
+        Attack {
+            amount,
+            damage_type,
+            crit_chance: stats.crit_chance,
+            crit_multiplier: stats.crit_multiplier,
+            variance: stats.variance,
+            avoidable: true,
+            threat: stats.threat,
+        }
+    }
+
+    /// A hit from a CPU entity.
+    pub fn cpu(amount: f64, damage_type: DamageType) -> Self {
+        Attack {
+            amount,
+            damage_type,
+            crit_chance: CPU_CRIT_CHANCE,
+            crit_multiplier: CPU_CRIT_MULTIPLIER,
+            variance: CPU_DAMAGE_VARIANCE,
+            avoidable: true,
+            threat: 0.0,
+        }
+    }
+
+    /// Damage over time such as bleed: never avoided, never crits or varies.
+    pub fn tick(amount: f64, damage_type: DamageType) -> Self {
+        Attack {
+            amount,
+            damage_type,
+            crit_chance: 0.0,
+            crit_multiplier: 1.0,
+            variance: 0.0,
+            avoidable: false,
+            threat: 0.0,
+        }
+    }
+}
+
+/// The defender's side of resolve().
+#[derive(Debug, Clone, Copy)]
+pub struct Defense {
+    pub dodge: f64,
+    pub block: f64,
+    pub armor: f64,
+    pub resistances: Resistances,
+    pub taken: f64, // other damage-taken multipliers (status effects, block state, combos)
+}
+
+impl Defense {
+    pub fn fighter(fighter: &Fighter) -> Self {
+        let stats = fighter.stats.defense;
+        Defense {
+            dodge: stats.auto_dodge,
+            block: stats.auto_block,
+            armor: stats.armor,
+            resistances: fighter_resistances(fighter.fighter_type),
+            taken: fighter.status_effects.damage_taken_multiplier(),
+        }
+    }
+
+    pub fn cpu_racer(cr: &CpuRacer) -> Self {
+        let stats = cr.stats.defense;
+        Defense {
+            dodge: stats.auto_dodge,
+            block: stats.auto_block,
+            armor: stats.armor,
+            resistances: fighter_resistances(FighterType::Racer),
+            taken: cr.status_effects.damage_taken_multiplier(),
+        }
+    }
+
+    /// Training dummies never dodge or block so practice damage reads true.
+    pub fn cpu(cpu: &CpuEntity) -> Self {
+        let (dodge, block, armor) = if cpu.is_training_dummy { (0.0, 0.0, 0.0) } else { cpu_avoidance(cpu.variant) };
+        Defense {
+            dodge,
+            block,
+            armor,
+            resistances: cpu_resistances(cpu.variant),
+            taken: cpu.status_effects.damage_taken_multiplier(),
+        }
+    }
+
+    /// Scales landed damage by `multiplier` on top of everything else.
+    pub fn taking(mut self, multiplier: f64) -> Self {
+        self.taken *= multiplier;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Dodged,
+    Blocked,
+    Hit,
+    Crit,
+}
+
+/// How a damage text is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextStyle {
+    Normal,
+    Crit,    // large, outlined
+    Dodged,  // small, drifts sideways
+    Blocked, // small, holds still
+}
+
+impl TextStyle {
+    pub fn font_size(self) -> u32 {
+        match self {
+            TextStyle::Normal => 16,
+            TextStyle::Crit => 24,
+            TextStyle::Dodged | TextStyle::Blocked => 14,
+        }
+    }
+}
+
+/// What resolve() decided. `amount` is the final damage to subtract from HP and
+/// `threat` what a landed hit adds to a CPU's threat table.
+#[derive(Debug, Clone, Copy)]
+pub struct Hit {
+    pub outcome: Outcome,
+    pub amount: f64,
+    pub damage_type: DamageType,
+    pub resist: f64,
+    pub threat: f64,
+}
+
+impl Hit {
+    /// A hit stopped by a raised block before resolve() ran.
+    pub fn blocked(damage_type: DamageType) -> Self {
+        Hit { outcome: Outcome::Blocked, amount: 0.0, damage_type, resist: 1.0, threat: 0.0 }
+    }
+
+    pub fn landed(&self) -> bool {
+        matches!(self.outcome, Outcome::Hit | Outcome::Crit)
+    }
+
+    pub fn sound(&self) -> Option<&'static str> {
+        match self.outcome {
+            Outcome::Dodged => Some("boost"),
+            Outcome::Blocked => Some("block"),
+            _ => None,
+        }
+    }
+
+    /// Damage text for the hit at (x, y).
+    pub fn text(&self, x: f64, y: f64) -> DamageText {
+        let (text, color, lifetime, style) = match self.outcome {
+            Outcome::Dodged => ("DODGE".to_string(), [0.0, 0.8, 1.0, 1.0], 0.5, TextStyle::Dodged),
+            Outcome::Blocked => ("BLOCK".to_string(), [1.0, 1.0, 1.0, 1.0], 0.5, TextStyle::Blocked),
+            Outcome::Hit => (
+                text(self.amount, self.resist),
+                text_color(self.damage_type, self.resist),
+                0.25,
+                TextStyle::Normal,
+            ),
+            Outcome::Crit => (
+                format!("CRIT {}", text(self.amount, self.resist)),
+                text_color(self.damage_type, self.resist),
+                0.6,
+                TextStyle::Crit,
+            ),
+        };
+        DamageText { text, x, y, color, lifetime, style }
+    }
+}
+
+/// The damage pipeline shared by every hit on a Fighter, CpuRacer or CpuEntity:
+/// dodge, then block (one roll), then armor (armor stat, type resistance and damage-taken
+/// multipliers), then crit. Variance is rolled on the base damage.
+pub fn resolve(attack: &Attack, defense: &Defense) -> Hit {
+    roll(attack, defense, &mut rng::gameplay_rng())
+}
+
+fn roll(attack: &Attack, defense: &Defense, rng: &mut impl Rng) -> Hit {
+    // A resistance can cancel a hit but never turn it into healing.
+    let resist = defense.resistances.multiplier(attack.damage_type).max(0.0);
+    let mut hit = Hit { outcome: Outcome::Hit, amount: 0.0, damage_type: attack.damage_type, resist, threat: 0.0 };
+
+    if attack.avoidable {
+        let roll: f64 = rng.random();
+        if roll < defense.dodge {
+            hit.outcome = Outcome::Dodged;
+            return hit;
+        } else if roll < defense.dodge + defense.block {
+            hit.outcome = Outcome::Blocked;
+            return hit;
+        }
+    }
+
+    let spread = if attack.variance > 0.0 { rng.random_range(-attack.variance..=attack.variance) } else { 0.0 };
+    let mut amount = attack.amount * (1.0 + spread);
+    amount *= (1.0 - defense.armor.clamp(0.0, 1.0)) * resist * defense.taken;
+    if attack.crit_chance > 0.0 && rng.random::<f64>() < attack.crit_chance {
+        amount *= attack.crit_multiplier;
+        hit.outcome = Outcome::Crit;
+    }
+    hit.amount = amount.max(0.0);
+    hit.threat = hit.amount * attack.threat;
+    hit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn attack(amount: f64) -> Attack {
+        Attack {
+            amount,
+            damage_type: DamageType::Slash,
+            crit_chance: 0.0,
+            crit_multiplier: 2.0,
+            variance: 0.0,
+            avoidable: true,
+            threat: 1.5,
+        }
+    }
+
+    fn defense() -> Defense {
+        Defense { dodge: 0.0, block: 0.0, armor: 0.0, resistances: Resistances::NEUTRAL, taken: 1.0 }
+    }
+
+    fn seeded() -> StdRng {
+        StdRng::seed_from_u64(24)
+    }
+
+    #[test]
+    fn plain_hit_lands_in_full() {
+        let hit = roll(&attack(40.0), &defense(), &mut seeded());
+        assert_eq!(hit.outcome, Outcome::Hit);
+        assert_eq!(hit.amount, 40.0);
+        assert_eq!(hit.threat, 60.0);
+        assert!(hit.landed());
+    }
+
+    #[test]
+    fn dodge_is_rolled_before_block() {
+        let defense = Defense { dodge: 1.0, block: 1.0, ..defense() };
+        let hit = roll(&attack(40.0), &defense, &mut seeded());
+        assert_eq!(hit.outcome, Outcome::Dodged);
+        assert_eq!((hit.amount, hit.threat), (0.0, 0.0));
+    }
+
+    #[test]
+    fn blocked_hit_cannot_crit() {
+        let attack = Attack { crit_chance: 1.0, ..attack(40.0) };
+        let hit = roll(&attack, &Defense { block: 1.0, ..defense() }, &mut seeded());
+        assert_eq!(hit.outcome, Outcome::Blocked);
+        assert_eq!((hit.amount, hit.threat), (0.0, 0.0));
+        assert!(!hit.landed());
+    }
+
+    #[test]
+    fn unavoidable_hits_skip_dodge_and_block() {
+        let attack = Attack { avoidable: false, ..attack(40.0) };
+        let hit = roll(&attack, &Defense { dodge: 1.0, block: 1.0, ..defense() }, &mut seeded());
+        assert_eq!(hit.outcome, Outcome::Hit);
+        assert_eq!(hit.amount, 40.0);
+    }
+
+    #[test]
+    fn dodge_and_block_share_one_roll() {
+        let defense = Defense { dodge: 0.2, block: 0.3, ..defense() };
+        let mut rng = seeded();
+        let mut counts = [0; 3];
+        for _ in 0..10_000 {
+            match roll(&attack(10.0), &defense, &mut rng).outcome {
+                Outcome::Dodged => counts[0] += 1,
+                Outcome::Blocked => counts[1] += 1,
+                _ => counts[2] += 1,
+            }
+        }
+        assert!((1800..2200).contains(&counts[0]), "{:?}", counts);
+        assert!((2800..3200).contains(&counts[1]), "{:?}", counts);
+        assert!((4800..5200).contains(&counts[2]), "{:?}", counts);
+    }
+
+    #[test]
+    fn crit_multiplies_after_armor_and_taken() {
+        let attack = Attack { crit_chance: 1.0, ..attack(40.0) };
+        let defense = Defense { armor: 0.25, taken: 0.5, ..defense() };
+        let hit = roll(&attack, &defense, &mut seeded());
+        assert_eq!(hit.outcome, Outcome::Crit);
+        assert_eq!(hit.amount, 40.0 * 0.75 * 0.5 * 2.0);
+        assert_eq!(hit.threat, hit.amount * 1.5);
+    }
+
+    #[test]
+    fn armor_and_resistances_clamp() {
+        let full_armor = Defense { armor: 1.5, ..defense() };
+        assert_eq!(roll(&attack(40.0), &full_armor, &mut seeded()).amount, 0.0);
+        let negative_armor = Defense { armor: -1.0, ..defense() };
+        assert_eq!(roll(&attack(40.0), &negative_armor, &mut seeded()).amount, 40.0);
+        let negative_resist = Defense { resistances: Resistances { slash: -0.5, ..Resistances::NEUTRAL }, ..defense() };
+        let hit = roll(&attack(40.0), &negative_resist, &mut seeded());
+        assert_eq!((hit.amount, hit.resist), (0.0, 0.0));
+    }
+
+    #[test]
+    fn variance_stays_in_its_spread() {
+        let attack = Attack { variance: 0.1, ..attack(100.0) };
+        let mut rng = seeded();
+        let amounts: Vec<f64> = (0..500).map(|_| roll(&attack, &defense(), &mut rng).amount).collect();
+        assert!(amounts.iter().all(|a| (90.0..=110.0).contains(a)));
+        assert!(amounts.iter().any(|a| *a < 95.0) && amounts.iter().any(|a| *a > 105.0));
+    }
+
+    #[test]
+    fn same_seed_same_rolls() {
+        let attack = Attack { crit_chance: 0.3, variance: 0.2, ..attack(50.0) };
+        let defense = Defense { dodge: 0.1, block: 0.1, ..defense() };
+        let sequence = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..50).map(|_| roll(&attack, &defense, &mut rng)).map(|h| (h.outcome, h.amount)).collect::<Vec<_>>()
+        };
+        assert_eq!(sequence(7), sequence(7));
+        assert_ne!(sequence(7), sequence(8));
+    }
+
+    #[test]
+    fn resistances_scale_each_damage_type() {
+        let resistances = Resistances { slash: 0.5, ballistic: 1.0, kinetic: 1.5, void: 0.0 };
+        let defense = Defense { armor: 0.5, resistances, ..defense() };
+        let mut rng = seeded();
+        let mut hit_with = |damage_type| roll(&Attack { damage_type, ..attack(40.0) }, &defense, &mut rng);
+        let slash = hit_with(DamageType::Slash);
+        assert_eq!((slash.amount, slash.resist), (10.0, 0.5));
+        assert_eq!(hit_with(DamageType::Ballistic).amount, 20.0);
+        let kinetic = hit_with(DamageType::Kinetic);
+        assert_eq!((kinetic.amount, kinetic.resist), (30.0, 1.5));
+        assert_eq!(hit_with(DamageType::Void).amount, 0.0);
+    }
+
+    #[test]
+    fn cpu_and_fighter_tables_feed_resolve() {
+        let idol = Defense { resistances: cpu_resistances(CpuVariant::BloodIdol), ..defense() };
+        let mut rng = seeded();
+        let void = roll(&Attack { damage_type: DamageType::Void, ..attack(40.0) }, &idol, &mut rng);
+        let ballistic = roll(&Attack { damage_type: DamageType::Ballistic, ..attack(40.0) }, &idol, &mut rng);
+        assert_eq!(void.amount, 20.0);
+        assert_eq!(ballistic.amount, 50.0);
+
+        let soldier = Defense { resistances: fighter_resistances(FighterType::Soldier), ..defense() };
+        let shot = roll(&Attack { damage_type: DamageType::Ballistic, ..attack(40.0) }, &soldier, &mut rng);
+        assert_eq!(shot.amount, 30.0);
+        assert_eq!(fighter_resistances(FighterType::Racer), Resistances::NEUTRAL);
+    }
+
+    #[test]
+    fn damage_text_marks_resists_and_weaknesses() {
+        let hit = |resist| Hit { outcome: Outcome::Hit, amount: 12.0, damage_type: DamageType::Kinetic, resist, threat: 0.0 };
+        assert_eq!(hit(1.5).text(0.0, 0.0).text, "12!");
+        assert_eq!(hit(0.5).text(0.0, 0.0).text, "12");
+        assert_eq!(hit(1.0).text(0.0, 0.0).color, DamageType::Kinetic.color());
+        assert_ne!(hit(0.5).text(0.0, 0.0).color, DamageType::Kinetic.color());
+    }
+}
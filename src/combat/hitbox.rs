@@ -0,0 +1,399 @@
+// File: src/combat/hitbox.rs
+
+use crate::entities::cpu_entity::{CpuEntity, CpuVariant};
+use crate::entities::cpu_racer::CpuRacer;
+use crate::entities::fighter::Fighter;
+use crate::game_state::RacerState;
+use piston_window::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Radius of the hitbox at a melee strike point, measured from the hurtbox edge.
+pub fn strike_point_radius() -> f64 {
+    crate::config::get().gameplay.collision_threshold / 2.0
+}
+
+/// Half width of shot and rush lines, measured from the hurtbox edge.
+pub const LINE_HITBOX_RADIUS: f64 = crate::utils::collision::LINE_COLLISION_WIDTH / 2.0;
+
+/// How far any hurtbox reaches from its entity's position. Pads spatial hash queries.
+pub const MAX_HURTBOX_REACH: f64 = 200.0;
+
+/// The area an attack covers.
+#[derive(Debug, Clone, Copy)]
+pub enum Hitbox {
+    /// Kinetic strike and melee strike points.
+    Circle { x: f64, y: f64, radius: f64 },
+    /// Shots and rush lines: every point within `radius` of the segment.
+    Capsule { x1: f64, y1: f64, x2: f64, y2: f64, radius: f64 },
+    /// Melee slashes: a sector around (x, y) facing (dir_x, dir_y).
+    Arc { x: f64, y: f64, radius: f64, dir_x: f64, dir_y: f64, half_angle: f64 },
+}
+
+impl Hitbox {
+    pub fn circle(x: f64, y: f64, radius: f64) -> Self {
+        Hitbox::Circle { x, y, radius }
+    }
+
+    pub fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        Hitbox::Capsule { x1, y1, x2, y2, radius: LINE_HITBOX_RADIUS }
+    }
+
+    /// A slash from (x, y) toward (toward_x, toward_y).
+    pub fn arc(x: f64, y: f64, toward_x: f64, toward_y: f64, radius: f64, half_angle: f64) -> Self {
+        let dx = toward_x - x;
+        let dy = toward_y - y;
+        let len = (dx * dx + dy * dy).sqrt();
+        let (dir_x, dir_y) = if len > 0.0 { (dx / len, dy / len) } else { (1.0, 0.0) };
+        Hitbox::Arc { x, y, radius, dir_x, dir_y, half_angle }
+    }
+
+    /// Whether the hitbox reaches `hurtbox`. Pure geometry; the caller records confirmed
+    /// hits for the debug overlay with `record_hit`.
+    pub fn hits(&self, hurtbox: &Hurtbox) -> bool {
+        match *self {
+            Hitbox::Circle { x, y, radius } => hurtbox.distance_to(x, y) <= radius,
+            Hitbox::Capsule { x1, y1, x2, y2, radius } => {
+                segment_distance((x1, y1), (x2, y2), (hurtbox.x1, hurtbox.y1), (hurtbox.x2, hurtbox.y2))
+                    <= radius + hurtbox.radius
+            }
+            Hitbox::Arc { x, y, radius, dir_x, dir_y, half_angle } => {
+                // Origin inside the hurtbox, or some point of its core segment inside the
+                // sector once widened by the hurtbox radius.
+                hurtbox.distance_to(x, y) <= 0.0
+                    || [
+                        (hurtbox.x1, hurtbox.y1),
+                        (hurtbox.x2, hurtbox.y2),
+                        closest_on_segment((hurtbox.x1, hurtbox.y1), (hurtbox.x2, hurtbox.y2), (x, y)),
+                    ]
+                    .iter()
+                    .any(|&(px, py)| {
+                        let dx = px - x;
+                        let dy = py - y;
+                        let dist = (dx * dx + dy * dy).sqrt();
+                        if dist - hurtbox.radius > radius {
+                            return false;
+                        }
+                        let cos = ((dx * dir_x + dy * dir_y) / dist).clamp(-1.0, 1.0);
+                        let slack = (hurtbox.radius / dist).min(1.0).asin();
+                        cos.acos() <= half_angle + slack
+                    })
+            }
+        }
+    }
+
+    /// Draws the outline in world space.
+    pub fn draw(&self, color: [f32; 4], transform: math::Matrix2d, g: &mut G2d) {
+        match *self {
+            Hitbox::Circle { x, y, radius } => {
+                Ellipse::new_border(color, 1.5).draw(
+                    ellipse::circle(x, y, radius),
+                    &DrawState::default(),
+                    transform,
+                    g,
+                );
+            }
+            Hitbox::Capsule { x1, y1, x2, y2, radius } => draw_capsule(color, (x1, y1), (x2, y2), radius, transform, g),
+            Hitbox::Arc { x, y, radius, dir_x, dir_y, half_angle } => {
+                let facing = dir_y.atan2(dir_x);
+                let steps = 16;
+                let mut last = (x, y);
+                for i in 0..=steps {
+                    let angle = facing - half_angle + 2.0 * half_angle * i as f64 / steps as f64;
+                    let point = (x + angle.cos() * radius, y + angle.sin() * radius);
+                    line(color, 1.5, [last.0, last.1, point.0, point.1], transform, g);
+                    last = point;
+                }
+                line(color, 1.5, [last.0, last.1, x, y], transform, g);
+            }
+        }
+    }
+}
+
+/// The area an entity can be hit in: a capsule fitted to its sprite.
+#[derive(Debug, Clone, Copy)]
+pub struct Hurtbox {
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+    pub radius: f64,
+}
+
+/// Hurtbox size and placement relative to the entity position, facing right.
+struct HurtboxShape {
+    width: f64,
+    height: f64,
+    forward: f64, // shifted toward the facing direction
+    lift: f64,    // negative is up
+}
+
+const fn shape(width: f64, height: f64, forward: f64, lift: f64) -> HurtboxShape {
+    HurtboxShape { width, height, forward, lift }
+}
+
+fn cpu_shape(variant: CpuVariant) -> HurtboxShape {
+    match variant {
+        CpuVariant::GiantMantis => shape(200.0, 220.0, 10.0, -10.0),
+        CpuVariant::BloodIdol => shape(110.0, 200.0, 0.0, -20.0),
+        CpuVariant::Rattlesnake => shape(90.0, 40.0, 0.0, 10.0),
+        CpuVariant::GiantRattlesnake => shape(220.0, 90.0, 0.0, 10.0),
+        CpuVariant::Raptor => shape(110.0, 90.0, 10.0, 0.0),
+        CpuVariant::TRex => shape(300.0, 260.0, 40.0, -20.0),
+        CpuVariant::VoidTempest => shape(220.0, 220.0, 0.0, 0.0),
+        CpuVariant::LightReaver => shape(70.0, 90.0, 0.0, 0.0),
+        CpuVariant::NightReaver => shape(70.0, 90.0, 0.0, 0.0),
+        CpuVariant::RazorFiend => shape(180.0, 180.0, 0.0, 0.0),
+    }
+}
+
+fn racer_shape(state: &RacerState) -> HurtboxShape {
+    match state {
+        RacerState::OnBike => shape(160.0, 70.0, 0.0, 10.0),
+        RacerState::OnFoot => shape(70.0, 110.0, 0.0, 0.0),
+    }
+}
+
+impl Hurtbox {
+    fn from_shape(shape: HurtboxShape, x: f64, y: f64, facing_left: bool) -> Self {
+        let cx = x + if facing_left { -shape.forward } else { shape.forward };
+        let cy = y + shape.lift;
+        // A stadium fitted to the box: the long side is the core segment.
+        if shape.width >= shape.height {
+            let half = (shape.width - shape.height) / 2.0;
+            Hurtbox { x1: cx - half, y1: cy, x2: cx + half, y2: cy, radius: shape.height / 2.0 }
+        } else {
+            let half = (shape.height - shape.width) / 2.0;
+            Hurtbox { x1: cx, y1: cy - half, x2: cx, y2: cy + half, radius: shape.width / 2.0 }
+        }
+    }
+
+    pub fn for_cpu(cpu: &CpuEntity) -> Self {
+        Hurtbox::from_shape(cpu_shape(cpu.variant), cpu.x, cpu.y, cpu.facing_left)
+    }
+
+    pub fn for_racer(cr: &CpuRacer) -> Self {
+        Hurtbox::from_shape(racer_shape(&cr.state), cr.x, cr.y, cr.facing_left)
+    }
+
+    pub fn for_fighter(fighter: &Fighter) -> Self {
+        Hurtbox::from_shape(racer_shape(&fighter.state), fighter.x, fighter.y, false)
+    }
+
+    /// Distance from a point to the hurtbox edge; zero or less inside it.
+    pub fn distance_to(&self, x: f64, y: f64) -> f64 {
+        let (cx, cy) = closest_on_segment((self.x1, self.y1), (self.x2, self.y2), (x, y));
+        ((x - cx).powi(2) + (y - cy).powi(2)).sqrt() - self.radius
+    }
+
+    pub fn draw(&self, color: [f32; 4], transform: math::Matrix2d, g: &mut G2d) {
+        draw_capsule(color, (self.x1, self.y1), (self.x2, self.y2), self.radius, transform, g);
+    }
+}
+
+/// Whether two hurtboxes touch. Contact melee uses the attacker's own hurtbox as its hitbox.
+pub fn overlaps(attacker: &Hurtbox, target: &Hurtbox) -> bool {
+    segment_distance((attacker.x1, attacker.y1), (attacker.x2, attacker.y2), (target.x1, target.y1), (target.x2, target.y2))
+        <= attacker.radius + target.radius
+}
+
+fn closest_on_segment(a: (f64, f64), b: (f64, f64), p: (f64, f64)) -> (f64, f64) {
+    let dx = b.0 - a.0;
+    let dy = b.1 - a.1;
+    let len_sq = dx * dx + dy * dy;
+    if len_sq == 0.0 {
+        return a;
+    }
+    let t = (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len_sq).clamp(0.0, 1.0);
+    (a.0 + t * dx, a.1 + t * dy)
+}
+
+fn point_segment_distance(a: (f64, f64), b: (f64, f64), p: (f64, f64)) -> f64 {
+    let (cx, cy) = closest_on_segment(a, b, p);
+    ((p.0 - cx).powi(2) + (p.1 - cy).powi(2)).sqrt()
+}
+
+/// Shortest distance between segments a-b and c-d.
+fn segment_distance(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> f64 {
+    let cross = |o: (f64, f64), p: (f64, f64), q: (f64, f64)| (p.0 - o.0) * (q.1 - o.1) - (p.1 - o.1) * (q.0 - o.0);
+    let d1 = cross(a, b, c);
+    let d2 = cross(a, b, d);
+    let d3 = cross(c, d, a);
+    let d4 = cross(c, d, b);
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return 0.0;
+    }
+    point_segment_distance(a, b, c)
+        .min(point_segment_distance(a, b, d))
+        .min(point_segment_distance(c, d, a))
+        .min(point_segment_distance(c, d, b))
+}
+
+fn draw_capsule(
+    color: [f32; 4],
+    (x1, y1): (f64, f64),
+    (x2, y2): (f64, f64),
+    radius: f64,
+    transform: math::Matrix2d,
+    g: &mut G2d,
+) {
+    let border = Ellipse::new_border(color, 1.5);
+    border.draw(ellipse::circle(x1, y1, radius), &DrawState::default(), transform, g);
+    border.draw(ellipse::circle(x2, y2, radius), &DrawState::default(), transform, g);
+    let dx = x2 - x1;
+    let dy = y2 - y1;
+    let len = (dx * dx + dy * dy).sqrt();
+    if len > 0.0 {
+        let nx = -dy / len * radius;
+        let ny = dx / len * radius;
+        line(color, 1.5, [x1 + nx, y1 + ny, x2 + nx, y2 + ny], transform, g);
+        line(color, 1.5, [x1 - nx, y1 - ny, x2 - nx, y2 - ny], transform, g);
+    }
+}
+
+// --- Debug overlay (F12) ---
+
+const DEBUG_HITBOX_LIFETIME: f64 = 0.3;
+
+static DEBUG_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Recent attack hitboxes and the hurtboxes they hit, kept while the overlay is on.
+#[derive(Default)]
+struct DebugLog {
+    hitboxes: Vec<(Hitbox, f64)>,
+    hits: Vec<(Hurtbox, f64)>,
+}
+
+fn debug_log() -> &'static Mutex<DebugLog> {
+    static LOG: OnceLock<Mutex<DebugLog>> = OnceLock::new();
+    LOG.get_or_init(|| Mutex::new(DebugLog::default()))
+}
+
+/// Turns hitbox recording on or off. Off by default, so nothing is kept outside the overlay.
+pub fn set_debug(enabled: bool) {
+    DEBUG_ENABLED.store(enabled, Ordering::Relaxed);
+    if !enabled {
+        let mut log = debug_log().lock().unwrap();
+        log.hitboxes.clear();
+        log.hits.clear();
+    }
+}
+
+/// Records an attack's hitbox for the overlay. Call once per attack, before its hit tests.
+pub fn record(hitbox: Hitbox) {
+    if DEBUG_ENABLED.load(Ordering::Relaxed) {
+        debug_log().lock().unwrap().hitboxes.push((hitbox, DEBUG_HITBOX_LIFETIME));
+    }
+}
+
+/// Records a hurtbox an attack connected with, for the overlay.
+pub fn record_hit(hurtbox: &Hurtbox) {
+    if DEBUG_ENABLED.load(Ordering::Relaxed) {
+        debug_log().lock().unwrap().hits.push((*hurtbox, DEBUG_HITBOX_LIFETIME));
+    }
+}
+
+/// Ages recorded hitboxes and drops expired ones.
+pub fn update_debug(dt: f64) {
+    if !DEBUG_ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let mut log = debug_log().lock().unwrap();
+    log.hitboxes.iter_mut().for_each(|(_, t)| *t -= dt);
+    log.hitboxes.retain(|(_, t)| *t > 0.0);
+    log.hits.iter_mut().for_each(|(_, t)| *t -= dt);
+    log.hits.retain(|(_, t)| *t > 0.0);
+}
+
+/// Draws every hurtbox (cyan), recent attack hitboxes (orange) and hurtboxes they hit (red).
+pub fn draw_debug(
+    transform: math::Matrix2d,
+    g: &mut G2d,
+    cpu_entities: &[CpuEntity],
+    cpu_racers: &[CpuRacer],
+) {
+    let hurt_color = [0.0, 1.0, 1.0, 0.6];
+    for cpu in cpu_entities {
+        Hurtbox::for_cpu(cpu).draw(hurt_color, transform, g);
+    }
+    for cr in cpu_racers.iter().filter(|cr| !cr.is_crashed) {
+        Hurtbox::for_racer(cr).draw(hurt_color, transform, g);
+    }
+    let log = debug_log().lock().unwrap();
+    for (hitbox, t) in &log.hitboxes {
+        let alpha = (t / DEBUG_HITBOX_LIFETIME) as f32;
+        hitbox.draw([1.0, 0.6, 0.0, alpha], transform, g);
+    }
+    for (hurtbox, t) in &log.hits {
+        let alpha = (t / DEBUG_HITBOX_LIFETIME) as f32;
+        hurtbox.draw([1.0, 0.0, 0.0, alpha], transform, g);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A lying stadium: core from (0, 0) to (100, 0), 20 thick on each side.
+    fn lying() -> Hurtbox {
+        Hurtbox { x1: 0.0, y1: 0.0, x2: 100.0, y2: 0.0, radius: 20.0 }
+    }
+
+    fn capsule(x1: f64, y1: f64, x2: f64, y2: f64, radius: f64) -> Hitbox {
+        Hitbox::Capsule { x1, y1, x2, y2, radius }
+    }
+
+    #[test]
+    fn capsules_touching_at_the_edge_hit() {
+        // Parallel capsule above: 30 apart, radii 20 + 10.
+        assert!(capsule(0.0, -30.0, 100.0, -30.0, 10.0).hits(&lying()));
+        // Past the rounded end: 140 from the core end, radii 20 + 20.
+        assert!(capsule(140.0, 0.0, 200.0, 0.0, 20.0).hits(&lying()));
+        assert!(!capsule(140.1, 0.0, 200.0, 0.0, 20.0).hits(&lying()));
+    }
+
+    #[test]
+    fn rotated_capsules_are_measured_along_their_segments() {
+        // A diagonal shot crossing the core hits however thin it is.
+        assert!(capsule(20.0, -80.0, 80.0, 80.0, 0.0).hits(&lying()));
+        // A diagonal past the end: its closest point is the core end at (100, 0).
+        let diagonal = capsule(110.0, -100.0, 210.0, 0.0, 5.0);
+        let gap = 110.0 / 2f64.sqrt();
+        assert!(!diagonal.hits(&lying()));
+        assert!(capsule(110.0, -100.0, 210.0, 0.0, gap - 20.0 + 0.01).hits(&lying()));
+        // A standing hurtbox against a lying shot.
+        let standing = Hurtbox { x1: 300.0, y1: -50.0, x2: 300.0, y2: 50.0, radius: 10.0 };
+        assert!(capsule(0.0, 55.0, 295.0, 55.0, 2.0).hits(&standing));
+        assert!(!capsule(0.0, 70.0, 400.0, 70.0, 2.0).hits(&standing));
+    }
+
+    #[test]
+    fn capsule_misses_beside_the_hurtbox() {
+        assert!(!capsule(0.0, 40.0, 100.0, 40.0, 10.0).hits(&lying()));
+        assert!(!Hitbox::line(-100.0, -100.0, -50.0, -30.0).hits(&lying()));
+    }
+
+    #[test]
+    fn circles_reach_from_the_hurtbox_edge() {
+        assert!(Hitbox::circle(50.0, 30.0, 10.0).hits(&lying()));
+        assert!(!Hitbox::circle(50.0, 30.1, 10.0).hits(&lying()));
+        assert_eq!(lying().distance_to(50.0, 0.0), -20.0);
+    }
+
+    #[test]
+    fn arcs_only_hit_what_they_face() {
+        let target = Hurtbox { x1: 200.0, y1: 0.0, x2: 200.0, y2: 0.0, radius: 20.0 };
+        let facing = Hitbox::arc(100.0, 0.0, 300.0, 0.0, 90.0, 0.5);
+        let away = Hitbox::arc(100.0, 0.0, 0.0, 0.0, 90.0, 0.5);
+        assert!(facing.hits(&target));
+        assert!(!away.hits(&target));
+        assert!(!Hitbox::arc(100.0, 0.0, 300.0, 0.0, 70.0, 0.5).hits(&target), "out of reach");
+    }
+
+    #[test]
+    fn hurtboxes_overlap_when_their_capsules_touch() {
+        let other = Hurtbox { x1: 140.0, y1: 0.0, x2: 180.0, y2: 0.0, radius: 20.0 };
+        assert!(overlaps(&lying(), &other));
+        assert!(!overlaps(&lying(), &Hurtbox { x1: 141.0, ..other }));
+    }
+}
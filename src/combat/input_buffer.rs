@@ -0,0 +1,223 @@
+// File: src/combat/input_buffer.rs
+
+use piston_window::{Button, ButtonArgs, ButtonState, Event, Input};
+
+/// Combat presses the buffer can hold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CombatInput {
+    Strike,
+    Rush,
+    Block,
+}
+
+/// What the fighter is busy with, for cancel rules.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CombatPhase {
+    Striking,
+    Blocking,
+}
+
+/// Length of a strike swing; the strike animation timer starts here and runs down.
+pub const STRIKE_SWING: f64 = 0.25;
+
+/// Inputs that may interrupt a phase instead of waiting for it to end, and how many
+/// seconds into the phase they may.
+const CANCEL_RULES: [(CombatPhase, CombatInput, f64); 4] = [
+    (CombatPhase::Striking, CombatInput::Rush, 0.10),  // strike -> rush, once the swing has hit
+    (CombatPhase::Striking, CombatInput::Block, 0.15), // strike -> block, on the follow-through
+    (CombatPhase::Blocking, CombatInput::Strike, 0.08), // block -> kinetic strike
+    (CombatPhase::Blocking, CombatInput::Rush, 0.08),  // block -> kinetic rush
+];
+
+/// Seconds into a strike, from the strike animation timer. A swing that has played out
+/// counts as arbitrarily far in.
+pub fn swing_elapsed(strike_animation_timer: f64) -> f64 {
+    if strike_animation_timer > 0.0 {
+        STRIKE_SWING - strike_animation_timer
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// Seconds until `input` may cancel `phase`, `elapsed` seconds in: 0 if it may now,
+/// infinite if it never can.
+pub fn cancel_wait(phase: CombatPhase, elapsed: f64, input: CombatInput) -> f64 {
+    CANCEL_RULES
+        .iter()
+        .find(|(p, i, _)| *p == phase && *i == input)
+        .map_or(f64::INFINITY, |(_, _, opens)| (opens - elapsed).max(0.0))
+}
+
+pub fn cancels(phase: CombatPhase, elapsed: f64, input: CombatInput) -> bool {
+    cancel_wait(phase, elapsed, input) <= 0.0
+}
+
+struct BufferedPress {
+    input: CombatInput,
+    button: Button,
+    age: f64,
+    released: bool, // the button was let go while buffered; replay the release too
+}
+
+/// Holds combat presses that arrive shortly before they'd be legal (a strike during the
+/// combo rest zone, a rush on cooldown, anything while stunned) and feeds them back into
+/// the event loop on the first update where they are. A press that would wait longer
+/// than the buffer window goes through unchanged, as before.
+pub struct InputBuffer {
+    pub window: f64,
+    held: Vec<BufferedPress>,
+}
+
+impl InputBuffer {
+    pub fn new(window: f64) -> Self {
+        InputBuffer { window, held: Vec::new() }
+    }
+
+    /// Offers a press whose action can't happen for another `wait` seconds. Returns true
+    /// if the press was buffered, in which case the caller drops the event.
+    pub fn hold(&mut self, input: CombatInput, button: Button, wait: f64) -> bool {
+        if self.held.iter().any(|p| p.button == button) {
+            return true; // Repeat of a press that is already waiting
+        }
+        if self.window <= 0.0 || wait <= 0.0 || wait > self.window {
+            return false;
+        }
+        self.held.push(BufferedPress { input, button, age: 0.0, released: false });
+        true
+    }
+
+    /// Returns true if `button` has a buffered press; its release is then replayed after it.
+    pub fn release(&mut self, button: Button) -> bool {
+        match self.held.iter_mut().find(|p| p.button == button) {
+            Some(press) => {
+                press.released = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Advances the buffer by one update tick. Presses whose action has become legal
+    /// (`wait_for` returns 0) or that outlived the window are returned as events to feed
+    /// back into the loop, in the order they were pressed.
+    pub fn update(&mut self, dt: f64, wait_for: impl Fn(CombatInput) -> f64) -> Vec<Event> {
+        let mut events = Vec::new();
+        let window = self.window;
+        self.held.retain_mut(|press| {
+            press.age += dt;
+            if wait_for(press.input) > 0.0 && press.age < window {
+                return true;
+            }
+            events.push(button_event(press.button, ButtonState::Press));
+            if press.released {
+                events.push(button_event(press.button, ButtonState::Release));
+            }
+            false
+        });
+        events
+    }
+
+    /// Drops everything held, e.g. on pause or when leaving the field.
+    pub fn clear(&mut self) {
+        self.held.clear();
+    }
+}
+
+fn button_event(button: Button, state: ButtonState) -> Event {
+    Event::Input(
+        Input::Button(ButtonArgs {
+            state,
+            button,
+            scancode: None,
+        }),
+        None,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use piston_window::{Key, MouseButton, PressEvent, ReleaseEvent};
+
+    const LMB: Button = Button::Mouse(MouseButton::Left);
+    const SPACE: Button = Button::Keyboard(Key::Space);
+
+    fn presses(events: &[Event]) -> Vec<Button> {
+        events.iter().filter_map(|e| e.press_args()).collect()
+    }
+
+    #[test]
+    fn only_presses_inside_the_window_are_held() {
+        let mut buffer = InputBuffer::new(0.15);
+        assert!(!buffer.hold(CombatInput::Strike, LMB, 0.0), "a legal press goes straight through");
+        assert!(!buffer.hold(CombatInput::Strike, LMB, 0.2), "too early to buffer");
+        assert!(buffer.hold(CombatInput::Strike, LMB, 0.1));
+        assert!(buffer.hold(CombatInput::Strike, LMB, 0.1), "a repeat joins the held press");
+        assert!(!InputBuffer::new(0.0).hold(CombatInput::Strike, LMB, 0.1), "a zero window is off");
+    }
+
+    #[test]
+    fn press_is_released_once_legal_and_consumed() {
+        let mut buffer = InputBuffer::new(0.15);
+        buffer.hold(CombatInput::Strike, LMB, 0.05);
+        assert!(buffer.update(0.02, |_| 0.03).is_empty());
+        let events = buffer.update(0.03, |_| 0.0);
+        assert_eq!(presses(&events), vec![LMB]);
+        assert!(buffer.update(0.02, |_| 0.0).is_empty(), "a released press is gone");
+        assert!(!buffer.release(LMB));
+    }
+
+    #[test]
+    fn press_expires_at_the_end_of_the_window() {
+        let mut buffer = InputBuffer::new(0.15);
+        buffer.hold(CombatInput::Rush, SPACE, 0.1);
+        assert!(buffer.update(0.1, |_| 1.0).is_empty());
+        // Still not legal, but the window is over: the press goes through as it is.
+        assert_eq!(presses(&buffer.update(0.05, |_| 1.0)), vec![SPACE]);
+        assert!(buffer.update(0.05, |_| 0.0).is_empty());
+    }
+
+    #[test]
+    fn release_is_replayed_after_its_press_in_order() {
+        let mut buffer = InputBuffer::new(0.15);
+        buffer.hold(CombatInput::Strike, LMB, 0.05);
+        buffer.hold(CombatInput::Rush, SPACE, 0.05);
+        assert!(buffer.release(LMB));
+        let events = buffer.update(0.05, |_| 0.0);
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].press_args(), Some(LMB));
+        assert_eq!(events[1].release_args(), Some(LMB));
+        assert_eq!(events[2].press_args(), Some(SPACE));
+    }
+
+    #[test]
+    fn clear_drops_held_presses() {
+        let mut buffer = InputBuffer::new(0.15);
+        buffer.hold(CombatInput::Block, Button::Mouse(MouseButton::Right), 0.1);
+        buffer.clear();
+        assert!(buffer.update(1.0, |_| 0.0).is_empty());
+    }
+
+    #[test]
+    fn cancels_open_partway_into_the_phase() {
+        // Rush can't cut a swing before it has hit, block has to wait for the follow-through.
+        assert!(!cancels(CombatPhase::Striking, swing_elapsed(STRIKE_SWING), CombatInput::Rush));
+        assert!(cancels(CombatPhase::Striking, swing_elapsed(0.1), CombatInput::Rush));
+        assert!(!cancels(CombatPhase::Striking, swing_elapsed(0.15), CombatInput::Block));
+        assert!(cancels(CombatPhase::Striking, swing_elapsed(0.05), CombatInput::Block));
+        assert!((cancel_wait(CombatPhase::Striking, 0.05, CombatInput::Block) - 0.10).abs() < 1e-9);
+        // A finished swing can always be cancelled.
+        assert!(cancels(CombatPhase::Striking, swing_elapsed(0.0), CombatInput::Block));
+
+        assert!(!cancels(CombatPhase::Blocking, 0.0, CombatInput::Strike));
+        assert!(cancels(CombatPhase::Blocking, 0.08, CombatInput::Strike));
+        assert!(cancels(CombatPhase::Blocking, 2.0, CombatInput::Rush));
+    }
+
+    #[test]
+    fn inputs_without_a_rule_never_cancel() {
+        assert!(!cancels(CombatPhase::Striking, 10.0, CombatInput::Strike));
+        assert!(!cancels(CombatPhase::Blocking, 10.0, CombatInput::Block));
+        assert_eq!(cancel_wait(CombatPhase::Blocking, 0.0, CombatInput::Block), f64::INFINITY);
+    }
+}
@@ -0,0 +1,284 @@
+// File: src/combat/melee.rs
+
+use crate::audio::AudioManager;
+use crate::combat::damage::{self, Attack, DamageType, Defense, TextStyle};
+use crate::combat::combo::{ComboSystem, StrikeResult};
+use crate::combat::hitbox::{self, Hitbox, Hurtbox, MAX_HURTBOX_REACH};
+use crate::combat::resources::ResourceAction;
+use crate::combat::threat::ThreatTarget;
+use crate::entities::cpu_entity::CpuEntity;
+use crate::entities::cpu_racer::CpuRacer;
+use crate::entities::fighter::Fighter;
+use crate::game_state::{CombatMode, EntityState, FighterType, RacerState};
+use crate::map_system::FieldId;
+use crate::utils::spatial_hash::SpatialHash;
+use crate::utils::vec2d::Vec2d;
+use crate::DamageText;
+
+/// Pulls a strike target back inside the fixed crater ellipse when the fighter
+/// is in close combat. Returns the (possibly clamped) world position.
+pub fn clamp_strike_point(
+    fighter: &Fighter,
+    crater_x: f64,
+    crater_y: f64,
+    crater_radius: f64,
+    wmx: f64,
+    wmy: f64,
+) -> (f64, f64) {
+    let dx = wmx - crater_x;
+    let dy = wmy - crater_y;
+    let hr = crater_radius;
+    let vr = crater_radius * 0.75;
+    let dsq = (dx * dx) / (hr * hr) + (dy * dy) / (vr * vr);
+
+    if fighter.combat_mode == CombatMode::CloseCombat && dsq > 1.0 {
+        let distance_factor = dsq.sqrt();
+        (
+            fighter.x + (wmx - fighter.x) / distance_factor,
+            fighter.y + (wmy - fighter.y) / distance_factor,
+        )
+    } else {
+        (wmx, wmy)
+    }
+}
+
+/// What a player strike or shot can land on, and what resolving those hits needs.
+/// `cpu_grid` must have been rebuilt from `cpu_entities` since they last moved.
+pub struct HitTargets<'a> {
+    pub cpu_entities: &'a mut [CpuEntity],
+    pub cpu_grid: &'a SpatialHash,
+    pub cpu_racers: &'a mut [CpuRacer],
+    pub damage_texts: &'a mut Vec<DamageText>,
+    pub current_field: FieldId,
+    pub audio_manager: &'a AudioManager,
+    pub current_time: f64,
+}
+
+/// Resolves a melee strike against every hostile CPU and CPU racer: point hit at
+/// the strike position, frontal arc hits inside the crater, knockback, stun and
+/// the Racer ATOMIC-STATE reward. Shared by the windowed game and the headless World.
+pub fn apply_melee_hits(
+    fighter: &mut Fighter,
+    result: &StrikeResult,
+    wmx: f64,
+    wmy: f64,
+    crater_radius: f64,
+    combo_system: &mut ComboSystem,
+    targets: HitTargets<'_>,
+) {
+    let HitTargets { cpu_entities, cpu_grid, cpu_racers, damage_texts, current_field, audio_manager, current_time } = targets;
+    let mut point_damage = fighter.melee_damage * result.damage_multiplier;
+			
+    // Damage reduction if in Ranged mode on foot (Racer only)
+    if fighter.fighter_type == FighterType::Racer && fighter.state == RacerState::OnFoot && !fighter.boost {
+        point_damage *= 0.90; // 10% reduction
+    }			
+
+    // Apply damage reduction if on bike
+    if fighter.state == RacerState::OnBike {
+        if fighter.fighter_type == FighterType::Racer && fighter.boost {
+            point_damage *= 0.50; // 25% increase over base 0.1 (0.1 * 1.25)
+        } else {
+            point_damage *= 0.25; // 90% reduction
+        }
+    }
+    // 25% damage boost while ATOMIC-STATE is active
+    if fighter.invincible_timer > 1.0 {
+        point_damage *= 1.25;
+    }			
+    let frontal_damage = point_damage / 2.0;
+    let mut point_hit_applied = false;
+
+    // The strike point hits one target; the frontal arc slashes the crater half facing it.
+    let point_hitbox = Hitbox::circle(wmx, wmy, hitbox::strike_point_radius());
+    let arc_hitbox = Hitbox::arc(fighter.x, fighter.y, wmx, wmy, crater_radius, std::f64::consts::FRAC_PI_2);
+    hitbox::record(point_hitbox);
+    hitbox::record(arc_hitbox);
+
+    // Only CPUs near the strike point or inside the crater around the fighter can be hit.
+    let reach = hitbox::strike_point_radius() + MAX_HURTBOX_REACH;
+    let crater_reach = crater_radius + MAX_HURTBOX_REACH;
+    let mut candidates = Vec::new();
+    cpu_grid.candidates_in_rect(
+        ((wmx - reach).min(fighter.x - crater_reach), (wmy - reach).min(fighter.y - crater_reach)),
+        ((wmx + reach).max(fighter.x + crater_reach), (wmy + reach).max(fighter.y + crater_reach)),
+        cpu_entities.len(),
+        &mut candidates,
+    );
+
+    for &index in &candidates {
+        let cpu = &mut cpu_entities[index];
+        if cpu.entity_state == EntityState::Friendly {
+            continue;
+        }				
+				
+        let mut total_damage_this_cpu = 0.0;
+        let mut was_point_hit = false;
+        let mut was_frontal_hit = false;
+        let hurtbox = Hurtbox::for_cpu(cpu);
+
+        if !point_hit_applied && point_hitbox.hits(&hurtbox) {
+            total_damage_this_cpu += point_damage;
+            was_point_hit = true;
+            point_hit_applied = true;
+        }
+
+        if !was_point_hit && arc_hitbox.hits(&hurtbox) {
+            total_damage_this_cpu += frontal_damage;
+            was_frontal_hit = true;
+        }
+
+        if total_damage_this_cpu > 0.0 {
+            hitbox::record_hit(&hurtbox);
+            let hit = damage::resolve(
+                &Attack::with_stats(total_damage_this_cpu, DamageType::Slash, &fighter.stats.attack),
+                &Defense::cpu(cpu),
+            );
+            if !hit.landed() {
+                damage_texts.push(hit.text(cpu.x, cpu.y - 50.0));
+                continue;
+            }
+            cpu.current_hp -= hit.amount;
+            cpu.threat.add(ThreatTarget::Player, hit.threat);
+            fighter.resources.gain(ResourceAction::MeleeHit);
+					
+            // Track that a hit connected before an ATOMIC-STATE finisher
+            if was_point_hit && !result.grants_atomic_state {
+                combo_system.racer_combo_hit_connected = true;
+            }					
+
+            // Safety: Prevent text overflow
+            if damage_texts.len() < 100 {
+						damage_texts.push(hit.text(cpu.x, cpu.y - 50.0));
+					}	
+
+            if !cpu.is_dead() {
+                if was_point_hit {
+                    if result.knockback {
+                        cpu.apply_knockback(wmx, wmy, result.knockback_force);
+                    }	
+							
+							// Achievement: ATOMIC-STATE finisher reward (Racer's 5-hit combo)
+							if result.grants_atomic_state {
+								fighter.invincible_timer = 2.5;
+							}							
+							
+                    if result.stun_duration > 0.0 {
+                        cpu.stun_timer = result.stun_duration;
+                        damage_texts.push(DamageText {
+                            text: "STUN".to_string(),
+                            x: cpu.x,
+                            y: cpu.y - 90.0,
+                            color: [1.0, 1.0, 1.0, 1.0],
+                            lifetime: 0.5,
+                            style: TextStyle::Normal,
+                        });
+                    }
+                } else if was_frontal_hit && result.is_combo_finisher {
+                    let frontal_knockback_force = result.knockback_force / 2.0;
+                    cpu.apply_knockback(wmx, wmy, frontal_knockback_force);
+                }
+            }
+        }
+    }
+			
+    if current_field == FieldId(0, 0) {
+        let mut r_point_hit_applied = false;
+        for cr in cpu_racers.iter_mut() {
+            if cr.is_crashed { continue; }
+					if cr.entity_state == EntityState::Friendly { continue; }
+            let mut total_damage_this_cr = 0.0;
+            let mut was_point_hit = false;
+            let mut was_frontal_hit = false;
+            let hurtbox = Hurtbox::for_racer(cr);
+ 
+            if !r_point_hit_applied && point_hitbox.hits(&hurtbox) {
+                total_damage_this_cr += point_damage;
+                was_point_hit = true;
+                r_point_hit_applied = true;
+            }
+ 
+            if !was_point_hit && arc_hitbox.hits(&hurtbox) {
+                total_damage_this_cr += frontal_damage;
+                was_frontal_hit = true;
+            }
+ 
+            if total_damage_this_cr > 0.0 {
+                hitbox::record_hit(&hurtbox);
+                if cr.entity_state == EntityState::Neutral {
+                    cr.entity_state = EntityState::Hostile;
+                }						
+                if cr.block_fighter_hit(fighter, audio_manager, current_time) {
+                    damage_texts.push(damage::Hit::blocked(DamageType::Slash).text(cr.x, cr.y - 50.0));
+                    continue;
+                }
+                let hit = damage::resolve(
+                    &Attack::with_stats(total_damage_this_cr, DamageType::Slash, &fighter.stats.attack),
+                    &Defense::cpu_racer(cr),
+                );
+                if !hit.landed() {
+                    damage_texts.push(hit.text(cr.x, cr.y - 50.0));
+                    continue;
+                }
+                cr.current_hp -= hit.amount;
+                fighter.resources.gain(ResourceAction::MeleeHit);
+                
+                if was_point_hit && !result.grants_atomic_state {
+                    combo_system.racer_combo_hit_connected = true;
+                }
+ 
+                if damage_texts.len() < 100 {
+                    damage_texts.push(hit.text(cr.x, cr.y - 50.0));
+                }
+ 
+                if cr.current_hp > 0.0 {
+                    if was_point_hit {
+                        if result.knockback {
+                            let kx = cr.x - wmx;
+                            let ky = cr.y - wmy;
+                            let k_dist = (kx * kx + ky * ky).sqrt();
+                            if k_dist > 0.0 {
+                                let norm_kx = kx / k_dist;
+                                let norm_ky = ky / k_dist;
+                                cr.knockback_velocity = Vec2d::new(norm_kx * result.knockback_force, norm_ky * result.knockback_force);
+                                cr.knockback_duration = 0.1;
+                            }
+                        }
+ 
+                        if result.stun_duration > 0.0 {
+                            cr.stun_timer = result.stun_duration;
+                            damage_texts.push(DamageText {
+                                text: "STUN".to_string(),
+                                x: cr.x,
+                                y: cr.y - 90.0,
+                                color: [1.0, 1.0, 1.0, 1.0],
+                                lifetime: 0.5,
+                                style: TextStyle::Normal,
+                            });
+                        }
+                    } else if was_frontal_hit && result.is_combo_finisher {
+                        let frontal_knockback_force = result.knockback_force / 2.0;
+                        let kx = cr.x - wmx;
+                        let ky = cr.y - wmy;
+                        let k_dist = (kx * kx + ky * ky).sqrt();
+                        if k_dist > 0.0 {
+                            let norm_kx = kx / k_dist;
+                            let norm_ky = ky / k_dist;
+                            cr.knockback_velocity = Vec2d::new(norm_kx * frontal_knockback_force, norm_ky * frontal_knockback_force);
+                            cr.knockback_duration = 0.1;
+                        }
+                    }
+                }
+            }
+        }
+    }			
+			
+    // ATOMIC-STATE: grant if the finisher fires and any earlier strike of the chain connected
+    if result.grants_atomic_state
+        && combo_system.racer_combo_hit_connected
+        && fighter.invincible_timer <= 1.0
+    {
+        fighter.invincible_timer = 2.5;
+        combo_system.racer_combo_hit_connected = false;
+    }			
+}
@@ -1,6 +1,16 @@
 // File: combat/mod.rs
 pub mod block;
 pub mod combo;
+pub mod combo_data;
+pub mod damage;
 pub mod field_traits;
+pub mod hitbox;
+pub mod input_buffer;
+pub mod melee;
+pub mod resources;
+pub mod skill_data;
+pub mod skill_runner;
 pub mod skills;
 pub mod stats;
+pub mod status_effects;
+pub mod threat;
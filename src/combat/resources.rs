@@ -0,0 +1,703 @@
+// File: src/combat/resources.rs
+
+use crate::combat::block::BlockSystem;
+use crate::config::{parse_bool, parse_ranged, read_ini, ConfigError, IniLine};
+use crate::game_state::FighterType;
+use piston_window::*;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Name of the resource definition file looked up next to the executable.
+pub const RESOURCES_FILE_NAME: &str = "resources.cfg";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceKind {
+    BlockPoints,   // spent by blocking (run by the BlockSystem)
+    KineticCharge, // built from spent block points and parries, released by kinetic strikes
+    Fuel,          // burned while riding
+    Ammo,          // spent per shot, refilled by reloading
+    Style,         // built by landing hits, spent by skills
+}
+
+impl ResourceKind {
+    pub const ALL: [ResourceKind; 5] = [
+        ResourceKind::BlockPoints,
+        ResourceKind::KineticCharge,
+        ResourceKind::Fuel,
+        ResourceKind::Ammo,
+        ResourceKind::Style,
+    ];
+
+    /// Name used in resources.cfg.
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            ResourceKind::BlockPoints => "block_points",
+            ResourceKind::KineticCharge => "kinetic_charge",
+            ResourceKind::Fuel => "fuel",
+            ResourceKind::Ammo => "ammo",
+            ResourceKind::Style => "style",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResourceKind::BlockPoints => "BLOCK",
+            ResourceKind::KineticCharge => "KINETIC",
+            ResourceKind::Fuel => "FUEL",
+            ResourceKind::Ammo => "AMMO",
+            ResourceKind::Style => "STYLE",
+        }
+    }
+
+    fn color(&self) -> [f32; 4] {
+        match self {
+            ResourceKind::BlockPoints => [0.4, 0.7, 1.0, 1.0],
+            ResourceKind::KineticCharge => [0.0, 1.0, 0.0, 1.0],
+            ResourceKind::Fuel => [1.0, 0.5, 0.0, 1.0],
+            ResourceKind::Ammo => [1.0, 0.8, 0.2, 1.0],
+            ResourceKind::Style => [1.0, 0.3, 0.8, 1.0],
+        }
+    }
+
+    /// Block points and kinetic charge live in the BlockSystem's block/break/fatigue cycle;
+    /// their pools mirror it for the HUD (see Resources::sync_block).
+    pub fn is_block_driven(&self) -> bool {
+        matches!(self, ResourceKind::BlockPoints | ResourceKind::KineticCharge)
+    }
+}
+
+/// How a resource refills on its own.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Regen {
+    None,                                  // only actions and pickups refill it
+    Rate { per_second: f64, delay: f64 }, // starts `delay` seconds after the last spend
+    Reload { duration: f64 },             // refills all at once after a reload
+}
+
+/// Actions that cost or give resources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceAction {
+    Block,    // per blocked hit
+    Ride,     // per second on the bike (or in flight)
+    Shoot,    // per shot
+    Skill,    // per skill cast
+    MeleeHit, // per target hit by a melee strike
+    Kill,     // per kill
+}
+
+impl ResourceAction {
+    pub const ALL: [ResourceAction; 6] = [
+        ResourceAction::Block,
+        ResourceAction::Ride,
+        ResourceAction::Shoot,
+        ResourceAction::Skill,
+        ResourceAction::MeleeHit,
+        ResourceAction::Kill,
+    ];
+
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            ResourceAction::Block => "block",
+            ResourceAction::Ride => "ride",
+            ResourceAction::Shoot => "shoot",
+            ResourceAction::Skill => "skill",
+            ResourceAction::MeleeHit => "melee_hit",
+            ResourceAction::Kill => "kill",
+        }
+    }
+}
+
+/// A resource a fighter type uses.
+#[derive(Debug, Clone)]
+pub struct ResourceSpec {
+    pub kind: ResourceKind,
+    pub max: f64, // kinetic charge always matches the block points max
+    pub start: f64,
+    pub regen: Regen,
+}
+
+/// An amount of one resource tied to an action.
+#[derive(Debug, Clone, Copy)]
+pub struct ActionAmount {
+    pub action: ResourceAction,
+    pub kind: ResourceKind,
+    pub amount: f64,
+}
+
+/// Everything a fighter type declares about resources: which it uses, what actions cost
+/// and what they give back. At most one cost and one gain per action.
+#[derive(Debug, Clone)]
+pub struct ResourceProfile {
+    pub resources: Vec<ResourceSpec>,
+    pub costs: Vec<ActionAmount>,
+    pub gains: Vec<ActionAmount>,
+}
+
+impl ResourceProfile {
+    pub fn spec(&self, kind: ResourceKind) -> Option<&ResourceSpec> {
+        self.resources.iter().find(|spec| spec.kind == kind)
+    }
+
+    pub fn cost(&self, action: ResourceAction) -> Option<ActionAmount> {
+        self.costs.iter().find(|cost| cost.action == action).copied()
+    }
+
+    pub fn gain(&self, action: ResourceAction) -> Option<ActionAmount> {
+        self.gains.iter().find(|gain| gain.action == action).copied()
+    }
+}
+
+/// Resource profiles for every fighter type.
+#[derive(Debug, Clone)]
+pub struct ResourceProfiles {
+    pub profiles: HashMap<FighterType, ResourceProfile>,
+}
+
+fn default_spec(kind: ResourceKind) -> ResourceSpec {
+    let (max, start, regen) = match kind {
+        ResourceKind::BlockPoints => (20.0, 20.0, Regen::Rate { per_second: 5.0, delay: 1.25 }),
+        ResourceKind::KineticCharge => (20.0, 0.0, Regen::None),
+        ResourceKind::Fuel => (100.0, 100.0, Regen::None),
+        ResourceKind::Ammo => (25.0, 25.0, Regen::Reload { duration: 1.0 }),
+        ResourceKind::Style => (100.0, 100.0, Regen::Rate { per_second: 5.0, delay: 0.0 }),
+    };
+    ResourceSpec { kind, max, start, regen }
+}
+
+const fn amount(action: ResourceAction, kind: ResourceKind, amount: f64) -> ActionAmount {
+    ActionAmount { action, kind, amount }
+}
+
+impl Default for ResourceProfiles {
+    fn default() -> Self {
+        use ResourceAction::*;
+        use ResourceKind::*;
+        let profiles = HashMap::from([
+            (
+                FighterType::Racer,
+                ResourceProfile {
+                    resources: [BlockPoints, KineticCharge, Fuel, Style].into_iter().map(default_spec).collect(),
+                    costs: vec![amount(Block, BlockPoints, 1.0), amount(Ride, Fuel, 1.0), amount(Skill, Style, 20.0)],
+                    gains: vec![amount(MeleeHit, Style, 4.0), amount(Kill, Fuel, 1.0)],
+                },
+            ),
+            (
+                FighterType::Soldier,
+                ResourceProfile {
+                    resources: [BlockPoints, KineticCharge, Fuel, Ammo, Style].into_iter().map(default_spec).collect(),
+                    costs: vec![
+                        amount(Block, BlockPoints, 1.0),
+                        amount(Ride, Fuel, 1.0),
+                        amount(Shoot, Ammo, 1.0),
+                        amount(Skill, Style, 20.0),
+                    ],
+                    gains: vec![amount(MeleeHit, Style, 4.0), amount(Kill, Fuel, 1.0)],
+                },
+            ),
+            (
+                FighterType::Raptor,
+                // Raptor flies without fuel.
+                ResourceProfile {
+                    resources: [BlockPoints, KineticCharge, Style].into_iter().map(default_spec).collect(),
+                    costs: vec![amount(Block, BlockPoints, 1.0), amount(Skill, Style, 20.0)],
+                    gains: vec![amount(MeleeHit, Style, 4.0)],
+                },
+            ),
+        ]);
+        ResourceProfiles { profiles }
+    }
+}
+
+impl ResourceProfiles {
+    pub fn profile(&self, fighter_type: FighterType) -> &ResourceProfile {
+        &self.profiles[&fighter_type]
+    }
+
+    /// Applies a resource file. `[racer.fuel]` sections set up a resource (adding it if the
+    /// fighter doesn't use it yet; `enabled = false` removes it), `[racer.costs]` and
+    /// `[racer.gains]` map actions to `<resource> <amount>` (or `none`).
+    pub fn apply_file(&mut self, path: &Path, errors: &mut Vec<ConfigError>) {
+        let mut section: Option<(FighterType, Section)> = None;
+        read_ini(path, errors, |line| match line {
+            IniLine::Section(name) => {
+                section = None;
+                let (fighter_type, part) = parse_section(name)?;
+                if let Section::Resource(kind) = part {
+                    let profile = self.profile_mut(fighter_type);
+                    if profile.spec(kind).is_none() {
+                        profile.resources.push(default_spec(kind));
+                    }
+                }
+                section = Some((fighter_type, part));
+                Ok(())
+            }
+            IniLine::Entry { key, value, .. } => match section {
+                Some((fighter_type, Section::Resource(kind))) => {
+                    let profile = self.profile_mut(fighter_type);
+                    if key == "enabled" {
+                        parse_bool(value).map(|enabled| {
+                            if !enabled {
+                                profile.resources.retain(|spec| spec.kind != kind);
+                            } else if profile.spec(kind).is_none() {
+                                profile.resources.push(default_spec(kind));
+                            }
+                        })
+                    } else {
+                        match profile.resources.iter_mut().find(|spec| spec.kind == kind) {
+                            Some(spec) => set_spec_value(spec, key, value),
+                            None => Err("belongs to a resource disabled above".to_string()),
+                        }
+                    }
+                }
+                Some((fighter_type, Section::Costs)) => {
+                    set_action_amount(&mut self.profile_mut(fighter_type).costs, key, value)
+                }
+                Some((fighter_type, Section::Gains)) => {
+                    set_action_amount(&mut self.profile_mut(fighter_type).gains, key, value)
+                }
+                None => Err("is outside a [fighter.resource], [fighter.costs] or [fighter.gains] section".to_string()),
+            },
+        });
+    }
+
+    fn profile_mut(&mut self, fighter_type: FighterType) -> &mut ResourceProfile {
+        self.profiles.get_mut(&fighter_type).expect("every fighter type has a profile")
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Section {
+    Resource(ResourceKind),
+    Costs,
+    Gains,
+}
+
+/// `racer.fuel` -> (Racer, Resource(Fuel)), `racer.costs` -> (Racer, Costs).
+fn parse_section(name: &str) -> Result<(FighterType, Section), String> {
+    let Some((fighter, part)) = name.split_once('.') else {
+        return Err("expects a section named fighter.resource, fighter.costs or fighter.gains".to_string());
+    };
+    let fighter_type = FighterType::from_config_name(fighter)?;
+    let part = part.to_ascii_lowercase();
+    let section = match part.as_str() {
+        "costs" => Section::Costs,
+        "gains" => Section::Gains,
+        _ => Section::Resource(parse_kind(&part)?),
+    };
+    Ok((fighter_type, section))
+}
+
+fn parse_kind(value: &str) -> Result<ResourceKind, String> {
+    ResourceKind::ALL
+        .into_iter()
+        .find(|kind| kind.config_name() == value.to_ascii_lowercase())
+        .ok_or_else(|| {
+            format!(
+                "expects block_points, kinetic_charge, fuel, ammo, style, costs or gains, found '{}'",
+                value
+            )
+        })
+}
+
+fn set_spec_value(spec: &mut ResourceSpec, key: &str, value: &str) -> Result<(), String> {
+    match key {
+        "max" => spec.max = parse_ranged(value, 0.0, 10000.0)?,
+        "start" => spec.start = parse_ranged(value, 0.0, 10000.0)?,
+        "regen" => spec.regen = parse_regen(value)?,
+        _ => return Err("is not a known resource setting".to_string()),
+    }
+    Ok(())
+}
+
+/// `none`, `rate <per second> <delay>` or `reload <seconds>`.
+fn parse_regen(value: &str) -> Result<Regen, String> {
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    match parts.as_slice() {
+        ["none"] => Ok(Regen::None),
+        ["rate", per_second, delay] => Ok(Regen::Rate {
+            per_second: parse_ranged(per_second, 0.0, 10000.0)?,
+            delay: parse_ranged(delay, 0.0, 60.0)?,
+        }),
+        ["reload", duration] => Ok(Regen::Reload { duration: parse_ranged(duration, 0.0, 60.0)? }),
+        _ => Err(format!(
+            "expects none, rate <per second> <delay> or reload <seconds>, found '{}'",
+            value
+        )),
+    }
+}
+
+/// `shoot = ammo 1` sets the action's amount, `shoot = none` clears it.
+fn set_action_amount(amounts: &mut Vec<ActionAmount>, key: &str, value: &str) -> Result<(), String> {
+    let action = ResourceAction::ALL
+        .into_iter()
+        .find(|action| action.config_name() == key)
+        .ok_or_else(|| "is not an action (block, ride, shoot, skill, melee_hit or kill)".to_string())?;
+    amounts.retain(|a| a.action != action);
+    if value.eq_ignore_ascii_case("none") {
+        return Ok(());
+    }
+    let Some((kind, number)) = value.split_once(char::is_whitespace) else {
+        return Err(format!("expects '<resource> <amount>' or none, found '{}'", value));
+    };
+    let kind = parse_kind(kind)?;
+    // The BlockSystem runs block points and kinetic charge; it only knows the block cost.
+    if (action == ResourceAction::Block) != (kind == ResourceKind::BlockPoints) || kind == ResourceKind::KineticCharge {
+        return Err("block goes with block_points only, other actions can't use block_points or kinetic_charge".to_string());
+    }
+    amounts.push(ActionAmount {
+        action,
+        kind,
+        amount: parse_ranged(number.trim(), 0.0, 10000.0)?,
+    });
+    Ok(())
+}
+
+static RESOURCE_PROFILES: OnceLock<ResourceProfiles> = OnceLock::new();
+
+/// Loads resources.cfg from `dir` on top of the built-in profiles, if the file exists.
+pub fn load(dir: &Path) -> Result<ResourceProfiles, Vec<ConfigError>> {
+    let mut profiles = ResourceProfiles::default();
+    let mut errors = Vec::new();
+    let path = dir.join(RESOURCES_FILE_NAME);
+    if path.exists() {
+        profiles.apply_file(&path, &mut errors);
+        println!("[RESOURCE] Loaded resource profiles from {:?}", path);
+    }
+    if errors.is_empty() {
+        Ok(profiles)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Makes `profiles` the active resource profiles. Only the first call has an effect.
+pub fn install(profiles: ResourceProfiles) {
+    if RESOURCE_PROFILES.set(profiles).is_err() {
+        println!("[RESOURCE] Resource profiles already installed; ignoring reinstall.");
+    }
+}
+
+/// The active resource profiles, or the built-in ones if none were installed.
+pub fn get() -> &'static ResourceProfiles {
+    RESOURCE_PROFILES.get_or_init(ResourceProfiles::default)
+}
+
+/// Runtime state of one resource.
+#[derive(Debug, Clone)]
+pub struct ResourcePool {
+    pub kind: ResourceKind,
+    pub current: f64,
+    pub max: f64,
+    pub regen: Regen,
+    pub idle_timer: f64,   // seconds since the last spend, for Regen::Rate delays
+    pub reload_timer: f64, // seconds left on a Regen::Reload reload
+}
+
+impl ResourcePool {
+    fn new(spec: &ResourceSpec) -> Self {
+        ResourcePool {
+            kind: spec.kind,
+            current: spec.start.min(spec.max),
+            max: spec.max,
+            regen: spec.regen,
+            idle_timer: 0.0,
+            reload_timer: 0.0,
+        }
+    }
+
+    pub fn fraction(&self) -> f64 {
+        if self.max <= 0.0 {
+            return 0.0;
+        }
+        (self.current / self.max).clamp(0.0, 1.0)
+    }
+
+    pub fn is_reloading(&self) -> bool {
+        self.reload_timer > 0.0
+    }
+
+    fn update(&mut self, dt: f64) {
+        match self.regen {
+            Regen::None => {}
+            Regen::Rate { per_second, delay } => {
+                if self.idle_timer < delay {
+                    self.idle_timer += dt;
+                } else {
+                    self.current = (self.current + per_second * dt).min(self.max);
+                }
+            }
+            Regen::Reload { .. } => {
+                if self.reload_timer > 0.0 {
+                    self.reload_timer -= dt;
+                    if self.reload_timer <= 0.0 {
+                        self.reload_timer = 0.0;
+                        self.current = self.max;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A fighter's resource pools, set up from its type's profile.
+#[derive(Debug, Clone)]
+pub struct Resources {
+    pub fighter_type: FighterType,
+    pub pools: Vec<ResourcePool>,
+}
+
+impl Resources {
+    pub fn for_fighter(fighter_type: FighterType) -> Self {
+        Resources {
+            fighter_type,
+            pools: get().profile(fighter_type).resources.iter().map(ResourcePool::new).collect(),
+        }
+    }
+
+    fn profile(&self) -> &'static ResourceProfile {
+        get().profile(self.fighter_type)
+    }
+
+    pub fn get(&self, kind: ResourceKind) -> Option<&ResourcePool> {
+        self.pools.iter().find(|pool| pool.kind == kind)
+    }
+
+    fn get_mut(&mut self, kind: ResourceKind) -> Option<&mut ResourcePool> {
+        self.pools.iter_mut().find(|pool| pool.kind == kind)
+    }
+
+    pub fn uses(&self, kind: ResourceKind) -> bool {
+        self.get(kind).is_some()
+    }
+
+    /// Whether the action's cost, times `scale` (dt for per-second costs), is covered.
+    /// Actions without a cost, or costing a resource this fighter doesn't use, are free.
+    pub fn can_afford(&self, action: ResourceAction, scale: f64) -> bool {
+        let Some(cost) = self.profile().cost(action) else {
+            return true;
+        };
+        self.get(cost.kind).is_none_or(|pool| pool.current >= cost.amount * scale)
+    }
+
+    /// Pays the action's cost, times `scale`. If the resource runs short it is emptied and
+    /// false is returned. Block-driven costs are paid by the BlockSystem.
+    pub fn spend(&mut self, action: ResourceAction, scale: f64) -> bool {
+        let Some(cost) = self.profile().cost(action) else {
+            return true;
+        };
+        if cost.kind.is_block_driven() {
+            return true;
+        }
+        let Some(pool) = self.get_mut(cost.kind) else {
+            return true;
+        };
+        pool.idle_timer = 0.0;
+        let price = cost.amount * scale;
+        if pool.current < price {
+            pool.current = 0.0;
+            return false;
+        }
+        pool.current -= price;
+        true
+    }
+
+    /// Adds what the action gives back, if anything.
+    pub fn gain(&mut self, action: ResourceAction) {
+        if let Some(gain) = self.profile().gain(action) {
+            self.add(gain.kind, gain.amount);
+        }
+    }
+
+    pub fn add(&mut self, kind: ResourceKind, amount: f64) {
+        if kind.is_block_driven() {
+            return;
+        }
+        if let Some(pool) = self.get_mut(kind) {
+            pool.current = (pool.current + amount).min(pool.max);
+        }
+    }
+
+    pub fn refill(&mut self, kind: ResourceKind) {
+        if let Some(pool) = self.get_mut(kind) {
+            pool.current = pool.max;
+        }
+    }
+
+    /// Starts reloading a Regen::Reload resource that isn't full. Returns false if there
+    /// was nothing to reload or a reload is already running.
+    pub fn start_reload(&mut self, kind: ResourceKind) -> bool {
+        let Some(pool) = self.get_mut(kind) else {
+            return false;
+        };
+        let Regen::Reload { duration } = pool.regen else {
+            return false;
+        };
+        if pool.is_reloading() || pool.current >= pool.max {
+            return false;
+        }
+        pool.reload_timer = duration.max(f64::EPSILON);
+        true
+    }
+
+    pub fn is_reloading(&self, kind: ResourceKind) -> bool {
+        self.get(kind).is_some_and(ResourcePool::is_reloading)
+    }
+
+    pub fn update(&mut self, dt: f64) {
+        for pool in self.pools.iter_mut().filter(|pool| !pool.kind.is_block_driven()) {
+            pool.update(dt);
+        }
+    }
+
+    /// Copies block points and kinetic charge from the block system.
+    pub fn sync_block(&mut self, block_system: &BlockSystem) {
+        let max = block_system.max_block_count as f64;
+        if let Some(pool) = self.get_mut(ResourceKind::BlockPoints) {
+            pool.current = block_system.block_count.max(0) as f64;
+            pool.max = max;
+        }
+        if let Some(pool) = self.get_mut(ResourceKind::KineticCharge) {
+            pool.current = block_system.kinetic_intake_count.max(0) as f64;
+            pool.max = max;
+        }
+    }
+
+    /// One labelled bar per resource, in profile order, below the skill bar.
+    pub fn draw_hud(&self, c: Context, g: &mut G2d, glyphs: &mut Glyphs) {
+        let base_x = 20.0;
+        let base_y = 390.0;
+        let row_height = 20.0;
+        let bar_x = base_x + 70.0;
+        let bar_width = 120.0;
+        let bar_height = 10.0;
+
+        let rows = self.pools.len() as f64;
+        rectangle(
+            [0.0, 0.0, 0.0, 0.7],
+            [base_x - 5.0, base_y - 5.0, bar_x - base_x + bar_width + 70.0, rows * row_height + 5.0],
+            c.transform,
+            g,
+        );
+        for (i, pool) in self.pools.iter().enumerate() {
+            let y = base_y + i as f64 * row_height;
+            let color = if pool.is_reloading() { [1.0, 0.0, 0.0, 1.0] } else { pool.kind.color() };
+            let value = if pool.is_reloading() {
+                "RELOAD".to_string()
+            } else {
+                format!("{:.0}/{:.0}", pool.current.max(0.0), pool.max)
+            };
+
+            text::Text::new_color(color, 13)
+                .draw(pool.kind.label(), glyphs, &c.draw_state, c.transform.trans(base_x, y + 11.0), g)
+                .ok();
+            rectangle([0.2, 0.2, 0.2, 1.0], [bar_x, y, bar_width, bar_height], c.transform, g);
+            rectangle(color, [bar_x, y, bar_width * pool.fraction(), bar_height], c.transform, g);
+            text::Text::new_color(color, 13)
+                .draw(&value, glyphs, &c.draw_state, c.transform.trans(bar_x + bar_width + 8.0, y + 11.0), g)
+                .ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::load_test_file;
+
+    fn load_resources(test_name: &str, contents: &str) -> Result<ResourceProfiles, Vec<ConfigError>> {
+        load_test_file(test_name, RESOURCES_FILE_NAME, contents, load)
+    }
+
+    fn messages(errors: &[ConfigError]) -> Vec<(&str, &str)> {
+        errors.iter().map(|e| (e.key.as_str(), e.message.as_str())).collect()
+    }
+
+    #[test]
+    fn applies_resources_costs_and_gains() {
+        let profiles = load_resources(
+            "resource_apply",
+            "[raptor.ammo]\nmax = 10\nregen = reload 2\n[raptor.costs]\nshoot = ammo 2\n[racer.fuel]\nenabled = false\n[racer.costs]\nride = none\n[racer.gains]\nkill = style 10\n",
+        )
+        .expect("resource file should load");
+        let raptor = profiles.profile(FighterType::Raptor);
+        let ammo = raptor.spec(ResourceKind::Ammo).expect("section adds the resource");
+        assert_eq!((ammo.max, ammo.start), (10.0, 25.0));
+        assert_eq!(ammo.regen, Regen::Reload { duration: 2.0 });
+        assert_eq!(raptor.cost(ResourceAction::Shoot).map(|c| (c.kind, c.amount)), Some((ResourceKind::Ammo, 2.0)));
+
+        let racer = profiles.profile(FighterType::Racer);
+        assert!(racer.spec(ResourceKind::Fuel).is_none());
+        assert!(racer.cost(ResourceAction::Ride).is_none());
+        assert_eq!(racer.gain(ResourceAction::Kill).map(|g| (g.kind, g.amount)), Some((ResourceKind::Style, 10.0)));
+    }
+
+    #[test]
+    fn reports_bad_sections() {
+        let errors = load_resources("resource_sections", "max = 1\n[racer]\n[racer.mana]\n[ninja.fuel]\n")
+            .expect_err("bad sections should fail");
+        let keys: Vec<&str> = errors.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, vec!["max", "racer", "racer.mana", "ninja.fuel"]);
+        assert_eq!(errors[1].message, "expects a section named fighter.resource, fighter.costs or fighter.gains");
+    }
+
+    #[test]
+    fn settings_after_disabling_a_resource_are_refused() {
+        let errors = load_resources("resource_disabled", "[soldier.fuel]\nenabled = false\nmax = 5\nregen = rate 5\n")
+            .expect_err("disabled resource settings should fail");
+        assert_eq!(
+            messages(&errors),
+            vec![
+                ("soldier.fuel.max", "belongs to a resource disabled above"),
+                ("soldier.fuel.regen", "belongs to a resource disabled above"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_regen_values() {
+        assert_eq!(parse_regen("none"), Ok(Regen::None));
+        assert_eq!(parse_regen("rate 5 1.5"), Ok(Regen::Rate { per_second: 5.0, delay: 1.5 }));
+        assert_eq!(parse_regen("reload 2"), Ok(Regen::Reload { duration: 2.0 }));
+        assert!(parse_regen("rate 5").is_err());
+        assert!(parse_regen("reload 61").is_err());
+    }
+
+    #[test]
+    fn block_points_only_pay_for_blocks() {
+        let errors = load_resources(
+            "resource_costs",
+            "[soldier.costs]\nshoot = block_points 1\nblock = fuel 1\nskill = kinetic_charge 1\njump = fuel 1\nride = fuel\n",
+        )
+        .expect_err("bad costs should fail");
+        let pairing = "block goes with block_points only, other actions can't use block_points or kinetic_charge";
+        assert_eq!(
+            messages(&errors),
+            vec![
+                ("soldier.costs.shoot", pairing),
+                ("soldier.costs.block", pairing),
+                ("soldier.costs.skill", pairing),
+                ("soldier.costs.jump", "is not an action (block, ride, shoot, skill, melee_hit or kill)"),
+                ("soldier.costs.ride", "expects '<resource> <amount>' or none, found 'fuel'"),
+            ]
+        );
+    }
+
+    #[test]
+    fn spending_stops_at_what_is_left() {
+        let mut resources = Resources::for_fighter(FighterType::Soldier);
+        assert!(resources.can_afford(ResourceAction::Shoot, 25.0));
+        assert!(resources.spend(ResourceAction::Shoot, 25.0));
+        assert!(!resources.can_afford(ResourceAction::Shoot, 1.0));
+        assert!(!resources.spend(ResourceAction::Shoot, 1.0));
+        // Actions without a cost are free.
+        assert!(resources.spend(ResourceAction::MeleeHit, 1.0));
+
+        assert!(resources.start_reload(ResourceKind::Ammo));
+        assert!(!resources.start_reload(ResourceKind::Ammo));
+        resources.update(1.0);
+        assert!(!resources.is_reloading(ResourceKind::Ammo));
+        assert!(resources.can_afford(ResourceAction::Shoot, 25.0));
+        assert!(!Resources::for_fighter(FighterType::Raptor).uses(ResourceKind::Ammo));
+    }
+}
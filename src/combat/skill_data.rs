@@ -0,0 +1,382 @@
+// File: src/combat/skill_data.rs
+
+use crate::combat::damage::DamageType;
+use crate::combat::skills::SkillType;
+use crate::combat::status_effects::StatusEffect;
+use crate::config::{parse_ranged, read_ini, ConfigError, IniLine};
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Name of the skill definition file looked up next to the executable.
+pub const SKILLS_FILE_NAME: &str = "skills.cfg";
+
+/// How a skill reaches its target. The skill runner (combat::skill_runner) executes each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillShape {
+    Teleport,   // blink next to the target and hit around the landing point
+    Projectile, // fire a pulse orb toward the target
+    Area,       // hit everything around the caster
+    Dash,       // run through toward the target, invulnerable for `duration`
+    Tether,     // latch onto the target and pull it toward the caster
+    Turret,     // deploy a turret that shoots at targets in `range`
+}
+
+impl SkillShape {
+    /// Teleports and tethers need a target in range; the rest are aimed at a point.
+    pub fn needs_target(&self) -> bool {
+        matches!(self, SkillShape::Teleport | SkillShape::Tether)
+    }
+}
+
+/// Effect drawn when a skill is cast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillVisual {
+    None,
+    Flicker, // flicker strike afterimage and strike flash
+    Ring,    // expanding ring around the caster
+    Trail,   // streak along the dash path
+    Tether,  // line between caster and target
+}
+
+#[derive(Debug, Clone)]
+pub struct SkillDefinition {
+    pub shape: SkillShape,
+    pub cooldown: f64,
+    pub range: f64,     // target reach, dash length, or turret targeting range
+    pub min_range: f64, // CPUs don't use the skill on a player closer than this
+    pub damage: f64,     // multiplier of the player's melee damage (ranged for projectiles/turrets)
+    pub cpu_damage: f64, // multiplier of a CPU's damage value
+    pub damage_type: DamageType,
+    pub radius: f64,   // hit radius: landing point, area, dash path, orb or turret shot
+    pub speed: f64,    // orb and turret shot speed, or tether pull distance
+    pub duration: f64, // orb lifetime, dash invulnerability, or turret lifetime
+    pub interval: f64, // seconds between turret shots
+    pub status: Option<StatusEffect>, // applied to everything the skill hits
+    pub visual: SkillVisual,
+}
+
+/// Definitions for every skill type, in SkillType::ALL order.
+#[derive(Debug, Clone)]
+pub struct SkillDefinitions {
+    pub skills: Vec<SkillDefinition>,
+}
+
+fn definition(shape: SkillShape, cooldown: f64, range: f64, damage_type: DamageType) -> SkillDefinition {
+    SkillDefinition {
+        shape,
+        cooldown,
+        range,
+        min_range: 0.0,
+        damage: 1.0,
+        cpu_damage: 1.0,
+        damage_type,
+        radius: 0.0,
+        speed: 0.0,
+        duration: 0.0,
+        interval: 0.0,
+        status: None,
+        visual: SkillVisual::None,
+    }
+}
+
+fn default_definition(skill_type: SkillType) -> SkillDefinition {
+    match skill_type {
+        SkillType::FlickerStrike => SkillDefinition {
+            damage: 2.0,
+            cpu_damage: 0.25,
+            radius: 60.0,
+            visual: SkillVisual::Flicker,
+            ..definition(SkillShape::Teleport, 3.25, 500.0, DamageType::Slash)
+        },
+        SkillType::PulseOrb => SkillDefinition {
+            min_range: 100.0,
+            damage: 1.5,
+            radius: 10.0,
+            speed: 750.0,
+            duration: 3.0,
+            ..definition(SkillShape::Projectile, 2.0, 600.0, DamageType::Void)
+        },
+        SkillType::GroundSlam => SkillDefinition {
+            damage: 1.5,
+            radius: 180.0,
+            status: Some(StatusEffect::slow(0.3, 2.0)),
+            visual: SkillVisual::Ring,
+            ..definition(SkillShape::Area, 6.0, 180.0, DamageType::Kinetic)
+        },
+        SkillType::Dash => SkillDefinition {
+            min_range: 120.0,
+            cpu_damage: 0.5,
+            radius: 40.0,
+            duration: 0.35,
+            visual: SkillVisual::Trail,
+            ..definition(SkillShape::Dash, 4.0, 350.0, DamageType::Slash)
+        },
+        SkillType::Tether => SkillDefinition {
+            min_range: 150.0,
+            damage: 0.5,
+            cpu_damage: 0.25,
+            radius: 40.0,
+            speed: 300.0,
+            status: Some(StatusEffect::vulnerable(0.2, 3.0)),
+            visual: SkillVisual::Tether,
+            ..definition(SkillShape::Tether, 5.0, 450.0, DamageType::Void)
+        },
+        SkillType::Turret => SkillDefinition {
+            damage: 0.5,
+            cpu_damage: 0.5,
+            radius: 8.0,
+            speed: 750.0,
+            duration: 8.0,
+            interval: 0.6,
+            status: Some(StatusEffect::taunt(2.0)),
+            ..definition(SkillShape::Turret, 12.0, 450.0, DamageType::Ballistic)
+        },
+    }
+}
+
+impl Default for SkillDefinitions {
+    fn default() -> Self {
+        SkillDefinitions {
+            skills: SkillType::ALL.into_iter().map(default_definition).collect(),
+        }
+    }
+}
+
+impl SkillDefinitions {
+    pub fn definition(&self, skill_type: SkillType) -> &SkillDefinition {
+        &self.skills[skill_type as usize]
+    }
+
+    /// Applies a skill file: `[flicker_strike]`, `[pulse_orb]`, ... sections of
+    /// `key = value` settings on top of the current definitions.
+    pub fn apply_file(&mut self, path: &Path, errors: &mut Vec<ConfigError>) {
+        let mut section: Option<SkillType> = None;
+        read_ini(path, errors, |line| match line {
+            IniLine::Section(name) => {
+                section = SkillType::ALL
+                    .into_iter()
+                    .find(|st| st.config_name() == name.to_ascii_lowercase());
+                match section {
+                    Some(_) => Ok(()),
+                    None => Err("is not a skill type".to_string()),
+                }
+            }
+            IniLine::Entry { key, value, .. } => match section {
+                Some(skill_type) => set_value(&mut self.skills[skill_type as usize], key, value),
+                None => Err("is outside a [skill] section".to_string()),
+            },
+        });
+    }
+}
+
+fn set_value(skill: &mut SkillDefinition, key: &str, value: &str) -> Result<(), String> {
+    match key {
+        "shape" => skill.shape = parse_shape(value)?,
+        "cooldown" => skill.cooldown = parse_ranged(value, 0.0, 120.0)?,
+        "range" => skill.range = parse_ranged(value, 0.0, 5000.0)?,
+        "min_range" => skill.min_range = parse_ranged(value, 0.0, 5000.0)?,
+        "damage" => skill.damage = parse_ranged(value, 0.0, 20.0)?,
+        "cpu_damage" => skill.cpu_damage = parse_ranged(value, 0.0, 20.0)?,
+        "damage_type" => skill.damage_type = parse_damage_type(value)?,
+        "radius" => skill.radius = parse_ranged(value, 0.0, 2000.0)?,
+        "speed" => skill.speed = parse_ranged(value, 0.0, 5000.0)?,
+        "duration" => skill.duration = parse_ranged(value, 0.0, 120.0)?,
+        "interval" => skill.interval = parse_ranged(value, 0.05, 60.0)?,
+        "status" => skill.status = parse_status(value)?,
+        "visual" => skill.visual = parse_visual(value)?,
+        _ => return Err("is not a known skill setting".to_string()),
+    }
+    Ok(())
+}
+
+fn parse_shape(value: &str) -> Result<SkillShape, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "teleport" => Ok(SkillShape::Teleport),
+        "projectile" => Ok(SkillShape::Projectile),
+        "area" => Ok(SkillShape::Area),
+        "dash" => Ok(SkillShape::Dash),
+        "tether" => Ok(SkillShape::Tether),
+        "turret" => Ok(SkillShape::Turret),
+        _ => Err(format!(
+            "expects teleport, projectile, area, dash, tether or turret, found '{}'",
+            value
+        )),
+    }
+}
+
+fn parse_visual(value: &str) -> Result<SkillVisual, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "none" => Ok(SkillVisual::None),
+        "flicker" => Ok(SkillVisual::Flicker),
+        "ring" => Ok(SkillVisual::Ring),
+        "trail" => Ok(SkillVisual::Trail),
+        "tether" => Ok(SkillVisual::Tether),
+        _ => Err(format!("expects none, flicker, ring, trail or tether, found '{}'", value)),
+    }
+}
+
+fn parse_damage_type(value: &str) -> Result<DamageType, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "slash" => Ok(DamageType::Slash),
+        "ballistic" => Ok(DamageType::Ballistic),
+        "kinetic" => Ok(DamageType::Kinetic),
+        "void" => Ok(DamageType::Void),
+        _ => Err(format!("expects slash, ballistic, kinetic or void, found '{}'", value)),
+    }
+}
+
+/// `none`, `bleed <total damage>`, `slow <fraction> <seconds>`, `attack_slow <fraction> <seconds>`,
+/// `vulnerable <fraction> <seconds>`, `immune <seconds>` or `taunt <seconds>`.
+fn parse_status(value: &str) -> Result<Option<StatusEffect>, String> {
+    let mut parts = value.split_whitespace();
+    let kind = parts.next().unwrap_or_default().to_ascii_lowercase();
+    let args: Vec<&str> = parts.collect();
+    let expect = |count: usize| {
+        if args.len() == count {
+            Ok(())
+        } else {
+            Err(format!("'{}' expects {} value(s), found {}", kind, count, args.len()))
+        }
+    };
+    let effect = match kind.as_str() {
+        "none" => {
+            expect(0)?;
+            return Ok(None);
+        }
+        "bleed" => {
+            expect(1)?;
+            StatusEffect::bleed(parse_ranged(args[0], 0.0, 1000.0)?)
+        }
+        "slow" | "attack_slow" | "vulnerable" => {
+            expect(2)?;
+            let fraction = parse_ranged(args[0], 0.0, 1.0)?;
+            let duration = parse_ranged(args[1], 0.0, 60.0)?;
+            match kind.as_str() {
+                "slow" => StatusEffect::slow(fraction, duration),
+                "attack_slow" => StatusEffect::attack_slow(fraction, duration),
+                _ => StatusEffect::vulnerable(fraction, duration),
+            }
+        }
+        "immune" | "taunt" => {
+            expect(1)?;
+            let duration = parse_ranged(args[0], 0.0, 60.0)?;
+            if kind == "immune" {
+                StatusEffect::immune(duration)
+            } else {
+                StatusEffect::taunt(duration)
+            }
+        }
+        _ => {
+            return Err(format!(
+                "expects none, bleed, slow, attack_slow, vulnerable, immune or taunt, found '{}'",
+                value
+            ))
+        }
+    };
+    Ok(Some(effect))
+}
+
+static SKILL_DEFINITIONS: OnceLock<SkillDefinitions> = OnceLock::new();
+
+/// Loads skills.cfg from `dir` on top of the built-in definitions, if the file exists.
+pub fn load(dir: &Path) -> Result<SkillDefinitions, Vec<ConfigError>> {
+    let mut definitions = SkillDefinitions::default();
+    let mut errors = Vec::new();
+    let path = dir.join(SKILLS_FILE_NAME);
+    if path.exists() {
+        definitions.apply_file(&path, &mut errors);
+        println!("[SKILL] Loaded skill definitions from {:?}", path);
+    }
+    if errors.is_empty() {
+        Ok(definitions)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Makes `definitions` the active skill definitions. Only the first call has an effect.
+pub fn install(definitions: SkillDefinitions) {
+    if SKILL_DEFINITIONS.set(definitions).is_err() {
+        println!("[SKILL] Skill definitions already installed; ignoring reinstall.");
+    }
+}
+
+/// The active skill definitions, or the built-in ones if none were installed.
+pub fn get() -> &'static SkillDefinitions {
+    SKILL_DEFINITIONS.get_or_init(SkillDefinitions::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::status_effects::StatusKind;
+    use crate::config::load_test_file;
+
+    fn load_skills(test_name: &str, contents: &str) -> Result<SkillDefinitions, Vec<ConfigError>> {
+        load_test_file(test_name, SKILLS_FILE_NAME, contents, load)
+    }
+
+    fn messages(errors: &[ConfigError]) -> Vec<(&str, &str)> {
+        errors.iter().map(|e| (e.key.as_str(), e.message.as_str())).collect()
+    }
+
+    #[test]
+    fn applies_skill_settings() {
+        let definitions = load_skills(
+            "skill_apply",
+            "[DASH]\nshape = area\ncooldown = 1.5\ndamage_type = VOID\nstatus = bleed 30\nvisual = ring\n[turret]\nstatus = none\n",
+        )
+        .expect("skill file should load");
+        let dash = definitions.definition(SkillType::Dash);
+        assert_eq!(dash.shape, SkillShape::Area);
+        assert_eq!(dash.cooldown, 1.5);
+        assert_eq!(dash.damage_type, DamageType::Void);
+        assert_eq!(dash.visual, SkillVisual::Ring);
+        assert_eq!(dash.status.as_ref().map(|s| s.kind), Some(StatusKind::Bleed));
+        // Unset keys keep their built-in values.
+        assert_eq!(dash.range, 350.0);
+        assert!(definitions.definition(SkillType::Turret).status.is_none());
+        assert_eq!(definitions.definition(SkillType::GroundSlam).shape, SkillShape::Area);
+    }
+
+    #[test]
+    fn parses_status_arguments() {
+        let slow = parse_status("slow 0.5 2").unwrap().unwrap();
+        assert_eq!((slow.kind, slow.magnitude, slow.duration), (StatusKind::Slow, 0.5, 2.0));
+        assert_eq!(parse_status("taunt 3").unwrap().unwrap().kind, StatusKind::Taunt);
+        assert_eq!(parse_status("slow 0.5").unwrap_err(), "'slow' expects 2 value(s), found 1");
+        assert_eq!(parse_status("none 1").unwrap_err(), "'none' expects 0 value(s), found 1");
+        assert!(parse_status("vulnerable 2 1").is_err());
+        assert!(parse_status("freeze 1").is_err());
+    }
+
+    #[test]
+    fn reports_unknown_skills_and_settings() {
+        let errors = load_skills("skill_sections", "cooldown = 1\n[fireball]\nrange = 10\n[pulse_orb]\nlaser = on\n")
+            .expect_err("bad skill file should fail");
+        assert_eq!(
+            messages(&errors),
+            vec![
+                ("cooldown", "is outside a [skill] section"),
+                ("fireball", "is not a skill type"),
+                ("fireball.range", "is outside a [skill] section"),
+                ("pulse_orb.laser", "is not a known skill setting"),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_bad_shapes_visuals_and_ranges() {
+        let errors = load_skills(
+            "skill_values",
+            "[tether]\nshape = spiral\nvisual = sparkle\ndamage_type = fire\ninterval = 0\nstatus = slow 2 1\n",
+        )
+        .expect_err("bad skill values should fail");
+        let keys: Vec<&str> = errors.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec!["tether.shape", "tether.visual", "tether.damage_type", "tether.interval", "tether.status"]
+        );
+        assert_eq!(errors[3].message, "must be between 0.05 and 60, found 0");
+    }
+}
@@ -0,0 +1,392 @@
+// File: src/combat/skill_runner.rs
+
+use crate::combat::damage::{self, Attack, DamageType, Defense};
+use crate::combat::hitbox::{self, Hitbox, Hurtbox};
+use crate::combat::skill_data::{self, SkillDefinition, SkillShape, SkillVisual};
+use crate::combat::skills::SkillType;
+use crate::combat::stats::AttackStats;
+use crate::combat::status_effects::StatusEffect;
+use crate::combat::threat::ThreatTarget;
+use crate::entities::cpu_entity::CpuEntity;
+use crate::entities::cpu_racer::CpuRacer;
+use crate::entities::fighter::Fighter;
+use crate::entities::pulse_orb::PulseOrb;
+use crate::entities::turret::Turret;
+use crate::game_state::EntityState;
+use crate::utils::vec2d::Vec2d;
+use crate::DamageText;
+use piston_window::*;
+
+/// How long a tether pull carries its target.
+const PULL_DURATION: f64 = 0.2;
+/// Gap a tether pull leaves between the target and the caster.
+const PULL_STOP_SHORT: f64 = 60.0;
+
+/// One use of a skill by a Fighter or a CpuEntity: where the caster ends up and what
+/// it hits. Player casts hit hostile CPUs; CPU casts hit the target they were aimed at,
+/// the fighter or a friendly CPU racer.
+#[derive(Debug, Clone)]
+pub struct SkillCast {
+    pub skill_type: SkillType,
+    pub from_player: bool,
+    pub from: (f64, f64),       // caster position when cast
+    pub to: (f64, f64),         // caster position afterwards (moved by teleports and dashes)
+    pub target: (f64, f64),     // aim point or targeted combatant
+    pub hitbox: Option<Hitbox>, // immediate hit area; projectiles and turrets hit later
+    pub damage: f64,            // before the target's resistances
+    pub damage_type: DamageType,
+    pub status: Option<StatusEffect>,
+    pub pull: f64,            // tether pull distance
+    pub invulnerability: f64, // granted to a Fighter caster
+}
+
+/// Walls a cast can't move its caster past: (min_x, max_x, min_y, max_y).
+pub type Bounds = (f64, f64, f64, f64);
+
+/// A cast by the player. Projectiles and turrets scale off ranged damage, everything
+/// else off melee damage.
+pub fn fighter_cast(skill_type: SkillType, fighter: &Fighter, target: (f64, f64), bounds: Bounds) -> SkillCast {
+    let base_damage = match skill_data::get().definition(skill_type).shape {
+        SkillShape::Projectile | SkillShape::Turret => fighter.ranged_damage,
+        _ => fighter.melee_damage,
+    };
+    cast(skill_type, true, (fighter.x, fighter.y), target, base_damage, bounds)
+}
+
+/// A cast by a CPU, scaled off its damage value.
+pub fn cpu_cast(skill_type: SkillType, cpu: &CpuEntity, target: (f64, f64), bounds: Bounds) -> SkillCast {
+    cast(skill_type, false, (cpu.x, cpu.y), target, cpu.damage_value, bounds)
+}
+
+fn cast(
+    skill_type: SkillType,
+    from_player: bool,
+    from: (f64, f64),
+    target: (f64, f64),
+    base_damage: f64,
+    bounds: Bounds,
+) -> SkillCast {
+    let def = skill_data::get().definition(skill_type);
+    let dx = target.0 - from.0;
+    let dy = target.1 - from.1;
+    let dist = (dx * dx + dy * dy).sqrt();
+    let (dir_x, dir_y) = if dist > 0.0 { (dx / dist, dy / dist) } else { (1.0, 0.0) };
+
+    let (to, hitbox) = match def.shape {
+        SkillShape::Teleport => {
+            // Land just short of the target, on the side the caster came from.
+            let stand_off = (def.radius / 2.0).min(dist);
+            let to = clamp_to(bounds, target.0 - dir_x * stand_off, target.1 - dir_y * stand_off);
+            (to, Some(Hitbox::circle(to.0, to.1, def.radius)))
+        }
+        SkillShape::Area => (from, Some(Hitbox::circle(from.0, from.1, def.radius))),
+        SkillShape::Dash => {
+            let to = clamp_to(bounds, from.0 + dir_x * def.range, from.1 + dir_y * def.range);
+            let path = Hitbox::Capsule { x1: from.0, y1: from.1, x2: to.0, y2: to.1, radius: def.radius };
+            (to, Some(path))
+        }
+        SkillShape::Tether => (from, Some(Hitbox::circle(target.0, target.1, def.radius))),
+        SkillShape::Projectile | SkillShape::Turret => (from, None),
+    };
+    if let Some(hitbox) = hitbox {
+        hitbox::record(hitbox);
+    }
+
+    let multiplier = if from_player { def.damage } else { def.cpu_damage };
+    SkillCast {
+        skill_type,
+        from_player,
+        from,
+        to,
+        target,
+        hitbox,
+        damage: base_damage * multiplier,
+        damage_type: def.damage_type,
+        status: def.status.clone(),
+        pull: if def.shape == SkillShape::Tether { def.speed } else { 0.0 },
+        invulnerability: if def.shape == SkillShape::Dash { def.duration } else { 0.0 },
+    }
+}
+
+fn clamp_to((min_x, max_x, min_y, max_y): Bounds, x: f64, y: f64) -> (f64, f64) {
+    (x.clamp(min_x, max_x), y.clamp(min_y, max_y))
+}
+
+/// Position of the nearest hostile CPU whose hurtbox is within `range` of `from`.
+/// Targets player teleports, tethers and turrets.
+pub fn nearest_cpu(from: (f64, f64), range: f64, cpu_entities: &[CpuEntity]) -> Option<(f64, f64)> {
+    cpu_entities
+        .iter()
+        .filter(|cpu| cpu.entity_state != EntityState::Friendly && !cpu.is_dead())
+        .filter(|cpu| Hurtbox::for_cpu(cpu).distance_to(from.0, from.1) <= range)
+        .min_by(|a, b| {
+            let da = (a.x - from.0).powi(2) + (a.y - from.1).powi(2);
+            let db = (b.x - from.0).powi(2) + (b.y - from.1).powi(2);
+            da.total_cmp(&db)
+        })
+        .map(|cpu| (cpu.x, cpu.y))
+}
+
+/// Knockback velocity that carries a combatant at `at` toward `toward` by up to
+/// `distance` over PULL_DURATION.
+fn pull_velocity(at: (f64, f64), toward: (f64, f64), distance: f64) -> Vec2d {
+    let dx = toward.0 - at.0;
+    let dy = toward.1 - at.1;
+    let dist = (dx * dx + dy * dy).sqrt();
+    let travel = distance.min(dist - PULL_STOP_SHORT);
+    if dist <= 0.0 || travel <= 0.0 {
+        return Vec2d::new(0.0, 0.0);
+    }
+    let speed = travel / PULL_DURATION;
+    Vec2d::new(dx / dist * speed, dy / dist * speed)
+}
+
+/// Deals a skill hit to a CPU and shows the damage text. Returns false if it was avoided.
+fn hit_cpu(
+    cpu: &mut CpuEntity,
+    attack: Attack,
+    status: Option<&StatusEffect>,
+    damage_texts: &mut Vec<DamageText>,
+) -> bool {
+    if cpu.entity_state == EntityState::Neutral {
+        cpu.entity_state = EntityState::Hostile;
+    }
+    let hit = damage::resolve(&attack, &Defense::cpu(cpu));
+    damage_texts.push(hit.text(cpu.x, cpu.y - 50.0));
+    if !hit.landed() {
+        return false;
+    }
+    cpu.current_hp -= hit.amount;
+    cpu.threat.add(ThreatTarget::Player, hit.threat);
+    if let Some(status) = status {
+        cpu.status_effects.apply(status.clone());
+    }
+    true
+}
+
+/// Checks a player-fired orb against hostile CPUs and spends it on the first one it reaches.
+/// `attack_stats` are the player's, for crit and variance.
+pub fn projectile_hits_cpus(
+    orb: &mut PulseOrb,
+    attack_stats: &AttackStats,
+    cpu_entities: &mut [CpuEntity],
+    damage_texts: &mut Vec<DamageText>,
+) {
+    let orb_hitbox = Hitbox::circle(orb.x, orb.y, orb.radius);
+    let Some(target) = cpu_entities.iter_mut().find(|cpu| {
+        cpu.entity_state != EntityState::Friendly && !cpu.is_dead() && orb_hitbox.hits(&Hurtbox::for_cpu(cpu))
+    }) else {
+        return;
+    };
+    hitbox::record(orb_hitbox);
+    hitbox::record_hit(&Hurtbox::for_cpu(target));
+    let attack = Attack::with_stats(orb.damage, orb.damage_type, attack_stats);
+    hit_cpu(target, attack, orb.status.as_ref(), damage_texts);
+    orb.active = false;
+}
+
+impl SkillCast {
+    pub fn definition(&self) -> &'static SkillDefinition {
+        skill_data::get().definition(self.skill_type)
+    }
+
+    /// Sound effect played on cast.
+    pub fn sound(&self) -> &'static str {
+        match self.definition().shape {
+            SkillShape::Projectile | SkillShape::Turret => "firearm",
+            _ => "rush",
+        }
+    }
+
+    /// Resolves a player cast: moves the fighter for teleports and dashes, then hits
+    /// every hostile CPU in the hitbox.
+    pub fn apply_player_cast(&self, fighter: &mut Fighter, cpu_entities: &mut [CpuEntity], damage_texts: &mut Vec<DamageText>) {
+        fighter.x = self.to.0;
+        fighter.y = self.to.1;
+        fighter.invincible_timer = fighter.invincible_timer.max(self.invulnerability);
+        let Some(hitbox) = self.hitbox else {
+            return;
+        };
+        for cpu in cpu_entities.iter_mut() {
+            let hurtbox = Hurtbox::for_cpu(cpu);
+            if cpu.entity_state == EntityState::Friendly || cpu.is_dead() || !hitbox.hits(&hurtbox) {
+                continue;
+            }
+            hitbox::record_hit(&hurtbox);
+            let attack = Attack::with_stats(self.damage, self.damage_type, &fighter.stats.attack);
+            if !hit_cpu(cpu, attack, self.status.as_ref(), damage_texts) {
+                continue;
+            }
+            if self.pull > 0.0 {
+                cpu.knockback_velocity = pull_velocity((cpu.x, cpu.y), self.to, self.pull);
+                cpu.knockback_duration = PULL_DURATION;
+            }
+        }
+    }
+
+    /// Whether a CPU cast's immediate hit reaches the fighter.
+    pub fn hits_fighter(&self, fighter: &Fighter) -> bool {
+        self.hitbox.is_some_and(|hitbox| hitbox.hits(&Hurtbox::for_fighter(fighter)))
+    }
+
+    /// Status effect and tether pull of a CPU cast that got through to the fighter.
+    /// Damage goes through the caller's block and invincibility handling.
+    pub fn afflict_fighter(&self, fighter: &mut Fighter) {
+        if let Some(status) = &self.status {
+            fighter.status_effects.apply(status.clone());
+        }
+        if self.pull > 0.0 {
+            fighter.knockback_velocity = pull_velocity((fighter.x, fighter.y), self.to, self.pull);
+            fighter.knockback_duration = PULL_DURATION;
+        }
+    }
+
+    /// Resolves a CPU cast aimed at a friendly CPU racer: damage, status effect and pull.
+    pub fn apply_to_racer(&self, cr: &mut CpuRacer, damage_texts: &mut Vec<DamageText>) {
+        let hurtbox = Hurtbox::for_racer(cr);
+        if cr.is_crashed || cr.invincible_timer > 0.0 || !self.hitbox.is_some_and(|hitbox| hitbox.hits(&hurtbox)) {
+            return;
+        }
+        hitbox::record_hit(&hurtbox);
+        let hit = damage::resolve(&Attack::cpu(self.damage, self.damage_type), &Defense::cpu_racer(cr));
+        damage_texts.push(hit.text(cr.x, cr.y - 50.0));
+        if !hit.landed() {
+            return;
+        }
+        cr.current_hp -= hit.amount;
+        if let Some(status) = &self.status {
+            cr.status_effects.apply(status.clone());
+        }
+        if self.pull > 0.0 {
+            cr.knockback_velocity = pull_velocity((cr.x, cr.y), self.to, self.pull);
+            cr.knockback_duration = PULL_DURATION;
+        }
+    }
+
+    /// Adds the orb, turret and effect the cast leaves behind.
+    pub fn spawn(&self, pulse_orbs: &mut Vec<PulseOrb>, turrets: &mut Vec<Turret>, skill_effects: &mut Vec<SkillEffect>) {
+        let def = self.definition();
+        match def.shape {
+            SkillShape::Projectile => pulse_orbs.push(PulseOrb {
+                radius: def.radius,
+                lifetime: def.duration,
+                from_player: self.from_player,
+                damage: self.damage,
+                damage_type: self.damage_type,
+                status: self.status.clone(),
+                ..PulseOrb::new(self.from.0, self.from.1, self.target.0, self.target.1, def.speed)
+            }),
+            SkillShape::Turret => turrets.push(Turret {
+                x: self.from.0,
+                y: self.from.1,
+                range: def.range,
+                lifetime: def.duration,
+                max_lifetime: def.duration,
+                interval: def.interval,
+                fire_timer: 0.0,
+                aim: self.target,
+                from_player: self.from_player,
+                shot_speed: def.speed,
+                shot_radius: def.radius,
+                damage: self.damage,
+                damage_type: self.damage_type,
+                status: self.status.clone(),
+            }),
+            _ => {}
+        }
+        // Flicker visuals use the flicker strike texture and are drawn by the caller.
+        let (from, to) = match def.visual {
+            SkillVisual::Ring => (self.from, self.from),
+            SkillVisual::Trail => (self.from, self.to),
+            SkillVisual::Tether => (self.from, self.target),
+            SkillVisual::None | SkillVisual::Flicker => return,
+        };
+        skill_effects.push(SkillEffect {
+            visual: def.visual,
+            from,
+            to,
+            radius: def.radius,
+            color: self.damage_type.color(),
+            lifetime: 0.3,
+            max_lifetime: 0.3,
+        });
+    }
+}
+
+/// Short-lived cast visual: a ring, a dash trail or a tether line.
+pub struct SkillEffect {
+    pub visual: SkillVisual,
+    pub from: (f64, f64),
+    pub to: (f64, f64),
+    pub radius: f64,
+    pub color: [f32; 4],
+    pub lifetime: f64,
+    pub max_lifetime: f64,
+}
+
+impl SkillEffect {
+    pub fn is_active(&self) -> bool {
+        self.lifetime > 0.0
+    }
+
+    pub fn draw(&self, c: Context, g: &mut G2d) {
+        let progress = 1.0 - (self.lifetime / self.max_lifetime).clamp(0.0, 1.0);
+        let color = [self.color[0], self.color[1], self.color[2], (1.0 - progress) as f32];
+        match self.visual {
+            SkillVisual::Ring => {
+                // Expands out to the slam radius
+                let radius = self.radius * (0.4 + 0.6 * progress);
+                Ellipse::new_border(color, 4.0).draw(
+                    ellipse::circle(self.from.0, self.from.1, radius),
+                    &c.draw_state,
+                    c.transform,
+                    g,
+                );
+            }
+            SkillVisual::Trail => {
+                line(color, self.radius / 2.0, [self.from.0, self.from.1, self.to.0, self.to.1], c.transform, g);
+            }
+            SkillVisual::Tether => {
+                line(color, 2.0, [self.from.0, self.from.1, self.to.0, self.to.1], c.transform, g);
+            }
+            SkillVisual::None | SkillVisual::Flicker => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::area::area::{BUNKER_HEIGHT, BUNKER_ORIGIN_X, BUNKER_ORIGIN_Y, BUNKER_WIDTH};
+    use crate::audio::AudioManager;
+    use crate::config::boundaries::{MAX_X, MAX_Y, MIN_X, MIN_Y};
+    use crate::simulation::World;
+
+    fn inside_bunker((x, y): (f64, f64)) -> bool {
+        (BUNKER_ORIGIN_X..=BUNKER_ORIGIN_X + BUNKER_WIDTH).contains(&x)
+            && (BUNKER_ORIGIN_Y..=BUNKER_ORIGIN_Y + BUNKER_HEIGHT).contains(&y)
+    }
+
+    #[test]
+    fn dashing_at_a_bunker_wall_stops_at_the_wall() {
+        let mut world = World::new(AudioManager::silent());
+        world.enter_bunker(1, 1);
+        world.fighter.x = BUNKER_ORIGIN_X + BUNKER_WIDTH - 50.0;
+        world.fighter.y = BUNKER_ORIGIN_Y + 50.0;
+        let target = (world.fighter.x + 500.0, world.fighter.y - 500.0);
+
+        let dash = fighter_cast(SkillType::Dash, &world.fighter, target, world.movement_bounds());
+        assert!(inside_bunker(dash.to), "dash left the bunker: {:?}", dash.to);
+        assert_eq!(dash.to.0, BUNKER_ORIGIN_X + BUNKER_WIDTH);
+
+        let teleport = fighter_cast(SkillType::FlickerStrike, &world.fighter, target, world.movement_bounds());
+        assert!(inside_bunker(teleport.to), "teleport left the bunker: {:?}", teleport.to);
+    }
+
+    #[test]
+    fn dashing_on_the_field_uses_the_field_bounds() {
+        let fighter = Fighter::new(MAX_X - 10.0, 1000.0);
+        let dash = fighter_cast(SkillType::Dash, &fighter, (MAX_X + 1000.0, 1000.0), (MIN_X, MAX_X, MIN_Y, MAX_Y));
+        assert_eq!(dash.to, (MAX_X, 1000.0));
+    }
+}
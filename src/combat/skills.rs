@@ -1,34 +1,104 @@
 // File: src/combat/skills.rs
 
+use crate::combat::skill_data;
+use crate::game_state::FighterType;
 use std::collections::HashMap;
 
+/// Radius of the spatial grid query for CPUs near the player. CPU skills with a range
+/// within it are only considered for CPUs the query returned.
 pub const FLICKER_STRIKE_RADIUS: f64 = 500.0;
-pub const FLICKER_STRIKE_DAMAGE_MULTIPLIER: f64 = 0.25;
+
+/// Number of skill hotkey slots.
+pub const SKILL_SLOTS: usize = 2;
+
+/// A skill in a fighter's loadout and the level it unlocks at.
+#[derive(Debug, Clone, Copy)]
+pub struct LoadoutSlot {
+    pub skill_type: SkillType,
+    pub unlock_level: u32,
+}
+
+const fn slot(skill_type: SkillType, unlock_level: u32) -> LoadoutSlot {
+    LoadoutSlot { skill_type, unlock_level }
+}
+
+/// Skills bound to the [SKILL 1] and [SKILL 2] hotkeys for each fighter type.
+pub fn loadout(fighter_type: FighterType) -> [LoadoutSlot; SKILL_SLOTS] {
+    match fighter_type {
+        FighterType::Racer => [slot(SkillType::FlickerStrike, 2), slot(SkillType::Dash, 5)],
+        FighterType::Soldier => [slot(SkillType::Turret, 2), slot(SkillType::PulseOrb, 5)],
+        FighterType::Raptor => [slot(SkillType::GroundSlam, 3), slot(SkillType::Tether, 6)],
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SkillType {
     FlickerStrike,
     PulseOrb,
+    GroundSlam,
+    Dash,
+    Tether,
+    Turret,
+}
+
+impl SkillType {
+    /// Every skill type, in the order CPUs consider them.
+    pub const ALL: [SkillType; 6] = [
+        SkillType::FlickerStrike,
+        SkillType::PulseOrb,
+        SkillType::GroundSlam,
+        SkillType::Dash,
+        SkillType::Tether,
+        SkillType::Turret,
+    ];
+
+    /// Section name in skills.cfg.
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            SkillType::FlickerStrike => "flicker_strike",
+            SkillType::PulseOrb => "pulse_orb",
+            SkillType::GroundSlam => "ground_slam",
+            SkillType::Dash => "dash",
+            SkillType::Tether => "tether",
+            SkillType::Turret => "turret",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SkillType::FlickerStrike => "FLICKER STRIKE",
+            SkillType::PulseOrb => "PULSE ORB",
+            SkillType::GroundSlam => "GROUND SLAM",
+            SkillType::Dash => "DASH",
+            SkillType::Tether => "TETHER",
+            SkillType::Turret => "TURRET",
+        }
+    }
+
+    /// Two-letter tag shown in the HUD slot.
+    pub fn short_label(&self) -> &'static str {
+        match self {
+            SkillType::FlickerStrike => "FS",
+            SkillType::PulseOrb => "PO",
+            SkillType::GroundSlam => "GS",
+            SkillType::Dash => "DA",
+            SkillType::Tether => "TE",
+            SkillType::Turret => "TU",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Skill {
-	#[allow(dead_code)] // TODO: Use in skill system implementation
-    pub skill_type: SkillType,
     pub cooldown_timer: f64,
     pub cooldown_duration: f64,
 }
 
 impl Skill {
     pub fn new(skill_type: SkillType) -> Self {
-        let cooldown_duration = match skill_type {
-            SkillType::FlickerStrike => 3.25,
-            SkillType::PulseOrb => 2.0,
-        };
         Self {
-            skill_type,
             cooldown_timer: 0.0, // Ready to use immediately
-            cooldown_duration,
+            cooldown_duration: skill_data::get().definition(skill_type).cooldown,
         }
     }
 
@@ -45,6 +115,14 @@ impl Skill {
             self.cooldown_timer -= dt;
         }
     }
+
+    /// Share of the cooldown still to run: 1.0 right after use, 0.0 when ready.
+    pub fn cooldown_fraction(&self) -> f64 {
+        if self.cooldown_duration <= 0.0 {
+            return 0.0;
+        }
+        (self.cooldown_timer / self.cooldown_duration).clamp(0.0, 1.0)
+    }
 }
 
 // This will hold all skills for one entity.
@@ -79,4 +157,8 @@ impl SkillManager {
             skill.trigger();
         }
     }
+
+    pub fn get(&self, skill_type: SkillType) -> Option<&Skill> {
+        self.skills.get(&skill_type)
+    }
 }
@@ -5,12 +5,17 @@ pub struct DefenseStats {
     pub hp: f64,
 	pub auto_block: f64,
 	pub auto_dodge: f64,
+	pub armor: f64, // fraction of landed damage absorbed
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct AttackStats {
     pub melee_damage: f64,
     pub ranged_damage: f64,
+	pub crit_chance: f64,
+	pub crit_multiplier: f64,
+	pub variance: f64, // each hit rolls within +/- this fraction of its damage
+	pub threat: f64,   // enemy threat generated per point of damage dealt
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -37,11 +42,16 @@ pub const RACER_LVL1_STATS: Stats = Stats {
     defense: DefenseStats {
         hp: 3.0 * HP_PER_DEFENSE_POINT, // 120.0 HP
         auto_block: 0.02,
-        auto_dodge: 0.02,		
+        auto_dodge: 0.02,
+        armor: 0.0,
     },
     attack: AttackStats {
         melee_damage: 1.0 * DAMAGE_PER_ATTACK_POINT, // 12.5 Damage
         ranged_damage: 1.0 * DAMAGE_PER_ATTACK_POINT, // 12.5 Damage
+        crit_chance: 0.10,
+        crit_multiplier: 1.5,
+        variance: 0.1,
+        threat: 1.0,
     },
     speed: SpeedStats {
         run_speed: 5.0 * SPEED_PER_SPEED_POINT,
@@ -53,11 +63,16 @@ pub const CPU_RACER_LVL1_STATS: Stats = Stats {
     defense: DefenseStats {
         hp: 10.0 * HP_PER_DEFENSE_POINT, 
         auto_block: 0.02,
-        auto_dodge: 0.02,		
+        auto_dodge: 0.02,
+        armor: 0.05,
     },
     attack: AttackStats {
         melee_damage: 1.0 * DAMAGE_PER_ATTACK_POINT, // 12.5 Damage
         ranged_damage: 1.0 * DAMAGE_PER_ATTACK_POINT, // 12.5 Damage
+        crit_chance: 0.05,
+        crit_multiplier: 1.5,
+        variance: 0.1,
+        threat: 1.0,
     },
     speed: SpeedStats {
         run_speed: 5.0 * SPEED_PER_SPEED_POINT,
@@ -69,11 +84,16 @@ pub const SOLDIER_LVL1_STATS: Stats = Stats {
     defense: DefenseStats {
         hp: 5.0 * HP_PER_DEFENSE_POINT, // 200.0 HP
         auto_block: 0.03,
-        auto_dodge: 0.01,		
+        auto_dodge: 0.01,
+        armor: 0.1,
     },
     attack: AttackStats {
         melee_damage: 3.0 * DAMAGE_PER_ATTACK_POINT, // 37.5 Damage
         ranged_damage: 3.0 * DAMAGE_PER_ATTACK_POINT, // 37.5 Damage
+        crit_chance: 0.05,
+        crit_multiplier: 2.0,
+        variance: 0.05,
+        threat: 1.5,
     },
     speed: SpeedStats {
         run_speed: 1.0 * SPEED_PER_SPEED_POINT,
@@ -86,10 +106,15 @@ pub const RAPTOR_LVL1_STATS: Stats = Stats {
         hp: 1.0 * HP_PER_DEFENSE_POINT,
         auto_block: 0.01,
         auto_dodge: 0.03,
+        armor: 0.0,
     },
     attack: AttackStats {
         melee_damage: 5.0 * DAMAGE_PER_ATTACK_POINT,
         ranged_damage: 5.0 * DAMAGE_PER_ATTACK_POINT,
+        crit_chance: 0.15,
+        crit_multiplier: 1.75,
+        variance: 0.15,
+        threat: 0.6,
     },
     speed: SpeedStats {
         run_speed: 3.0 * SPEED_PER_SPEED_POINT,
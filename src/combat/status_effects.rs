@@ -0,0 +1,299 @@
+// File: src/combat/status_effects.rs
+
+use piston_window::*;
+
+/// Kinds of status effect. Each kind is held at most once per combatant; applying it again
+/// adds a stack (up to the effect's limit) and refreshes the duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusKind {
+    Bleed,      // damage over time
+    Slow,       // movement speed reduction
+    AttackSlow, // attack rate reduction
+    Vulnerable, // damage taken amplification
+    Immune,     // rejects new harmful effects and pauses tick damage
+    Taunt,      // a CPU ignores its threat table and goes after the player
+}
+
+impl StatusKind {
+    fn is_harmful(self) -> bool {
+        self != StatusKind::Immune
+    }
+
+    fn icon_color(self) -> [f32; 4] {
+        match self {
+            StatusKind::Bleed => [1.0, 0.0, 0.0, 1.0],
+            StatusKind::Slow => [0.3, 0.6, 1.0, 1.0],
+            StatusKind::AttackSlow => [0.7, 0.4, 1.0, 1.0],
+            StatusKind::Vulnerable => [1.0, 0.6, 0.0, 1.0],
+            StatusKind::Immune => [1.0, 1.0, 1.0, 1.0],
+            StatusKind::Taunt => [1.0, 0.9, 0.2, 1.0],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub stacks: u32,
+    pub max_stacks: u32,
+    pub duration: f64,  // full duration, restored when the effect is reapplied
+    pub remaining: f64, // seconds left
+    pub tick_rate: f64, // seconds between damage ticks (Bleed)
+    pub tick_timer: f64,
+    pub magnitude: f64, // per stack: damage per tick, or the slow/amplification fraction
+}
+
+impl StatusEffect {
+    fn new(kind: StatusKind, duration: f64, magnitude: f64, max_stacks: u32) -> Self {
+        StatusEffect {
+            kind,
+            stacks: 1,
+            max_stacks,
+            duration,
+            remaining: duration,
+            tick_rate: 0.0,
+            tick_timer: 0.0,
+            magnitude,
+        }
+    }
+
+    /// Deals `total_damage` in 5-damage ticks every 0.5 seconds. Reapplying restarts it.
+    pub fn bleed(total_damage: f64) -> Self {
+        let tick_rate = 0.5;
+        let ticks = (total_damage / 5.0).ceil().max(1.0);
+        StatusEffect {
+            tick_rate,
+            ..StatusEffect::new(StatusKind::Bleed, ticks * tick_rate, total_damage / ticks, 1)
+        }
+    }
+
+    /// Movement speed reduced by `fraction` per stack.
+    pub fn slow(fraction: f64, duration: f64) -> Self {
+        StatusEffect::new(StatusKind::Slow, duration, fraction, 3)
+    }
+
+    /// Attacks come `fraction` per stack slower.
+    pub fn attack_slow(fraction: f64, duration: f64) -> Self {
+        StatusEffect::new(StatusKind::AttackSlow, duration, fraction, 3)
+    }
+
+    /// Damage taken increased by `fraction` per stack.
+    pub fn vulnerable(fraction: f64, duration: f64) -> Self {
+        StatusEffect::new(StatusKind::Vulnerable, duration, fraction, 3)
+    }
+
+    /// Immunity window against harmful effects.
+    pub fn immune(duration: f64) -> Self {
+        StatusEffect::new(StatusKind::Immune, duration, 0.0, 1)
+    }
+
+    /// Forces a CPU onto the player for `duration`. Has no effect on fighters.
+    pub fn taunt(duration: f64) -> Self {
+        StatusEffect::new(StatusKind::Taunt, duration, 0.0, 1)
+    }
+}
+
+/// Status effects on one combatant (Fighter, CpuEntity or CpuRacer).
+#[derive(Debug, Clone, Default)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    pub fn new() -> Self {
+        StatusEffects::default()
+    }
+
+    /// Adds an effect, or a stack of it if already present. Returns false if an immunity
+    /// window rejected it.
+    pub fn apply(&mut self, effect: StatusEffect) -> bool {
+        if effect.kind.is_harmful() && self.is_immune() {
+            return false;
+        }
+        match self.effects.iter_mut().find(|e| e.kind == effect.kind) {
+            Some(existing) => {
+                existing.stacks = (existing.stacks + 1).min(existing.max_stacks.max(effect.max_stacks));
+                existing.magnitude = existing.magnitude.max(effect.magnitude);
+                existing.duration = effect.duration;
+                existing.remaining = effect.duration;
+                existing.tick_rate = effect.tick_rate;
+            }
+            None => self.effects.push(effect),
+        }
+        true
+    }
+
+    /// Advances durations and tick timers. Returns the tick damage dealt this frame.
+    pub fn update(&mut self, dt: f64) -> f64 {
+        let immune = self.is_immune();
+        let mut damage = 0.0;
+        for effect in self.effects.iter_mut() {
+            effect.remaining -= dt;
+            if effect.tick_rate > 0.0 {
+                effect.tick_timer += dt;
+                if effect.tick_timer >= effect.tick_rate {
+                    effect.tick_timer -= effect.tick_rate;
+                    if !immune {
+                        damage += effect.magnitude * effect.stacks as f64;
+                    }
+                }
+            }
+        }
+        self.effects.retain(|e| e.remaining > 1e-9);
+        damage
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.effects.iter().any(|e| e.kind == kind)
+    }
+
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+
+    pub fn is_immune(&self) -> bool {
+        self.has(StatusKind::Immune)
+    }
+
+    pub fn is_taunted(&self) -> bool {
+        self.has(StatusKind::Taunt)
+    }
+
+    fn total(&self, kind: StatusKind) -> f64 {
+        self.effects
+            .iter()
+            .filter(|e| e.kind == kind)
+            .map(|e| e.magnitude * e.stacks as f64)
+            .sum()
+    }
+
+    /// Multiplier for movement speed.
+    pub fn move_speed_multiplier(&self) -> f64 {
+        (1.0 - self.total(StatusKind::Slow)).max(0.1)
+    }
+
+    /// Multiplier for attack rate; divide cooldowns by it.
+    pub fn attack_speed_multiplier(&self) -> f64 {
+        (1.0 - self.total(StatusKind::AttackSlow)).max(0.1)
+    }
+
+    /// Multiplier for incoming damage.
+    pub fn damage_taken_multiplier(&self) -> f64 {
+        1.0 + self.total(StatusKind::Vulnerable)
+    }
+
+    /// Draws one icon per effect in a row centred on `x`, bottom edge at `y` (just above a
+    /// health bar). Each icon shows its remaining duration as a fill and its stacks as pips.
+    pub fn draw_icons(&self, transform: math::Matrix2d, g: &mut G2d, x: f64, y: f64) {
+        let size = 10.0;
+        let gap = 3.0;
+        let row_width = self.effects.len() as f64 * (size + gap) - gap;
+        let mut icon_x = x - row_width / 2.0;
+        for effect in &self.effects {
+            let color = effect.kind.icon_color();
+            let fill = (effect.remaining / effect.duration).clamp(0.0, 1.0);
+            rectangle([0.0, 0.0, 0.0, 0.6], [icon_x, y - size, size, size], transform, g);
+            rectangle(color, [icon_x, y - size * fill, size, size * fill], transform, g);
+            Rectangle::new_border(color, 1.0).draw(
+                [icon_x, y - size, size, size],
+                &DrawState::default(),
+                transform,
+                g,
+            );
+            for pip in 1..effect.stacks {
+                rectangle(color, [icon_x + (pip - 1) as f64 * 4.0, y - size - 5.0, 3.0, 3.0], transform, g);
+            }
+            icon_x += size + gap;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn reapplying_adds_a_stack_and_refreshes() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffect::slow(0.1, 2.0));
+        effects.update(1.5);
+        effects.apply(StatusEffect::slow(0.2, 3.0));
+        let slow = &effects.effects[0];
+        assert_eq!(effects.effects.len(), 1);
+        assert_eq!(slow.stacks, 2);
+        assert!(close(slow.magnitude, 0.2), "the stronger magnitude wins");
+        assert!(close(slow.remaining, 3.0), "the new duration replaces what was left");
+    }
+
+    #[test]
+    fn stacks_stop_at_the_limit() {
+        let mut effects = StatusEffects::new();
+        for _ in 0..5 {
+            effects.apply(StatusEffect::vulnerable(0.1, 2.0));
+        }
+        effects.apply(StatusEffect::bleed(10.0));
+        effects.apply(StatusEffect::bleed(10.0));
+        assert_eq!(effects.effects[0].stacks, 3);
+        assert_eq!(effects.effects[1].stacks, 1);
+    }
+
+    #[test]
+    fn effects_expire_on_tick() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffect::taunt(1.0));
+        effects.apply(StatusEffect::slow(0.1, 2.0));
+        effects.update(0.99);
+        assert!(effects.is_taunted());
+        effects.update(0.02);
+        assert!(!effects.is_taunted());
+        assert!(effects.has(StatusKind::Slow));
+        effects.update(1.0);
+        assert!(!effects.has(StatusKind::Slow));
+    }
+
+    #[test]
+    fn bleed_deals_its_total_in_ticks() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffect::bleed(12.0));
+        assert!(close(effects.update(0.4), 0.0));
+        assert!(close(effects.update(0.1), 4.0));
+        let rest: f64 = (0..4).map(|_| effects.update(0.5)).sum();
+        assert!(close(rest, 8.0));
+        assert!(!effects.has(StatusKind::Bleed));
+    }
+
+    #[test]
+    fn immunity_blocks_harmful_effects_and_pauses_ticks() {
+        let mut effects = StatusEffects::new();
+        effects.apply(StatusEffect::bleed(10.0));
+        assert!(effects.apply(StatusEffect::immune(1.0)));
+        assert!(!effects.apply(StatusEffect::slow(0.5, 1.0)));
+        assert!(close(effects.update(0.5), 0.0));
+        assert!(effects.apply(StatusEffect::immune(1.0)), "immunity itself can be refreshed");
+    }
+
+    #[test]
+    fn multipliers_scale_with_stacks_and_floor() {
+        let mut effects = StatusEffects::new();
+        assert_eq!(effects.move_speed_multiplier(), 1.0);
+        assert_eq!(effects.attack_speed_multiplier(), 1.0);
+        assert_eq!(effects.damage_taken_multiplier(), 1.0);
+
+        effects.apply(StatusEffect::slow(0.2, 5.0));
+        effects.apply(StatusEffect::slow(0.2, 5.0));
+        effects.apply(StatusEffect::attack_slow(0.25, 5.0));
+        effects.apply(StatusEffect::vulnerable(0.15, 5.0));
+        effects.apply(StatusEffect::vulnerable(0.15, 5.0));
+        assert!(close(effects.move_speed_multiplier(), 0.6));
+        assert!(close(effects.attack_speed_multiplier(), 0.75));
+        assert!(close(effects.damage_taken_multiplier(), 1.3));
+
+        // Slows never stop a combatant outright.
+        effects.apply(StatusEffect::slow(0.5, 5.0));
+        assert!(close(effects.move_speed_multiplier(), 0.1));
+    }
+}
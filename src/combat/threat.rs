@@ -0,0 +1,227 @@
+// File: src/combat/threat.rs
+
+use crate::combat::damage::{self, Attack, Defense};
+use crate::combat::hitbox::{self, Hurtbox};
+use crate::entities::cpu_entity::CpuEntity;
+use crate::entities::cpu_racer::CpuRacer;
+use crate::game_state::EntityState;
+use crate::DamageText;
+
+/// Range at which a CPU starts building threat on targets by proximity alone.
+pub const AGGRO_RADIUS: f64 = 600.0;
+/// Threat per second a target draws at point blank, falling off to 0 at AGGRO_RADIUS.
+const PROXIMITY_THREAT: f64 = 10.0;
+/// Fraction of threat forgotten per second.
+const THREAT_DECAY: f64 = 0.15;
+/// A target has to beat the current target's threat by this factor to pull it away.
+const SWITCH_MARGIN: f64 = 1.1;
+
+/// Something a CPU can be after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreatTarget {
+    Player,
+    Racer(usize),    // index into the CPU racers
+    Survivor(usize), // index into the current field's survivors
+}
+
+/// A target a CPU may pick this frame and where it stands.
+#[derive(Debug, Clone, Copy)]
+pub struct Candidate {
+    pub target: ThreatTarget,
+    pub x: f64,
+    pub y: f64,
+    pub proximity: f64, // scales the proximity threat it draws
+}
+
+impl Candidate {
+    pub fn player(x: f64, y: f64) -> Self {
+        Candidate { target: ThreatTarget::Player, x, y, proximity: 1.0 }
+    }
+
+    /// A survivor waiting for rescue. It draws half the proximity threat of a fighter.
+    pub fn survivor(index: usize, x: f64, y: f64) -> Self {
+        Candidate { target: ThreatTarget::Survivor(index), x, y, proximity: 0.5 }
+    }
+}
+
+/// Adds every friendly CPU racer still standing to `candidates`.
+pub fn push_racers(cpu_racers: &[CpuRacer], candidates: &mut Vec<Candidate>) {
+    for (index, cr) in cpu_racers.iter().enumerate() {
+        if cr.entity_state == EntityState::Friendly && !cr.is_crashed && cr.current_hp > 0.0 {
+            candidates.push(Candidate { target: ThreatTarget::Racer(index), x: cr.x, y: cr.y, proximity: 1.0 });
+        }
+    }
+}
+
+/// One CPU's threat on each target. Damage and proximity add threat, which fades over
+/// time; the CPU goes after the target with the most, and a taunt overrides it.
+#[derive(Debug, Clone, Default)]
+pub struct ThreatTable {
+    entries: Vec<(ThreatTarget, f64)>,
+    pub current: Option<ThreatTarget>,
+}
+
+impl ThreatTable {
+    pub fn add(&mut self, target: ThreatTarget, amount: f64) {
+        if amount <= 0.0 {
+            return;
+        }
+        match self.entries.iter_mut().find(|(t, _)| *t == target) {
+            Some((_, threat)) => *threat += amount,
+            None => self.entries.push((target, amount)),
+        }
+    }
+
+    pub fn threat(&self, target: ThreatTarget) -> f64 {
+        self.entries.iter().find(|(t, _)| *t == target).map_or(0.0, |(_, threat)| *threat)
+    }
+
+    /// Decays threat, adds proximity threat, forgets targets that are gone and picks the
+    /// target for this frame. With no threat on anyone the CPU falls back to the player.
+    pub fn update(&mut self, dt: f64, at: (f64, f64), candidates: &[Candidate], taunted: bool) -> Option<Candidate> {
+        let decay = (1.0 - THREAT_DECAY * dt).max(0.0);
+        self.entries.retain_mut(|(target, threat)| {
+            *threat *= decay;
+            candidates.iter().any(|c| c.target == *target)
+        });
+        for candidate in candidates {
+            let distance = ((candidate.x - at.0).powi(2) + (candidate.y - at.1).powi(2)).sqrt();
+            if distance < AGGRO_RADIUS {
+                let closeness = 1.0 - distance / AGGRO_RADIUS;
+                self.add(candidate.target, PROXIMITY_THREAT * candidate.proximity * closeness * dt);
+            }
+        }
+
+        let find = |target: ThreatTarget| candidates.iter().find(|c| c.target == target).copied();
+        if taunted {
+            if let Some(player) = find(ThreatTarget::Player) {
+                self.current = Some(player.target);
+                return Some(player);
+            }
+        }
+        let top = self.entries.iter().copied().max_by(|a, b| a.1.total_cmp(&b.1));
+        self.current = match (self.current.and_then(find), top) {
+            (Some(current), Some((_, top_threat))) if self.threat(current.target) * SWITCH_MARGIN >= top_threat => {
+                Some(current.target)
+            }
+            (_, Some((target, _))) => Some(target),
+            (current, None) => current.or_else(|| find(ThreatTarget::Player)).map(|c| c.target),
+        };
+        self.current.and_then(find)
+    }
+}
+
+/// A CPU's contact attack on the friendly CPU racer it is after, with the same hurtbox
+/// contact and rate as its attack on the player.
+pub fn strike_racer(cpu: &mut CpuEntity, racer: &mut CpuRacer, damage_texts: &mut Vec<DamageText>) {
+    if cpu.stun_timer > 0.0 || cpu.damage_display_cooldown > 0.0 || racer.is_crashed || racer.invincible_timer > 0.0 {
+        return;
+    }
+    let hurtbox = Hurtbox::for_racer(racer);
+    if !hitbox::overlaps(&Hurtbox::for_cpu(cpu), &hurtbox) {
+        return;
+    }
+    hitbox::record_hit(&hurtbox);
+    let hit = damage::resolve(
+        &Attack::cpu(cpu.damage_value, damage::cpu_attack_type(cpu.variant)),
+        &Defense::cpu_racer(racer),
+    );
+    if hit.landed() {
+        racer.current_hp -= hit.amount;
+    }
+    damage_texts.push(hit.text(racer.x, racer.y - 50.0));
+    cpu.damage_display_cooldown = 0.125 / cpu.status_effects.attack_speed_multiplier();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Both well outside AGGRO_RADIUS of the CPU at the origin, so no proximity threat.
+    fn far_player() -> Candidate {
+        Candidate::player(5000.0, 0.0)
+    }
+
+    fn far_racer() -> Candidate {
+        Candidate { target: ThreatTarget::Racer(0), x: 0.0, y: 5000.0, proximity: 1.0 }
+    }
+
+    #[test]
+    fn falls_back_to_player_without_threat() {
+        let mut table = ThreatTable::default();
+        let picked = table.update(0.1, (0.0, 0.0), &[far_racer(), far_player()], false);
+        assert_eq!(picked.map(|c| c.target), Some(ThreatTarget::Player));
+        assert_eq!(table.current, Some(ThreatTarget::Player));
+    }
+
+    #[test]
+    fn threat_decays_over_time() {
+        let mut table = ThreatTable::default();
+        table.add(ThreatTarget::Player, 100.0);
+        table.update(1.0, (0.0, 0.0), &[far_player()], false);
+        assert!((table.threat(ThreatTarget::Player) - 100.0 * (1.0 - THREAT_DECAY)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn proximity_adds_threat() {
+        let mut table = ThreatTable::default();
+        table.update(1.0, (0.0, 0.0), &[Candidate::player(0.0, 0.0)], false);
+        assert!((table.threat(ThreatTarget::Player) - PROXIMITY_THREAT).abs() < 1e-9);
+    }
+
+    #[test]
+    fn switching_needs_the_margin() {
+        let candidates = [far_player(), far_racer()];
+        let mut table = ThreatTable::default();
+        table.add(ThreatTarget::Player, 100.0);
+        table.update(0.0, (0.0, 0.0), &candidates, false);
+        assert_eq!(table.current, Some(ThreatTarget::Player));
+
+        table.add(ThreatTarget::Racer(0), 105.0);
+        table.update(0.0, (0.0, 0.0), &candidates, false);
+        assert_eq!(table.current, Some(ThreatTarget::Player));
+
+        table.add(ThreatTarget::Racer(0), 10.0);
+        let picked = table.update(0.0, (0.0, 0.0), &candidates, false);
+        assert_eq!(table.current, Some(ThreatTarget::Racer(0)));
+        assert_eq!(picked.map(|c| (c.x, c.y)), Some((0.0, 5000.0)));
+    }
+
+    #[test]
+    fn taunt_overrides_threat() {
+        let mut table = ThreatTable::default();
+        table.add(ThreatTarget::Racer(0), 500.0);
+        let picked = table.update(0.0, (0.0, 0.0), &[far_player(), far_racer()], true);
+        assert_eq!(picked.map(|c| c.target), Some(ThreatTarget::Player));
+
+        let picked = table.update(0.0, (0.0, 0.0), &[far_player(), far_racer()], false);
+        assert_eq!(picked.map(|c| c.target), Some(ThreatTarget::Racer(0)));
+    }
+
+    #[test]
+    fn a_lone_survivor_draws_a_cpu_away_from_the_player() {
+        let mut table = ThreatTable::default();
+        let candidates = [far_player(), Candidate::survivor(2, 100.0, 0.0)];
+        let picked = table.update(0.5, (0.0, 0.0), &candidates, false);
+        assert_eq!(picked.map(|c| (c.target, c.x)), Some((ThreatTarget::Survivor(2), 100.0)));
+
+        // Side by side, the player draws twice the threat and keeps the CPU.
+        let mut table = ThreatTable::default();
+        let candidates = [Candidate::player(100.0, 0.0), Candidate::survivor(0, 100.0, 0.0)];
+        let picked = table.update(0.5, (0.0, 0.0), &candidates, false);
+        assert_eq!(picked.map(|c| c.target), Some(ThreatTarget::Player));
+        assert!((table.threat(ThreatTarget::Survivor(0)) * 2.0 - table.threat(ThreatTarget::Player)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn forgets_targets_that_are_gone() {
+        let mut table = ThreatTable::default();
+        table.add(ThreatTarget::Racer(0), 500.0);
+        table.update(0.0, (0.0, 0.0), &[far_player(), far_racer()], false);
+        assert_eq!(table.current, Some(ThreatTarget::Racer(0)));
+
+        let picked = table.update(0.0, (0.0, 0.0), &[far_player()], false);
+        assert_eq!(table.threat(ThreatTarget::Racer(0)), 0.0);
+        assert_eq!(picked.map(|c| c.target), Some(ThreatTarget::Player));
+    }
+}
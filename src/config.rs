@@ -1,18 +1,15 @@
 // config.rs
 
-/// Enables or disables CPU entities in the game
-pub const CPU_ENABLED: bool = true;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
-/// Enables or disables the Fog of War system.
-pub const FOG_OF_WAR_ENABLED: bool = false; 
+use crate::entities::cpu_entity::CpuVariant;
+use crate::graphics::viewport::{ScaleMode, WindowMode};
 
-/// Directly initiates Arena Mode on program start when true
-pub const ARENA_MODE: bool = false; // false = campaign
-
-/// When enabled, reduces visual effects to improve performance || true = on || false = off
-pub const PERFORMANCE_MODE: bool = true;
-
-/// Game resolution constants
+/// Game resolution constants. This is the logical canvas: everything is drawn in these
+/// units and scaled to the actual window by graphics::viewport.
 pub mod resolution {
     pub const WIDTH: f64 = 1920.0;
     pub const HEIGHT: f64 = 1080.0;
@@ -27,22 +24,482 @@ pub mod boundaries {
     pub const MAX_Y: f64 = 3250.0;
 }
 
-/// Fighter movement constants
-pub mod movement {
-    pub const BIKE_SPEED: f64 = 650.0;
-    pub const MOVEMENT_BUFFER_DURATION: f64 = 0.1;
-    pub const RUSH_DURATION: f64 = 0.25;
-    pub const RUSH_DISTANCE: f64 = 251.0;
-    pub const ON_FOOT_HOLD_DURATION: f64 = 0.00;
-    //pub const ON_FOOT_CONTINUOUS_SPEED: f64 = 150.0;
-}
-
-/// Gameplay constants
-pub mod gameplay {
-    pub const COLLISION_THRESHOLD: f64 = 75.0; // cpu strike/collision zone. Distance in pixels
-    pub const BIKE_INTERACTION_DISTANCE: f64 = 125.0;
-    pub const FIGHTER_JET_INTERACTION_DISTANCE: f64 = 150.0;
-    pub const RAPTOR_NEST_INTERACTION_DISTANCE: f64 = 150.0;
-    //pub const KINETIC_STRIKE_IMMUNITY_DURATION: f64 = 0.25;
-    pub const WARNING_MESSAGE_DURATION: f64 = 3.0;
+/// Name of the settings file looked up next to the executable.
+pub const SETTINGS_FILE_NAME: &str = "settings.cfg";
+
+/// Fighter movement settings
+#[derive(Debug, Clone)]
+pub struct MovementConfig {
+    pub bike_speed: f64,
+    pub movement_buffer_duration: f64,
+    pub rush_duration: f64,
+    pub rush_distance: f64,
+    pub on_foot_hold_duration: f64,
+}
+
+/// Gameplay settings
+#[derive(Debug, Clone)]
+pub struct GameplayConfig {
+    pub collision_threshold: f64, // cpu strike/collision zone. Distance in pixels
+    pub bike_interaction_distance: f64,
+    pub fighter_jet_interaction_distance: f64,
+    pub raptor_nest_interaction_distance: f64,
+    pub warning_message_duration: f64,
+    pub input_buffer_duration: f64, // how early strike/rush/block presses are held for. 0 = off
+    pub parry_window: f64, // hits this soon after raising the block are parried. 0 = off
+}
+
+/// Gamepad settings
+#[derive(Debug, Clone)]
+pub struct GamepadConfig {
+    pub enabled: bool,
+    pub left_stick_deadzone: f64,  // fraction of full tilt ignored around the centre
+    pub right_stick_deadzone: f64,
+    pub trigger_threshold: f64,    // how far a trigger must be pulled to count as pressed
+    pub aim_radius: f64,           // distance of the aim reticle from the fighter, in screen pixels
+}
+
+/// Window and scaling settings
+#[derive(Debug, Clone)]
+pub struct DisplayConfig {
+    pub window_mode: WindowMode,
+    pub scale_mode: ScaleMode,   // letterbox = black bars, expand = world fills the margins
+    pub windowed_width: u32,     // window size in windowed mode
+    pub windowed_height: u32,
+}
+
+/// Training mode (practice dummy) settings
+#[derive(Debug, Clone)]
+pub struct TrainingConfig {
+    pub dummy_variant: CpuVariant,
+    pub dummy_hp: f64,
+    pub dummy_fights_back: bool,
+    pub dummy_reset_delay: f64, // seconds without a hit before the dummy heals
+}
+
+/// Runtime settings, loaded once at startup from the settings file and command line.
+#[derive(Debug, Clone)]
+pub struct GameConfig {
+    /// Enables or disables CPU entities in the game
+    pub cpu_enabled: bool,
+    /// Enables or disables the Fog of War system.
+    pub fog_of_war_enabled: bool,
+    /// Directly initiates Arena Mode on program start when true (false = campaign)
+    pub arena_mode: bool,
+    /// When enabled, reduces visual effects to improve performance
+    pub performance_mode: bool,
+    pub movement: MovementConfig,
+    pub gameplay: GameplayConfig,
+    pub gamepad: GamepadConfig,
+    pub display: DisplayConfig,
+    pub training: TrainingConfig,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            cpu_enabled: true,
+            fog_of_war_enabled: false,
+            arena_mode: false,
+            performance_mode: true,
+            movement: MovementConfig {
+                bike_speed: 650.0,
+                movement_buffer_duration: 0.1,
+                rush_duration: 0.25,
+                rush_distance: 251.0,
+                on_foot_hold_duration: 0.0,
+            },
+            gameplay: GameplayConfig {
+                collision_threshold: 75.0,
+                bike_interaction_distance: 125.0,
+                fighter_jet_interaction_distance: 150.0,
+                raptor_nest_interaction_distance: 150.0,
+                warning_message_duration: 3.0,
+                input_buffer_duration: 0.15,
+                parry_window: 0.12,
+            },
+            gamepad: GamepadConfig {
+                enabled: true,
+                left_stick_deadzone: 0.2,
+                right_stick_deadzone: 0.25,
+                trigger_threshold: 0.5,
+                aim_radius: 300.0,
+            },
+            display: DisplayConfig {
+                window_mode: WindowMode::Borderless,
+                scale_mode: ScaleMode::Letterbox,
+                windowed_width: 1280,
+                windowed_height: 720,
+            },
+            training: TrainingConfig {
+                dummy_variant: CpuVariant::GiantMantis,
+                dummy_hp: 5000.0,
+                dummy_fights_back: false,
+                dummy_reset_delay: 3.0,
+            },
+        }
+    }
+}
+
+/// A setting that could not be applied, with where it came from.
+#[derive(Debug)]
+pub struct ConfigError {
+    pub source: String,
+    pub key: String,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.key.is_empty() {
+            write!(f, "{}: {}", self.source, self.message)
+        } else {
+            write!(f, "{}: '{}' {}", self.source, self.key, self.message)
+        }
+    }
+}
+
+impl GameConfig {
+    /// Sets one value by its `section.key` name (top-level keys have no section).
+    /// Numbers are checked against their allowed range.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        match key {
+            "cpu_enabled" => self.cpu_enabled = parse_bool(value)?,
+            "fog_of_war_enabled" => self.fog_of_war_enabled = parse_bool(value)?,
+            "arena_mode" => self.arena_mode = parse_bool(value)?,
+            "performance_mode" => self.performance_mode = parse_bool(value)?,
+            "movement.bike_speed" => self.movement.bike_speed = parse_ranged(value, 50.0, 5000.0)?,
+            "movement.movement_buffer_duration" => {
+                self.movement.movement_buffer_duration = parse_ranged(value, 0.0, 1.0)?
+            }
+            "movement.rush_duration" => self.movement.rush_duration = parse_ranged(value, 0.01, 2.0)?,
+            "movement.rush_distance" => self.movement.rush_distance = parse_ranged(value, 0.0, 2000.0)?,
+            "movement.on_foot_hold_duration" => {
+                self.movement.on_foot_hold_duration = parse_ranged(value, 0.0, 1.0)?
+            }
+            "gameplay.collision_threshold" => {
+                self.gameplay.collision_threshold = parse_ranged(value, 1.0, 500.0)?
+            }
+            "gameplay.bike_interaction_distance" => {
+                self.gameplay.bike_interaction_distance = parse_ranged(value, 1.0, 1000.0)?
+            }
+            "gameplay.fighter_jet_interaction_distance" => {
+                self.gameplay.fighter_jet_interaction_distance = parse_ranged(value, 1.0, 1000.0)?
+            }
+            "gameplay.raptor_nest_interaction_distance" => {
+                self.gameplay.raptor_nest_interaction_distance = parse_ranged(value, 1.0, 1000.0)?
+            }
+            "gameplay.warning_message_duration" => {
+                self.gameplay.warning_message_duration = parse_ranged(value, 0.1, 60.0)?
+            }
+            "gameplay.input_buffer_duration" => {
+                self.gameplay.input_buffer_duration = parse_ranged(value, 0.0, 0.5)?
+            }
+            "gameplay.parry_window" => self.gameplay.parry_window = parse_ranged(value, 0.0, 0.5)?,
+            "gamepad.enabled" => self.gamepad.enabled = parse_bool(value)?,
+            "gamepad.left_stick_deadzone" => {
+                self.gamepad.left_stick_deadzone = parse_ranged(value, 0.0, 0.95)?
+            }
+            "gamepad.right_stick_deadzone" => {
+                self.gamepad.right_stick_deadzone = parse_ranged(value, 0.0, 0.95)?
+            }
+            "gamepad.trigger_threshold" => self.gamepad.trigger_threshold = parse_ranged(value, 0.05, 1.0)?,
+            "gamepad.aim_radius" => self.gamepad.aim_radius = parse_ranged(value, 50.0, 1000.0)?,
+            "display.window_mode" => self.display.window_mode = WindowMode::parse(value)?,
+            "display.scale_mode" => self.display.scale_mode = ScaleMode::parse(value)?,
+            "display.windowed_width" => self.display.windowed_width = parse_ranged(value, 320.0, 7680.0)? as u32,
+            "display.windowed_height" => self.display.windowed_height = parse_ranged(value, 180.0, 4320.0)? as u32,
+            "training.dummy_variant" => self.training.dummy_variant = CpuVariant::parse(value)?,
+            "training.dummy_hp" => self.training.dummy_hp = parse_ranged(value, 1.0, 1_000_000.0)?,
+            "training.dummy_fights_back" => self.training.dummy_fights_back = parse_bool(value)?,
+            "training.dummy_reset_delay" => self.training.dummy_reset_delay = parse_ranged(value, 0.0, 60.0)?,
+            _ => return Err("is not a known setting".to_string()),
+        }
+        Ok(())
+    }
+
+    /// Applies a settings file. Lines are `key = value`; `[section]` headers prefix the
+    /// keys that follow, and `#` starts a comment. Every bad line is reported, not just the first.
+    pub fn apply_file(&mut self, path: &Path, errors: &mut Vec<ConfigError>) {
+        read_ini(path, errors, |line| match line {
+            IniLine::Section(_) => Ok(()),
+            IniLine::Entry { section: "", key, value } => self.set(key, value),
+            IniLine::Entry { section, key, value } => self.set(&format!("{}.{}", section, key), value),
+        });
+    }
+
+    /// Applies `--set section.key=value` overrides from the command line.
+    pub fn apply_args(&mut self, args: &[String], errors: &mut Vec<ConfigError>) {
+        let mut i = 0;
+        while i < args.len() {
+            if args[i] == "--set" {
+                let source = "--set".to_string();
+                match args.get(i + 1).and_then(|a| a.split_once('=')) {
+                    Some((key, value)) => {
+                        if let Err(message) = self.set(key.trim(), value) {
+                            errors.push(ConfigError { source, key: key.trim().to_string(), message });
+                        }
+                    }
+                    None => errors.push(ConfigError {
+                        source,
+                        key: String::new(),
+                        message: "expects an argument of the form section.key=value".to_string(),
+                    }),
+                }
+                i += 1;
+            }
+            i += 1;
+        }
+    }
+}
+
+/// One line of an ini-style data file (settings.cfg, combos.cfg, skills.cfg, resources.cfg).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IniLine<'a> {
+    /// A `[name]` header, trimmed.
+    Section(&'a str),
+    /// A `key = value` line under `section` ("" before the first header), both trimmed.
+    Entry { section: &'a str, key: &'a str, value: &'a str },
+}
+
+/// Reads an ini-style file and passes every header and entry to `visit`. `#` starts a
+/// comment. An error returned by `visit` is recorded against that line, keyed by the
+/// section name or the `section.key` name, and reading continues.
+pub fn read_ini(path: &Path, errors: &mut Vec<ConfigError>, visit: impl FnMut(IniLine<'_>) -> Result<(), String>) {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse_ini(&contents, &path.display().to_string(), errors, visit),
+        Err(e) => errors.push(ConfigError {
+            source: format!("{}", path.display()),
+            key: String::new(),
+            message: format!("could not be read: {}", e),
+        }),
+    }
+}
+
+/// `read_ini` for text already in memory; `source` names it in errors.
+pub fn parse_ini(
+    contents: &str,
+    source: &str,
+    errors: &mut Vec<ConfigError>,
+    mut visit: impl FnMut(IniLine<'_>) -> Result<(), String>,
+) {
+    let mut section = "";
+    for (i, raw_line) in contents.lines().enumerate() {
+        let line_source = format!("{}:{}", source, i + 1);
+        let line = raw_line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = name.trim();
+            if let Err(message) = visit(IniLine::Section(section)) {
+                errors.push(ConfigError { source: line_source, key: section.to_string(), message });
+            }
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            errors.push(ConfigError {
+                source: line_source,
+                key: String::new(),
+                message: format!("expected 'key = value', found '{}'", line),
+            });
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+        if let Err(message) = visit(IniLine::Entry { section, key, value }) {
+            let key = if section.is_empty() { key.to_string() } else { format!("{}.{}", section, key) };
+            errors.push(ConfigError { source: line_source, key, message });
+        }
+    }
+}
+
+/// `true`/`false`, `1`/`0`, `on`/`off` or `yes`/`no`.
+pub fn parse_bool(value: &str) -> Result<bool, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "1" | "on" | "yes" => Ok(true),
+        "false" | "0" | "off" | "no" => Ok(false),
+        _ => Err(format!("expects true or false, found '{}'", value)),
+    }
+}
+
+/// Parses a number and checks it lies in `min..=max`.
+pub fn parse_ranged(value: &str, min: f64, max: f64) -> Result<f64, String> {
+    let number = value
+        .parse::<f64>()
+        .map_err(|_| format!("expects a number, found '{}'", value))?;
+    if !(min..=max).contains(&number) {
+        return Err(format!("must be between {} and {}, found {}", min, max, number));
+    }
+    Ok(number)
+}
+
+static GAME_CONFIG: OnceLock<GameConfig> = OnceLock::new();
+
+/// Loads the settings file (`--config <file>`, or settings.cfg next to the executable
+/// if present) and applies command line overrides. Returns every validation error found.
+pub fn load(args: &[String]) -> Result<GameConfig, Vec<ConfigError>> {
+    let mut config = GameConfig::default();
+    let mut errors = Vec::new();
+
+    let explicit_path = args
+        .iter()
+        .position(|a| a == "--config")
+        .map(|pos| args.get(pos + 1).map(PathBuf::from));
+    match explicit_path {
+        Some(Some(path)) => config.apply_file(&path, &mut errors),
+        Some(None) => errors.push(ConfigError {
+            source: "--config".to_string(),
+            key: String::new(),
+            message: "expects a file path".to_string(),
+        }),
+        None => {
+            let default_path = std::env::current_exe()
+                .ok()
+                .and_then(|exe| exe.parent().map(|dir| dir.join(SETTINGS_FILE_NAME)));
+            if let Some(path) = default_path.filter(|p| p.exists()) {
+                config.apply_file(&path, &mut errors);
+            }
+        }
+    }
+    config.apply_args(args, &mut errors);
+
+    if errors.is_empty() {
+        Ok(config)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Makes `config` the active configuration. Only the first call has an effect.
+pub fn install(config: GameConfig) {
+    if GAME_CONFIG.set(config).is_err() {
+        println!("[CONFIG] Configuration already installed; ignoring reinstall.");
+    }
+}
+
+/// The active configuration, or the defaults if none was installed (e.g. headless tools).
+pub fn get() -> &'static GameConfig {
+    GAME_CONFIG.get_or_init(GameConfig::default)
+}
+
+/// Writes `contents` to `file_name` in a fresh temp directory named after `test_name` and
+/// returns the file's path. Remove it with `fs::remove_dir_all` on its parent.
+#[cfg(test)]
+pub fn write_test_file(test_name: &str, file_name: &str, contents: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("sbrx_test_{}_{}", std::process::id(), test_name));
+    fs::create_dir_all(&dir).expect("temp test directory should be creatable");
+    let path = dir.join(file_name);
+    fs::write(&path, contents).expect("temp test file should be writable");
+    path
+}
+
+/// Writes `contents` as `file_name` into a fresh temp directory, runs `load` on that directory
+/// and cleans up. Shared by the tests of every loader that reads a file next to the executable.
+#[cfg(test)]
+pub fn load_test_file<T>(test_name: &str, file_name: &str, contents: &str, load: impl FnOnce(&Path) -> T) -> T {
+    let path = write_test_file(test_name, file_name, contents);
+    let dir = path.parent().expect("test files live in a directory");
+    let loaded = load(dir);
+    fs::remove_dir_all(dir).ok();
+    loaded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|a| a.to_string()).collect()
+    }
+
+    fn write_settings(name: &str, contents: &str) -> PathBuf {
+        write_test_file(&format!("config_{}", name), SETTINGS_FILE_NAME, contents)
+    }
+
+    fn remove(path: &Path) {
+        fs::remove_dir_all(path.parent().unwrap()).ok();
+    }
+
+    #[test]
+    fn applies_sections_and_top_level_keys() {
+        let path = write_settings(
+            "sections",
+            "cpu_enabled = off  # top level\n\n[movement]\nbike_speed = 800\n[display]\nwindow_mode = Windowed\n[training]\ndummy_variant = raptor\n",
+        );
+        let config = load(&args(&["sbrx", "--config", path.to_str().unwrap()])).expect("settings should load");
+        remove(&path);
+        assert!(!config.cpu_enabled);
+        assert_eq!(config.movement.bike_speed, 800.0);
+        assert_eq!(config.display.window_mode, WindowMode::Windowed);
+        assert_eq!(config.training.dummy_variant, CpuVariant::Raptor);
+        // Untouched settings keep their defaults.
+        assert_eq!(config.movement.rush_duration, GameConfig::default().movement.rush_duration);
+    }
+
+    #[test]
+    fn reports_every_bad_line() {
+        let path = write_settings("errors", "[movement]\nbike_speed = 10\nrush_duration\n[gamepad]\nenabled = maybe\nwarp = 1\n");
+        let errors = load(&args(&["sbrx", "--config", path.to_str().unwrap()])).expect_err("bad settings should fail");
+        remove(&path);
+        let found: Vec<(String, String)> = errors
+            .iter()
+            .map(|e| (e.source.rsplit(':').next().unwrap().to_string(), e.key.clone()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("2".to_string(), "movement.bike_speed".to_string()),
+                ("3".to_string(), String::new()),
+                ("5".to_string(), "gamepad.enabled".to_string()),
+                ("6".to_string(), "gamepad.warp".to_string()),
+            ]
+        );
+        assert_eq!(errors[0].message, "must be between 50 and 5000, found 10");
+    }
+
+    #[test]
+    fn set_overrides_the_file() {
+        let path = write_settings("override", "[gameplay]\nparry_window = 0.2\n");
+        let config = load(&args(&[
+            "sbrx",
+            "--config",
+            path.to_str().unwrap(),
+            "--set",
+            "gameplay.parry_window=0.05",
+            "--set",
+            " arena_mode = yes",
+        ]))
+        .expect("overrides should apply");
+        remove(&path);
+        assert_eq!(config.gameplay.parry_window, 0.05);
+        assert!(config.arena_mode);
+    }
+
+    #[test]
+    fn set_reports_bad_overrides() {
+        let mut config = GameConfig::default();
+        let mut errors = Vec::new();
+        config.apply_args(&args(&["--set", "display.scale_mode=stretch", "--set", "no_equals_sign", "--set"]), &mut errors);
+        let keys: Vec<&str> = errors.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, vec!["display.scale_mode", "", ""]);
+        assert!(errors.iter().all(|e| e.source == "--set"));
+        assert_eq!(config.display.scale_mode, ScaleMode::Letterbox);
+    }
+
+    #[test]
+    fn missing_config_path_is_an_error() {
+        let errors = load(&args(&["sbrx", "--config"])).expect_err("--config needs a path");
+        assert_eq!(errors[0].source, "--config");
+    }
+
+    #[test]
+    fn parses_bools_and_ranges() {
+        assert_eq!(parse_bool("ON"), Ok(true));
+        assert_eq!(parse_bool("0"), Ok(false));
+        assert!(parse_bool("2").is_err());
+        assert_eq!(parse_ranged("0.5", 0.0, 1.0), Ok(0.5));
+        assert!(parse_ranged("1.5", 0.0, 1.0).is_err());
+        assert!(parse_ranged("fast", 0.0, 1.0).is_err());
+    }
 }
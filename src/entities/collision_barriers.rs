@@ -2,6 +2,7 @@
 // racetrack manager
 
 use crate::map_system::FieldId as SbrxFieldId;
+use crate::utils::spatial_hash::SpatialHash;
 use std::collections::HashMap;
 
 /// A single collision line segment defined by start and end points.
@@ -173,6 +174,8 @@ pub struct FieldCollisionBarriers {
     pub rut_zones: Vec<RutZone>,
     pub boundary_width: f64,
     pub boundary_height: f64,
+    /// Grid over `lines` (by index) for point queries. Rebuilt by `index_lines`.
+    line_index: SpatialHash,
 }
 
 impl FieldCollisionBarriers {
@@ -186,6 +189,7 @@ impl FieldCollisionBarriers {
             rut_zones: Vec::new(),
             boundary_width: width,
             boundary_height: height,
+            line_index: SpatialHash::for_world(),
         }
     }
 
@@ -339,10 +343,26 @@ impl FieldCollisionBarriers {
         
         // Link chain zones: each '0' -> next '0'
         barriers.link_chain_zones();
+
+        barriers.index_lines();
         
         barriers
     }
 
+    /// Rebuilds the line grid. Call after changing `lines`.
+    pub fn index_lines(&mut self) {
+        self.line_index.clear();
+        for (index, line) in self.lines.iter().enumerate() {
+            self.line_index.insert_rect(
+                index,
+                line.x1.min(line.x2),
+                line.y1.min(line.y2),
+                line.x1.max(line.x2),
+                line.y1.max(line.y2),
+            );
+        }
+    }
+
     /// Links jump zones in sequence: Launch -> nearest Air -> nearest Landing
     fn link_jump_zones(&mut self) {
         let zones_snapshot: Vec<(JumpZoneType, f64, f64)> = self.jump_zones
@@ -491,7 +511,11 @@ impl FieldCollisionBarriers {
 
     /// Checks if a point collides with any barrier in this field.
     pub fn check_point_collision(&self, x: f64, y: f64, threshold: f64) -> Option<(f64, f64)> {
-        for line in &self.lines {
+        let mut candidates = Vec::new();
+        self.line_index
+            .candidates_in_radius(x, y, threshold, self.lines.len(), &mut candidates);
+        for &index in &candidates {
+            let line = &self.lines[index];
             if line.check_collision(x, y, threshold) {
                 return Some(((line.x1 + line.x2) / 2.0, (line.y1 + line.y2) / 2.0));
             }
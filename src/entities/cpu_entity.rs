@@ -1,36 +1,23 @@
 // File: entities/cpu_entity.rs
 
+use crate::combat::damage::{self, Attack, DamageType, Defense};
+use crate::combat::hitbox::{self, Hurtbox};
+use crate::combat::status_effects::StatusEffects;
+use crate::combat::threat::{Candidate, ThreatTable, ThreatTarget};
+use crate::combat::skill_data;
+use crate::combat::skill_runner::{self, Bounds, SkillCast};
 use crate::combat::skills::*;
-use crate::config::{boundaries::*, CPU_ENABLED};
+use crate::config::{self, boundaries::*};
 use crate::rand::Rng;
 use crate::utils::math::safe_gen_range;
 use crate::utils::vec2d::Vec2d;
 use crate::AudioManager;
-use crate::game_state::EntityState;
+use crate::game_state::{DeathType, EntityState};
 use piston_window::*;
 
 use crate::{BUNKER_HEIGHT, BUNKER_ORIGIN_X, BUNKER_ORIGIN_Y, BUNKER_WIDTH};
 
-#[derive(Clone)]
-pub struct BleedEffect {
-    pub remaining_damage: f64,
-    pub tick_timer: f64,
-    pub tick_rate: f64,
-    pub damage_per_tick: f64,
-}
-
-impl BleedEffect {
-    pub fn new(total_damage: f64) -> Self {
-        BleedEffect {
-            remaining_damage: total_damage,
-            tick_timer: 0.0,
-            tick_rate: 0.5,       // damage ticks every 0.5 seconds
-            damage_per_tick: 5.0, // 5 damage per tick
-        }
-    }
-}
-
-#[derive(PartialEq, Clone, Copy, Debug)] // Added for type comparison
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)] // Added for type comparison
 pub enum CpuVariant {
     GiantMantis,
     BloodIdol,
@@ -44,31 +31,82 @@ pub enum CpuVariant {
     RazorFiend,
 }
 
-pub enum VisualEffect {
-    FlickerStrike {
-        from_x: f64,
-        from_y: f64,
-        to_x: f64,
-        to_y: f64,
-    },
-    ShootPulseOrb {
-        start_x: f64,
-        start_y: f64,
-        target_x: f64,
-        target_y: f64,
-    },
+impl CpuVariant {
+    /// Name used in settings files.
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            CpuVariant::GiantMantis => "giant_mantis",
+            CpuVariant::BloodIdol => "blood_idol",
+            CpuVariant::Rattlesnake => "rattlesnake",
+            CpuVariant::GiantRattlesnake => "giant_rattlesnake",
+            CpuVariant::Raptor => "raptor",
+            CpuVariant::TRex => "t_rex",
+            CpuVariant::VoidTempest => "void_tempest",
+            CpuVariant::LightReaver => "light_reaver",
+            CpuVariant::NightReaver => "night_reaver",
+            CpuVariant::RazorFiend => "razor_fiend",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<CpuVariant, String> {
+        const ALL: [CpuVariant; 10] = [
+            CpuVariant::GiantMantis,
+            CpuVariant::BloodIdol,
+            CpuVariant::Rattlesnake,
+            CpuVariant::GiantRattlesnake,
+            CpuVariant::Raptor,
+            CpuVariant::TRex,
+            CpuVariant::VoidTempest,
+            CpuVariant::LightReaver,
+            CpuVariant::NightReaver,
+            CpuVariant::RazorFiend,
+        ];
+        ALL.into_iter()
+            .find(|v| v.config_name() == value.to_ascii_lowercase())
+            .ok_or_else(|| format!("is not a CPU type, found '{}'", value))
+    }
+
+    /// Kill score awarded for defeating this variant.
+    pub fn score_value(&self) -> u32 {
+        match self {
+            CpuVariant::GiantMantis => 3,
+            CpuVariant::Rattlesnake => 1,
+            CpuVariant::GiantRattlesnake => 3,
+            CpuVariant::Raptor => 2,
+            CpuVariant::TRex => 5,
+            CpuVariant::BloodIdol => 3,
+            CpuVariant::VoidTempest => 4,
+            CpuVariant::LightReaver => 2,
+            CpuVariant::NightReaver => 2,
+            CpuVariant::RazorFiend => 10,
+        }
+    }
+
+    /// Death screen shown when this variant defeats the player.
+    pub fn death_type(&self) -> DeathType {
+        match self {
+            CpuVariant::GiantMantis => DeathType::GiantMantis,
+            CpuVariant::BloodIdol => DeathType::BloodIdol,
+            CpuVariant::Rattlesnake => DeathType::Rattlesnake,
+            CpuVariant::GiantRattlesnake => DeathType::GiantRattlesnake,
+            CpuVariant::Raptor => DeathType::Raptor,
+            CpuVariant::TRex => DeathType::TRex,
+            CpuVariant::VoidTempest => DeathType::VoidTempest,
+            CpuVariant::LightReaver => DeathType::LightReaver,
+            CpuVariant::NightReaver => DeathType::NightReaver,
+            CpuVariant::RazorFiend => DeathType::RazorFiend,
+        }
+    }
 }
 
 pub struct CpuUpdateResult {
-    pub damage_to_player: Option<f64>,
-    pub visual_effect: Option<VisualEffect>,
+    pub skill_cast: Option<SkillCast>, // resolved against `threat.current` by the caller
 }
 
 pub struct CpuEntity {
     pub x: f64,
     pub y: f64,
     pub speed: f64,
-    pub size: f64,
     pub facing_left: bool,
     pub current_hp: f64,
     pub max_hp: f64,
@@ -86,9 +124,11 @@ pub struct CpuEntity {
     pub variant: CpuVariant, // To distinguish CPU types for textures/stats
     pub damage_display_cooldown: f64,
     pub stun_timer: f64,
-    pub bleed_effect: Option<BleedEffect>,
+    pub status_effects: StatusEffects,
     pub skill_manager: SkillManager,
+    pub threat: ThreatTable,
 	pub entity_state: EntityState,
+    pub is_training_dummy: bool, // Practice target: never dies, see mechanics::training
 }
 
 impl CpuEntity {
@@ -98,7 +138,6 @@ impl CpuEntity {
             x: safe_gen_range(50.0, 1870.0, "CpuEntity x (GiantMantis)"),
             y: safe_gen_range(line_y, line_y + 400.0, "CpuEntity y (GiantMantis)"),
             speed: 150.0,
-            size: 100.0,
             facing_left: false,
             current_hp: 500.0,
             max_hp: 500.0,
@@ -115,9 +154,11 @@ impl CpuEntity {
             variant: CpuVariant::GiantMantis,
             damage_display_cooldown: 0.0,
             stun_timer: 0.0,
-            bleed_effect: None,
+            status_effects: StatusEffects::new(),
             skill_manager: SkillManager::new(),
+            threat: ThreatTable::default(),
 			entity_state: EntityState::Hostile,
+            is_training_dummy: false,
         }
     }
 
@@ -130,7 +171,6 @@ impl CpuEntity {
             x,
             y,
             speed: 250.0,
-            size: 100.0,
             facing_left: false,
             current_hp: 2500.0,
             max_hp: 2500.0,
@@ -147,9 +187,11 @@ impl CpuEntity {
             variant: CpuVariant::RazorFiend,
             damage_display_cooldown: 0.0,
             stun_timer: 0.0,
-            bleed_effect: None,
+            status_effects: StatusEffects::new(),
             skill_manager,
+            threat: ThreatTable::default(),
 			entity_state: EntityState::Hostile,
+            is_training_dummy: false,
         }
     }
 
@@ -161,7 +203,6 @@ impl CpuEntity {
             x,
             y,
             speed: 250.0,
-            size: 10.0,
             facing_left: false,
             current_hp: 300.0,
             max_hp: 300.0,
@@ -178,9 +219,11 @@ impl CpuEntity {
             variant: CpuVariant::LightReaver,
             damage_display_cooldown: 0.0,
             stun_timer: 0.0,
-            bleed_effect: None,
+            status_effects: StatusEffects::new(),
             skill_manager,
+            threat: ThreatTable::default(),
 			entity_state: EntityState::Hostile,
+            is_training_dummy: false,
         }
     }
 
@@ -192,7 +235,6 @@ impl CpuEntity {
             x,
             y,
             speed: 200.0,
-            size: 10.0,
             facing_left: false,
             current_hp: 300.0,
             max_hp: 300.0,
@@ -209,9 +251,11 @@ impl CpuEntity {
             variant: CpuVariant::NightReaver,
             damage_display_cooldown: 0.0,
             stun_timer: 0.0,
-            bleed_effect: None,
+            status_effects: StatusEffects::new(),
             skill_manager,
+            threat: ThreatTable::default(),
 			entity_state: EntityState::Hostile,
+            is_training_dummy: false,
         }
     }
 
@@ -221,7 +265,6 @@ impl CpuEntity {
             x: safe_gen_range(50.0, 1870.0, "CpuEntity x (BloodIdol)"),
             y: safe_gen_range(line_y, line_y + 400.0, "CpuEntity y (BloodIdol)"),
             speed: 450.0,
-            size: 50.0,
             facing_left: false,
             current_hp: 750.0,
             max_hp: 750.0,
@@ -238,9 +281,11 @@ impl CpuEntity {
             variant: CpuVariant::BloodIdol,
             damage_display_cooldown: 0.0,
             stun_timer: 0.0,
-            bleed_effect: None,
+            status_effects: StatusEffects::new(),
             skill_manager: SkillManager::new(),
+            threat: ThreatTable::default(),
 			entity_state: EntityState::Hostile,
+            is_training_dummy: false,
         }
     }
 
@@ -250,7 +295,6 @@ impl CpuEntity {
             x: safe_gen_range(50.0, 1870.0, "CpuEntity x (Rattlesnake)"),
             y: safe_gen_range(line_y, line_y + 400.0, "CpuEntity y (Rattlesnake)"),
             speed: 125.0,
-            size: 10.0,
             facing_left: false,
             current_hp: 100.0,
             max_hp: 100.0,
@@ -267,19 +311,22 @@ impl CpuEntity {
             variant: CpuVariant::Rattlesnake,
             damage_display_cooldown: 0.0,
             stun_timer: 0.0,
-            bleed_effect: None,
+            status_effects: StatusEffects::new(),
             skill_manager: SkillManager::new(),
+            threat: ThreatTable::default(),
 			entity_state: EntityState::Hostile,
+            is_training_dummy: false,
         }
     }
 
     // Constructor for GiantRattlesnake
     pub fn new_giant_rattlesnake(line_y: f64) -> Self {
+        let mut skill_manager = SkillManager::new();
+        skill_manager.add_skill(SkillType::Dash);
         Self {
             x: safe_gen_range(50.0, 1870.0, "CpuEntity x (GiantRattlesnake)"),
             y: safe_gen_range(line_y, line_y + 400.0, "CpuEntity y (GiantRattlesnake)"),
             speed: 100.0,
-            size: 50.0, // Assuming slightly larger size, can be adjusted
             facing_left: false,
             current_hp: 650.0,
             max_hp: 650.0,
@@ -296,9 +343,11 @@ impl CpuEntity {
             variant: CpuVariant::GiantRattlesnake,
             damage_display_cooldown: 0.0,
             stun_timer: 0.0,
-            bleed_effect: None,
-            skill_manager: SkillManager::new(),
+            status_effects: StatusEffects::new(),
+            skill_manager,
+            threat: ThreatTable::default(),
 			entity_state: EntityState::Hostile,
+            is_training_dummy: false,
         }
     }
 
@@ -308,7 +357,6 @@ impl CpuEntity {
             x,
             y,
             speed: 350.0,
-            size: 10.0,
             facing_left: false,
             current_hp: 250.0,
             max_hp: 250.0,
@@ -325,19 +373,22 @@ impl CpuEntity {
             variant: CpuVariant::Raptor,
             damage_display_cooldown: 0.0,
             stun_timer: 0.0,
-            bleed_effect: None,
+            status_effects: StatusEffects::new(),
             skill_manager: SkillManager::new(),
+            threat: ThreatTable::default(),
 			entity_state: EntityState::Hostile,
+            is_training_dummy: false,
         }
     }
 
     // Constructor for T-Rex
     pub fn new_t_rex(x: f64, y: f64) -> Self {
+        let mut skill_manager = SkillManager::new();
+        skill_manager.add_skill(SkillType::GroundSlam);
         Self {
             x,
             y,
             speed: 350.0,
-            size: 100.0, // T-Rex is bigger
             facing_left: false,
             current_hp: 1500.0,
             max_hp: 1500.0,
@@ -354,9 +405,11 @@ impl CpuEntity {
             variant: CpuVariant::TRex,
             damage_display_cooldown: 0.0,
             stun_timer: 0.0,
-            bleed_effect: None,
-            skill_manager: SkillManager::new(),
+            status_effects: StatusEffects::new(),
+            skill_manager,
+            threat: ThreatTable::default(),
 			entity_state: EntityState::Hostile,
+            is_training_dummy: false,
         }
     }
 
@@ -364,11 +417,11 @@ impl CpuEntity {
     pub fn new_void_tempest(line_y: f64, _base_mantis_hp: f64, _base_mantis_speed: f64) -> Self {
         let mut skill_manager = SkillManager::new(); // add skill 1/3
         skill_manager.add_skill(SkillType::FlickerStrike); // add skill 2/3
+        skill_manager.add_skill(SkillType::Tether);
         Self {
             x: safe_gen_range(50.0, 1870.0, "CpuEntity x (VoidTempest)"),
             y: safe_gen_range(line_y, line_y + 400.0, "CpuEntity y (VoidTempest)"),
             speed: 450.0,
-            size: 100.0,
             facing_left: false,
             current_hp: 750.0,
             max_hp: 750.0,
@@ -385,9 +438,11 @@ impl CpuEntity {
             variant: CpuVariant::VoidTempest,
             damage_display_cooldown: 0.0,
             stun_timer: 0.0,
-            bleed_effect: None,
+            status_effects: StatusEffects::new(),
             skill_manager, // add skill 3/3
+            threat: ThreatTable::default(),
 			entity_state: EntityState::Hostile,
+            is_training_dummy: false,
         }
     }
 
@@ -444,7 +499,26 @@ impl CpuEntity {
     }
 
     pub fn is_dead(&self) -> bool {
-        self.current_hp <= 0.0
+        self.current_hp <= 0.0 && !self.is_training_dummy
+    }
+
+    /// Builds a CPU of any variant at (x, y).
+    pub fn new_variant(variant: CpuVariant, x: f64, y: f64, line_y: f64) -> Self {
+        let mut cpu = match variant {
+            CpuVariant::GiantMantis => CpuEntity::new_giant_mantis(line_y),
+            CpuVariant::BloodIdol => CpuEntity::new_blood_idol(line_y, 250.0, 150.0),
+            CpuVariant::Rattlesnake => CpuEntity::new_rattlesnake(line_y),
+            CpuVariant::GiantRattlesnake => CpuEntity::new_giant_rattlesnake(line_y),
+            CpuVariant::Raptor => CpuEntity::new_raptor(x, y),
+            CpuVariant::TRex => CpuEntity::new_t_rex(x, y),
+            CpuVariant::VoidTempest => CpuEntity::new_void_tempest(line_y, 250.0, 150.0),
+            CpuVariant::LightReaver => CpuEntity::new_light_reaver(x, y),
+            CpuVariant::NightReaver => CpuEntity::new_night_reaver(x, y),
+            CpuVariant::RazorFiend => CpuEntity::new_razor_fiend(x, y),
+        };
+        cpu.x = x;
+        cpu.y = y;
+        cpu
     }
 
     pub fn apply_knockback(&mut self, source_x: f64, source_y: f64, force: f64) {
@@ -462,7 +536,7 @@ impl CpuEntity {
             // Edge case: source and entity are at the same position.
             // This can happen if RazorFiend flicker strikes to the player's location.
             // Apply knockback in a random direction to prevent immunity.
-            let mut rng = rand::rng();
+            let mut rng = crate::utils::rng::gameplay_rng();
             let random_angle = rng.random_range(0.0..std::f64::consts::TAU);
             let normalized_dx = random_angle.cos();
             let normalized_dy = random_angle.sin();
@@ -472,19 +546,39 @@ impl CpuEntity {
         }
     }
 
+    /// Casts the first ready skill (in SkillType::ALL order) the target is in range of.
+    /// Teleports and dashes move the CPU.
+    fn use_skill(&mut self, racer_x: f64, racer_y: f64, near_player: bool, bounds: Bounds) -> Option<SkillCast> {
+        let dx = racer_x - self.x;
+        let dy = racer_y - self.y;
+        let distance = (dx * dx + dy * dy).sqrt();
+        let skill_type = SkillType::ALL.into_iter().find(|skill_type| {
+            let def = skill_data::get().definition(*skill_type);
+            self.skill_manager.is_skill_ready(*skill_type)
+                && (near_player || def.range > FLICKER_STRIKE_RADIUS)
+                && (def.min_range..=def.range).contains(&distance)
+        })?;
+        self.skill_manager.trigger_skill(skill_type);
+        let cast = skill_runner::cpu_cast(skill_type, self, (racer_x, racer_y), bounds);
+        self.x = cast.to.0;
+        self.y = cast.to.1;
+        Some(cast)
+    }
+
+    /// Chases and casts at the target its threat table picks from `targets`.
+    /// `near_player` comes from the spatial grid query around the player
+    /// (FLICKER_STRIKE_RADIUS); CPUs outside it skip skills that can't reach past it.
     pub fn update(
         &mut self,
-        racer_x: f64,
-        racer_y: f64,
+        targets: &[Candidate],
         dt: f64,
         _line_y: f64,
+        bounds: Bounds,
         audio_manager: &AudioManager,
+        near_player: bool,
     ) -> CpuUpdateResult {
-        if !CPU_ENABLED {
-            return CpuUpdateResult {
-                damage_to_player: None,
-                visual_effect: None,
-            };
+        if !config::get().cpu_enabled {
+            return CpuUpdateResult { skill_cast: None };
         }
 		
         if self.entity_state == EntityState::Friendly {
@@ -496,31 +590,38 @@ impl CpuEntity {
                     self.knockback_velocity = Vec2d::new(0.0, 0.0);
                 }
             }
-            self.x = self.x.max(MIN_X).min(MAX_X);
-            self.y = self.y.max(MIN_Y).min(MAX_Y);
+            self.x = self.x.clamp(MIN_X, MAX_X);
+            self.y = self.y.clamp(MIN_Y, MAX_Y);
  
-            return CpuUpdateResult {
-                damage_to_player: None,
-                visual_effect: None,
-            };
+            return CpuUpdateResult { skill_cast: None };
         }		
 
-        // Update bleed effect
-        if let Some(ref mut bleed) = self.bleed_effect {
-            bleed.tick_timer += dt;
-            if bleed.tick_timer >= bleed.tick_rate {
-                bleed.tick_timer = 0.0;
-                let damage = bleed.damage_per_tick.min(bleed.remaining_damage);
-                self.current_hp -= damage;
-                bleed.remaining_damage -= damage;
-
-                if bleed.remaining_damage <= 0.0 {
-                    self.bleed_effect = None;
+        // Tick status effects (bleed damage)
+        let status_damage = self.status_effects.update(dt);
+        if status_damage > 0.0 {
+            self.current_hp -= damage::resolve(&Attack::tick(status_damage, DamageType::Slash), &Defense::cpu(self)).amount;
+        }
+
+        // A passive training dummy only takes status effects, stun and knockback.
+        if self.is_training_dummy && !config::get().training.dummy_fights_back {
+            if self.stun_timer > 0.0 {
+                self.stun_timer -= dt;
+            }
+            if self.knockback_duration > 0.0 {
+                self.x += self.knockback_velocity.x * dt;
+                self.y += self.knockback_velocity.y * dt;
+                self.knockback_duration -= dt;
+                if self.knockback_duration <= 0.0 {
+                    self.knockback_velocity = Vec2d::new(0.0, 0.0);
                 }
             }
+            self.x = self.x.max(MIN_X).min(MAX_X);
+            self.y = self.y.max(MIN_Y).min(MAX_Y);
+            return CpuUpdateResult { skill_cast: None };
         }
 
         self.skill_manager.update(dt);
+        let target = self.threat.update(dt, (self.x, self.y), targets, self.status_effects.is_taunted());
 
         // --- Stun logic is a complete override ---
         if self.stun_timer > 0.0 {
@@ -550,10 +651,7 @@ impl CpuEntity {
             self.y = self.y.max(MIN_Y).min(MAX_Y);
 
             // Skip all other logic for this frame.
-            return CpuUpdateResult {
-                damage_to_player: None,
-                visual_effect: None,
-            };
+            return CpuUpdateResult { skill_cast: None };
         }
 
         if self.damage_display_cooldown > 0.0 {
@@ -561,7 +659,7 @@ impl CpuEntity {
         }
 
         if self.is_attacking {
-            self.attack_timer += dt;
+            self.attack_timer += dt * self.status_effects.attack_speed_multiplier();
             if self.attack_timer >= self.frame_duration {
                 self.attack_timer = 0.0;
                 self.attack_frame = (self.attack_frame + 1) % 3;
@@ -599,56 +697,10 @@ impl CpuEntity {
         }
 
         // --- SKILL USAGE LOGIC ---
-        if self.skill_manager.is_skill_ready(SkillType::FlickerStrike) {
-            let dx = racer_x - self.x;
-            let dy = racer_y - self.y;
-            let distance = (dx * dx + dy * dy).sqrt();
-
-            if distance <= FLICKER_STRIKE_RADIUS {
-                //println!("[SKILL] {:?} using Flicker Strike!", self.variant);
-
-                let from_x = self.x;
-                let from_y = self.y;
-
-                // Teleport to player
-                self.x = racer_x;
-                self.y = racer_y;
-
-                self.skill_manager.trigger_skill(SkillType::FlickerStrike);
-
-                let damage = self.damage_value * FLICKER_STRIKE_DAMAGE_MULTIPLIER;
-						
-
-                return CpuUpdateResult {
-                    damage_to_player: Some(damage),
-                    visual_effect: Some(VisualEffect::FlickerStrike {
-                        from_x,
-                        from_y,
-                        to_x: self.x,
-                        to_y: self.y,
-                    }),
-                };
-            }
-        }
-
-        // Pulse Orb Skill Logic & range
-        if self.skill_manager.is_skill_ready(SkillType::PulseOrb) {
-            let dx = racer_x - self.x;
-            let dy = racer_y - self.y;
-            let distance = (dx * dx + dy * dy).sqrt();
-
-            // Shoot if player is within range (e.g., 600) but not too close (e.g., 100)
-            if distance <= 600.0 && distance >= 100.0 {
-                self.skill_manager.trigger_skill(SkillType::PulseOrb);
-                return CpuUpdateResult {
-                    damage_to_player: None,
-                    visual_effect: Some(VisualEffect::ShootPulseOrb {
-                        start_x: self.x,
-                        start_y: self.y,
-                        target_x: racer_x,
-                        target_y: racer_y,
-                    }),
-                };
+        if let Some(target) = target {
+            let near_target = near_player || target.target != ThreatTarget::Player;
+            if let Some(cast) = self.use_skill(target.x, target.y, near_target, bounds) {
+                return CpuUpdateResult { skill_cast: Some(cast) };
             }
         }
 
@@ -660,17 +712,18 @@ impl CpuEntity {
             if self.knockback_duration <= 0.0 {
                 self.knockback_velocity = Vec2d::new(0.0, 0.0);
             }
-        } else {
-            let dx = racer_x - self.x;
-            let dy = racer_y - self.y;
+        } else if let Some(target) = target {
+            let dx = target.x - self.x;
+            let dy = target.y - self.y;
             let distance = (dx * dx + dy * dy).sqrt();
 
             if distance > 0.0 {
                 let norm_dx = dx / distance;
                 let norm_dy = dy / distance;
 
-                self.x += norm_dx * self.speed * dt;
-                self.y += norm_dy * self.speed * dt;
+                let speed = self.speed * self.status_effects.move_speed_multiplier();
+                self.x += norm_dx * speed * dt;
+                self.y += norm_dy * speed * dt;
                 self.facing_left = norm_dx < 0.0;
             }
         }
@@ -678,14 +731,11 @@ impl CpuEntity {
         self.x = self.x.max(MIN_X).min(MAX_X);
         self.y = self.y.max(MIN_Y).min(MAX_Y);
 
-        CpuUpdateResult {
-            damage_to_player: None,
-            visual_effect: None,
-        }
+        CpuUpdateResult { skill_cast: None }
     }
 
     pub fn draw(&self, context: Context, g: &mut G2d, textures: &[G2dTexture]) {
-        if !CPU_ENABLED || textures.is_empty() {
+        if !config::get().cpu_enabled || textures.is_empty() {
             return;
         }
 
@@ -764,17 +814,7 @@ impl CpuEntity {
             g,
         );
 
-        // Draw bleed indicator
-        if self.bleed_effect.is_some() {
-            let bleed_indicator_y = hp_bar_world_y - 10.0;
-            // Simple visual indicator (you can replace with text rendering if glyphs are available)
-            rectangle(
-                [1.0, 0.0, 0.0, 0.8], // Red with transparency
-                [self.x - 15.0, bleed_indicator_y, 5.0, 5.0],
-                context.transform,
-                g,
-            );
-        }
+        self.status_effects.draw_icons(context.transform, g, self.x, hp_bar_world_y - 3.0);
 
         // Draw stun indicator
         if self.stun_timer > 0.0 {
@@ -788,23 +828,23 @@ impl CpuEntity {
         }
     }
 
+    /// Starts or stops the contact attack depending on whether this CPU's hurtbox
+    /// touches `target`.
     pub fn check_collision(
         &mut self,
-        racer_x: f64,
-        racer_y: f64,
+        target: &Hurtbox,
         is_blocking: bool,
         _audio_manager: &AudioManager,
     ) -> bool {
-        if !CPU_ENABLED || self.entity_state == EntityState::Friendly {
+        if !config::get().cpu_enabled || self.entity_state == EntityState::Friendly {
+            return false;
+        }
+        if self.is_training_dummy && !config::get().training.dummy_fights_back {
             return false;
         }
 
-        let collision_distance = self.size + crate::config::gameplay::COLLISION_THRESHOLD / 2.0;
-        let dx = racer_x - self.x;
-        let dy = racer_y - self.y;
-        let distance_squared = dx * dx + dy * dy;
-
-        if distance_squared < collision_distance * collision_distance {
+        if hitbox::overlaps(&Hurtbox::for_cpu(self), target) {
+            hitbox::record_hit(target);
             if !self.is_attacking {
                 self.is_attacking = true;
                 self.attack_frame = 0;
@@ -2,17 +2,26 @@
 
 use crate::AudioManager;
 use crate::rand::Rng;
-use crate::combat::block::BlockSystem;
+use crate::combat::block::{BlockSystem, PARRY_KNOCKBACK, PARRY_STAGGER_DURATION};
 use crate::combat::combo::ComboSystem;
+use crate::combat::hitbox::{self, Hurtbox};
 use crate::combat::stats::{Stats, CPU_RACER_LVL1_STATS};
-use crate::game_state::{CombatMode, RacerState, MovementDirection};
+use crate::game_state::{CombatMode, FighterType, RacerState, MovementDirection};
 use crate::utils::vec2d::Vec2d;
 use crate::graphics::fighter_textures::FighterTextures;
-use crate::entities::cpu_entity::BleedEffect;
+use crate::combat::damage::{self, Attack, DamageType, Defense};
+use crate::combat::status_effects::StatusEffects;
 use crate::game_state::EntityState;
+use crate::entities::fighter::Fighter;
 use piston_window::*;
 use rodio::Sink;
 
+// Guard AI: a hostile racer near the player raises its block now and then.
+const GUARD_RANGE: f64 = 350.0;
+const GUARD_CHANCE: f64 = 0.35; // per guard decision
+const GUARD_HOLD: f64 = 0.6; // seconds the block stays up
+const GUARD_COOLDOWN: f64 = 1.5; // seconds between guard decisions
+
 pub struct CpuRacer {
     pub x: f64,
     pub y: f64,
@@ -32,7 +41,7 @@ pub struct CpuRacer {
     
     pub block_system: BlockSystem,
     pub combo_system: ComboSystem,
-    pub bleed_effect: Option<BleedEffect>,
+    pub status_effects: StatusEffects,
     
     pub is_crashed: bool,
     pub boost: bool,
@@ -78,6 +87,8 @@ pub struct CpuRacer {
 	pub rush_timer: f64,
 	pub entity_state: EntityState,
 	pub reset_timer: f64,
+	pub guard_timer: f64,
+	pub guard_cooldown: f64,
 	pub bike_sound_sink: Option<Sink>,
 }
 
@@ -100,7 +111,7 @@ impl CpuRacer {
             knockback_duration: 0.0,
             block_system: BlockSystem::new(20),
             combo_system: ComboSystem::new(),
-            bleed_effect: None,
+            status_effects: StatusEffects::new(),
             is_crashed: false,
             boost: false,
             facing_left: false,
@@ -161,6 +172,8 @@ impl CpuRacer {
 			rush_timer: 0.0,
 			entity_state: EntityState::Neutral,
 			reset_timer: 0.0,
+			guard_timer: 0.0,
+			guard_cooldown: 0.0,
 			bike_sound_sink: None,
         }
     }
@@ -197,18 +210,10 @@ impl CpuRacer {
 			}
 			println!("CpuRacer HP hit 0 centrally! Transitioned to Phase: {}", self.phase);
 		}		
-        // Apply Bleed
-        if let Some(ref mut bleed) = self.bleed_effect {
-            bleed.tick_timer += dt;
-            if bleed.tick_timer >= bleed.tick_rate {
-                bleed.tick_timer = 0.0;
-                let damage = bleed.damage_per_tick.min(bleed.remaining_damage);
-                self.current_hp -= damage;
-                bleed.remaining_damage -= damage;
-                if bleed.remaining_damage <= 0.0 {
-                    self.bleed_effect = None;
-                }
-            }
+        // Tick status effects (bleed damage)
+        let status_damage = self.status_effects.update(dt);
+        if status_damage > 0.0 {
+            self.current_hp -= damage::resolve(&Attack::tick(status_damage, DamageType::Slash), &Defense::cpu_racer(self)).amount;
         }
 
         if self.stun_timer > 0.0 {
@@ -240,7 +245,7 @@ impl CpuRacer {
 				self.rush_active = false;
 			} else {
 				let rush_speed = 1800.0;
-				let speed_mult = if self.boost { 1.25 } else { 1.0 } * rut_mult;
+				let speed_mult = if self.boost { 1.25 } else { 1.0 } * rut_mult * self.status_effects.move_speed_multiplier();
 				self.x += self.rush_dir_x * rush_speed * speed_mult * dt;
 				self.y += self.rush_dir_y * rush_speed * speed_mult * dt;
 				
@@ -264,7 +269,7 @@ impl CpuRacer {
 						let speed_bonus = (self.stats.speed.run_speed - CPU_RACER_LVL1_STATS.speed.run_speed).max(0.0);
 						base_speed + speed_bonus
 					};
-					let speed_mult = if self.boost { 1.25 } else { 1.0 } * rut_mult;
+					let speed_mult = if self.boost { 1.25 } else { 1.0 } * rut_mult * self.status_effects.move_speed_multiplier();
 					
 					self.x += (pdx / p_dist) * speed * speed_mult * dt;
 					self.y += (pdy / p_dist) * speed * speed_mult * dt;
@@ -298,7 +303,7 @@ impl CpuRacer {
 							let speed_bonus = (self.stats.speed.run_speed - CPU_RACER_LVL1_STATS.speed.run_speed).max(0.0);
 							base_speed + speed_bonus
 						};
-						let speed_mult = if self.boost { 1.25 } else { 1.0 } * rut_mult;
+						let speed_mult = if self.boost { 1.25 } else { 1.0 } * rut_mult * self.status_effects.move_speed_multiplier();
 						
 						self.x += (dx / dist) * speed * speed_mult * dt;
 						self.y += (dy / dist) * speed * speed_mult * dt;
@@ -311,7 +316,8 @@ impl CpuRacer {
 		}
 
         self.block_system.update(dt, 0.0);
-        self.combo_system.update(dt);
+        self.update_guard(dt, player_x, player_y, audio_manager);
+        self.combo_system.update(dt, FighterType::Racer);
 
         if self.strike_animation_timer > 0.0 {
             self.strike_animation_timer -= dt;
@@ -323,7 +329,7 @@ impl CpuRacer {
 				self.sound_effect_timer = 0.0;
 				self.attack_frame = 0;
 				} else {				
-					self.attack_timer += dt;
+					self.attack_timer += dt * self.status_effects.attack_speed_multiplier();
 					if self.attack_timer >= self.frame_duration {
 						self.attack_timer = 0.0;
 						self.attack_frame = (self.attack_frame + 1) % 3;
@@ -435,24 +441,75 @@ impl CpuRacer {
 		
     }
 	
+	/// Raises and lowers the racer's block. Raising opens the same parry window the
+	/// player gets (see BlockSystem::activate).
+	fn update_guard(&mut self, dt: f64, player_x: f64, player_y: f64, audio_manager: &AudioManager) {
+		let can_guard = self.entity_state == EntityState::Hostile
+			&& !self.is_crashed
+			&& self.current_hp > 0.0
+			&& self.stun_timer <= 0.0
+			&& !self.rush_active;
+
+		if self.block_system.active {
+			self.guard_timer -= dt;
+			if self.guard_timer <= 0.0 || !can_guard {
+				self.block_system.deactivate();
+			}
+			return;
+		}
+		if self.guard_cooldown > 0.0 {
+			self.guard_cooldown -= dt;
+			return;
+		}
+		let dx = player_x - self.x;
+		let dy = player_y - self.y;
+		if can_guard && dx * dx + dy * dy < GUARD_RANGE * GUARD_RANGE {
+			self.guard_cooldown = GUARD_COOLDOWN;
+			if crate::utils::rng::gameplay_rng().random_bool(GUARD_CHANCE) && self.block_system.activate(audio_manager) {
+				self.guard_timer = GUARD_HOLD;
+				self.is_attacking = false;
+			}
+		}
+	}
+
+	/// Runs one of the fighter's hits through the racer's block. Returns true if the
+	/// hit was blocked; inside the parry window the fighter is staggered as well.
+	pub fn block_fighter_hit(&mut self, fighter: &mut Fighter, audio_manager: &AudioManager, current_time: f64) -> bool {
+		if self.block_system.try_parry(audio_manager) {
+			fighter.stun_timer = fighter.stun_timer.max(PARRY_STAGGER_DURATION);
+			let dx = fighter.x - self.x;
+			let dy = fighter.y - self.y;
+			let dist = (dx * dx + dy * dy).sqrt();
+			if dist > 0.0 {
+				fighter.knockback_velocity = Vec2d::new(dx / dist * PARRY_KNOCKBACK, dy / dist * PARRY_KNOCKBACK);
+				fighter.knockback_duration = 0.1;
+			}
+			return true;
+		}
+		// A broken guard stuns the racer; it never unseats it (crashing does that).
+		let blocked = self.block_system.process_block(false, audio_manager, current_time);
+		if self.block_system.block_broken {
+			self.stun_timer = self.stun_timer.max(self.block_system.stun_lock_timer);
+		}
+		blocked
+	}
+
+	/// Starts or stops the contact attack depending on whether this racer's hurtbox
+	/// touches `target`.
 	pub fn check_collision(
 		&mut self,
-		racer_x: f64,
-		racer_y: f64,
+		target: &Hurtbox,
 		is_blocking: bool,
 		_audio_manager: &AudioManager,
 	) -> bool {
- 			if self.is_crashed || self.current_hp <= 0.0 || self.stun_timer > 0.0 || self.entity_state != EntityState::Hostile {
+ 			if self.is_crashed || self.current_hp <= 0.0 || self.stun_timer > 0.0 || self.entity_state != EntityState::Hostile
+ 				|| self.block_system.active {
  				self.is_attacking = false;
  				return false;
  			}		
 		
-		let collision_distance = 100.0 + crate::config::gameplay::COLLISION_THRESHOLD / 2.0;
-		let dx = racer_x - self.x;
-		let dy = racer_y - self.y;
-		let distance_squared = dx * dx + dy * dy;
-
-		if distance_squared < collision_distance * collision_distance {
+		if hitbox::overlaps(&Hurtbox::for_racer(self), target) {
+			hitbox::record_hit(target);
 			if !self.is_attacking {
 				self.is_attacking = true;
 				self.attack_frame = 0;
@@ -485,7 +542,7 @@ impl CpuRacer {
  				let ldys = ey - sy;
  				let bls = (ldxs * ldxs + ldys * ldys).sqrt();
  				if bls > 0.0 {
- 					let mut ers = rand::rng();
+ 					let mut ers = crate::utils::rng::cosmetic_rng();
  					for (lm, ofr, col, w) in [
  						(0.7, 5.0, [0.8, 0.8, 1.0, 0.7], 1.0),
  						(1.2, 8.0, [0.7, 0.7, 0.9, 0.6], 1.5),
@@ -524,6 +581,12 @@ impl CpuRacer {
 				} else {
 					&textures.strike[current_texture_index]
 				}
+  			} else if self.block_system.active {
+ 				if self.state == RacerState::OnBike {
+ 					&textures.bike_block
+ 				} else {
+ 					&textures.block
+ 				}
   			} else if self.ranged_animation_timer > 0.0 {
  				if self.state == RacerState::OnBike {
  					&textures.bike_ranged
@@ -561,6 +624,7 @@ impl CpuRacer {
         } else {
             image(tex, c.transform.trans(img_x, img_y), g);
         }
+        self.block_system.draw_parry_flash(c.transform, g, self.x, self.y);
 
         let hp_bar_width = 50.0;
         let hp_bar_height = 5.0;
@@ -586,5 +650,6 @@ impl CpuRacer {
             c.transform,
             g,
         );
+        self.status_effects.draw_icons(c.transform, g, self.x, hp_bar_world_y - 3.0);
     }
 }
\ No newline at end of file
@@ -1,8 +1,12 @@
 //File: fighter.rs
 
+use crate::combat::resources::{ResourceAction, ResourceKind, Resources};
+use crate::combat::skills::{self, SkillManager, SkillType};
+use crate::combat::status_effects::StatusEffects;
 use crate::combat::stats::RAPTOR_LVL1_STATS;
 use crate::combat::stats::{Stats, RACER_LVL1_STATS, SOLDIER_LVL1_STATS};
 use crate::config::boundaries::{MAX_X, MAX_Y, MIN_X, MIN_Y};
+use crate::game::input_handler::{Action, KeyBindings};
 use crate::game_state::{FighterType, RacerState};
 use crate::graphics::seven_segment::SevenSegmentDisplay;
 use crate::mechanics::lvl_up::PROGRESSION_POINTS;
@@ -29,33 +33,28 @@ pub struct Fighter {
     pub melee_damage: f64,
     pub ranged_damage: f64,
     pub run_speed: f64,
-    pub fuel: f64,
-    pub max_fuel: f64,
-    pub fuel_tanks: HashMap<FighterType, f64>,
+    pub resources: Resources, // block points, kinetic charge, fuel, ammo and style
+    pub resource_banks: HashMap<FighterType, Resources>, // resources of the other fighter types
     pub combat_action_slowdown_timer: f64,
     pub combat_mode: CombatMode,
     pub kill_counters: HashMap<FighterType, u32>,
     pub levels: HashMap<FighterType, u32>,
     pub stat_points_to_spend: HashMap<FighterType, u32>,
-    pub ammo: u32,
-    pub max_ammo: u32,
-    pub is_reloading: bool,
-    pub reload_timer: f64,
 	pub boost: bool,
 	pub bike_boost_toggle_cooldown: f64,
 	pub boost_indicator_timer: f64,
 	pub show_gear: bool,
 	pub racer_variant: u32,
-	pub bleed_effect: Option<crate::entities::cpu_entity::BleedEffect>,
+	pub status_effects: StatusEffects,
+	pub skill_manager: SkillManager, // unlocked skills of the current fighter type's loadout
 }
 
 impl Fighter {
     pub fn new(x: f64, y: f64) -> Self {
         let initial_stats = RACER_LVL1_STATS;
-        let mut fuel_tanks = HashMap::new();
-        fuel_tanks.insert(FighterType::Racer, 100.0);
-        fuel_tanks.insert(FighterType::Soldier, 100.0);
-        fuel_tanks.insert(FighterType::Raptor, 100.0);
+        let mut resource_banks = HashMap::new();
+        resource_banks.insert(FighterType::Soldier, Resources::for_fighter(FighterType::Soldier));
+        resource_banks.insert(FighterType::Raptor, Resources::for_fighter(FighterType::Raptor));
 
         let mut kill_counters = HashMap::new();
         kill_counters.insert(FighterType::Racer, 0);
@@ -87,25 +86,21 @@ impl Fighter {
             melee_damage: initial_stats.attack.melee_damage,
             ranged_damage: initial_stats.attack.ranged_damage,
             run_speed: initial_stats.speed.run_speed,
-            fuel: 100.0,
-            max_fuel: 100.0,
-            fuel_tanks,
+            resources: Resources::for_fighter(FighterType::Racer),
+            resource_banks,
             combat_action_slowdown_timer: 0.0,
             combat_mode: CombatMode::CloseCombat,
             kill_counters,
             levels,
             stat_points_to_spend,
 			stun_timer: 0.0,
-            ammo: 25,
-            max_ammo: 25,
-            is_reloading: false,
-            reload_timer: 0.0,
 			boost: true, // false to swap starting [SHIFT] key
 			bike_boost_toggle_cooldown: 0.0,
 			boost_indicator_timer: 0.0,
 			show_gear: false,
 			racer_variant: 0,
-			bleed_effect: None,
+			status_effects: StatusEffects::new(),
+			skill_manager: SkillManager::new(),
         }
     }
     pub fn get_shoot_radius(&self) -> f64 {
@@ -117,8 +112,8 @@ impl Fighter {
     }
 
     pub fn switch_fighter_type(&mut self, fighter_type: FighterType) -> f64 {
-        // Store current fuel before switching
-        self.fuel_tanks.insert(self.fighter_type, self.fuel);
+        // Bank the current resources before switching
+        self.resource_banks.insert(self.fighter_type, self.resources.clone());
 
         self.fighter_type = fighter_type;
 
@@ -138,46 +133,19 @@ impl Fighter {
         self.ranged_damage = new_stats.attack.ranged_damage;
         self.run_speed = new_stats.speed.run_speed;
 
-        // Load new fighter's fuel
-        self.fuel = *self.fuel_tanks.get(&self.fighter_type).unwrap_or(&100.0);
+        // Load the new fighter's resources
+        self.resources = self
+            .resource_banks
+            .remove(&self.fighter_type)
+            .unwrap_or_else(|| Resources::for_fighter(self.fighter_type));
 
-        // Raptor is fuel-independent
-        if self.fighter_type == FighterType::Raptor {
-            self.fuel = 100.0;
-        }		
+        // Each fighter type brings its own loadout
+        self.skill_manager = SkillManager::new();
+        self.sync_skills();
 		
         new_radius
     }
 
-    // --- MODIFIED: Draws the fuel meter as a numerical percentage ---
-    pub fn draw_fuel_meter(&self, original_context: Context, g: &mut G2d, glyphs: &mut Glyphs) {
-        // Don't draw fuel meter for Raptor
-        if self.fighter_type == crate::game_state::FighterType::Raptor {
-            return;
-        }		
-		
-        // Calculate the fuel percentage
-        let fuel_percentage = (self.fuel / self.max_fuel) * 100.0;
-        // Format the text string, ensuring it doesn't go below 0.0
-        let fuel_text = format!("FUEL: {:.0}%", fuel_percentage.max(0.0));
-
-        let font_size = 20;
-        let text_color = [1.0, 0.5, 0.0, 1.0]; // Orange color for fuel
-        let text_x = 80.0; // Position near other UI elements
-        let text_y = 280.0; // FUEL METER
-
-        // Draw the text
-        text::Text::new_color(text_color, font_size)
-            .draw(
-                &fuel_text,
-                glyphs,
-                &original_context.draw_state,
-                original_context.transform.trans(text_x, text_y),
-                g,
-            )
-            .unwrap_or_else(|e| eprintln!("Failed to draw fuel text: {}", e));
-    }
-
     pub fn draw_stats_display(
         &self,
         original_context: Context,
@@ -474,7 +442,86 @@ impl Fighter {
         }
     }
 */
+    /// Adds kill score for the active fighter type and applies any level ups.
+    /// Returns the number of levels gained; stat points are banked per fighter type.
+    pub fn add_kill_score(&mut self, points: u32) -> u32 {
+        let current_kills = self.kill_counters.entry(self.fighter_type).or_insert(0);
+        *current_kills += points;
+        let current_level = self.levels.entry(self.fighter_type).or_insert(1);
+        let levels_gained = crate::mechanics::lvl_up::check_for_level_up(current_kills, current_level);
+        if levels_gained > 0 {
+            *self
+                .stat_points_to_spend
+                .entry(self.fighter_type)
+                .or_insert(0) += levels_gained;
+        }
+        levels_gained
+    }
+
+    /// Adds loadout skills the current level has unlocked. Returns the newly added ones.
+    pub fn sync_skills(&mut self) -> Vec<SkillType> {
+        let level = *self.levels.get(&self.fighter_type).unwrap_or(&1);
+        let mut unlocked = Vec::new();
+        for slot in skills::loadout(self.fighter_type) {
+            if level >= slot.unlock_level && self.skill_manager.get(slot.skill_type).is_none() {
+                self.skill_manager.add_skill(slot.skill_type);
+                unlocked.push(slot.skill_type);
+            }
+        }
+        unlocked
+    }
+
+    /// The skill in a hotkey slot, if unlocked and off cooldown.
+    pub fn ready_skill(&self, slot: usize) -> Option<SkillType> {
+        let skill_type = skills::loadout(self.fighter_type).get(slot)?.skill_type;
+        self.skill_manager.is_skill_ready(skill_type).then_some(skill_type)
+    }
+
+    /// Skill slots with their hotkeys; locked slots show the unlock level, skills on
+    /// cooldown fill from the bottom as they recharge.
+    pub fn draw_skill_bar(&self, c: Context, g: &mut G2d, glyphs: &mut Glyphs, key_bindings: &KeyBindings) {
+        let size = 56.0;
+        let gap = 10.0;
+        let base_x = 20.0;
+        let base_y = 300.0;
+        let actions = [Action::Skill1, Action::Skill2];
+        for (i, slot) in skills::loadout(self.fighter_type).iter().enumerate() {
+            let x = base_x + i as f64 * (size + gap);
+            let skill = self.skill_manager.get(slot.skill_type);
+            rectangle([0.0, 0.0, 0.0, 0.8], [x, base_y, size, size], c.transform, g);
+
+            let (label, color) = match skill {
+                None => (format!("LV{}", slot.unlock_level), [0.5, 0.5, 0.5, 1.0]),
+                Some(skill) => {
+                    let remaining = skill.cooldown_fraction();
+                    if remaining > 0.0 {
+                        let fill = size * (1.0 - remaining);
+                        rectangle([0.0, 0.6, 0.2, 0.6], [x, base_y + size - fill, size, fill], c.transform, g);
+                    }
+                    // Ready skills the style meter can't pay for stay white
+                    let affordable = self.resources.can_afford(ResourceAction::Skill, 1.0);
+                    let color = if remaining > 0.0 || !affordable { [1.0, 1.0, 1.0, 1.0] } else { [0.0, 1.0, 0.0, 1.0] };
+                    (slot.skill_type.short_label().to_string(), color)
+                }
+            };
+            Rectangle::new_border(color, 1.5).draw([x, base_y, size, size], &c.draw_state, c.transform, g);
+            text::Text::new_color(color, 18)
+                .draw(&label, glyphs, &c.draw_state, c.transform.trans(x + 8.0, base_y + 34.0), g)
+                .ok();
+            text::Text::new_color([0.7, 0.7, 0.7, 1.0], 12)
+                .draw(
+                    &key_bindings.keys_label(actions[i]),
+                    glyphs,
+                    &c.draw_state,
+                    c.transform.trans(x + 4.0, base_y + size + 14.0),
+                    g,
+                )
+                .ok();
+        }
+    }
+
     pub fn update(&mut self, dt: f64, _line_y: f64) {
+        self.skill_manager.update(dt);
 		if self.stun_timer > 0.0 {
  			self.stun_timer -= dt;
  		}		
@@ -512,13 +559,7 @@ impl Fighter {
             }
         }
 
-        if self.is_reloading {
-            self.reload_timer -= dt;
-            if self.reload_timer <= 0.0 {
-                self.is_reloading = false;
-                self.ammo = self.max_ammo;
-            }
-        }
+        self.resources.update(dt);
         if self.bike_boost_toggle_cooldown > 0.0 {
             self.bike_boost_toggle_cooldown -= dt;
         }	
@@ -561,57 +602,25 @@ impl Fighter {
             g,
         );
 
+        self.status_effects.draw_icons(
+            context.transform,
+            g,
+            self.x - hp_bar_width / 2.5 + hp_bar_width / 2.0,
+            bar_y - 3.0,
+        );
+
         // Draw HP text
         let _hp_text = format!("{}/{}", self.current_hp as i32, self.max_hp as i32);
         // Add text drawing here if you want to display the numbers
     }
 
     pub fn trigger_reload(&mut self, audio_manager: &crate::audio::AudioManager) {
-        if !self.is_reloading {
-            self.is_reloading = true;
-            self.reload_timer = 1.0;
+        if self.resources.start_reload(ResourceKind::Ammo) {
             audio_manager.play_sound_effect("reload").ok();
         }
     }
 
-    pub fn draw_ammo_gauge(&self, c: Context, g: &mut G2d, glyphs: &mut Glyphs) {
-        if self.fighter_type != crate::game_state::FighterType::Soldier {
-            return;
-        }
-
-        let font_size = 15;
-        let text_color = if self.is_reloading {
-            [1.0, 0.0, 0.0, 1.0]
-        } else {
-            [1.0, 0.5, 0.0, 1.0]
-        };
-        let bar_char = "|";
-        let bars = bar_char.repeat(self.ammo as usize);
-        let display_text = if self.is_reloading {
-            "RELOADING...".to_string()
-        } else {
-            format!("AMMO: {}", bars)
-        };
-
-        let x = 1.0;
-        let y = 65.0; // Positioned below score
-
-        // Background
-        rectangle(
-            [0.0, 0.0, 0.0, 0.9],
-            [x - 5.0, y - 25.0, 350.0, 30.0],
-            c.transform,
-            g,
-        );
-
-        text::Text::new_color(text_color, font_size)
-            .draw(
-                &display_text,
-                glyphs,
-                &c.draw_state,
-                c.transform.trans(x, y),
-                g,
-            )
-            .ok();
+    pub fn is_reloading(&self) -> bool {
+        self.resources.is_reloading(ResourceKind::Ammo)
     }
-}
\ No newline at end of file
+}
@@ -1,5 +1,6 @@
 // entities/fuel_pump.rs
 
+use crate::combat::resources::ResourceKind;
 use crate::Fighter;
 use piston_window::*;
 
@@ -22,10 +23,7 @@ impl FuelPump {
         // Interaction distance (150.0)
         if (dx * dx + dy * dy).sqrt() < 150.0 {
             // Refill fuel
-            fighter.fuel = fighter.max_fuel;
-            fighter
-                .fuel_tanks
-                .insert(fighter.fighter_type, fighter.fuel);
+            fighter.resources.refill(ResourceKind::Fuel);
         }
     }
 
@@ -7,7 +7,6 @@ use std::collections::HashSet;
 // Represents a single ground asset with its properties.
 pub struct GroundAsset {
     pub name: &'static str,
-	#[allow(dead_code)] // Reserved for asset loading
     pub path: &'static str,
     // A weight for randomization. Higher means more common.
     pub spawn_weight: u32,
@@ -164,6 +163,11 @@ impl GroundAssetManager {
         }
     }
 
+    /// All ground assets, in definition order.
+    pub fn assets(&self) -> &[GroundAsset] {
+        &self.assets
+    }
+
     /// Checks if a given field is an exclusion zone for ground assets.
     pub fn is_exclusion_zone(&self, field_id: &SbrxFieldId) -> bool {
         self.exclusion_zones.contains(field_id)
@@ -176,7 +180,7 @@ impl GroundAssetManager {
             return None;
         }
 
-        let mut rng = rand::rng();
+        let mut rng = crate::utils::rng::cosmetic_rng();
         let mut choice = rng.random_range(0..self.total_spawn_weight);
 
         for asset in &self.assets {
@@ -15,4 +15,5 @@ pub mod shoot;
 pub mod star;
 pub mod strike;
 pub mod track;
+pub mod turret;
 pub mod cpu_racer;
@@ -1,5 +1,7 @@
 // entities/pulse_orb.rs
 
+use crate::combat::damage::DamageType;
+use crate::combat::status_effects::StatusEffect;
 use crate::utils::vec2d::Vec2d;
 use piston_window::*;
 
@@ -10,11 +12,14 @@ pub struct PulseOrb {
     pub radius: f64,
     pub lifetime: f64,
     pub active: bool,
+    pub from_player: bool, // Player skill orbs hit CPUs instead of the player
+    pub damage: f64,       // before the target's resistances
+    pub damage_type: DamageType,
+    pub status: Option<StatusEffect>, // applied on hit
 }
 
 impl PulseOrb {
-    pub fn new(x: f64, y: f64, target_x: f64, target_y: f64) -> Self {
-        let speed = 750.0;
+    pub fn new(x: f64, y: f64, target_x: f64, target_y: f64, speed: f64) -> Self {
         let dx = target_x - x;
         let dy = target_y - y;
         let dist = (dx * dx + dy * dy).sqrt();
@@ -32,6 +37,10 @@ impl PulseOrb {
             radius: 10.0,
             lifetime: 3.0, // Lasts 3 seconds
             active: true,
+            from_player: false,
+            damage: 20.0,
+            damage_type: DamageType::Void,
+            status: None,
         }
     }
 
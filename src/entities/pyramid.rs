@@ -1,6 +1,6 @@
 // entities/pyramid.rs
 /*
-use crate::utils::math::safe_gen_range;
+use crate::utils::math::safe_gen_range_cosmetic;
 use piston_window::*;
 
 /// Represents a pyramid obstacle in the game world
@@ -27,8 +27,8 @@ impl Pyramid {
         Pyramid {
             x,
             y,
-            base_width: safe_gen_range(25.0, 80.0, "Pyramid width"),
-            height: safe_gen_range(30.0, 100.0, "Pyramid height"),
+            base_width: safe_gen_range_cosmetic(25.0, 80.0, "Pyramid width"),
+            height: safe_gen_range_cosmetic(30.0, 100.0, "Pyramid height"),
         }
     }
 
@@ -93,7 +93,7 @@ pub fn generate_border_pyramids(
     for i in 0..10 {
         // Random spacing for more natural appearance
         let pyramid_y = middle_line
-            + safe_gen_range(
+            + safe_gen_range_cosmetic(
                 i as f64 * 30.0,
                 (i as f64 + 1.0) * 60.0,
                 "Left border y pos",
@@ -102,10 +102,10 @@ pub fn generate_border_pyramids(
         // Make sure we stay within the vertical range
         if pyramid_y < bottom_border + 100.0 {
             pyramids.push(Pyramid {
-                x: left_border - safe_gen_range(5.0, 40.0, "Left pyramid offset"), // Place them outside
+                x: left_border - safe_gen_range_cosmetic(5.0, 40.0, "Left pyramid offset"), // Place them outside
                 y: pyramid_y,
-                base_width: safe_gen_range(25.0, 80.0, "Pyramid width"),
-                height: safe_gen_range(30.0, 100.0, "Pyramid height"),
+                base_width: safe_gen_range_cosmetic(25.0, 80.0, "Pyramid width"),
+                height: safe_gen_range_cosmetic(30.0, 100.0, "Pyramid height"),
             });
         }
     }
@@ -114,7 +114,7 @@ pub fn generate_border_pyramids(
     for i in 0..10 {
         // Random spacing for more natural appearance
         let pyramid_y = middle_line
-            + safe_gen_range(
+            + safe_gen_range_cosmetic(
                 i as f64 * 30.0,
                 (i as f64 + 1.0) * 60.0,
                 "Right border y pos",
@@ -123,10 +123,10 @@ pub fn generate_border_pyramids(
         // Make sure we stay within the vertical range
         if pyramid_y < bottom_border + 100.0 {
             pyramids.push(Pyramid {
-                x: right_border + safe_gen_range(5.0, 40.0, "Right pyramid offset"), // Place them outside
+                x: right_border + safe_gen_range_cosmetic(5.0, 40.0, "Right pyramid offset"), // Place them outside
                 y: pyramid_y,
-                base_width: safe_gen_range(25.0, 80.0, "Pyramid width"),
-                height: safe_gen_range(30.0, 100.0, "Pyramid height"),
+                base_width: safe_gen_range_cosmetic(25.0, 80.0, "Pyramid width"),
+                height: safe_gen_range_cosmetic(30.0, 100.0, "Pyramid height"),
             });
         }
     }
@@ -135,7 +135,7 @@ pub fn generate_border_pyramids(
     for i in 0..10 {
         // Random spacing for more natural appearance
         let pyramid_x = left_border
-            + safe_gen_range(
+            + safe_gen_range_cosmetic(
                 i as f64 * 50.0,
                 (i as f64 + 1.0) * 60.0,
                 "Bottom border x pos",
@@ -145,18 +145,18 @@ pub fn generate_border_pyramids(
         if pyramid_x < right_border + 100.0 {
             pyramids.push(Pyramid {
                 x: pyramid_x,
-                y: bottom_border + safe_gen_range(5.0, 40.0, "Bottom pyramid offset"), // Place them outside
-                base_width: safe_gen_range(25.0, 80.0, "Pyramid width"),
-                height: safe_gen_range(30.0, 100.0, "Pyramid height"),
+                y: bottom_border + safe_gen_range_cosmetic(5.0, 40.0, "Bottom pyramid offset"), // Place them outside
+                base_width: safe_gen_range_cosmetic(25.0, 80.0, "Pyramid width"),
+                height: safe_gen_range_cosmetic(30.0, 100.0, "Pyramid height"),
             });
         }
     }
 
     // Add a few random pyramids in the playable area for consistency with the original design
     for _ in 0..10 {
-        let pyramid_x = safe_gen_range(50.0, 1870.0, "Pyramid x");
-        let pyramid_base_width = safe_gen_range(25.0, 100.0, "Pyramid width");
-        let pyramid_height = safe_gen_range(25.0, 100.0, "Pyramid height");
+        let pyramid_x = safe_gen_range_cosmetic(50.0, 1870.0, "Pyramid x");
+        let pyramid_base_width = safe_gen_range_cosmetic(25.0, 100.0, "Pyramid width");
+        let pyramid_height = safe_gen_range_cosmetic(25.0, 100.0, "Pyramid height");
 
         pyramids.push(Pyramid {
             x: pyramid_x,
@@ -184,15 +184,15 @@ pub fn generate_border_pyramids(
     // Left border pyramids
     for i in 0..10 {
         // Random spacing for more natural appearance
-        let pyramid_y = middle_line + safe_gen_range(i as f64 * 30.0, (i as f64 + 1.0) * 60.0, "Left border y pos");
+        let pyramid_y = middle_line + safe_gen_range_cosmetic(i as f64 * 30.0, (i as f64 + 1.0) * 60.0, "Left border y pos");
 
         // Make sure we stay within the vertical range
         if pyramid_y < bottom_border + 100.0 {
             pyramids.push(Pyramid {
-                x: left_border - safe_gen_range(5.0, 40.0, "Left pyramid offset"), // Place them outside
+                x: left_border - safe_gen_range_cosmetic(5.0, 40.0, "Left pyramid offset"), // Place them outside
                 y: pyramid_y,
-                base_width: safe_gen_range(25.0, 80.0, "Pyramid width"),
-                height: safe_gen_range(30.0, 100.0, "Pyramid height"),
+                base_width: safe_gen_range_cosmetic(25.0, 80.0, "Pyramid width"),
+                height: safe_gen_range_cosmetic(30.0, 100.0, "Pyramid height"),
             });
         }
     }
@@ -200,15 +200,15 @@ pub fn generate_border_pyramids(
     // Right border pyramids
     for i in 0..10 {
         // Random spacing for more natural appearance
-        let pyramid_y = middle_line + safe_gen_range(i as f64 * 30.0, (i as f64 + 1.0) * 60.0, "Right border y pos");
+        let pyramid_y = middle_line + safe_gen_range_cosmetic(i as f64 * 30.0, (i as f64 + 1.0) * 60.0, "Right border y pos");
 
         // Make sure we stay within the vertical range
         if pyramid_y < bottom_border + 100.0 {
             pyramids.push(Pyramid {
-                x: right_border + safe_gen_range(5.0, 40.0, "Right pyramid offset"), // Place them outside
+                x: right_border + safe_gen_range_cosmetic(5.0, 40.0, "Right pyramid offset"), // Place them outside
                 y: pyramid_y,
-                base_width: safe_gen_range(25.0, 80.0, "Pyramid width"),
-                height: safe_gen_range(30.0, 100.0, "Pyramid height"),
+                base_width: safe_gen_range_cosmetic(25.0, 80.0, "Pyramid width"),
+                height: safe_gen_range_cosmetic(30.0, 100.0, "Pyramid height"),
             });
         }
     }
@@ -216,24 +216,24 @@ pub fn generate_border_pyramids(
     // Bottom border pyramids
     for i in 0..10 {
         // Random spacing for more natural appearance
-        let pyramid_x = left_border + safe_gen_range(i as f64 * 50.0, (i as f64 + 1.0) * 60.0, "Bottom border x pos");
+        let pyramid_x = left_border + safe_gen_range_cosmetic(i as f64 * 50.0, (i as f64 + 1.0) * 60.0, "Bottom border x pos");
 
         // Make sure we stay within the horizontal range
         if pyramid_x < right_border + 100.0 {
             pyramids.push(Pyramid {
                 x: pyramid_x,
-                y: bottom_border + safe_gen_range(5.0, 40.0, "Bottom pyramid offset"), // Place them outside
-                base_width: safe_gen_range(25.0, 80.0, "Pyramid width"),
-                height: safe_gen_range(30.0, 100.0, "Pyramid height"),
+                y: bottom_border + safe_gen_range_cosmetic(5.0, 40.0, "Bottom pyramid offset"), // Place them outside
+                base_width: safe_gen_range_cosmetic(25.0, 80.0, "Pyramid width"),
+                height: safe_gen_range_cosmetic(30.0, 100.0, "Pyramid height"),
             });
         }
     }
 
     // Add a few random pyramids in the playable area for consistency with the original design
     for _ in 0..10 {
-        let pyramid_x = safe_gen_range(50.0, 1870.0, "Pyramid x");
-        let pyramid_base_width = safe_gen_range(25.0, 100.0, "Pyramid width");
-        let pyramid_height = safe_gen_range(25.0, 100.0, "Pyramid height");
+        let pyramid_x = safe_gen_range_cosmetic(50.0, 1870.0, "Pyramid x");
+        let pyramid_base_width = safe_gen_range_cosmetic(25.0, 100.0, "Pyramid width");
+        let pyramid_height = safe_gen_range_cosmetic(25.0, 100.0, "Pyramid height");
 
         pyramids.push(Pyramid {
             x: pyramid_x,
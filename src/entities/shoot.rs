@@ -1,10 +1,12 @@
 // File: src/entities/shoot.rs
 
-use crate::config::CPU_ENABLED;
-use crate::entities::cpu_entity::CpuEntity;
+use crate::config;
+use crate::combat::damage::{self, Attack, DamageType, Defense, TextStyle};
+use crate::combat::hitbox::{self, Hitbox, Hurtbox, LINE_HITBOX_RADIUS, MAX_HURTBOX_REACH};
+use crate::combat::melee::HitTargets;
+use crate::combat::status_effects::StatusEffect;
+use crate::combat::threat::ThreatTarget;
 use crate::entities::fighter::Fighter;
-use crate::entities::cpu_racer::CpuRacer;
-use crate::utils::collision::check_line_collision;
 use crate::DamageText;
 use crate::RacerState;
 use crate::utils::vec2d::Vec2d;
@@ -69,13 +71,11 @@ impl Shoot {
     pub fn update(
         &mut self,
         dt: f64,
-        cpu_entities: &mut Vec<CpuEntity>,
-		cpu_racers: &mut Vec<CpuRacer>,
+        targets: HitTargets<'_>,
         fighter: &mut Fighter,
-        damage_texts: &mut Vec<DamageText>,
         is_paused: bool,
-		current_field: crate::map_system::FieldId,
     ) {
+        let HitTargets { cpu_entities, cpu_grid, cpu_racers, damage_texts, current_field, audio_manager, current_time } = targets;
         // Timers always run, even when paused, to manage cooldowns and visual effects.
         if self.cooldown > 0.0 {
             self.cooldown -= dt;
@@ -95,20 +95,26 @@ impl Shoot {
             return;
         }
 
-        if self.active && CPU_ENABLED {
+        if self.active && config::get().cpu_enabled {
             let mut hit_entity = false;
-            for cpu_entity in cpu_entities.iter_mut() {
+            let shot_hitbox = Hitbox::line(self.start_x, self.start_y, self.target_x, self.target_y);
+            hitbox::record(shot_hitbox);
+            let mut candidates = Vec::new();
+            cpu_grid.candidates_along_segment(
+                (self.start_x, self.start_y),
+                (self.target_x, self.target_y),
+                LINE_HITBOX_RADIUS + MAX_HURTBOX_REACH,
+                cpu_entities.len(),
+                &mut candidates,
+            );
+            for &index in &candidates {
+                let cpu_entity = &mut cpu_entities[index];
                 if cpu_entity.entity_state == crate::game_state::EntityState::Friendly {
                     continue;
                 }				
-                if check_line_collision(
-                    self.start_x,
-                    self.start_y,
-                    self.target_x,
-                    self.target_y,
-                    cpu_entity.x,
-                    cpu_entity.y,
-                ) {
+                let hurtbox = Hurtbox::for_cpu(cpu_entity);
+                if shot_hitbox.hits(&hurtbox) {
+                    hitbox::record_hit(&hurtbox);
                     let mut damage = fighter.ranged_damage;
 
                     // Apply damage reduction if on bike
@@ -126,28 +132,24 @@ impl Shoot {
                         damage *= 1.25;
                     }					
 
-                    cpu_entity.current_hp -= damage;
-
-                    let ranged_dmg_color = if fighter.invincible_timer > 1.0 {
-                        [0.7, 1.0, 0.0, 1.0] // Cyan — ATOMIC-STATE active
-                    } else {
-                        [1.0, 1.0, 1.0, 1.0] // White
-                    };
-					
+                    let hit = damage::resolve(
+                        &Attack::with_stats(damage, DamageType::Ballistic, &fighter.stats.attack),
+                        &Defense::cpu(cpu_entity),
+                    );
 					if damage_texts.len() < 100 {
-						damage_texts.push(DamageText {
-							text: format!("{:.0}", damage),
-							x: cpu_entity.x,
-							y: cpu_entity.y - 50.0,
-							color: ranged_dmg_color,
-							lifetime: 0.25,
-						});
+						damage_texts.push(hit.text(cpu_entity.x, cpu_entity.y - 50.0));
 					}	
+                    if !hit.landed() {
+                        // Dodged or blocked: the shot is spent
+                        hit_entity = true;
+                        break;
+                    }
+                    cpu_entity.current_hp -= hit.amount;
+                    cpu_entity.threat.add(ThreatTarget::Player, hit.threat);
 
                     // Apply bleed effect for RACER only
                     if fighter.fighter_type == crate::game_state::FighterType::Racer {
-                        use crate::entities::cpu_entity::BleedEffect;
-                        cpu_entity.bleed_effect = Some(BleedEffect::new(50.0));
+                        cpu_entity.status_effects.apply(StatusEffect::bleed(50.0));
 
                         // Add bleed application text
 						if damage_texts.len() < 100 {
@@ -157,6 +159,7 @@ impl Shoot {
 								y: cpu_entity.y - 70.0,
 								color: [1.0, 0.0, 0.0, 1.0], // Red
 								lifetime: 0.5,
+								style: TextStyle::Normal,
 							});
 						}
 					}
@@ -178,17 +181,19 @@ impl Shoot {
                 for cr in cpu_racers.iter_mut() {
                     if cr.is_crashed { continue; }
 					if cr.entity_state == crate::game_state::EntityState::Friendly { continue; }
-                    if check_line_collision(
-                        self.start_x,
-                        self.start_y,
-                        self.target_x,
-                        self.target_y,
-                        cr.x,
-                        cr.y,
-                    ) {
+                    let hurtbox = Hurtbox::for_racer(cr);
+                    if shot_hitbox.hits(&hurtbox) {
+					    hitbox::record_hit(&hurtbox);
 					    if cr.entity_state == crate::game_state::EntityState::Neutral {
 					 	   cr.entity_state = crate::game_state::EntityState::Hostile;
 					    }						
+                        if cr.block_fighter_hit(fighter, audio_manager, current_time) {
+                            if damage_texts.len() < 100 {
+                                damage_texts.push(damage::Hit::blocked(DamageType::Ballistic).text(cr.x, cr.y - 50.0));
+                            }
+                            hit_entity = true;
+                            break;
+                        }
                         let mut damage = fighter.ranged_damage;
                         if fighter.state == RacerState::OnBike {
                             damage *= 0.1;
@@ -200,27 +205,21 @@ impl Shoot {
                             damage *= 1.25;
                         }
  
-                        cr.current_hp -= damage;
- 
-                        let ranged_dmg_color = if fighter.invincible_timer > 1.0 {
-                            [0.7, 1.0, 0.0, 1.0]
-                        } else {
-                            [1.0, 1.0, 1.0, 1.0]
-                        };
- 
+                        let hit = damage::resolve(
+                            &Attack::with_stats(damage, DamageType::Ballistic, &fighter.stats.attack),
+                            &Defense::cpu_racer(cr),
+                        );
                         if damage_texts.len() < 100 {
-                            damage_texts.push(DamageText {
-                                text: format!("{:.0}", damage),
-                                x: cr.x,
-                                y: cr.y - 50.0,
-                                color: ranged_dmg_color,
-                                lifetime: 0.25,
-                            });
+                            damage_texts.push(hit.text(cr.x, cr.y - 50.0));
+                        }
+                        if !hit.landed() {
+                            hit_entity = true;
+                            break;
                         }
+                        cr.current_hp -= hit.amount;
  
                         if fighter.fighter_type == crate::game_state::FighterType::Racer {
-                            use crate::entities::cpu_entity::BleedEffect;
-                            cr.bleed_effect = Some(BleedEffect::new(50.0));
+                            cr.status_effects.apply(StatusEffect::bleed(50.0));
                             if damage_texts.len() < 100 {
                                 damage_texts.push(DamageText {
                                     text: "BLEED".to_string(),
@@ -228,6 +227,7 @@ impl Shoot {
                                     y: cr.y - 70.0,
                                     color: [1.0, 0.0, 0.0, 1.0],
                                     lifetime: 0.5,
+                                    style: TextStyle::Normal,
                                 });
                             }
                         }
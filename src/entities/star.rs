@@ -1,6 +1,6 @@
 // entities/star.rs
 
-use crate::config::PERFORMANCE_MODE;
+use crate::config;
 use piston_window::*;
 use rand::Rng;
 
@@ -18,7 +18,7 @@ pub struct Star {
 
 impl Star {
     pub fn new(x: f64, y: f64) -> Self {
-        let mut rng = rand::rng();
+        let mut rng = crate::utils::rng::cosmetic_rng();
         Star {
             x,
             y,
@@ -30,7 +30,7 @@ impl Star {
     }
 
     pub fn update(&mut self, dt: f64) {
-        if PERFORMANCE_MODE {
+        if config::get().performance_mode {
             return;
         }
         // Update twinkle animation
@@ -41,7 +41,7 @@ impl Star {
     }
 
     pub fn current_brightness(&self) -> f32 {
-        if PERFORMANCE_MODE {
+        if config::get().performance_mode {
             return self.brightness;
         }
         // Calculate current brightness based on sine wave
@@ -1,6 +1,6 @@
 //File: /entities/strike.rs
 
-use crate::utils::math::safe_gen_range;
+use crate::utils::math::safe_gen_range_cosmetic;
 
 /// Represents a melee strike attack
 pub struct Strike {
@@ -38,7 +38,7 @@ impl Strike {
         self.visible = true;
         self.timer = 0.1;
         // Use safe range for angle
-        self.angle = safe_gen_range(-125.0, 125.0, "Strike angle");
+        self.angle = safe_gen_range_cosmetic(-125.0, 125.0, "Strike angle");
     }
 
     pub fn update(&mut self, dt: f64) {
@@ -0,0 +1,90 @@
+// entities/turret.rs
+
+use crate::combat::damage::DamageType;
+use crate::combat::status_effects::StatusEffect;
+use crate::entities::pulse_orb::PulseOrb;
+use piston_window::*;
+
+/// A turret deployed by the Turret skill. Shoots pulse orbs at a target in range
+/// every `interval` seconds until its lifetime runs out.
+pub struct Turret {
+    pub x: f64,
+    pub y: f64,
+    pub range: f64,
+    pub lifetime: f64,
+    pub max_lifetime: f64,
+    pub interval: f64,
+    pub fire_timer: f64,
+    pub aim: (f64, f64), // last target, for the barrel
+    pub from_player: bool,
+    // Shot settings
+    pub shot_speed: f64,
+    pub shot_radius: f64,
+    pub damage: f64,
+    pub damage_type: DamageType,
+    pub status: Option<StatusEffect>,
+}
+
+impl Turret {
+    pub fn is_active(&self) -> bool {
+        self.lifetime > 0.0
+    }
+
+    /// Counts down and, once the fire timer is up and `target` is in range, fires at it.
+    pub fn update(&mut self, dt: f64, target: Option<(f64, f64)>) -> Option<PulseOrb> {
+        self.lifetime -= dt;
+        self.fire_timer -= dt;
+        if !self.is_active() || self.fire_timer > 0.0 {
+            return None;
+        }
+        let (tx, ty) = target?;
+        let dx = tx - self.x;
+        let dy = ty - self.y;
+        if dx * dx + dy * dy > self.range * self.range {
+            return None;
+        }
+        self.fire_timer = self.interval;
+        self.aim = (tx, ty);
+        Some(PulseOrb {
+            radius: self.shot_radius,
+            lifetime: self.range / self.shot_speed.max(1.0),
+            from_player: self.from_player,
+            damage: self.damage,
+            damage_type: self.damage_type,
+            status: self.status.clone(),
+            ..PulseOrb::new(self.x, self.y, tx, ty, self.shot_speed)
+        })
+    }
+
+    pub fn draw(&self, c: Context, g: &mut G2d) {
+        if !self.is_active() {
+            return;
+        }
+        let color = if self.from_player {
+            [0.4, 0.9, 0.4, 1.0]
+        } else {
+            [0.9, 0.3, 0.3, 1.0]
+        };
+        // Fade out over the last second
+        let alpha = self.lifetime.min(1.0) as f32;
+        let base = [color[0], color[1], color[2], alpha];
+
+        let dx = self.aim.0 - self.x;
+        let dy = self.aim.1 - self.y;
+        let len = (dx * dx + dy * dy).sqrt();
+        let (dir_x, dir_y) = if len > 0.0 { (dx / len, dy / len) } else { (1.0, 0.0) };
+        line(
+            [0.2, 0.2, 0.2, alpha],
+            4.0,
+            [self.x, self.y, self.x + dir_x * 30.0, self.y + dir_y * 30.0],
+            c.transform,
+            g,
+        );
+        rectangle(base, [self.x - 14.0, self.y - 14.0, 28.0, 28.0], c.transform, g);
+
+        // Remaining lifetime
+        let fraction = (self.lifetime / self.max_lifetime).clamp(0.0, 1.0);
+        rectangle([0.0, 0.0, 0.0, alpha], [self.x - 16.0, self.y + 20.0, 32.0, 4.0], c.transform, g);
+        rectangle(base, [self.x - 16.0, self.y + 20.0, 32.0 * fraction, 4.0], c.transform, g);
+    }
+}
@@ -145,6 +145,21 @@ impl FogOfWar {
             .or_insert_with(|| FogGrid::new(bounds, tile_size))
     }
 
+    /// Restores a field's explored tiles from a save (row-major, `width * height` entries).
+    /// Saved grids whose size no longer matches the current tile layout are skipped.
+    pub fn restore_explored(&mut self, field_id: SbrxFieldId, width: usize, height: usize, explored: &[bool]) {
+        let grid = self.get_or_create_grid_mut(field_id);
+        if grid.width != width || grid.height != height || explored.len() != width * height {
+            println!("Skipping saved fog for field {:?}: grid size changed", field_id);
+            return;
+        }
+        for (y, row) in grid.tiles.iter_mut().enumerate() {
+            for (x, tile) in row.iter_mut().enumerate() {
+                tile.explored = explored[y * width + x];
+            }
+        }
+    }
+
     pub fn is_fog_enabled(&self, field_id: SbrxFieldId) -> bool {
         self.enabled_fields.contains(&field_id)
     }
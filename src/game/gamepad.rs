@@ -0,0 +1,415 @@
+// game/gamepad.rs
+
+use crate::config::GamepadConfig;
+use crate::game::input_handler::{Action, KeyBindings};
+use firmament_lib::JetControl;
+use gilrs::{Axis as PadAxis, Button as PadButton, EventType, Gilrs};
+use piston_window::*;
+
+/// Axis ids the pad state is kept in (SDL game controller layout). Replays store and
+/// replay the axes under these ids.
+pub mod axis {
+    pub const LEFT_X: u8 = 0;
+    pub const LEFT_Y: u8 = 1;
+    pub const RIGHT_X: u8 = 2;
+    pub const RIGHT_Y: u8 = 3;
+    pub const LEFT_TRIGGER: u8 = 4;
+    pub const RIGHT_TRIGGER: u8 = 5;
+}
+
+/// Button ids gilrs buttons are mapped onto (SDL game controller layout).
+pub mod button {
+    pub const A: u8 = 0;
+    pub const B: u8 = 1;
+    pub const X: u8 = 2;
+    pub const Y: u8 = 3;
+    pub const BACK: u8 = 4;
+    pub const START: u8 = 6;
+    pub const LEFT_STICK: u8 = 7;
+    pub const RIGHT_STICK: u8 = 8;
+    pub const LEFT_SHOULDER: u8 = 9;
+    pub const RIGHT_SHOULDER: u8 = 10;
+    pub const DPAD_UP: u8 = 11;
+    pub const DPAD_DOWN: u8 = 12;
+    pub const DPAD_LEFT: u8 = 13;
+    pub const DPAD_RIGHT: u8 = 14;
+}
+
+/// Which game the pad is currently driving.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode {
+    Field,
+    Jet,
+}
+
+/// What a pad button stands for on foot / on the bike.
+enum PadBinding {
+    Action(Action),
+    // Fixed keys that are not actions (pause, menu choices, FirmamentMode eject).
+    Key(Key),
+}
+
+fn field_binding(pad_button: u8) -> Option<PadBinding> {
+    match pad_button {
+        button::A => Some(PadBinding::Action(Action::Rush)),
+        button::B => Some(PadBinding::Action(Action::Interact)),
+        button::X => Some(PadBinding::Action(Action::Reload)),
+        button::Y => Some(PadBinding::Action(Action::Mount)),
+        button::LEFT_SHOULDER => Some(PadBinding::Action(Action::Stance)),
+        button::RIGHT_SHOULDER => Some(PadBinding::Action(Action::Boost)),
+        button::BACK => Some(PadBinding::Action(Action::ToggleTasks)),
+        button::LEFT_STICK => Some(PadBinding::Action(Action::LevelUp)),
+        button::RIGHT_STICK => Some(PadBinding::Action(Action::ToggleInputDisplay)),
+        button::DPAD_UP => Some(PadBinding::Action(Action::SwitchRacer)),
+        button::DPAD_LEFT => Some(PadBinding::Action(Action::SwitchSoldier)),
+        button::DPAD_RIGHT => Some(PadBinding::Action(Action::SwitchRaptor)),
+        button::START => Some(PadBinding::Key(Key::Escape)),
+        _ => None,
+    }
+}
+
+fn jet_binding(pad_button: u8) -> Option<JetControl> {
+    match pad_button {
+        button::A => Some(JetControl::Rush),
+        button::B => Some(JetControl::Restart),
+        button::LEFT_SHOULDER => Some(JetControl::Brake),
+        button::BACK => Some(JetControl::ToggleTasks),
+        _ => None,
+    }
+}
+
+/// Result of translating controller input.
+pub enum PadOutput {
+    /// Keyboard/mouse events to feed through the normal input path.
+    Event(Event),
+    /// A jet control for FirmamentMode (true = pressed).
+    Jet(JetControl, bool),
+}
+
+/// Tracks controller state and turns it into the same actions as keyboard and mouse.
+/// Buttons and triggers become synthetic key/mouse events for the first key bound to the
+/// matching action, so rebinding, replays and every existing key handler keep working.
+/// Stick positions are read directly for analog movement and the aim reticle.
+/// Controllers are polled through gilrs; the window backend never reports them.
+pub struct Gamepad {
+    gilrs: Option<Gilrs>,
+    axes: [f64; 6],
+    // Digital directions currently emitted from the left stick: up, down, left, right.
+    stick_directions: [bool; 4],
+    left_trigger_down: bool,
+    right_trigger_down: bool,
+}
+
+impl Gamepad {
+    /// Opens the controller backend when `enabled`. Without it the pad just stays idle.
+    pub fn new(enabled: bool) -> Self {
+        let gilrs = if enabled {
+            match Gilrs::new() {
+                Ok(gilrs) => Some(gilrs),
+                Err(e) => {
+                    println!("[GAMEPAD] Controller support unavailable: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        Gamepad {
+            gilrs,
+            axes: [0.0; 6],
+            stick_directions: [false; 4],
+            left_trigger_down: false,
+            right_trigger_down: false,
+        }
+    }
+
+    /// Raw axis positions, for the replay recorder.
+    pub fn axes(&self) -> [f64; 6] {
+        self.axes
+    }
+
+    /// Sets one raw axis without emitting any key events. Used by replay playback, whose
+    /// recorded frames already hold the keys the stick produced.
+    pub fn set_axis(&mut self, axis: u8, position: f64) {
+        if let Some(slot) = self.axes.get_mut(axis as usize) {
+            *slot = position;
+        }
+    }
+
+    /// Left stick after its deadzone, rescaled so the edge of the deadzone reads as zero.
+    pub fn left_stick(&self, config: &GamepadConfig) -> Option<[f64; 2]> {
+        apply_deadzone(self.axes[axis::LEFT_X as usize], self.axes[axis::LEFT_Y as usize], config.left_stick_deadzone)
+    }
+
+    pub fn right_stick(&self, config: &GamepadConfig) -> Option<[f64; 2]> {
+        apply_deadzone(self.axes[axis::RIGHT_X as usize], self.axes[axis::RIGHT_Y as usize], config.right_stick_deadzone)
+    }
+
+    /// Screen position of the aim reticle around `origin` (the fighter on screen), if the
+    /// right stick is held.
+    pub fn aim_cursor(&self, config: &GamepadConfig, origin: (f64, f64)) -> Option<(f64, f64)> {
+        let [x, y] = self.right_stick(config)?;
+        let length = (x * x + y * y).sqrt();
+        Some((
+            origin.0 + x / length * config.aim_radius,
+            origin.1 + y / length * config.aim_radius,
+        ))
+    }
+
+    /// Analog jet steering for FirmamentMode: (turn, thrust) from the left stick and right trigger.
+    pub fn jet_controls(&self, config: &GamepadConfig) -> (f64, f64) {
+        let stick = self.left_stick(config).unwrap_or([0.0, 0.0]);
+        let trigger = trigger_value(self.axes[axis::RIGHT_TRIGGER as usize]);
+        let thrust = trigger.max(-stick[1]).max(0.0);
+        (stick[0], thrust)
+    }
+
+    /// Drains the controller events gilrs has queued since the last call and translates them.
+    pub fn poll(&mut self, mode: PadMode, bindings: &KeyBindings, config: &GamepadConfig) -> Vec<PadOutput> {
+        let mut out = Vec::new();
+        while let Some(event) = self.gilrs.as_mut().and_then(|gilrs| gilrs.next_event()) {
+            match event.event {
+                EventType::AxisChanged(pad_axis, value, _) => {
+                    if let Some((axis, position)) = axis_id(pad_axis, value) {
+                        self.axis_changed(axis, position, mode, bindings, config, &mut out);
+                    }
+                }
+                // Analog triggers arrive as button values in 0..1.
+                EventType::ButtonChanged(PadButton::LeftTrigger2, value, _) => {
+                    self.axis_changed(axis::LEFT_TRIGGER, value as f64, mode, bindings, config, &mut out)
+                }
+                EventType::ButtonChanged(PadButton::RightTrigger2, value, _) => {
+                    self.axis_changed(axis::RIGHT_TRIGGER, value as f64, mode, bindings, config, &mut out)
+                }
+                EventType::ButtonPressed(pad_button, _) => {
+                    if let Some(id) = button_id(pad_button) {
+                        self.button_changed(id, true, mode, bindings, &mut out);
+                    }
+                }
+                EventType::ButtonReleased(pad_button, _) => {
+                    if let Some(id) = button_id(pad_button) {
+                        self.button_changed(id, false, mode, bindings, &mut out);
+                    }
+                }
+                EventType::Disconnected => {
+                    println!("[GAMEPAD] Controller disconnected.");
+                    out.extend(self.release_all(bindings).into_iter().map(PadOutput::Event));
+                    self.axes = [0.0; 6];
+                }
+                EventType::Connected => println!("[GAMEPAD] Controller connected."),
+                _ => {}
+            }
+        }
+        out
+    }
+
+    fn axis_changed(
+        &mut self,
+        axis: u8,
+        position: f64,
+        mode: PadMode,
+        bindings: &KeyBindings,
+        config: &GamepadConfig,
+        out: &mut Vec<PadOutput>,
+    ) {
+        self.set_axis(axis, position);
+        match (mode, axis) {
+            (PadMode::Field, axis::LEFT_X | axis::LEFT_Y) => self.update_stick_directions(bindings, config, out),
+            (PadMode::Field, axis::LEFT_TRIGGER) => {
+                // Block, like holding the right mouse button.
+                let down = trigger_value(position) >= config.trigger_threshold;
+                if down != self.left_trigger_down {
+                    self.left_trigger_down = down;
+                    out.push(PadOutput::Event(button_event(Button::Mouse(MouseButton::Right), down)));
+                }
+            }
+            (PadMode::Field, axis::RIGHT_TRIGGER) => {
+                // Strike / shoot, like the left mouse button.
+                let down = trigger_value(position) >= config.trigger_threshold;
+                if down != self.right_trigger_down {
+                    self.right_trigger_down = down;
+                    out.push(PadOutput::Event(button_event(Button::Mouse(MouseButton::Left), down)));
+                }
+            }
+            // In the jet the sticks and right trigger are read each tick by jet_controls().
+            _ => {}
+        }
+    }
+
+    fn button_changed(&mut self, pad_button: u8, pressed: bool, mode: PadMode, bindings: &KeyBindings, out: &mut Vec<PadOutput>) {
+        match mode {
+            PadMode::Field => match field_binding(pad_button) {
+                Some(PadBinding::Action(action)) => {
+                    if let Some(key) = bindings.keys(action).first() {
+                        out.push(PadOutput::Event(button_event(Button::Keyboard(*key), pressed)));
+                    }
+                }
+                Some(PadBinding::Key(key)) => out.push(PadOutput::Event(button_event(Button::Keyboard(key), pressed))),
+                None => {}
+            },
+            PadMode::Jet => {
+                if pad_button == button::RIGHT_SHOULDER {
+                    if pressed {
+                        out.push(PadOutput::Jet(JetControl::Fire, true));
+                    }
+                } else if pad_button == button::START {
+                    out.push(PadOutput::Event(button_event(Button::Keyboard(Key::Escape), pressed)));
+                } else if pad_button == button::DPAD_DOWN {
+                    // Eject, same as [F5] in FirmamentMode.
+                    out.push(PadOutput::Event(button_event(Button::Keyboard(Key::F5), pressed)));
+                } else if let Some(control) = jet_binding(pad_button) {
+                    out.push(PadOutput::Jet(control, pressed));
+                }
+            }
+        }
+    }
+
+    /// Releases everything the pad is holding, e.g. when switching between field and jet.
+    pub fn release_all(&mut self, bindings: &KeyBindings) -> Vec<Event> {
+        let mut out = Vec::new();
+        for (i, action) in MOVE_ACTIONS.iter().enumerate() {
+            if self.stick_directions[i] {
+                if let Some(key) = bindings.keys(*action).first() {
+                    out.push(button_event(Button::Keyboard(*key), false));
+                }
+            }
+        }
+        if self.left_trigger_down {
+            out.push(button_event(Button::Mouse(MouseButton::Right), false));
+        }
+        if self.right_trigger_down {
+            out.push(button_event(Button::Mouse(MouseButton::Left), false));
+        }
+        self.stick_directions = [false; 4];
+        self.left_trigger_down = false;
+        self.right_trigger_down = false;
+        out
+    }
+
+    fn update_stick_directions(&mut self, bindings: &KeyBindings, config: &GamepadConfig, out: &mut Vec<PadOutput>) {
+        // Half tilt on an axis counts as holding that direction's key.
+        let [x, y] = self.left_stick(config).unwrap_or([0.0, 0.0]);
+        let directions = [y < -0.5, y > 0.5, x < -0.5, x > 0.5];
+        for (i, action) in MOVE_ACTIONS.iter().enumerate() {
+            if directions[i] != self.stick_directions[i] {
+                if let Some(key) = bindings.keys(*action).first() {
+                    out.push(PadOutput::Event(button_event(Button::Keyboard(*key), directions[i])));
+                }
+            }
+        }
+        self.stick_directions = directions;
+    }
+}
+
+const MOVE_ACTIONS: [Action; 4] = [Action::MoveUp, Action::MoveDown, Action::MoveLeft, Action::MoveRight];
+
+/// Maps a gilrs axis onto our axis ids. gilrs reports stick Y up as positive, so it is
+/// flipped to the screen's down-positive convention.
+fn axis_id(pad_axis: PadAxis, value: f32) -> Option<(u8, f64)> {
+    let value = value as f64;
+    match pad_axis {
+        PadAxis::LeftStickX => Some((axis::LEFT_X, value)),
+        PadAxis::LeftStickY => Some((axis::LEFT_Y, -value)),
+        PadAxis::RightStickX => Some((axis::RIGHT_X, value)),
+        PadAxis::RightStickY => Some((axis::RIGHT_Y, -value)),
+        // Some pads report the triggers as Z axes in -1..1 instead of button values.
+        PadAxis::LeftZ => Some((axis::LEFT_TRIGGER, value)),
+        PadAxis::RightZ => Some((axis::RIGHT_TRIGGER, value)),
+        _ => None,
+    }
+}
+
+fn button_id(pad_button: PadButton) -> Option<u8> {
+    match pad_button {
+        PadButton::South => Some(button::A),
+        PadButton::East => Some(button::B),
+        PadButton::West => Some(button::X),
+        PadButton::North => Some(button::Y),
+        PadButton::Select => Some(button::BACK),
+        PadButton::Start => Some(button::START),
+        PadButton::LeftThumb => Some(button::LEFT_STICK),
+        PadButton::RightThumb => Some(button::RIGHT_STICK),
+        PadButton::LeftTrigger => Some(button::LEFT_SHOULDER),
+        PadButton::RightTrigger => Some(button::RIGHT_SHOULDER),
+        PadButton::DPadUp => Some(button::DPAD_UP),
+        PadButton::DPadDown => Some(button::DPAD_DOWN),
+        PadButton::DPadLeft => Some(button::DPAD_LEFT),
+        PadButton::DPadRight => Some(button::DPAD_RIGHT),
+        _ => None,
+    }
+}
+
+/// Radial deadzone: returns None inside it, otherwise the stick rescaled to 0..1 magnitude.
+fn apply_deadzone(x: f64, y: f64, deadzone: f64) -> Option<[f64; 2]> {
+    let length = (x * x + y * y).sqrt();
+    if length <= deadzone || length == 0.0 {
+        return None;
+    }
+    let scaled = ((length - deadzone) / (1.0 - deadzone)).min(1.0);
+    Some([x / length * scaled, y / length * scaled])
+}
+
+/// Triggers report either 0..1 or -1..1 depending on the backend; normalise to 0..1.
+fn trigger_value(position: f64) -> f64 {
+    if position < 0.0 {
+        (position + 1.0) / 2.0
+    } else {
+        position
+    }
+}
+
+fn button_event(button: Button, pressed: bool) -> Event {
+    Event::Input(
+        Input::Button(ButtonArgs {
+            state: if pressed { ButtonState::Press } else { ButtonState::Release },
+            button,
+            scancode: None,
+        }),
+        None,
+    )
+}
+
+/// Combines digital movement with the left stick. With the stick held its analog vector
+/// wins; otherwise each held direction counts as full tilt.
+pub fn movement_axes(up: bool, down: bool, left: bool, right: bool, stick: Option<[f64; 2]>) -> (f64, f64) {
+    if let Some([x, y]) = stick {
+        return (x, y);
+    }
+    let mut dx = 0.0;
+    let mut dy = 0.0;
+    if up {
+        dy -= 1.0;
+    }
+    if down {
+        dy += 1.0;
+    }
+    if left {
+        dx -= 1.0;
+    }
+    if right {
+        dx += 1.0;
+    }
+    (dx, dy)
+}
+
+/// Coarse 8-way direction of a movement vector, each component -1, 0 or 1. Analog input
+/// wobbles constantly, so "still moving the same way" checks compare this instead.
+pub fn direction_octant(dx: f64, dy: f64) -> (f64, f64) {
+    let mag = (dx * dx + dy * dy).sqrt();
+    // sin(22.5°): components smaller than this share of the vector don't count.
+    let threshold = mag * 0.383;
+    let step = |v: f64| {
+        if v > threshold {
+            1.0
+        } else if v < -threshold {
+            -1.0
+        } else {
+            0.0
+        }
+    };
+    if mag == 0.0 {
+        (0.0, 0.0)
+    } else {
+        (step(dx), step(dy))
+    }
+}
@@ -1,271 +1,629 @@
 // game/input_handler.rs
 
-use crate::audio::AudioManager;
-use crate::entities::cpu_entity::CpuEntity;
-use crate::entities::fighter::Fighter;
-use crate::entities::fixed_crater::FixedCrater;
-use crate::entities::shoot::Shoot;
-use crate::entities::strike::Strike;
-use crate::game_state::{FighterType, MovementDirection};
-use crate::graphics::camera::screen_to_world;
-use crate::graphics::camera::Camera;
-use crate::utils::collision::check_line_collision;
 use piston_window::*;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 
-#[allow(dead_code)] // TODO: Wire up to event system
-pub fn handle_mouse_press<'a>(
-    button: MouseButton,
-    fighter: &mut Fighter,
-    camera: &Camera,
-    mouse_x: f64,
-    mouse_y: f64,
-    fixed_crater: &mut FixedCrater,
-    strike: &mut Strike,
-    shoot: &mut Shoot,
-    cpu_entities: &mut Vec<CpuEntity>,
-    line_y: f64,
-    current_racer_texture: &mut &'a G2dTexture,
-    current_block_texture: &'a G2dTexture,
-    current_ranged_texture: &'a G2dTexture,
-    current_strike_textures: &'a Vec<G2dTexture>,
-    strike_frame: &mut usize,
-    strike_animation_timer: &mut f64,
-    block_active: &mut bool,
-    rmb_held: &mut bool,
-    audio_manager: &AudioManager,
-) {
-    match button {
-        MouseButton::Left => {
-            *block_active = false;
-            *rmb_held = false;
-            let (world_mouse_x, world_mouse_y) = screen_to_world(camera, mouse_x, mouse_y);
-            let dx = world_mouse_x - fixed_crater.x;
-            let dy = world_mouse_y - fixed_crater.y;
-            let horizontal_radius = fixed_crater.radius;
-            let vertical_radius = fixed_crater.radius * 0.75;
-            let distance_squared = (dx * dx) / (horizontal_radius * horizontal_radius)
-                + (dy * dy) / (vertical_radius * vertical_radius);
-
-            if distance_squared <= 1.0 {
-                // Melee attack - play slash sound
-                audio_manager
-                    .play_sound_effect("death")
-                    .unwrap_or_else(|e| println!("Failed to play melee sound: {}", e));
-
-                strike.trigger(world_mouse_x, world_mouse_y);
-
-                // Always play strike animation for melee attacks
-                if !current_strike_textures.is_empty() {
-                    *current_racer_texture =
-                        &current_strike_textures[*strike_frame % current_strike_textures.len()];
-                    *strike_frame = (*strike_frame + 1) % current_strike_textures.len();
-                }
-                *strike_animation_timer = 0.25;
-
-                // Process CPU hits (reuse existing code from main.rs)
-                if crate::config::CPU_ENABLED {
-                    for cpu_entity in cpu_entities.iter_mut() {
-                        let strike_dx = world_mouse_x - cpu_entity.x;
-                        let strike_dy = world_mouse_y - cpu_entity.y;
-                        if (strike_dx * strike_dx + strike_dy * strike_dy).sqrt() < 50.0 {
-                            cpu_entity.current_hp -= 25.0;
-                            if cpu_entity.is_dead() {
-                                fighter.score = (fighter.score + 1).min(999);
-                                fighter.current_hp =
-                                    (fighter.current_hp + 25.0).min(fighter.max_hp);
-                                cpu_entity.respawn(line_y);
-                            } else {
-                                cpu_entity.apply_knockback(world_mouse_x, world_mouse_y, 400.0);
-                            }
-                        }
-                    }
+/// Name of the key binding file looked up next to the executable.
+pub const BINDINGS_FILE_NAME: &str = "keybindings.cfg";
+
+/// Opens the key binding screen. Fixed so it can never be unbound.
+pub const REBIND_SCREEN_KEY: Key = Key::F9;
+
+/// Keys with a fixed meaning that can't be bound to an action: pause/exit, chatbox,
+/// menu choices ([1]-[3] for bunker entry and fighter select), FirmamentMode eject and debug.
+pub const RESERVED_KEYS: [Key; 12] = [
+    Key::Escape,
+    Key::Return,
+    Key::Up,
+    Key::Down,
+    Key::D1,
+    Key::D2,
+    Key::D3,
+    Key::F5,
+    REBIND_SCREEN_KEY,
+    Key::F10,
+    Key::F11,
+    Key::F12,
+];
+
+/// Something the player can do with the keyboard, independent of which key does it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Rush,
+    Stance, // Held: ranged stance / racer boost / raptor run
+    Skill1,
+    Skill2,
+    Interact,
+    Mount,
+    Boost,
+    Reload,
+    ToggleTasks,
+    ToggleInputDisplay,
+    ToggleGear,
+    ToggleRacerVariant,
+    SwitchRacer,
+    SwitchSoldier,
+    SwitchRaptor,
+    CycleAmbient,
+    PrevTrack,
+    NextTrack,
+    LevelUp,
+    SpendDefense,
+    SpendAttack,
+    SpendSpeed,
+    Confirm,
+    Decline,
+    SaveSlot1,
+    SaveSlot2,
+    SaveSlot3,
+    CycleWindowMode,
+}
+
+impl Action {
+    /// All actions, in the order shown on the binding screen.
+    pub const ALL: [Action; 32] = [
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::Rush,
+        Action::Stance,
+        Action::Skill1,
+        Action::Skill2,
+        Action::Interact,
+        Action::Mount,
+        Action::Boost,
+        Action::Reload,
+        Action::ToggleTasks,
+        Action::ToggleInputDisplay,
+        Action::ToggleGear,
+        Action::ToggleRacerVariant,
+        Action::SwitchRacer,
+        Action::SwitchSoldier,
+        Action::SwitchRaptor,
+        Action::CycleAmbient,
+        Action::PrevTrack,
+        Action::NextTrack,
+        Action::LevelUp,
+        Action::SpendDefense,
+        Action::SpendAttack,
+        Action::SpendSpeed,
+        Action::Confirm,
+        Action::Decline,
+        Action::SaveSlot1,
+        Action::SaveSlot2,
+        Action::SaveSlot3,
+        Action::CycleWindowMode,
+    ];
+
+    /// Name used in the binding file.
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            Action::MoveUp => "move_up",
+            Action::MoveDown => "move_down",
+            Action::MoveLeft => "move_left",
+            Action::MoveRight => "move_right",
+            Action::Rush => "rush",
+            Action::Stance => "stance",
+            Action::Skill1 => "skill_1",
+            Action::Skill2 => "skill_2",
+            Action::Interact => "interact",
+            Action::Mount => "mount",
+            Action::Boost => "boost",
+            Action::Reload => "reload",
+            Action::ToggleTasks => "toggle_tasks",
+            Action::ToggleInputDisplay => "toggle_input_display",
+            Action::ToggleGear => "toggle_gear",
+            Action::ToggleRacerVariant => "toggle_racer_variant",
+            Action::SwitchRacer => "switch_racer",
+            Action::SwitchSoldier => "switch_soldier",
+            Action::SwitchRaptor => "switch_raptor",
+            Action::CycleAmbient => "cycle_ambient",
+            Action::PrevTrack => "prev_track",
+            Action::NextTrack => "next_track",
+            Action::LevelUp => "level_up",
+            Action::SpendDefense => "spend_defense",
+            Action::SpendAttack => "spend_attack",
+            Action::SpendSpeed => "spend_speed",
+            Action::Confirm => "confirm",
+            Action::Decline => "decline",
+            Action::SaveSlot1 => "save_slot_1",
+            Action::SaveSlot2 => "save_slot_2",
+            Action::SaveSlot3 => "save_slot_3",
+            Action::CycleWindowMode => "cycle_window_mode",
+        }
+    }
+
+    /// Label shown on the binding screen.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::MoveUp => "MOVE UP",
+            Action::MoveDown => "MOVE DOWN",
+            Action::MoveLeft => "MOVE LEFT",
+            Action::MoveRight => "MOVE RIGHT",
+            Action::Rush => "RUSH",
+            Action::Stance => "STANCE / BOOST (HOLD)",
+            Action::Skill1 => "SKILL 1",
+            Action::Skill2 => "SKILL 2",
+            Action::Interact => "INTERACT",
+            Action::Mount => "MOUNT BIKE",
+            Action::Boost => "TOGGLE BOOST",
+            Action::Reload => "RELOAD",
+            Action::ToggleTasks => "TASKS",
+            Action::ToggleInputDisplay => "INPUT DISPLAY",
+            Action::ToggleGear => "GEAR",
+            Action::ToggleRacerVariant => "RACER VARIANT",
+            Action::SwitchRacer => "SWITCH TO RACER",
+            Action::SwitchSoldier => "SWITCH TO SOLDIER",
+            Action::SwitchRaptor => "SWITCH TO RAPTOR",
+            Action::CycleAmbient => "CYCLE AMBIENT",
+            Action::PrevTrack => "PREVIOUS TRACK",
+            Action::NextTrack => "NEXT TRACK",
+            Action::LevelUp => "LEVEL UP",
+            Action::SpendDefense => "LEVEL UP: DEF",
+            Action::SpendAttack => "LEVEL UP: ATK",
+            Action::SpendSpeed => "LEVEL UP: SPD",
+            Action::Confirm => "CONFIRM (YES)",
+            Action::Decline => "DECLINE (NO)",
+            Action::SaveSlot1 => "SAVE SLOT 1",
+            Action::SaveSlot2 => "SAVE SLOT 2",
+            Action::SaveSlot3 => "SAVE SLOT 3",
+            Action::CycleWindowMode => "WINDOW MODE",
+        }
+    }
+
+    fn from_config_name(name: &str) -> Option<Action> {
+        Action::ALL.into_iter().find(|a| a.config_name() == name)
+    }
+
+    fn default_keys(&self) -> Vec<Key> {
+        match self {
+            Action::MoveUp => vec![Key::W],
+            Action::MoveDown => vec![Key::S],
+            Action::MoveLeft => vec![Key::A],
+            Action::MoveRight => vec![Key::D],
+            Action::Rush => vec![Key::Space],
+            Action::Stance => vec![Key::LShift, Key::RShift],
+            Action::Skill1 => vec![Key::Q],
+            Action::Skill2 => vec![Key::B],
+            Action::Interact => vec![Key::E],
+            Action::Mount => vec![Key::V],
+            Action::Boost => vec![Key::F],
+            Action::Reload => vec![Key::R],
+            Action::ToggleTasks => vec![Key::T],
+            Action::ToggleInputDisplay => vec![Key::G],
+            Action::ToggleGear => vec![Key::I],
+            Action::ToggleRacerVariant => vec![Key::D0],
+            Action::SwitchRacer => vec![Key::F1],
+            Action::SwitchSoldier => vec![Key::F2],
+            Action::SwitchRaptor => vec![Key::F3],
+            Action::CycleAmbient => vec![Key::L],
+            Action::PrevTrack => vec![Key::Comma],
+            Action::NextTrack => vec![Key::Period],
+            Action::LevelUp => vec![Key::Tab],
+            Action::SpendDefense => vec![Key::Z],
+            Action::SpendAttack => vec![Key::X],
+            Action::SpendSpeed => vec![Key::C],
+            Action::Confirm => vec![Key::Y],
+            Action::Decline => vec![Key::N],
+            Action::SaveSlot1 => vec![Key::F6],
+            Action::SaveSlot2 => vec![Key::F7],
+            Action::SaveSlot3 => vec![Key::F8],
+            Action::CycleWindowMode => vec![Key::F4],
+        }
+    }
+}
+
+/// Keys that can appear in a binding file, by name.
+const BINDABLE_KEYS: [Key; 78] = [
+    Key::A, Key::B, Key::C, Key::D, Key::E, Key::F, Key::G, Key::H, Key::I, Key::J,
+    Key::K, Key::L, Key::M, Key::N, Key::O, Key::P, Key::Q, Key::R, Key::S, Key::T,
+    Key::U, Key::V, Key::W, Key::X, Key::Y, Key::Z,
+    Key::D0, Key::D1, Key::D2, Key::D3, Key::D4, Key::D5, Key::D6, Key::D7, Key::D8, Key::D9,
+    Key::F1, Key::F2, Key::F3, Key::F4, Key::F5, Key::F6, Key::F7, Key::F8, Key::F9,
+    Key::F10, Key::F11, Key::F12,
+    Key::Up, Key::Down, Key::Left, Key::Right,
+    Key::Space, Key::Tab, Key::Return, Key::Escape, Key::Backspace,
+    Key::LShift, Key::RShift, Key::LCtrl, Key::RCtrl, Key::LAlt, Key::RAlt,
+    Key::Comma, Key::Period, Key::Slash, Key::Semicolon, Key::Quote, Key::Minus, Key::Equals,
+    Key::LeftBracket, Key::RightBracket, Key::Backslash, Key::Backquote,
+    Key::NumPad0, Key::NumPad1, Key::NumPad2, Key::NumPad3,
+];
+
+/// Name of a key as written in the binding file and shown on screen.
+pub fn key_name(key: Key) -> String {
+    format!("{:?}", key)
+}
+
+fn parse_key(name: &str) -> Option<Key> {
+    BINDABLE_KEYS
+        .into_iter()
+        .find(|k| key_name(*k).eq_ignore_ascii_case(name))
+}
+
+/// A key that is bound more than once, or bound while reserved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BindingConflict {
+    pub key: Key,
+    pub actions: Vec<Action>,
+    pub reserved: bool,
+}
+
+impl BindingConflict {
+    pub fn describe(&self) -> String {
+        let names: Vec<&str> = self.actions.iter().map(|a| a.label()).collect();
+        if self.reserved {
+            format!("[{}] IS RESERVED BUT BOUND TO {}", key_name(self.key), names.join(", "))
+        } else {
+            format!("[{}] IS BOUND TO {}", key_name(self.key), names.join(" AND "))
+        }
+    }
+}
+
+/// Which keys trigger each action.
+#[derive(Debug, Clone)]
+pub struct KeyBindings {
+    bindings: HashMap<Action, Vec<Key>>,
+    // Reverse lookup, rebuilt whenever bindings change.
+    by_key: HashMap<Key, Action>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let mut bindings = KeyBindings {
+            bindings: Action::ALL.iter().map(|a| (*a, a.default_keys())).collect(),
+            by_key: HashMap::new(),
+        };
+        bindings.rebuild_lookup();
+        bindings
+    }
+}
+
+impl KeyBindings {
+    fn rebuild_lookup(&mut self) {
+        self.by_key.clear();
+        // Iterate in display order so a conflicting key resolves to the earlier action.
+        for action in Action::ALL.iter().rev() {
+            for key in self.bindings.get(action).into_iter().flatten() {
+                self.by_key.insert(*key, *action);
+            }
+        }
+    }
+
+    /// The action a key triggers, if any.
+    pub fn action_for(&self, key: Key) -> Option<Action> {
+        self.by_key.get(&key).copied()
+    }
+
+    pub fn keys(&self, action: Action) -> &[Key] {
+        self.bindings.get(&action).map(|k| k.as_slice()).unwrap_or(&[])
+    }
+
+    /// Short text for the bound keys, e.g. "W" or "LSHIFT/RSHIFT".
+    pub fn keys_label(&self, action: Action) -> String {
+        let keys = self.keys(action);
+        if keys.is_empty() {
+            return "-".to_string();
+        }
+        keys.iter()
+            .map(|k| key_name(*k).to_uppercase())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Replaces an action's keys.
+    pub fn set(&mut self, action: Action, keys: Vec<Key>) {
+        self.bindings.insert(action, keys);
+        self.rebuild_lookup();
+    }
+
+    /// Every key bound to more than one action or to a reserved key.
+    pub fn conflicts(&self) -> Vec<BindingConflict> {
+        let mut users: HashMap<Key, Vec<Action>> = HashMap::new();
+        for action in Action::ALL {
+            for key in self.keys(action) {
+                let actions = users.entry(*key).or_default();
+                if !actions.contains(&action) {
+                    actions.push(action);
                 }
-            } else {
-                if shoot.cooldown <= 0.0 {
-                    // Ranged attack - play ranged sound
-                    audio_manager
-                        .play_sound_effect("ranged")
-                        .unwrap_or_else(|e| println!("Failed to play ranged sound: {}", e));
-
-                    shoot.trigger(fighter.x, fighter.y, world_mouse_x, world_mouse_y);
-                    *current_racer_texture = current_ranged_texture;
-                    *strike_animation_timer = 0.25;
+            }
+        }
+        let mut conflicts: Vec<BindingConflict> = users
+            .into_iter()
+            .filter_map(|(key, actions)| {
+                let reserved = RESERVED_KEYS.contains(&key);
+                (reserved || actions.len() > 1).then_some(BindingConflict { key, actions, reserved })
+            })
+            .collect();
+        conflicts.sort_by_key(|c| Action::ALL.iter().position(|a| *a == c.actions[0]));
+        conflicts
+    }
+
+    /// Parses a binding file. Lines are `action = Key, Key`; unlisted actions keep their defaults.
+    pub fn parse(contents: &str) -> Result<Self, Vec<String>> {
+        let mut bindings = KeyBindings::default();
+        let mut errors = Vec::new();
+        for (i, raw_line) in contents.lines().enumerate() {
+            let line = raw_line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((name, keys_text)) = line.split_once('=') else {
+                errors.push(format!("line {}: expected 'action = Key'", i + 1));
+                continue;
+            };
+            let Some(action) = Action::from_config_name(name.trim()) else {
+                errors.push(format!("line {}: unknown action '{}'", i + 1, name.trim()));
+                continue;
+            };
+            let mut keys = Vec::new();
+            for key_text in keys_text.split(',').map(str::trim).filter(|k| !k.is_empty()) {
+                match parse_key(key_text) {
+                    Some(key) => keys.push(key),
+                    None => errors.push(format!("line {}: unknown key '{}'", i + 1, key_text)),
                 }
             }
+            bindings.bindings.insert(action, keys);
+        }
+        bindings.rebuild_lookup();
+        errors.extend(bindings.conflicts().iter().map(|c| c.describe()));
+        if errors.is_empty() {
+            Ok(bindings)
+        } else {
+            Err(errors)
         }
-        MouseButton::Right => {
-            // Block action - play block sound
-            audio_manager
-                .play_sound_effect("block")
-                .unwrap_or_else(|e| println!("Failed to play block sound: {}", e));
-
-            *rmb_held = true;
-            *current_racer_texture = current_block_texture;
-            *block_active = true;
-
-            // Cancel conflicting movement animations
-            // (reuse existing code from main.rs)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = String::from("# sbrx key bindings: action = Key[, Key]\n");
+        for action in Action::ALL {
+            let keys: Vec<String> = self.keys(action).iter().map(|k| key_name(*k)).collect();
+            out.push_str(&format!("{} = {}\n", action.config_name(), keys.join(", ")));
+        }
+        out
+    }
+
+    /// Loads the binding file if present. Problems are reported and the defaults are used,
+    /// so a broken file never leaves the game unplayable.
+    pub fn load_or_default(path: &Path) -> Self {
+        let Ok(contents) = fs::read_to_string(path) else {
+            return KeyBindings::default();
+        };
+        match KeyBindings::parse(&contents) {
+            Ok(bindings) => {
+                println!("[INPUT] Loaded key bindings from {:?}", path);
+                bindings
+            }
+            Err(errors) => {
+                eprintln!("[INPUT] Ignoring {:?}, using default key bindings:", path);
+                for error in &errors {
+                    eprintln!("  {}", error);
+                }
+                KeyBindings::default()
+            }
         }
-        _ => {}
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_text())
+            .map_err(|e| format!("Failed to write key bindings {:?}: {}", path, e))?;
+        println!("[INPUT] Saved key bindings to {:?}", path);
+        Ok(())
     }
 }
 
-// Function to handle space key press for rush action
-#[allow(dead_code)] // TODO: Wire up to event system
-pub fn handle_space_key<'a>(
-    fighter: &mut Fighter,
-    camera: &Camera,
-    mouse_x: f64,
-    mouse_y: f64,
-    fixed_crater: &mut FixedCrater,
-    strike: &mut Strike,
-    cpu_entities: &mut Vec<CpuEntity>,
-    line_y: f64,
-    current_racer_texture: &mut &'a G2dTexture,
-    current_rush_texture: &'a G2dTexture,
-    block_active: &mut bool,
-    rmb_held: &mut bool,
-    movement_active: &mut bool,
-    backpedal_active: &mut bool,
-    current_movement_direction: &mut MovementDirection,
-    rush_active: &mut bool,
-    rush_timer: &mut f64,
-    rush_cooldown: &mut f64,
-    audio_manager: &AudioManager,
-) {
-    if *rush_cooldown <= 0.0 && !*block_active {
-        // Play rush sound effect
-        audio_manager
-            .play_sound_effect("rush")
-            .unwrap_or_else(|e| println!("Failed to play rush sound: {}", e));
-
-        *block_active = false;
-        *rmb_held = false;
-
-        // Reuse existing rush code from main.rs
-        let (world_mouse_x, world_mouse_y) = screen_to_world(camera, mouse_x, mouse_y);
-        let dx = world_mouse_x - fighter.x;
-        let dy = world_mouse_y - fighter.y;
-        let distance = (dx * dx + dy * dy).sqrt();
-
-        if distance > 0.0 {
-            let initial_x = fighter.x;
-            let initial_y = fighter.y;
-            let norm_dx = dx / distance;
-            let norm_dy = dy / distance;
-
-            // Calculate end position and collision check line
-            let base_rush_distance = crate::config::movement::RUSH_DISTANCE;
-            let rush_distance = match fighter.fighter_type {
-                FighterType::Racer => base_rush_distance,
-                FighterType::Raptor => base_rush_distance * 0.85,
-                FighterType::Soldier => base_rush_distance * 0.65,
-            };
-            let rush_end_x = (initial_x + norm_dx * rush_distance).clamp(0.0, 5000.0);
-            let rush_end_y = (initial_y + norm_dy * rush_distance).clamp(line_y, 3250.0);
-            let collision_end_x = initial_x + norm_dx * (rush_distance * 2.50);
-            let collision_end_y = initial_y + norm_dy * (rush_distance * 2.50);
-
-            // Check collision along the extended path
-            if crate::config::CPU_ENABLED {
-                for cpu_entity in cpu_entities.iter_mut() {
-                    if check_line_collision(
-                        initial_x,
-                        initial_y,
-                        collision_end_x,
-                        collision_end_y,
-                        cpu_entity.x,
-                        cpu_entity.y,
-                    ) {
-                        cpu_entity.current_hp -= 25.0;
-                        if cpu_entity.is_dead() {
-                            fighter.score = (fighter.score + 1).min(999);
-                            fighter.current_hp = (fighter.current_hp + 25.0).min(fighter.max_hp);
-                            cpu_entity.respawn(line_y);
-                        } else {
-                            cpu_entity.apply_knockback(initial_x, initial_y, 400.0);
-                        }
-                    }
+/// In-game screen for rebinding actions. [UP]/[DOWN] select, [RETURN] waits for a new key,
+/// [BACKSPACE] unbinds, [ESC] closes. Bindings are only applied when there are no conflicts.
+pub struct RebindScreen {
+    pub draft: KeyBindings,
+    pub selected: usize,
+    pub capturing: bool,
+    pub message: Option<String>,
+}
+
+/// What the caller should do after the screen handled a key.
+pub enum RebindOutcome {
+    Stay,
+    Apply(KeyBindings),
+    Cancel,
+}
+
+impl RebindScreen {
+    pub fn new(current: &KeyBindings) -> Self {
+        RebindScreen {
+            draft: current.clone(),
+            selected: 0,
+            capturing: false,
+            message: None,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: Key) -> RebindOutcome {
+        let action = Action::ALL[self.selected];
+        if self.capturing {
+            self.capturing = false;
+            if key == Key::Escape {
+                self.message = None;
+                return RebindOutcome::Stay;
+            }
+            if RESERVED_KEYS.contains(&key) {
+                self.message = Some(format!("[{}] IS RESERVED", key_name(key).to_uppercase()));
+                return RebindOutcome::Stay;
+            }
+            if !BINDABLE_KEYS.contains(&key) {
+                self.message = Some(format!("[{}] CAN'T BE BOUND", key_name(key).to_uppercase()));
+                return RebindOutcome::Stay;
+            }
+            self.draft.set(action, vec![key]);
+            self.message = self.draft.conflicts().first().map(|c| c.describe().to_uppercase());
+            return RebindOutcome::Stay;
+        }
+        match key {
+            Key::Up => {
+                self.selected = (self.selected + Action::ALL.len() - 1) % Action::ALL.len();
+            }
+            Key::Down => {
+                self.selected = (self.selected + 1) % Action::ALL.len();
+            }
+            Key::Return => {
+                self.capturing = true;
+                self.message = Some(format!("PRESS A KEY FOR {}", action.label()));
+            }
+            Key::Backspace => {
+                self.draft.set(action, Vec::new());
+                self.message = None;
+            }
+            Key::Escape | REBIND_SCREEN_KEY => {
+                let conflicts = self.draft.conflicts();
+                if conflicts.is_empty() {
+                    return RebindOutcome::Apply(self.draft.clone());
+                }
+                if self.message.as_deref() == Some(UNRESOLVED_MESSAGE) {
+                    // Second press with conflicts still present: discard the changes.
+                    return RebindOutcome::Cancel;
                 }
+                self.message = Some(UNRESOLVED_MESSAGE.to_string());
+            }
+            _ => {}
+        }
+        RebindOutcome::Stay
+    }
+
+    pub fn draw(&self, c: Context, g: &mut G2d, glyphs: &mut Glyphs, screen_width: f64, screen_height: f64) {
+        let green = [0.0, 1.0, 0.0, 1.0];
+        let red = [1.0, 0.27, 0.0, 1.0];
+        let row_h = 28.0;
+        let panel_w = 900.0;
+        let panel_h = row_h * (Action::ALL.len() as f64 + 4.0);
+        let x = (screen_width - panel_w) / 2.0;
+        let y = (screen_height - panel_h) / 2.0;
+
+        rectangle([0.0, 0.0, 0.0, 0.85], [x, y, panel_w, panel_h], c.transform, g);
+
+        let draw_text = |glyphs: &mut Glyphs, g: &mut G2d, text: &str, color: [f32; 4], tx: f64, ty: f64| {
+            text::Text::new_color(color, 18)
+                .draw(text, glyphs, &c.draw_state, c.transform.trans(tx, ty), g)
+                .ok();
+        };
+
+        draw_text(glyphs, g, "KEY BINDINGS", green, x + 20.0, y + row_h);
+        let conflicting: Vec<Action> = self
+            .draft
+            .conflicts()
+            .into_iter()
+            .flat_map(|c| c.actions)
+            .collect();
+        for (i, action) in Action::ALL.iter().enumerate() {
+            let row_y = y + row_h * (i as f64 + 2.5);
+            if i == self.selected {
+                rectangle([0.0, 0.4, 0.0, 0.5], [x + 10.0, row_y - row_h + 6.0, panel_w - 20.0, row_h], c.transform, g);
             }
+            let color = if conflicting.contains(action) { red } else { green };
+            let keys = if i == self.selected && self.capturing {
+                "...".to_string()
+            } else {
+                self.draft.keys_label(*action)
+            };
+            draw_text(glyphs, g, action.label(), color, x + 20.0, row_y);
+            draw_text(glyphs, g, &keys, color, x + 520.0, row_y);
+        }
 
-            // Move fighter
-            fighter.x = rush_end_x;
-            fighter.y = rush_end_y;
-
-            // Update fixed crater position
-            fixed_crater.x = fighter.x;
-            fixed_crater.y = fighter.y;
-
-            // Trigger rush animation state
-            *current_racer_texture = current_rush_texture;
-            *rush_active = true;
-            *rush_timer = crate::config::movement::RUSH_DURATION;
-            *rush_cooldown = 0.5;
-
-            // Trigger melee strike visual effect partway through rush
-            let slash_x = fighter.x + norm_dx * (rush_distance * 0.5);
-            let slash_y = fighter.y + norm_dy * (rush_distance * 0.5);
-            strike.trigger(slash_x, slash_y);
-            strike.angle = (-dy).atan2(dx).to_degrees();
-            strike.timer = 0.2;
-
-            // Reset conflicting movement states
-            *movement_active = false;
-            *backpedal_active = false;
-            *current_movement_direction = MovementDirection::None;
+        let footer_y = y + panel_h - row_h * 0.5;
+        match &self.message {
+            Some(message) => draw_text(glyphs, g, message, red, x + 20.0, footer_y),
+            None => draw_text(
+                glyphs,
+                g,
+                "[UP/DOWN] SELECT  [RETURN] REBIND  [BACKSPACE] UNBIND  [ESC] SAVE & CLOSE",
+                green,
+                x + 20.0,
+                footer_y,
+            ),
         }
     }
 }
 
-// Main key press handler function
-#[allow(dead_code)] // TODO: Wire up to event system
-pub fn handle_key_press<'a>(
-    key: Key,
-    fighter: &mut Fighter,
-    camera: &Camera,
-    mouse_x: f64,
-    mouse_y: f64,
-    fixed_crater: &mut FixedCrater,
-    strike: &mut Strike,
-    cpu_entities: &mut Vec<CpuEntity>,
-    line_y: f64,
-    current_racer_texture: &mut &'a G2dTexture,
-    current_rush_texture: &'a G2dTexture,
-    block_active: &mut bool,
-    rmb_held: &mut bool,
-    movement_active: &mut bool,
-    backpedal_active: &mut bool,
-    current_movement_direction: &mut MovementDirection,
-    rush_active: &mut bool,
-    rush_timer: &mut f64,
-    rush_cooldown: &mut f64,
-    audio_manager: &AudioManager,
-) {
-    match key {
-        Key::Space => {
-            handle_space_key(
-                fighter,
-                camera,
-                mouse_x,
-                mouse_y,
-                fixed_crater,
-                strike,
-                cpu_entities,
-                line_y,
-                current_racer_texture,
-                current_rush_texture,
-                block_active,
-                rmb_held,
-                movement_active,
-                backpedal_active,
-                current_movement_direction,
-                rush_active,
-                rush_timer,
-                rush_cooldown,
-                audio_manager,
-            );
+const UNRESOLVED_MESSAGE: &str = "RESOLVE CONFLICTS IN RED, OR PRESS [ESC] AGAIN TO DISCARD CHANGES";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_have_no_conflicts() {
+        assert!(KeyBindings::default().conflicts().is_empty());
+    }
+
+    #[test]
+    fn bindings_round_trip_through_text() {
+        let mut bindings = KeyBindings::default();
+        bindings.set(Action::Rush, vec![Key::J, Key::K]);
+        bindings.set(Action::Skill2, Vec::new());
+        let parsed = KeyBindings::parse(&bindings.to_text()).expect("saved bindings should parse");
+        for action in Action::ALL {
+            assert_eq!(parsed.keys(action), bindings.keys(action), "{:?}", action);
         }
-        _ => {}
+        assert_eq!(parsed.action_for(Key::K), Some(Action::Rush));
+        assert_eq!(parsed.action_for(Key::Space), None);
+    }
+
+    #[test]
+    fn unlisted_actions_keep_defaults() {
+        let parsed = KeyBindings::parse("# comment only\nrush = j # jump\n").expect("key names ignore case");
+        assert_eq!(parsed.keys(Action::Rush), &[Key::J]);
+        assert_eq!(parsed.keys(Action::MoveUp), &[Key::W]);
+    }
+
+    #[test]
+    fn reports_bad_lines() {
+        let errors = KeyBindings::parse("rush\nfly = Space\nmove_up = Hyper\n").expect_err("bad lines should fail");
+        assert_eq!(
+            errors,
+            vec![
+                "line 1: expected 'action = Key'".to_string(),
+                "line 2: unknown action 'fly'".to_string(),
+                "line 3: unknown key 'Hyper'".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_keys_conflict() {
+        let mut bindings = KeyBindings::default();
+        bindings.set(Action::Skill1, vec![Key::W]);
+        let conflicts = bindings.conflicts();
+        assert_eq!(
+            conflicts,
+            vec![BindingConflict { key: Key::W, actions: vec![Action::MoveUp, Action::Skill1], reserved: false }]
+        );
+        // The earlier action in display order keeps the key.
+        assert_eq!(bindings.action_for(Key::W), Some(Action::MoveUp));
+        assert!(KeyBindings::parse(&bindings.to_text()).is_err());
+    }
+
+    #[test]
+    fn reserved_keys_conflict() {
+        let errors = KeyBindings::parse("reload = Escape\n").expect_err("reserved key should fail");
+        assert_eq!(errors, vec!["[Escape] IS RESERVED BUT BOUND TO RELOAD".to_string()]);
+    }
+
+    #[test]
+    fn rebind_screen_refuses_reserved_and_holds_conflicts() {
+        let mut screen = RebindScreen::new(&KeyBindings::default());
+        // MoveUp is selected first.
+        screen.handle_key(Key::Return);
+        screen.handle_key(Key::F5);
+        assert_eq!(screen.draft.keys(Action::MoveUp), &[Key::W]);
+
+        screen.handle_key(Key::Return);
+        screen.handle_key(Key::S);
+        assert!(matches!(screen.handle_key(Key::Escape), RebindOutcome::Stay));
+        assert!(matches!(screen.handle_key(Key::Escape), RebindOutcome::Cancel));
     }
 }
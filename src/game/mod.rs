@@ -1,3 +1,4 @@
-// game/mod.rs
-
-pub mod input_handler;
\ No newline at end of file
+// game/mod.rs
+
+pub mod gamepad;
+pub mod input_handler;
@@ -0,0 +1,117 @@
+// File: src/game_event.rs
+
+use crate::entities::cpu_entity::CpuVariant;
+use crate::game_state::{DeathType, FighterType};
+use crate::map_system::FieldId as SbrxFieldId;
+use std::collections::{HashMap, HashSet};
+
+/// Something that happened in the game this frame. Gameplay code publishes these
+/// instead of calling every interested system directly.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    EnemyKilled {
+        variant: CpuVariant,
+        x: f64,
+        y: f64,
+    },
+    LevelUp {
+        fighter_type: FighterType,
+        // Unspent stat points after the level-up.
+        stat_points: u32,
+    },
+    TasksCompleted {
+        count: u32,
+    },
+    FighterDowned {
+        fighter_type: FighterType,
+        death_type: DeathType,
+        // True when other group members are still standing (group death screen).
+        group_continues: bool,
+    },
+    FieldChanged {
+        from: SbrxFieldId,
+        to: SbrxFieldId,
+    },
+}
+
+/// Systems that react to game events implement this.
+pub trait GameEventListener {
+    fn on_game_event(&mut self, event: &GameEvent);
+}
+
+/// Frame-local event queue. Events published during a frame are handed to the
+/// listeners at the start of the next update tick.
+#[derive(Default)]
+pub struct GameEvents {
+    pending: Vec<GameEvent>,
+}
+
+impl GameEvents {
+    pub fn new() -> Self {
+        GameEvents::default()
+    }
+
+    pub fn publish(&mut self, event: GameEvent) {
+        self.pending.push(event);
+    }
+
+    /// Takes every event published since the last call, oldest first.
+    pub fn drain(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+/// Display name used in level-up messages.
+pub fn fighter_name(fighter_type: FighterType) -> &'static str {
+    match fighter_type {
+        FighterType::Racer => "RACER",
+        FighterType::Soldier => "SOLDIER",
+        FighterType::Raptor => "RAPTOR",
+    }
+}
+
+/// Session statistics, built purely from events.
+#[derive(Default)]
+pub struct GameStats {
+    pub kills: HashMap<CpuVariant, u32>,
+    pub level_ups: u32,
+    pub tasks_completed: u32,
+    pub deaths: u32,
+    pub fields_visited: HashSet<SbrxFieldId>,
+}
+
+impl GameStats {
+    pub fn new() -> Self {
+        GameStats::default()
+    }
+
+    pub fn total_kills(&self) -> u32 {
+        self.kills.values().sum()
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "kills {}, level-ups {}, tasks {}, deaths {}, fields visited {}",
+            self.total_kills(),
+            self.level_ups,
+            self.tasks_completed,
+            self.deaths,
+            self.fields_visited.len()
+        )
+    }
+}
+
+impl GameEventListener for GameStats {
+    fn on_game_event(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::EnemyKilled { variant, .. } => *self.kills.entry(*variant).or_insert(0) += 1,
+            GameEvent::LevelUp { .. } => self.level_ups += 1,
+            GameEvent::TasksCompleted { count } => self.tasks_completed += count,
+            GameEvent::FighterDowned { .. } => self.deaths += 1,
+            GameEvent::FieldChanged { from, to } => {
+                self.fields_visited.insert(*from);
+                self.fields_visited.insert(*to);
+            }
+        }
+    }
+}
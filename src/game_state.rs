@@ -64,6 +64,26 @@ pub enum FighterType {
     Raptor,
 }
 
+impl FighterType {
+    pub const ALL: [FighterType; 3] = [FighterType::Racer, FighterType::Soldier, FighterType::Raptor];
+
+    /// Section prefix in the combo and resource data files.
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            FighterType::Racer => "racer",
+            FighterType::Soldier => "soldier",
+            FighterType::Raptor => "raptor",
+        }
+    }
+
+    pub fn from_config_name(name: &str) -> Result<FighterType, String> {
+        FighterType::ALL
+            .into_iter()
+            .find(|ft| ft.config_name() == name.to_ascii_lowercase())
+            .ok_or_else(|| "is not a fighter type (racer, soldier or raptor)".to_string())
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub enum CombatMode {
     CloseCombat,
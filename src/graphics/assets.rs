@@ -0,0 +1,219 @@
+// graphics/assets.rs
+
+use crate::asset_manifest;
+use piston_window::*;
+use piston_window::texture::{CreateTexture, Format};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Edge length of generated placeholder textures, in pixels.
+const PLACEHOLDER_SIZE: u32 = 64;
+/// Checker square size inside a placeholder.
+const PLACEHOLDER_CHECKER: u32 = 8;
+/// Fonts tried when the bundled font is missing.
+const SYSTEM_FONTS: [&str; 3] = [
+    "C:\\Windows\\Fonts\\arial.ttf",
+    "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+    "/System/Library/Fonts/Supplemental/Arial.ttf",
+];
+
+/// Loads textures from the assets folder. Anything missing or unreadable is replaced by a
+/// generated placeholder so the game still boots with an empty or partial `assets/` folder
+/// (the art pack is distributed separately). Every miss is logged once.
+pub struct AssetRegistry {
+    root: PathBuf,
+    missing: HashSet<PathBuf>,
+}
+
+impl AssetRegistry {
+    pub fn new(root: PathBuf) -> Self {
+        AssetRegistry {
+            root,
+            missing: HashSet::new(),
+        }
+    }
+
+    /// Loads a texture, or a placeholder if it can't be read. `path` may be relative to the
+    /// assets folder or absolute.
+    pub fn texture(
+        &mut self,
+        texture_context: &mut G2dTextureContext,
+        path: &Path,
+        settings: &TextureSettings,
+        name: &str,
+    ) -> G2dTexture {
+        self.texture_flipped(texture_context, path, Flip::None, settings, name)
+    }
+
+    /// Loads the manifest texture called `name` (see `asset_manifest::GAME_TEXTURES`).
+    pub fn game_texture(
+        &mut self,
+        texture_context: &mut G2dTextureContext,
+        name: &str,
+        settings: &TextureSettings,
+    ) -> G2dTexture {
+        self.texture(texture_context, Path::new(asset_manifest::game_texture(name)), settings, name)
+    }
+
+    pub fn texture_flipped(
+        &mut self,
+        texture_context: &mut G2dTextureContext,
+        path: &Path,
+        flip: Flip,
+        settings: &TextureSettings,
+        name: &str,
+    ) -> G2dTexture {
+        let full_path = self.root.join(path);
+        match Texture::from_path(texture_context, &full_path, flip, settings) {
+            Ok(texture) => texture,
+            Err(e) => {
+                self.log_missing(&full_path, &format!("{} texture: {}", name, e));
+                placeholder_texture(texture_context, name, settings)
+            }
+        }
+    }
+
+    /// Loads a texture that the game can do without (boost variants, aim poses). Misses are
+    /// not logged since these are absent for most fighters.
+    pub fn optional_texture(
+        &mut self,
+        texture_context: &mut G2dTextureContext,
+        path: &Path,
+        settings: &TextureSettings,
+    ) -> Option<G2dTexture> {
+        Texture::from_path(texture_context, self.root.join(path), Flip::None, settings).ok()
+    }
+
+    /// Loads the HUD font. Falls back to a system font, and if none can be read, to a blank
+    /// font so the game still runs, just without text.
+    pub fn font(&mut self, window: &mut PistonWindow, path: &Path) -> Glyphs {
+        let full_path = self.root.join(path);
+        let error = match window.load_font(&full_path) {
+            Ok(glyphs) => return glyphs,
+            Err(e) => e,
+        };
+        for fallback in SYSTEM_FONTS.iter().map(Path::new).filter(|p| p.exists()) {
+            match window.load_font(fallback) {
+                Ok(glyphs) => {
+                    eprintln!("[ASSETS] Missing font ({:?}: {}); using {:?}.", full_path, error, fallback);
+                    return glyphs;
+                }
+                Err(e) => eprintln!("[ASSETS] Fallback font {:?} failed: {}", fallback, e),
+            }
+        }
+        eprintln!("[ASSETS] Missing font ({:?}: {}) and no system font found; text is hidden.", full_path, error);
+        let blank: &'static [u8] = Box::leak(blank_font().into_boxed_slice());
+        Glyphs::from_bytes(blank, window.create_texture_context(), TextureSettings::new())
+            .expect("the generated blank font is a valid TrueType file")
+    }
+
+    /// Number of distinct files that were replaced by placeholders.
+    pub fn missing_count(&self) -> usize {
+        self.missing.len()
+    }
+
+    /// Prints a one-line summary after loading, if anything was missing.
+    pub fn report(&self) {
+        if self.missing_count() > 0 {
+            println!(
+                "[ASSETS] {} texture(s) missing under {:?}; using placeholders.",
+                self.missing_count(),
+                self.root
+            );
+        }
+    }
+
+    fn log_missing(&mut self, path: &Path, reason: &str) {
+        if self.missing.insert(path.to_path_buf()) {
+            eprintln!("[ASSETS] Missing {} ({:?}); using placeholder.", reason, path);
+        }
+    }
+}
+
+/// Smallest TrueType file the font loader accepts: a single empty glyph that every character
+/// maps to, so text draws as nothing.
+fn blank_font() -> Vec<u8> {
+    let be16 = |v: u16| v.to_be_bytes();
+    let mut head = Vec::with_capacity(54);
+    head.extend_from_slice(&0x0001_0000u32.to_be_bytes()); // version
+    head.extend_from_slice(&0x0001_0000u32.to_be_bytes()); // font revision
+    head.extend_from_slice(&0u32.to_be_bytes()); // checksum adjustment
+    head.extend_from_slice(&0x5F0F_3CF5u32.to_be_bytes()); // magic number
+    head.extend_from_slice(&be16(0)); // flags
+    head.extend_from_slice(&be16(1000)); // units per em
+    head.extend_from_slice(&[0; 16]); // created, modified
+    head.extend_from_slice(&[0; 8]); // x/y min/max
+    head.extend_from_slice(&[0; 6]); // mac style, lowest PPEM, direction hint
+    head.extend_from_slice(&[0; 4]); // loca format, glyph data format
+    let mut hhea = Vec::with_capacity(36);
+    hhea.extend_from_slice(&0x0001_0000u32.to_be_bytes()); // version
+    hhea.extend_from_slice(&be16(800)); // ascender
+    hhea.extend_from_slice(&be16((-200i16) as u16)); // descender
+    hhea.extend_from_slice(&[0; 26]); // line gap .. metric data format
+    hhea.extend_from_slice(&be16(1)); // number of h-metrics
+    let hmtx = [0u8; 4]; // advance and left side bearing of glyph 0
+    let mut maxp = 0x0000_5000u32.to_be_bytes().to_vec(); // version 0.5
+    maxp.extend_from_slice(&be16(1)); // glyph count
+
+    // Table records must be sorted by tag.
+    let tables: [(&[u8; 4], &[u8]); 4] = [(b"head", &head), (b"hhea", &hhea), (b"hmtx", &hmtx), (b"maxp", &maxp)];
+    let mut font = 0x0001_0000u32.to_be_bytes().to_vec();
+    font.extend_from_slice(&be16(tables.len() as u16));
+    font.extend_from_slice(&[0; 6]); // search range, entry selector, range shift
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, data) in tables.iter() {
+        font.extend_from_slice(*tag);
+        font.extend_from_slice(&0u32.to_be_bytes()); // checksum (not verified)
+        font.extend_from_slice(&(offset as u32).to_be_bytes());
+        font.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        offset += data.len();
+    }
+    for (_, data) in tables.iter() {
+        font.extend_from_slice(data);
+    }
+    font
+}
+
+/// Generates a checkerboard texture tinted by `name`, so different missing sprites can be
+/// told apart on screen.
+pub fn placeholder_texture(
+    texture_context: &mut G2dTextureContext,
+    name: &str,
+    settings: &TextureSettings,
+) -> G2dTexture {
+    let tint = placeholder_color(name);
+    let mut pixels = Vec::with_capacity((PLACEHOLDER_SIZE * PLACEHOLDER_SIZE * 4) as usize);
+    for y in 0..PLACEHOLDER_SIZE {
+        for x in 0..PLACEHOLDER_SIZE {
+            let on_border = x == 0 || y == 0 || x == PLACEHOLDER_SIZE - 1 || y == PLACEHOLDER_SIZE - 1;
+            let light = (x / PLACEHOLDER_CHECKER + y / PLACEHOLDER_CHECKER).is_multiple_of(2);
+            let color = if on_border {
+                [255, 255, 255, 255]
+            } else if light {
+                tint
+            } else {
+                [tint[0] / 4, tint[1] / 4, tint[2] / 4, 255]
+            };
+            pixels.extend_from_slice(&color);
+        }
+    }
+    Texture::create(
+        texture_context,
+        Format::Rgba8,
+        &pixels,
+        [PLACEHOLDER_SIZE, PLACEHOLDER_SIZE],
+        settings,
+    )
+    .unwrap_or_else(|e| panic!("Failed to create placeholder texture for {}: {:?}", name, e))
+}
+
+/// Stable bright color derived from the asset name (FNV-1a hash).
+fn placeholder_color(name: &str) -> [u8; 4] {
+    let mut hash: u32 = 0x811c9dc5;
+    for byte in name.bytes() {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(0x01000193);
+    }
+    let channel = |shift: u32| 96 + ((hash >> shift) & 0x9f) as u8;
+    [channel(0), channel(8), channel(16), 255]
+}
@@ -1,5 +1,6 @@
 // graphics/camera.rs
 
+use crate::config::resolution::{HEIGHT, WIDTH};
 use piston_window::*;
 
 pub struct Camera {
@@ -32,8 +33,8 @@ impl Camera {
     }
 
     pub fn transform(&self, c: Context) -> Context {
-        let center_x = 1920.0 / 2.0;
-        let center_y = 1080.0 / 2.0;
+        let center_x = WIDTH / 2.0;
+        let center_y = HEIGHT / 2.0;
         c.trans(center_x, center_y)
             .scale(self.zoom, self.zoom)
             .trans(-self.x, -self.y)
@@ -41,9 +42,17 @@ impl Camera {
 }
 
 pub fn screen_to_world(camera: &Camera, mouse_x: f64, mouse_y: f64) -> (f64, f64) {
-    let center_x = 1920.0 / 2.0;
-    let center_y = 1080.0 / 2.0;
+    let center_x = WIDTH / 2.0;
+    let center_y = HEIGHT / 2.0;
     let world_x = (mouse_x - center_x) / camera.zoom + camera.x;
     let world_y = (mouse_y - center_y) / camera.zoom + camera.y;
     (world_x, world_y)
 }
+
+pub fn world_to_screen(camera: &Camera, world_x: f64, world_y: f64) -> (f64, f64) {
+    let center_x = WIDTH / 2.0;
+    let center_y = HEIGHT / 2.0;
+    let screen_x = (world_x - camera.x) * camera.zoom + center_x;
+    let screen_y = (world_y - camera.y) * camera.zoom + center_y;
+    (screen_x, screen_y)
+}
@@ -1,22 +1,10 @@
 // File: fighter_textures.rs
 
+use crate::asset_manifest;
+use crate::graphics::assets::AssetRegistry;
 use piston_window::*;
-use std::path::PathBuf;
-
-/// Helper function to load a texture with a descriptive error
-fn load_texture(
-	window: &mut PistonWindow,
-	path: &PathBuf,
-	name: &str,
-) -> Result<G2dTexture, String> {
-	Texture::from_path(
-		&mut window.create_texture_context(),
-		path,
-		Flip::None,
-		&TextureSettings::new(),
-	)
-	.map_err(|e| format!("Failed to load {} texture at {:?}: {}", name, path, e))
-}
+use std::collections::HashMap;
+use std::path::Path;
 
 /// Contains all textures for a fighter type (both on foot and on bike)
 pub struct FighterTextures {
@@ -47,175 +35,61 @@ pub struct FighterTextures {
 	pub bike_ranged_aim: Option<G2dTexture>,
 }
 
-/// Helper function to load textures for a specific fighter type
+/// Helper function to load textures for a specific fighter type (missing files become placeholders)
 pub fn load_fighter_textures(
-    window: &mut PistonWindow,
+    texture_context: &mut G2dTextureContext,
+    assets: &mut AssetRegistry,
     fighter_type: &str,
-    assets: PathBuf,
-) -> Result<FighterTextures, String> {
-    // Load basic textures
-    let idle_path = assets.join(format!("player/{}/idle.png", fighter_type)); // Changed
-	let idle_texture = load_texture(window, &idle_path, "idle")?;
-
-    let fwd_path = assets.join(format!("player/{}/fwd.png", fighter_type)); // Changed
-	let fwd_texture = load_texture(window, &fwd_path, "fwd")?;
-
-    let backpedal_path = assets.join(format!("player/{}/backpedal.png", fighter_type)); // Changed
-	let backpedal_texture = load_texture(window, &backpedal_path, "backpedal")?;
-
-    let block_path = assets.join(format!("player/{}/block.png", fighter_type)); // Changed
-	let block_texture = load_texture(window, &block_path, "block")?;
-
-    let ranged_path = assets.join(format!("player/{}/ranged.png", fighter_type)); // Changed
-	let ranged_texture = load_texture(window, &ranged_path, "ranged")?;
-
-    let (ranged_marker_filename, ranged_blur_filename) = match fighter_type {
-        "soldier" => (
-            "player/soldier/bullet_pen.png",
-            "player/soldier/bullet_blur.png",
-        ),
-        _ => (
-            "player/racer/ranged_racer_shield.png",
-            "player/racer/racer_shield_blur.png",
-        ),
-    };
-
-    let ranged_marker_path = assets.join(ranged_marker_filename);
-	let ranged_marker_texture = load_texture(window, &ranged_marker_path, "ranged_marker")?;	
-
-    let ranged_blur_path = assets.join(ranged_blur_filename);
-	let ranged_blur_texture = load_texture(window, &ranged_blur_path, "ranged_blur")?;
-
-    let rush_path = assets.join(format!("player/{}/rush.png", fighter_type)); // Changed
-	let rush_texture = load_texture(window, &rush_path, "rush")?;
-
-    // Load strike animation textures
-    let mut strike_textures = Vec::new();
-    for i in 1..=3 {
-        let strike_path = assets.join(format!("player/{}/strike{}.png", fighter_type, i)); // Changed
-		let strike_texture = load_texture(window, &strike_path, &format!("strike{}", i))?;
-        strike_textures.push(strike_texture);
+) -> FighterTextures {
+    let settings = TextureSettings::new();
+    // Everything comes from the manifest; required misses get placeholders, optional ones stay None.
+    let mut required: HashMap<String, G2dTexture> = HashMap::new();
+    let mut optional: HashMap<String, G2dTexture> = HashMap::new();
+    for texture in asset_manifest::fighter_texture_paths(fighter_type) {
+        let path = Path::new(&texture.path);
+        if texture.required {
+            let name = format!("{} {}", fighter_type, texture.key);
+            required.insert(texture.key, assets.texture(texture_context, path, &settings, &name));
+        } else if let Some(loaded) = assets.optional_texture(texture_context, path, &settings) {
+            optional.insert(texture.key, loaded);
+        }
     }
+    let mut take = |key: &str| {
+        required
+            .remove(key)
+            .unwrap_or_else(|| panic!("fighter texture '{}' is not in the asset manifest", key))
+    };
 
-    let block_break_path = assets.join(format!("player/{}/block_break.png", fighter_type));
-	let block_break_texture = load_texture(window, &block_break_path, "block_break")?;
-
-    // Load bike textures
-    let bike_idle_path = assets.join(format!(
-        "player/{}/racer_onBike/rcrBikeIdle.png",
-        fighter_type
-    )); 
-	let bike_idle_texture = load_texture(window, &bike_idle_path, "bike_idle")?;
-
-    let mut bike_accelerate_textures = Vec::new();
-    let bike_accelerate_path1 = assets.join(format!(
-        "player/{}/racer_onBike/rcrBikeAccelerate.png",
-        fighter_type
-    ));
-	let bike_accelerate_texture1 = load_texture(window, &bike_accelerate_path1, "bike_accelerate1")?;
-    bike_accelerate_textures.push(bike_accelerate_texture1);
-
-    let bike_accelerate_path2 = assets.join(format!(
-        "player/{}/racer_onBike/rcrBikeAccelerate2.png",
-        fighter_type
-    ));
-	let bike_accelerate_texture2 = load_texture(window, &bike_accelerate_path2, "bike_accelerate2")?;
-    bike_accelerate_textures.push(bike_accelerate_texture2);
-
-    let bike_slide_path = assets.join(format!(
-        "player/{}/racer_onBike/rcrBikeSlide.png",
-        fighter_type
-    )); // Changed
-	let bike_slide_texture = load_texture(window, &bike_slide_path, "bike_slide")?;
-
-
-    let bike_block_path = assets.join(format!(
-        "player/{}/racer_onBike/rcrBikeBlock.png",
-        fighter_type
-    )); // Changed
-	let bike_block_texture = load_texture(window, &bike_block_path, "bike_block")?;
-
-    let bike_ranged_path = assets.join(format!(
-        "player/{}/racer_onBike/rcrBikeRanged.png",
-        fighter_type
-    )); // Changed
-	let bike_ranged_texture = load_texture(window, &bike_ranged_path, "bike_ranged")?;
-
-    let bike_rush_path = assets.join(format!(
-        "player/{}/racer_onBike/rcrBikeRush.png",
-        fighter_type
-    )); // Changed
-	let bike_rush_texture = load_texture(window, &bike_rush_path, "bike_rush")?;	
-
-    // Load bike strike textures
-    let mut bike_strike_textures = Vec::new();
-    for i in 1..=3 {
-        let bike_strike_path = assets.join(format!(
-            "player/{}/racer_onBike/rcrBikeStrike{}.png",
-            fighter_type, i
-        )); // Changed
-	let bike_strike_texture = load_texture(window, &bike_strike_path, &format!("bike_strike{}", i))?;
-        bike_strike_textures.push(bike_strike_texture);
+    let bike_accelerate_boost: Vec<G2dTexture> = ["bike_accelerate_boost1", "bike_accelerate_boost2"]
+        .iter()
+        .filter_map(|key| optional.remove(*key))
+        .collect();
+
+    FighterTextures {
+        idle: take("idle"),
+        fwd: take("fwd"),
+        backpedal: take("backpedal"),
+        block: take("block"),
+        block_break: take("block_break"),
+        ranged: take("ranged"),
+        ranged_marker: take("ranged_marker"),
+        ranged_blur: take("ranged_blur"),
+        rush: take("rush"),
+        strike: vec![take("strike1"), take("strike2"), take("strike3")],
+        bike_idle: take("bike_idle"),
+        bike_accelerate: vec![take("bike_accelerate1"), take("bike_accelerate2")],
+        bike_slide: take("bike_slide"),
+        bike_block: take("bike_block"),
+        bike_ranged: take("bike_ranged"),
+        bike_rush: take("bike_rush"),
+        bike_strike: vec![take("bike_strike1"), take("bike_strike2"), take("bike_strike3")],
+        fwd_boost: optional.remove("fwd_boost"),
+        backpedal_boost: optional.remove("backpedal_boost"),
+        bike_accelerate_boost: if bike_accelerate_boost.is_empty() { None } else { Some(bike_accelerate_boost) },
+        bike_slide_boost: optional.remove("bike_slide_boost"),
+		ranged_aim: optional.remove("ranged_aim"),
+		bike_ranged_aim: optional.remove("bike_ranged_aim"),
     }
-	
-    // Load Boost Textures (Only for Racer)
-    let (fwd_boost, backpedal_boost, bike_accelerate_boost, bike_slide_boost) = if fighter_type == "racer" || fighter_type == "racer2" {
-        let fb_path = assets.join(format!("player/{}/fwd_boost.png", fighter_type));
-        let fwd_b = Texture::from_path(&mut window.create_texture_context(), &fb_path, Flip::None, &TextureSettings::new()).ok();
- 
-        let bb_path = assets.join(format!("player/{}/backpedal_boost.png", fighter_type));
-        let back_b = Texture::from_path(&mut window.create_texture_context(), &bb_path, Flip::None, &TextureSettings::new()).ok();
- 
-        let mut bike_acc_b_vec = Vec::new();
-        let bab1_path = assets.join(format!("player/{}/racer_onBike/rcrBikeAccelerate_boost.png", fighter_type));
-        if let Ok(tex) = Texture::from_path(&mut window.create_texture_context(), &bab1_path, Flip::None, &TextureSettings::new()) {
-            bike_acc_b_vec.push(tex);
-        }
-        let bab2_path = assets.join(format!("player/{}/racer_onBike/rcrBikeAccelerate2_boost.png", fighter_type));
-        if let Ok(tex) = Texture::from_path(&mut window.create_texture_context(), &bab2_path, Flip::None, &TextureSettings::new()) {
-            bike_acc_b_vec.push(tex);
-        }
-        let bike_acc_b = if !bike_acc_b_vec.is_empty() { Some(bike_acc_b_vec) } else { None };
- 
-        let bsb_path = assets.join(format!("player/{}/racer_onBike/rcrBikeSlide_boost.png", fighter_type));
-        let slide_b = Texture::from_path(&mut window.create_texture_context(), &bsb_path, Flip::None, &TextureSettings::new()).ok();
- 
-        (fwd_b, back_b, bike_acc_b, slide_b)
-    } else {
-        (None, None, None, None)
-    };	
-	
-    let ranged_aim_path = assets.join(format!("player/{}/ranged_aim.png", fighter_type));
-    let ranged_aim = Texture::from_path(&mut window.create_texture_context(), &ranged_aim_path, Flip::None, &TextureSettings::new()).ok();	
-
-    let bike_ranged_aim_path = assets.join(format!("player/{}/racer_onBike/rcrBikeRangedAim.png", fighter_type));
-    let bike_ranged_aim = Texture::from_path(&mut window.create_texture_context(), &bike_ranged_aim_path, Flip::None, &TextureSettings::new()).ok();
-
-    Ok(FighterTextures {
-        idle: idle_texture,
-        fwd: fwd_texture,
-        backpedal: backpedal_texture,
-        block: block_texture,
-        block_break: block_break_texture,
-        ranged: ranged_texture,
-        ranged_marker: ranged_marker_texture,
-        ranged_blur: ranged_blur_texture,
-        rush: rush_texture,
-        strike: strike_textures,
-        bike_idle: bike_idle_texture,
-        bike_accelerate: bike_accelerate_textures,
-        bike_slide: bike_slide_texture,
-        bike_block: bike_block_texture,
-        bike_ranged: bike_ranged_texture,
-        bike_rush: bike_rush_texture,
-        bike_strike: bike_strike_textures,
-        fwd_boost,
-        backpedal_boost,
-        bike_accelerate_boost,
-        bike_slide_boost,	
-		ranged_aim,
-		bike_ranged_aim,
-    })
 }
 
 /// Helper function to update current texture references based on fighter state and type
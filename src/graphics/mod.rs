@@ -1,6 +1,8 @@
 // graphics/mod.rs
 
+pub mod assets;
 pub mod camera;
 pub mod crater;
 pub mod fighter_textures;
 pub mod seven_segment;
+pub mod viewport;
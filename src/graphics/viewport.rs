@@ -0,0 +1,209 @@
+// graphics/viewport.rs
+
+use crate::config::resolution::{HEIGHT, WIDTH};
+use piston_window::*;
+
+/// How the window is presented.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindowMode {
+    Windowed,
+    // Fullscreen-sized window without decorations on the current monitor.
+    Borderless,
+    // Exclusive fullscreen at the monitor's largest video mode.
+    Fullscreen,
+}
+
+impl WindowMode {
+    pub fn next(self) -> WindowMode {
+        match self {
+            WindowMode::Windowed => WindowMode::Borderless,
+            WindowMode::Borderless => WindowMode::Fullscreen,
+            WindowMode::Fullscreen => WindowMode::Windowed,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            WindowMode::Windowed => "windowed",
+            WindowMode::Borderless => "borderless",
+            WindowMode::Fullscreen => "fullscreen",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<WindowMode, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "windowed" => Ok(WindowMode::Windowed),
+            "borderless" => Ok(WindowMode::Borderless),
+            "fullscreen" => Ok(WindowMode::Fullscreen),
+            _ => Err(format!("expects windowed, borderless or fullscreen, found '{}'", value)),
+        }
+    }
+}
+
+/// What fills the window when its aspect ratio differs from the logical 16:9 canvas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScaleMode {
+    // Black bars; nothing outside the logical canvas is shown.
+    Letterbox,
+    // The world view extends into the margins; HUD stays inside the logical canvas.
+    Expand,
+}
+
+impl ScaleMode {
+    pub fn parse(value: &str) -> Result<ScaleMode, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "letterbox" => Ok(ScaleMode::Letterbox),
+            "expand" => Ok(ScaleMode::Expand),
+            _ => Err(format!("expects letterbox or expand, found '{}'", value)),
+        }
+    }
+}
+
+/// Maps the logical WIDTH x HEIGHT canvas that all game code draws in onto the actual
+/// window. The canvas is scaled uniformly and centred; window coordinates (mouse input)
+/// go back through `window_to_logical`.
+#[derive(Debug, Clone)]
+pub struct Viewport {
+    pub scale_mode: ScaleMode,
+    window_size: [f64; 2],
+    scale: f64,
+    offset: [f64; 2],
+}
+
+impl Viewport {
+    pub fn new(scale_mode: ScaleMode, window_size: [f64; 2]) -> Self {
+        let mut viewport = Viewport {
+            scale_mode,
+            window_size: [WIDTH, HEIGHT],
+            scale: 1.0,
+            offset: [0.0, 0.0],
+        };
+        viewport.resize(window_size);
+        viewport
+    }
+
+    /// Call when the window size changes (resize events, window mode switches).
+    pub fn resize(&mut self, window_size: [f64; 2]) {
+        if window_size[0] <= 0.0 || window_size[1] <= 0.0 {
+            // Minimised; keep the last usable mapping.
+            return;
+        }
+        self.window_size = window_size;
+        self.scale = (window_size[0] / WIDTH).min(window_size[1] / HEIGHT);
+        self.offset = [
+            (window_size[0] - WIDTH * self.scale) / 2.0,
+            (window_size[1] - HEIGHT * self.scale) / 2.0,
+        ];
+    }
+
+    pub fn window_to_logical(&self, x: f64, y: f64) -> (f64, f64) {
+        ((x - self.offset[0]) / self.scale, (y - self.offset[1]) / self.scale)
+    }
+
+    /// Logical rectangle [x, y, w, h] covered by the window. Equals the canvas when
+    /// letterboxed; larger on one axis when expanded.
+    pub fn visible_rect(&self) -> [f64; 4] {
+        match self.scale_mode {
+            ScaleMode::Letterbox => [0.0, 0.0, WIDTH, HEIGHT],
+            ScaleMode::Expand => [
+                -self.offset[0] / self.scale,
+                -self.offset[1] / self.scale,
+                self.window_size[0] / self.scale,
+                self.window_size[1] / self.scale,
+            ],
+        }
+    }
+
+    /// Turns the window context from `draw_2d` into one in logical coordinates. When
+    /// letterboxed, drawing is clipped to the canvas.
+    pub fn apply(&self, c: Context) -> Context {
+        let mut logical = c.trans(self.offset[0], self.offset[1]).scale(self.scale, self.scale);
+        if self.scale_mode == ScaleMode::Letterbox {
+            // Scissor rects are in framebuffer pixels, which differ from window units on HiDPI.
+            let pixel_ratio = c
+                .viewport
+                .map(|v| v.draw_size[0] as f64 / v.window_size[0].max(1.0))
+                .unwrap_or(1.0);
+            logical.draw_state = logical.draw_state.scissor([
+                (self.offset[0] * pixel_ratio) as u32,
+                (self.offset[1] * pixel_ratio) as u32,
+                (WIDTH * self.scale * pixel_ratio).ceil() as u32,
+                (HEIGHT * self.scale * pixel_ratio).ceil() as u32,
+            ]);
+        }
+        logical
+    }
+
+    /// `clear` for logical contexts: fills the canvas with `color`, and the letterbox bars
+    /// (which `clear` would otherwise paint too) with black.
+    pub fn clear(&self, color: [f32; 4], c: &Context, g: &mut G2d) {
+        match self.scale_mode {
+            ScaleMode::Letterbox => {
+                clear([0.0, 0.0, 0.0, 1.0], g);
+                rectangle(color, [0.0, 0.0, WIDTH, HEIGHT], c.transform, g);
+            }
+            ScaleMode::Expand => clear(color, g),
+        }
+    }
+
+    /// Repaints the letterbox bars black, for screens that draw their own background
+    /// (FirmamentMode). Takes the logical context from `apply`.
+    pub fn cover_bars(&self, c: &Context, g: &mut G2d) {
+        if self.scale_mode != ScaleMode::Letterbox {
+            return;
+        }
+        let black = [0.0, 0.0, 0.0, 1.0];
+        let unclipped = DrawState::new_alpha();
+        let [x, y, w, h] = [
+            -self.offset[0] / self.scale,
+            -self.offset[1] / self.scale,
+            self.window_size[0] / self.scale,
+            self.window_size[1] / self.scale,
+        ];
+        // Top, bottom, left, right.
+        let bars = [
+            [x, y, w, -y],
+            [x, HEIGHT, w, y + h - HEIGHT],
+            [x, 0.0, -x, HEIGHT],
+            [WIDTH, 0.0, x + w - WIDTH, HEIGHT],
+        ];
+        for bar in bars {
+            if bar[2] > 0.0 && bar[3] > 0.0 {
+                Rectangle::new(black).draw(bar, &unclipped, c.transform, g);
+            }
+        }
+    }
+}
+
+/// Switches the window between windowed, borderless and exclusive fullscreen. The window
+/// reports its new size through a resize event, which updates the viewport.
+pub fn set_window_mode(window: &mut PistonWindow, mode: WindowMode, windowed_size: [u32; 2]) {
+    use winit::dpi::LogicalSize;
+    use winit::window::Fullscreen;
+
+    let winit_window = &window.window.window;
+    match mode {
+        WindowMode::Windowed => {
+            winit_window.set_fullscreen(None);
+            winit_window.set_decorations(true);
+            winit_window.set_inner_size(LogicalSize::new(windowed_size[0], windowed_size[1]));
+        }
+        WindowMode::Borderless => {
+            winit_window.set_decorations(false);
+            winit_window.set_fullscreen(Some(Fullscreen::Borderless(None)));
+        }
+        WindowMode::Fullscreen => {
+            let video_mode = winit_window.current_monitor().and_then(|monitor| {
+                monitor
+                    .video_modes()
+                    .max_by_key(|m| (m.size().width * m.size().height, m.refresh_rate_millihertz()))
+            });
+            match video_mode {
+                Some(video_mode) => winit_window.set_fullscreen(Some(Fullscreen::Exclusive(video_mode))),
+                // No mode list (e.g. some Wayland setups): borderless looks the same.
+                None => winit_window.set_fullscreen(Some(Fullscreen::Borderless(None))),
+            }
+        }
+    }
+    println!("[DISPLAY] Window mode: {}", mode.name());
+}
@@ -27,14 +27,20 @@ mod audio;
 mod combat;
 mod config;
 mod entities;
+mod game_event;
 mod game_state;
 mod graphics;
 mod map_system;
+mod replay;
+mod save;
+mod simulation;
 mod utils;
 mod game {
+    pub mod gamepad;
     pub mod input_handler;
 }
 mod area;
+mod asset_manifest;
 mod chatbox;
 mod fog_of_war;
 mod mechanics;
@@ -52,8 +58,9 @@ use piston_window::Context;
 use piston_window::G2d;
 use piston_window::Glyphs;
 
+use crate::game_event::{GameEvent, GameEventListener, GameStats};
 use crate::game_state::EntityState;
-use crate::entities::collision_barriers::{CollisionBarrierManager, JumpZoneType};
+use crate::entities::collision_barriers::JumpZoneType;
 
 use crate::game_state::AmbientTrackState;
 
@@ -64,64 +71,60 @@ use crate::combat::block::KINETIC_STRIKE_DAMAGE_IMMUNITY_DURATION;
 use crate::piston_window::MouseCursorEvent;
 use crate::combat::block::BlockSystem;
 use crate::combat::combo::ComboSystem;
+use crate::combat::damage::{self, DamageType, TextStyle};
+use crate::combat::hitbox::{self, Hitbox, Hurtbox};
 use crate::combat::field_traits::{FieldTraitManager, StatAttribute, TraitTarget};
+use crate::combat::input_buffer::{cancel_wait, cancels, swing_elapsed, CombatInput, CombatPhase, InputBuffer};
+use crate::combat::resources::{ResourceAction, ResourceKind};
+use crate::combat::skill_data::{self, SkillShape, SkillVisual};
+use crate::combat::skill_runner::{self, SkillEffect};
+use crate::combat::status_effects::StatusEffect;
 use crate::combat::stats;
+use crate::combat::threat::ThreatTarget;
 use piston_window::Image;
 // NEW: Import stats constants for group UI
 use crate::combat::stats::{RAPTOR_LVL1_STATS, RACER_LVL1_STATS, SOLDIER_LVL1_STATS};
 use crate::piston_window::MouseScrollEvent;
-use graphics::camera::{screen_to_world, Camera};
+use graphics::assets::AssetRegistry;
+use graphics::camera::{screen_to_world, world_to_screen, Camera};
 use graphics::crater::draw_crater;
+use graphics::viewport::{set_window_mode, Viewport};
 use graphics::fighter_textures::{
     is_high_priority_animation_active, load_fighter_textures, update_current_textures,
 };
 
-use crate::entities::cpu_entity::VisualEffect;
 use crate::game_state::{
     CombatMode, DeathType, FighterType, GameState, MovementDirection, RacerState,
 };
 use crate::vehicle::fighter_jet::FighterJet;
 use entities::cpu_entity::{CpuEntity, CpuVariant};
 use entities::fighter::Fighter;
-use entities::fixed_crater::FixedCrater;
 use entities::fuel_pump::FuelPump;
 use entities::moving_sphere::MovingSphere;
-use entities::pulse_orb::PulseOrb;
 //use entities::pyramid::{generate_border_pyramids, Pyramid};
 //use utils::animation_queue::AnimationQueue;
 use entities::raptor_nest::RaptorNest;
 use entities::sbrx_bike::SbrxBike;
-use entities::shoot::Shoot;
 use entities::cpu_racer::CpuRacer;
 use entities::star::Star;
-use entities::strike::Strike;
 use entities::track::Track;
 use audio::AudioManager;
 use chatbox::{ChatBox, MessageType}; // Import the new ChatBox system
-use crate::map_system::{FieldId as SbrxFieldId, MapSystem as SbrxMapSystem};
-use utils::collision::check_line_collision;
-use utils::math::safe_gen_range;
+use game::gamepad::{direction_octant, movement_axes, Gamepad, PadMode, PadOutput};
+use game::input_handler::{Action, KeyBindings, RebindOutcome, RebindScreen, REBIND_SCREEN_KEY};
+use crate::map_system::FieldId as SbrxFieldId;
+use utils::math::{safe_gen_range, safe_gen_range_cosmetic};
+use simulation::{MeleeSwing, World, WorldEvent, WorldRules};
+use utils::timestep::{FixedTimestep, RenderInterpolation, FIXED_DT};
 use utils::vec2d::Vec2d;
-use config::gameplay::{
-    BIKE_INTERACTION_DISTANCE, COLLISION_THRESHOLD,
-    RAPTOR_NEST_INTERACTION_DISTANCE, FIGHTER_JET_INTERACTION_DISTANCE,
-};
-use config::movement::{
-    BIKE_SPEED, MOVEMENT_BUFFER_DURATION, ON_FOOT_HOLD_DURATION,
-    RUSH_DURATION,
-};
 use config::resolution::{HEIGHT, HORIZON_LINE, WIDTH};
-use config::{
-    boundaries::{MAX_X, MAX_Y, MIN_X, MIN_Y},
-    FOG_OF_WAR_ENABLED,
-};
-use config::{CPU_ENABLED, PERFORMANCE_MODE};
+use config::boundaries::{MAX_X, MAX_Y, MIN_X, MIN_Y};
 use piston_window::{
-    clear, ellipse, image, line, polygon, rectangle, text, AdvancedWindow, 
-	Button, CharacterCache, Flip, G2dTexture, G2dTextureContext,
-    ImageSize, Key, MouseButton, PistonWindow, PressEvent,
-    ReleaseEvent, RenderEvent, Texture, TextureSettings, Transformed,
-    UpdateEvent, Window, WindowSettings,
+    clear, ellipse, image, Ellipse, line, polygon, rectangle, text,
+	Button, CharacterCache, ControllerAxisEvent, Event, Flip, G2dTexture, G2dTextureContext,
+    ImageSize, Key, Loop, MouseButton, PistonWindow, PressEvent,
+    ReleaseEvent, RenderEvent, ResizeEvent, TextureSettings, Transformed,
+    UpdateArgs, UpdateEvent, Window, WindowSettings,
 };
 use rand::Rng;
 use rodio::Sink;
@@ -132,20 +135,20 @@ use crate::area::area::{AreaState, AREA_HEIGHT, AREA_ORIGIN_X, AREA_ORIGIN_Y, AR
 use crate::area::area::{AreaType, BUNKER_HEIGHT, BUNKER_ORIGIN_X, BUNKER_ORIGIN_Y, BUNKER_WIDTH};
 use crate::entities::ground_assets::GroundAssetManager;
 use crate::fog_of_war::FogOfWar;
-use crate::mechanics::wave::WaveManager;
-use crate::task::TaskSystem;
+use crate::mechanics::training::TrainingMode;
 
 const PARTICLE_COUNT_CPU: usize = 5;
-const FUEL_DEPLETION_RATE: f64 = 1.0; // move to fighter.rs
-const FUEL_REPLENISH_AMOUNT: f64 = 1.0; // move to fighter.rs
 const RACER_RANGED_COOLDOWN: f64 = 0.5; // move to fighter.rs
 const BOUNDARY_WARNING_COOLDOWN_TIME: f64 = 3.0;
+// FirmamentMode renders through the same viewport, so its playfield must match the canvas.
+const _: () = assert!(WIDTH == firmament_lib::WINDOW_WIDTH && HEIGHT == firmament_lib::WINDOW_HEIGHT);
 const DEFAULT_FIGHTER_JET_WORLD_X: f64 = MIN_X + (MAX_X - MIN_X) / 2.0;
 const DEFAULT_FIGHTER_JET_WORLD_Y: f64 = MIN_Y + (MAX_Y - MIN_Y) / 4.0;
 const BIKE_ACCELERATE_FRAME_DURATION: f64 = 0.08;
 const RAPTOR_INTERACTION_DISTANCE: f64 = 150.0;
 const INFO_POST_INTERACTION_DISTANCE: f64 = 150.0;
 const SOLDIER_RAPID_FIRE_RATE: f64 = 0.09; // Match CPU entity attack rate
+const SOLDIER_SHOT_RANGE: f64 = 800.0;
 const MELEE_RAPID_FIRE_RATE: f64 = 0.125; // Match CPU entity damage application rate
 const ESC_HOLD_DURATION_TO_EXIT: f64 = 3.0;
 const DEATH_SCREEN_COOLDOWN_TIME: f64 = 0.5;
@@ -184,6 +187,7 @@ pub struct DamageText {
     y: f64,
     color: [f32; 4],
     lifetime: f64,
+    style: TextStyle,
 }
 
 pub struct TaskRewardNotification {
@@ -221,6 +225,27 @@ struct KineticRushLine {
     max_lifetime: f64,
 }
 
+/// The arc flashed in front of the fighter on each melee strike.
+struct FrontalStrike {
+    timer: f64,
+    angle: f64,
+    color: [f32; 4],
+    slash_count: u32, // slashes drawn for a combo finisher
+}
+
+impl FrontalStrike {
+    fn show(&mut self, swing: &MeleeSwing, fighter: &Fighter) {
+        self.slash_count = swing.result.finisher_slash_count;
+        self.color = if swing.special {
+            [0.0, 1.0, 0.2, 0.4]
+        } else {
+            [0.25, 0.25, 0.25, 0.25]
+        };
+        self.timer = 0.1;
+        self.angle = (swing.y - fighter.y).atan2(swing.x - fighter.x);
+    }
+}
+
 struct CrashedFighterJetSite {
     sbrx_field_id: SbrxFieldId,
     world_x: f64,
@@ -271,7 +296,7 @@ fn spawn_particles(particles: &mut Vec<Particle>, pos_x: f64, pos_y: f64, count:
     // Safety: Global hard cap on particles
     if particles.len() > 2000 { return; }	
 	
-    let mut rng = rand::rng();
+    let mut rng = utils::rng::cosmetic_rng();
     let speed_range = (150.0, 250.0);
     let lifetime_range = (0.3, 0.5);
     let base_size = 10.0;
@@ -309,448 +334,46 @@ fn find_assets_folder(exe_dir: &Path) -> PathBuf {
     PathBuf::from("assets")
 }
 
+/// Strikes toward `aim` and, if the strike was taken, shows it: the frontal arc and the
+/// next strike frame. Returns whether the fighter struck.
 fn handle_melee_strike<'a>(
-    fighter: &mut Fighter,
-    camera: &Camera,
-    mouse_x: f64,
-    mouse_y: f64,
-    fixed_crater: &mut FixedCrater,
-    combo_system: &mut ComboSystem,
-    strike: &mut Strike,
-    audio_manager: &AudioManager,
-	chatbox: &mut ChatBox,
-    current_strike_textures: &'a Vec<G2dTexture>,
+    world: &mut World,
+    aim: (f64, f64),
+    is_paused: bool,
+    frontal_strike: &mut FrontalStrike,
+    strike_textures: &'a [G2dTexture],
     current_racer_texture: &mut &'a G2dTexture,
     strike_frame: &mut usize,
-    strike_animation_timer: &mut f64,
-    movement_active: &mut bool,
-    backpedal_active: &mut bool,
-    frontal_strike_timer: &mut f64,
-    frontal_strike_angle: &mut f64,
-    frontal_strike_color: &mut [f32; 4],
-    frontal_strike_is_special: &mut bool,
-    combo_finisher_slash_count: &mut u32,
-    cpu_entities: &mut Vec<CpuEntity>,
-	cpu_racers: &mut Vec<CpuRacer>,
-    damage_texts: &mut Vec<DamageText>,
-	is_paused: bool,
-	current_field: SbrxFieldId,
-) {
-    let (mut wmx, mut wmy) = screen_to_world(camera, mouse_x, mouse_y);
-    let dx = wmx - fixed_crater.x;
-    let dy = wmy - fixed_crater.y;
-    let hr = fixed_crater.radius;
-    let vr = fixed_crater.radius * 0.75;
-    let dsq = (dx * dx) / (hr * hr) + (dy * dy) / (vr * vr);
-
-    if fighter.combat_mode == CombatMode::CloseCombat && dsq > 1.0 {
-        let distance_factor = dsq.sqrt();
-        wmx = fighter.x + (wmx - fighter.x) / distance_factor;
-        wmy = fighter.y + (wmy - fighter.y) / distance_factor;
-    }
-
-    if let Some(result) = combo_system.handle_strike_for_fighter(fighter.fighter_type) {
-        audio_manager
-            .play_sound_effect(if result.is_combo_finisher {
-                "slash_combo"
-            } else {
-                "melee"
-            })
-            .ok();
-        strike.trigger(wmx, wmy);
-        *combo_finisher_slash_count = result.finisher_slash_count;
-        if !current_strike_textures.is_empty() {
-            *current_racer_texture =
-                &current_strike_textures[*strike_frame % current_strike_textures.len()];
-            *strike_frame = (*strike_frame + 1) % current_strike_textures.len();
-        }
-        *strike_animation_timer = 0.25;
-        *movement_active = false;
-        *backpedal_active = false;
-
-        let strike_is_special = result.is_combo_finisher || combo_system.is_combo_strike_active();
-        *frontal_strike_is_special = strike_is_special;
-        *frontal_strike_color = if strike_is_special {
-            [0.0, 1.0, 0.2, 0.4]
-        } else {
-            [0.25, 0.25, 0.25, 0.25]
-        };
-        *frontal_strike_timer = 0.1;
-        *frontal_strike_angle = (wmy - fighter.y).atan2(wmx - fighter.x);
-
-        if CPU_ENABLED && !is_paused {
-            let mut point_damage = fighter.melee_damage * result.damage_multiplier;
-			
-            // Damage reduction if in Ranged mode on foot (Racer only)
-            if fighter.fighter_type == FighterType::Racer && fighter.state == RacerState::OnFoot && !fighter.boost {
-                point_damage *= 0.90; // 10% reduction
-            }			
-
-            // Apply damage reduction if on bike
-            if fighter.state == RacerState::OnBike {
-                if fighter.fighter_type == FighterType::Racer && fighter.boost {
-                    point_damage *= 0.50; // 25% increase over base 0.1 (0.1 * 1.25)
-                } else {
-                    point_damage *= 0.25; // 90% reduction
-                }
-            }
-            // 25% damage boost while ATOMIC-STATE is active
-            if fighter.invincible_timer > 1.0 {
-                point_damage *= 1.25;
-            }			
-            let frontal_damage = point_damage / 2.0;
-            let mut point_hit_applied = false;
-
-            for cpu in cpu_entities.iter_mut() {
-                if cpu.entity_state == EntityState::Friendly {
-                    continue;
-                }				
-				
-                let mut total_damage_this_cpu = 0.0;
-                let mut was_point_hit = false;
-                let mut was_frontal_hit = false;
-
-                if !point_hit_applied {
-                    let sdx = wmx - cpu.x;
-                    let sdy = wmy - cpu.y;
-                    if (sdx * sdx + sdy * sdy).sqrt() < COLLISION_THRESHOLD {
-                        total_damage_this_cpu += point_damage;
-                        was_point_hit = true;
-                        point_hit_applied = true;
-                    }
-                }
-
-                if !was_point_hit {
-                    let dist_sq_from_player =
-                        (cpu.x - fighter.x).powi(2) + (cpu.y - fighter.y).powi(2);
-                    if dist_sq_from_player <= fixed_crater.radius.powi(2) {
-                        let mouse_vec_x = wmx - fighter.x;
-                        let mouse_vec_y = wmy - fighter.y;
-                        let enemy_vec_x = cpu.x - fighter.x;
-                        let enemy_vec_y = cpu.y - fighter.y;
-                        let dot_product = mouse_vec_x * enemy_vec_x + mouse_vec_y * enemy_vec_y;
-
-                        if dot_product > 0.0 {
-                            total_damage_this_cpu += frontal_damage;
-                            was_frontal_hit = true;
-                        }
-                    }
-                }
-
-                if total_damage_this_cpu > 0.0 {
-                    cpu.current_hp -= total_damage_this_cpu;
-					
-                    // Track that a hit connected during the T3 combo (strikes 1-4) for Racer
-                    if was_point_hit
-                        && result.finisher_hit_count < 5
-                        && fighter.fighter_type == FighterType::Racer
-                    {
-                        combo_system.racer_combo_hit_connected = true;
-                    }					
-
-                    let text_color = if fighter.invincible_timer > 1.0 {
-                        [0.7, 1.0, 0.0, 1.0] // yellow-green — ATOMIC-STATE active
-                    } else if was_point_hit && result.is_combo_finisher {
-                        [0.0, 1.0, 0.0, 1.0]
-                    } else if was_point_hit {
-                        [1.0, 1.0, 1.0, 1.0]
-                    } else {
-                        [0.8, 0.8, 0.8, 1.0]
-                    };
-					
-                    // Safety: Prevent text overflow
-                    if damage_texts.len() < 100 {
-						damage_texts.push(DamageText {
-							text: format!("{:.0}", total_damage_this_cpu),
-							x: cpu.x,
-							y: cpu.y - 50.0,
-							color: text_color,
-							lifetime: 0.25,
-						});
-					}	
-
-                    if !cpu.is_dead() {
-                        if was_point_hit {
-                            if result.knockback {
-                                cpu.apply_knockback(wmx, wmy, result.knockback_force);
-                            }	
-							
-							// Achievement: 5-Hit Combo Reward for RACER
-							// We check result.finisher_hit_count directly instead of result.apply_stun
-							if result.finisher_hit_count == 5 && fighter.fighter_type == FighterType::Racer {
-								fighter.invincible_timer = 2.5;
-								chatbox.add_interaction(vec![
-									//("ATOMIC-STATE", MessageType::Warning),
-								]);
-							}							
-							
-                            if result.apply_stun {
-                                if result.finisher_hit_count == 3 {
-                                    cpu.stun_timer = 1.0;
-                                    damage_texts.push(DamageText {
-                                        text: "STUN".to_string(),
-                                        x: cpu.x,
-                                        y: cpu.y - 90.0,
-                                        color: [1.0, 1.0, 1.0, 1.0],
-                                        lifetime: 0.5,
-                                    });
-                                } else if result.finisher_hit_count == 5 {
-                                    cpu.stun_timer = 2.0;
-									
-									// Achievement: 5-Hit Combo Reward for RACER
-									if fighter.fighter_type == FighterType::Racer {
-										fighter.invincible_timer = 2.5;
-										chatbox.add_interaction(vec![
-											//("ATOMIC-STATE", MessageType::Warning),
-										]);
-									}									
-                                }
-                            }
-                        } else if was_frontal_hit && result.is_combo_finisher {
-                            let frontal_knockback_force = result.knockback_force / 2.0;
-                            cpu.apply_knockback(wmx, wmy, frontal_knockback_force);
-                        }
-                    }
-                }
-            }
-			
-            if current_field == SbrxFieldId(0, 0) {
-                let mut r_point_hit_applied = false;
-                for cr in cpu_racers.iter_mut() {
-                    if cr.is_crashed { continue; }
-					if cr.entity_state == EntityState::Friendly { continue; }
-                    let mut total_damage_this_cr = 0.0;
-                    let mut was_point_hit = false;
-                    let mut was_frontal_hit = false;
- 
-                    if !r_point_hit_applied {
-                        let sdx = wmx - cr.x;
-                        let sdy = wmy - cr.y;
-                        if (sdx * sdx + sdy * sdy).sqrt() < COLLISION_THRESHOLD {
-                            total_damage_this_cr += point_damage;
-                            was_point_hit = true;
-                            r_point_hit_applied = true;
-                        }
-                    }
- 
-                    if !was_point_hit {
-                        let dist_sq_from_player = (cr.x - fighter.x).powi(2) + (cr.y - fighter.y).powi(2);
-                        if dist_sq_from_player <= fixed_crater.radius.powi(2) {
-                            let mouse_vec_x = wmx - fighter.x;
-                            let mouse_vec_y = wmy - fighter.y;
-                            let enemy_vec_x = cr.x - fighter.x;
-                            let enemy_vec_y = cr.y - fighter.y;
-                            let dot_product = mouse_vec_x * enemy_vec_x + mouse_vec_y * enemy_vec_y;
- 
-                            if dot_product > 0.0 {
-                                total_damage_this_cr += frontal_damage;
-                                was_frontal_hit = true;
-                            }
-                        }
-                    }
- 
-                    if total_damage_this_cr > 0.0 {
-                        if cr.entity_state == EntityState::Neutral {
-                            cr.entity_state = EntityState::Hostile;
-                        }						
-                        cr.current_hp -= total_damage_this_cr;
-                        
-                        if was_point_hit && result.finisher_hit_count < 5 && fighter.fighter_type == FighterType::Racer {
-                            combo_system.racer_combo_hit_connected = true;
-                        }
- 
-                        let text_color = if fighter.invincible_timer > 1.0 {
-                            [0.7, 1.0, 0.0, 1.0]
-                        } else if was_point_hit && result.is_combo_finisher {
-                            [0.0, 1.0, 0.0, 1.0]
-                        } else if was_point_hit {
-                            [1.0, 1.0, 1.0, 1.0]
-                        } else {
-                            [0.8, 0.8, 0.8, 1.0]
-                        };
- 
-                        if damage_texts.len() < 100 {
-                            damage_texts.push(DamageText {
-                                text: format!("{:.0}", total_damage_this_cr),
-                                x: cr.x,
-                                y: cr.y - 50.0,
-                                color: text_color,
-                                lifetime: 0.25,
-                            });
-                        }
- 
-                        if cr.current_hp > 0.0 {
-                            if was_point_hit {
-                                if result.knockback {
-                                    let kx = cr.x - wmx;
-                                    let ky = cr.y - wmy;
-                                    let k_dist = (kx * kx + ky * ky).sqrt();
-                                    if k_dist > 0.0 {
-                                        let norm_kx = kx / k_dist;
-                                        let norm_ky = ky / k_dist;
-                                        cr.knockback_velocity = Vec2d::new(norm_kx * result.knockback_force, norm_ky * result.knockback_force);
-                                        cr.knockback_duration = 0.1;
-                                    }
-                                }
- 
-                                if result.apply_stun {
-                                    if result.finisher_hit_count == 3 {
-                                        cr.stun_timer = 1.0;
-                                        damage_texts.push(DamageText {
-                                            text: "STUN".to_string(),
-                                            x: cr.x,
-                                            y: cr.y - 90.0,
-                                            color: [1.0, 1.0, 1.0, 1.0],
-                                            lifetime: 0.5,
-                                        });
-                                    } else if result.finisher_hit_count == 5 {
-                                        cr.stun_timer = 2.0;
-                                        if fighter.fighter_type == FighterType::Racer {
-                                            fighter.invincible_timer = 2.5;
-                                        }
-                                    }
-                                }
-                            } else if was_frontal_hit && result.is_combo_finisher {
-                                let frontal_knockback_force = result.knockback_force / 2.0;
-                                let kx = cr.x - wmx;
-                                let ky = cr.y - wmy;
-                                let k_dist = (kx * kx + ky * ky).sqrt();
-                                if k_dist > 0.0 {
-                                    let norm_kx = kx / k_dist;
-                                    let norm_ky = ky / k_dist;
-                                    cr.knockback_velocity = Vec2d::new(norm_kx * frontal_knockback_force, norm_ky * frontal_knockback_force);
-                                    cr.knockback_duration = 0.1;
-                                }
-                            }
-                        }
-                    }
-                }
-            }			
-			
-            // Racer ATOMIC-STATE: grant if 5th strike fires and any of strikes 1-4 connected
-            if result.finisher_hit_count == 5
-                && fighter.fighter_type == FighterType::Racer
-                && combo_system.racer_combo_hit_connected
-                && fighter.invincible_timer <= 1.0
-            {
-                fighter.invincible_timer = 2.5;
-                chatbox.add_interaction(vec![
-                    //("ATOMIC-STATE", MessageType::Warning),
-                ]);
-                combo_system.racer_combo_hit_connected = false;
-            }			
-        }
-    }
-}
-
-fn award_kill_score(
-    fighter: &mut Fighter,
-    points: u32,
-    chatbox: &mut ChatBox,
-    lvl_up_state: &mut LvlUpState,
-    _reason: &str,
-	aptitude: f64,
-) {
-	let scaled_points = (points as f64 * aptitude).round() as u32;
-    let current_kills = fighter
-        .kill_counters
-        .entry(fighter.fighter_type)
-        .or_insert(0);
-    *current_kills += scaled_points;
-    println!("Awarded {} kill score points for {}.", scaled_points, aptitude);
-
-    let current_level = fighter.levels.entry(fighter.fighter_type).or_insert(1);
-    let stat_points = fighter
-        .stat_points_to_spend
-        .entry(fighter.fighter_type)
-        .or_insert(0);
-    let levels_gained = mechanics::lvl_up::check_for_level_up(current_kills, current_level);
-
-    if levels_gained > 0 {
-        *stat_points += levels_gained;
-        *lvl_up_state = LvlUpState::PendingTab {
-            fighter_type: fighter.fighter_type,
-        };
-        let fighter_name = match fighter.fighter_type {
-            FighterType::Racer => "RACER",
-            FighterType::Soldier => "SOLDIER",
-            FighterType::Raptor => "RAPTOR",
-        };
-        chatbox.add_interaction(vec![(
-            &format!(
-                "!! [TAB] TO LVL UP [{}] +[{}] !!",
-                fighter_name, *stat_points
-            ),
-            MessageType::Warning,
-        )]);
+) -> bool {
+    let Some(swing) = world.melee_strike(aim.0, aim.1, is_paused) else {
+        return false;
+    };
+    frontal_strike.show(&swing, &world.fighter);
+    if !strike_textures.is_empty() {
+        *current_racer_texture = &strike_textures[*strike_frame % strike_textures.len()];
+        *strike_frame = (*strike_frame + 1) % strike_textures.len();
     }
-}
-
-/// Helper to load a texture or exit with error message
-fn load_texture_or_exit(
-    texture_context: &mut G2dTextureContext,
-    path: &Path,
-    settings: &TextureSettings,
-    name: &str,
-) -> G2dTexture {
-    Texture::from_path(texture_context, path, Flip::None, settings)
-        .unwrap_or_else(|e| {
-            eprintln!("Fatal: Failed to load {} texture at {:?}: {}", name, path, e);
-            std::process::exit(1);
-        })
+    true
 }
 
 fn load_cpu_textures(
     texture_context: &mut G2dTextureContext,
-    assets_path: &Path,
+    assets: &mut AssetRegistry,
     variant_folder: &str,
 ) -> Vec<G2dTexture> {
-    let mut textures = Vec::new();
     let settings = TextureSettings::new();
-    let base_path = assets_path.join(format!("entity/{}/{}.png", variant_folder, variant_folder));
-	let base_texture = match Texture::from_path(texture_context, &base_path, Flip::None, &settings) {
-		Ok(tex) => tex,
-		Err(e) => {
-			eprintln!(
-				"Fatal: Failed to load {} base texture at {:?}: {}",
-				variant_folder, base_path, e
-			);
-			std::process::exit(1);
-		}
-	};
-    textures.push(base_texture);
-    for i in 1..=3 {
-        let strike_path = assets_path.join(format!(
-            "entity/{}/{}Strike{}.png",
-            variant_folder, variant_folder, i
-        ));
-        if strike_path.exists() {
-			match Texture::from_path(texture_context, &strike_path, Flip::None, &settings) {
-				Ok(tex) => textures.push(tex),
-				Err(e) => {
-					eprintln!(
-						"Fatal: Failed to load {} strike{} texture at {:?}: {}",
-						variant_folder, i, strike_path, e
-					);
-					std::process::exit(1);
-				}
-			}
-        } else {
-            if let Some(first_tex) = textures.first().cloned() {
-                textures.push(first_tex);
-            }
-        }
-    }
-    while textures.len() < 4 {
-        if let Some(first_tex) = textures.first().cloned() {
-            textures.push(first_tex);
-        } else {
-            eprintln!(
-                "No textures loaded for CPU variant {}, cannot create fallbacks.",
-                variant_folder
-            );
-			std::process::exit(1);
-        }
+    let (base_path, strike_paths) = asset_manifest::cpu_texture_paths(variant_folder);
+	let base_texture = assets.texture(
+		texture_context,
+		Path::new(&base_path),
+		&settings,
+		&format!("{} base", variant_folder),
+	);
+    let mut textures = vec![base_texture];
+    for strike_path in strike_paths {
+        // Missing strike frames reuse the base sprite
+        let strike_texture = assets.optional_texture(texture_context, Path::new(&strike_path), &settings);
+        textures.push(strike_texture.unwrap_or_else(|| textures[0].clone()));
     }
     textures
 }
@@ -761,7 +384,7 @@ fn check_and_display_demonic_presence(
     chatbox: &mut ChatBox,
     fog_of_war: &FogOfWar,
 ) {
-    if FOG_OF_WAR_ENABLED && fog_of_war.is_fog_enabled(*current_field_id) {
+    if config::get().fog_of_war_enabled && fog_of_war.is_fog_enabled(*current_field_id) {
         if cpu_entities
             .iter()
             .any(|e| e.variant == CpuVariant::BloodIdol && !e.is_dead())
@@ -779,14 +402,18 @@ fn handle_ambient_playlist(
     bgm_sink: &mut Option<Sink>,
     index: &mut usize,
 ) -> Option<String> {
-    let playlist = [
-        "sdtrk1", "sdtrk2", "sdtrk3", "sdtrk4", "sdtrk5",
-        "sdtrk6", "sdtrk7", "sdtrk8", "sdtrk9", "sdtrk10",
-        "sdtrk11", "sdtrk12", "sdtrk13", "sdtrk14", "sdtrk15"
-    ];	
+    let playlist = asset_manifest::SOUNDTRACK_PLAYLIST;	
 
     if bgm_sink.as_ref().map_or(true, |s| s.empty()) {
+        if audio_manager.is_silent() {
+            return None;
+        }
         let track = playlist[*index];
+        if !audio_manager.has_sound(track) {
+            // Missing track: skip it rather than "playing" an empty sink every frame.
+            *index = (*index + 1) % playlist.len();
+            return None;
+        }
         if let Ok(sink) = audio_manager.play_sfx_with_sink(track) {
             *bgm_sink = Some(sink);
             *index = (*index + 1) % playlist.len();
@@ -796,17 +423,120 @@ fn handle_ambient_playlist(
 	None
 }
 
+/// Installs a loaded data file, or lists its errors and exits.
+fn install_or_exit<T>(what: &str, loaded: Result<T, Vec<config::ConfigError>>, install: fn(T)) {
+    match loaded {
+        Ok(loaded) => install(loaded),
+        Err(errors) => {
+            eprintln!("Fatal: invalid {} ({} error(s)):", what, errors.len());
+            for error in &errors {
+                eprintln!("  {}", error);
+            }
+            std::process::exit(1);
+        }
+    }
+}
+
 fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    // Settings: settings.cfg next to the executable (or --config <file>), then --set key=value overrides.
+    install_or_exit("configuration", config::load(&args), config::install);
+    let game_config = config::get();
+
+    // Combo chains, skills and resource profiles: built-in, with combos.cfg, skills.cfg
+    // and resources.cfg next to the executable layered on top.
+    let combos_dir = env::current_exe().ok().and_then(|exe| exe.parent().map(Path::to_path_buf));
+    if let Some(dir) = combos_dir {
+        install_or_exit("combo definitions", combat::combo_data::load(&dir), combat::combo_data::install);
+        install_or_exit("skill definitions", combat::skill_data::load(&dir), combat::skill_data::install);
+        install_or_exit("resource profiles", combat::resources::load(&dir), combat::resources::install);
+    }
+
+    // --seed <u64>: reproduce a run exactly. Without it a random seed is chosen and logged.
+    if let Some(pos) = args.iter().position(|a| a == "--seed") {
+        match args.get(pos + 1).map(|v| v.parse::<u64>()) {
+            Some(Ok(seed)) => utils::rng::reseed(seed),
+            _ => {
+                eprintln!("Fatal: --seed expects an unsigned integer value");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // --replay <file>: play back recorded input (and its seed) instead of live input.
+    let mut replay_player = match args.iter().position(|a| a == "--replay") {
+        Some(pos) => {
+            let path = PathBuf::from(args.get(pos + 1).map(String::as_str).unwrap_or("replay.sbrx"));
+            match replay::ReplayPlayer::load(&path) {
+                Ok(player) => {
+                    utils::rng::reseed(player.seed);
+                    Some(player)
+                }
+                Err(e) => {
+                    eprintln!("Fatal: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        None => None,
+    };
+    let mut pending_replay_events = std::collections::VecDeque::new();
+    println!("[RNG] Game seed: {}", utils::rng::current_seed());
+
+    // --record <file>: stream per-frame input into a replay file as the game runs.
+    let mut replay_recorder = args.iter().position(|a| a == "--record").and_then(|pos| {
+        let path = PathBuf::from(args.get(pos + 1).map(String::as_str).unwrap_or("replay.sbrx"));
+        match replay::ReplayRecorder::create(path, utils::rng::current_seed()) {
+            Ok(recorder) => Some(recorder),
+            Err(e) => {
+                eprintln!("[REPLAY] {}", e);
+                None
+            }
+        }
+    });
+
+    // --headless <seconds> [--bunker <floor>]: run the simulation core with no window or audio.
+    if let Some(pos) = args.iter().position(|a| a == "--headless") {
+        let seconds = args
+            .get(pos + 1)
+            .and_then(|v| v.parse::<f64>().ok())
+            .unwrap_or(60.0);
+        let bunker_floor = args
+            .iter()
+            .position(|a| a == "--bunker")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|v| v.parse::<i32>().ok());
+        simulation::run_headless(seconds, bunker_floor);
+        return;
+    }
+
+    // --check-assets <dir>: validate an asset drop (<dir>/assets, <dir>/sfx) against the manifest and exit.
+    if let Some(pos) = args.iter().position(|a| a == "--check-assets") {
+        let dir = PathBuf::from(args.get(pos + 1).map(String::as_str).unwrap_or("."));
+        let ok = asset_manifest::check_assets(&dir);
+        std::process::exit(if ok { 0 } else { 1 });
+    }
+
+    // --bench-spatial [entities]: time full scans against spatial hash queries and exit.
+    if let Some(pos) = args.iter().position(|a| a == "--bench-spatial") {
+        let entity_count = args
+            .get(pos + 1)
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(1000);
+        simulation::run_spatial_benchmark(entity_count);
+        return;
+    }
+
     let screen_width = WIDTH;
     let screen_height = HEIGHT;
     let line_y = HORIZON_LINE;
-    let movement_buffer_duration = MOVEMENT_BUFFER_DURATION;
-    let rush_duration = RUSH_DURATION;
+    let movement_buffer_duration = game_config.movement.movement_buffer_duration;
+    let rush_duration = game_config.movement.rush_duration;
 
-    let bike_interaction_distance = BIKE_INTERACTION_DISTANCE;
-    let max_stars = if PERFORMANCE_MODE { 25 } else { 25 };
+    let bike_interaction_distance = game_config.gameplay.bike_interaction_distance;
+    let max_stars = if game_config.performance_mode { 25 } else { 25 };
     let sky_width = 7250.0;
-    let mut game_time = 0.0;
     let mut firmament_boss_defeated = false;
     let mut bunker_entry_choice = BunkerEntryChoice::None;
     let mut fort_silo_gravity_message_shown = false;
@@ -820,7 +550,7 @@ fn main() {
 
     // Helper function to spawn a random CPU entity for the arena mode
     fn spawn_random_cpu(line_y: f64, stage: u32, arena_timer: f64) -> CpuEntity {
-        let mut rng = rand::rng();
+        let mut rng = utils::rng::gameplay_rng();
         // Determine the range of enemies to spawn based on the arena stage
         let max_variant = if stage >= 2 {
             10 // Stage 2+ includes all 10 variants
@@ -886,11 +616,22 @@ fn main() {
  	        std::process::exit(1);
  	    }
  	};
+ 	let saves_dir = save::saves_dir(exe_dir);
+ 	let key_bindings_path = exe_dir.join(game::input_handler::BINDINGS_FILE_NAME);
+ 	let mut key_bindings = KeyBindings::load_or_default(&key_bindings_path);
+ 	let mut rebind_screen: Option<RebindScreen> = None;
+ 	let mut paused_before_rebind = false;
+	let mut gamepad = Gamepad::new(game_config.gamepad.enabled);
+	let mut game_stats = GameStats::new();
+	let mut fixed_timestep = FixedTimestep::new();
+	let mut render_interpolation = RenderInterpolation::new();
+	let mut fixed_steps_queued: u32 = 0;
+	let mut last_pad_mode = PadMode::Field;
  	let audio_manager = match AudioManager::new() {
  	    Ok(manager) => manager,
  	    Err(e) => {
- 	        eprintln!("Fatal: Failed to initialize audio: {}", e);
- 	        std::process::exit(1);
+ 	        eprintln!("Warning: Failed to initialize audio: {}. Continuing without sound.", e);
+ 	        AudioManager::silent()
  	    }
  	};
     audio_manager
@@ -901,182 +642,62 @@ fn main() {
                 e
             )
         });
+    // Gameplay state the update tick shares with the headless runner (see simulation.rs).
+    let mut world = World::new(audio_manager);
 
+    // The game draws on a logical screen_width x screen_height canvas; the viewport scales
+    // it to whatever size the window ends up with.
+    let windowed_size = [game_config.display.windowed_width, game_config.display.windowed_height];
     let mut window: PistonWindow =
-        WindowSettings::new("Sabercross", [screen_width as u32, screen_height as u32])
-            .resizable(false)
-            .decorated(false)
+        WindowSettings::new("Sabercross", windowed_size)
+            .resizable(true)
             .exit_on_esc(false)
             .build()
  	        .unwrap_or_else(|e| {
  	            eprintln!("Fatal: Failed to build PistonWindow: {}", e);
  	            std::process::exit(1);
  	        });
-    window.set_position([0, 0]);
+    let mut window_mode = game_config.display.window_mode;
+    set_window_mode(&mut window, window_mode, windowed_size);
 	window.window.window.set_cursor_visible(false);
+    let window_size = window.size();
+    let mut viewport = Viewport::new(game_config.display.scale_mode, [window_size.width, window_size.height]);
     println!("sbrx0.2.22 Window created.");
 
     let sbrx_assets_path = find_assets_folder(&exe_dir);
+    let mut assets = AssetRegistry::new(sbrx_assets_path.clone());
     let mut texture_context = window.create_texture_context();
 
     // Initialize ChatBox after texture_context is created
-    let mut chatbox = ChatBox::new(&mut window, &sbrx_assets_path);
+    let mut chatbox = ChatBox::new(&mut texture_context, &mut assets);
 
     // --- NEW: Load all ground asset textures ---
     let mut ground_asset_textures: HashMap<String, G2dTexture> = HashMap::new();
-    let ground_asset_paths = [
-        ("broken_post", "ground/broken_post.png"),
-        ("cactus", "ground/cactus.png"),
-        ("cactus2", "ground/cactus2.png"),
-        ("campfire_lit", "ground/campfire_lit.png"),
-        ("campfire_loaded", "ground/campfire_loaded.png"),
-        ("campfire_out", "ground/campfire_out.png"),
-        ("cow_skull", "ground/cow_skull.png"),
-        ("dead_tree", "ground/dead_tree.png"),
-        ("fence", "ground/fence.png"),
-        ("fence_corner", "ground/fence_corner.png"),
-        ("fence_side", "ground/fence_side.png"),
-        ("fence_side_double", "ground/fence_side_double.png"),
-        ("log", "ground/log.png"),
-        ("log_pile", "ground/log_pile.png"),
-        ("plant", "ground/plant.png"),
-        ("rock", "ground/rock.png"),
-        ("rock2", "ground/rock2.png"),
-        ("rock3", "ground/rock3.png"),
-        ("rock4", "ground/rock4.png"),
-        ("rock5", "ground/rock5.png"),
-        ("rock6", "ground/rock6.png"),
-        ("tall_grass", "ground/tall_grass.png"),
-        ("wagon", "ground/wagon.png"),
-        ("yucca", "ground/yucca.png"),
-        ("yucca2", "ground/yucca2.png"),
-    ];
 
     let texture_settings = TextureSettings::new();
-    for (name, path) in ground_asset_paths.iter() {
-        let full_path = sbrx_assets_path.join(path);
-        match Texture::from_path(
+    for ground_asset in GroundAssetManager::new().assets() {
+        let texture = assets.texture(
             &mut texture_context,
-            &full_path,
-            Flip::None,
+            Path::new(ground_asset.path),
             &texture_settings,
- 	    ) {
- 	        Ok(texture) => {
- 	            ground_asset_textures.insert(name.to_string(), texture);
- 	        }
- 	        Err(e) => {
- 	            eprintln!(
- 	                "Fatal: Failed to load ground asset texture '{}' at {:?}: {}",
- 	                name, full_path, e
- 	            );
- 	            std::process::exit(1);
- 	        }
- 	    }
+            ground_asset.name,
+        );
+        ground_asset_textures.insert(ground_asset.name.to_string(), texture);
     }
 
-    let font_path = sbrx_assets_path.join("fonts").join("Segment16A.ttf");
-	let mut glyphs = match window.load_font(font_path.clone()) {
- 	    Ok(g) => g,
- 	    Err(e) => {
- 	        eprintln!(
- 	            "Failed to load font at {:?}: {}. Attempting fallback.",
- 	            font_path, e
- 	        );
- 	        let fallback_font_path_win = Path::new("C:\\Windows\\Fonts\\arial.ttf");
- 	        let fallback_font_path_linux = Path::new("/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf");
- 	        if fallback_font_path_win.exists() {
- 	            match window.load_font(fallback_font_path_win) {
- 	                Ok(g) => g,
- 	                Err(fe) => {
- 	                    eprintln!(
- 	                        "Fatal: Failed to load Windows fallback font: {:?}, original error: {:?}",
- 	                        fe, e
- 	                    );
- 	                    std::process::exit(1);
- 	                }
- 	            }
- 	        } else if fallback_font_path_linux.exists() {
- 	            match window.load_font(fallback_font_path_linux) {
- 	                Ok(g) => g,
- 	                Err(fe) => {
- 	                    eprintln!(
- 	                        "Fatal: Failed to load Linux fallback font: {:?}, original error: {:?}",
- 	                        fe, e
- 	                    );
- 	                    std::process::exit(1);
- 	                }
- 	            }
- 	        } else {
- 	            eprintln!(
- 	                "Fatal: Failed to load primary font and no fallback fonts found: original error: {:?}",
- 	                e
- 	            );
- 	            std::process::exit(1);
- 	        }
- 	    }
- 	};
+	let mut glyphs = assets.font(&mut window, Path::new(asset_manifest::FONT));
+
+ 	let title_screen_texture = assets.game_texture(&mut texture_context, "title_screen", &texture_settings);		
+ 	let pause_screen_texture = assets.game_texture(&mut texture_context, "pause_screen", &texture_settings);			
+ 	let inputs_display_texture = assets.game_texture(&mut texture_context, "inputs_display", &texture_settings);
+	let gear_texture = assets.game_texture(&mut texture_context, "gear", &texture_settings);	
+ 	let block_fatigue_texture = assets.game_texture(&mut texture_context, "block_fatigue", &texture_settings);
+ 	let flicker_strike_effect_texture = assets.game_texture(&mut texture_context, "flicker_strike_effect", &texture_settings);
+	let atomic_state_texture = assets.game_texture(&mut texture_context, "atomic_state", &texture_settings);	
 
- 	let title_screen_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("sabercrossTITLE.png"),
- 	    &texture_settings,
- 	    "title_screen",
- 	);		
- 	let pause_screen_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("pause_screen1.png"),
- 	    &texture_settings,
- 	    "pause_screen",
- 	);			
- 	let inputs_display_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("inputs.png"),
- 	    &texture_settings,
- 	    "inputs_display",
- 	);
-	let gear_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("gear.png"),
- 	    &texture_settings,
- 	    "gear",
- 	);	
- 	let block_fatigue_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("block_fatigue.png"),
- 	    &texture_settings,
- 	    "block_fatigue",
- 	);
- 	let flicker_strike_effect_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("effects/FlickerStrikeEffect.png"),
- 	    &texture_settings,
- 	    "flicker_strike_effect",
- 	);
-	let atomic_state_texture = load_texture_or_exit(
-	    &mut texture_context,
-	    &sbrx_assets_path.join("effects/atomic_state.png"),
-	    &texture_settings,
-	    "atomic_state",
-	);	
-
- 	let kinetic_strike_effect_texture1 = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("effects/KineticStrikeEffect1.png"),
- 	    &texture_settings,
- 	    "kinetic_strike_effect1",
- 	);
- 	let kinetic_strike_effect_texture2 = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("effects/KineticStrikeEffect2.png"),
- 	    &texture_settings,
- 	    "kinetic_strike_effect2",
- 	);
-	let kinetic_strike_effect_texture3 = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("effects/KineticStrikeEffect3.png"),
- 	    &texture_settings,
- 	    "kinetic_strike_effect3",
- 	);
+ 	let kinetic_strike_effect_texture1 = assets.game_texture(&mut texture_context, "kinetic_strike_effect1", &texture_settings);
+ 	let kinetic_strike_effect_texture2 = assets.game_texture(&mut texture_context, "kinetic_strike_effect2", &texture_settings);
+	let kinetic_strike_effect_texture3 = assets.game_texture(&mut texture_context, "kinetic_strike_effect3", &texture_settings);
 
     let kinetic_strike_textures = vec![
         kinetic_strike_effect_texture1,
@@ -1084,109 +705,35 @@ fn main() {
         kinetic_strike_effect_texture3,
     ];
 
- 	let aim_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("aim.png"),
- 	    &texture_settings,
- 	    "aim",
- 	);
- 	let strike_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("strike.png"),
- 	    &texture_settings,
- 	    "strike",
- 	);
- 	let track_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("racetrack.png"),
- 	    &texture_settings,
- 	    "track",
- 	);
- 	let rocketbay_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("rocketbay.png"),
- 	    &texture_settings,
- 	    "rocketbay",
- 	);
-	let fort_silo2_texture = load_texture_or_exit(
-	    &mut texture_context,
-	    &sbrx_assets_path.join("fort_silo2.png"),
-	    &texture_settings,
-	    "fort_silo2",
-	);	
+ 	let aim_texture = assets.game_texture(&mut texture_context, "aim", &texture_settings);
+ 	let strike_texture = assets.game_texture(&mut texture_context, "strike", &texture_settings);
+ 	let track_texture = assets.game_texture(&mut texture_context, "track", &texture_settings);
+ 	let rocketbay_texture = assets.game_texture(&mut texture_context, "rocketbay", &texture_settings);
+	let fort_silo2_texture = assets.game_texture(&mut texture_context, "fort_silo2", &texture_settings);	
 	
-    let info_post_texture_path = sbrx_assets_path.join("info_post.png");
- 	let info_post_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &info_post_texture_path,
- 	    &texture_settings,
- 	    "info_post",
- 	);
-    let fort_silo_texture_path = sbrx_assets_path.join("fort_silo.png");
- 	let fort_silo_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &fort_silo_texture_path,
- 	    &texture_settings,
- 	    "fort_silo",
- 	);
-    let remains_texture_path = sbrx_assets_path.join("remains.png");
- 	let remains_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &remains_texture_path,
- 	    &texture_settings,
- 	    "remains",
- 	);
-    let raptor_block_break_nest_texture_path =
-        sbrx_assets_path.join("player/raptor/block_break.png");
- 	let raptor_block_break_nest_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &raptor_block_break_nest_texture_path,
- 	    &texture_settings,
- 	    "raptor_block_break_nest",
- 	);
+ 	let info_post_texture = assets.game_texture(&mut texture_context, "info_post", &texture_settings);
+ 	let fort_silo_texture = assets.game_texture(&mut texture_context, "fort_silo", &texture_settings);
+ 	let remains_texture = assets.game_texture(&mut texture_context, "remains", &texture_settings);
+ 	let raptor_block_break_nest_texture = assets.game_texture(&mut texture_context, "raptor_block_break_nest", &texture_settings);
 
     // --- NEW: Load ground textures ---
-    let ground_texture_path = sbrx_assets_path.join("ground/ground_texture.png");
+    let ground_texture_path = Path::new(asset_manifest::game_texture("ground"));
     let mut ground_textures = Vec::new();
     let flips = [Flip::None, Flip::Horizontal, Flip::Vertical, Flip::Both];
     for flip in &flips {
-	    match Texture::from_path(
+	    ground_textures.push(assets.texture_flipped(
  	        &mut texture_context,
- 	        &ground_texture_path,
+ 	        ground_texture_path,
  	        *flip,
  	        &texture_settings,
- 	    ) {
- 	        Ok(tex) => ground_textures.push(tex),
- 	        Err(e) => {
- 	            eprintln!(
- 	                "Fatal: Failed to load ground texture at {:?} with flip {:?}: {}",
- 	                ground_texture_path, flip, e
- 	            );
- 	            std::process::exit(1);
- 	        }
- 	    }
+ 	        "ground",
+ 	    ));
     }
 
-	    let racer_textures = load_fighter_textures(&mut window, "racer", sbrx_assets_path.clone())
-	        .unwrap_or_else(|e| {
-	            eprintln!("Fatal error loading racer textures: {}", e);
-	            std::process::exit(1);
-	        });
- 	    let racer2_textures = load_fighter_textures(&mut window, "racer2", sbrx_assets_path.clone())
- 	        .unwrap_or_else(|e| {
- 	            eprintln!("Fatal error loading racer2 textures: {}", e);
- 	            std::process::exit(1);
- 	        });			
-	    let soldier_textures = load_fighter_textures(&mut window, "soldier", sbrx_assets_path.clone())
-	        .unwrap_or_else(|e| {
-	            eprintln!("Fatal error loading soldier textures: {}", e);
-	            std::process::exit(1);
-	        });
-	    let raptor_textures = load_fighter_textures(&mut window, "raptor", sbrx_assets_path.clone())
-	        .unwrap_or_else(|e| {
-	            eprintln!("Fatal error loading raptor textures: {}", e);
-	            std::process::exit(1);
-	        });
+	    let racer_textures = load_fighter_textures(&mut texture_context, &mut assets, "racer");
+ 	    let racer2_textures = load_fighter_textures(&mut texture_context, &mut assets, "racer2");			
+	    let soldier_textures = load_fighter_textures(&mut texture_context, &mut assets, "soldier");
+	    let raptor_textures = load_fighter_textures(&mut texture_context, &mut assets, "raptor");
     let mut current_idle_texture = &racer_textures.idle;
     let mut current_fwd_texture = &racer_textures.fwd;
     let mut current_backpedal_texture = &racer_textures.backpedal;
@@ -1198,58 +745,18 @@ fn main() {
     let mut current_ranged_marker_texture = &racer_textures.ranged_marker;
     let mut current_ranged_blur_texture = &racer_textures.ranged_blur;
     let mut current_racer_texture = current_idle_texture;
-    let sbrx_bike_texture_path = sbrx_assets_path.join("player/racer/sbrx_bike.png");
- 	let sbrx_bike_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_bike_texture_path,
- 	    &texture_settings,
- 	    "sbrx_bike",
- 	);
- 	let sbrx_bike_crashed_texture = load_texture_or_exit(
- 		&mut texture_context,
- 		&sbrx_assets_path.join("player/racer/sbrx_bike_crashed.png"),
- 		&texture_settings,
- 		"sbrx_bike_crashed",
- 	);	
-    let sbrx_quad_texture_path = sbrx_assets_path.join("player/soldier/quad.png");
- 	let sbrx_quad_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_quad_texture_path,
- 	    &texture_settings,
- 	    "sbrx_quad",
- 	);
- 	let sbrx_quad_crashed_texture = load_texture_or_exit(
- 		&mut texture_context,
- 		&sbrx_assets_path.join("player/soldier/quad_crashed.png"),
- 		&texture_settings,
- 		"sbrx_quad_crashed",
- 	);	
+ 	let sbrx_bike_texture = assets.game_texture(&mut texture_context, "sbrx_bike", &texture_settings);
+ 	let sbrx_bike_crashed_texture = assets.game_texture(&mut texture_context, "sbrx_bike_crashed", &texture_settings);	
+ 	let sbrx_quad_texture = assets.game_texture(&mut texture_context, "sbrx_quad", &texture_settings);
+ 	let sbrx_quad_crashed_texture = assets.game_texture(&mut texture_context, "sbrx_quad_crashed", &texture_settings);	
 
     // Load Pulse Orb Texture
-    let pulse_orb_texture_path = sbrx_assets_path.join("projectile/pulse_orb.png");
- 	let pulse_orb_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &pulse_orb_texture_path,
- 	    &texture_settings,
- 	    "pulse_orb",
- 	);
+ 	let pulse_orb_texture = assets.game_texture(&mut texture_context, "pulse_orb", &texture_settings);
 	
     // Load Shift Function Indicator Textures
-    let set_boost_texture_path = sbrx_assets_path.join("set_boost.png");
- 	let set_boost_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &set_boost_texture_path,
- 	    &texture_settings,
- 	    "set_boost",
- 	);
+ 	let set_boost_texture = assets.game_texture(&mut texture_context, "set_boost", &texture_settings);
  
-    let set_ranged_texture_path = sbrx_assets_path.join("set_ranged.png");
- 	let set_ranged_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &set_ranged_texture_path,
- 	    &texture_settings,
- 	    "set_ranged",
- 	);
+ 	let set_ranged_texture = assets.game_texture(&mut texture_context, "set_ranged", &texture_settings);
 	
 
     // --- NEW: Load Group Icon Textures ---
@@ -1257,18 +764,8 @@ fn main() {
     let mut group_icons_selected: HashMap<FighterType, G2dTexture> = HashMap::new();
 	
     // Racer2 specific icons
-    let racer2_group_icon = load_texture_or_exit(
-        &mut texture_context,
-        &sbrx_assets_path.join("player/racer2/group_icon.png"),
-        &texture_settings,
-        "racer2_group_icon",
-    );
-    let racer2_group_icon_selected = load_texture_or_exit(
-        &mut texture_context,
-        &sbrx_assets_path.join("player/racer2/group_icon_selected.png"),
-        &texture_settings,
-        "racer2_group_icon_selected",
-    );	
+    let racer2_group_icon = assets.game_texture(&mut texture_context, "racer2_group_icon", &texture_settings);
+    let racer2_group_icon_selected = assets.game_texture(&mut texture_context, "racer2_group_icon_selected", &texture_settings);	
 
     let fighter_types_for_assets = ["racer", "soldier", "raptor"];
     let fighter_type_enums = [
@@ -1278,22 +775,9 @@ fn main() {
     ];
 
     for (i, name) in fighter_types_for_assets.iter().enumerate() {
-        let icon_path = sbrx_assets_path.join(format!("player/{}/group_icon.png", name));
-        let icon_selected_path =
-            sbrx_assets_path.join(format!("player/{}/group_icon_selected.png", name));
-
- 	    let icon_tex = load_texture_or_exit(
- 	        &mut texture_context,
- 	        &icon_path,
- 	        &texture_settings,
- 	        &format!("{}_group_icon", name),
- 	    );
- 	    let icon_selected_tex = load_texture_or_exit(
- 	        &mut texture_context,
- 	        &icon_selected_path,
- 	        &texture_settings,
- 	        &format!("{}_group_icon_selected", name),
- 	    );
+ 	    let icon_tex = assets.game_texture(&mut texture_context, &format!("{}_group_icon", name), &texture_settings);
+ 	    let icon_selected_tex =
+ 	        assets.game_texture(&mut texture_context, &format!("{}_group_icon_selected", name), &texture_settings);
 
         group_icons.insert(fighter_type_enums[i], icon_tex);
         group_icons_selected.insert(fighter_type_enums[i], icon_selected_tex);
@@ -1302,111 +786,53 @@ fn main() {
     let random_image_x = 500.0;
     let random_image_y = 700.0;
     let mantis_cpu_textures =
-        load_cpu_textures(&mut texture_context, &sbrx_assets_path, "giant_mantis");
+        load_cpu_textures(&mut texture_context, &mut assets, "giant_mantis");
     let blood_idol_cpu_textures =
-        load_cpu_textures(&mut texture_context, &sbrx_assets_path, "blood_idol");
+        load_cpu_textures(&mut texture_context, &mut assets, "blood_idol");
     let rattlesnake_cpu_textures =
-        load_cpu_textures(&mut texture_context, &sbrx_assets_path, "rattlesnake");
+        load_cpu_textures(&mut texture_context, &mut assets, "rattlesnake");
     let giant_rattlesnake_cpu_textures =
-        load_cpu_textures(&mut texture_context, &sbrx_assets_path, "giant_rattlesnake");
-    let raptor_cpu_textures = load_cpu_textures(&mut texture_context, &sbrx_assets_path, "raptor");
-    let t_rex_cpu_textures = load_cpu_textures(&mut texture_context, &sbrx_assets_path, "t-rex");
+        load_cpu_textures(&mut texture_context, &mut assets, "giant_rattlesnake");
+    let raptor_cpu_textures = load_cpu_textures(&mut texture_context, &mut assets, "raptor");
+    let t_rex_cpu_textures = load_cpu_textures(&mut texture_context, &mut assets, "t-rex");
     let void_tempest_cpu_textures =
-        load_cpu_textures(&mut texture_context, &sbrx_assets_path, "void_tempest");
+        load_cpu_textures(&mut texture_context, &mut assets, "void_tempest");
     let light_reaver_cpu_textures =
-        load_cpu_textures(&mut texture_context, &sbrx_assets_path, "light_reaver");
+        load_cpu_textures(&mut texture_context, &mut assets, "light_reaver");
     let night_reaver_cpu_textures =
-        load_cpu_textures(&mut texture_context, &sbrx_assets_path, "night_reaver");
+        load_cpu_textures(&mut texture_context, &mut assets, "night_reaver");
     let razor_fiend_cpu_textures =
-        load_cpu_textures(&mut texture_context, &sbrx_assets_path, "razor_fiend");
-
-    let grand_commander_down_texture_path = sbrx_assets_path.join("grand_commanderDown.png");
- 	let grand_commander_down_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &grand_commander_down_texture_path,
- 	    &texture_settings,
- 	    "grand_commander_down",
- 	);
-
-    let grand_commander_texture_path = sbrx_assets_path.join("grand_commander.png");
- 	let grand_commander_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &grand_commander_texture_path,
- 	    &texture_settings,
- 	    "grand_commander",
- 	);
-
-    let fighter_jet_texture_path = sbrx_assets_path.join("vehicle/fighter_jet.png");
-	let fighter_jet_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &fighter_jet_texture_path,
- 	    &texture_settings,
- 	    "fighter_jet",
- 	);
-
-    let fuel_pump_texture_path = sbrx_assets_path.join("FuelPump.png");
-	let fuel_pump_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &fuel_pump_texture_path,
- 	    &texture_settings,
- 	    "fuel_pump",
- 	);
+        load_cpu_textures(&mut texture_context, &mut assets, "razor_fiend");
+
+ 	let grand_commander_down_texture = assets.game_texture(&mut texture_context, "grand_commander_down", &texture_settings);
+
+ 	let grand_commander_texture = assets.game_texture(&mut texture_context, "grand_commander", &texture_settings);
+
+	let fighter_jet_texture = assets.game_texture(&mut texture_context, "fighter_jet", &texture_settings);
+
+	let fuel_pump_texture = assets.game_texture(&mut texture_context, "fuel_pump", &texture_settings);
 
     // Position at top-left of playable area (MIN_X, MIN_Y is horizon line)
     let fuel_pump = FuelPump::new(MIN_X + 50.0, MIN_Y + 50.0);
 
-    let crashed_fighter_jet_texture_path = sbrx_assets_path.join("vehicle/crashed_fighter_jet.png");
-	let crashed_fighter_jet_texture = load_texture_or_exit(
-	    &mut texture_context,
-	    &crashed_fighter_jet_texture_path,
-	    &texture_settings,
-	    "crashed_fighter_jet",
-	);
+	let crashed_fighter_jet_texture = assets.game_texture(&mut texture_context, "crashed_fighter_jet", &texture_settings);
 
-    let raptor_nest_texture_path = sbrx_assets_path.join("raptor_nest.png");
-	let raptor_nest_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &raptor_nest_texture_path,
- 	    &texture_settings,
- 	    "raptor_nest",
- 	);
+	let raptor_nest_texture = assets.game_texture(&mut texture_context, "raptor_nest", &texture_settings);
 	
-	let loading_screen_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("loading_screen.png"),
- 	    &texture_settings,
- 	    "loading_screen",
- 	);
+	let loading_screen_texture = assets.game_texture(&mut texture_context, "loading_screen", &texture_settings);
 	
-	let racer_lineup_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("RacerLineup.png"),
- 	    &texture_settings,
- 	    "racer_lineup",
- 	);
+	let racer_lineup_texture = assets.game_texture(&mut texture_context, "racer_lineup", &texture_settings);
 	
-	let race_spectators_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("RaceSpectators.png"),
- 	    &texture_settings,
- 	    "race_spectators",
- 	);
- 	let race_spectators2_texture = load_texture_or_exit(
- 	    &mut texture_context,
- 	    &sbrx_assets_path.join("RaceSpectators2.png"),
- 	    &texture_settings,
- 	    "race_spectators2",
- 	);
+	let race_spectators_texture = assets.game_texture(&mut texture_context, "race_spectators", &texture_settings);
+ 	let race_spectators2_texture = assets.game_texture(&mut texture_context, "race_spectators2", &texture_settings);
+	assets.report();
     let mut sbrx_bike = SbrxBike::new(line_y);	
 	
     // Use fixed spawn point for Racetrack field (0, 0)
-    let mut fighter = Fighter::new(RACETRACK_SPAWN_POINT.0, RACETRACK_SPAWN_POINT.1);	
-    sbrx_bike.x = fighter.x + 100.0;
-    sbrx_bike.y = fighter.y;	
+    sbrx_bike.x = world.fighter.x + 100.0;
+    sbrx_bike.y = world.fighter.y;	
     let mut lvl_up_state = LvlUpState::None;
 	
-    let mut cpu_racers: Vec<CpuRacer> = Vec::new();
-    cpu_racers.push(CpuRacer::new(RACETRACK_SPAWN_POINT.0, RACETRACK_SPAWN_POINT.1 - 150.0));	
 
     // Create the map of mutable stats for each fighter
     let mut fighter_stats_map: HashMap<FighterType, combat::stats::Stats> = HashMap::new();
@@ -1420,47 +846,23 @@ fn main() {
     let field_trait_manager = FieldTraitManager::new();
 
     let mut camera = Camera::new();
-    camera.x = fighter.x;
-    camera.y = fighter.y;
+    camera.x = world.fighter.x;
+    camera.y = world.fighter.y;
     let track = Track::centered(line_y, screen_height, 2020.0, 1233.0);
     let mut stars: Vec<Star> = (0..max_stars)
         .map(|_| {
             Star::new(
-                safe_gen_range(-250.0, sky_width + 250.0, "Star x"),
-                safe_gen_range(-250.0, line_y - 5.0, "Star y"),
+                safe_gen_range_cosmetic(-250.0, sky_width + 250.0, "Star x"),
+                safe_gen_range_cosmetic(-250.0, line_y - 5.0, "Star y"),
             )
         })
         .collect();
-    let mut fixed_crater = FixedCrater {
-        x: fighter.x,
-        y: fighter.y,
-        radius: 125.0,
-    };
-    let mut strike = Strike::new(fixed_crater.radius, 10.0);
-    let mut shoot = Shoot::new(10.0);
-    let mut cpu_entities: Vec<CpuEntity> = Vec::new();
-    if CPU_ENABLED {
-        if cpu_entities.len() < 10 {
-			let mut cpu = CpuEntity::new_giant_mantis(line_y);
-			let aptitude = get_field_aptitude(SbrxFieldId(0, 0));
-			cpu.max_hp *= aptitude;
-			cpu.current_hp = cpu.max_hp;
-			cpu.damage_value *= aptitude;
-			cpu.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-			cpu_entities.push(cpu);
-        }
-    }
     let mut spawned_blood_idol_scores: HashSet<u32> = HashSet::new();
     let mut spheres: Vec<Box<MovingSphere>> = Vec::new();
     let mut game_state = GameState::TitleScreen;
     let mut is_paused = false;
     let mut mouse_x = 0.0;
     let mut mouse_y = 0.0;
-    let mut task_system = TaskSystem::new();
-    let mut block_system = BlockSystem::new(20);
-    let mut combo_system = ComboSystem::new();
-    let mut wave_manager = WaveManager::new();
-    let mut combo_finisher_slash_count: u32 = 1;
     let mut block_break_animation_active = false;
     let mut key_w_pressed = false;
     let mut key_s_pressed = false;
@@ -1476,10 +878,12 @@ fn main() {
     let mut rush_timer = 0.0;
     let mut rush_active = false;
     let mut rush_cooldown = 0.0;
-    let mut frontal_strike_timer = 0.0;
-    let mut frontal_strike_angle = 0.0;
-    let mut frontal_strike_color: [f32; 4] = [0.25, 0.25, 0.25, 0.25];
-    let mut frontal_strike_is_special = false;
+    let mut frontal_strike = FrontalStrike {
+        timer: 0.0,
+        angle: 0.0,
+        color: [0.25, 0.25, 0.25, 0.25],
+        slash_count: 1,
+    };
     let mut spawn_timer = 0.0;
     let mut next_spawn = 1.0;
     let mut continuous_move_timer = 0.0;
@@ -1518,7 +922,6 @@ fn main() {
     let mut show_racetrack_soldier_prompt = false;
 
 
-    let mut survivors: HashMap<SbrxFieldId, Vec<Survivor>> = HashMap::new();
     let mut show_survivor_interaction_prompt = false;
     let mut nearby_survivor_index: Option<usize> = None;
     let mut rocketbay_dialogue_triggered = false;
@@ -1527,7 +930,6 @@ fn main() {
     let ground_asset_manager = GroundAssetManager::new();
     // Stores generated assets for each visited field to keep them consistent.
     let mut placed_ground_assets: HashMap<SbrxFieldId, Vec<PlacedAsset>> = HashMap::new();
-	let collision_barrier_manager = CollisionBarrierManager::new();
 	let mut in_jump_sequence = false;  // Tracks if player is mid-jump from '1' -> '2' -> '3'
 	
 
@@ -1544,8 +946,7 @@ fn main() {
     let mut current_bgm_sink: Option<Sink> = None;
     let mut crickets_sound_sink: Option<Sink> = None;
 	let mut ambient_track_state = AmbientTrackState::Crickets; // [L] key cycles
-    let mut sbrx_map_system = SbrxMapSystem::new("FLATLINE".to_string(), SbrxFieldId(0, 0));
-	let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+	let aptitude = get_field_aptitude(world.map_system.current_field_id);
     let mut fog_of_war = FogOfWar::new();
     let mut rattlesnakes_spawned_in_field0_score3 = false;
     let mut last_field_id_for_rattlesnake_spawn: Option<SbrxFieldId> = None;
@@ -1561,10 +962,8 @@ fn main() {
         firmament_lib::FieldId3D(-2, 5, 0);
     let mut show_fighter_jet_prompt: bool = false;
     let mut crashed_fighter_jet_sites: Vec<CrashedFighterJetSite> = Vec::new();
-    let mut damage_texts: Vec<DamageText> = Vec::new();
     let mut particles: Vec<Particle> = Vec::new();
     let mut active_visual_effects: Vec<FlickerStrikeEffectInstance> = Vec::new();
-    let mut pulse_orbs: Vec<PulseOrb> = Vec::new();
     let mut active_kinetic_strike_effects: Vec<KineticStrikeEffectInstance> = Vec::new();
 	let mut kinetic_rush_lines: Vec<KineticRushLine> = Vec::new();
     let mut raptor_nests: Vec<RaptorNest> = Vec::new();
@@ -1577,7 +976,6 @@ fn main() {
     let mut target_floor_from_prompt: Option<i32> = None;
     let mut area_entrance_x: f64 = 0.0;
     let mut area_entrance_y: f64 = 0.0;
-    let mut current_area: Option<AreaState> = None;
     let mut next_game_state_after_event: Option<GameState> = None;
     let mut t_rex_spawn_pending: bool = false;
     let mut esc_key_held = false;
@@ -1585,7 +983,9 @@ fn main() {
 	let mut enter_key_held = false;
     let mut lmb_held = false;
     let mut soldier_rapid_fire_timer = 0.0;
-    let mut melee_rapid_fire_timer = 0.0;
+    let mut input_buffer = InputBuffer::new(game_config.gameplay.input_buffer_duration);
+    // Some while in practice mode ([P] on the title screen).
+    let mut training_mode: Option<TrainingMode> = None;
     let mut shift_held = false;
     let mut shift_override_active = false;
 
@@ -1604,7 +1004,7 @@ fn main() {
 
     // --- NEW: State for persistent fighter HP and downed status ---
     let mut fighter_hp_map: HashMap<FighterType, f64> = HashMap::new();
-    fighter_hp_map.insert(FighterType::Racer, fighter.current_hp);
+    fighter_hp_map.insert(FighterType::Racer, world.fighter.current_hp);
     let mut downed_fighters: Vec<FighterType> = Vec::new();
     let mut revival_kill_score: u32 = 0;
     let mut group_animation_timers: HashMap<FighterType, f64> = HashMap::new();
@@ -1634,15 +1034,15 @@ fn main() {
     let mut task_reward_notification: Option<TaskRewardNotification> = None;
 	let mut track_notification: Option<TrackNotification> = None;
 
-    let mut racetrack_active = crate::config::ARENA_MODE;
-    if crate::config::ARENA_MODE {
+    let mut racetrack_active = game_config.arena_mode;
+    if game_config.arena_mode {
         println!("[CONFIG] Arena Mode pre-activated.");
     }
 	
 	let mut show_collision_debug = 1; // 0: DISABLE ALL, 1: BARRIERS ONLY, 2: ALL
     let mut endless_arena_mode_active = false;
 	
-    if crate::config::ARENA_MODE {
+    if game_config.arena_mode {
         endless_arena_mode_active = true;
     }	
 	
@@ -1654,17 +1054,177 @@ fn main() {
     let mut firmament_load_requested = false; // New flag to control the loading sequence
 
     println!("sbrx0.2.22 Starting game loop...");
-    while let Some(e) = window.next() {
+    while let Some(e) = replay::next_event(&mut window, &mut replay_player, &mut pending_replay_events) {
+        // Fixed timestep: each window update tick is replaced by as many FIXED_DT steps as the
+        // real time since the last tick covers. Replays already supply one recorded step per tick.
+        if e.update_args().is_some() {
+            if fixed_steps_queued > 0 {
+                fixed_steps_queued -= 1;
+            } else if replay_player.is_none() {
+                // Gamepad: polled once per window tick, so the keyboard/mouse events its buttons,
+                // triggers and left stick stand for are queued ahead of this tick's steps.
+                if game_config.gamepad.enabled {
+                    let pad_mode = if matches!(game_state, GameState::FirmamentMode(_)) {
+                        PadMode::Jet
+                    } else {
+                        PadMode::Field
+                    };
+                    if pad_mode != last_pad_mode {
+                        pending_replay_events.extend(gamepad.release_all(&key_bindings));
+                        last_pad_mode = pad_mode;
+                    }
+                    for output in gamepad.poll(pad_mode, &key_bindings, &game_config.gamepad) {
+                        match output {
+                            PadOutput::Event(pad_event) => pending_replay_events.push_back(pad_event),
+                            PadOutput::Jet(control, pressed) => {
+                                if let GameState::FirmamentMode(ref mut firmament_game) = game_state {
+                                    if pressed {
+                                        firmament_game.control_pressed(control);
+                                    } else {
+                                        firmament_game.control_released(control);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                let steps = fixed_timestep.advance();
+                for _ in 0..steps {
+                    pending_replay_events.push_back(Event::Loop(Loop::Update(UpdateArgs { dt: FIXED_DT })));
+                }
+                fixed_steps_queued = steps;
+                continue;
+            }
+            render_interpolation.capture(&world.fighter, &camera, &world.cpu_entities);
+        }
+
+        // Replay playback restores the recorded stick positions ahead of each tick.
+        if let Some(args) = e.controller_axis_args() {
+            gamepad.set_axis(args.axis, args.position);
+            continue;
+        }
+        if game_config.gamepad.enabled {
+            // Right stick aims: the cursor follows the reticle around the fighter while it is held.
+            if e.update_args().is_some() && matches!(game_state, GameState::Playing) && !is_paused {
+                let origin = world_to_screen(&camera, world.fighter.x, world.fighter.y);
+                if let Some((aim_x, aim_y)) = gamepad.aim_cursor(&game_config.gamepad, origin) {
+                    mouse_x = aim_x;
+                    mouse_y = aim_y;
+                }
+            }
+        }
+
+        // Input buffer: strike/rush/block presses that arrive just before they'd be legal wait
+        // for their first legal tick. Replays already hold those presses at that tick.
+        if matches!(game_state, GameState::Playing) && !is_paused && replay_player.is_none() {
+            let combat_wait = |input: CombatInput| {
+                let stunned = if world.block_system.is_stun_locked() {
+                    world.fighter.stun_timer.max(world.block_system.stun_lock_timer)
+                } else {
+                    world.fighter.stun_timer.max(0.0)
+                };
+                // A press that would start a cancel too early waits for the cancel window.
+                let block = &world.block_system;
+                let kinetic_wait = if block.rmb_held && block.kinetic_intake_count > 0 {
+                    cancel_wait(CombatPhase::Blocking, block.raised_for, input)
+                } else {
+                    0.0
+                };
+                match input {
+                    CombatInput::Strike if world.fighter.combat_mode != CombatMode::Ranged => {
+                        stunned.max(world.combo_system.strike_wait()).max(kinetic_wait)
+                    }
+                    CombatInput::Rush => stunned.max(rush_cooldown).max(kinetic_wait),
+                    CombatInput::Block if lmb_held => {
+                        stunned.max(cancel_wait(CombatPhase::Striking, swing_elapsed(strike_animation_timer), input))
+                    }
+                    _ => stunned,
+                }
+            };
+            let combat_input = match e.press_args().or(e.release_args()) {
+                Some(Button::Mouse(MouseButton::Left)) => Some(CombatInput::Strike),
+                Some(Button::Mouse(MouseButton::Right)) => Some(CombatInput::Block),
+                Some(Button::Keyboard(key)) if key_bindings.action_for(key) == Some(Action::Rush) => {
+                    Some(CombatInput::Rush)
+                }
+                _ => None,
+            };
+            if let (Some(input), Some(button)) = (combat_input, e.press_args()) {
+                if input_buffer.hold(input, button, combat_wait(input)) {
+                    continue;
+                }
+            }
+            if let Some(button) = e.release_args() {
+                if input_buffer.release(button) {
+                    continue;
+                }
+            }
+            if let Some(args) = e.update_args() {
+                // Released presses go ahead of any further queued update ticks.
+                for event in input_buffer.update(args.dt, combat_wait).into_iter().rev() {
+                    pending_replay_events.push_front(event);
+                }
+            }
+        } else {
+            input_buffer.clear();
+        }
+
+        // Hand last frame's game events to the systems that react to them.
+        if e.update_args().is_some() {
+            for event in world.game_events.drain() {
+                world.task_system.on_game_event(&event);
+                chatbox.on_game_event(&event);
+                world.audio.on_game_event(&event);
+                game_stats.on_game_event(&event);
+            }
+        }
+
         // Track key and mouse input globally
         if let Some(Button::Keyboard(key)) = e.press_args() {
             pressed_keys.insert(key);
-            if key == Key::G {
+            if key_bindings.action_for(key) == Some(Action::ToggleInputDisplay) {
                 key_input_display_open = !key_input_display_open;
             }
         }
         if let Some(Button::Keyboard(key)) = e.release_args() {
             pressed_keys.remove(&key);
         }
+
+        // Key binding screen: opened with [F9] while playing, and takes all key presses while open.
+        if let Some(Button::Keyboard(key)) = e.press_args() {
+            if let Some(screen) = rebind_screen.as_mut() {
+                match screen.handle_key(key) {
+                    RebindOutcome::Stay => {}
+                    RebindOutcome::Apply(new_bindings) => {
+                        if let Err(err) = new_bindings.save(&key_bindings_path) {
+                            eprintln!("[INPUT] {}", err);
+                        }
+                        key_bindings = new_bindings;
+                        rebind_screen = None;
+                        is_paused = paused_before_rebind;
+                        chatbox.add_interaction(vec![("KEY BINDINGS SAVED", MessageType::Notification)]);
+                    }
+                    RebindOutcome::Cancel => {
+                        rebind_screen = None;
+                        is_paused = paused_before_rebind;
+                        chatbox.add_interaction(vec![("KEY BINDING CHANGES DISCARDED", MessageType::Warning)]);
+                    }
+                }
+                continue;
+            }
+            if key == REBIND_SCREEN_KEY && matches!(game_state, GameState::Playing) {
+                paused_before_rebind = is_paused;
+                is_paused = true;
+                // Drop held movement so nothing keeps moving under the screen.
+                key_w_pressed = false;
+                key_s_pressed = false;
+                key_a_pressed = false;
+                key_d_pressed = false;
+                shift_held = false;
+                rebind_screen = Some(RebindScreen::new(&key_bindings));
+                continue;
+            }
+        }
         if let Some(Button::Mouse(button)) = e.press_args() {
             match button {
                 MouseButton::Left => mouse_lmb_pressed = true,
@@ -1679,6 +1239,22 @@ fn main() {
                 _ => {}
             }
         }		
+        if let Some(recorder) = replay_recorder.as_mut() {
+            if let Some(button) = e.press_args() {
+                recorder.note_press(button);
+            }
+            // Cursor events always precede the update tick, so mouse_x/y are current here.
+            if let Some(args) = e.update_args() {
+                recorder.record_frame(
+                    args.dt,
+                    &pressed_keys,
+                    (mouse_x, mouse_y),
+                    mouse_lmb_pressed,
+                    mouse_rmb_pressed,
+                    gamepad.axes(),
+                );
+            }
+        }
 		
         // This block now handles the blocking load AFTER the loading screen has been rendered.
         if firmament_load_requested {
@@ -1690,10 +1266,12 @@ fn main() {
                 &mut window,
                 Some(firmament_target_field_id),
                 firmament_boss_defeated,
-				task_system.is_task_complete("LAND ON FORT SILO"),
+				world.task_system.is_task_complete("LAND ON FORT SILO"),
+                utils::rng::gameplay_rng(),
+                utils::rng::cosmetic_rng(),
             ) {
                 Ok(mut firmament_game_instance) => {
-                    firmament_game_instance.task_bar_open = task_system.open;
+                    firmament_game_instance.task_bar_open = world.task_system.open;
                     game_state = GameState::FirmamentMode(Box::new(firmament_game_instance));
                     println!(
                         "Transitioned to FirmamentMode. Target field: {:?}",
@@ -1705,7 +1283,7 @@ fn main() {
                     key_s_pressed = false;
                     key_a_pressed = false;
                     key_d_pressed = false;
-                    block_system.deactivate();
+                    world.block_system.deactivate();
                 }
                 Err(err) => {
                     eprintln!("Failed to initialize Firmament game: {}", err);
@@ -1741,21 +1319,21 @@ fn main() {
             }
         }
         if let Some(Button::Keyboard(Key::Escape)) = e.press_args() {
-            if matches!(game_state, GameState::Playing) && fighter.stun_timer > 0.0 {
+            if matches!(game_state, GameState::Playing) && world.fighter.stun_timer > 0.0 {
                 // Ignore Escape during crash stun
             } else {
             match game_state {
                 GameState::Playing | GameState::FirmamentMode(_) => {
                     // Check for conditions that prevent pausing
                     if let GameState::Playing = game_state {
-                        if block_system.block_broken || block_system.block_fatigue || fighter.stun_timer > 0.0 {
+                        if world.block_system.block_broken || world.block_system.block_fatigue || world.fighter.stun_timer > 0.0 {
                             println!("Cannot pause while block is broken or fatigued.");
                             continue; // Skip pause logic
                         }
                     }
 
                     is_paused = !is_paused;
-                    combo_system.reset();
+                    world.combo_system.reset();
                     if is_paused {
                         println!("Game Paused.");
 						// Pause bike sounds only - BGM/ambient continues seamlessly
@@ -1793,36 +1371,50 @@ fn main() {
             chatbox.handle_key_press(key);
         }
 
+        if let Some(args) = e.resize_args() {
+            viewport.resize(args.window_size);
+        }
+
         if let Some(pos) = e.mouse_cursor_args() {
-            mouse_x = pos[0];
-            mouse_y = pos[1];
+            if replay_player.is_some() {
+                // Replays store the logical position, so they play back at any window size.
+                mouse_x = pos[0];
+                mouse_y = pos[1];
+            } else {
+                // Window coordinates -> logical canvas coordinates.
+                (mouse_x, mouse_y) = viewport.window_to_logical(pos[0], pos[1]);
+            }
         }
 
         if let Some(Button::Keyboard(key)) = e.press_args() {
-            match key {
-                Key::LShift | Key::RShift => {
+            match key_bindings.action_for(key) {
+                Some(Action::Stance) => {
                     shift_held = true;
 					
                     // Play boost.wav if raptor is active
                     if matches!(game_state, GameState::Playing) 
-                        && fighter.fighter_type == FighterType::Raptor
+                        && world.fighter.fighter_type == FighterType::Raptor
                     {
-                        audio_manager.play_sound_effect("boost").unwrap_or_else(|_e| {
+                        world.audio.play_sound_effect("boost").unwrap_or_else(|_e| {
                             //println!("[Audio] Failed to play boost sound for raptor: {}", e);
                         });
                     }					
 					
                     // Check if Racer is active and Boost mode is enabled [F]
                     if matches!(game_state, GameState::Playing)
-                        && fighter.fighter_type == FighterType::Racer
-                        && fighter.boost
+                        && world.fighter.fighter_type == FighterType::Racer
+                        && world.fighter.boost
                     {
-                        audio_manager.play_sound_effect("boost").unwrap_or_else(|_e| {
+                        world.audio.play_sound_effect("boost").unwrap_or_else(|_e| {
                             //println!("[Audio] Failed to play boost sound: {}", e);
                         });
                     }
                 }
-                Key::L => {
+                Some(Action::CycleWindowMode) => {
+                    window_mode = window_mode.next();
+                    set_window_mode(&mut window, window_mode, windowed_size);
+                }
+                Some(Action::CycleAmbient) => {
                     if is_paused {
                         // While paused, [M] cycles the same ambient track state as unpaused
                         // This affects the BGM that continues playing during pause
@@ -1831,7 +1423,7 @@ fn main() {
                                 if let Some(sink) = current_bgm_sink.take() {
                                     sink.stop();
                                 }
-                                match audio_manager.play_sfx_loop("crickets") {
+                                match world.audio.play_sfx_loop("crickets") {
                                     Ok(sink) => crickets_sound_sink = Some(sink),
                                     Err(e) => eprintln!("Failed to play crickets sound: {}", e),
                                 }
@@ -1848,25 +1440,9 @@ fn main() {
                             }
                             AmbientTrackState::Muted => {
                                 // Start the next track in the playlist
-                                let playlist = [
-								"sdtrk1", 
-								"sdtrk2", 
-								"sdtrk3", 
-								"sdtrk4", 
-								"sdtrk5",
-								"sdtrk6", 
-								"sdtrk7", 
-								"sdtrk8", 
-								"sdtrk9", 
-								"sdtrk10",
-								"sdtrk11", 
-								"sdtrk12", 
-								"sdtrk13", 
-								"sdtrk14", 
-								"sdtrk15"								
-								];
+                                let playlist = asset_manifest::SOUNDTRACK_PLAYLIST;
                                 let track = playlist[ambient_playlist_index];
-                                if let Ok(sink) = audio_manager.play_sfx_with_sink(track) {
+                                if let Ok(sink) = world.audio.play_sfx_with_sink(track) {
                                     current_bgm_sink = Some(sink);
                                 }
                                 ambient_playlist_index = (ambient_playlist_index + 1) % playlist.len();							
@@ -1880,7 +1456,7 @@ fn main() {
                                 if let Some(sink) = current_bgm_sink.take() {
                                     sink.stop();
                                 }
-                                match audio_manager.play_sfx_loop("crickets") {
+                                match world.audio.play_sfx_loop("crickets") {
                                     Ok(sink) => crickets_sound_sink = Some(sink),
                                     Err(e) => eprintln!("Failed to play crickets sound: {}", e),
                                 }
@@ -1899,25 +1475,9 @@ fn main() {
                             }
                             AmbientTrackState::Muted => {
                                 // Start the next track in the playlist
-                                let playlist = [
-								"sdtrk1", 
-								"sdtrk2", 
-								"sdtrk3", 
-								"sdtrk4", 
-								"sdtrk5",
-								"sdtrk6", 
-								"sdtrk7", 
-								"sdtrk8", 
-								"sdtrk9", 
-								"sdtrk10",
-								"sdtrk11", 
-								"sdtrk12", 
-								"sdtrk13", 
-								"sdtrk14", 
-								"sdtrk15"								
-								];
+                                let playlist = asset_manifest::SOUNDTRACK_PLAYLIST;
                                 let track = playlist[ambient_playlist_index];
-                                if let Ok(sink) = audio_manager.play_sfx_with_sink(track) {
+                                if let Ok(sink) = world.audio.play_sfx_with_sink(track) {
                                     current_bgm_sink = Some(sink);
                                 }
                                 ambient_playlist_index = (ambient_playlist_index + 1) % playlist.len();								
@@ -1926,16 +1486,12 @@ fn main() {
                         };
                     }
                 }	
-                Key::Comma => {
+                Some(Action::PrevTrack) => {
                     // Previous track in playlist
                     if let Some(sink) = current_bgm_sink.take() {
                         sink.stop();
                     }
-                    let playlist = [
-                        "sdtrk1", "sdtrk2", "sdtrk3", "sdtrk4", "sdtrk5",
-                        "sdtrk6", "sdtrk7", "sdtrk8", "sdtrk9", "sdtrk10",
-                        "sdtrk11", "sdtrk12", "sdtrk13", "sdtrk14", "sdtrk15"
-                    ];
+                    let playlist = asset_manifest::SOUNDTRACK_PLAYLIST;
                     // Go back TWO tracks (one to get current, one more for previous)
                     // because index always points to the NEXT track to play
                     if ambient_playlist_index < 2 {
@@ -1944,7 +1500,7 @@ fn main() {
                         ambient_playlist_index -= 2;
                     }
                     let track = playlist[ambient_playlist_index];
-                    if let Ok(sink) = audio_manager.play_sfx_with_sink(track) {
+                    if let Ok(sink) = world.audio.play_sfx_with_sink(track) {
                         current_bgm_sink = Some(sink);
 						track_notification = Some(TrackNotification {
 						    track_name: track.to_string(),
@@ -1961,19 +1517,15 @@ fn main() {
                         ambient_track_state = AmbientTrackState::Background;
                     }
                 }
-                Key::Period => {
+                Some(Action::NextTrack) => {
                     // Next track in playlist
                     if let Some(sink) = current_bgm_sink.take() {
                         sink.stop();
                     }
-                    let playlist = [
-                        "sdtrk1", "sdtrk2", "sdtrk3", "sdtrk4", "sdtrk5",
-                        "sdtrk6", "sdtrk7", "sdtrk8", "sdtrk9", "sdtrk10",
-                        "sdtrk11", "sdtrk12", "sdtrk13", "sdtrk14", "sdtrk15"
-                    ];
+                    let playlist = asset_manifest::SOUNDTRACK_PLAYLIST;
 					// Index already points to the next track, so just use it
                     let track = playlist[ambient_playlist_index];
-                    if let Ok(sink) = audio_manager.play_sfx_with_sink(track) {
+                    if let Ok(sink) = world.audio.play_sfx_with_sink(track) {
                         current_bgm_sink = Some(sink);
 					    track_notification = Some(TrackNotification {
 						    track_name: track.to_string(),
@@ -2003,21 +1555,51 @@ fn main() {
         }
 
         if let Some(Button::Keyboard(key)) = e.release_args() {
-            match key {
-                Key::LShift | Key::RShift => shift_held = false,
-                _ => {}
+            if let Some(Action::Stance) = key_bindings.action_for(key) {
+                shift_held = false;
             }
         }
 
         match game_state {
             GameState::TitleScreen => {
                 if !title_sound_played {
-                    if let Ok(sink) = audio_manager.play_sfx_with_sink("title") {
+                    if let Ok(sink) = world.audio.play_sfx_with_sink("title") {
                         title_sound_sink = Some(sink);
                     }
                     title_sound_played = true;
                 }
-                if e.press_args().is_some() {
+                // [L] loads the most recent save, [1]-[3] load a manual slot, [P] starts practice mode,
+                // anything else starts a new game.
+                let mut loaded_save: Option<save::SaveData> = None;
+                let mut load_failed = false;
+                let mut start_training = false;
+                if let Some(button) = e.press_args() {
+                    start_training = button == Button::Keyboard(Key::P);
+                    let load_slot = match button {
+                        Button::Keyboard(Key::L) => Some(save::latest_slot(&saves_dir)),
+                        Button::Keyboard(Key::D1) => Some(Some(1)),
+                        Button::Keyboard(Key::D2) => Some(Some(2)),
+                        Button::Keyboard(Key::D3) => Some(Some(3)),
+                        _ => None,
+                    };
+                    match load_slot {
+                        Some(Some(slot)) => match save::read_slot(&saves_dir, slot) {
+                            Ok(data) => loaded_save = Some(data),
+                            Err(err) => {
+                                eprintln!("[SAVE] {}", err);
+                                let message = format!("COULD NOT LOAD SAVE SLOT {}", slot);
+                                chatbox.add_interaction(vec![(message.as_str(), MessageType::Warning)]);
+                                load_failed = true;
+                            }
+                        },
+                        Some(None) => {
+                            chatbox.add_interaction(vec![("NO SAVED GAME FOUND", MessageType::Warning)]);
+                            load_failed = true;
+                        }
+                        None => {}
+                    }
+                }
+                if e.press_args().is_some() && !load_failed {
                     // Stop title sound when starting the game
                     if let Some(sink) = title_sound_sink.take() {
                         sink.stop();
@@ -2028,15 +1610,16 @@ fn main() {
                     fog_of_war = FogOfWar::new();
                     placed_ground_assets.clear();
 
-                    task_system.active = true;
+                    world.task_system.active = true;
+                    training_mode = None;
 					
                     // Ensure config-based Arena Mode persists through start-press
-                    if crate::config::ARENA_MODE {
+                    if game_config.arena_mode {
                         racetrack_active = true;
                         endless_arena_mode_active = true;
                     }					
 					
-                    last_field_id_for_rattlesnake_spawn = Some(sbrx_map_system.current_field_id);
+                    last_field_id_for_rattlesnake_spawn = Some(world.map_system.current_field_id);
                     spawned_giant_rattlesnake_scores.clear();
                     spawned_blood_idol_scores.clear();
                     fighter_jet_current_sbrx_location = SbrxFieldId(-2, 5);
@@ -2047,15 +1630,70 @@ fn main() {
                     crashed_fighter_jet_sites.clear();
                     println!("Transitioning to sbrx0.2.22 Playing state.");
                     has_blood_idol_fog_spawned_once = false;
-                    check_and_display_demonic_presence(
-                        &sbrx_map_system.current_field_id,
-                        &cpu_entities,
-                        &mut chatbox,
-                        &fog_of_war,
-                    );
+
+                    if let Some(data) = loaded_save.take() {
+                        world.map_system.current_field_id = data.field;
+                        data.apply_fighter_progress(
+                            &mut world.fighter,
+                            &mut fighter_stats_map,
+                            &mut base_fighter_stats_map,
+                        );
+                        data.apply_task_progress(&mut world.task_system);
+                        data.apply_fog(&mut fog_of_war);
+                        completed_bunker_waves = data.completed_bunker_floors.clone();
+                        soldier_has_joined = data.soldier_has_joined;
+                        raptor_has_joined = data.raptor_has_joined;
+
+                        world.fixed_crater.radius = world.fighter.switch_fighter_type(data.fighter_type);
+                        world.fighter.stats = fighter_stats_map
+                            .get(&data.fighter_type)
+                            .copied()
+                            .unwrap_or(world.fighter.stats);
+                        world.fighter.max_hp = world.fighter.stats.defense.hp;
+                        world.fighter.melee_damage = world.fighter.stats.attack.melee_damage;
+                        world.fighter.ranged_damage = world.fighter.stats.attack.ranged_damage;
+                        world.fighter.run_speed = world.fighter.stats.speed.run_speed;
+                        world.fighter.current_hp = world.fighter.max_hp;
+                        for (ft, stats) in &base_fighter_stats_map {
+                            fighter_hp_map.insert(*ft, stats.defense.hp);
+                        }
+
+                        let points_to_spend = world.fighter
+                            .stat_points_to_spend
+                            .get(&world.fighter.fighter_type)
+                            .copied()
+                            .unwrap_or(0);
+                        lvl_up_state = if points_to_spend > 0 {
+                            LvlUpState::PendingTab { fighter_type: world.fighter.fighter_type }
+                        } else {
+                            LvlUpState::None
+                        };
+
+                        last_field_id_for_rattlesnake_spawn = Some(data.field);
+                        last_field_entry_point = (world.fighter.x, world.fighter.y);
+                        println!(
+                            "[SAVE] Resuming at field {:?} as {:?}",
+                            data.field, data.fighter_type
+                        );
+                    }
+                    if start_training {
+                        // Practice mode: no tasks, and only the dummy on the field.
+                        world.task_system.active = false;
+                        world.cpu_entities.clear();
+                        training_mode = Some(TrainingMode::new());
+                        println!("[TRAINING] Practice mode started.");
+                    } else {
+                        check_and_display_demonic_presence(
+                            &world.map_system.current_field_id,
+                            &world.cpu_entities,
+                            &mut chatbox,
+                            &fog_of_war,
+                        );
+                    }
                 }
                 if let Some(_) = e.render_args() {
                     window.draw_2d(&e, |c, g, device| {
+                        let c = viewport.apply(c);
                         clear([0.0, 0.0, 0.0, 1.0], g);
                         image(
                             &title_screen_texture,
@@ -2083,24 +1721,45 @@ fn main() {
                             )
                             .ok();
 
-                        chatbox.draw(c, g, &mut glyphs); // Draw chatbox on title screen if open
-                        glyphs.factory.encoder.flush(device);
-                    });
-                }
-            }
-            GameState::Playing => {
-                if let Some(args) = e.update_args() {
-                    let dt = args.dt;
-                    let tex_set = match fighter.fighter_type {
-                        FighterType::Racer => if fighter.racer_variant == 1 { &racer2_textures } else { &racer_textures },
-                        FighterType::Soldier => &soldier_textures,
-                        FighterType::Raptor => &raptor_textures,
-                    };					
+                        if save::latest_slot(&saves_dir).is_some() {
+                            text::Text::new_color(text_color, font_size)
+                                .draw(
+                                    "[L] CONTINUE   [1-3] LOAD SLOT",
+                                    &mut glyphs,
+                                    &c.draw_state,
+                                    c.transform.trans(text_x, text_y - 30.0),
+                                    g,
+                                )
+                                .ok();
+                        }
+                        text::Text::new_color(text_color, font_size)
+                            .draw(
+                                "[P] PRACTICE",
+                                &mut glyphs,
+                                &c.draw_state,
+                                c.transform.trans(screen_width - 160.0, text_y),
+                                g,
+                            )
+                            .ok();
+
+                        chatbox.draw(c, g, &mut glyphs); // Draw chatbox on title screen if open
+                        glyphs.factory.encoder.flush(device);
+                    });
+                }
+            }
+            GameState::Playing => {
+                if let Some(args) = e.update_args() {
+                    let dt = args.dt;
+                    let tex_set = match world.fighter.fighter_type {
+                        FighterType::Racer => if world.fighter.racer_variant == 1 { &racer2_textures } else { &racer_textures },
+                        FighterType::Soldier => &soldier_textures,
+                        FighterType::Raptor => &raptor_textures,
+                    };					
 					sbrx_bike.update(dt);
 
                     if !is_paused {
                         update_current_textures(
-                            &fighter,
+                            &world.fighter,
                             tex_set,
                             &mut current_idle_texture,
                             &mut current_fwd_texture,
@@ -2117,20 +1776,20 @@ fn main() {
 						
                         // --- DEMO END ZONE & ARENA MODE ---
                         if racetrack_active
-                            && sbrx_map_system.current_field_id == SbrxFieldId(0, 0)
+                            && world.map_system.current_field_id == SbrxFieldId(0, 0)
                             && !endless_arena_mode_active
                         {
-                            if fighter.x >= DEMO_END_ZONE[0]
-                                && fighter.x <= DEMO_END_ZONE[1]
-                                && fighter.y >= DEMO_END_ZONE[2]
-                                && fighter.y <= DEMO_END_ZONE[3]
+                            if world.fighter.x >= DEMO_END_ZONE[0]
+                                && world.fighter.x <= DEMO_END_ZONE[1]
+                                && world.fighter.y >= DEMO_END_ZONE[2]
+                                && world.fighter.y <= DEMO_END_ZONE[3]
                             {
                                 endless_arena_mode_active = true;
                                 endless_arena_timer = 0.0; // Reset timer on start
                                 endless_arena_stage = 1; // Reset stage
 								arena_kill_count = 0;    // Ensure score is 0
 								last_arena_milestone = 0; // Reset milestones to allow buffs to trigger again								
-                                task_system.mark_proceed_to_starting_line_complete();
+                                world.task_system.mark_proceed_to_starting_line_complete();
                                 chatbox.add_interaction(vec![(
                                     "RACING MODE IN DEVELOPMENT. INITIATING ENDLESS ARENA MODE.",
                                     MessageType::Warning,
@@ -2139,27 +1798,17 @@ fn main() {
                             }
                         }
 						
-                        // Update bleed effect for player
-                        if let Some(ref mut bleed) = fighter.bleed_effect {
-                            bleed.tick_timer += dt;
-                            if bleed.tick_timer >= bleed.tick_rate {
-                                bleed.tick_timer = 0.0;
-                                let damage = bleed.damage_per_tick.min(bleed.remaining_damage);
-                                fighter.current_hp -= damage;
-                                bleed.remaining_damage -= damage;
-
-                                damage_texts.push(DamageText {
-                                    text: format!("{:.0}", damage),
-                                    x: fighter.x,
-                                    y: fighter.y - 70.0,
-                                    color: [1.0, 0.0, 0.0, 1.0], // Red
-                                    lifetime: 0.25,
-                                });
-
-                                if bleed.remaining_damage <= 0.0 {
-                                    fighter.bleed_effect = None;
-                                }
-                            }
+                        // Tick the player's status effects (bleed damage)
+                        let status_damage = world.fighter.status_effects.update(dt);
+                        if status_damage > 0.0 {
+                            let hit = damage::resolve(
+                                &damage::Attack::tick(status_damage, DamageType::Slash),
+                                &damage::Defense::fighter(&world.fighter),
+                            );
+                            world.fighter.current_hp -= hit.amount;
+                            let mut text = hit.text(world.fighter.x, world.fighter.y - 70.0);
+                            text.color = [1.0, 0.0, 0.0, 1.0]; // Red
+                            world.damage_texts.push(text);
                         }						
 						
 						// Update background_track notification lifetime
@@ -2190,20 +1839,20 @@ fn main() {
                                 )]);
                                 //println!("[ARENA MODE] Reached 20 seconds. Stage 3 activated. Buffing all enemies.");
                                 // Buff all existing enemies
-                                for cpu in &mut cpu_entities {
+                                for cpu in &mut world.cpu_entities {
                                     cpu.speed *= 2.0;
                                     cpu.damage_value *= 2.0;
                                 }
                             }
 
-                            if cpu_entities.len() < 10 {
-                                cpu_entities.push(spawn_random_cpu(line_y, endless_arena_stage, endless_arena_timer));
+                            if world.cpu_entities.len() < 10 {
+                                world.cpu_entities.push(spawn_random_cpu(line_y, endless_arena_stage, endless_arena_timer));
                             }
                         }
 
                         // --- FIELD TRAIT APPLICATION ---
                         let active_traits = field_trait_manager
-                            .get_active_traits_for_field(&sbrx_map_system.current_field_id);
+                            .get_active_traits_for_field(&world.map_system.current_field_id);
 
                         let all_fighter_types = [
                             FighterType::Racer,
@@ -2256,23 +1905,23 @@ fn main() {
                                     println!("Applied field trait to {:?}", ft);
 
                                     // If this is the active fighter, update their stats immediately.
-                                    if fighter.fighter_type == *ft {
-                                        fighter.stats = *stats_to_modify;
-										let old_max_hp = fighter.max_hp;
-                                        fighter.max_hp = stats_to_modify.defense.hp;
+                                    if world.fighter.fighter_type == *ft {
+                                        world.fighter.stats = *stats_to_modify;
+										let old_max_hp = world.fighter.max_hp;
+                                        world.fighter.max_hp = stats_to_modify.defense.hp;
                                         // Scale current HP proportionally, or set to new max if was at full health
-                                        if old_max_hp > 0.0 && fighter.current_hp >= old_max_hp {
-                                            fighter.current_hp = fighter.max_hp; // Was at full, stay at full
+                                        if old_max_hp > 0.0 && world.fighter.current_hp >= old_max_hp {
+                                            world.fighter.current_hp = world.fighter.max_hp; // Was at full, stay at full
                                         } else if old_max_hp > 0.0 {
                                             // Scale proportionally
-                                            fighter.current_hp = (fighter.current_hp / old_max_hp) * fighter.max_hp;
+                                            world.fighter.current_hp = (world.fighter.current_hp / old_max_hp) * world.fighter.max_hp;
                                         }
-                                        fighter.melee_damage = stats_to_modify.attack.melee_damage;
-                                        fighter.ranged_damage =
+                                        world.fighter.melee_damage = stats_to_modify.attack.melee_damage;
+                                        world.fighter.ranged_damage =
                                             stats_to_modify.attack.ranged_damage;
-                                        fighter.run_speed = stats_to_modify.speed.run_speed;
+                                        world.fighter.run_speed = stats_to_modify.speed.run_speed;
                                         // Update fighter_hp_map with new HP
-                                        fighter_hp_map.insert(*ft, fighter.current_hp);										
+                                        fighter_hp_map.insert(*ft, world.fighter.current_hp);										
                                     } else {
                                         // For non-selected fighters, update their HP in the map
                                         let old_max_hp = original_stats.defense.hp;
@@ -2300,15 +1949,15 @@ fn main() {
                                     println!("Reverted field trait from {:?}", ft);
 
                                     // If this is the active fighter, update their stats immediately.
-                                    if fighter.fighter_type == *ft {
-                                        fighter.stats = *stats_to_modify;
-                                        fighter.max_hp = stats_to_modify.defense.hp;
-                                        fighter.current_hp = fighter.current_hp.min(fighter.max_hp);
-                                        fighter.melee_damage = stats_to_modify.attack.melee_damage;
-                                        fighter.ranged_damage =
+                                    if world.fighter.fighter_type == *ft {
+                                        world.fighter.stats = *stats_to_modify;
+                                        world.fighter.max_hp = stats_to_modify.defense.hp;
+                                        world.fighter.current_hp = world.fighter.current_hp.min(world.fighter.max_hp);
+                                        world.fighter.melee_damage = stats_to_modify.attack.melee_damage;
+                                        world.fighter.ranged_damage =
                                             stats_to_modify.attack.ranged_damage;
-                                        fighter.run_speed = stats_to_modify.speed.run_speed;									
-                                        fighter_hp_map.insert(*ft, fighter.current_hp);                                    
+                                        world.fighter.run_speed = stats_to_modify.speed.run_speed;									
+                                        fighter_hp_map.insert(*ft, world.fighter.current_hp);                                    
 										} else {
 											// For non-selected fighters, cap their HP to the new (lower) max
 											let new_max_hp = stats_to_modify.defense.hp;
@@ -2334,14 +1983,15 @@ fn main() {
                     }
 					
                     // Safety: Cap visual effects and helper vectors
-                    if damage_texts.len() > 100 { damage_texts.remove(0); }
+                    if world.damage_texts.len() > 100 { world.damage_texts.remove(0); }
                     if active_visual_effects.len() > 100 { active_visual_effects.remove(0); }
-                    if pulse_orbs.len() > 100 { pulse_orbs.remove(0); }
+                    if world.pulse_orbs.len() > 100 { world.pulse_orbs.remove(0); }
+                    if world.skill_effects.len() > 50 { world.skill_effects.remove(0); }
                     if active_kinetic_strike_effects.len() > 50 { active_kinetic_strike_effects.remove(0); }
                     if kinetic_rush_lines.len() > 50 { kinetic_rush_lines.remove(0); }					
 
-                    if frontal_strike_timer > 0.0 {
-                        frontal_strike_timer -= dt;
+                    if frontal_strike.timer > 0.0 {
+                        frontal_strike.timer -= dt;
                     }
 
                     if let Some(notification) = &mut task_reward_notification {
@@ -2353,32 +2003,32 @@ fn main() {
 
                     chatbox.update(dt, enter_key_held);
 
-                    if shift_held && fighter.fighter_type != FighterType::Raptor {
-                        if fighter.fighter_type == FighterType::Racer && fighter.boost {
+                    if shift_held && world.fighter.fighter_type != FighterType::Raptor {
+                        if world.fighter.fighter_type == FighterType::Racer && world.fighter.boost {
                             // boost mode active, do not enter Ranged mode
                         } else {
-                            if fighter.combat_mode == CombatMode::CloseCombat && !shift_override_active
+                            if world.fighter.combat_mode == CombatMode::CloseCombat && !shift_override_active
                             {
-                                fighter.combat_mode = CombatMode::Ranged;
+                                world.fighter.combat_mode = CombatMode::Ranged;
                                 shift_override_active = true;
-                                audio_manager.play_sound_effect("aim").ok();
+                                world.audio.play_sound_effect("aim").ok();
                             }
 						}
-                    } else if shift_held && fighter.fighter_type == FighterType::Raptor {
+                    } else if shift_held && world.fighter.fighter_type == FighterType::Raptor {
                         // raptor Shift-Flight Logic (Toggle ON)
-                        if fighter.state == RacerState::OnFoot
+                        if world.fighter.state == RacerState::OnFoot
                             && !shift_override_active
-                            && fighter.fuel > 0.0
-							&& !block_system.active
-							&& !block_system.rmb_held
+                            && world.fighter.resources.can_afford(ResourceAction::Ride, dt)
+							&& !world.block_system.active
+							&& !world.block_system.rmb_held
                         {
-                            fighter.state = RacerState::OnBike;
+                            world.fighter.state = RacerState::OnBike;
                             shift_override_active = true;
 
                             // Update textures for flight mode
                             let tex_set = &raptor_textures;
                             update_current_textures(
-                                &fighter,
+                                &world.fighter,
                                 tex_set,
                                 &mut current_idle_texture,
                                 &mut current_fwd_texture,
@@ -2394,7 +2044,7 @@ fn main() {
                             );
 							// Update to appropriate texture based on current state
 							if strike_animation_timer <= 0.0 {
-								if block_system.active || block_system.rmb_held {
+								if world.block_system.active || world.block_system.rmb_held {
 									current_racer_texture = current_block_texture;
 								} else {
 									current_racer_texture = current_idle_texture;
@@ -2403,15 +2053,15 @@ fn main() {
                         }
                     } else {
                         if shift_override_active {
-                            if fighter.fighter_type == FighterType::Raptor {
+                            if world.fighter.fighter_type == FighterType::Raptor {
                                 // raptor Shift-Flight Logic (Toggle OFF)
-                                if fighter.state == RacerState::OnBike {
-                                    fighter.state = RacerState::OnFoot;
+                                if world.fighter.state == RacerState::OnBike {
+                                    world.fighter.state = RacerState::OnFoot;
 
                                     // Update textures for foot mode
                                     let tex_set = &raptor_textures;
                                     update_current_textures(
-                                        &fighter,
+                                        &world.fighter,
                                         tex_set,
                                         &mut current_idle_texture,
                                         &mut current_fwd_texture,
@@ -2431,7 +2081,7 @@ fn main() {
                                 }
                             } else {
                                 // Standard Ranged Mode logic
-                                fighter.combat_mode = CombatMode::CloseCombat;
+                                world.fighter.combat_mode = CombatMode::CloseCombat;
                             }
                             shift_override_active = false;
                         }
@@ -2446,6 +2096,11 @@ fn main() {
                         }
                     }
 
+                    for effect in &mut world.skill_effects {
+                        effect.lifetime -= dt;
+                    }
+                    world.skill_effects.retain(SkillEffect::is_active);
+
                     for effect in &mut active_kinetic_strike_effects {
                         effect.lifetime -= dt;
                     }
@@ -2462,15 +2117,16 @@ fn main() {
                         if kinetic_rush_lines[i].lifetime <= 0.0 {
                             kinetic_rush_lines.swap_remove(i);
                         }
-                    }				
+                    }
+                    hitbox::update_debug(dt);				
 
                     if bunker_entry_choice == BunkerEntryChoice::AwaitingInput {
                         let mut is_in_range = false;
                         if let Some(&(bunker_x, bunker_y)) = fort_silo_bunkers.first() {
-                            let dx = fighter.x - bunker_x;
-                            let dy = fighter.y - bunker_y;
+                            let dx = world.fighter.x - bunker_x;
+                            let dy = world.fighter.y - bunker_y;
                             let distance_sq = dx * dx + dy * dy;
-                            if distance_sq < RAPTOR_NEST_INTERACTION_DISTANCE.powi(2) {
+                            if distance_sq < game_config.gameplay.raptor_nest_interaction_distance.powi(2) {
                                 is_in_range = true;
                             }
                         }
@@ -2479,113 +2135,6 @@ fn main() {
                         }
                     }
 
-                    // End wave progress if not in a bunker.
-                    let is_in_bunker = if let Some(area) = &current_area {
-                        area.area_type == AreaType::Bunker
-                    } else {
-                        false
-                    };
-                    if !is_in_bunker && wave_manager.is_active() {
-                        wave_manager.reset();
-                    }
-
-                    if wave_manager.is_active() && !is_paused {
-                        let was_active = wave_manager.is_active();
-
-                        if wave_manager.update(dt) {
-                            // Time to spawn an enemy for the wave
-                            if cpu_entities.len() < 10 {
-                                // Determine spawn variant based on current floor
-                                let current_floor = if let Some(area) = &current_area {
-                                    area.floor
-                                } else {
-                                    1 // Default if no area context (shouldn't happen during active wave)
-                                };
-                                
-                                let spawn_table = WaveManager::get_spawn_table_for_floor(current_floor);
-                                let variant = WaveManager::pick_random_variant(&spawn_table);
-                                
-                                let x = safe_gen_range(BUNKER_ORIGIN_X, BUNKER_ORIGIN_X + BUNKER_WIDTH, "wave spawn x");
-                                let y = safe_gen_range(BUNKER_ORIGIN_Y, BUNKER_ORIGIN_Y + BUNKER_HEIGHT, "wave spawn y");
-                                
-                                // Factory function logic
-                                let mut new_cpu = match variant {
-                                    CpuVariant::NightReaver => CpuEntity::new_night_reaver(x, y),
-                                    CpuVariant::LightReaver => CpuEntity::new_light_reaver(x, y),
-                                    CpuVariant::VoidTempest => {
-                                        // VoidTempest uses a different constructor signature, assume base stats for now or define a position-based one
-                                        // The original constructor takes (line_y, hp, speed)
-                                        // We'll adapt it or create a temporary instance. 
-                                        // Better: Update CpuEntity to have a consistent constructor or handle special cases.
-                                        // For now, using existing pattern for VoidTempest which usually overrides position.
-                                        // Let's use `new_void_tempest` but override x/y immediately.
-                                        let mut vt = CpuEntity::new_void_tempest(line_y, 250.0, 150.0);
-                                        vt.x = x;
-                                        vt.y = y;
-                                        vt
-                                    },
-                                    CpuVariant::RazorFiend => CpuEntity::new_razor_fiend(x, y),
-                                    // Fallback for types not usually in bunker waves but safe to handle
-                                    _ => CpuEntity::new_night_reaver(x, y), 
-                                };
-
-                                wave_manager.apply_modifiers_to_new_cpu(&mut new_cpu);
-								let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
-								new_cpu.max_hp *= aptitude;
-								new_cpu.current_hp = new_cpu.max_hp;
-								new_cpu.damage_value *= aptitude;
-								new_cpu.speed *= 1.0 + (aptitude - 1.0) * 0.2;								
-                                cpu_entities.push(new_cpu);
-                                wave_manager.notify_enemy_spawned(); // Notify manager a spawn occurred
-                            }
-                        }
-
-                        // Check for transition to FRENZY and apply buff
-                        if wave_manager.state == crate::mechanics::wave::WaveState::Frenzy
-                            && !wave_manager.enrage_buff_applied
-                        {
-                            chatbox.add_interaction(vec![(
-                                "ENEMY FEROCITY INCREASED",
-                                MessageType::Notification,
-                            )]);
-                            for cpu in cpu_entities.iter_mut() {
-                                cpu.damage_value *= 2.0;
-                                cpu.speed *= 2.0;
-                            }
-                            wave_manager.enrage_buff_applied = true;
-                        }
-
-                        // Check if the encounter just ended in this frame
-                        if was_active && !wave_manager.is_active() {
-                            if let Some(area) = current_area.as_mut() {
-                                area.waves_active = false;
-                                // Mark this floor's waves as completed
-                                completed_bunker_waves.insert(area.floor);
-                                println!(
-                                    "[WAVE SYSTEM] Bunker floor {} marked as complete.",
-                                    area.floor
-                                );
-                            }
-                            chatbox.add_interaction(vec![(
-                                "ALL WAVES CLEARED. EXITS UNLOCKED.",
-                                MessageType::Notification,
-                            )]);
-                            // Check if all bunker waves have been cleared for the first time
-                            if !bunker_waves_fully_completed {
-                                let required_floors: HashSet<i32> =
-                                    [1, 0, -1, -2].iter().cloned().collect();
-                                if required_floors.is_subset(&completed_bunker_waves) {
-                                    bunker_waves_fully_completed = true;
-                                    //println!("[WAVE SYSTEM] All bunker wave encounters completed for the first time. Lockdowns will now be disabled on future visits.");
-                                    chatbox.add_interaction(vec![(
-                                        "BUNKER SECURED. ALL FLOORS ACCESSIBLE.",
-                                        MessageType::Notification,
-                                    )]);
-                                }
-                            }
-                        }
-                    }
-
                     // NEW: Update group icon animation timers
                     let mut finished_anims = Vec::new();
                     for (ft, timer) in group_animation_timers.iter_mut() {
@@ -2598,31 +2147,30 @@ fn main() {
                         group_animation_timers.remove(&ft);
                     }
 
-                    combo_system.update(dt);
-                    // Handle fighter-specific state progression when timer expires
-                    if combo_system.timer <= 0.0 && combo_system.is_in_rest_period {
-                        combo_system.progress_state_for_fighter(fighter.fighter_type);
+                    world.combo_system.update(dt, world.fighter.fighter_type);
+                    if let Some(training) = training_mode.as_mut() {
+                        training.update(dt, &mut world.cpu_entities, &world.combo_system, world.fighter.x, world.fighter.y, line_y);
                     }
 
                     // Handle fighter-specific combo state progression
-                    if combo_system.timer > 0.0 {
+                    if world.combo_system.timer > 0.0 {
                         // Check if we need to handle fighter-specific progression
                         // This will be called when the timer expires and state should progress
                     }
 
                     // NEW: Combined rapid fire logic for all fighters
                     if lmb_held {
-                        if fighter.is_reloading {
+                        if world.fighter.is_reloading() {
                             // Prevent action if reloading
                         } else {
                             let (wmx, wmy) = screen_to_world(&camera, mouse_x, mouse_y);
-                            let dx = wmx - fixed_crater.x;
-                            let dy = wmy - fixed_crater.y;
-                            let hr = fixed_crater.radius;
-                            let vr = fixed_crater.radius * 0.75;
+                            let dx = wmx - world.fixed_crater.x;
+                            let dy = wmy - world.fixed_crater.y;
+                            let hr = world.fixed_crater.radius;
+                            let vr = world.fixed_crater.radius * 0.75;
                             let dsq = (dx * dx) / (hr * hr) + (dy * dy) / (vr * vr);
 
-                            let perform_melee = match fighter.combat_mode {
+                            let perform_melee = match world.fighter.combat_mode {
                                 CombatMode::CloseCombat => true,
                                 CombatMode::Ranged => false,
                                 CombatMode::Balanced => dsq <= 1.0,
@@ -2630,84 +2178,43 @@ fn main() {
 
                             if perform_melee {
                                 // Melee logic for ALL fighters
-                                melee_rapid_fire_timer -= dt;
-                                if melee_rapid_fire_timer <= 0.0 {
-                                    handle_melee_strike(
-                                        &mut fighter,
-                                        &camera,
-                                        mouse_x,
-                                        mouse_y,
-                                        &mut fixed_crater,
-                                        &mut combo_system,
-                                        &mut strike,
-                                        &audio_manager,
-										&mut chatbox,
+                                world.melee_rapid_fire_timer -= dt;
+                                if world.melee_rapid_fire_timer <= 0.0 {
+                                    if handle_melee_strike(
+                                        &mut world,
+                                        screen_to_world(&camera, mouse_x, mouse_y),
+                                        is_paused,
+                                        &mut frontal_strike,
                                         &current_strike_textures,
                                         &mut current_racer_texture,
                                         &mut strike_frame,
-                                        &mut strike_animation_timer,
-                                        &mut movement_active,
-                                        &mut backpedal_active,
-                                        &mut frontal_strike_timer,
-                                        &mut frontal_strike_angle,
-                                        &mut frontal_strike_color,
-                                        &mut frontal_strike_is_special,
-                                        &mut combo_finisher_slash_count,
-                                        &mut cpu_entities,
-										&mut cpu_racers,
-                                        &mut damage_texts,
-										is_paused,
-										sbrx_map_system.current_field_id,
-                                    );
-                                    melee_rapid_fire_timer = MELEE_RAPID_FIRE_RATE;
+                                    ) {
+                                        strike_animation_timer = 0.25;
+                                        movement_active = false;
+                                        backpedal_active = false;
+                                    }
+                                    world.melee_rapid_fire_timer =
+                                        MELEE_RAPID_FIRE_RATE / world.fighter.status_effects.attack_speed_multiplier();
                                 }
                             } else {
                                 // Ranged logic, currently only for Soldier
-                                if fighter.fighter_type == FighterType::Soldier {
+                                if world.fighter.fighter_type == FighterType::Soldier {
                                     soldier_rapid_fire_timer -= dt;
                                     if soldier_rapid_fire_timer <= 0.0 {
-                                        if fighter.ammo > 0 {
-                                            audio_manager.play_sound_effect("firearm").ok();
-
-                                            let facing_left = wmx < fighter.x;
-                                            let (offset_x, offset_y) = match fighter.state {
-                                                RacerState::OnFoot => {
-                                                    let x = if facing_left { -35.0 } else { 35.0 };
-                                                    (x, -15.0)
-                                                }
-                                                RacerState::OnBike => {
-                                                    let x = if facing_left { -60.0 } else { 60.0 };
-                                                    (x, -40.0)
-                                                }
-                                            };
-									let _start_x = fighter.x + offset_x;
-									let _start_y = fighter.y + offset_y;
-
-                                    let mut target_x = wmx;
-                                    let mut target_y = wmy;
-                                    let dx = wmx - fighter.x;
-                                    let dy = wmy - fighter.y;
-                                    let dist = (dx * dx + dy * dy).sqrt();
-                                    let max_range = 800.0;
-                                    if dist > max_range {
-                                        target_x = fighter.x + (dx / dist) * max_range;
-                                        target_y = fighter.y + (dy / dist) * max_range;
-                                    }
-                                    shoot.trigger(fighter.x, fighter.y, target_x, target_y);
-
+                                        if world.fire_shot(wmx, wmy, SOLDIER_SHOT_RANGE) {
                                             current_racer_texture = current_ranged_texture;
                                             strike_animation_timer = 0.25;
                                             movement_active = false;
                                             backpedal_active = false;
-                                            soldier_rapid_fire_timer = SOLDIER_RAPID_FIRE_RATE;
-                                            fighter.ammo -= 1;
-                                            if fighter.ammo == 0 {
-                                                fighter.trigger_reload(&audio_manager);
+                                            soldier_rapid_fire_timer =
+                                                SOLDIER_RAPID_FIRE_RATE / world.fighter.status_effects.attack_speed_multiplier();
+                                            if !world.fighter.resources.can_afford(ResourceAction::Shoot, 1.0) {
+                                                world.fighter.trigger_reload(&world.audio);
                                                 lmb_held = false; // Force input release on reload
                                             }
                                         } else {
-                                            // Should generally be caught by ammo check but safe fallback
-                                            fighter.trigger_reload(&audio_manager);
+                                            // Should generally be caught by the ammo check after the last shot but safe fallback
+                                            world.fighter.trigger_reload(&world.audio);
                                             lmb_held = false;
                                         }
                                     }
@@ -2716,38 +2223,24 @@ fn main() {
                         }
                     }
 
-                    block_system.update(dt, game_time);
-                    strike.update(dt);
-                    shoot.update(
-                        dt,
-                        &mut cpu_entities,
-						&mut cpu_racers,
-                        &mut fighter,
-                        &mut damage_texts,
-                        is_paused,
-						sbrx_map_system.current_field_id,
-                    );
+                    world.update_attacks(dt, is_paused);
 
-                    if fighter.state == RacerState::OnBike && !is_paused {
-                        // Only deplete fuel for RACER and SOLDIER
-                        if fighter.fighter_type != FighterType::Raptor && fighter.fuel > 0.0 {
-                            fighter.fuel -= FUEL_DEPLETION_RATE * dt;
-                        }
-                        if fighter.fuel <= 0.0 {
-                            fighter.fuel = 0.0;
+                    if world.fighter.state == RacerState::OnBike && !is_paused {
+                        // Riding costs whatever the fighter's profile says (fuel for RACER and SOLDIER)
+                        if !world.fighter.resources.spend(ResourceAction::Ride, dt) {
                             println!("Fuel depleted! Forcing dismount.");
-                            fighter.state = RacerState::OnFoot;
+                            world.fighter.state = RacerState::OnFoot;
                             // Only respawn a physical bike for non-raptors
-                            if fighter.fighter_type != FighterType::Raptor {
-                                sbrx_bike.respawn(fighter.x, fighter.y);
+                            if world.fighter.fighter_type != FighterType::Raptor {
+                                sbrx_bike.respawn(world.fighter.x, world.fighter.y);
                             }
-                            let tex_set = match fighter.fighter_type {
+                            let tex_set = match world.fighter.fighter_type {
                                 FighterType::Racer => &racer_textures,
                                 FighterType::Soldier => &soldier_textures,
                                 FighterType::Raptor => &raptor_textures,
                             };
                             update_current_textures(
-                                &fighter,
+                                &world.fighter,
                                 tex_set,
                                 &mut current_idle_texture,
                                 &mut current_fwd_texture,
@@ -2765,7 +2258,7 @@ fn main() {
                         }
                     }
 
-                    let is_stun_locked_for_anim = block_system.is_stun_locked() || fighter.stun_timer > 0.0;
+                    let is_stun_locked_for_anim = world.block_system.is_stun_locked() || world.fighter.stun_timer > 0.0;
                     if is_stun_locked_for_anim {
                         if !block_break_animation_active {
                             block_break_animation_active = true;
@@ -2785,54 +2278,32 @@ fn main() {
                             if !is_high_priority_animation_active(
                                 rush_active,
                                 strike_animation_timer,
-                                block_system.active,
-                                block_system.rmb_held,
+                                world.block_system.active,
+                                world.block_system.rmb_held,
                             ) && !movement_active
                                 && !backpedal_active
                             {
                                 current_racer_texture = current_idle_texture;
                             }
                         }
-                        let (current_min_x, current_max_x, current_min_y, current_max_y) =
-                            if let Some(ref area_state) = current_area {
-                                let (width, height, origin_x, origin_y) = match area_state.area_type
-                                {
-                                    AreaType::RaptorNest => {
-                                        (AREA_WIDTH, AREA_HEIGHT, AREA_ORIGIN_X, AREA_ORIGIN_Y)
-                                    }
-                                    AreaType::Bunker => (
-                                        BUNKER_WIDTH,
-                                        BUNKER_HEIGHT,
-                                        BUNKER_ORIGIN_X,
-                                        BUNKER_ORIGIN_Y,
-                                    ),
-                                };
-                                (origin_x, origin_x + width, origin_y, origin_y + height)
-                            } else {
-                                let min_y_world = if fighter.state == RacerState::OnBike {
-                                    line_y
-                                } else {
-                                    MIN_Y
-                                };
-                                (MIN_X, MAX_X, min_y_world, MAX_Y)
-                            };
+                        let (current_min_x, current_max_x, current_min_y, current_max_y) = world.movement_bounds();
 							
 						// Detect Rut Zone once for both Bike and OnFoot logic
-						let in_rut_zone = collision_barrier_manager.check_rut(
-							&sbrx_map_system.current_field_id,
-							fighter.x,
-							fighter.y,
+						let in_rut_zone = world.collision_barriers.check_rut(
+							&world.map_system.current_field_id,
+							world.fighter.x,
+							world.fighter.y,
 						);							
 							
-                        if fighter.state == RacerState::OnBike {
-                            let bike_speed = match fighter.fighter_type {
-                                FighterType::Racer => BIKE_SPEED + (fighter.run_speed - RACER_LVL1_STATS.speed.run_speed).max(0.0),
-                                FighterType::Raptor => BIKE_SPEED * 0.75 + (fighter.run_speed - RAPTOR_LVL1_STATS.speed.run_speed).max(0.0),
-                                FighterType::Soldier => BIKE_SPEED * 0.5 + (fighter.run_speed - SOLDIER_LVL1_STATS.speed.run_speed).max(0.0),
+                        if world.fighter.state == RacerState::OnBike {
+                            let bike_speed = match world.fighter.fighter_type {
+                                FighterType::Racer => game_config.movement.bike_speed + (world.fighter.run_speed - RACER_LVL1_STATS.speed.run_speed).max(0.0),
+                                FighterType::Raptor => game_config.movement.bike_speed * 0.75 + (world.fighter.run_speed - RAPTOR_LVL1_STATS.speed.run_speed).max(0.0),
+                                FighterType::Soldier => game_config.movement.bike_speed * 0.5 + (world.fighter.run_speed - SOLDIER_LVL1_STATS.speed.run_speed).max(0.0),
                             };
                             // Apply speed reduction for combat actions AND blocking
-                            let mut final_bike_speed = if fighter.combat_action_slowdown_timer > 0.0
-                                || block_system.active
+                            let mut final_bike_speed = if world.fighter.combat_action_slowdown_timer > 0.0
+                                || world.block_system.active
                             {
                                 bike_speed * 0.75 // 25% speed reduction for combat/blocking
                             } else {
@@ -2844,12 +2315,12 @@ fn main() {
                                 final_bike_speed *= 0.5;
                             }
 							
-                            if fighter.fighter_type == FighterType::Racer && fighter.boost && shift_held {
+                            if world.fighter.fighter_type == FighterType::Racer && world.fighter.boost && shift_held {
                                 final_bike_speed *= 1.25 // 1.03; // Increase bike speed by 3%
                             }
 							
                             // Apply 2.0x speed buff while ATOMIC-STATE is active (consistent with OnFoot)
-                            if fighter.invincible_timer > 1.0 {
+                            if world.fighter.invincible_timer > 1.0 {
                                 final_bike_speed *= 2.0;
                             }							
 
@@ -2862,47 +2333,45 @@ fn main() {
 							if in_rut_zone {
 								final_bike_speed *= 0.85;
 							}							
+                            final_bike_speed *= world.fighter.status_effects.move_speed_multiplier();
 							
                             let move_distance = final_bike_speed * dt;
-                            let mut moved = false;
-                            if key_w_pressed || key_s_pressed || key_a_pressed || key_d_pressed {
-                                moved = true;
-                            }
-                            if !is_paused && !block_system.is_stun_locked() {
+                            let pad_stick = if is_paused { None } else { gamepad.left_stick(&game_config.gamepad) };
+                            let (move_x, move_y) = movement_axes(
+                                key_w_pressed,
+                                key_s_pressed,
+                                key_a_pressed,
+                                key_d_pressed,
+                                pad_stick,
+                            );
+                            let moved = move_x != 0.0 || move_y != 0.0;
+                            if !is_paused && !world.block_system.is_stun_locked() {
                                 // raptor flight mode: prevent movement while blocking (same as OnFoot)
                                 let raptor_block_prevents_movement = 
-                                    fighter.fighter_type == FighterType::Raptor && block_system.active;								
+                                    world.fighter.fighter_type == FighterType::Raptor && world.block_system.active;								
 								
-                                let mut target_x = fighter.x;
-                                let mut target_y = fighter.y;
-                                if key_w_pressed && !raptor_block_prevents_movement {
-                                    target_y -= move_distance;
-                                }
-                                if key_s_pressed && !raptor_block_prevents_movement {
-                                    target_y += move_distance;
-                                }
-                                if key_a_pressed && !raptor_block_prevents_movement {
-                                    target_x -= move_distance;
+                                let mut target_x = world.fighter.x;
+                                let mut target_y = world.fighter.y;
+                                if !raptor_block_prevents_movement {
+                                    target_x += move_x * move_distance;
+                                    target_y += move_y * move_distance;
                                 }
-                                if key_d_pressed && !raptor_block_prevents_movement {
-                                    target_x += move_distance;
-                                }
-                                fighter.x = target_x.clamp(current_min_x, current_max_x);
-                                fighter.y = target_y.clamp(current_min_y, current_max_y);
+                                world.fighter.x = target_x.clamp(current_min_x, current_max_x);
+                                world.fighter.y = target_y.clamp(current_min_y, current_max_y);
                             }
                             if moved
                                 && !is_high_priority_animation_active(
                                     rush_active,
                                     strike_animation_timer,
-                                    block_system.active,
-                                    block_system.rmb_held,
+                                    world.block_system.active,
+                                    world.block_system.rmb_held,
                                 )
                             {
                                 let (world_mouse_x, _) = screen_to_world(&camera, mouse_x, mouse_y);
                                 let moving_horizontally = key_a_pressed || key_d_pressed;
                                 let moving_left = key_a_pressed && !key_d_pressed;
                                 let moving_towards_mouse = if moving_horizontally {
-                                    if world_mouse_x < fighter.x {
+                                    if world_mouse_x < world.fighter.x {
                                         moving_left
                                     } else {
                                         !moving_left
@@ -2911,7 +2380,7 @@ fn main() {
                                     true
                                 };
                                 if moving_towards_mouse {
-                                    let anim_vec = if fighter.fighter_type == FighterType::Racer && fighter.boost && shift_held {
+                                    let anim_vec = if world.fighter.fighter_type == FighterType::Racer && world.fighter.boost && shift_held {
                                         tex_set.bike_accelerate_boost.as_ref().unwrap_or(&tex_set.bike_accelerate)
                                     } else {
                                         &tex_set.bike_accelerate
@@ -2948,8 +2417,8 @@ fn main() {
                                 && !is_high_priority_animation_active(
                                     rush_active,
                                     strike_animation_timer,
-                                    block_system.active,
-                                    block_system.rmb_held,
+                                    world.block_system.active,
+                                    world.block_system.rmb_held,
                                 )
                             {
                                 if !(movement_active || backpedal_active) {
@@ -2965,8 +2434,8 @@ fn main() {
                                 if !is_high_priority_animation_active(
                                     rush_active,
                                     strike_animation_timer,
-                                    block_system.active,
-                                    block_system.rmb_held,
+                                    world.block_system.active,
+                                    world.block_system.rmb_held,
                                 ) && !backpedal_active
                                 {
                                     current_racer_texture = current_idle_texture;
@@ -2981,8 +2450,8 @@ fn main() {
                                 if !is_high_priority_animation_active(
                                     rush_active,
                                     strike_animation_timer,
-                                    block_system.active,
-                                    block_system.rmb_held,
+                                    world.block_system.active,
+                                    world.block_system.rmb_held,
                                 ) && !movement_active
                                 {
                                     current_racer_texture = current_idle_texture;
@@ -2993,7 +2462,7 @@ fn main() {
                         if strike_animation_timer > 0.0 {
                             strike_animation_timer -= dt;
                             if strike_animation_timer <= 0.0 {
-                                if !block_system.active
+                                if !world.block_system.active
                                     && !rush_active
                                     && !movement_active
                                     && !backpedal_active
@@ -3006,7 +2475,7 @@ fn main() {
                             rush_timer -= dt;
                             if rush_timer <= 0.0 {
                                 rush_active = false;
-                                if !block_system.active && !movement_active && !backpedal_active {
+                                if !world.block_system.active && !movement_active && !backpedal_active {
                                     current_racer_texture = current_idle_texture;
                                 }
                             }
@@ -3017,27 +2486,27 @@ fn main() {
                         if !block_break_animation_active
                             && !movement_active
                             && !backpedal_active
-                            && !block_system.active
-                            && !block_system.rmb_held
+                            && !world.block_system.active
+                            && !world.block_system.rmb_held
                             && strike_animation_timer <= 0.0
                             && !rush_active
                         {
-                            if (fighter.state == RacerState::OnFoot
+                            if (world.fighter.state == RacerState::OnFoot
                                 && !key_w_pressed
                                 && !key_s_pressed
                                 && !key_a_pressed
                                 && !key_d_pressed)
-                                || (fighter.state == RacerState::OnBike
+                                || (world.fighter.state == RacerState::OnBike
                                     && !key_w_pressed
                                     && !key_s_pressed
                                     && !key_a_pressed
                                     && !key_d_pressed)
                             {
-                                let is_soldier_aiming_idle = fighter.fighter_type == FighterType::Soldier
+                                let is_soldier_aiming_idle = world.fighter.fighter_type == FighterType::Soldier
                                     && shift_held;
  
                                 if is_soldier_aiming_idle {
-                                    if fighter.state == RacerState::OnFoot {
+                                    if world.fighter.state == RacerState::OnFoot {
                                         current_racer_texture = tex_set.ranged_aim.as_ref().unwrap_or(current_idle_texture);
                                     } else {
                                         current_racer_texture = tex_set.bike_ranged_aim.as_ref().unwrap_or(current_idle_texture);
@@ -3048,49 +2517,47 @@ fn main() {
                             }
                         }
                     }
-                    let tasks_completed = task_system.update();
-					task_system.update_timer(dt);
+                    let tasks_completed = world.task_system.update();
+					world.task_system.update_timer(dt);
                     if tasks_completed > 0 {
+                        world.game_events.publish(GameEvent::TasksCompleted { count: tasks_completed });
                         let points_awarded = 10 * tasks_completed;
                         task_reward_notification = Some(TaskRewardNotification {
                             text: format!("+{}", points_awarded),
                             lifetime: 1.5,
                         });
-						let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
-                        award_kill_score(
-                            &mut fighter,
-                            points_awarded,
-                            &mut chatbox,
-                            &mut lvl_up_state,
-                            &format!("completing {} task(s)", tasks_completed),
-							aptitude,
-                        );
-						fighter.score = (fighter.score + points_awarded).min(999999);
+						let aptitude = get_field_aptitude(world.map_system.current_field_id);
+                        if world.award_kill_score(points_awarded, aptitude) {
+                            lvl_up_state = LvlUpState::PendingTab {
+                                fighter_type: world.fighter.fighter_type,
+                            };
+                        }
+						world.fighter.score = (world.fighter.score + points_awarded).min(999999);
                     }
 					
 					
 
                     // Deactivate CPUs on the racetrack if the final task is active
                     // --- RUT ZONE CHECK ---
-                    let in_rut_zone = collision_barrier_manager.check_rut(
-                        &sbrx_map_system.current_field_id,
-                        fighter.x,
-                        fighter.y,
+                    let in_rut_zone = world.collision_barriers.check_rut(
+                        &world.map_system.current_field_id,
+                        world.fighter.x,
+                        world.fighter.y,
                     );					
 					
-                    let is_racetrack_finale_task_active = task_system
+                    let is_racetrack_finale_task_active = world.task_system
                         .has_task("PROCEED TO THE STARTING LINE")
-                        && !task_system.is_task_complete("PROCEED TO THE STARTING LINE");
+                        && !world.task_system.is_task_complete("PROCEED TO THE STARTING LINE");
 
                     if is_racetrack_finale_task_active
-                        && sbrx_map_system.current_field_id == SbrxFieldId(0, 0)
+                        && world.map_system.current_field_id == SbrxFieldId(0, 0)
                     {
                         if !racetrack_active {
                             // First frame this condition is met, clear enemies
                             println!(
                                 "[FINALE] Racetrack peaceful mode activated. Clearing enemies."
                             );
-                            cpu_entities.clear();
+                            world.cpu_entities.clear();
                             racetrack_active = true;
                         }
                     } else {
@@ -3100,7 +2567,7 @@ fn main() {
                             racetrack_active = false;
                             // Deactivate arena mode only if player leaves the field
                             if endless_arena_mode_active
-                                && sbrx_map_system.current_field_id != SbrxFieldId(0, 0)
+                                && world.map_system.current_field_id != SbrxFieldId(0, 0)
                             {
                                 endless_arena_mode_active = false;
                                 endless_arena_timer = 0.0;
@@ -3113,28 +2580,28 @@ fn main() {
                     }
 
                     // Force switch to Racer if on the racetrack during the finale
-                    if racetrack_active && fighter.fighter_type != FighterType::Racer {
+                    if racetrack_active && world.fighter.fighter_type != FighterType::Racer {
                         //println!("[FINALE] Forcing switch to RACER for the race.");
                         // This logic is adapted from the F1 key press handler
-                        fighter_hp_map.insert(fighter.fighter_type, fighter.current_hp);
-                        let new_radius = fighter.switch_fighter_type(FighterType::Racer);
-                        fixed_crater.radius = new_radius;						
-						fighter.stats = fighter_stats_map
+                        fighter_hp_map.insert(world.fighter.fighter_type, world.fighter.current_hp);
+                        let new_radius = world.fighter.switch_fighter_type(FighterType::Racer);
+                        world.fixed_crater.radius = new_radius;						
+						world.fighter.stats = fighter_stats_map
 							.get(&FighterType::Racer)
 							.copied()
 							.unwrap_or(combat::stats::RACER_LVL1_STATS);							
-                        fighter.max_hp = fighter.stats.defense.hp;
-                        fighter.melee_damage = fighter.stats.attack.melee_damage;
-                        fighter.ranged_damage = fighter.stats.attack.ranged_damage;
-                        fighter.run_speed = fighter.stats.speed.run_speed;
-                        fighter.current_hp = fighter.max_hp; // Set to full health for the race
-                        fighter_hp_map.insert(FighterType::Racer, fighter.max_hp); // Update map
-                        combo_system.is_combo3_stun_disabled = false;
-                        fighter.combat_mode = CombatMode::CloseCombat;
+                        world.fighter.max_hp = world.fighter.stats.defense.hp;
+                        world.fighter.melee_damage = world.fighter.stats.attack.melee_damage;
+                        world.fighter.ranged_damage = world.fighter.stats.attack.ranged_damage;
+                        world.fighter.run_speed = world.fighter.stats.speed.run_speed;
+                        world.fighter.current_hp = world.fighter.max_hp; // Set to full health for the race
+                        fighter_hp_map.insert(FighterType::Racer, world.fighter.max_hp); // Update map
+                        world.combo_system.is_finisher_stun_disabled = false;
+                        world.fighter.combat_mode = CombatMode::CloseCombat;
                         shift_override_active = false;
 
                         update_current_textures(
-                            &fighter,
+                            &world.fighter,
                             &racer_textures,
                             &mut current_idle_texture,
                             &mut current_fwd_texture,
@@ -3155,25 +2622,25 @@ fn main() {
                     }
 
                     // Spawn VoidTempest when all survivors are found
-                    if task_system.survivors_found >= 10
+                    if world.task_system.survivors_found >= 10
                         && !void_tempest_spawned_for_survivors
-                        && sbrx_map_system.current_field_id == SbrxFieldId(-2, 5)
+                        && world.map_system.current_field_id == SbrxFieldId(-2, 5)
                     {
-                        let void_tempest_exists = cpu_entities
+                        let void_tempest_exists = world.cpu_entities
                             .iter()
                             .any(|e| e.variant == CpuVariant::VoidTempest);
-                        if !void_tempest_exists && CPU_ENABLED {
-                            let (base_hp, base_speed) = cpu_entities
+                        if !void_tempest_exists && game_config.cpu_enabled {
+                            let (base_hp, base_speed) = world.cpu_entities
                                 .iter()
                                 .find(|e| e.variant == CpuVariant::GiantMantis && !e.is_dead())
                                 .map_or((250.0, 150.0), |m| (m.max_hp, m.speed));
 							let mut vt = CpuEntity::new_void_tempest(line_y, base_hp, base_speed);
-							let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+							let aptitude = get_field_aptitude(world.map_system.current_field_id);
 							vt.max_hp *= aptitude;
 							vt.current_hp = vt.max_hp;
 							vt.damage_value *= aptitude;
 							vt.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-							cpu_entities.push(vt);
+							world.cpu_entities.push(vt);
                             void_tempest_spawned_for_survivors = true;
                             chatbox.add_interaction(vec![(
                                 "WARNING: STRONG ENCOUNTER",
@@ -3192,11 +2659,11 @@ fn main() {
                     }
 
                     // Racetrack Soldier interaction check (Disabled in Arena Mode)
-                    if !crate::config::ARENA_MODE && racetrack_active && !racetrack_soldier_dialogue_triggered {
+                    if !game_config.arena_mode && racetrack_active && !racetrack_soldier_dialogue_triggered {
                         let soldier_x = 300.0;
                         let soldier_y = 650.0;
-                        let dx = fighter.x - soldier_x;
-                        let dy = fighter.y - soldier_y;
+                        let dx = world.fighter.x - soldier_x;
+                        let dy = world.fighter.y - soldier_y;
                         if (dx * dx + dy * dy).sqrt() < INFO_POST_INTERACTION_DISTANCE {
                             show_racetrack_soldier_prompt = true;
                         } else {
@@ -3215,11 +2682,11 @@ fn main() {
 */
                     show_fort_silo_survivor_prompt = false;
                     if !is_paused
-                        && sbrx_map_system.current_field_id == SbrxFieldId(-25, 25)
+                        && world.map_system.current_field_id == SbrxFieldId(-25, 25)
                         && !fort_silo_survivor.interaction_triggered
                     {
-                        let dx = fighter.x - fort_silo_survivor.x;
-                        let dy = fighter.y - fort_silo_survivor.y;
+                        let dx = world.fighter.x - fort_silo_survivor.x;
+                        let dy = world.fighter.y - fort_silo_survivor.y;
                         if (dx * dx + dy * dy).sqrt() < INFO_POST_INTERACTION_DISTANCE {
                             show_fort_silo_survivor_prompt = true;
                         }
@@ -3227,13 +2694,13 @@ fn main() {
 
                     // Grand Commander interaction check
                     show_grand_commander_prompt = false;
-                    if let Some(area_state) = &current_area {
+                    if let Some(area_state) = &world.current_area {
                         if area_state.area_type == AreaType::Bunker && area_state.floor == -3 {
                             if razor_fiend_defeated_flag && !grand_commander_dialogue_triggered {
                                 let gc_x = BUNKER_ORIGIN_X + BUNKER_WIDTH / 2.0;
                                 let gc_y = BUNKER_ORIGIN_Y + BUNKER_HEIGHT / 2.0;
-                                let dx = fighter.x - gc_x;
-                                let dy = fighter.y - gc_y;
+                                let dx = world.fighter.x - gc_x;
+                                let dy = world.fighter.y - gc_y;
                                 if (dx * dx + dy * dy).sqrt() < INFO_POST_INTERACTION_DISTANCE {
                                     show_grand_commander_prompt = true;
                                 }
@@ -3244,21 +2711,21 @@ fn main() {
                     // Survivor interaction check
                     show_survivor_interaction_prompt = false;
                     nearby_survivor_index = None;
-                    if sbrx_map_system.current_field_id == SbrxFieldId(-2, 5) {
+                    if world.map_system.current_field_id == SbrxFieldId(-2, 5) {
                         if let Some(field_survivors) =
-                            survivors.get_mut(&sbrx_map_system.current_field_id)
+                            world.survivors.get_mut(&world.map_system.current_field_id)
                         {
                             for (i, survivor) in field_survivors.iter().enumerate() {
                                 if !survivor.is_rescued
-                                    && (!FOG_OF_WAR_ENABLED
+                                    && (!game_config.fog_of_war_enabled
                                         || fog_of_war.is_position_visible(
-                                            sbrx_map_system.current_field_id,
+                                            world.map_system.current_field_id,
                                             survivor.x,
                                             survivor.y,
                                         ))
                                 {
-                                    let dx = fighter.x - survivor.x;
-                                    let dy = fighter.y - survivor.y;
+                                    let dx = world.fighter.x - survivor.x;
+                                    let dy = world.fighter.y - survivor.y;
                                     if (dx * dx + dy * dy).sqrt() < 150.0 {
                                         // Interaction distance
                                         show_survivor_interaction_prompt = true;
@@ -3270,26 +2737,26 @@ fn main() {
                         }
                     }
 
-                    game_time += dt;
+                    world.game_time += dt;
                     show_soldier_interaction_prompt = false;
-                    if soldier_visible && sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
-                        let dx = fighter.x - random_image_x;
-                        let dy = fighter.y - random_image_y;
+                    if soldier_visible && world.map_system.current_field_id == SbrxFieldId(0, 0) {
+                        let dx = world.fighter.x - random_image_x;
+                        let dy = world.fighter.y - random_image_y;
                         if (dx * dx + dy * dy).sqrt() < soldier_interaction_distance {
                             show_soldier_interaction_prompt = true;
                         }
                     }
                     show_raptor_interaction_prompt = false;
-                    if let Some(area_state) = &current_area {
+                    if let Some(area_state) = &world.current_area {
                         // Only show raptor interaction in raptor nest, not in bunker
                         if area_state.area_type == AreaType::RaptorNest {
                             if raptor_is_trapped_in_nest
-                                && task_system.is_task_complete("CLEAR RAPTOR NEST: FIELD[X1 Y0]")
+                                && world.task_system.is_task_complete("CLEAR RAPTOR NEST: FIELD[X1 Y0]")
                             {
                                 let raptor_x = AREA_ORIGIN_X + (AREA_WIDTH / 2.0) + 50.0;
                                 let raptor_y = AREA_ORIGIN_Y + (AREA_HEIGHT / 2.0) - 30.0;
-                                let dx = fighter.x - raptor_x;
-                                let dy = fighter.y - raptor_y;
+                                let dx = world.fighter.x - raptor_x;
+                                let dy = world.fighter.y - raptor_y;
                                 if (dx * dx + dy * dy).sqrt() < RAPTOR_INTERACTION_DISTANCE {
                                     show_raptor_interaction_prompt = true;
                                 }
@@ -3297,13 +2764,13 @@ fn main() {
                         }
                     }
                     show_info_post_prompt = false;
-                    if !crate::config::ARENA_MODE 
+                    if !game_config.arena_mode 
                         && !racetrack_active
-                        && sbrx_map_system.current_field_id == SbrxFieldId(0, 0)
+                        && world.map_system.current_field_id == SbrxFieldId(0, 0)
                         && !racetrack_info_post_interacted
                     {
-                        let dx = fighter.x - info_post_position.0;
-                        let dy = fighter.y - info_post_position.1;
+                        let dx = world.fighter.x - info_post_position.0;
+                        let dy = world.fighter.y - info_post_position.1;
                         if (dx * dx + dy * dy).sqrt() < INFO_POST_INTERACTION_DISTANCE {
                             show_info_post_prompt = true;
                         }
@@ -3311,34 +2778,34 @@ fn main() {
 
                     show_finale_info_post_prompt = false;
                     if racetrack_active
-                        && sbrx_map_system.current_field_id == SbrxFieldId(0, 0)
+                        && world.map_system.current_field_id == SbrxFieldId(0, 0)
                         && !finale_info_post_interacted
                     {
-                        let dx = fighter.x - info_post_position.0;
-                        let dy = fighter.y - info_post_position.1;
+                        let dx = world.fighter.x - info_post_position.0;
+                        let dy = world.fighter.y - info_post_position.1;
                         if (dx * dx + dy * dy).sqrt() < INFO_POST_INTERACTION_DISTANCE {
                             show_finale_info_post_prompt = true;
                         }
                     }
 
-                    if FOG_OF_WAR_ENABLED {
+                    if game_config.fog_of_war_enabled {
                         fog_of_war.update_player_visibility(
-                            sbrx_map_system.current_field_id,
-                            fighter.x,
-                            fighter.y,
-                            game_time,
+                            world.map_system.current_field_id,
+                            world.fighter.x,
+                            world.fighter.y,
+                            world.game_time,
                         );
                     }
 					
                     // Mark Rocketbay task as found when entering these fields
-                    if sbrx_map_system.current_field_id == SbrxFieldId(-2, 5) 
-                        || sbrx_map_system.current_field_id == SbrxFieldId(-25, 25) {
-                        task_system.mark_rocketbay_found();
+                    if world.map_system.current_field_id == SbrxFieldId(-2, 5) 
+                        || world.map_system.current_field_id == SbrxFieldId(-25, 25) {
+                        world.task_system.mark_rocketbay_found();
                     }					
 					
                     match ambient_track_state {
                         AmbientTrackState::Background => {
-                            if let Some(track_name) = handle_ambient_playlist(&audio_manager, &mut current_bgm_sink, &mut ambient_playlist_index) {
+                            if let Some(track_name) = handle_ambient_playlist(&world.audio, &mut current_bgm_sink, &mut ambient_playlist_index) {
                                 track_notification = Some(TrackNotification {
                                     track_name,
                                     lifetime: 3.0, // Display for 3 seconds
@@ -3348,7 +2815,7 @@ fn main() {
                         AmbientTrackState::Crickets => {
                             if current_bgm_sink.is_some() { if let Some(s) = current_bgm_sink.take() { s.stop(); } }
                             if crickets_sound_sink.is_none() {
-                                if let Ok(s) = audio_manager.play_sfx_loop("crickets") { crickets_sound_sink = Some(s); }
+                                if let Ok(s) = world.audio.play_sfx_loop("crickets") { crickets_sound_sink = Some(s); }
                             }
                         }
                         AmbientTrackState::Muted => {
@@ -3356,50 +2823,22 @@ fn main() {
                             if let Some(s) = crickets_sound_sink.take() { s.stop(); }
                         }
                     }				
-                    let (current_min_x, current_max_x, current_min_y, current_max_y) =
-                        if let Some(ref area_state) = current_area {
-                            let (width, height, origin_x, origin_y) = match area_state.area_type {
-                                AreaType::RaptorNest => {
-                                    (AREA_WIDTH, AREA_HEIGHT, AREA_ORIGIN_X, AREA_ORIGIN_Y)
-                                }
-                                AreaType::Bunker => (
-                                    BUNKER_WIDTH,
-                                    BUNKER_HEIGHT,
-                                    BUNKER_ORIGIN_X,
-                                    BUNKER_ORIGIN_Y,
-                                ),
-                            };
-                            (origin_x, origin_x + width, origin_y, origin_y + height)
-                        } else {
-                            let min_y_world = if fighter.state == RacerState::OnBike {
-                                line_y
-                            } else {
-                                MIN_Y
-                            };
-                            (MIN_X, MAX_X, min_y_world, MAX_Y)
-                        };
-                    if fighter.state == RacerState::OnFoot
-                        && !block_system.active
+                    if world.fighter.state == RacerState::OnFoot
+                        && !world.block_system.active
                         && !rush_active
                         && strike_animation_timer <= 0.0
-                        && fighter.knockback_duration <= 0.0
-                        && !block_system.is_stun_locked()
+                        && world.fighter.knockback_duration <= 0.0
+                        && !world.block_system.is_stun_locked()
                     {
-                        let mut dx = 0.0;
-                        let mut dy = 0.0;
-                        if key_w_pressed {
-                            dy -= 1.0;
-                        }
-                        if key_s_pressed {
-                            dy += 1.0;
-                        }
-                        if key_a_pressed {
-                            dx -= 1.0;
-                        }
-                        if key_d_pressed {
-                            dx += 1.0;
-                        }
-                        let current_move_direction = (dx, dy);
+                        let pad_stick = if is_paused { None } else { gamepad.left_stick(&game_config.gamepad) };
+                        let (dx, dy) = movement_axes(
+                            key_w_pressed,
+                            key_s_pressed,
+                            key_a_pressed,
+                            key_d_pressed,
+                            pad_stick,
+                        );
+                        let current_move_direction = direction_octant(dx, dy);
                         if current_move_direction != (0.0, 0.0) {
                             if current_move_direction == last_move_direction {
                                 continuous_move_timer += dt;
@@ -3407,42 +2846,18 @@ fn main() {
                                 continuous_move_timer = 0.0;
                                 is_in_continuous_move = false;
                             }
-                            if continuous_move_timer > ON_FOOT_HOLD_DURATION {
+                            if continuous_move_timer > game_config.movement.on_foot_hold_duration {
                                 is_in_continuous_move = true;
                             }
                             if is_in_continuous_move {
                                 let (world_mouse_x, _) = screen_to_world(&camera, mouse_x, mouse_y);
-                                let moving_horizontally = dx != 0.0;
-                                let moving_left = dx < 0.0;
-                                let is_moving_forward = if moving_horizontally {
-                                    if moving_left {
-                                        world_mouse_x < fighter.x
-                                    } else {
-                                        world_mouse_x > fighter.x
-                                    }
-                                } else {
-                                    true
-                                };
-                                if is_moving_forward {
+                                let boost_mult = if world.fighter.fighter_type == FighterType::Racer && world.fighter.boost && shift_held { 1.5 } else { 1.0 };
+								let rut_mult = if in_rut_zone { 0.5 } else { 1.0 };
+                                if world.walk(dx, dy, world_mouse_x, boost_mult * rut_mult, dt) {
                                     current_racer_texture = current_fwd_texture;
                                 } else {
                                     current_racer_texture = current_backpedal_texture;
                                 }
-                                let mut move_vec = Vec2d::new(dx, dy);
-                                let mag = (move_vec.x.powi(2) + move_vec.y.powi(2)).sqrt();
-                                if mag > 0.0 {
-                                    move_vec.x /= mag;
-                                    move_vec.y /= mag;
-                                }
-                                let boost_mult = if fighter.fighter_type == FighterType::Racer && fighter.boost && shift_held { 1.5 } else { 1.0 };
-								let rut_mult = if in_rut_zone { 0.5 } else { 1.0 };
-                                let backpedal_mult = if !is_moving_forward { 0.5 } else { 1.0 };
-								let atomic_mult = if fighter.invincible_timer > 1.0 { 2.0 } else { 1.0 }; // 25% speed buff during ATOMIC-STATE
-								let speed_mult = boost_mult * backpedal_mult * rut_mult * atomic_mult;
-                                fighter.x += move_vec.x * fighter.run_speed * speed_mult * dt;
-                                fighter.y += move_vec.y * fighter.run_speed * speed_mult * dt;
-                                fighter.x = fighter.x.clamp(current_min_x, current_max_x);
-                                fighter.y = fighter.y.clamp(current_min_y, current_max_y);
                             }
                         } else {
                             continuous_move_timer = 0.0;
@@ -3454,32 +2869,24 @@ fn main() {
                         is_in_continuous_move = false;
                         last_move_direction = (0.0, 0.0);
                     }
-                    for text in &mut damage_texts {
-                        text.lifetime -= dt;
-                        text.y -= 30.0 * dt;
-                    }
-                    for i in (0..damage_texts.len()).rev() {
-                        if damage_texts[i].lifetime <= 0.0 {
-                            damage_texts.swap_remove(i);
-                        }
-                    }
-                    if current_area.is_none() {
+                    world.age_damage_texts(dt);
+                    if world.current_area.is_none() {
                         if (racetrack_active || endless_arena_mode_active)
-                            && sbrx_map_system.current_field_id == SbrxFieldId(0, 0)
+                            && world.map_system.current_field_id == SbrxFieldId(0, 0)
                         {
                             // Player is on the racetrack during the finale. Lock them in.
-                            let hit_boundary = fighter.x >= config::boundaries::MAX_X
-                                || fighter.x <= config::boundaries::MIN_X
-                                || fighter.y >= config::boundaries::MAX_Y
-                                || fighter.y <= config::boundaries::MIN_Y;
+                            let hit_boundary = world.fighter.x >= config::boundaries::MAX_X
+                                || world.fighter.x <= config::boundaries::MIN_X
+                                || world.fighter.y >= config::boundaries::MAX_Y
+                                || world.fighter.y <= config::boundaries::MIN_Y;
 
                             if hit_boundary {
                                 // Clamp position
-                                fighter.x = fighter.x.clamp(
+                                world.fighter.x = world.fighter.x.clamp(
                                     config::boundaries::MIN_X + 10.0,
                                     config::boundaries::MAX_X - 10.0,
                                 );
-                                fighter.y = fighter.y.clamp(
+                                world.fighter.y = world.fighter.y.clamp(
                                     config::boundaries::MIN_Y + 10.0,
                                     config::boundaries::MAX_Y - 10.0,
                                 );
@@ -3495,7 +2902,7 @@ fn main() {
                             }
                         } else {
                             // Original field transition logic for all other cases
-                            let can_transition = sbrx_map_system.current_field_id
+                            let can_transition = world.map_system.current_field_id
                                 != SbrxFieldId(0, 0)
                                 || soldier_has_joined
                                 || racetrack_active;
@@ -3504,11 +2911,11 @@ fn main() {
                                 get_allowed_fields(soldier_has_joined, raptor_has_joined);
                             let has_field_restrictions = !allowed_fields.is_empty();
 
-                            let is_saucer_defeated = task_system.flying_saucer_defeated;
+                            let is_saucer_defeated = world.task_system.flying_saucer_defeated;
                             // Block ground access to Fort Silo until flying saucer is defeated
                             let fort_silo_ground_restriction = !is_saucer_defeated
-                                && (fighter.state == RacerState::OnFoot
-                                    || fighter.state == RacerState::OnBike);
+                                && (world.fighter.state == RacerState::OnFoot
+                                    || world.fighter.state == RacerState::OnBike);
 
                             if can_transition {
                                 let mut dx_field_transition = 0;
@@ -3516,23 +2923,23 @@ fn main() {
 
                                 // Rocketbay (x-2, y5) Lockdown Check
                                 let is_in_rocketbay =
-                                    sbrx_map_system.current_field_id == SbrxFieldId(-2, 5);
-                                let void_tempest_active = cpu_entities
+                                    world.map_system.current_field_id == SbrxFieldId(-2, 5);
+                                let void_tempest_active = world.cpu_entities
                                     .iter()
                                     .any(|e| e.variant == CpuVariant::VoidTempest && !e.is_dead());
                                 let rocketbay_lockdown = is_in_rocketbay && void_tempest_active;
 								
                                 // T-Rex (x1, y0) Lockdown Check
-                                let is_in_nest_field = sbrx_map_system.current_field_id == SbrxFieldId(1, 0);
+                                let is_in_nest_field = world.map_system.current_field_id == SbrxFieldId(1, 0);
                                 let t_rex_lockdown = is_in_nest_field && t_rex_is_active;								
 
-                                if fighter.x >= config::boundaries::MAX_X {
+                                if world.fighter.x >= config::boundaries::MAX_X {
                                     let target_field = SbrxFieldId(
-                                        sbrx_map_system.current_field_id.0 + 1,
-                                        sbrx_map_system.current_field_id.1,
+                                        world.map_system.current_field_id.0 + 1,
+                                        world.map_system.current_field_id.1,
                                     );
                                     if rocketbay_lockdown {
-                                        fighter.x = config::boundaries::MAX_X - 10.0;
+                                        world.fighter.x = config::boundaries::MAX_X - 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "DEFEAT THE VOID TEMPEST TO ESCAPE",
@@ -3542,7 +2949,7 @@ fn main() {
                                                 BOUNDARY_WARNING_COOLDOWN_TIME;
                                         }
                                     } else if t_rex_lockdown {
-                                        fighter.x = config::boundaries::MAX_X - 10.0;
+                                        world.fighter.x = config::boundaries::MAX_X - 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "DEFEAT THE T-REX TO ESCAPE",
@@ -3554,7 +2961,7 @@ fn main() {
                                     } else if fort_silo_ground_restriction
                                         && target_field == SbrxFieldId(-25, 25)
                                     {
-                                        fighter.x = config::boundaries::MAX_X - 10.0;
+                                        world.fighter.x = config::boundaries::MAX_X - 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                             "A GRAVITATIONAL FORCE PREVENTS YOU FROM \nENTERING BY FOOT.",
@@ -3567,10 +2974,10 @@ fn main() {
                                         || !has_field_restrictions
                                         || allowed_fields.contains(&target_field)
                                     {
-                                        fighter.x = config::boundaries::MIN_X + 1.0;
+                                        world.fighter.x = config::boundaries::MIN_X + 1.0;
                                         dx_field_transition = 1;
                                     } else {
-                                        fighter.x = config::boundaries::MAX_X - 10.0;
+                                        world.fighter.x = config::boundaries::MAX_X - 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "FIELD RESTRICTED",
@@ -3580,13 +2987,13 @@ fn main() {
                                                 BOUNDARY_WARNING_COOLDOWN_TIME;
                                         }
                                     }
-                                } else if fighter.x <= config::boundaries::MIN_X {
+                                } else if world.fighter.x <= config::boundaries::MIN_X {
                                     let target_field = SbrxFieldId(
-                                        sbrx_map_system.current_field_id.0 - 1,
-                                        sbrx_map_system.current_field_id.1,
+                                        world.map_system.current_field_id.0 - 1,
+                                        world.map_system.current_field_id.1,
                                     );
                                     if rocketbay_lockdown {
-                                        fighter.x = config::boundaries::MIN_X + 10.0;
+                                        world.fighter.x = config::boundaries::MIN_X + 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "DEFEAT THE VOID TEMPEST TO ESCAPE",
@@ -3596,7 +3003,7 @@ fn main() {
                                                 BOUNDARY_WARNING_COOLDOWN_TIME;
                                         }
                                     } else if t_rex_lockdown {
-                                        fighter.x = config::boundaries::MIN_X + 10.0;
+                                        world.fighter.x = config::boundaries::MIN_X + 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "DEFEAT THE T-REX TO ESCAPE",
@@ -3608,7 +3015,7 @@ fn main() {
                                     } else if fort_silo_ground_restriction
                                         && target_field == SbrxFieldId(-25, 25)
                                     {
-                                        fighter.x = config::boundaries::MIN_X + 10.0;
+                                        world.fighter.x = config::boundaries::MIN_X + 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                             "A GRAVITATIONAL FORCE PREVENTS YOU FROM \nENTERING BY FOOT.",
@@ -3621,10 +3028,10 @@ fn main() {
                                         || !has_field_restrictions
                                         || allowed_fields.contains(&target_field)
                                     {
-                                        fighter.x = config::boundaries::MAX_X - 1.0;
+                                        world.fighter.x = config::boundaries::MAX_X - 1.0;
                                         dx_field_transition = -1;
                                     } else {
-                                        fighter.x = config::boundaries::MIN_X + 10.0;
+                                        world.fighter.x = config::boundaries::MIN_X + 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "FIELD RESTRICTED",
@@ -3635,13 +3042,13 @@ fn main() {
                                         }
                                     }
                                 }
-                                if fighter.y >= config::boundaries::MAX_Y {
+                                if world.fighter.y >= config::boundaries::MAX_Y {
                                     let target_field = SbrxFieldId(
-                                        sbrx_map_system.current_field_id.0,
-                                        sbrx_map_system.current_field_id.1 - 1,
+                                        world.map_system.current_field_id.0,
+                                        world.map_system.current_field_id.1 - 1,
                                     );
                                     if rocketbay_lockdown {
-                                        fighter.y = config::boundaries::MAX_Y - 10.0;
+                                        world.fighter.y = config::boundaries::MAX_Y - 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "DEFEAT THE VOID TEMPEST TO ESCAPE",
@@ -3651,7 +3058,7 @@ fn main() {
                                                 BOUNDARY_WARNING_COOLDOWN_TIME;
                                         }
                                     } else if t_rex_lockdown {
-                                        fighter.y = config::boundaries::MAX_Y - 10.0;
+                                        world.fighter.y = config::boundaries::MAX_Y - 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "DEFEAT THE T-REX TO ESCAPE",
@@ -3663,7 +3070,7 @@ fn main() {
                                     } else if fort_silo_ground_restriction
                                         && target_field == SbrxFieldId(-25, 25)
                                     {
-                                        fighter.y = config::boundaries::MAX_Y - 10.0;
+                                        world.fighter.y = config::boundaries::MAX_Y - 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                             "A GRAVITATIONAL FORCE PREVENTS YOU FROM \nENTERING BY FOOT.",
@@ -3676,10 +3083,10 @@ fn main() {
                                         || !has_field_restrictions
                                         || allowed_fields.contains(&target_field)
                                     {
-                                        fighter.y = config::boundaries::MIN_Y + 1.0;
+                                        world.fighter.y = config::boundaries::MIN_Y + 1.0;
                                         dy_field_transition = -1;
                                     } else {
-                                        fighter.y = config::boundaries::MAX_Y - 10.0;
+                                        world.fighter.y = config::boundaries::MAX_Y - 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "FIELD RESTRICTED",
@@ -3689,13 +3096,13 @@ fn main() {
                                                 BOUNDARY_WARNING_COOLDOWN_TIME;
                                         }
                                     }
-                                } else if fighter.y <= config::boundaries::MIN_Y {
+                                } else if world.fighter.y <= config::boundaries::MIN_Y {
                                     let target_field = SbrxFieldId(
-                                        sbrx_map_system.current_field_id.0,
-                                        sbrx_map_system.current_field_id.1 + 1,
+                                        world.map_system.current_field_id.0,
+                                        world.map_system.current_field_id.1 + 1,
                                     );
                                     if rocketbay_lockdown {
-                                        fighter.y = config::boundaries::MIN_Y + 10.0;
+                                        world.fighter.y = config::boundaries::MIN_Y + 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "DEFEAT THE VOID TEMPEST TO ESCAPE",
@@ -3705,7 +3112,7 @@ fn main() {
                                                 BOUNDARY_WARNING_COOLDOWN_TIME;
                                         }
                                     } else if t_rex_lockdown {
-                                        fighter.y = config::boundaries::MIN_Y + 10.0;
+                                        world.fighter.y = config::boundaries::MIN_Y + 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "DEFEAT THE T-REX TO ESCAPE",
@@ -3717,7 +3124,7 @@ fn main() {
                                     } else if fort_silo_ground_restriction
                                         && target_field == SbrxFieldId(-25, 25)
                                     {
-                                        fighter.y = config::boundaries::MIN_Y + 10.0;
+                                        world.fighter.y = config::boundaries::MIN_Y + 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                             "A GRAVITATIONAL FORCE PREVENTS YOU FROM 
@@ -3731,10 +3138,10 @@ fn main() {
                                         || !has_field_restrictions
                                         || allowed_fields.contains(&target_field)
                                     {
-                                        fighter.y = config::boundaries::MAX_Y - 1.0;
+                                        world.fighter.y = config::boundaries::MAX_Y - 1.0;
                                         dy_field_transition = 1;
                                     } else {
-                                        fighter.y = config::boundaries::MIN_Y + 10.0;
+                                        world.fighter.y = config::boundaries::MIN_Y + 10.0;
                                         if boundary_warning_cooldown <= 0.0 {
                                             chatbox.add_interaction(vec![(
                                                 "FIELD RESTRICTED",
@@ -3746,34 +3153,56 @@ fn main() {
                                     }
                                 }
                                 if dx_field_transition != 0 || dy_field_transition != 0 {
-                                    sbrx_map_system.transition_field_by_delta(
+                                    let previous_field = world.map_system.current_field_id;
+                                    world.map_system.transition_field_by_delta(
                                         dx_field_transition,
                                         dy_field_transition,
                                     );
+                                    world.game_events.publish(GameEvent::FieldChanged {
+                                        from: previous_field,
+                                        to: world.map_system.current_field_id,
+                                    });
 
 
-                                    cpu_entities.clear();
+                                    world.cpu_entities.clear();
 									
 
                                     // Refresh aptitude and apply to any immediate field-specific spawns 
-                                    let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);									
-
-                                    last_field_entry_point = (fighter.x, fighter.y);
+                                    let aptitude = get_field_aptitude(world.map_system.current_field_id);									
+
+                                    last_field_entry_point = (world.fighter.x, world.fighter.y);
+
+                                    // Practice sessions never touch the save slots.
+                                    if training_mode.is_none() {
+                                        let autosave = save::SaveData::capture(
+                                            &world.fighter,
+                                            &base_fighter_stats_map,
+                                            &world.task_system,
+                                            world.map_system.current_field_id,
+                                            &completed_bunker_waves,
+                                            &fog_of_war,
+                                            soldier_has_joined,
+                                            raptor_has_joined,
+                                        );
+                                        if let Err(err) = save::write_slot(&saves_dir, save::AUTOSAVE_SLOT, &autosave) {
+                                            eprintln!("[SAVE] Autosave failed: {}", err);
+                                        }
+                                    }
 
                                     // Spawn T-Rex if active and entering its field
                                     if t_rex_is_active
-                                        && sbrx_map_system.current_field_id == SbrxFieldId(1, 0)
-                                        && CPU_ENABLED
+                                        && world.map_system.current_field_id == SbrxFieldId(1, 0)
+                                        && game_config.cpu_enabled
                                     {
-                                        let t_rex_exists = cpu_entities
+                                        let t_rex_exists = world.cpu_entities
                                             .iter()
                                             .any(|e| e.variant == CpuVariant::TRex && !e.is_dead());
-                                        if !t_rex_exists && cpu_entities.len() < 10 {
+                                        if !t_rex_exists && world.cpu_entities.len() < 10 {
                                             let t_rex_x =
                                                 safe_gen_range(MIN_X, MAX_X, "T-Rex field entry x");
                                             let t_rex_y =
                                                 safe_gen_range(MIN_X, MAX_Y, "T-Rex field entry y");
-                                            cpu_entities
+                                            world.cpu_entities
                                                 .push(CpuEntity::new_t_rex(t_rex_x, t_rex_y));
                                             println!("Respawned T-Rex when entering field x1 y0");
 											chatbox.add_interaction(vec![(
@@ -3783,34 +3212,34 @@ fn main() {
                                         }
                                     }
 
-                                    if sbrx_map_system.current_field_id == SbrxFieldId(-2, 5) {
-                                        if !task_system.has_task("FIND 10 SURVIVORS") {
-                                            task_system.add_task("FIND 10 SURVIVORS");
+                                    if world.map_system.current_field_id == SbrxFieldId(-2, 5) {
+                                        if !world.task_system.has_task("FIND 10 SURVIVORS") {
+                                            world.task_system.add_task("FIND 10 SURVIVORS");
                                         }
 
-                                        let has_night_reavers = cpu_entities
+                                        let has_night_reavers = world.cpu_entities
                                             .iter()
                                             .any(|e| e.variant == CpuVariant::NightReaver);
 // main.rs - Rocketbay spawn logic
 
-                                        if !has_night_reavers && CPU_ENABLED {
+                                        if !has_night_reavers && game_config.cpu_enabled {
                                             println!(
                                                 "Spawning Night Reavers in ROCKETBAY field x-2 y5"
                                             );
                                             for _ in 0..4 {
-                                                if cpu_entities.len() < 10 {
+                                                if world.cpu_entities.len() < 10 {
                                                    let reaver_x = safe_gen_range(MIN_X, MAX_X, "NightReaver x");
                                                    let reaver_y = safe_gen_range(MIN_Y, MAX_Y, "NightReaver y");
 
                                                    let mut reaver = CpuEntity::new_night_reaver(reaver_x, reaver_y);
 												   
-                                                   let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+                                                   let aptitude = get_field_aptitude(world.map_system.current_field_id);
 												   
                                                    reaver.max_hp *= aptitude;
                                                    reaver.current_hp = reaver.max_hp;
                                                    reaver.damage_value *= aptitude;
                                                    reaver.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                                   cpu_entities.push(reaver);
+                                                   world.cpu_entities.push(reaver);
                                                 }
                                             }
                                         }
@@ -3874,8 +3303,8 @@ fn main() {
 
                                             // Spawn survivors if not already spawned for this field
                                             // (moved outside rocketbay_dialogue_triggered check so it works with or without fog)
-                                            survivors
-                                                .entry(sbrx_map_system.current_field_id)
+                                            world.survivors
+                                                .entry(world.map_system.current_field_id)
                                                 .or_insert_with(|| {
                                                     let mut new_survivors = Vec::new();
                                                     let num_survivors_to_spawn = 10;
@@ -3901,19 +3330,19 @@ fn main() {
                                     }
 
                                     // Spawn Light Reavers and Night Reavers when entering Fort Silo field via field transition
-									if sbrx_map_system.current_field_id == SbrxFieldId(-25, 25) 
-										&& !task_system.is_task_complete("SPEAK TO THE GRAND COMMANDER") 
+									if world.map_system.current_field_id == SbrxFieldId(-25, 25) 
+										&& !world.task_system.is_task_complete("SPEAK TO THE GRAND COMMANDER") 
 									{
-                                        let has_light_reavers = cpu_entities
+                                        let has_light_reavers = world.cpu_entities
                                             .iter()
                                             .any(|e| e.variant == CpuVariant::LightReaver);
-                                        let has_night_reavers = cpu_entities
+                                        let has_night_reavers = world.cpu_entities
                                             .iter()
                                             .any(|e| e.variant == CpuVariant::NightReaver);
-                                        if (!has_light_reavers || !has_night_reavers) && CPU_ENABLED {
+                                        if (!has_light_reavers || !has_night_reavers) && game_config.cpu_enabled {
                                             println!("Spawning Reavers in Fort Silo field x-25 y25 (via field transition)");
                                             for _ in 0..3 {
-                                                if cpu_entities.len() < 10 {
+                                                if world.cpu_entities.len() < 10 {
                                                     let reaver_x = safe_gen_range(
                                                         MIN_X,
                                                         MAX_X,
@@ -3929,11 +3358,11 @@ fn main() {
                                                     reaver.current_hp = reaver.max_hp;
                                                     reaver.damage_value *= aptitude;
                                                     reaver.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                                    cpu_entities.push(reaver);
+                                                    world.cpu_entities.push(reaver);
                                                 }
                                             }
                                             for _ in 0..3 {
-                                                if cpu_entities.len() < 10 {
+                                                if world.cpu_entities.len() < 10 {
                                                     let reaver_x = safe_gen_range(
                                                         MIN_X,
                                                         MAX_X,
@@ -3945,19 +3374,19 @@ fn main() {
                                                         "NightReaver y",
                                                     );
 													let mut reaver = CpuEntity::new_night_reaver(reaver_x, reaver_y);
-													let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+													let aptitude = get_field_aptitude(world.map_system.current_field_id);
 													reaver.max_hp *= aptitude;
 													reaver.current_hp = reaver.max_hp;
 													reaver.damage_value *= aptitude;
 													reaver.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-													cpu_entities.push(reaver);
+													world.cpu_entities.push(reaver);
                                                 }
                                             }											
                                         }
                                     }
 
                                     // --- NEW: Generate ground assets for the new field ---
-                                    let current_field_id = sbrx_map_system.current_field_id;
+                                    let current_field_id = world.map_system.current_field_id;
                                     if !ground_asset_manager.is_exclusion_zone(&current_field_id)
                                         && !placed_ground_assets.contains_key(&current_field_id)
                                     {
@@ -3967,14 +3396,14 @@ fn main() {
                                         );
                                         let mut assets_for_field = Vec::new();
                                         let num_assets_to_spawn =
-                                            rand::rng().random_range(30..=60);
+                                            utils::rng::cosmetic_rng().random_range(30..=60);
 
                                         for _ in 0..num_assets_to_spawn {
                                             if let Some(asset_to_spawn) =
                                                 ground_asset_manager.get_random_asset()
                                             {
-                                                let asset_x = safe_gen_range(MIN_X, MAX_X, "ground asset x");
-                                                let asset_y = safe_gen_range(line_y + 150.0, MAX_Y, "ground asset y");
+                                                let asset_x = safe_gen_range_cosmetic(MIN_X, MAX_X, "ground asset x");
+                                                let asset_y = safe_gen_range_cosmetic(line_y + 150.0, MAX_Y, "ground asset y");
  
                                                 // --- FLATLINE_field.x1y0 Raptor Nest Exclusion Zone ---
                                                 if current_field_id == SbrxFieldId(1, 0) {
@@ -4008,9 +3437,9 @@ fn main() {
                                     }
 
                                     raptor_nests.clear();
-                                    if FOG_OF_WAR_ENABLED
+                                    if game_config.fog_of_war_enabled
                                         && fog_of_war
-                                            .is_fog_enabled(sbrx_map_system.current_field_id)
+                                            .is_fog_enabled(world.map_system.current_field_id)
                                     {
                                         let blood_idol_spawn_chance =
                                             if has_blood_idol_fog_spawned_once {
@@ -4018,7 +3447,7 @@ fn main() {
                                             } else {
                                                 0.25
                                             };
-                                        if CPU_ENABLED
+                                        if game_config.cpu_enabled
                                             && safe_gen_range(
                                                 0.0,
                                                 1.0,
@@ -4026,12 +3455,12 @@ fn main() {
                                             ) < blood_idol_spawn_chance
                                         {
                                             if !has_blood_idol_fog_spawned_once {
-                                                println!("25% fog-of-war spawn triggered! Spawning BLOOD IDOL in field {:?}", sbrx_map_system.current_field_id);
+                                                println!("25% fog-of-war spawn triggered! Spawning BLOOD IDOL in field {:?}", world.map_system.current_field_id);
                                                 has_blood_idol_fog_spawned_once = true;
                                             } else {
-                                                println!("3% fog-of-war spawn triggered! Spawning BLOOD IDOL in field {:?}", sbrx_map_system.current_field_id);
+                                                println!("3% fog-of-war spawn triggered! Spawning BLOOD IDOL in field {:?}", world.map_system.current_field_id);
                                             }
-                                            let (base_hp, base_speed) = cpu_entities
+                                            let (base_hp, base_speed) = world.cpu_entities
                                                 .iter()
                                                 .find(|e| {
                                                     e.variant == CpuVariant::GiantMantis
@@ -4039,32 +3468,32 @@ fn main() {
                                                 })
                                                 .map_or((250.0, 150.0), |m| (m.max_hp, m.speed));
                                             let mut spirit = CpuEntity::new_blood_idol(line_y, base_hp, base_speed);
-                                            let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+                                            let aptitude = get_field_aptitude(world.map_system.current_field_id);
                                             spirit.max_hp *= aptitude;
                                             spirit.current_hp = spirit.max_hp;
                                             spirit.damage_value *= aptitude;
                                             spirit.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                            cpu_entities.push(spirit);
+                                            world.cpu_entities.push(spirit);
                                         }
                                     }
                                     check_and_display_demonic_presence(
-                                        &sbrx_map_system.current_field_id,
-                                        &cpu_entities,
+                                        &world.map_system.current_field_id,
+                                        &world.cpu_entities,
                                         &mut chatbox,
                                         &fog_of_war,
                                     );
                                 }
                             } else {
-                                let hit_boundary = fighter.x >= config::boundaries::MAX_X
-                                    || fighter.x <= config::boundaries::MIN_X
-                                    || fighter.y >= config::boundaries::MAX_Y
-                                    || fighter.y <= config::boundaries::MIN_Y;
+                                let hit_boundary = world.fighter.x >= config::boundaries::MAX_X
+                                    || world.fighter.x <= config::boundaries::MIN_X
+                                    || world.fighter.y >= config::boundaries::MAX_Y
+                                    || world.fighter.y <= config::boundaries::MIN_Y;
                                 if hit_boundary && boundary_warning_cooldown <= 0.0 {
-                                    fighter.x = fighter.x.clamp(
+                                    world.fighter.x = world.fighter.x.clamp(
                                         config::boundaries::MIN_X + 10.0,
                                         config::boundaries::MAX_X - 10.0,
                                     );
-                                    fighter.y = fighter.y.clamp(
+                                    world.fighter.y = world.fighter.y.clamp(
                                         config::boundaries::MIN_Y + 10.0,
                                         config::boundaries::MAX_Y - 10.0,
                                     );
@@ -4077,33 +3506,33 @@ fn main() {
                             }
                         }
                     }
-                    if current_area.is_none()
-                        && sbrx_map_system.current_field_id == SbrxFieldId(1, 0)
+                    if world.current_area.is_none()
+                        && world.map_system.current_field_id == SbrxFieldId(1, 0)
                         && raptor_nests.is_empty()
                     {
                         println!("Spawning a raptor nest in FLATLINE_field.x1y0");
                         let nest = RaptorNest::new();
-                        if cpu_entities.len() < 10 {
+                        if world.cpu_entities.len() < 10 {
                                     let mut r1 = CpuEntity::new_raptor(nest.x - 75.0, nest.y);
                                     r1.max_hp *= aptitude;
                                     r1.current_hp = r1.max_hp;
                                     r1.damage_value *= aptitude;
                                     r1.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                    cpu_entities.push(r1);
+                                    world.cpu_entities.push(r1);
                         }
-                        if cpu_entities.len() < 10 {
+                        if world.cpu_entities.len() < 10 {
                                     let mut r2 = CpuEntity::new_raptor(nest.x + 75.0, nest.y);
                                     r2.max_hp *= aptitude;
                                     r2.current_hp = r2.max_hp;
                                     r2.damage_value *= aptitude;
                                     r2.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                    cpu_entities.push(r2);
+                                    world.cpu_entities.push(r2);
                         }
                         raptor_nests.push(nest);
                     }
                     // Spawn bunker entrance (only once)
-                    if current_area.is_none()
-                        && sbrx_map_system.current_field_id == SbrxFieldId(-25, 25)
+                    if world.current_area.is_none()
+                        && world.map_system.current_field_id == SbrxFieldId(-25, 25)
                         && fort_silo_bunkers.is_empty()
                     {
                         println!("Spawning bunker entrance at Fort Silo field x-25 y25");
@@ -4113,21 +3542,21 @@ fn main() {
                         let bunker_y = 2800.0; // was 3000
                         fort_silo_bunkers.push((bunker_x, bunker_y));
                     }
-                    if current_area.is_none()
-                        && sbrx_map_system.current_field_id == SbrxFieldId(-25, 25)
-                        && !task_system.is_task_complete("SPEAK TO THE GRAND COMMANDER")
+                    if world.current_area.is_none()
+                        && world.map_system.current_field_id == SbrxFieldId(-25, 25)
+                        && !world.task_system.is_task_complete("SPEAK TO THE GRAND COMMANDER")
                     {
-                        let has_light_reavers = cpu_entities
+                        let has_light_reavers = world.cpu_entities
                             .iter()
                             .any(|e| e.variant == CpuVariant::LightReaver);
-                        let has_night_reavers = cpu_entities
+                        let has_night_reavers = world.cpu_entities
                             .iter()
                             .any(|e| e.variant == CpuVariant::NightReaver);
-                        if (!has_light_reavers || !has_night_reavers) && CPU_ENABLED {
+                        if (!has_light_reavers || !has_night_reavers) && game_config.cpu_enabled {
                             println!("Spawning Reavers in Fort Silo field x-25 y25");
-							let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+							let aptitude = get_field_aptitude(world.map_system.current_field_id);
                             for _ in 0..3 {
-                                if cpu_entities.len() < 10 {
+                                if world.cpu_entities.len() < 10 {
                                     let reaver_x = safe_gen_range(MIN_X, MAX_X, "LightReaver x");
                                     let reaver_y = safe_gen_range(MIN_Y, MAX_Y, "LightReaver y");
 									let mut reaver = CpuEntity::new_light_reaver(reaver_x, reaver_y);
@@ -4135,11 +3564,11 @@ fn main() {
 									reaver.current_hp = reaver.max_hp;
 									reaver.damage_value *= aptitude;
 									reaver.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-									cpu_entities.push(reaver);
+									world.cpu_entities.push(reaver);
                                 }
                             }
                             for _ in 0..3 {
-                                if cpu_entities.len() < 10 {
+                                if world.cpu_entities.len() < 10 {
                                     let reaver_x = safe_gen_range(MIN_X, MAX_X, "NightReaver x");
                                     let reaver_y = safe_gen_range(MIN_Y, MAX_Y, "NightReaver y");
 									let mut reaver = CpuEntity::new_night_reaver(reaver_x, reaver_y);
@@ -4147,611 +3576,223 @@ fn main() {
 									reaver.current_hp = reaver.max_hp;
 									reaver.damage_value *= aptitude;
 									reaver.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-									cpu_entities.push(reaver);
+									world.cpu_entities.push(reaver);
                                 }
                             }
                         }
                     }
-                    fighter.update(dt, line_y);
-                    fixed_crater.x = fighter.x;
-                    fixed_crater.y = fighter.y;
+                    world.fighter.update(dt, line_y);
+                    for skill_type in world.fighter.sync_skills() {
+                        chatbox.add_interaction(vec![(
+                            &format!("SKILL UNLOCKED: {}", skill_type.label()),
+                            MessageType::Stats,
+                        )]);
+                    }
+                    world.fixed_crater.x = world.fighter.x;
+                    world.fixed_crater.y = world.fighter.y;
                     stars.iter_mut().for_each(|s| s.update(dt));
 					
-                    // Unconditional update of racer states and sound-stopping checks
-                    for cr in &mut cpu_racers {
-                        let in_rut_zone = collision_barrier_manager.check_rut(
-                            &sbrx_map_system.current_field_id,
-                            cr.x,
-                            cr.y,
-                        );
-                        let rut_mult = if in_rut_zone { 0.85 } else { 1.0 };
-                        let is_on_rt = sbrx_map_system.current_field_id == SbrxFieldId(0, 0);
-                        cr.update(dt, rut_mult, fighter.x, fighter.y, &audio_manager, is_on_rt);
-                    }
-
-                    // Keep player collision, damage, and attack triggers locked to field x0y0
-                    if sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
-                        let mut player_died_this_frame = false;
-                        for cr in &mut cpu_racers {
-                            let dx = fighter.x - cr.x;
-                            let dy = fighter.y - cr.y;
-                            let dist = (dx * dx + dy * dy).sqrt();
- 								
-                            // RUSH attack trigger
-                            if dist < 400.0 && cr.rush_cooldown <= 0.0 && cr.stun_timer <= 0.0 && !cr.is_crashed && !cr.rush_active && cr.entity_state == EntityState::Hostile {
-                                cr.rush_active = true;
-                                cr.rush_timer = 0.25;
-                                cr.rush_cooldown = 3.0; // cpu_racer rush attack cooldown
-                                cr.rush_has_hit = false;
-                                
-                                if dist > 0.0 {
-                                    cr.rush_dir_x = dx / dist;
-                                    cr.rush_dir_y = dy / dist;
-                                    cr.facing_left = cr.rush_dir_x < 0.0;
-                                } else {
-                                    cr.rush_dir_x = 1.0;
-                                    cr.rush_dir_y = 0.0;
-                                }
- 
-                                audio_manager.play_sound_effect("rush").ok();
+                    // Waves, CPU racers, CPU AI and skills, projectiles, contact damage and
+                    // defeated CPUs all run in the shared World (see simulation.rs).
+                    let world_rules = WorldRules {
+                        cpus_frozen: racetrack_active && !endless_arena_mode_active,
+                        endless_arena: endless_arena_mode_active,
+                    };
+                    world.update(dt, &world_rules);
+                    let mut raptor_died_in_area = false;
+                    let mut t_rex_was_defeated_this_frame = false;
+                    for world_event in world.drain_events() {
+                        match world_event {
+                            WorldEvent::WaveFrenzy => {
+                                chatbox.add_interaction(vec![(
+                                    "ENEMY FEROCITY INCREASED",
+                                    MessageType::Notification,
+                                )]);
                             }
- 
-                            // Active RUSH collision check
-                            if cr.rush_active && !cr.rush_has_hit && dist < COLLISION_THRESHOLD {
-                                cr.rush_has_hit = true;
- 
-                                // Process block/negation
-                                let mut attack_negated = false;
-                                if block_system.active && !block_system.block_broken && !block_system.block_fatigue {
-                                   attack_negated = block_system.process_projectile_block(&mut fighter, &audio_manager, game_time);
+                            WorldEvent::WavesCleared { floor } => {
+                                if let Some(floor) = floor {
+                                    // Mark this floor's waves as completed
+                                    completed_bunker_waves.insert(floor);
+                                    println!("[WAVE SYSTEM] Bunker floor {} marked as complete.", floor);
                                 }
- 
-                                if !attack_negated && fighter.invincible_timer <= 0.0 && !block_system.block_broken {
-                                    let mut rng = rand::rng();
-                                    let roll: f64 = rng.random();
-                                    if roll < fighter.stats.defense.auto_dodge {
-                                        attack_negated = true;
-                                        audio_manager.play_sound_effect("boost").ok();
-                                        damage_texts.push(DamageText {
-                                            text: "DODGE".to_string(),
-                                            x: fighter.x, y: fighter.y - 90.0,
-                                            color: [0.0, 0.8, 1.0, 1.0], lifetime: 0.5,
-                                        });
-                                    } else if roll < fighter.stats.defense.auto_dodge + fighter.stats.defense.auto_block {
-                                        attack_negated = true;
-                                        audio_manager.play_sound_effect("block").ok();
-                                        damage_texts.push(DamageText {
-                                            text: "BLOCK".to_string(),
-                                            x: fighter.x, y: fighter.y - 90.0,
-                                            color: [1.0, 1.0, 1.0, 1.0], lifetime: 0.5,
-                                        });
-                                    }
-                                }
- 
-                                if !attack_negated && !player_died_this_frame {
-                                    if fighter.invincible_timer <= 0.0 {
-                                        let damage_chunk = cr.stats.attack.melee_damage;
-                                        let combo_dr_multiplier = combo_system.get_damage_intake_multiplier();
-                                        let final_damage = damage_chunk * block_system.get_damage_multiplier() * combo_dr_multiplier;
-                                        fighter.current_hp -= final_damage;
- 
-                                        damage_texts.push(DamageText {
-                                            text: format!("{:.0}", damage_chunk),
-                                            x: fighter.x,
-                                            y: fighter.y - 70.0,
-                                            color: [1.0, 1.0, 0.0, 1.0], // Yellow
-                                            lifetime: 0.25,
-                                        });
- 
-                                        // Apply bleed to player
-                                        fighter.bleed_effect = Some(crate::entities::cpu_entity::BleedEffect::new(50.0));
-                                        damage_texts.push(DamageText {
-                                            text: "BLEED".to_string(),
-                                            x: fighter.x,
-                                            y: fighter.y - 90.0,
-                                            color: [1.0, 0.0, 0.0, 1.0],
-                                            lifetime: 0.5,
-                                        });
- 
-                                        if fighter.current_hp <= 0.0 {
-                                            player_died_this_frame = true;
-                                            lmb_held = false;
-                                            melee_rapid_fire_timer = 0.0;
-                                            soldier_rapid_fire_timer = 0.0;
-                                            if let Some(sink) = bike_accelerate_sound_sink.take() { sink.stop(); }
-                                            if let Some(sink) = bike_idle_sound_sink.take() { sink.stop(); }
- 
-                                            fighter_hp_map.insert(fighter.fighter_type, 0.0);
- 
-                                            if fighter.state == RacerState::OnBike {
-                                                fighter.state = RacerState::OnFoot;
-                                                if fighter.fighter_type != FighterType::Raptor {
-                                                    sbrx_bike.respawn(fighter.x, fighter.y);
-                                                }
-                                            }
- 
-                                            block_break_animation_active = false;
-                                            block_system = BlockSystem::new(20);
- 
-                                            let mut group_members = vec![FighterType::Racer];
-                                            if soldier_has_joined { group_members.push(FighterType::Soldier); }
-                                            if raptor_has_joined { group_members.push(FighterType::Raptor); }
- 
-                                            let has_survivors = group_members.iter().any(|ft| !downed_fighters.contains(ft) && *ft != fighter.fighter_type);
- 
-                                            if group_members.len() > 1 && has_survivors {
-                                                game_state = GameState::DeathScreenGroup {
-                                                    death_type: DeathType::Crashed,
-                                                    downed_fighter_type: fighter.fighter_type,
-                                                };
-                                                death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
-                                                if !downed_fighters.contains(&fighter.fighter_type) {
-                                                    downed_fighters.push(fighter.fighter_type);
-                                                }
-                                                revival_kill_score = 0;
-                                            } else {
-                                                if !downed_fighters.contains(&fighter.fighter_type) {
-                                                    downed_fighters.push(fighter.fighter_type);
-                                                }
-                                                game_state = GameState::DeathScreen(DeathType::Crashed);
-                                                death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
-                                            }
-                                            audio_manager.play_sound_effect("death").ok();
-                                        }
+                                chatbox.add_interaction(vec![(
+                                    "ALL WAVES CLEARED. EXITS UNLOCKED.",
+                                    MessageType::Notification,
+                                )]);
+                                // Check if all bunker waves have been cleared for the first time
+                                if !bunker_waves_fully_completed {
+                                    let required_floors: HashSet<i32> =
+                                        [1, 0, -1, -2].iter().cloned().collect();
+                                    if required_floors.is_subset(&completed_bunker_waves) {
+                                        bunker_waves_fully_completed = true;
+                                        chatbox.add_interaction(vec![(
+                                            "BUNKER SECURED. ALL FLOORS ACCESSIBLE.",
+                                            MessageType::Notification,
+                                        )]);
                                     }
                                 }
-                            }								
-								
-								// Ranged attack trigger
-								if dist < 800.0 && dist >= 100.0 && cr.ranged_cooldown <= 0.0 && cr.stun_timer <= 0.0 && !cr.is_crashed && !cr.rush_active && cr.entity_state == EntityState::Hostile {
-									cr.ranged_cooldown = 1.5; // cpu_racer ranged attack cooldown
-									cr.ranged_shoot_visible = true;
-									cr.ranged_shoot_timer = 0.1;
-									cr.ranged_shoot_start_x = cr.x;
-									cr.ranged_shoot_start_y = cr.y;
-									cr.ranged_shoot_target_x = fighter.x;
-									cr.ranged_shoot_target_y = fighter.y;
-									cr.ranged_animation_timer = 0.25;
-	 
-									audio_manager.play_sound_effect("ranged").ok();
-	 
-									// Process block/negation
-									let mut attack_negated = false;
-									if block_system.active && !block_system.block_broken && !block_system.block_fatigue {
-										attack_negated = block_system.process_projectile_block(&mut fighter, &audio_manager, game_time);
-									}
-	 
-									if !attack_negated && fighter.invincible_timer <= 0.0 && !block_system.block_broken {
-										let mut rng = rand::rng();
-										let roll: f64 = rng.random();
-										if roll < fighter.stats.defense.auto_dodge {
-											attack_negated = true;
-											audio_manager.play_sound_effect("boost").ok();
-											damage_texts.push(DamageText {
-												text: "DODGE".to_string(),
-												x: fighter.x, y: fighter.y - 90.0,
-												color: [0.0, 0.8, 1.0, 1.0], lifetime: 0.5,
-											});
-										} else if roll < fighter.stats.defense.auto_dodge + fighter.stats.defense.auto_block {
-											attack_negated = true;
-											audio_manager.play_sound_effect("block").ok();
-											damage_texts.push(DamageText {
-												text: "BLOCK".to_string(),
-												x: fighter.x, y: fighter.y - 90.0,
-												color: [1.0, 1.0, 1.0, 1.0], lifetime: 0.5,
-											});
-										}
-									}
-	 
-									if !attack_negated && !player_died_this_frame {
-										if fighter.invincible_timer <= 0.0 {
-											let damage_chunk = cr.stats.attack.ranged_damage;
-											let combo_dr_multiplier = combo_system.get_damage_intake_multiplier();
-											let final_damage = damage_chunk * block_system.get_damage_multiplier() * combo_dr_multiplier;
-											fighter.current_hp -= final_damage;
-	 
-											damage_texts.push(DamageText {
-												text: format!("{:.0}", damage_chunk),
-												x: fighter.x,
-												y: fighter.y - 70.0,
-												color: [1.0, 1.0, 1.0, 1.0],
-												lifetime: 0.25,
-											});
-	 
-											// Apply bleed to player
-											fighter.bleed_effect = Some(crate::entities::cpu_entity::BleedEffect::new(50.0));
-											damage_texts.push(DamageText {
-												text: "BLEED".to_string(),
-												x: fighter.x,
-												y: fighter.y - 90.0,
-												color: [1.0, 0.0, 0.0, 1.0],
-												lifetime: 0.5,
-											});
-	 
-											if fighter.current_hp <= 0.0 {
-												player_died_this_frame = true;
-												lmb_held = false;
-												melee_rapid_fire_timer = 0.0;
-												soldier_rapid_fire_timer = 0.0;
-												if let Some(sink) = bike_accelerate_sound_sink.take() { sink.stop(); }
-												if let Some(sink) = bike_idle_sound_sink.take() { sink.stop(); }
-	 
-												fighter_hp_map.insert(fighter.fighter_type, 0.0);
-	 
-												if fighter.state == RacerState::OnBike {
-													fighter.state = RacerState::OnFoot;
-													if fighter.fighter_type != FighterType::Raptor {
-														sbrx_bike.respawn(fighter.x, fighter.y);
-													}
-												}
-	 
-												block_break_animation_active = false;
-												block_system = BlockSystem::new(20);
-	 
-												let mut group_members = vec![FighterType::Racer];
-												if soldier_has_joined { group_members.push(FighterType::Soldier); }
-												if raptor_has_joined { group_members.push(FighterType::Raptor); }
-	 
-												let has_survivors = group_members.iter().any(|ft| !downed_fighters.contains(ft) && *ft != fighter.fighter_type);
-	 
-												if group_members.len() > 1 && has_survivors {
-													game_state = GameState::DeathScreenGroup {
-														death_type: DeathType::Crashed,
-														downed_fighter_type: fighter.fighter_type,
-													};
-													death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
-													if !downed_fighters.contains(&fighter.fighter_type) {
-														downed_fighters.push(fighter.fighter_type);
-													}
-													revival_kill_score = 0;
-												} else {
-													if !downed_fighters.contains(&fighter.fighter_type) {
-														downed_fighters.push(fighter.fighter_type);
-													}
-													game_state = GameState::DeathScreen(DeathType::Crashed);
-													death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
-												}
-												audio_manager.play_sound_effect("death").ok();
-											}
-										}
-									}
-								}
-	 
-								if !player_died_this_frame
-									&& cr.check_collision(
-										fighter.x,
-										fighter.y,
-										block_system.active,
-										&audio_manager,
-									)
-								{
-									let mut _attack_negated = false;
-									if block_system.active && !block_system.block_broken && !block_system.block_fatigue {
-										_attack_negated = block_system.process_projectile_block(&mut fighter, &audio_manager, game_time);
-									}
-								}
-							}
-						}
-						
-						if sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
-							let mut player_died_this_frame = false;
-							for cr in &mut cpu_racers {
-								if !player_died_this_frame
-									&& cr.check_collision(
-										fighter.x,
-										fighter.y,
-										block_system.active,
-										&audio_manager,
-									)
-								{
-									let mut attack_negated = false;
-									if block_system.active && !block_system.block_broken && !block_system.block_fatigue {
-										attack_negated = block_system.process_projectile_block(&mut fighter, &audio_manager, game_time);
-									}
-	 
-									// Passive defense checks (Auto-block/Auto-dodge)
-									if !attack_negated && fighter.invincible_timer <= 0.0 && !block_system.block_broken {
-										let mut rng = rand::rng();
-										let roll: f64 = rng.random();
-										let can_show_passive_anim = !is_high_priority_animation_active(
-											rush_active, strike_animation_timer, block_system.active, block_system.rmb_held
-										) && !movement_active && !backpedal_active;
-	 
-										if roll < fighter.stats.defense.auto_dodge {
-											attack_negated = true;
-											audio_manager.play_sound_effect("boost").ok();
-											damage_texts.push(DamageText {
-												text: "DODGE".to_string(),
-												x: fighter.x, y: fighter.y - 90.0,
-												color: [0.0, 0.8, 1.0, 1.0], lifetime: 0.5,
-											});
-											if can_show_passive_anim {
-												current_racer_texture = current_backpedal_texture;
-												strike_animation_timer = 0.25;
-											}
-										} else if roll < fighter.stats.defense.auto_dodge + fighter.stats.defense.auto_block {
-											attack_negated = true;
-											audio_manager.play_sound_effect("block").ok();
-											damage_texts.push(DamageText {
-												text: "BLOCK".to_string(),
-												x: fighter.x, y: fighter.y - 90.0,
-												color: [1.0, 1.0, 1.0, 1.0], lifetime: 0.5,
-											});
-											if can_show_passive_anim {
-												current_racer_texture = current_block_texture;
-												strike_animation_timer = 0.25;
-											}
-										}
-									}
-	 
-									if !attack_negated && !player_died_this_frame {
-										if cr.damage_display_cooldown <= 0.0 {
-											if fighter.invincible_timer <= 0.0 {
-												let damage_chunk = cr.stats.attack.melee_damage;
-												let combo_dr_multiplier = combo_system.get_damage_intake_multiplier();
-												let final_damage = damage_chunk * block_system.get_damage_multiplier() * combo_dr_multiplier;
-												fighter.current_hp -= final_damage;
-												cr.damage_display_cooldown = 0.125;
-												
-												damage_texts.push(DamageText {
-													text: format!("{:.0}", damage_chunk),
-													x: fighter.x,
-													y: fighter.y - 70.0,
-													color: [1.0, 1.0, 1.0, 1.0],
-													lifetime: 0.25,
-												});	
-	 
-												if fighter.current_hp <= 0.0 {
-													player_died_this_frame = true;
-													lmb_held = false;
-													melee_rapid_fire_timer = 0.0;
-													soldier_rapid_fire_timer = 0.0;
-													if let Some(sink) = bike_accelerate_sound_sink.take() { sink.stop(); }
-													if let Some(sink) = bike_idle_sound_sink.take() { sink.stop(); }
-	 
-													fighter_hp_map.insert(fighter.fighter_type, 0.0);
-	 
-													if fighter.state == RacerState::OnBike {
-														fighter.state = RacerState::OnFoot;
-														if fighter.fighter_type != FighterType::Raptor {
-															sbrx_bike.respawn(fighter.x, fighter.y);
-														}
-													}
-	 
-													block_break_animation_active = false;
-													block_system = BlockSystem::new(20);
-	 
-													let mut group_members = vec![FighterType::Racer];
-													if soldier_has_joined { group_members.push(FighterType::Soldier); }
-													if raptor_has_joined { group_members.push(FighterType::Raptor); }
-	 
-													let has_survivors = group_members.iter().any(|ft| !downed_fighters.contains(ft) && *ft != fighter.fighter_type);
-	 
-													if group_members.len() > 1 && has_survivors {
-														game_state = GameState::DeathScreenGroup {
-															death_type: DeathType::Crashed,
-															downed_fighter_type: fighter.fighter_type,
-														};
-														death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
-														if !downed_fighters.contains(&fighter.fighter_type) {
-															downed_fighters.push(fighter.fighter_type);
-														}
-														revival_kill_score = 0;
-													} else {
-														if !downed_fighters.contains(&fighter.fighter_type) {
-															downed_fighters.push(fighter.fighter_type);
-														}
-														game_state = GameState::DeathScreen(DeathType::Crashed);
-														death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
-													}
-													audio_manager.play_sound_effect("death").ok();
-												}
-											}
-										}
-									}
-								}
-							}
-						}											
-                    			
-
-                    for cpu in &mut cpu_entities {
-                        if racetrack_active && !endless_arena_mode_active {
-                            continue; // Skip AI logic for all CPUs
-                        }
-                        let result = cpu.update(fighter.x, fighter.y, dt, line_y, &audio_manager);
-
-                        // Handle skill effects returned from the update
-                        if let Some(damage) = result.damage_to_player {
-                            // Check if blocked first
-                            if block_system.active
-                                && !block_system.block_broken
-                                && !block_system.block_fatigue
-                            {
-                                audio_manager.play_sound_effect("block").ok();
-                                // Consume block point for skill block? Or just negate damage.
-                                // Let's negate damage for now to fix the bug.
-                            } else if fighter.invincible_timer <= 0.0 {
-                                fighter.current_hp -= damage;
-                                damage_texts.push(DamageText {
-                                    text: format!("{:.0}", damage),
-                                    x: fighter.x,
-                                    y: fighter.y - 70.0,
-                                    color: [1.0, 0.0, 0.0, 1.0], // Red for skill damage
-                                    lifetime: 0.5,
-                                });
-
-                                // Dismount if hit on bike
-                                if fighter.state == RacerState::OnBike {
-                                    println!("Dismounted by enemy skill damage!");
-                                    fighter.state = RacerState::OnFoot;
-
-                                    if fighter.fighter_type != FighterType::Raptor {
-                                        sbrx_bike.respawn(fighter.x, fighter.y);
-                                    }
-
-                                    let tex_set = match fighter.fighter_type {
-										FighterType::Racer => {
-											if fighter.racer_variant == 1 { &racer2_textures } else { &racer_textures }
-										},
-                                        FighterType::Soldier => &soldier_textures,
-                                        FighterType::Raptor => &raptor_textures,
+                            }
+                            WorldEvent::Avoided(outcome) => {
+                                // Visual Priority: Only show passive anims if player isn't busy with inputs
+                                let can_show_passive_anim = !is_high_priority_animation_active(
+                                    rush_active, strike_animation_timer, world.block_system.active, world.block_system.rmb_held
+                                ) && !movement_active && !backpedal_active;
+                                if can_show_passive_anim {
+                                    current_racer_texture = if outcome == damage::Outcome::Dodged {
+                                        current_backpedal_texture
+                                    } else {
+                                        current_block_texture
                                     };
-                                    update_current_textures(
-                                        &fighter,
-                                        tex_set,
-                                        &mut current_idle_texture,
-                                        &mut current_fwd_texture,
-                                        &mut current_backpedal_texture,
-                                        &mut current_block_texture,
-                                        &mut current_block_break_texture,
-                                        &mut current_ranged_texture,
-                                        &mut current_ranged_marker_texture,
-                                        &mut current_ranged_blur_texture,
-                                        &mut current_rush_texture,
-                                        &mut current_strike_textures,
-										shift_held,
-                                    );
-                                    current_racer_texture = current_idle_texture;
+                                    strike_animation_timer = 0.25;
                                 }
                             }
-                        }
-
-                        if let Some(effect) = result.visual_effect {
-                            match effect {
-                                VisualEffect::FlickerStrike {
-                                    from_x,
-                                    from_y,
-                                    to_x,
-                                    to_y,
-                                } => {
-                                    // Trigger a strike visual at the destination
-                                    strike.trigger(to_x, to_y);
-                                    strike.timer = 0.15; // A quick flash
-
-                                    active_visual_effects.push(FlickerStrikeEffectInstance {
-                                        x: from_x,
-                                        y: from_y,
-                                        lifetime: 0.2, // Short duration for the effect
-                                        max_lifetime: 0.2,
-                                    });
+                            WorldEvent::Dismounted { announce } => {
+                                if world.fighter.fighter_type != FighterType::Raptor {
+                                    sbrx_bike.respawn(world.fighter.x, world.fighter.y);
                                 }
-                                VisualEffect::ShootPulseOrb {
-                                    start_x,
-                                    start_y,
-                                    target_x,
-                                    target_y,
-                                } => {
-                                    pulse_orbs
-                                        .push(PulseOrb::new(start_x, start_y, target_x, target_y));
-                                    audio_manager.play_sound_effect("firearm").ok();
+                                let tex_set = match world.fighter.fighter_type {
+                                    FighterType::Racer => {
+                                        if world.fighter.racer_variant == 1 { &racer2_textures } else { &racer_textures }
+                                    }
+                                    FighterType::Soldier => &soldier_textures,
+                                    FighterType::Raptor => &raptor_textures,
+                                };
+                                update_current_textures(
+                                    &world.fighter,
+                                    tex_set,
+                                    &mut current_idle_texture,
+                                    &mut current_fwd_texture,
+                                    &mut current_backpedal_texture,
+                                    &mut current_block_texture,
+                                    &mut current_block_break_texture,
+                                    &mut current_ranged_texture,
+                                    &mut current_ranged_marker_texture,
+                                    &mut current_ranged_blur_texture,
+                                    &mut current_rush_texture,
+                                    &mut current_strike_textures,
+                                    shift_held && !announce, // Force standard textures on a contact dismount
+                                );
+                                current_racer_texture = current_idle_texture;
+                                if announce {
+                                    chatbox.add_interaction(vec![("DISMOUNTED", MessageType::Warning)]);
                                 }
                             }
-                        }
-                    }
-
-                    // Update Pulse Orbs
-                    for orb in &mut pulse_orbs {
-                        orb.update(dt);
-
-                        // Check collision with player
-                        let dx = orb.x - fighter.x;
-                        let dy = orb.y - fighter.y;
-                        let dist_sq = dx * dx + dy * dy;
-                        let collision_radius = orb.radius + 25.0; // Approx player radius
-
-                        if orb.active && dist_sq < collision_radius * collision_radius {
-                            // Check for Block
-                            let projectile_blocked = block_system.process_projectile_block(
-                                &mut fighter,
-                                &audio_manager,
-                                game_time,
-                            );
-
-                            if projectile_blocked {
-                                orb.active = false;
-                            } else if fighter.invincible_timer <= 0.0 {
-                                let damage = 20.0; // pulse_orb damage
-                                fighter.current_hp -= damage;
-                                audio_manager.play_sound_effect("hit").ok();
-                                damage_texts.push(DamageText {
-                                    text: format!("{:.0}", damage),
-                                    x: fighter.x,
-                                    y: fighter.y - 70.0,
-                                    color: [1.0, 0.0, 0.0, 1.0],
-                                    lifetime: 0.5,
+                            WorldEvent::Flicker { x, y } => {
+                                active_visual_effects.push(FlickerStrikeEffectInstance {
+                                    x,
+                                    y,
+                                    lifetime: 0.2, // Short duration for the effect
+                                    max_lifetime: 0.2,
                                 });
-
-                                orb.active = false;
-
-                                // Pulse Orb Death Logic
-                                if fighter.current_hp <= 0.0 {
-                                    lmb_held = false;
-                                    melee_rapid_fire_timer = 0.0;
-                                    soldier_rapid_fire_timer = 0.0;
-									
-                                    if let Some(sink) = bike_accelerate_sound_sink.take() { sink.stop(); }
-                                    if let Some(sink) = bike_idle_sound_sink.take() { sink.stop(); }									
-
-                                    fighter_hp_map.insert(fighter.fighter_type, 0.0);
-
-                                    if fighter.state == RacerState::OnBike {
-                                        fighter.state = RacerState::OnFoot;
-                                        if fighter.fighter_type != FighterType::Raptor {
-                                            sbrx_bike.respawn(fighter.x, fighter.y);
-                                        }
-                                    }
-
-                                    block_break_animation_active = false;
-                                    block_system = BlockSystem::new(20);
-
-                                    let mut group_members = vec![FighterType::Racer];
-                                    if soldier_has_joined {
-                                        group_members.push(FighterType::Soldier);
+                            }
+                            WorldEvent::CpuKilled { variant, x, y } => {
+                                spawn_particles(&mut particles, x, y, PARTICLE_COUNT_CPU);
+                                if endless_arena_mode_active {
+                                    arena_kill_count = (arena_kill_count + variant.score_value()).min(999999);
+                                    // Milestones for every 25 points
+                                    let current_milestone = arena_kill_count / 25;
+                                    if current_milestone > last_arena_milestone && current_milestone > 0 {
+                                        last_arena_milestone = current_milestone;
+                                        let damage_bonus = 1.25;
+                                        world.fighter.melee_damage = world.fighter.stats.attack.melee_damage + damage_bonus;
+                                        world.fighter.ranged_damage = world.fighter.stats.attack.ranged_damage + damage_bonus;
+                                        world.fighter.invincible_timer = 5.0;
+                                        chatbox.add_interaction(vec![
+                                            ("KILL BONUS: ATOMIC-STATE", MessageType::Warning),
+                                        ]);
+                                        world.audio.play_sound_effect("boost").ok();
                                     }
-                                    if raptor_has_joined {
-                                        group_members.push(FighterType::Raptor);
+                                }
+                                if !downed_fighters.is_empty() {
+                                    revival_kill_score += 1;
+                                }
+                                match variant {
+                                    CpuVariant::Raptor => raptor_died_in_area |= world.current_area.is_some(),
+                                    CpuVariant::TRex => t_rex_was_defeated_this_frame = true,
+                                    CpuVariant::RazorFiend => razor_fiend_defeated_flag = true,
+                                    _ => {}
+                                }
+                            }
+                            WorldEvent::LeveledUp => {
+                                lvl_up_state = LvlUpState::PendingTab {
+                                    fighter_type: world.fighter.fighter_type,
+                                };
+                            }
+                            WorldEvent::FighterDowned(death_type) => {
+                                lmb_held = false; // Stop rapid fire on death
+                                soldier_rapid_fire_timer = 0.0;
+                                if let Some(sink) = bike_accelerate_sound_sink.take() { sink.stop(); }
+                                if let Some(sink) = bike_idle_sound_sink.take() { sink.stop(); }
+
+                                fighter_hp_map.insert(world.fighter.fighter_type, 0.0);
+                                if world.fighter.state == RacerState::OnBike {
+                                    world.fighter.state = RacerState::OnFoot;
+                                    if world.fighter.fighter_type != FighterType::Raptor {
+                                        sbrx_bike.respawn(world.fighter.x, world.fighter.y);
                                     }
+                                }
+                                block_break_animation_active = false;
 
-                                    let has_survivors = group_members.iter().any(|ft| {
-                                        !downed_fighters.contains(ft) && *ft != fighter.fighter_type
-                                    });
-
-                                    let death_type = DeathType::NightReaver; // Pulse Orb is a Night Reaver skill
-
-                                    if group_members.len() > 1 && has_survivors {
-                                        game_state = GameState::DeathScreenGroup {
-                                            death_type,
-                                            downed_fighter_type: fighter.fighter_type,
-                                        };
-                                        death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
-                                        if !downed_fighters.contains(&fighter.fighter_type) {
-                                            downed_fighters.push(fighter.fighter_type);
-                                        }
-                                        revival_kill_score = 0;
-                                    } else {
-                                        if !downed_fighters.contains(&fighter.fighter_type) {
-                                            downed_fighters.push(fighter.fighter_type);
-                                        }
-                                        game_state = GameState::DeathScreen(death_type);
-                                        death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
+                                let mut group_members = vec![FighterType::Racer];
+                                if soldier_has_joined { group_members.push(FighterType::Soldier); }
+                                if raptor_has_joined { group_members.push(FighterType::Raptor); }
+                                let has_survivors = group_members
+                                    .iter()
+                                    .any(|ft| !downed_fighters.contains(ft) && *ft != world.fighter.fighter_type);
+                                let group_continues = group_members.len() > 1 && has_survivors;
+
+                                game_state = if group_continues {
+                                    revival_kill_score = 0;
+                                    GameState::DeathScreenGroup {
+                                        death_type,
+                                        downed_fighter_type: world.fighter.fighter_type,
                                     }
-                                    audio_manager.play_sound_effect("death").ok();
+                                } else {
+                                    GameState::DeathScreen(death_type)
+                                };
+                                world.game_events.publish(GameEvent::FighterDowned {
+                                    fighter_type: world.fighter.fighter_type,
+                                    death_type,
+                                    group_continues,
+                                });
+                                death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
+                                if !downed_fighters.contains(&world.fighter.fighter_type) {
+                                    downed_fighters.push(world.fighter.fighter_type);
                                 }
                             }
                         }
                     }
-                    for i in (0..pulse_orbs.len()).rev() {
-                        if !pulse_orbs[i].active {
-                            pulse_orbs.swap_remove(i);
+
+                    if raptor_died_in_area {
+                        let live_raptors = world
+                            .cpu_entities
+                            .iter()
+                            .filter(|c| c.variant == CpuVariant::Raptor && !c.is_dead())
+                            .count();
+                        if live_raptors == 0 {
+                            if !world.task_system.raptor_nest_cleared {
+                                world.task_system.mark_raptor_nest_cleared();
+                                println!("All raptors in nest cleared for the first time! raptor can be rescued.");
+                                raptor_is_trapped_in_nest = true;
+                            }
+                            println!("Raptor Nest cleared. A T-Rex will be waiting outside.");
+                            t_rex_spawn_pending = true;
+                        }
+                    }
+                    if t_rex_was_defeated_this_frame {
+                        println!("T-Rex defeated. Restoring default CPU spawn (Giant Mantis).");
+                        t_rex_is_active = false;
+                        if game_config.cpu_enabled && world.cpu_entities.len() < 10 {
+                            world.add_cpu(CpuEntity::new_giant_mantis(line_y));
                         }
                     }
 
-                    let is_on_bike = fighter.state == RacerState::OnBike;
+                    let is_on_bike = world.fighter.state == RacerState::OnBike;
                     let is_moving_on_bike_input =
                         key_w_pressed || key_s_pressed || key_a_pressed || key_d_pressed;
-                    let is_stunned = block_system.is_stun_locked();
+                    let is_stunned = world.block_system.is_stun_locked();
                     let is_raptor_mounted =
-                        fighter.fighter_type == FighterType::Raptor && is_on_bike;
+                        world.fighter.fighter_type == FighterType::Raptor && is_on_bike;
                     let should_play_bike_accelerate =
                         is_on_bike && is_moving_on_bike_input && !is_stunned && !is_raptor_mounted;
                     let should_play_bike_idle =
@@ -4761,7 +3802,7 @@ fn main() {
                             if let Some(idle_sink) = bike_idle_sound_sink.take() {
                                 idle_sink.stop();
                             }
-                            match audio_manager.play_sfx_loop("bike_accelerate") {
+                            match world.audio.play_sfx_loop("bike_accelerate") {
                                 Ok(sink) => bike_accelerate_sound_sink = Some(sink),
                                 Err(e_msg) => {
                                     eprintln!("Failed to play bike_accelerate: {}", e_msg)
@@ -4778,7 +3819,7 @@ fn main() {
                             if let Some(accel_sink) = bike_accelerate_sound_sink.take() {
                                 accel_sink.stop();
                             }
-                            match audio_manager.play_sfx_loop("bike_idle") {
+                            match world.audio.play_sfx_loop("bike_idle") {
                                 Ok(sink) => bike_idle_sound_sink = Some(sink),
                                 Err(e_msg) => eprintln!("Failed to play bike_idle: {}", e_msg),
                             }
@@ -4790,19 +3831,24 @@ fn main() {
                     }
                     spheres.retain_mut(|s| {
                         s.update(dt);
-                        if s.check_explosion_collision(fighter.x, fighter.y)
+                        if s.check_explosion_collision(world.fighter.x, world.fighter.y)
                             && matches!(game_state, GameState::Playing)
                         {
                             game_state = GameState::DeathScreen(DeathType::Meteorite);
+                            world.game_events.publish(GameEvent::FighterDowned {
+                                fighter_type: world.fighter.fighter_type,
+                                death_type: DeathType::Meteorite,
+                                group_continues: false,
+                            });
                             death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
-                            audio_manager.play_sound_effect("death").ok();
+                            world.audio.play_sound_effect("death").ok();
                             lmb_held = false; // Stop rapid fire on death
-                            melee_rapid_fire_timer = 0.0;
+                            world.melee_rapid_fire_timer = 0.0;
                             soldier_rapid_fire_timer = 0.0;
                         }
                         s.is_visible()
                     });
-                    if !PERFORMANCE_MODE {
+                    if !game_config.performance_mode {
                         spawn_timer += dt;
                         if spawn_timer >= next_spawn && spheres.len() < 50 {
                             spheres.push(Box::new(MovingSphere::new(
@@ -4816,13 +3862,13 @@ fn main() {
                             next_spawn = safe_gen_range(0.3, 1.5, "Next spawn time");
                         }
                     }
-                    if sbrx_map_system.current_field_id == fighter_jet_current_sbrx_location {
+                    if world.map_system.current_field_id == fighter_jet_current_sbrx_location {
                         if fighter_jet_instance.is_none() {
                             fighter_jet_instance = Some(FighterJet {
                                 x: fighter_jet_world_x,
                                 y: fighter_jet_world_y,
                             });
-                            println!("fighter_jet object created/visible for sbrx plane '{}', field {:?} at ({:.2}, {:.2})", sbrx_map_system.current_plane_name, fighter_jet_current_sbrx_location, fighter_jet_world_x, fighter_jet_world_y);
+                            println!("fighter_jet object created/visible for sbrx plane '{}', field {:?} at ({:.2}, {:.2})", world.map_system.current_plane_name, fighter_jet_current_sbrx_location, fighter_jet_world_x, fighter_jet_world_y);
                         } else {
                             if let Some(sj_mut) = fighter_jet_instance.as_mut() {
                                 sj_mut.x = fighter_jet_world_x;
@@ -4837,15 +3883,15 @@ fn main() {
                     }
                     show_fighter_jet_prompt = false;
                     if let Some(ref sj) = fighter_jet_instance {
-                        if sbrx_map_system.current_field_id == fighter_jet_current_sbrx_location {
-                            let dx = fighter.x - sj.x;
-                            let dy = fighter.y - sj.y;
+                        if world.map_system.current_field_id == fighter_jet_current_sbrx_location {
+                            let dx = world.fighter.x - sj.x;
+                            let dy = world.fighter.y - sj.y;
                             let distance_to_fighter_jet = (dx * dx + dy * dy).sqrt();
-                            if distance_to_fighter_jet <= FIGHTER_JET_INTERACTION_DISTANCE {
+                            if distance_to_fighter_jet <= game_config.gameplay.fighter_jet_interaction_distance {
                                 let find_survivors_task_active =
-                                    task_system.has_task("FIND 10 SURVIVORS");
+                                    world.task_system.has_task("FIND 10 SURVIVORS");
                                 let find_survivors_task_complete =
-                                    task_system.is_task_complete("FIND 10 SURVIVORS");
+                                    world.task_system.is_task_complete("FIND 10 SURVIVORS");
 
                                 if (!find_survivors_task_active || find_survivors_task_complete)
                                     && !racetrack_active
@@ -4856,592 +3902,168 @@ fn main() {
                         }
                     }
                     show_raptor_nest_prompt = false;
-                    if current_area.is_none()
-                        && sbrx_map_system.current_field_id == SbrxFieldId(1, 0)
+                    if world.current_area.is_none()
+                        && world.map_system.current_field_id == SbrxFieldId(1, 0)
                     {
                         if let Some(nest) = raptor_nests.first() {
-                            let dx = fighter.x - nest.x;
-                            let dy = fighter.y - nest.y;
+                            let dx = world.fighter.x - nest.x;
+                            let dy = world.fighter.y - nest.y;
                             let distance_sq = dx * dx + dy * dy;
-                            if distance_sq < RAPTOR_NEST_INTERACTION_DISTANCE.powi(2) {
+                            if distance_sq < game_config.gameplay.raptor_nest_interaction_distance.powi(2) {
                                 show_raptor_nest_prompt = true;
                             }
                         }
                     }
 
                     show_bunker_prompt = false;
-                    if current_area.is_none()
-                        && sbrx_map_system.current_field_id == SbrxFieldId(-25, 25)
+                    if world.current_area.is_none()
+                        && world.map_system.current_field_id == SbrxFieldId(-25, 25)
                     {
                         if let Some(&(bunker_x, bunker_y)) = fort_silo_bunkers.first() {
-                            let dx = fighter.x - bunker_x;
-                            let dy = fighter.y - bunker_y;
-                            let distance_sq = dx * dx + dy * dy;
-                            if distance_sq < RAPTOR_NEST_INTERACTION_DISTANCE.powi(2) {
-                                show_bunker_prompt = true;
-                            }
-                        }
-                    }
-
-                    // Check for raptor nest exit prompt
-                    show_raptor_nest_exit_prompt = false;
-                    show_bunker_exit_prompt = false;
-                    show_bunker_floor_transition_prompt = false;
-                    target_floor_from_prompt = None;
-                    if let Some(area_state) = &current_area {
-                        if let Some(target_floor) =
-                            area_state.get_player_floor_transition(fighter.x, fighter.y)
-                        {
-                            if area_state.area_type == AreaType::Bunker {
-                                show_bunker_floor_transition_prompt = true;
-                                target_floor_from_prompt = Some(target_floor);
-                            }
-                        }
-                        if area_state.is_player_at_world_exit(fighter.x, fighter.y) {
-                            match area_state.area_type {
-                                AreaType::RaptorNest => show_raptor_nest_exit_prompt = true,
-                                AreaType::Bunker => show_bunker_exit_prompt = true,
-                            }
-                        }
-                    }
-
-                    let field_id_has_changed_for_spawn_check = last_field_id_for_rattlesnake_spawn
-                        != Some(sbrx_map_system.current_field_id);
-                    if field_id_has_changed_for_spawn_check {
-                        // Do not spawn default rattlesnakes in Rocketbay
-                        if sbrx_map_system.current_field_id != SbrxFieldId(0, 0)
-                            && sbrx_map_system.current_field_id != SbrxFieldId(-2, 5)
-                            && sbrx_map_system.current_field_id != SbrxFieldId(-25, 25)							
-                            && !wave_manager.is_active()
-                        {
-                            println!("sbrx Field changed to non-(0,0) field {:?}. Spawning 3 rattlesnakes.", sbrx_map_system.current_field_id);
-							let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
-                            for _ in 0..3 {
-                                if CPU_ENABLED {
-                                    if cpu_entities.len() < 10 {
-                                        let mut snake = CpuEntity::new_rattlesnake(line_y);
-                                        snake.max_hp *= aptitude;
-                                        snake.current_hp = snake.max_hp;
-                                        snake.damage_value *= aptitude;
-                                        snake.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                        cpu_entities.push(snake);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    last_field_id_for_rattlesnake_spawn = Some(sbrx_map_system.current_field_id);
-                    if !rattlesnakes_spawned_in_field0_score3
-                        && sbrx_map_system.current_field_id == SbrxFieldId(0, 0)
-                        && fighter.score >= 3
-                        && !wave_manager.is_active()
-                    {
-                        if CPU_ENABLED && !wave_manager.is_active() && !racetrack_active {
-                            println!("Spawning 3 rattlesnakes due to score 3 in sbrx field (0,0).");
-							let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
-                            for _ in 0..3 {
-                                if cpu_entities.len() < 10 {
-                                    let mut snake = CpuEntity::new_rattlesnake(line_y);
-                                    snake.max_hp *= aptitude;
-                                    snake.current_hp = snake.max_hp;
-                                    snake.damage_value *= aptitude;
-                                    snake.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                    cpu_entities.push(snake);
-                                }
-                            }
-                            rattlesnakes_spawned_in_field0_score3 = true;
-                        }
-                    }
-                    if CPU_ENABLED && !racetrack_active {
-                        let current_score = fighter.score;
-                        let mut score_tier_to_check = 5;
-                        while score_tier_to_check <= current_score {
-                            if !spawned_giant_rattlesnake_scores.contains(&score_tier_to_check) {
-                                // Do not spawn score-based Giant Rattlesnakes in certain areas.
-                                if current_area.is_none()
-                                    && sbrx_map_system.current_field_id != SbrxFieldId(-2, 5)
-									&& sbrx_map_system.current_field_id != SbrxFieldId(-25, 25)
-                                {
-                                    println!(
-                                        "Spawning Giant Rattlesnake for score tier {}.",
-                                        score_tier_to_check
-                                    );
-                                    if cpu_entities.len() < 10 {
-                                        let mut cpu = CpuEntity::new_giant_rattlesnake(line_y);
-                                        let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
-                                        cpu.max_hp *= aptitude;
-                                        cpu.current_hp = cpu.max_hp;
-                                        cpu.damage_value *= aptitude;
-                                        cpu.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                        cpu_entities.push(cpu);
-                                    }
-                                }
-                                spawned_giant_rattlesnake_scores.insert(score_tier_to_check);
-                            }
-                            score_tier_to_check += 5;
-                        }
-                        let next_blood_idol_spawn_score = 0;
-						/*
-                        if current_score >= 0 {
-                            if next_blood_idol_spawn_score == 0 {
-                                next_blood_idol_spawn_score = 0;
-                            }
-                            while next_blood_idol_spawn_score <= current_score
-                                && spawned_blood_idol_scores.contains(&next_blood_idol_spawn_score)
-                            {
-                            }
-                        }
-						*/
-                        if next_blood_idol_spawn_score > 0
-                            && next_blood_idol_spawn_score <= current_score
-                        {
-                            let blood_idol_active = cpu_entities
-                                .iter()
-                                .any(|e| e.variant == CpuVariant::BloodIdol && !e.is_dead());
-                            let mantis_exists_alive = cpu_entities
-                                .iter()
-                                .any(|e| e.variant == CpuVariant::GiantMantis && !e.is_dead());
-                            if !blood_idol_active && mantis_exists_alive {
-                                println!(
-                                    "BLOOD IDOL spawning for score {}.",
-                                    next_blood_idol_spawn_score
-                                );
-                                let (base_hp, base_speed) = cpu_entities
-                                    .iter()
-                                    .find(|e| e.variant == CpuVariant::GiantMantis && !e.is_dead())
-                                    .map_or((250.0, 150.0), |m| (m.max_hp, m.speed));
-                                let spirit = CpuEntity::new_blood_idol(line_y, base_hp, base_speed);
-                                if cpu_entities.len() < 10 {
-                                    cpu_entities.push(spirit);
-                                }
-                                spawned_blood_idol_scores.insert(next_blood_idol_spawn_score);
-                            }
-                        }
-                    }
-                    if CPU_ENABLED
-                        && matches!(game_state, GameState::Playing)
-                        && (!racetrack_active || endless_arena_mode_active)
-                    {
-                        let mut player_died_this_frame = false;
-
-                        for cpu_entity in &mut cpu_entities {
-                            if !player_died_this_frame
-                                && cpu_entity.check_collision(
-                                    fighter.x,
-                                    fighter.y,
-                                    block_system.active,
-                                    &audio_manager,
-                                )
-                            {
-                                let mut attack_negated = block_system.process_attack(
-                                    &mut fighter,
-                                    cpu_entity,
-                                    &audio_manager,
-                                    game_time,
-                                );
-
-                                // --- Passive Defense Check (Auto-Block / Auto-Dodge) ---
-                                // Condition: Attack wasn't manually blocked, player isn't invincible, NOT vulnerable/broken, AND enemy isn't stunned
-                                if !attack_negated && fighter.invincible_timer <= 0.0 && !block_system.block_broken && cpu_entity.stun_timer <= 0.0 {
-                                    let mut rng = rand::rng();
-                                    let roll: f64 = rng.random();
-                                    
-                                    // Visual Priority: Only show passive anims if player isn't busy with inputs
-                                    let can_show_passive_anim = !is_high_priority_animation_active(
-                                        rush_active, strike_animation_timer, block_system.active, block_system.rmb_held
-                                    ) && !movement_active && !backpedal_active;
-
-                                    if roll < fighter.stats.defense.auto_dodge {
-                                        attack_negated = true;
-                                        audio_manager.play_sound_effect("boost").ok();
-                                        damage_texts.push(DamageText {
-                                            text: "DODGE".to_string(),
-                                            x: fighter.x, y: fighter.y - 90.0,
-                                            color: [0.0, 0.8, 1.0, 1.0], lifetime: 0.5,
-                                        });
-                                        if can_show_passive_anim {
-                                            current_racer_texture = current_backpedal_texture;
-                                            strike_animation_timer = 0.25
-                                        }
-                                    } else if roll < fighter.stats.defense.auto_dodge + fighter.stats.defense.auto_block {
-                                        attack_negated = true;
-                                        audio_manager.play_sound_effect("block").ok();
-                                        damage_texts.push(DamageText {
-                                            text: "BLOCK".to_string(),
-                                            x: fighter.x, y: fighter.y - 90.0,
-                                            color: [1.0, 1.0, 1.0, 1.0], lifetime: 0.5,
-                                        });
-                                        if can_show_passive_anim {
-                                            current_racer_texture = current_block_texture;
-                                            strike_animation_timer = 0.25
-                                        }
-                                    }
-                                }
-
-                                if !attack_negated {
-                                    if cpu_entity.damage_display_cooldown <= 0.0 {
-                                        if fighter.invincible_timer <= 0.0 {
-                                            let damage_chunk = cpu_entity.damage_value;
-                                            let combo_dr_multiplier =
-                                                combo_system.get_damage_intake_multiplier();
-                                            let final_damage = damage_chunk
-                                                * block_system.get_damage_multiplier()
-                                                * combo_dr_multiplier;
-                                            fighter.current_hp -= final_damage;
-                                            damage_texts.push(DamageText {
-                                                text: format!("{:.0}", damage_chunk),
-                                                x: fighter.x,
-                                                y: fighter.y - 70.0,
-                                                color: [1.0, 1.0, 1.0, 1.0],
-                                                lifetime: 0.25,
-                                            });
-                                            cpu_entity.damage_display_cooldown = 0.125;
-
-                                            // Dismount if hit on bike
-                                            if fighter.state == RacerState::OnBike {
-                                                println!("Dismounted by enemy damage!");
-                                                fighter.state = RacerState::OnFoot;
-
-                                                if fighter.fighter_type != FighterType::Raptor {
-                                                    sbrx_bike.respawn(fighter.x, fighter.y);
-                                                }
-
-                                                let tex_set = match fighter.fighter_type {
-                                                    FighterType::Racer => &racer_textures,
-                                                    FighterType::Soldier => &soldier_textures,
-                                                    FighterType::Raptor => &raptor_textures,
-                                                };
-                                                update_current_textures(
-                                                    &fighter,
-                                                    tex_set,
-                                                    &mut current_idle_texture,
-                                                    &mut current_fwd_texture,
-                                                    &mut current_backpedal_texture,
-                                                    &mut current_block_texture,
-                                                    &mut current_block_break_texture,
-                                                    &mut current_ranged_texture,
-                                                    &mut current_ranged_marker_texture,
-                                                    &mut current_ranged_blur_texture,
-                                                    &mut current_rush_texture,
-                                                    &mut current_strike_textures,
-													false, // Force standard textures on dismount event
-                                                );
-                                                current_racer_texture = current_idle_texture;
-                                                chatbox.add_interaction(vec![(
-                                                    "DISMOUNTED",
-                                                    MessageType::Warning,
-                                                )]);
-                                            }
-
-                                            if fighter.current_hp <= 0.0 {
-                                                player_died_this_frame = true;
-                                                lmb_held = false; // Stop rapid fire on death
-                                                melee_rapid_fire_timer = 0.0;
-                                                soldier_rapid_fire_timer = 0.0;
-												
-                                                if let Some(sink) = bike_accelerate_sound_sink.take() { sink.stop(); }
-                                                if let Some(sink) = bike_idle_sound_sink.take() { sink.stop(); }										
-												
-                                                let death_type_for_cpu = match cpu_entity.variant {
-                                                    CpuVariant::GiantMantis => {
-                                                        DeathType::GiantMantis
-                                                    }
-                                                    CpuVariant::BloodIdol => DeathType::BloodIdol,
-                                                    CpuVariant::Rattlesnake => {
-                                                        DeathType::Rattlesnake
-                                                    }
-                                                    CpuVariant::GiantRattlesnake => {
-                                                        DeathType::GiantRattlesnake
-                                                    }
-                                                    CpuVariant::Raptor => DeathType::Raptor,
-                                                    CpuVariant::TRex => DeathType::TRex,
-                                                    CpuVariant::VoidTempest => {
-                                                        DeathType::VoidTempest
-                                                    }
-                                                    CpuVariant::LightReaver => {
-                                                        DeathType::LightReaver
-                                                    }
-                                                    CpuVariant::NightReaver => {
-                                                        DeathType::NightReaver
-                                                    }
-                                                    CpuVariant::RazorFiend => DeathType::RazorFiend,
-                                                };
-
-                                                fighter_hp_map.insert(fighter.fighter_type, 0.0);
-
-                                                if fighter.state == RacerState::OnBike {
-                                                    fighter.state = RacerState::OnFoot;
-                                                    if fighter.fighter_type != FighterType::Raptor {
-                                                        sbrx_bike.respawn(fighter.x, fighter.y);
-                                                    }
-                                                }
-
-                                                block_break_animation_active = false;
-                                                block_system = BlockSystem::new(20);
-
-                                                let mut group_members = vec![FighterType::Racer];
-                                                if soldier_has_joined {
-                                                    group_members.push(FighterType::Soldier);
-                                                }
-                                                if raptor_has_joined {
-                                                    group_members.push(FighterType::Raptor);
-                                                }
-
-                                                let has_survivors =
-                                                    group_members.iter().any(|ft| {
-                                                        !downed_fighters.contains(ft)
-                                                            && *ft != fighter.fighter_type
-                                                    });
-
-                                                if group_members.len() > 1 && has_survivors {
-                                                    game_state = GameState::DeathScreenGroup {
-                                                        death_type: death_type_for_cpu,
-                                                        downed_fighter_type: fighter.fighter_type,
-                                                    };
-                                                    death_screen_cooldown =
-                                                        DEATH_SCREEN_COOLDOWN_TIME;
-                                                    if !downed_fighters
-                                                        .contains(&fighter.fighter_type)
-                                                    {
-                                                        downed_fighters.push(fighter.fighter_type);
-                                                    }
-                                                    revival_kill_score = 0;
-                                                } else {
-                                                    if !downed_fighters
-                                                        .contains(&fighter.fighter_type)
-                                                    {
-                                                        downed_fighters.push(fighter.fighter_type);
-                                                    }
-                                                    game_state =
-                                                        GameState::DeathScreen(death_type_for_cpu);
-                                                    death_screen_cooldown =
-                                                        DEATH_SCREEN_COOLDOWN_TIME;
-                                                }
-                                                audio_manager.play_sound_effect("death").ok();
-                                            }
-                                        } else {
-                                            cpu_entity.damage_display_cooldown = 0.125;
-                                        }
-                                    }
-                                }
+                            let dx = world.fighter.x - bunker_x;
+                            let dy = world.fighter.y - bunker_y;
+                            let distance_sq = dx * dx + dy * dy;
+                            if distance_sq < game_config.gameplay.raptor_nest_interaction_distance.powi(2) {
+                                show_bunker_prompt = true;
                             }
                         }
+                    }
 
-                        // --- Phase 2: Identify and process dead enemies ---
-                        let mut dead_indices = Vec::new();
-                        for (i, cpu) in cpu_entities.iter().enumerate() {
-                            if cpu.is_dead() {
-                                dead_indices.push(i);
+                    // Check for raptor nest exit prompt
+                    show_raptor_nest_exit_prompt = false;
+                    show_bunker_exit_prompt = false;
+                    show_bunker_floor_transition_prompt = false;
+                    target_floor_from_prompt = None;
+                    if let Some(area_state) = &world.current_area {
+                        if let Some(target_floor) =
+                            area_state.get_player_floor_transition(world.fighter.x, world.fighter.y)
+                        {
+                            if area_state.area_type == AreaType::Bunker {
+                                show_bunker_floor_transition_prompt = true;
+                                target_floor_from_prompt = Some(target_floor);
                             }
                         }
-
-                        if !dead_indices.is_empty() {
-                            let mut raptor_died_in_area = false;
-                            let mut t_rex_was_defeated_this_frame = false;
-                            let mut cpus_to_remove = Vec::new();
-                            let mut cpus_to_respawn = Vec::new();
-
-                            for &index in &dead_indices {
-                                if let Some(cpu_entity) = cpu_entities.get(index) {
-                                    spawn_particles(
-                                        &mut particles,
-                                        cpu_entity.x,
-                                        cpu_entity.y,
-                                        PARTICLE_COUNT_CPU,
-                                    );
-
-                                    if wave_manager.is_active() {
-                                        wave_manager.notify_enemy_defeated();
-                                    }
-                                    task_system.increment_kill_count(cpu_entity.variant);																
-									
-                                    let score_value = match cpu_entity.variant {
-                                        CpuVariant::GiantMantis => 3,
-                                        CpuVariant::Rattlesnake => 1,
-                                        CpuVariant::GiantRattlesnake => 3,
-                                        CpuVariant::Raptor => 2,
-                                        CpuVariant::TRex => 5,
-                                        CpuVariant::BloodIdol => 3,
-                                        CpuVariant::VoidTempest => 4,
-                                        CpuVariant::LightReaver => 2,
-                                        CpuVariant::NightReaver => 2,
-                                        CpuVariant::RazorFiend => 10,
-                                    };
-									
-                                    if endless_arena_mode_active {
-                                        arena_kill_count = (arena_kill_count + score_value).min(999999);
-                                        // Milestones for every 25 points
-                                        let current_milestone = arena_kill_count / 25;
-                                        if current_milestone > last_arena_milestone && current_milestone > 0 {
-                                            last_arena_milestone = current_milestone;
-                                                                                       
-                                            let damage_bonus = 1.25;
-                                            fighter.melee_damage = fighter.stats.attack.melee_damage + damage_bonus;
-                                            fighter.ranged_damage = fighter.stats.attack.ranged_damage + damage_bonus;
-											//fighter.run_speed *= 1.25;
-                                            
-                                            // Grant 3 seconds of invincibility
-                                            fighter.invincible_timer = 5.0;
- 
-                                            chatbox.add_interaction(vec![
-                                                (&format!("KILL BONUS: ATOMIC-STATE"), MessageType::Warning),
-                                            ]);
-                                            audio_manager.play_sound_effect("boost").ok();
-                                        }										
-                                    }									
-                                    
-                                    fighter.score = (fighter.score + score_value).min(999999);
-
-                                    let current_kills = fighter
-                                        .kill_counters
-                                        .entry(fighter.fighter_type)
-                                        .or_insert(0);
-                                    *current_kills += score_value;
-                                    let current_level =
-                                        fighter.levels.entry(fighter.fighter_type).or_insert(1);
-                                    let stat_points = fighter
-                                        .stat_points_to_spend
-                                        .entry(fighter.fighter_type)
-                                        .or_insert(0);
-                                    let levels_gained = mechanics::lvl_up::check_for_level_up(
-                                        current_kills,
-                                        current_level,
-                                    );
-                                    if levels_gained > 0 {
-                                        *stat_points += levels_gained;
-                                        lvl_up_state = LvlUpState::PendingTab {
-                                            fighter_type: fighter.fighter_type,
-                                        };
-                                        let fighter_name = match fighter.fighter_type {
-                                            FighterType::Racer => "RACER",
-                                            FighterType::Soldier => "SOLDIER",
-                                            FighterType::Raptor => "RAPTOR",
-                                        };
-                                        chatbox.add_interaction(vec![(
-                                            &format!(
-                                                "!! [TAB] TO LVL UP [{}] +[{}] !!",
-                                                fighter_name, *stat_points
-                                            ),
-                                            MessageType::Warning,
-                                        )]);
-										audio_manager.play_sound_effect("death").ok();
-                                    }
-
-                                    if !downed_fighters.is_empty() {
-                                        revival_kill_score += 1;
-                                    }
-
-                                    let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
-                                    award_kill_score(
-                                        &mut fighter,
-                                        score_value,
-                                        &mut chatbox,
-                                        &mut lvl_up_state,
-                                        "killing an enemy",
-                                        aptitude,
-									);
-									
-                                    fighter.current_hp =
-                                        (fighter.current_hp + 25.0).min(fighter.max_hp);
-                                    fighter.fuel = (fighter.fuel + FUEL_REPLENISH_AMOUNT)
-                                        .min(fighter.max_fuel);
-
-                                    match cpu_entity.variant {
-                                        CpuVariant::Raptor => {
-                                            cpus_to_remove.push(index);
-                                            if current_area.is_some() {
-                                                raptor_died_in_area = true;
-                                            }
-                                        }
-                                        CpuVariant::TRex => {
-                                            cpus_to_remove.push(index);
-                                            t_rex_was_defeated_this_frame = true;
-                                        }
-                                        CpuVariant::BloodIdol
-                                        | CpuVariant::VoidTempest
-                                        | CpuVariant::LightReaver
-                                        | CpuVariant::NightReaver
-                                        | CpuVariant::RazorFiend => {
-                                            cpus_to_remove.push(index);
-                                            if cpu_entity.variant == CpuVariant::RazorFiend {
-                                                razor_fiend_defeated_flag = true;
-                                            }
-                                        }
-                                        _ => {
-                                            if endless_arena_mode_active {
-                                                cpus_to_remove.push(index);
-                                            // In Rocketbay, remove default enemies instead of respawning them.
-                                            // The continuous spawner will add Night Reavers.
-                                            } else if sbrx_map_system.current_field_id
-                                                == SbrxFieldId(-2, 5)
-                                            {
-                                                cpus_to_remove.push(index);
-                                            } else {
-                                                cpus_to_respawn.push(index);
-                                            }
-                                        }
-                                    }
-                                }
+                        if area_state.is_player_at_world_exit(world.fighter.x, world.fighter.y) {
+                            match area_state.area_type {
+                                AreaType::RaptorNest => show_raptor_nest_exit_prompt = true,
+                                AreaType::Bunker => show_bunker_exit_prompt = true,
                             }
+                        }
+                    }
 
-                            // --- Phase 3: Mutate the cpu_entities list safely ---
-                            for &index in &cpus_to_respawn {
-                                let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);								
-                                if let Some(cpu) = cpu_entities.get_mut(index) {
-                                    cpu.respawn(line_y);
-									
-                                    // Apply aptitude scaling to newly spawned/respawned CPU stats
-                                    cpu.max_hp *= aptitude;
-                                    cpu.current_hp = cpu.max_hp;
-                                    cpu.damage_value *= aptitude;
-                                    // Speed scales more slowly to keep gameplay manageable (20% of aptitude growth)
-                                    cpu.speed *= 1.0 + (aptitude - 1.0) * 0.2;									
+                    let field_id_has_changed_for_spawn_check = last_field_id_for_rattlesnake_spawn
+                        != Some(world.map_system.current_field_id);
+                    if field_id_has_changed_for_spawn_check {
+                        // Do not spawn default rattlesnakes in Rocketbay
+                        if world.map_system.current_field_id != SbrxFieldId(0, 0)
+                            && world.map_system.current_field_id != SbrxFieldId(-2, 5)
+                            && world.map_system.current_field_id != SbrxFieldId(-25, 25)							
+                            && !world.wave_manager.is_active()
+                        {
+                            println!("sbrx Field changed to non-(0,0) field {:?}. Spawning 3 rattlesnakes.", world.map_system.current_field_id);
+							let aptitude = get_field_aptitude(world.map_system.current_field_id);
+                            for _ in 0..3 {
+                                if game_config.cpu_enabled {
+                                    if world.cpu_entities.len() < 10 {
+                                        let mut snake = CpuEntity::new_rattlesnake(line_y);
+                                        snake.max_hp *= aptitude;
+                                        snake.current_hp = snake.max_hp;
+                                        snake.damage_value *= aptitude;
+                                        snake.speed *= 1.0 + (aptitude - 1.0) * 0.2;
+                                        world.cpu_entities.push(snake);
+                                    }
                                 }
                             }
-
-                            cpus_to_remove.sort_unstable();
-                            for &index in cpus_to_remove.iter().rev() {
-                                if index < cpu_entities.len() {
-                                    cpu_entities.remove(index);
+                        }
+                    }
+                    last_field_id_for_rattlesnake_spawn = Some(world.map_system.current_field_id);
+                    if !rattlesnakes_spawned_in_field0_score3
+                        && world.map_system.current_field_id == SbrxFieldId(0, 0)
+                        && world.fighter.score >= 3
+                        && !world.wave_manager.is_active()
+                    {
+                        if game_config.cpu_enabled && !world.wave_manager.is_active() && !racetrack_active {
+                            println!("Spawning 3 rattlesnakes due to score 3 in sbrx field (0,0).");
+							let aptitude = get_field_aptitude(world.map_system.current_field_id);
+                            for _ in 0..3 {
+                                if world.cpu_entities.len() < 10 {
+                                    let mut snake = CpuEntity::new_rattlesnake(line_y);
+                                    snake.max_hp *= aptitude;
+                                    snake.current_hp = snake.max_hp;
+                                    snake.damage_value *= aptitude;
+                                    snake.speed *= 1.0 + (aptitude - 1.0) * 0.2;
+                                    world.cpu_entities.push(snake);
                                 }
                             }
-
-                            if raptor_died_in_area {
-                                let live_raptors = cpu_entities
-                                    .iter()
-                                    .filter(|c| c.variant == CpuVariant::Raptor && !c.is_dead())
-                                    .count();
-                                if live_raptors == 0 {
-                                    if !task_system.raptor_nest_cleared {
-                                        task_system.mark_raptor_nest_cleared();
-                                        println!("All raptors in nest cleared for the first time! raptor can be rescued.");
-                                        raptor_is_trapped_in_nest = true;
-                                    }
+                            rattlesnakes_spawned_in_field0_score3 = true;
+                        }
+                    }
+                    if game_config.cpu_enabled && !racetrack_active {
+                        let current_score = world.fighter.score;
+                        let mut score_tier_to_check = 5;
+                        while score_tier_to_check <= current_score {
+                            if !spawned_giant_rattlesnake_scores.contains(&score_tier_to_check) {
+                                // Do not spawn score-based Giant Rattlesnakes in certain areas.
+                                if world.current_area.is_none()
+                                    && world.map_system.current_field_id != SbrxFieldId(-2, 5)
+									&& world.map_system.current_field_id != SbrxFieldId(-25, 25)
+                                {
                                     println!(
-                                        "Raptor Nest cleared. A T-Rex will be waiting outside."
+                                        "Spawning Giant Rattlesnake for score tier {}.",
+                                        score_tier_to_check
                                     );
-                                    t_rex_spawn_pending = true;
+                                    if world.cpu_entities.len() < 10 {
+                                        let mut cpu = CpuEntity::new_giant_rattlesnake(line_y);
+                                        let aptitude = get_field_aptitude(world.map_system.current_field_id);
+                                        cpu.max_hp *= aptitude;
+                                        cpu.current_hp = cpu.max_hp;
+                                        cpu.damage_value *= aptitude;
+                                        cpu.speed *= 1.0 + (aptitude - 1.0) * 0.2;
+                                        world.cpu_entities.push(cpu);
+                                    }
                                 }
+                                spawned_giant_rattlesnake_scores.insert(score_tier_to_check);
                             }
-
-                            if t_rex_was_defeated_this_frame {
+                            score_tier_to_check += 5;
+                        }
+                        let next_blood_idol_spawn_score = 0;
+						/*
+                        if current_score >= 0 {
+                            if next_blood_idol_spawn_score == 0 {
+                                next_blood_idol_spawn_score = 0;
+                            }
+                            while next_blood_idol_spawn_score <= current_score
+                                && spawned_blood_idol_scores.contains(&next_blood_idol_spawn_score)
+                            {
+                            }
+                        }
+						*/
+                        if next_blood_idol_spawn_score > 0
+                            && next_blood_idol_spawn_score <= current_score
+                        {
+                            let blood_idol_active = world.cpu_entities
+                                .iter()
+                                .any(|e| e.variant == CpuVariant::BloodIdol && !e.is_dead());
+                            let mantis_exists_alive = world.cpu_entities
+                                .iter()
+                                .any(|e| e.variant == CpuVariant::GiantMantis && !e.is_dead());
+                            if !blood_idol_active && mantis_exists_alive {
                                 println!(
-                                    "T-Rex defeated. Restoring default CPU spawn (Giant Mantis)."
+                                    "BLOOD IDOL spawning for score {}.",
+                                    next_blood_idol_spawn_score
                                 );
-                                t_rex_is_active = false;
-                                if CPU_ENABLED && cpu_entities.len() < 10 {
-									let mut cpu = CpuEntity::new_giant_mantis(line_y);
-									let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
-									cpu.max_hp *= aptitude;
-									cpu.current_hp = cpu.max_hp;
-									cpu.damage_value *= aptitude;
-									cpu.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-									cpu_entities.push(cpu);
+                                let (base_hp, base_speed) = world.cpu_entities
+                                    .iter()
+                                    .find(|e| e.variant == CpuVariant::GiantMantis && !e.is_dead())
+                                    .map_or((250.0, 150.0), |m| (m.max_hp, m.speed));
+                                let spirit = CpuEntity::new_blood_idol(line_y, base_hp, base_speed);
+                                if world.cpu_entities.len() < 10 {
+                                    world.cpu_entities.push(spirit);
                                 }
+                                spawned_blood_idol_scores.insert(next_blood_idol_spawn_score);
                             }
                         }
                     }
-                    //  //	//	//	//
                     // NEW: Check for fighter revival
                     if revival_kill_score >= 10 {
                         if !downed_fighters.is_empty() {
@@ -5475,18 +4097,18 @@ fn main() {
                         }
                     }
 
-                    if block_system.needs_dismount {
-                        if fighter.state == RacerState::OnBike {
+                    if world.block_system.needs_dismount {
+                        if world.fighter.state == RacerState::OnBike {
                             println!("Dismounting from block break!");
-                            fighter.state = RacerState::OnFoot;
-                            sbrx_bike.respawn(fighter.x, fighter.y);
-                            let tex_set = match fighter.fighter_type {
+                            world.fighter.state = RacerState::OnFoot;
+                            sbrx_bike.respawn(world.fighter.x, world.fighter.y);
+                            let tex_set = match world.fighter.fighter_type {
                                 FighterType::Racer => &racer_textures,
                                 FighterType::Soldier => &soldier_textures,
                                 FighterType::Raptor => &raptor_textures,
                             };
                             update_current_textures(
-                                &fighter,
+                                &world.fighter,
                                 tex_set,
                                 &mut current_idle_texture,
                                 &mut current_fwd_texture,
@@ -5506,17 +4128,17 @@ fn main() {
                                 current_idle_texture
                             };
                         }
-                        block_system.needs_dismount = false;
+                        world.block_system.needs_dismount = false;
                     }
 
                     // --- Special Field Spawning Logic ---
-                    if !is_paused && CPU_ENABLED && current_area.is_none() {
-                        if sbrx_map_system.current_field_id == SbrxFieldId(-2, 5) {
+                    if !is_paused && game_config.cpu_enabled && world.current_area.is_none() {
+                        if world.map_system.current_field_id == SbrxFieldId(-2, 5) {
                             // ROCKETBAY: Continuously spawn Night Reavers if count is low.
-                            let void_tempest_exists = cpu_entities
+                            let void_tempest_exists = world.cpu_entities
                                 .iter()
                                 .any(|e| e.variant == CpuVariant::VoidTempest);
-                            let night_reaver_count = cpu_entities
+                            let night_reaver_count = world.cpu_entities
                                 .iter()
                                 .filter(|e| e.variant == CpuVariant::NightReaver)
                                 .count();
@@ -5524,7 +4146,7 @@ fn main() {
                             // Keep a population of up to 4 Night Reavers, but don't spawn if Void Tempest is active.
                             if !void_tempest_exists
                                 && night_reaver_count < 4
-                                && cpu_entities.len() < 10
+                                && world.cpu_entities.len() < 10
                             {
                                 night_reaver_spawn_timer += dt;
                                 if night_reaver_spawn_timer >= next_night_reaver_spawn {
@@ -5532,12 +4154,12 @@ fn main() {
                                     let reaver_x = safe_gen_range(MIN_X, MAX_X, "NightReaver x");
                                     let reaver_y = safe_gen_range(MIN_Y, MAX_Y, "NightReaver y");
                                     let mut reaver = CpuEntity::new_night_reaver(reaver_x, reaver_y);
-                                    let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+                                    let aptitude = get_field_aptitude(world.map_system.current_field_id);
                                     reaver.max_hp *= aptitude;
                                     reaver.current_hp = reaver.max_hp;
                                     reaver.damage_value *= aptitude;
                                     reaver.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                    cpu_entities.push(reaver);
+                                    world.cpu_entities.push(reaver);
                                     night_reaver_spawn_timer = 0.0;
                                     next_night_reaver_spawn =
                                         safe_gen_range(3.0, 6.0, "Next Night Reaver spawn time");
@@ -5546,32 +4168,32 @@ fn main() {
                         }
                     }
 
-                    camera.update(fighter.x, fighter.y);
+                    camera.update(world.fighter.x, world.fighter.y);
                 }
 				
 // --- JUMP ZONE CHECK (1 -> 2 -> 3) ---
 				// This is moved OUTSIDE the invincibility check to allow Leg 2 to trigger immediately after Leg 1
-				if !is_paused && current_area.is_none() {
-					if let Some(hit) = collision_barrier_manager.check_jump(
-						&sbrx_map_system.current_field_id,
-						fighter.x,
-						fighter.y,
+				if !is_paused && world.current_area.is_none() {
+					if let Some(hit) = world.collision_barriers.check_jump(
+						&world.map_system.current_field_id,
+						world.fighter.x,
+						world.fighter.y,
 					) {
 						match hit.zone_type {
 							JumpZoneType::Launch => {
 								// LEG 1: RAPID LAUNCH ('1' -> '2')
-								if fighter.knockback_duration <= 0.0 {
-									let dx = hit.target_x - fighter.x;
-									let dy = hit.target_y - fighter.y;
+								if world.fighter.knockback_duration <= 0.0 {
+									let dx = hit.target_x - world.fighter.x;
+									let dy = hit.target_y - world.fighter.y;
 									let dist = (dx * dx + dy * dy).sqrt();
 									if dist > 10.0 {
 										let norm_dx = dx / dist;
 										let norm_dy = dy / dist;
 										let travel_time = 0.25; 
 										let required_speed = dist / travel_time;
-										fighter.knockback_velocity = Vec2d::new(norm_dx * required_speed, norm_dy * required_speed);
-										fighter.knockback_duration = travel_time;
-										fighter.invincible_timer = 1.0;
+										world.fighter.knockback_velocity = Vec2d::new(norm_dx * required_speed, norm_dy * required_speed);
+										world.fighter.knockback_duration = travel_time;
+										world.fighter.invincible_timer = 1.0;
 										in_jump_sequence = true;  // Start jump sequence
 									}
 								}
@@ -5580,8 +4202,8 @@ fn main() {
 								// LEG 2: FLUID MID-AIR REDIRECT ('2' -> '3')
 								// Only triggers if player is in a jump sequence from '1'
 								if in_jump_sequence {
-									let dx = (hit.target_x + 50.0) - fighter.x;
-									let dy = (hit.target_y + 50.0) - fighter.y;  // Add 50 units to land lower
+									let dx = (hit.target_x + 50.0) - world.fighter.x;
+									let dy = (hit.target_y + 50.0) - world.fighter.y;  // Add 50 units to land lower
 									let dist = (dx * dx + dy * dy).sqrt();
 									
 									if dist > 50.0 {
@@ -5589,9 +4211,9 @@ fn main() {
 										let norm_dy = dy / dist;
 										let travel_time = 0.25; 
 										let required_speed = dist / travel_time;
-										fighter.knockback_velocity = Vec2d::new(norm_dx * required_speed, norm_dy * required_speed);
-										fighter.knockback_duration = travel_time;
-										fighter.invincible_timer = 1.0; 
+										world.fighter.knockback_velocity = Vec2d::new(norm_dx * required_speed, norm_dy * required_speed);
+										world.fighter.knockback_duration = travel_time;
+										world.fighter.invincible_timer = 1.0; 
 									}
 								}
 								// If not in jump sequence, do nothing (player walks/rides over)
@@ -5599,10 +4221,10 @@ fn main() {
 							JumpZoneType::Landing => {
 								// TOUCHDOWN: Reset physics and end high-immunity
 								if in_jump_sequence {
-									fighter.knockback_velocity = Vec2d::new(0.0, 0.0);
-									fighter.knockback_duration = 0.0;
-									if fighter.invincible_timer > 0.3 {
-										fighter.invincible_timer = 0.3;
+									world.fighter.knockback_velocity = Vec2d::new(0.0, 0.0);
+									world.fighter.knockback_duration = 0.0;
+									if world.fighter.invincible_timer > 0.3 {
+										world.fighter.invincible_timer = 0.3;
 									}
 									in_jump_sequence = false;  // End jump sequence
 								}
@@ -5612,155 +4234,165 @@ fn main() {
 				}
 				
 				// --- CHAIN ZONE CHECK ('0' -> '0' -> '0' ...) ---
-				if let Some(hit) = collision_barrier_manager.check_chain(
-					&sbrx_map_system.current_field_id,
-					fighter.x,
-					fighter.y,
+				if let Some(hit) = world.collision_barriers.check_chain(
+					&world.map_system.current_field_id,
+					world.fighter.x,
+					world.fighter.y,
 				) {
 					if hit.is_final {
 						// Final zone in chain - stop and end immunity
-						fighter.knockback_velocity = Vec2d::new(0.0, 0.0);
-						fighter.knockback_duration = 0.0;
-						if fighter.invincible_timer > 0.3 {
-							fighter.invincible_timer = 0.3;
+						world.fighter.knockback_velocity = Vec2d::new(0.0, 0.0);
+						world.fighter.knockback_duration = 0.0;
+						if world.fighter.invincible_timer > 0.3 {
+							world.fighter.invincible_timer = 0.3;
 						}
 					} else {
 						// Launch to next '0' in chain
-						let dx = hit.target_x - fighter.x;
-						let dy = hit.target_y - fighter.y;
+						let dx = hit.target_x - world.fighter.x;
+						let dy = hit.target_y - world.fighter.y;
 						let dist = (dx * dx + dy * dy).sqrt();
 						if dist > 0.0 {
 							let norm_dx = dx / dist;
 							let norm_dy = dy / dist;
 							let travel_time = 0.4;
 							let required_speed = dist / travel_time;
-							fighter.knockback_velocity = Vec2d::new(norm_dx * required_speed, norm_dy * required_speed);
-							fighter.knockback_duration = travel_time;
-							fighter.invincible_timer = 1.5;
+							world.fighter.knockback_velocity = Vec2d::new(norm_dx * required_speed, norm_dy * required_speed);
+							world.fighter.knockback_duration = travel_time;
+							world.fighter.invincible_timer = 1.5;
 						}
 					}
 				}				
  
  				// --- GROUND ASSET COLLISIONS ---
- 				if !is_paused && fighter.invincible_timer <= 0.0 && current_area.is_none() {
+ 				if !is_paused && world.fighter.invincible_timer <= 0.0 && world.current_area.is_none() {
 					// --- COLLISION BARRIER CHECK ---
- 					if let Some((barrier_x, barrier_y)) = collision_barrier_manager.check_collision(
- 						&sbrx_map_system.current_field_id,
- 						fighter.x,
- 						fighter.y,
+ 					if let Some((barrier_x, barrier_y)) = world.collision_barriers.check_collision(
+ 						&world.map_system.current_field_id,
+ 						world.fighter.x,
+ 						world.fighter.y,
  						45.0, // collision threshold
  					) {
- 						audio_manager.play_sound_effect("death").ok();
- 						let dx = fighter.x - barrier_x;
- 						let dy = fighter.y - barrier_y;
+ 						world.audio.play_sound_effect("death").ok();
+ 						let dx = world.fighter.x - barrier_x;
+ 						let dy = world.fighter.y - barrier_y;
  						let angle = dy.atan2(dx);
  						
- 						if fighter.state == RacerState::OnFoot {
+ 						if world.fighter.state == RacerState::OnFoot {
  							// [TRIP] - on foot collision with barrier
- 							fighter.current_hp -= 25.0;
+ 							world.fighter.current_hp -= 25.0;
  							let force = 400.0;
- 							fighter.knockback_velocity = Vec2d::new(angle.cos() * force, angle.sin() * force);
- 							fighter.knockback_duration = 0.2;
+ 							world.fighter.knockback_velocity = Vec2d::new(angle.cos() * force, angle.sin() * force);
+ 							world.fighter.knockback_duration = 0.2;
  						} else {
  							// [CRASH] - vehicle collision with barrier
- 							fighter.current_hp -= 100.0;
- 							fighter.stun_timer = 1.0;
+ 							world.fighter.current_hp -= 100.0;
+ 							world.fighter.stun_timer = 1.0;
  							let force = 800.0;
- 							fighter.knockback_velocity = Vec2d::new(angle.cos() * force, angle.sin() * force);
- 							fighter.knockback_duration = 0.4;
+ 							world.fighter.knockback_velocity = Vec2d::new(angle.cos() * force, angle.sin() * force);
+ 							world.fighter.knockback_duration = 0.4;
 							
 							// BUG FIX: Deactivate rapid-fire melee state on crash
 							lmb_held = false;
-							melee_rapid_fire_timer = 0.0;							
+							world.melee_rapid_fire_timer = 0.0;							
  						
  							// Dismount
- 							fighter.state = RacerState::OnFoot;
+ 							world.fighter.state = RacerState::OnFoot;
  							sbrx_bike.is_crashed = true;
  
- 							if fighter.fighter_type != FighterType::Raptor {
+ 							if world.fighter.fighter_type != FighterType::Raptor {
  								sbrx_bike.visible = true;
- 								sbrx_bike.x = fighter.x;
- 								sbrx_bike.y = fighter.y;
+ 								sbrx_bike.x = world.fighter.x;
+ 								sbrx_bike.y = world.fighter.y;
  								let bike_angle = angle + 0.5;
  								sbrx_bike.knockback_velocity = Vec2d::new(bike_angle.cos() * 600.0, bike_angle.sin() * 600.0);
  								sbrx_bike.knockback_duration = 0.5;
  							}
  
- 							let tex_set = match fighter.fighter_type {
+ 							let tex_set = match world.fighter.fighter_type {
  								FighterType::Racer => &racer_textures,
  								FighterType::Soldier => &soldier_textures,
  								FighterType::Raptor => &raptor_textures,
  							};
- 							update_current_textures(&fighter, tex_set, &mut current_idle_texture, &mut current_fwd_texture, &mut current_backpedal_texture, &mut current_block_texture, &mut current_block_break_texture, &mut current_ranged_texture, &mut current_ranged_marker_texture, &mut current_ranged_blur_texture, &mut current_rush_texture, &mut current_strike_textures, shift_held);
+ 							update_current_textures(&world.fighter, tex_set, &mut current_idle_texture, &mut current_fwd_texture, &mut current_backpedal_texture, &mut current_block_texture, &mut current_block_break_texture, &mut current_ranged_texture, &mut current_ranged_marker_texture, &mut current_ranged_blur_texture, &mut current_rush_texture, &mut current_strike_textures, shift_held);
  							current_racer_texture = current_block_break_texture;
  							chatbox.add_interaction(vec![("COLLISION", MessageType::Warning)]);
  						}
  
- 						if fighter.current_hp <= 0.0 {
- 							fighter_hp_map.insert(fighter.fighter_type, 0.0);
+ 						if world.fighter.current_hp <= 0.0 {
+ 							fighter_hp_map.insert(world.fighter.fighter_type, 0.0);
  							if let Some(sink) = bike_accelerate_sound_sink.take() { sink.stop(); }
  							if let Some(sink) = bike_idle_sound_sink.take() { sink.stop(); }
  							let mut group_members = vec![FighterType::Racer];
  							if soldier_has_joined { group_members.push(FighterType::Soldier); }
  							if raptor_has_joined { group_members.push(FighterType::Raptor); }
- 							let has_survivors = group_members.iter().any(|ft| !downed_fighters.contains(ft) && *ft != fighter.fighter_type);
+ 							let has_survivors = group_members.iter().any(|ft| !downed_fighters.contains(ft) && *ft != world.fighter.fighter_type);
  
  							if group_members.len() > 1 && has_survivors {
- 								game_state = GameState::DeathScreenGroup { death_type: DeathType::Crashed, downed_fighter_type: fighter.fighter_type };
+ 								game_state = GameState::DeathScreenGroup { death_type: DeathType::Crashed, downed_fighter_type: world.fighter.fighter_type };
+ 								world.game_events.publish(GameEvent::FighterDowned {
+ 								    fighter_type: world.fighter.fighter_type,
+ 								    death_type: DeathType::Crashed,
+ 								    group_continues: true,
+ 								});
  							} else {
  								game_state = GameState::DeathScreen(DeathType::Crashed);
+ 								world.game_events.publish(GameEvent::FighterDowned {
+ 								    fighter_type: world.fighter.fighter_type,
+ 								    death_type: DeathType::Crashed,
+ 								    group_continues: false,
+ 								});
  							}
- 							if !downed_fighters.contains(&fighter.fighter_type) { downed_fighters.push(fighter.fighter_type); }
+ 							if !downed_fighters.contains(&world.fighter.fighter_type) { downed_fighters.push(world.fighter.fighter_type); }
  							death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
  						}
- 						fighter.invincible_timer = 1.0;
+ 						world.fighter.invincible_timer = 1.0;
  					}
 					
  					// --- RUT ZONE CHECK (speed reduction applied in movement code) ---
- 					let _in_rut_zone = collision_barrier_manager.check_rut(
- 						&sbrx_map_system.current_field_id,
- 						fighter.x,
- 						fighter.y,
+ 					let _in_rut_zone = world.collision_barriers.check_rut(
+ 						&world.map_system.current_field_id,
+ 						world.fighter.x,
+ 						world.fighter.y,
  					);					
 					
-					if let Some(assets) = placed_ground_assets.get(&sbrx_map_system.current_field_id) {
+					if let Some(assets) = placed_ground_assets.get(&world.map_system.current_field_id) {
 						for asset in assets {
-							let dx = fighter.x - asset.x;
-							let dy = fighter.y - asset.y;
+							let dx = world.fighter.x - asset.x;
+							let dy = world.fighter.y - asset.y;
 							let dist_sq = dx * dx + dy * dy;
 							let collision_radius = 45.0; // Standard hitbox for assets
 
 							if dist_sq < collision_radius * collision_radius {
-								audio_manager.play_sound_effect("death").ok();
+								world.audio.play_sound_effect("death").ok();
 								let angle = dy.atan2(dx);
 								
-								if fighter.state == RacerState::OnFoot {
+								if world.fighter.state == RacerState::OnFoot {
 									// [TRIP]
-									fighter.current_hp -= 25.0;
+									world.fighter.current_hp -= 25.0;
 									let force = 400.0;
-									fighter.knockback_velocity = Vec2d::new(angle.cos() * force, angle.sin() * force);
-									fighter.knockback_duration = 0.2;
+									world.fighter.knockback_velocity = Vec2d::new(angle.cos() * force, angle.sin() * force);
+									world.fighter.knockback_duration = 0.2;
 								} else {
 									// [CRASH]
-									fighter.current_hp -= 100.0;
-									fighter.stun_timer = 1.0;
+									world.fighter.current_hp -= 100.0;
+									world.fighter.stun_timer = 1.0;
 									let force = 800.0;
-									fighter.knockback_velocity = Vec2d::new(angle.cos() * force, angle.sin() * force);
-									fighter.knockback_duration = 0.4;
+									world.fighter.knockback_velocity = Vec2d::new(angle.cos() * force, angle.sin() * force);
+									world.fighter.knockback_duration = 0.4;
 									
 									// BUG FIX: Deactivate rapid-fire melee state on crash
 									lmb_held = false;
-									melee_rapid_fire_timer = 0.0;										
+									world.melee_rapid_fire_timer = 0.0;										
 								
 									// Dismount
-									fighter.state = RacerState::OnFoot;
+									world.fighter.state = RacerState::OnFoot;
 									sbrx_bike.is_crashed = true; // Swaps texture to crashed for all synced views
 
 									// Relocate and knockback vehicle ONLY for RACER or SOLDIER
-									if fighter.fighter_type != FighterType::Raptor {
+									if world.fighter.fighter_type != FighterType::Raptor {
 										sbrx_bike.visible = true;
-										sbrx_bike.x = fighter.x;
-										sbrx_bike.y = fighter.y;
+										sbrx_bike.x = world.fighter.x;
+										sbrx_bike.y = world.fighter.y;
 
 										let bike_angle = angle + 0.5; // different trajectory from player
 										sbrx_bike.knockback_velocity = Vec2d::new(bike_angle.cos() * 600.0, bike_angle.sin() * 600.0);
@@ -5769,33 +4401,43 @@ fn main() {
 									// If raptor, sbrx_bike stays at its last left location 
 									// but is_crashed flag (set above) ensures it renders as crashed.
 
-									let tex_set = match fighter.fighter_type {
+									let tex_set = match world.fighter.fighter_type {
 										FighterType::Racer => &racer_textures,
 										FighterType::Soldier => &soldier_textures,
 										FighterType::Raptor => &raptor_textures,
 									};
-									update_current_textures(&fighter, tex_set, &mut current_idle_texture, &mut current_fwd_texture, &mut current_backpedal_texture, &mut current_block_texture, &mut current_block_break_texture, &mut current_ranged_texture, &mut current_ranged_marker_texture, &mut current_ranged_blur_texture, &mut current_rush_texture, &mut current_strike_textures, shift_held);
+									update_current_textures(&world.fighter, tex_set, &mut current_idle_texture, &mut current_fwd_texture, &mut current_backpedal_texture, &mut current_block_texture, &mut current_block_break_texture, &mut current_ranged_texture, &mut current_ranged_marker_texture, &mut current_ranged_blur_texture, &mut current_rush_texture, &mut current_strike_textures, shift_held);
                                     current_racer_texture = current_block_break_texture; // Display crash animation immediately
 								}
 
-								if fighter.current_hp <= 0.0 {
-									fighter_hp_map.insert(fighter.fighter_type, 0.0);
+								if world.fighter.current_hp <= 0.0 {
+									fighter_hp_map.insert(world.fighter.fighter_type, 0.0);
 								    if let Some(sink) = bike_accelerate_sound_sink.take() { sink.stop(); }
 								    if let Some(sink) = bike_idle_sound_sink.take() { sink.stop(); }									
 									let mut group_members = vec![FighterType::Racer];
 									if soldier_has_joined { group_members.push(FighterType::Soldier); }
 									if raptor_has_joined { group_members.push(FighterType::Raptor); }
-									let has_survivors = group_members.iter().any(|ft| !downed_fighters.contains(ft) && *ft != fighter.fighter_type);
+									let has_survivors = group_members.iter().any(|ft| !downed_fighters.contains(ft) && *ft != world.fighter.fighter_type);
 
 									if group_members.len() > 1 && has_survivors {
-										game_state = GameState::DeathScreenGroup { death_type: DeathType::Crashed, downed_fighter_type: fighter.fighter_type };
+										game_state = GameState::DeathScreenGroup { death_type: DeathType::Crashed, downed_fighter_type: world.fighter.fighter_type };
+										world.game_events.publish(GameEvent::FighterDowned {
+										    fighter_type: world.fighter.fighter_type,
+										    death_type: DeathType::Crashed,
+										    group_continues: true,
+										});
 									} else {
 										game_state = GameState::DeathScreen(DeathType::Crashed);
+										world.game_events.publish(GameEvent::FighterDowned {
+										    fighter_type: world.fighter.fighter_type,
+										    death_type: DeathType::Crashed,
+										    group_continues: false,
+										});
 									}
-									if !downed_fighters.contains(&fighter.fighter_type) { downed_fighters.push(fighter.fighter_type); }
+									if !downed_fighters.contains(&world.fighter.fighter_type) { downed_fighters.push(world.fighter.fighter_type); }
 									death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
 								}
-								fighter.invincible_timer = 1.0;
+								world.fighter.invincible_timer = 1.0;
 								break;
 							}
 						}
@@ -5803,16 +4445,26 @@ fn main() {
 				}				
 
                 if let Some(_) = e.render_args() {
+                    let alpha = if replay_player.is_some() { 1.0 } else { fixed_timestep.alpha() };
+                    let render_view = render_interpolation.view(alpha, &world.fighter, &camera, &world.cpu_entities);
                     window.draw_2d(&e, |c, g, device| {
+                        let c = viewport.apply(c);
                         // Optimized: Get field colors once per frame
-                        let (field_ground_color, field_sky_color) = sbrx_map_system.get_current_field_colors();						
+                        let (field_ground_color, field_sky_color) = world.map_system.get_current_field_colors();						
 						
+                        // Everything below draws at the interpolated positions in render_view.
+                        let camera = &render_view.camera;
+                        let (fighter_x, fighter_y) = render_view.fighter;
                         let tc = camera.transform(c);
+                        let (fighter_dx, fighter_dy) = render_view.fighter_offset(&world.fighter);
+                        let fighter_tc = tc.trans(fighter_dx, fighter_dy);
                         let oc = c;
 						
                         // --- OPTIMIZATION: Calculate World View Bounds ---
-                        let half_w = (screen_width / 2.0) / camera.zoom;
-                        let half_h = (screen_height / 2.0) / camera.zoom;
+                        // In expand mode the window shows more than the logical canvas.
+                        let [_, _, visible_w, visible_h] = viewport.visible_rect();
+                        let half_w = (visible_w / 2.0) / camera.zoom;
+                        let half_h = (visible_h / 2.0) / camera.zoom;
                         let view_l = camera.x - half_w - 100.0; // Buffer for large sprites
                         let view_r = camera.x + half_w + 100.0;
                         let view_t = camera.y - half_h - 100.0;
@@ -5849,7 +4501,7 @@ fn main() {
 								.ok();
 						}						
 
-                        if let Some(area_state) = &current_area {
+                        if let Some(area_state) = &world.current_area {
                             let (origin_x, origin_y, width, height, ground_color) =
                                 match area_state.area_type {
                                     AreaType::RaptorNest => (
@@ -6046,15 +4698,15 @@ fn main() {
                                     .ok();
                             }
                         } else {
-                            clear(field_ground_color, g);
+                            viewport.clear(field_ground_color, &c, g);
 
                             // --- NEW: Render Ground Texture ---
                             if !excluded_ground_texture_fields
-                                .contains(&sbrx_map_system.current_field_id)
+                                .contains(&world.map_system.current_field_id)
                             {
                                 let texture_index = *field_ground_texture_indices
-                                    .entry(sbrx_map_system.current_field_id)
-                                    .or_insert_with(|| rand::rng().random_range(0..4));
+                                    .entry(world.map_system.current_field_id)
+                                    .or_insert_with(|| utils::rng::cosmetic_rng().random_range(0..4));
                                 let ground_texture = &ground_textures[texture_index];
 
                                 let tex_width = ground_texture.get_width() as f64;
@@ -6063,9 +4715,9 @@ fn main() {
                                 if tex_width > 0.0 && tex_height > 0.0 {
                                     // Calculate the visible world coordinates to ensure the entire screen is covered
                                     let (world_tl_x, world_tl_y) =
-                                        screen_to_world(&camera, 0.0, 0.0);
+                                        screen_to_world(camera, 0.0, 0.0);
                                     let (world_br_x, world_br_y) =
-                                        screen_to_world(&camera, WIDTH, HEIGHT);
+                                        screen_to_world(camera, WIDTH, HEIGHT);
 
                                     // Align the starting coordinates to the texture grid to prevent texture "swimming"
                                     let start_x = (world_tl_x / tex_width).floor() * tex_width;
@@ -6094,9 +4746,9 @@ fn main() {
                             stars.iter().for_each(|s| s.draw(tc, g));
 
                             // --- NEW: Render Placed Ground Assets ---
-                            if current_area.is_none() {
+                            if world.current_area.is_none() {
                                 if let Some(assets_to_render) =
-                                    placed_ground_assets.get(&sbrx_map_system.current_field_id)
+                                    placed_ground_assets.get(&world.map_system.current_field_id)
                                 {
                                 for asset in assets_to_render {
                                     // Frustum Culling: Skip if asset is off-screen
@@ -6114,9 +4766,9 @@ fn main() {
                                         let img_y = asset.y - tex_h;
 
                                         // Only render if it should be visible
-                                        if !FOG_OF_WAR_ENABLED
+                                        if !game_config.fog_of_war_enabled
                                             || fog_of_war.should_render_entity(
-                                                sbrx_map_system.current_field_id,
+                                                world.map_system.current_field_id,
                                                 asset.x,
                                                 asset.y,
                                             )
@@ -6130,16 +4782,16 @@ fn main() {
 							
 							
 
-                            if sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
-                                fuel_pump.fuel_interaction(&mut fighter, &fuel_pump_texture);
+                            if world.map_system.current_field_id == SbrxFieldId(0, 0) {
+                                fuel_pump.fuel_interaction(&mut world.fighter, &fuel_pump_texture);
                             }
 
-                            if sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
+                            if world.map_system.current_field_id == SbrxFieldId(0, 0) {
                                 image(&track_texture, tc.transform.trans(track.x, track.y), g);
 								
                                 // TOGGLEABLE DEBUG: Draw collision barriers
                                 if show_collision_debug > 0 {
-                                    if let Some(barriers) = collision_barrier_manager.get_barriers(&sbrx_map_system.current_field_id) {
+                                    if let Some(barriers) = world.collision_barriers.get_barriers(&world.map_system.current_field_id) {
                                         for line in &barriers.lines {
                                             piston_window::line(
                                                 [1.0, 0.0, 0.0, 0.5], // Semi-transparent red
@@ -6241,7 +4893,7 @@ fn main() {
                                 */
                                 // TEMPORARY RACE SETUP ASSETS AT START
 
-                                if task_system.is_task_complete("LAND THE FIGHTERJET ON THE RACETRACK")
+                                if world.task_system.is_task_complete("LAND THE FIGHTERJET ON THE RACETRACK")
                                 {
                                     // --- Render Race Setup Assets ---
                                     // RacerLineup.png - Positioned near the bottom-center of the world, like a starting line.
@@ -6333,7 +4985,7 @@ fn main() {
                                 }
 
                                 // Hide Info Post and its prompt in Arena Mode
-                                if !crate::config::ARENA_MODE {
+                                if !game_config.arena_mode {
                                     let info_post_w = info_post_texture.get_width() as f64;
                                     let info_post_h = info_post_texture.get_height() as f64;
                                     let img_x = info_post_position.0 - info_post_w / 2.0;
@@ -6362,8 +5014,8 @@ fn main() {
                                      }
                                  }
                             }
-                            if sbrx_map_system.current_field_id == SbrxFieldId(-25, 25) {
-                                let fs_tex = if task_system.is_task_complete("SPEAK TO THE GRAND COMMANDER") {
+                            if world.map_system.current_field_id == SbrxFieldId(-25, 25) {
+                                let fs_tex = if world.task_system.is_task_complete("SPEAK TO THE GRAND COMMANDER") {
                                     &fort_silo2_texture
                                 } else {
                                     &fort_silo_texture
@@ -6419,10 +5071,10 @@ fn main() {
                                         .ok();
                                 }
                             }
-                            if !crate::config::ARENA_MODE && sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
-                                if !FOG_OF_WAR_ENABLED
+                            if !game_config.arena_mode && world.map_system.current_field_id == SbrxFieldId(0, 0) {
+                                if !game_config.fog_of_war_enabled
                                     || fog_of_war.should_render_entity(
-                                        sbrx_map_system.current_field_id,
+                                        world.map_system.current_field_id,
                                         random_image_x,
                                         random_image_y,
                                     )
@@ -6457,7 +5109,7 @@ fn main() {
                                     }
                                 }
                             }
-                            if sbrx_map_system.current_field_id == SbrxFieldId(-2, 5) {
+                            if world.map_system.current_field_id == SbrxFieldId(-2, 5) {
                                 image(&rocketbay_texture, tc.transform.trans(track.x, track.y), g);
                             }
 							/*
@@ -6521,11 +5173,11 @@ fn main() {
 
                         if let Some(ref sj) = fighter_jet_instance {
                             // Don't render fighter_jet if player is in any area (bunker, raptor nest, etc.)
-                            if current_area.is_none()
-                                && sbrx_map_system.current_field_id == fighter_jet_current_sbrx_location
-                                && (!FOG_OF_WAR_ENABLED
+                            if world.current_area.is_none()
+                                && world.map_system.current_field_id == fighter_jet_current_sbrx_location
+                                && (!game_config.fog_of_war_enabled
                                     || fog_of_war.should_render_entity(
-                                        sbrx_map_system.current_field_id,
+                                        world.map_system.current_field_id,
                                         sj.x,
                                         sj.y,
                                     ))
@@ -6534,18 +5186,18 @@ fn main() {
                             }
                         }
 
-                        if sbrx_map_system.current_field_id == SbrxFieldId(-2, 5) {
+                        if world.map_system.current_field_id == SbrxFieldId(-2, 5) {
                             if let Some(field_survivors) =
-                                survivors.get(&sbrx_map_system.current_field_id)
+                                world.survivors.get(&world.map_system.current_field_id)
                             {
                                 for survivor in field_survivors {
                                     if !survivor.is_rescued && 
                                        survivor.x > view_l && survivor.x < view_r && 
                                        survivor.y > view_t && survivor.y < view_b 
                                     {
-                                        if !FOG_OF_WAR_ENABLED
+                                        if !game_config.fog_of_war_enabled
                                             || fog_of_war.is_position_visible(
-                                                sbrx_map_system.current_field_id,
+                                                world.map_system.current_field_id,
                                                 survivor.x,
                                                 survivor.y,
                                             )
@@ -6569,11 +5221,11 @@ fn main() {
                         }
 
                         for site in &crashed_fighter_jet_sites {
-                            if current_area.is_none()
-                                && site.sbrx_field_id == sbrx_map_system.current_field_id
-                                && (!FOG_OF_WAR_ENABLED
+                            if world.current_area.is_none()
+                                && site.sbrx_field_id == world.map_system.current_field_id
+                                && (!game_config.fog_of_war_enabled
                                     || fog_of_war.should_render_entity(
-                                        sbrx_map_system.current_field_id,
+                                        world.map_system.current_field_id,
                                         site.world_x,
                                         site.world_y,
                                     ))
@@ -6589,11 +5241,11 @@ fn main() {
                                 );
                             }
                         }
-                        if current_area.is_none() {
+                        if world.current_area.is_none() {
                             for nest in &raptor_nests {
-                                if !FOG_OF_WAR_ENABLED
+                                if !game_config.fog_of_war_enabled
                                     || fog_of_war.should_render_entity(
-                                        sbrx_map_system.current_field_id,
+                                        world.map_system.current_field_id,
                                         nest.x,
                                         nest.y,
                                     )
@@ -6602,16 +5254,16 @@ fn main() {
                                 }
                             }
                         }
-                        for cpu_entity in &cpu_entities {
+                        for (cpu_index, cpu_entity) in world.cpu_entities.iter().enumerate() {
                             // Frustum Culling: Skip CPUs that aren't visible
                             if cpu_entity.x < view_l || cpu_entity.x > view_r || 
                                cpu_entity.y < view_t || cpu_entity.y > view_b {
                                 continue;
                             }							
 							
-                            if !FOG_OF_WAR_ENABLED
+                            if !game_config.fog_of_war_enabled
                                 || fog_of_war.should_render_entity(
-                                    sbrx_map_system.current_field_id,
+                                    world.map_system.current_field_id,
                                     cpu_entity.x,
                                     cpu_entity.y,
                                 )
@@ -6628,12 +5280,13 @@ fn main() {
                                     CpuVariant::NightReaver => &night_reaver_cpu_textures,
                                     CpuVariant::RazorFiend => &razor_fiend_cpu_textures,
                                 };
-                                cpu_entity.draw(tc, g, textures_to_use);
+                                let (cpu_dx, cpu_dy) = render_view.cpu_offset(cpu_index, cpu_entity);
+                                cpu_entity.draw(tc.trans(cpu_dx, cpu_dy), g, textures_to_use);
                             }
                         }
 						
-                        if sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
-                            for cr in &cpu_racers {
+                        if world.map_system.current_field_id == SbrxFieldId(0, 0) {
+                            for cr in &world.cpu_racers {
                                 cr.draw(tc, g, &racer2_textures, &sbrx_bike_crashed_texture);
                             }
                         }						
@@ -6656,10 +5309,18 @@ fn main() {
                             );
                         }
 
-                        for orb in &pulse_orbs {
+                        for turret in &world.turrets {
+                            turret.draw(tc, g);
+                        }
+
+                        for orb in &world.pulse_orbs {
                             orb.draw(tc, g, &pulse_orb_texture);
                         }
 
+                        for effect in &world.skill_effects {
+                            effect.draw(tc, g);
+                        }
+
                         for effect in &active_kinetic_strike_effects {
                             let alpha = (effect.lifetime / effect.max_lifetime) as f32;
                             let img = Image::new_color([1.0, 1.0, 1.0, alpha]);
@@ -6686,7 +5347,7 @@ fn main() {
                             let bls = (ldx * ldx + ldy * ldy).sqrt();
                             
                             if bls > 0.0 {
-                                let mut rng = rand::rng();
+                                let mut rng = utils::rng::cosmetic_rng();
                                 let ndx = ldx / bls;
                                 let ndy = ldy / bls;
 								
@@ -6727,6 +5388,16 @@ fn main() {
                             }
                         }						
 
+                        // DEBUG (F12): hurtboxes and recent attack hitboxes
+                        if show_collision_debug == 2 {
+                            let racers: &[CpuRacer] = if world.map_system.current_field_id == SbrxFieldId(0, 0) {
+                                &world.cpu_racers
+                            } else {
+                                &[]
+                            };
+                            hitbox::draw_debug(tc.transform, g, &world.cpu_entities, racers);
+                        }
+
                         // CRATER STRIKE RADIUS: deactivated
                         /*
                                                 if !is_special_strike_active {
@@ -6741,23 +5412,23 @@ fn main() {
                                                 }
                         */
                         // NEW: Draw frontal strike visual effect
-                        if frontal_strike_timer > 0.0 {
-                            let mut color = frontal_strike_color;
+                        if frontal_strike.timer > 0.0 {
+                            let mut color = frontal_strike.color;
                             // Fade out alpha
                             color[3] =
-                                frontal_strike_color[3] * (frontal_strike_timer / 0.1) as f32;
+                                frontal_strike.color[3] * (frontal_strike.timer / 0.1) as f32;
 
                             let mut points = Vec::new();
-                            points.push([fighter.x, fighter.y]); // Center of the arc is the player
+                            points.push([fighter_x, fighter_y]); // Center of the arc is the player
 
                             let num_segments = 20; // Number of triangles to approximate the arc
-                            let angle_start = frontal_strike_angle - std::f64::consts::FRAC_PI_2; // -90 degrees
+                            let angle_start = frontal_strike.angle - std::f64::consts::FRAC_PI_2; // -90 degrees
 
                             for i in 0..=num_segments {
                                 let current_angle = angle_start
                                     + (std::f64::consts::PI * (i as f64 / num_segments as f64));
-                                let px = fighter.x + fixed_crater.radius * current_angle.cos();
-                                let py = fighter.y + fixed_crater.radius * current_angle.sin();
+                                let px = fighter_x + world.fixed_crater.radius * current_angle.cos();
+                                let py = fighter_y + world.fixed_crater.radius * current_angle.sin();
                                 points.push([px, py]);
                             }
 
@@ -6766,22 +5437,22 @@ fn main() {
 
                         // Draw bike/vehicles AFTER entities but BEFORE player
                         if sbrx_bike.visible
-                            && (!FOG_OF_WAR_ENABLED
+                            && (!game_config.fog_of_war_enabled
                                 || fog_of_war.should_render_entity(
-                                    sbrx_map_system.current_field_id,
+                                    world.map_system.current_field_id,
                                     sbrx_bike.x,
                                     sbrx_bike.y,
                                 ))
                         {
                             // Don't render bike in area instances
-                            let should_render_bike = if let Some(ref _area_state) = current_area {
+                            let should_render_bike = if let Some(ref _area_state) = world.current_area {
                                 false // Never render bike in any area
                             } else {
                                 true // Render normally in open world
                             };
 
                             if should_render_bike {
-                                let vehicle_texture_to_draw = match (fighter.fighter_type, sbrx_bike.is_crashed) {
+                                let vehicle_texture_to_draw = match (world.fighter.fighter_type, sbrx_bike.is_crashed) {
                                     (FighterType::Soldier, false) => Some(&sbrx_quad_texture),
                                     (FighterType::Soldier, true) => Some(&sbrx_quad_crashed_texture),
                                     (_, false) => Some(&sbrx_bike_texture),
@@ -6799,14 +5470,14 @@ fn main() {
                                     );
                                 }
 
-                                if !FOG_OF_WAR_ENABLED
+                                if !game_config.fog_of_war_enabled
                                     || fog_of_war.is_position_visible(
-                                        sbrx_map_system.current_field_id,
+                                        world.map_system.current_field_id,
                                         sbrx_bike.x,
                                         sbrx_bike.y,
                                     )
                                 {
-                                    fighter.draw_bike_interaction_indicator(
+                                    world.fighter.draw_bike_interaction_indicator(
                                         oc,
                                         g,
                                         sbrx_bike.x,
@@ -6847,25 +5518,25 @@ fn main() {
                             }
                         });
                         if !is_paused {
-                            let (wmxr, _) = screen_to_world(&camera, mouse_x, mouse_y);
-                            let flip = wmxr < fighter.x;
+                            let (wmxr, _) = screen_to_world(camera, mouse_x, mouse_y);
+                            let flip = wmxr < fighter_x;
                             let sw = current_racer_texture.get_width() as f64;
                             let sh = current_racer_texture.get_height() as f64;
-                            let idx = fighter.x - sw / 2.0;
-                            let idy = fighter.y - sh / 2.0;
+                            let idx = fighter_x - sw / 2.0;
+                            let idy = fighter_y - sh / 2.0;
 							
                             // Display Atomic State visual beneath player if Invincible 
                             // Logic: Prevent during stun recovery (stun_timer/knockback) and block-break.
                             // Added check: Only show if timer > 1.0s to prevent showing during the 1s post-collision penalty.
-                            if fighter.invincible_timer > 1.0 
-                                && fighter.stun_timer <= 0.0 
-                                && fighter.knockback_duration <= 0.0 
-                                && !block_system.is_stun_locked() 
+                            if world.fighter.invincible_timer > 1.0 
+                                && world.fighter.stun_timer <= 0.0 
+                                && world.fighter.knockback_duration <= 0.0 
+                                && !world.block_system.is_stun_locked() 
                             {
                                 let tex_w = atomic_state_texture.get_width() as f64;
                                 let tex_h = atomic_state_texture.get_height() as f64;
                                 // Center the effect on the player's position
-                                image(&atomic_state_texture, tc.transform.trans(fighter.x - tex_w / 2.0, fighter.y - tex_h / 2.0), g);
+                                image(&atomic_state_texture, tc.transform.trans(fighter_x - tex_w / 2.0, fighter_y - tex_h / 2.0), g);
                             }							
 							
                             let mut dtf = tc.transform.trans(idx, idy);
@@ -6873,11 +5544,23 @@ fn main() {
                                 dtf = tc.transform.trans(idx + sw, idy).scale(-1.0, 1.0);
                             }
                             image(current_racer_texture, dtf, g);
-                            fighter.draw_health_bar(tc, g);
+                            world.fighter.draw_health_bar(fighter_tc, g);
+                            world.block_system.draw_parry_flash(tc.transform, g, fighter_x, fighter_y);
+                            if world.block_system.parry_flash_timer > 0.0 {
+                                text::Text::new_color([0.6, 0.9, 1.0, 1.0], 22)
+                                    .draw(
+                                        "PARRY",
+                                        &mut glyphs,
+                                        &tc.draw_state,
+                                        tc.transform.trans(fighter_x - 35.0, fighter_y - 100.0),
+                                        g,
+                                    )
+                                    .ok();
+                            }
 							
                             // Draw Shift Function Indicator (Boost/Ranged)
-                            if fighter.fighter_type == FighterType::Racer && fighter.boost_indicator_timer > 0.0 {
-                                let indicator_tex = if fighter.boost {
+                            if world.fighter.fighter_type == FighterType::Racer && world.fighter.boost_indicator_timer > 0.0 {
+                                let indicator_tex = if world.fighter.boost {
                                     &set_boost_texture
                                 } else {
                                     &set_ranged_texture
@@ -6888,15 +5571,15 @@ fn main() {
                                 // We'll put this slightly above that
                                 let ind_w = indicator_tex.get_width() as f64;
                                 let _ind_h = indicator_tex.get_height() as f64;
-                                let ind_x = fighter.x - ind_w / 2.0;
-                                let ind_y = fighter.y - 125.0; // Above HP bar
+                                let ind_x = fighter_x - ind_w / 2.0;
+                                let ind_y = fighter_y - 125.0; // Above HP bar
                                 
                                 image(indicator_tex, tc.transform.trans(ind_x, ind_y), g);
                             }							
                         }
                         // Show fatigue icon as soon as stun ends (during vulnerability) and through the fatigue period.
-                        if block_system.block_fatigue
-                            || (block_system.block_broken && block_system.stun_lock_timer <= 0.0)
+                        if world.block_system.block_fatigue
+                            || (world.block_system.block_broken && world.block_system.stun_lock_timer <= 0.0)
                         {
                             let radius = 60.0;
                             let (offset_x, offset_y) = (radius * 0.707, -radius * 0.707);
@@ -6904,18 +5587,19 @@ fn main() {
                             let tex_w = block_fatigue_texture.get_width() as f64;
                             let tex_h = block_fatigue_texture.get_height() as f64;
 
-                            let img_center_x = fighter.x + offset_x;
-                            let img_center_y = fighter.y + offset_y;
+                            let img_center_x = fighter_x + offset_x;
+                            let img_center_y = fighter_y + offset_y;
 
                             let img_x = img_center_x - tex_w / 2.0;
                             let img_y = img_center_y - tex_h / 2.0;
 
                             image(&block_fatigue_texture, tc.transform.trans(img_x, img_y), g);
-                        } else if block_system.kinetic_intake_count > 0 {
-                            let ki_count = block_system.kinetic_intake_count;
+                        } else if world.block_system.kinetic_intake_count > 0 {
+                            let ki_count = world.block_system.kinetic_intake_count;
                             let ki_text = format!("{}", ki_count);
                             let ki_font_size = 24;
-                            let ki_color = match ki_count {
+                            // Color tiers follow the strike tiers, whatever the max block count
+                            let ki_color = match world.block_system.kinetic_level() {
                                 1..=10 => [0.0, 1.0, 0.0, 1.0],  // GREEN
                                 11..=17 => [1.0, 1.0, 0.0, 1.0], // YELLOW
                                 18..=20 => [1.0, 0.0, 0.0, 1.0], // RED
@@ -6925,8 +5609,8 @@ fn main() {
                             let radius = 60.0;
                             let positions = [(radius * 0.707, -radius * 0.707)];
                             for (offset_x, offset_y) in &positions {
-                                let text_x = fighter.x + offset_x - text_width / 2.0;
-                                let text_y = fighter.y + offset_y + ki_font_size as f64 / 2.0;
+                                let text_x = fighter_x + offset_x - text_width / 2.0;
+                                let text_y = fighter_y + offset_y + ki_font_size as f64 / 2.0;
                                 let padding = 1.0;
                                 let backdrop_x = text_x - padding;
                                 let backdrop_y = text_y - ki_font_size as f64 - padding;
@@ -6950,11 +5634,11 @@ fn main() {
                                     .ok();
                             }
                         }
-                        if FOG_OF_WAR_ENABLED {
-                            fog_of_war.render_fog_overlay(sbrx_map_system.current_field_id, tc, g);
+                        if game_config.fog_of_war_enabled {
+                            fog_of_war.render_fog_overlay(world.map_system.current_field_id, tc, g);
                         }
-                        task_system.draw(oc, g, &mut glyphs);
-                        fighter.draw_score(tc, oc, g);
+                        world.task_system.draw(oc, g, &mut glyphs);
+                        world.fighter.draw_score(tc, oc, g);
 
                         if let Some(notification) = &task_reward_notification {
                             let font_size = 18;
@@ -7014,7 +5698,7 @@ fn main() {
                         }						
 
                         // --- WAVE UI ---
-                        if let Some(wave_text) = wave_manager.get_ui_text() {
+                        if let Some(wave_text) = world.wave_manager.get_ui_text() {
                             let font_size = 22;
                             let text_color = [1.0, 0.2, 0.2, 1.0]; // Red
                             let text_width = glyphs.width(font_size, &wave_text).unwrap_or(0.0);
@@ -7073,18 +5757,18 @@ fn main() {
                                 .ok();								
                         }
 
-                        block_system.draw_ui(oc, g);
-                        fighter.draw_fuel_meter(oc, g, &mut glyphs);
-                        fighter.draw_ammo_gauge(oc, g, &mut glyphs);
+                        world.block_system.draw_ui(oc, g);
+                        world.fighter.draw_skill_bar(oc, g, &mut glyphs, &key_bindings);
+                        world.fighter.resources.draw_hud(oc, g, &mut glyphs);
 
                         // Calculate level modifier for the current fighter for rendering
                         let mut total_level_mod_for_render = 0;
                         let active_traits_for_render = field_trait_manager
-                            .get_active_traits_for_field(&sbrx_map_system.current_field_id);
+                            .get_active_traits_for_field(&world.map_system.current_field_id);
                         for trait_instance in &active_traits_for_render {
                             let mut applies = false;
                             if let TraitTarget::Fighter(target_ft) = trait_instance.target {
-                                if target_ft == fighter.fighter_type {
+                                if target_ft == world.fighter.fighter_type {
                                     applies = true;
                                 }
                             }
@@ -7094,11 +5778,11 @@ fn main() {
                             }
                         }
 
-                        fighter.draw_stats_display(oc, g, &mut glyphs, total_level_mod_for_render);
+                        world.fighter.draw_stats_display(oc, g, &mut glyphs, total_level_mod_for_render);
 
                         // --- Render Active Field Traits ---
                         let active_traits = field_trait_manager
-                            .get_active_traits_for_field(&sbrx_map_system.current_field_id);
+                            .get_active_traits_for_field(&world.map_system.current_field_id);
                         if !active_traits.is_empty() {
                             let trait_font_size = 15;
                             let trait_color = [0.1, 1.0, 0.1, 1.0]; // green
@@ -7120,7 +5804,7 @@ fn main() {
                         }
 
                         // --- NEW: Render Group Icon UI ---
-                        fighter_hp_map.insert(fighter.fighter_type, fighter.current_hp);
+                        fighter_hp_map.insert(world.fighter.fighter_type, world.fighter.current_hp);
                         // INCREASE THIS VALUE FOR MORE HORIZONTAL SPACING BETWEEN GROUP ICONS
                         let mut current_x = 25.0;
                         let start_y = 70.0;
@@ -7143,8 +5827,8 @@ fn main() {
 
                             // BUG FIX 2: Check if fighter is downed
                             if has_joined && !downed_fighters.contains(ft) {
-                                let is_selected = fighter.fighter_type == *ft;
-                                let icon_to_draw = if *ft == FighterType::Racer && fighter.racer_variant == 1 {
+                                let is_selected = world.fighter.fighter_type == *ft;
+                                let icon_to_draw = if *ft == FighterType::Racer && world.fighter.racer_variant == 1 {
                                     if is_selected { Some(&racer2_group_icon_selected) } else { Some(&racer2_group_icon) }
                                 } else {
                                     if is_selected { group_icons_selected.get(ft) } else { group_icons.get(ft) }
@@ -7157,7 +5841,7 @@ fn main() {
                                     image(icon, oc.transform.trans(current_x, start_y), g);
 
                                     // Draw '+' sign if level up points are available
-                                    if fighter.stat_points_to_spend.get(ft).unwrap_or(&0) > &0 {
+                                    if world.fighter.stat_points_to_spend.get(ft).unwrap_or(&0) > &0 {
                                         let plus_text = "+";
                                         let plus_font_size = 20;
                                         let plus_color = [1.0, 0.5, 0.0, 1.0]; // Orange
@@ -7233,19 +5917,19 @@ fn main() {
                             }
                         }
 						
-                        if fighter.show_gear {
+                        if world.fighter.show_gear {
                             let gear_h = gear_texture.get_height() as f64;
                             // Drawing here ensures it is beneath the cursor logic that follows
-                            image(&gear_texture, tc.transform.trans(fighter.x + 175.0, fighter.y - gear_h / 2.5), g);
+                            image(&gear_texture, tc.transform.trans(fighter_x + 175.0, fighter_y - gear_h / 2.5), g);
                         }						
 
-                        let (wmxc, wmxyc) = screen_to_world(&camera, mouse_x, mouse_y);
-                        let dx_c = wmxc - fixed_crater.x;
-                        let dy_c = wmxyc - fixed_crater.y;
-                        let hr_c = fixed_crater.radius;
-                        let vr_c = fixed_crater.radius * 0.75;
+                        let (wmxc, wmxyc) = screen_to_world(camera, mouse_x, mouse_y);
+                        let dx_c = wmxc - world.fixed_crater.x;
+                        let dy_c = wmxyc - world.fixed_crater.y;
+                        let hr_c = world.fixed_crater.radius;
+                        let vr_c = world.fixed_crater.radius * 0.75;
                         let ds_c = (dx_c * dx_c) / (hr_c * hr_c) + (dy_c * dy_c) / (vr_c * vr_c);
-                        let cur_tex = match fighter.combat_mode {
+                        let cur_tex = match world.fighter.combat_mode {
                             CombatMode::CloseCombat => &strike_texture,
                             CombatMode::Ranged => &aim_texture,
                             CombatMode::Balanced => {
@@ -7258,12 +5942,12 @@ fn main() {
                         };
                         let cur_w = cur_tex.get_width() as f64;
                         let cur_h = cur_tex.get_height() as f64;
-                        let can_shoot_ranged = fighter.combat_mode == CombatMode::Ranged
-                            || (fighter.combat_mode == CombatMode::Balanced && ds_c > 1.0);
-                        let dist_to_mouse = ((wmxc - fighter.x).powi(2) + (wmxyc - fighter.y).powi(2)).sqrt();
-                        let is_beyond_range = can_shoot_ranged && dist_to_mouse > fighter.get_shoot_radius();
+                        let can_shoot_ranged = world.fighter.combat_mode == CombatMode::Ranged
+                            || (world.fighter.combat_mode == CombatMode::Balanced && ds_c > 1.0);
+                        let dist_to_mouse = ((wmxc - fighter_x).powi(2) + (wmxyc - fighter_y).powi(2)).sqrt();
+                        let is_beyond_range = can_shoot_ranged && dist_to_mouse > world.fighter.get_shoot_radius();
  
-                        if (can_shoot_ranged && shoot.cooldown > 0.0) || is_beyond_range {
+                        if (can_shoot_ranged && world.shoot.cooldown > 0.0) || is_beyond_range {
                             piston_window::rectangle(
                                 [1.0, 0.0, 0.0, 0.5],
                                 [wmxc - cur_w / 2.0, wmxyc - cur_h / 2.0, cur_w, cur_h],
@@ -7276,28 +5960,28 @@ fn main() {
                             tc.transform.trans(wmxc - cur_w / 2.0, wmxyc - cur_h / 2.0),
                             g,
                         );
-                        if strike.visible {
-                            let strike_color = if block_system.is_kinetic_strike_active() {
+                        if world.strike.visible {
+                            let strike_color = if world.block_system.is_kinetic_strike_active() {
                                 [0.0, 1.0, 0.2, 0.8]
-                            } else if combo_system.is_combo_strike_active() {
+                            } else if world.combo_system.is_combo_strike_active() {
                                 [0.0, 1.0, 0.2, 0.8]
                             } else {
                                 [1.0, 1.0, 1.0, 0.8]
                             };
-                            let num_slashes = if block_system.is_kinetic_strike_active() && fighter.fighter_type == FighterType::Raptor {
+                            let num_slashes = if world.block_system.is_kinetic_strike_active() && world.fighter.fighter_type == FighterType::Raptor {
                                 // RAPTOR kinetic strike uses triple slash visual
                                 3
-                            } else if combo_system.is_combo_strike_active() {
-                                combo_finisher_slash_count
-                            } else if rush_active && fighter.fighter_type == FighterType::Raptor {
+                            } else if world.combo_system.is_combo_strike_active() {
+                                frontal_strike.slash_count
+                            } else if rush_active && world.fighter.fighter_type == FighterType::Raptor {
                                 // raptor rush attack uses triple slash visual
                                 3
                             } else {
                                 1
                             };
-                            let angle_rad = strike.angle.to_radians();
+                            let angle_rad = world.strike.angle.to_radians();
                             let slash_base_half_width = 52.1;
-                            let local_tip = [strike.length, 0.0];
+                            let local_tip = [world.strike.length, 0.0];
                             let local_base1 = [0.0, slash_base_half_width];
                             let local_base2 = [0.0, -slash_base_half_width];
                             let local_polygon = [local_tip, local_base1, local_base2];
@@ -7305,14 +5989,14 @@ fn main() {
                                 polygon(
                                     strike_color,
                                     &local_polygon,
-                                    tc.transform.trans(strike.x, strike.y).rot_rad(angle_rad),
+                                    tc.transform.trans(world.strike.x, world.strike.y).rot_rad(angle_rad),
                                     g,
                                 );
                             } else if num_slashes == 2 {
                                 polygon(
                                     strike_color,
                                     &local_polygon,
-                                    tc.transform.trans(strike.x, strike.y).rot_rad(angle_rad),
+                                    tc.transform.trans(world.strike.x, world.strike.y).rot_rad(angle_rad),
                                     g,
                                 );
                                 let perpendicular_angle = angle_rad + std::f64::consts::PI / 2.0;
@@ -7320,13 +6004,13 @@ fn main() {
                                     strike_color,
                                     &local_polygon,
                                     tc.transform
-                                        .trans(strike.x, strike.y)
+                                        .trans(world.strike.x, world.strike.y)
                                         .rot_rad(perpendicular_angle),
                                     g,
                                 );
                             } else if num_slashes == 3 {
                                 let base_transform =
-                                    tc.transform.trans(strike.x, strike.y).rot_rad(angle_rad);
+                                    tc.transform.trans(world.strike.x, world.strike.y).rot_rad(angle_rad);
                                 polygon(strike_color, &local_polygon, base_transform, g);
                                 let back_offset_1 = -25.0;
                                 let back_offset_2 = 25.0;
@@ -7339,21 +6023,21 @@ fn main() {
                                 polygon(strike_color, &local_polygon, second_add_transform, g);
                             }
                         }
-                        if shoot.visible {
+                        if world.shoot.visible {
                             image(
                                 current_ranged_marker_texture,
                                 tc.transform.trans(
-                                    shoot.target_x - (current_ranged_marker_texture.get_width() as f64 / 2.0),
-                                    shoot.target_y - (current_ranged_marker_texture.get_height() as f64 / 2.0),
+                                    world.shoot.target_x - (current_ranged_marker_texture.get_width() as f64 / 2.0),
+                                    world.shoot.target_y - (current_ranged_marker_texture.get_height() as f64 / 2.0),
                                 ),
                                 g,
                             );
                         }
-                        if shoot.line_visible {
-                            let sx = shoot.start_x;
-                            let sy = shoot.start_y;
-                            let ex = shoot.target_x;
-                            let ey = shoot.target_y;
+                        if world.shoot.line_visible {
+                            let sx = world.shoot.start_x;
+                            let sy = world.shoot.start_y;
+                            let ex = world.shoot.target_x;
+                            let ey = world.shoot.target_y;
                             let ldxs = ex - sx;
                             let ldys = ey - sy;
                             let bls = (ldxs * ldxs + ldys * ldys).sqrt();
@@ -7372,7 +6056,7 @@ fn main() {
                             //                                line([1.0, 1.0, 1.0, 0.8], 2.0, [sx, sy, ex, ey], tc.transform, g);
                             //                            }
                             if bls > 0.0 {
-                                let mut ers = rand::rng();
+                                let mut ers = utils::rng::cosmetic_rng();
                                 for (lm, ofr, col, w) in [
                                     (0.7, 5.0, [0.8, 0.8, 1.0, 0.7], 1.0),
                                     (1.2, 8.0, [0.7, 0.7, 0.9, 0.6], 1.5),
@@ -7387,11 +6071,26 @@ fn main() {
                                 }
                             }
                         }
-                        for text in &damage_texts {
+                        for text in &world.damage_texts {
                             let final_color = text.color;
-                            let text_width = glyphs.width(16, &text.text).unwrap_or(0.0);
+                            let font_size = text.style.font_size();
+                            let text_width = glyphs.width(font_size, &text.text).unwrap_or(0.0);
                             let text_x = text.x - text_width / 2.0;
-                            text::Text::new_color(final_color, 16)
+                            if text.style == TextStyle::Crit {
+                                // Dark outline so crits stand out from the hits around them
+                                for (ox, oy) in [(-2.0, 0.0), (2.0, 0.0), (0.0, -2.0), (0.0, 2.0)] {
+                                    text::Text::new_color([0.0, 0.0, 0.0, 1.0], font_size)
+                                        .draw(
+                                            &text.text,
+                                            &mut glyphs,
+                                            &tc.draw_state,
+                                            tc.transform.trans(text_x + ox, text.y + oy),
+                                            g,
+                                        )
+                                        .ok();
+                                }
+                            }
+                            text::Text::new_color(final_color, font_size)
                                 .draw(
                                     &text.text,
                                     &mut glyphs,
@@ -7401,7 +6100,7 @@ fn main() {
                                 )
                                 .unwrap_or_else(|e| eprintln!("Failed to draw damage text: {}", e));
                         }
-						let field_display_text = if let Some(area_ref) = current_area.as_ref() {
+						let field_display_text = if let Some(area_ref) = world.current_area.as_ref() {
 							match area_ref.area_type {
                                 AreaType::RaptorNest => format!(
                                     "FLATLINE_field.x[1]y[0] RAPTOR NEST[{}]",
@@ -7413,8 +6112,8 @@ fn main() {
                                 ),
                             }
                         } else {
-                            let mut base_text = sbrx_map_system.get_display_string();
-                            let current_field = sbrx_map_system.current_field_id;
+                            let mut base_text = world.map_system.get_display_string();
+                            let current_field = world.map_system.current_field_id;
                             if current_field == SbrxFieldId(0, 0) {
                                 base_text.push_str(" RACETRACK");
                             } else if current_field == SbrxFieldId(-2, 5) {
@@ -7465,7 +6164,7 @@ fn main() {
                         .unwrap_or_else(|e| eprintln!("Failed to draw field text: {:?}", e));
 						
                         // --- ENEMY APTITUDE UI ---
-                        let base_aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+                        let base_aptitude = get_field_aptitude(world.map_system.current_field_id);
                         let aptitude = if endless_arena_mode_active {
                             base_aptitude + (endless_arena_timer / 10.0).floor() * 0.10
                         } else {
@@ -7483,10 +6182,10 @@ fn main() {
                             g,
                         ).ok();						
 						
-                        if FOG_OF_WAR_ENABLED {
-                            if fog_of_war.is_fog_enabled(sbrx_map_system.current_field_id) {
+                        if game_config.fog_of_war_enabled {
+                            if fog_of_war.is_fog_enabled(world.map_system.current_field_id) {
                                 let (explored, total, percentage) = fog_of_war
-                                    .get_exploration_stats(sbrx_map_system.current_field_id);
+                                    .get_exploration_stats(world.map_system.current_field_id);
                                 let exploration_text = format!(
                                     "EXPLORED: {:.1}% ({}/{})",
                                     percentage, explored, total
@@ -7744,7 +6443,7 @@ fn main() {
 
                         if show_bunker_floor_transition_prompt {
                             if let Some(target_floor) = target_floor_from_prompt {
-								let prompt_text = match current_area.as_ref() {
+								let prompt_text = match world.current_area.as_ref() {
 									Some(area) if target_floor > area.floor => "ASCEND [E]",
 									_ => "DESCEND [E]",
 								};
@@ -7790,20 +6489,20 @@ fn main() {
                             image(&pause_screen_texture, c.transform.trans(x, y), g);
 
                             // Draw player on top of pause screen
-                            let (wmxr, _) = screen_to_world(&camera, mouse_x, mouse_y);
-                            let flip = wmxr < fighter.x;
+                            let (wmxr, _) = screen_to_world(camera, mouse_x, mouse_y);
+                            let flip = wmxr < fighter_x;
                             let sw = current_racer_texture.get_width() as f64;
                             let sh = current_racer_texture.get_height() as f64;
-                            let idx = fighter.x - sw / 2.0;
-                            let idy = fighter.y - sh / 2.0;
+                            let idx = fighter_x - sw / 2.0;
+                            let idy = fighter_y - sh / 2.0;
                             let mut dtf = tc.transform.trans(idx, idy);
                             if flip {
                                 dtf = tc.transform.trans(idx + sw, idy).scale(-1.0, 1.0);
                             }
                             image(current_racer_texture, dtf, g);
-                            fighter.draw_health_bar(tc, g);
+                            world.fighter.draw_health_bar(fighter_tc, g);
 
-                            fighter.draw_inputs_display(oc, g, &inputs_display_texture);
+                            world.fighter.draw_inputs_display(oc, g, &inputs_display_texture);
                         }										
 
                         // --- Find hovered CPU entity ---
@@ -7811,13 +6510,13 @@ fn main() {
                         if !is_paused {
                             // Only check for hover when not paused
                             let (world_mouse_x, world_mouse_y) =
-                                screen_to_world(&camera, mouse_x, mouse_y);
+                                screen_to_world(camera, mouse_x, mouse_y);
 
-                            for cpu in &cpu_entities {
+                            for cpu in &world.cpu_entities {
                                 // Only check visible entities
-                                if !FOG_OF_WAR_ENABLED
+                                if !game_config.fog_of_war_enabled
                                     || fog_of_war.should_render_entity(
-                                        sbrx_map_system.current_field_id,
+                                        world.map_system.current_field_id,
                                         cpu.x,
                                         cpu.y,
                                     )
@@ -7883,6 +6582,37 @@ fn main() {
                                 .ok();
                         }
 
+                        // Gamepad aim reticle: while the right stick is held it stands in for the mouse pointer
+                        if !is_paused && gamepad.right_stick(&game_config.gamepad).is_some() {
+                            let reticle_color = [1.0, 1.0, 1.0, 0.85];
+                            let reticle_radius = 14.0;
+                            Ellipse::new_border(reticle_color, 1.5).draw(
+                                [
+                                    mouse_x - reticle_radius,
+                                    mouse_y - reticle_radius,
+                                    reticle_radius * 2.0,
+                                    reticle_radius * 2.0,
+                                ],
+                                &oc.draw_state,
+                                oc.transform,
+                                g,
+                            );
+                            line(reticle_color, 1.0, [mouse_x - 20.0, mouse_y, mouse_x - 6.0, mouse_y], oc.transform, g);
+                            line(reticle_color, 1.0, [mouse_x + 6.0, mouse_y, mouse_x + 20.0, mouse_y], oc.transform, g);
+                            line(reticle_color, 1.0, [mouse_x, mouse_y - 20.0, mouse_x, mouse_y - 6.0], oc.transform, g);
+                            line(reticle_color, 1.0, [mouse_x, mouse_y + 6.0, mouse_x, mouse_y + 20.0], oc.transform, g);
+                        }
+
+                        if let Some(training) = &training_mode {
+                            training.draw(
+                                oc,
+                                g,
+                                &mut glyphs,
+                                screen_width,
+                                &world,
+                            );
+                        }
+
                         // Draw the chatbox on top of everything including pause screen
                         chatbox.draw(oc, g, &mut glyphs);
 
@@ -7896,54 +6626,60 @@ fn main() {
                                 g,
                                 &mut glyphs,
                                 &pressed_keys,
+                                &key_bindings,
                                 mouse_lmb_pressed,
                                 mouse_rmb_pressed,
                             );
                         }						
 
+                        if let Some(screen) = &rebind_screen {
+                            screen.draw(oc, g, &mut glyphs, screen_width, screen_height);
+                        }
+
                         glyphs.factory.encoder.flush(device);
                     });
                 }
 
                 if let Some(Button::Mouse(MouseButton::Left)) = e.press_args() {
-                    if !block_system.is_stun_locked() && fighter.stun_timer <= 0.0 {
-                        if fighter.is_reloading {
+                    if !world.block_system.is_stun_locked() && world.fighter.stun_timer <= 0.0 {
+                        if world.fighter.is_reloading() {
                             // Disable LMB input during reload
                         } else {
                             // Apply combat action slowdown when on bike
-                            if fighter.state == RacerState::OnBike {
-                                fighter.combat_action_slowdown_timer = 0.25;
+                            if world.fighter.state == RacerState::OnBike {
+                                world.fighter.combat_action_slowdown_timer = 0.25;
                             }
 
-                            if block_system.rmb_held {
-                                if block_system.kinetic_intake_count > 0
-                                    && !block_system.block_fatigue
-                                    && !block_system.block_broken
+                            if world.block_system.rmb_held {
+                                if world.block_system.kinetic_intake_count > 0
+                                    && !world.block_system.block_fatigue
+                                    && !world.block_system.block_broken
+                                    && cancels(CombatPhase::Blocking, world.block_system.raised_for, CombatInput::Strike)
                                 {
-                                    let intake_count = block_system.kinetic_intake_count;
+                                    let intake_count = world.block_system.kinetic_level();
                                     // RANGED KINETIC STRIKE = off. remove fighter.x,.y,
                                     // let (wmx, wmy) = screen_to_world(&camera, mouse_x, mouse_y);
-                                    block_system.perform_kinetic_strike(
+                                    world.block_system.perform_kinetic_strike(
                                         //    wmx,
                                         //     wmy,
-                                        fighter.x,
-                                        fighter.y,
-                                        &mut fighter,
-                                        &mut cpu_entities,
-										&mut cpu_racers,
-                                        &mut strike,
-                                        &audio_manager,
+                                        world.fighter.x,
+                                        world.fighter.y,
+                                        &mut world.fighter,
+                                        &mut world.cpu_entities,
+										&mut world.cpu_racers,
+                                        &mut world.strike,
+                                        &world.audio,
                                         line_y,
-                                        &mut damage_texts,
-                                        &mut task_system,
-                                        &mut combo_system,
+                                        &mut world.damage_texts,
+                                        &mut world.task_system,
+                                        &mut world.combo_system,
 										is_paused,
-										sbrx_map_system.current_field_id,
+										world.map_system.current_field_id,
                                     );
 									
                                     // Soldier Reward: 1.5s Invincibility on Kinetic Strike
-                                    if fighter.fighter_type == FighterType::Soldier {
-                                        fighter.invincible_timer = 2.0;
+                                    if world.fighter.fighter_type == FighterType::Soldier {
+                                        world.fighter.invincible_timer = 2.0;
                                         chatbox.add_interaction(vec![
                                             //("ATOMIC-STATE", MessageType::Warning),
                                         ]);
@@ -7959,8 +6695,8 @@ fn main() {
 
                                     active_kinetic_strike_effects.push(
                                         KineticStrikeEffectInstance {
-                                            x: fighter.x,
-                                            y: fighter.y,
+                                            x: world.fighter.x,
+                                            y: world.fighter.y,
                                             lifetime: 0.25,
                                             max_lifetime: 0.25,
                                             texture_index,
@@ -7980,8 +6716,8 @@ fn main() {
                                 }
                             } else {
                                 lmb_held = true; // Set held flag for continuous strikes
-                                if block_system.active {
-                                    block_system.deactivate();
+                                if world.block_system.active {
+                                    world.block_system.deactivate();
                                     if !is_high_priority_animation_active(
                                         rush_active,
                                         strike_animation_timer,
@@ -7994,74 +6730,51 @@ fn main() {
                                     }
                                 }
                                 let (wmx, wmy) = screen_to_world(&camera, mouse_x, mouse_y);
-                                let dx = wmx - fixed_crater.x;
-                                let dy = wmy - fixed_crater.y;
-                                let hr = fixed_crater.radius;
-                                let vr = fixed_crater.radius * 0.75;
+                                let dx = wmx - world.fixed_crater.x;
+                                let dy = wmy - world.fixed_crater.y;
+                                let hr = world.fixed_crater.radius;
+                                let vr = world.fixed_crater.radius * 0.75;
                                 let dsq = (dx * dx) / (hr * hr) + (dy * dy) / (vr * vr);
 
-                                let perform_melee = match fighter.combat_mode {
+                                let perform_melee = match world.fighter.combat_mode {
                                     CombatMode::CloseCombat => true,
                                     CombatMode::Ranged => false,
                                     CombatMode::Balanced => dsq <= 1.0,
                                 };
 
                                 if perform_melee {
-                                    handle_melee_strike(
-                                        &mut fighter,
-                                        &camera,
-                                        mouse_x,
-                                        mouse_y,
-                                        &mut fixed_crater,
-                                        &mut combo_system,
-                                        &mut strike,
-                                        &audio_manager,
-										&mut chatbox,
+                                    if handle_melee_strike(
+                                        &mut world,
+                                        screen_to_world(&camera, mouse_x, mouse_y),
+                                        is_paused,
+                                        &mut frontal_strike,
                                         &current_strike_textures,
                                         &mut current_racer_texture,
                                         &mut strike_frame,
-                                        &mut strike_animation_timer,
-                                        &mut movement_active,
-                                        &mut backpedal_active,
-                                        &mut frontal_strike_timer,
-                                        &mut frontal_strike_angle,
-                                        &mut frontal_strike_color,
-                                        &mut frontal_strike_is_special,
-                                        &mut combo_finisher_slash_count,
-                                        &mut cpu_entities,
-										&mut cpu_racers,
-                                        &mut damage_texts,
-										is_paused,
-										sbrx_map_system.current_field_id,
-                                    );
-                                    melee_rapid_fire_timer = MELEE_RAPID_FIRE_RATE;
+                                    ) {
+                                        strike_animation_timer = 0.25;
+                                        movement_active = false;
+                                        backpedal_active = false;
+                                    }
+                                    world.melee_rapid_fire_timer = MELEE_RAPID_FIRE_RATE;
                                 } else {
                                     // Ranged Attack
-                                    if fighter.fighter_type != FighterType::Raptor {
-                                        if fighter.fighter_type == FighterType::Soldier {
+                                    if world.fighter.fighter_type != FighterType::Raptor {
+                                        if world.fighter.fighter_type == FighterType::Soldier {
                                             //lmb_held = true;
                                             soldier_rapid_fire_timer = 0.0;
-                                        } else if fighter.fighter_type == FighterType::Racer
-                                            && shoot.cooldown <= 0.0
+                                        } else if world.fighter.fighter_type == FighterType::Racer
+                                            && world.shoot.cooldown <= 0.0
                                         {
-                                            audio_manager.play_sound_effect("ranged").ok();
-
-                                            let mut target_x = wmx;
-                                            let mut target_y = wmy;
-                                            let dx = wmx - fighter.x;
-                                            let dy = wmy - fighter.y;
-                                            let dist = (dx * dx + dy * dy).sqrt();
-                                            let max_range = fighter.get_shoot_radius();
-                                            if dist > max_range {
-                                                target_x = fighter.x + (dx / dist) * max_range;
-                                                target_y = fighter.y + (dy / dist) * max_range;
+                                            if world.fire_shot(wmx, wmy, world.fighter.get_shoot_radius()) {
+                                                world.shoot.cooldown = RACER_RANGED_COOLDOWN;
+                                                current_racer_texture = current_ranged_texture;
+                                                strike_animation_timer = 0.25;
+                                                movement_active = false;
+                                                backpedal_active = false;
+                                            } else {
+                                                world.fighter.trigger_reload(&world.audio);
                                             }
-                                            shoot.trigger(fighter.x, fighter.y, target_x, target_y);
-                                            shoot.cooldown = RACER_RANGED_COOLDOWN;
-                                            current_racer_texture = current_ranged_texture;
-                                            strike_animation_timer = 0.25;
-                                            movement_active = false;
-                                            backpedal_active = false;
                                         }
                                     }
                                 }
@@ -8071,16 +6784,23 @@ fn main() {
                 }
 
                 if let Some(Button::Mouse(MouseButton::Right)) = e.press_args() {
-                    if !block_system.is_stun_locked() && fighter.stun_timer <= 0.0 && !lmb_held && !fighter.is_reloading {
+                    let strike_cancelled = lmb_held && cancels(CombatPhase::Striking, swing_elapsed(strike_animation_timer), CombatInput::Block);
+                    if !world.block_system.is_stun_locked() && world.fighter.stun_timer <= 0.0 && (!lmb_held || strike_cancelled) && !world.fighter.is_reloading() {
+					if strike_cancelled {
+						// Block cancels the strike: stop rapid strikes until LMB is pressed again.
+						lmb_held = false;
+						strike_animation_timer = 0.0;
+						world.melee_rapid_fire_timer = 0.0;
+					}
 					// Force raptor out of flight mode when blocking
-					if fighter.fighter_type == FighterType::Raptor && fighter.state == RacerState::OnBike {
-						fighter.state = RacerState::OnFoot;
+					if world.fighter.fighter_type == FighterType::Raptor && world.fighter.state == RacerState::OnBike {
+						world.fighter.state = RacerState::OnFoot;
 						shift_override_active = false;
 						
 						// Update textures for foot mode
 						let tex_set = &raptor_textures;
 						update_current_textures(
-							&fighter,
+							&world.fighter,
 							tex_set,
 							&mut current_idle_texture,
 							&mut current_fwd_texture,
@@ -8095,7 +6815,7 @@ fn main() {
 							shift_held,
 						);
 					}						
-                        if block_system.activate(&audio_manager) {
+                        if world.block_system.activate(&world.audio) {
                             current_racer_texture = current_block_texture;
                             movement_active = false;
                             movement_timer = 0.0;
@@ -8105,7 +6825,7 @@ fn main() {
                     }
                 }
                 if let Some(Button::Mouse(MouseButton::Right)) = e.release_args() {
-                    block_system.deactivate();
+                    world.block_system.deactivate();
                     if !block_break_animation_active
                         && !is_high_priority_animation_active(
                             rush_active,
@@ -8123,7 +6843,7 @@ fn main() {
                 if let Some(Button::Mouse(MouseButton::Left)) = e.release_args() {
                     lmb_held = false;
                     soldier_rapid_fire_timer = 0.0;
-                    melee_rapid_fire_timer = 0.0;
+                    world.melee_rapid_fire_timer = 0.0;
                 }
 
                 if let Some(Button::Keyboard(key)) = e.press_args() {
@@ -8133,14 +6853,14 @@ fn main() {
                         LvlUpState::PendingTab {
                             fighter_type: pending_fighter,
                         } => {
-                            if key == Key::Tab && pending_fighter == fighter.fighter_type {
-                                let points = fighter
+                            if key_bindings.action_for(key) == Some(Action::LevelUp) && pending_fighter == world.fighter.fighter_type {
+                                let points = world.fighter
                                     .stat_points_to_spend
-                                    .get(&fighter.fighter_type)
+                                    .get(&world.fighter.fighter_type)
                                     .unwrap_or(&0);
                                 if *points > 0 {
                                     lvl_up_state = LvlUpState::SelectingStat;
-                                    let fighter_name = match fighter.fighter_type {
+                                    let fighter_name = match world.fighter.fighter_type {
                                         FighterType::Racer => "RACER",
                                         FighterType::Soldier => "SOLDIER",
                                         FighterType::Raptor => "RAPTOR",
@@ -8159,18 +6879,18 @@ fn main() {
                         LvlUpState::SelectingStat => {
                             let mut choice: Option<StatChoice> = None;
                             let mut stat_name = "";
-                            match key {
-                                Key::Z => {
+                            match key_bindings.action_for(key) {
+                                Some(Action::SpendDefense) => {
                                     choice = Some(StatChoice::Def);
                                     stat_name = "DEF";
                                     key_handled_by_lvl_up = true;
                                 }
-                                Key::X => {
+                                Some(Action::SpendAttack) => {
                                     choice = Some(StatChoice::Atk);
                                     stat_name = "ATK";
                                     key_handled_by_lvl_up = true;
                                 }
-                                Key::C => {
+                                Some(Action::SpendSpeed) => {
                                     choice = Some(StatChoice::Spd);
                                     stat_name = "SPD";
                                     key_handled_by_lvl_up = true;
@@ -8178,7 +6898,7 @@ fn main() {
                                 _ => {}
                             }
                             if let Some(stat_choice) = choice {
-                                let fighter_name = match fighter.fighter_type {
+                                let fighter_name = match world.fighter.fighter_type {
                                     FighterType::Racer => "RACER",
                                     FighterType::Soldier => "SOLDIER",
                                     FighterType::Raptor => "RAPTOR",
@@ -8192,7 +6912,7 @@ fn main() {
                                 )]);
                                 lvl_up_state = LvlUpState::ConfirmingStat {
                                     stat_to_increase: stat_choice,
-                                    fighter_type: fighter.fighter_type,
+                                    fighter_type: world.fighter.fighter_type,
                                 };
                             }
                         }
@@ -8200,9 +6920,9 @@ fn main() {
                             stat_to_increase,
                             fighter_type,
                         } => {
-                            match key {
-                                Key::Y => {
-                                    let points = fighter
+                            match key_bindings.action_for(key) {
+                                Some(Action::Confirm) => {
+                                    let points = world.fighter
                                         .stat_points_to_spend
                                         .entry(fighter_type)
                                         .or_insert(0);
@@ -8244,7 +6964,7 @@ fn main() {
                                                 // Re-apply any active field traits.
                                                 let active_traits = field_trait_manager
                                                     .get_active_traits_for_field(
-                                                        &sbrx_map_system.current_field_id,
+                                                        &world.map_system.current_field_id,
                                                     );
                                                 let mut total_level_mod = 0;
                                                 for trait_instance in &active_traits {
@@ -8280,19 +7000,19 @@ fn main() {
                                         }
 
                                         // 3. If the leveled-up fighter is active, update their stats immediately for responsiveness.
-                                        if fighter_type == fighter.fighter_type {
+                                        if fighter_type == world.fighter.fighter_type {
                                             if let Some(recalculated_stats) =
                                                 fighter_stats_map.get(&fighter_type)
                                             {
-                                                fighter.stats = *recalculated_stats;
-                                                fighter.max_hp = recalculated_stats.defense.hp;
-                                                fighter.melee_damage =
+                                                world.fighter.stats = *recalculated_stats;
+                                                world.fighter.max_hp = recalculated_stats.defense.hp;
+                                                world.fighter.melee_damage =
                                                     recalculated_stats.attack.melee_damage;
-                                                fighter.ranged_damage =
+                                                world.fighter.ranged_damage =
                                                     recalculated_stats.attack.ranged_damage;
-                                                fighter.run_speed =
+                                                world.fighter.run_speed =
                                                     recalculated_stats.speed.run_speed;
-                                                fighter.current_hp = fighter.max_hp;
+                                                world.fighter.current_hp = world.fighter.max_hp;
                                                 // Heal to new max HP
                                             }
                                         }
@@ -8321,9 +7041,9 @@ fn main() {
                                     }
                                     key_handled_by_lvl_up = true;
                                 }
-                                Key::N => {
+                                Some(Action::Decline) => {
                                     lvl_up_state = LvlUpState::SelectingStat;
-                                    let fighter_name = match fighter.fighter_type {
+                                    let fighter_name = match world.fighter.fighter_type {
                                         FighterType::Racer => "RACER",
                                         FighterType::Soldier => "SOLDIER",
                                         FighterType::Raptor => "RAPTOR",
@@ -8347,17 +7067,17 @@ fn main() {
                         continue; // Skip other key handlers if the level-up system used the key
                     }
 
-                    match key {
-                        Key::D0 => {
-                            if !is_paused && fighter.fighter_type == FighterType::Racer {
-                                fighter.racer_variant = if fighter.racer_variant == 0 { 1 } else { 0 };
-                                audio_manager.play_sound_effect("aim").ok(); // Small feedback sound
+                    match (key_bindings.action_for(key), key) {
+                        (Some(Action::ToggleRacerVariant), _) => {
+                            if !is_paused && world.fighter.fighter_type == FighterType::Racer {
+                                world.fighter.racer_variant = if world.fighter.racer_variant == 0 { 1 } else { 0 };
+                                world.audio.play_sound_effect("aim").ok(); // Small feedback sound
                             }
                         }						
-                        Key::I => {
-                            fighter.show_gear = !fighter.show_gear;
+                        (Some(Action::ToggleGear), _) => {
+                            world.fighter.show_gear = !world.fighter.show_gear;
                         }						
-                        Key::D1 => {
+                        (_, Key::D1) => {
                             if shift_held {
                                 println!("Shift + 1 was pressed!");
                                 // ADD YOUR SHIFT+1 LOGIC HERE
@@ -8369,22 +7089,22 @@ fn main() {
                                 bunker_entry_choice = BunkerEntryChoice::None;
 
                                 // --- BUNKER ENTRY LOGIC (PEACEFUL) ---
-                                area_entrance_x = fighter.x;
-                                area_entrance_y = fighter.y;
+                                area_entrance_x = world.fighter.x;
+                                area_entrance_y = world.fighter.y;
 
                                 // Waves are NOT active, so pass false.
                                 let (new_area, player_start_pos) =
                                     AreaState::new(AreaType::Bunker, 1, false, true);
-                                current_area = Some(new_area);
-                                fighter.x = player_start_pos.0;
-                                fighter.y = player_start_pos.1;
-                                camera.x = fighter.x;
-                                camera.y = fighter.y;
-                                cpu_entities.clear(); // No CPUs
-                                wave_manager.reset(); // No waves
+                                world.current_area = Some(new_area);
+                                world.fighter.x = player_start_pos.0;
+                                world.fighter.y = player_start_pos.1;
+                                camera.x = world.fighter.x;
+                                camera.y = world.fighter.y;
+                                world.cpu_entities.clear(); // No CPUs
+                                world.wave_manager.reset(); // No waves
                             }
                         }
-                        Key::D2 => {
+                        (_, Key::D2) => {
                             if shift_held {
                                 println!("Shift + 2 was pressed!");
                                 // ADD YOUR SHIFT+2 LOGIC HERE
@@ -8402,13 +7122,13 @@ fn main() {
 
                                 // Reset Razor Fiend and Grand Commander state for the restart
                                 razor_fiend_defeated_flag = false;
-                                task_system.razor_fiend_defeated = 0;
+                                world.task_system.razor_fiend_defeated = 0;
                                 grand_commander_dialogue_triggered = false;
                                 //println!("[BUNKER] Razor Fiend boss state has been reset.");
 
                                 // --- BUNKER ENTRY LOGIC (WAVES RESTARTED) ---
-                                area_entrance_x = fighter.x;
-                                area_entrance_y = fighter.y;
+                                area_entrance_x = world.fighter.x;
+                                area_entrance_y = world.fighter.y;
 
                                 let mut bunker_wave_definitions: HashMap<i32, u32> = HashMap::new();
                                 bunker_wave_definitions.insert(1, 2);
@@ -8422,24 +7142,24 @@ fn main() {
                                 let lockdown_active = waves_for_floor_1 > 0;
 
                                 if waves_for_floor_1 > 0 {
-                                    wave_manager.start_encounter(waves_for_floor_1);
+                                    world.wave_manager.start_encounter(waves_for_floor_1);
                                 }
                                 let (new_area, player_start_pos) =
                                     AreaState::new(AreaType::Bunker, 1, lockdown_active, false);
 
                                 let should_spawn_reavers = new_area.spawn_night_reavers;
-                                current_area = Some(new_area);
-                                fighter.x = player_start_pos.0;
-                                fighter.y = player_start_pos.1;
-                                camera.x = fighter.x;
-                                camera.y = fighter.y;
-                                cpu_entities.clear();
-                                if should_spawn_reavers && CPU_ENABLED {
+                                world.current_area = Some(new_area);
+                                world.fighter.x = player_start_pos.0;
+                                world.fighter.y = player_start_pos.1;
+                                camera.x = world.fighter.x;
+                                camera.y = world.fighter.y;
+                                world.cpu_entities.clear();
+                                if should_spawn_reavers && game_config.cpu_enabled {
                                     println!(
                                         "Spawning Night Reavers in bunker floor 1 (Wave Restart)"
                                     );
                                     for _ in 0..4 {
-                                        if cpu_entities.len() < 10 {
+                                        if world.cpu_entities.len() < 10 {
                                             let reaver_x = safe_gen_range(
                                                 BUNKER_ORIGIN_X + 50.0,
                                                 BUNKER_ORIGIN_X + BUNKER_WIDTH - 50.0,
@@ -8451,70 +7171,78 @@ fn main() {
                                                 "NightReaver y",
                                             );
 											let mut reaver = CpuEntity::new_night_reaver(reaver_x, reaver_y);
-											let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+											let aptitude = get_field_aptitude(world.map_system.current_field_id);
 											reaver.max_hp *= aptitude;
 											reaver.current_hp = reaver.max_hp;
 											reaver.damage_value *= aptitude;
 											reaver.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-											cpu_entities.push(reaver);
+											world.cpu_entities.push(reaver);
                                         }
                                     }
                                 }
                             }
                         }
 						
-                        Key::F10 => show_collision_debug = 0, // DISABLE ALL
-                        Key::F11 => show_collision_debug = 1, // ENABLE COLLISION BARRIERS
-                        Key::F12 => show_collision_debug = 2, // ENABLE ALL					
-                        Key::T => {
-                            if task_system.active {
-                                task_system.open = !task_system.open;
-                                if task_system.open {
-                                    task_system.auto_close_timer = 7.0;
+                        (_, Key::F10) => { // DISABLE ALL
+                            show_collision_debug = 0;
+                            hitbox::set_debug(false);
+                        }
+                        (_, Key::F11) => { // ENABLE COLLISION BARRIERS
+                            show_collision_debug = 1;
+                            hitbox::set_debug(false);
+                        }
+                        (_, Key::F12) => { // ENABLE ALL, plus hitboxes and hurtboxes
+                            show_collision_debug = 2;
+                            hitbox::set_debug(true);
+                        }					
+                        (Some(Action::ToggleTasks), _) => {
+                            if world.task_system.active {
+                                world.task_system.open = !world.task_system.open;
+                                if world.task_system.open {
+                                    world.task_system.auto_close_timer = 7.0;
                                 }								
                             }
                         }
-                        Key::R => {
+                        (Some(Action::Reload), _) => {
                             if !is_paused
-                                && fighter.fighter_type == FighterType::Soldier
-                                && fighter.ammo < fighter.max_ammo
+                                && world.fighter.resources.uses(ResourceKind::Ammo)
                             {
-                                fighter.trigger_reload(&audio_manager);
+                                world.fighter.trigger_reload(&world.audio);
                             }
                         }
-                        Key::F => {
-                            // Key::F Functionality (Racer Only): Toggle Boost Mode
-                            if !is_paused && fighter.fighter_type == FighterType::Racer {
+                        (Some(Action::Boost), _) => {
+                            // Boost (Racer Only): Toggle Boost Mode
+                            if !is_paused && world.fighter.fighter_type == FighterType::Racer {
                                 // Check cooldown regardless of state (OnBike or OnFoot)
-                                if fighter.bike_boost_toggle_cooldown > 0.0 {
+                                if world.fighter.bike_boost_toggle_cooldown > 0.0 {
                                     chatbox.add_interaction(vec![(
                                         "TOGGLE COOLDOWN ACTIVE",
                                         MessageType::Info,
                                     )]);
                                 } else {
                                     // Apply cooldown on successful toggle
-                                    fighter.bike_boost_toggle_cooldown = 3.0;
+                                    world.fighter.bike_boost_toggle_cooldown = 3.0;
                                     
-                                    fighter.boost = !fighter.boost;
+                                    world.fighter.boost = !world.fighter.boost;
 									
                                     // Activate indicator display
-                                    fighter.boost_indicator_timer = 0.75;
+                                    world.fighter.boost_indicator_timer = 0.75;
 
                                     // Play sound effect based on new mode
-                                    if fighter.boost {
-                                        audio_manager.play_sound_effect("slash_combo").ok();
+                                    if world.fighter.boost {
+                                        world.audio.play_sound_effect("slash_combo").ok();
                                     } else {
-                                        audio_manager.play_sound_effect("aim").ok();
+                                        world.audio.play_sound_effect("aim").ok();
                                     }									
 									
-                                    let mode = if fighter.boost { "BOOST" } else { "RANGED" };
+                                    let mode = if world.fighter.boost { "BOOST" } else { "RANGED" };
                                     chatbox.add_interaction(vec![(
                                         &format!("[SHIFT] SET: {}", mode),
                                         MessageType::Stats,
                                     )]);
                                     // If switching to BOOST while AIM is active, force AIM off
-                                    if fighter.boost && shift_override_active {
-                                        fighter.combat_mode = CombatMode::CloseCombat;
+                                    if world.fighter.boost && shift_override_active {
+                                        world.fighter.combat_mode = CombatMode::CloseCombat;
                                         shift_override_active = false;
                                     }
                                 }
@@ -8523,55 +7251,33 @@ fn main() {
                         _ => {}
                     }
 
-                    if !block_system.is_stun_locked() && fighter.stun_timer <= 0.0 {
-                        let (current_min_x, current_max_x, current_min_y, current_max_y) =
-                            if let Some(ref area_state) = current_area {
-                                let (width, height, origin_x, origin_y) = match area_state.area_type
-                                {
-                                    AreaType::RaptorNest => {
-                                        (AREA_WIDTH, AREA_HEIGHT, AREA_ORIGIN_X, AREA_ORIGIN_Y)
-                                    }
-                                    AreaType::Bunker => (
-                                        BUNKER_WIDTH,
-                                        BUNKER_HEIGHT,
-                                        BUNKER_ORIGIN_X,
-                                        BUNKER_ORIGIN_Y,
-                                    ),
-                                };
-                                (origin_x, origin_x + width, origin_y, origin_y + height)
-                            } else {
-                                let min_y_world = if fighter.state == RacerState::OnBike {
-                                    line_y
-                                } else {
-                                    MIN_Y
-                                };
-                                (MIN_X, MAX_X, min_y_world, MAX_Y)
-                            };
-                        match key {
-                            Key::V => {
-                                if !block_system.active && !is_paused {
+                    if !world.block_system.is_stun_locked() && world.fighter.stun_timer <= 0.0 {
+                        let (current_min_x, current_max_x, current_min_y, current_max_y) = world.movement_bounds();
+                        match key_bindings.action_for(key) {
+                            Some(Action::Mount) => {
+                                if !world.block_system.active && !is_paused {
                                     // Clear crash state on mount
-                                    if fighter.state == RacerState::OnFoot {
-                                        let dx = fighter.x - sbrx_bike.x;
-                                        let dy = fighter.y - sbrx_bike.y;
+                                    if world.fighter.state == RacerState::OnFoot {
+                                        let dx = world.fighter.x - sbrx_bike.x;
+                                        let dy = world.fighter.y - sbrx_bike.y;
                                         if (dx*dx + dy*dy).sqrt() <= bike_interaction_distance {
                                             sbrx_bike.is_crashed = false;
                                         }
                                     }									
 									
-                                    if fighter.fighter_type == FighterType::Raptor {
-                                        if current_area.is_none() {                     
+                                    if world.fighter.fighter_type == FighterType::Raptor {
+                                        if world.current_area.is_none() {                     
                                             if true {
-                                                if fighter.state == RacerState::OnFoot {
-                                                    fighter.state = RacerState::OnBike;
-													audio_manager.play_sound_effect("boost").ok();
+                                                if world.fighter.state == RacerState::OnFoot {
+                                                    world.fighter.state = RacerState::OnBike;
+													world.audio.play_sound_effect("boost").ok();
                                                 } else {
-                                                    fighter.state = RacerState::OnFoot;
+                                                    world.fighter.state = RacerState::OnFoot;
                                                 }
                                                 // Update textures for raptor after state change
                                                 let tex_set = &raptor_textures;
                                                 update_current_textures(
-                                                    &fighter,
+                                                    &world.fighter,
                                                     tex_set,
                                                     &mut current_idle_texture,
                                                     &mut current_fwd_texture,
@@ -8595,23 +7301,23 @@ fn main() {
                                         }
                                     } else {
                                         // RACER and SOLDIER logic (original logic)
-                                        let dx = fighter.x - sbrx_bike.x;
-                                        let dy = fighter.y - sbrx_bike.y;
+                                        let dx = world.fighter.x - sbrx_bike.x;
+                                        let dy = world.fighter.y - sbrx_bike.y;
                                         let d_bike = (dx * dx + dy * dy).sqrt();
-                                        match fighter.state {
+                                        match world.fighter.state {
                                            RacerState::OnFoot => {
                                                if d_bike <= bike_interaction_distance && sbrx_bike.visible {
                                             {
-                                                audio_manager.play_sound_effect("bike_start").ok();
-                                                fighter.state = RacerState::OnBike;
+                                                world.audio.play_sound_effect("bike_start").ok();
+                                                world.fighter.state = RacerState::OnBike;
                                                 sbrx_bike.visible = false;
-                                                let tex_set = match fighter.fighter_type {
+                                                let tex_set = match world.fighter.fighter_type {
                                                     FighterType::Racer => &racer_textures,
                                                     FighterType::Soldier => &soldier_textures,
                                                     _ => unreachable!(),
                                                 };
                                                 update_current_textures(
-                                                    &fighter,
+                                                    &world.fighter,
                                                     tex_set,
                                                     &mut current_idle_texture,
                                                     &mut current_fwd_texture,
@@ -8630,15 +7336,15 @@ fn main() {
 										}
                                             }																																	
                                             RacerState::OnBike => {
-                                                fighter.state = RacerState::OnFoot;
-                                                sbrx_bike.respawn(fighter.x, fighter.y);
-                                                let tex_set = match fighter.fighter_type {
+                                                world.fighter.state = RacerState::OnFoot;
+                                                sbrx_bike.respawn(world.fighter.x, world.fighter.y);
+                                                let tex_set = match world.fighter.fighter_type {
                                                     FighterType::Racer => &racer_textures,
                                                     FighterType::Soldier => &soldier_textures,
                                                     _ => unreachable!(),
                                                 };
                                                 update_current_textures(
-                                                    &fighter,
+                                                    &world.fighter,
                                                     tex_set,
                                                     &mut current_idle_texture,
                                                     &mut current_fwd_texture,
@@ -8658,15 +7364,15 @@ fn main() {
                                     }
                                 }
                             }
-                            Key::W => {
+                            Some(Action::MoveUp) => {
                                 key_w_pressed = true;
-                                if fighter.state == RacerState::OnFoot
-                                    && !block_system.active
+                                if world.fighter.state == RacerState::OnFoot
+                                    && !world.block_system.active
                                     && !rush_active
                                 {
                                     if !is_paused {
                                         // Normalized: 600 pixels per second
-                                        fighter.y = (fighter.y - 10.0).max(current_min_y); // Revert to fixed nudge
+                                        world.fighter.y = (world.fighter.y - 10.0).max(current_min_y); // Revert to fixed nudge
                                     }
                                     if !movement_active || movement_timer < movement_buffer_duration
                                     {
@@ -8678,14 +7384,14 @@ fn main() {
                                     }
                                 }
                             }
-                            Key::S => {
+                            Some(Action::MoveDown) => {
                                 key_s_pressed = true;
-                                if fighter.state == RacerState::OnFoot
-                                    && !block_system.active
+                                if world.fighter.state == RacerState::OnFoot
+                                    && !world.block_system.active
                                     && !rush_active
                                 {
                                     if !is_paused {
-                                        fighter.y = (fighter.y + 10.0).min(current_max_y); // Revert to fixed nudge
+                                        world.fighter.y = (world.fighter.y + 10.0).min(current_max_y); // Revert to fixed nudge
                                     }
                                     if !movement_active || movement_timer < movement_buffer_duration
                                     {
@@ -8697,17 +7403,17 @@ fn main() {
                                     }
                                 }
                             }
-                            Key::A => {
+                            Some(Action::MoveLeft) => {
                                 key_a_pressed = true;
-                                if fighter.state == RacerState::OnFoot
-                                    && !block_system.active
+                                if world.fighter.state == RacerState::OnFoot
+                                    && !world.block_system.active
                                     && !rush_active
                                 {
                                     if !is_paused {
-                                        fighter.x = (fighter.x - 10.0).max(current_min_x);
+                                        world.fighter.x = (world.fighter.x - 10.0).max(current_min_x);
                                     }
                                     let (wmx, _) = screen_to_world(&camera, mouse_x, mouse_y);
-                                    let m_to_m = wmx < fighter.x;
+                                    let m_to_m = wmx < world.fighter.x;
                                     let n_dir = if m_to_m {
                                         MovementDirection::Forward
                                     } else {
@@ -8735,17 +7441,17 @@ fn main() {
                                     }
                                 }
                             }
-                            Key::D => {
+                            Some(Action::MoveRight) => {
                                 key_d_pressed = true;
-                                if fighter.state == RacerState::OnFoot
-                                    && !block_system.active
+                                if world.fighter.state == RacerState::OnFoot
+                                    && !world.block_system.active
                                     && !rush_active
                                 {
                                     if !is_paused {
-                                        fighter.x = (fighter.x + 10.0).min(current_max_x);
+                                        world.fighter.x = (world.fighter.x + 10.0).min(current_max_x);
                                     }
                                     let (wmx, _) = screen_to_world(&camera, mouse_x, mouse_y);
-                                    let m_to_m = wmx > fighter.x;
+                                    let m_to_m = wmx > world.fighter.x;
                                     let n_dir = if m_to_m {
                                         MovementDirection::Forward
                                     } else {
@@ -8773,42 +7479,43 @@ fn main() {
                                     }
                                 }
                             }
-                            Key::Space => {
+                            Some(Action::Rush) => {
                                // KINETIC_RUSH: [RMB] + [SPACEBAR] with kinetic_intake
                                 if rush_cooldown <= 0.0
-                                    && block_system.rmb_held
-                                    && block_system.kinetic_intake_count > 0
-                                    && !block_system.block_fatigue
-                                    && !block_system.block_broken
+                                    && world.block_system.rmb_held
+                                    && cancels(CombatPhase::Blocking, world.block_system.raised_for, CombatInput::Rush)
+                                    && world.block_system.kinetic_intake_count > 0
+                                    && !world.block_system.block_fatigue
+                                    && !world.block_system.block_broken
                                 {
-                                    let intake_count = block_system.kinetic_intake_count;
-                                    let effectiveness_multiplier = if intake_count >= 1 && intake_count <= 20 {
-                                        KINETIC_STRIKE_MULTIPLIERS[intake_count as usize]
+                                    let intake_count = world.block_system.kinetic_level();
+                                    let effectiveness_multiplier = if intake_count >= 1 {
+                                        KINETIC_STRIKE_MULTIPLIERS[intake_count]
                                     } else {
                                         1.0
                                    };
  
                                     // Apply combat action slowdown when on bike
-                                    if fighter.state == RacerState::OnBike {
-                                        fighter.combat_action_slowdown_timer = 0.25;
+                                    if world.fighter.state == RacerState::OnBike {
+                                        world.fighter.combat_action_slowdown_timer = 0.25;
                                     }
  
-                                    audio_manager.play_sound_effect("rush").ok();
-									audio_manager.play_sound_effect("death").ok();
+                                    world.audio.play_sound_effect("rush").ok();
+									world.audio.play_sound_effect("death").ok();
                                     let (wmx, wmy) = screen_to_world(&camera, mouse_x, mouse_y);
-                                    let dx = wmx - fighter.x;
-                                    let dy = wmy - fighter.y;
+                                    let dx = wmx - world.fighter.x;
+                                    let dy = wmy - world.fighter.y;
                                     let dist = (dx * dx + dy * dy).sqrt();
  
                                     if dist > 0.0 {
-                                        let ix = fighter.x;
-                                        let iy = fighter.y;
+                                        let ix = world.fighter.x;
+                                        let iy = world.fighter.y;
                                         let ndx = dx / dist;
                                         let ndy = dy / dist;
  
                                         // KINETIC_RUSH distance scales with kinetic_intake
-                                        let base_rush_distance = crate::config::movement::RUSH_DISTANCE;
-                                        let fighter_multiplier = match fighter.fighter_type {
+                                        let base_rush_distance = game_config.movement.rush_distance;
+                                        let fighter_multiplier = match world.fighter.fighter_type {
                                             FighterType::Racer => 1.0,
                                             FighterType::Raptor => 0.85,
                                             FighterType::Soldier => 0.65,
@@ -8821,48 +7528,54 @@ fn main() {
                                         if !is_paused {
                                             let cex = ix + ndx * (rush_distance * 1.5);
                                             let cey = iy + ndy * (rush_distance * 1.5);
+                                            let rush_hitbox = Hitbox::line(ix, iy, cex, cey);
+                                            hitbox::record(rush_hitbox);
  
                                             // Grant immunity during kinetic rush
-                                            fighter.invincible_timer = KINETIC_STRIKE_DAMAGE_IMMUNITY_DURATION;
+                                            world.fighter.invincible_timer = KINETIC_STRIKE_DAMAGE_IMMUNITY_DURATION;
 											
                                             // Raptor Reward: 1.5s Invincibility on Kinetic Rush
-                                            if fighter.fighter_type == FighterType::Raptor {
-                                                fighter.invincible_timer = 1.5;
+                                            if world.fighter.fighter_type == FighterType::Raptor {
+                                                world.fighter.invincible_timer = 1.5;
                                                 chatbox.add_interaction(vec![
                                                     //("ATOMIC-STATE", MessageType::Warning),
                                                 ]);
                                             }											
  
-                                            if CPU_ENABLED {
-                                                for cpu in cpu_entities.iter_mut() {
+                                            if game_config.cpu_enabled {
+                                                for cpu in world.cpu_entities.iter_mut() {
                                                     if cpu.entity_state == EntityState::Friendly {
                                                         continue;
                                                     }													
 													
-													if check_line_collision(ix, iy, cex, cey, cpu.x, cpu.y) {
-														let mut rush_damage = fighter.melee_damage; // * effectiveness_multiplier;
+													let hurtbox = Hurtbox::for_cpu(cpu);
+													if rush_hitbox.hits(&hurtbox) {
+														hitbox::record_hit(&hurtbox);
+														let mut rush_damage = world.fighter.melee_damage; // * effectiveness_multiplier;
 														// 25% damage boost while ATOMIC-STATE is active
-														if fighter.invincible_timer > 1.0 {
+														if world.fighter.invincible_timer > 1.0 {
 															rush_damage *= 1.25;
 														}
-                                                        cpu.current_hp -= rush_damage;
+                                                        let hit = damage::resolve(
+                                                            &damage::Attack::with_stats(rush_damage, DamageType::Kinetic, &world.fighter.stats.attack),
+                                                            &damage::Defense::cpu(cpu),
+                                                        );
  
-                                                        damage_texts.push(DamageText {
-                                                            text: format!("{:.0}", rush_damage),
-                                                            x: cpu.x,
-                                                            y: cpu.y - 50.0,
-                                                            color: if fighter.invincible_timer > 1.0 { [0.7, 1.0, 0.0, 1.0] } else { [1.0, 1.0, 0.0, 1.0] },
-                                                            lifetime: 0.25,
-                                                        });
+                                                        world.damage_texts.push(hit.text(cpu.x, cpu.y - 50.0));
+                                                        if !hit.landed() {
+                                                            continue;
+                                                        }
+                                                        cpu.current_hp -= hit.amount;
+                                                        cpu.threat.add(ThreatTarget::Player, hit.threat);
  
-                                                        use crate::entities::cpu_entity::BleedEffect;
-                                                        cpu.bleed_effect = Some(BleedEffect::new(50.0 * effectiveness_multiplier));
-                                                        damage_texts.push(DamageText {
+                                                        cpu.status_effects.apply(StatusEffect::bleed(50.0 * effectiveness_multiplier));
+                                                        world.damage_texts.push(DamageText {
                                                             text: "BLEED".to_string(),
                                                             x: cpu.x,
                                                             y: cpu.y - 70.0,
                                                             color: [1.0, 0.0, 0.0, 1.0],
                                                             lifetime: 0.5,
+                                                            style: TextStyle::Normal,
                                                         });
  
                                                         if !cpu.is_dead() {
@@ -8871,36 +7584,43 @@ fn main() {
                                                     }
                                                 }
 												
-                                                if sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
-                                                    for cr in cpu_racers.iter_mut() {
+                                                if world.map_system.current_field_id == SbrxFieldId(0, 0) {
+                                                    for cr in world.cpu_racers.iter_mut() {
                                                         if cr.is_crashed { continue; }
 														if cr.entity_state == EntityState::Friendly { continue; }
-                                                        if check_line_collision(ix, iy, cex, cey, cr.x, cr.y) {
+                                                        let hurtbox = Hurtbox::for_racer(cr);
+                                                        if rush_hitbox.hits(&hurtbox) {
+                                                            hitbox::record_hit(&hurtbox);
                                                             if cr.entity_state == EntityState::Neutral {
                                                                 cr.entity_state = EntityState::Hostile;
                                                             }															
-                                                            let mut rush_damage = fighter.melee_damage;
-                                                            if fighter.invincible_timer > 1.0 {
+                                                            if cr.block_fighter_hit(&mut world.fighter, &world.audio, world.game_time) {
+                                                                world.damage_texts.push(damage::Hit::blocked(DamageType::Kinetic).text(cr.x, cr.y - 50.0));
+                                                                continue;
+                                                            }
+                                                            let mut rush_damage = world.fighter.melee_damage;
+                                                            if world.fighter.invincible_timer > 1.0 {
                                                                 rush_damage *= 1.25;
                                                             }
-                                                            cr.current_hp -= rush_damage;
+                                                            let hit = damage::resolve(
+                                                                &damage::Attack::with_stats(rush_damage, DamageType::Kinetic, &world.fighter.stats.attack),
+                                                                &damage::Defense::cpu_racer(cr),
+                                                            );
  
-                                                            damage_texts.push(DamageText {
-                                                                text: format!("{:.0}", rush_damage),
-                                                                x: cr.x,
-                                                                y: cr.y - 50.0,
-                                                                color: if fighter.invincible_timer > 1.0 { [0.7, 1.0, 0.0, 1.0] } else { [1.0, 1.0, 0.0, 1.0] },
-                                                                lifetime: 0.25,
-                                                            });
+                                                            world.damage_texts.push(hit.text(cr.x, cr.y - 50.0));
+                                                            if !hit.landed() {
+                                                                continue;
+                                                            }
+                                                            cr.current_hp -= hit.amount;
  
-                                                            use crate::entities::cpu_entity::BleedEffect;
-                                                            cr.bleed_effect = Some(BleedEffect::new(50.0 * effectiveness_multiplier));
-                                                            damage_texts.push(DamageText {
+                                                            cr.status_effects.apply(StatusEffect::bleed(50.0 * effectiveness_multiplier));
+                                                            world.damage_texts.push(DamageText {
                                                                 text: "BLEED".to_string(),
                                                                 x: cr.x,
                                                                 y: cr.y - 70.0,
                                                                 color: [1.0, 0.0, 0.0, 1.0],
                                                                 lifetime: 0.5,
+                                                                style: TextStyle::Normal,
                                                             });
  
                                                             if cr.current_hp > 0.0 {
@@ -8918,21 +7638,25 @@ fn main() {
                                                     }
                                                 }												
                                             }
-                                            fighter.x = rex;
-                                            fighter.y = rey;
+                                            world.fighter.x = rex;
+                                            world.fighter.y = rey;
                                         }
  
                                         current_racer_texture = current_rush_texture;
+                                        if strike_animation_timer > 0.0 && cancels(CombatPhase::Striking, swing_elapsed(strike_animation_timer), CombatInput::Rush) {
+                                            strike_animation_timer = 0.0;
+                                            world.melee_rapid_fire_timer = world.melee_rapid_fire_timer.max(rush_duration);
+                                        }
                                         rush_active = true;
                                         rush_timer = rush_duration;
-                                        rush_cooldown = if fighter.state == RacerState::OnBike { 0.75 } else { 0.5 };
+                                        rush_cooldown = if world.fighter.state == RacerState::OnBike { 0.75 } else { 0.5 };
  
-                                        let slx = fighter.x + ndx * (rush_distance * 0.5);
-                                        let sly = fighter.y + ndy * (rush_distance * 0.5);
-                                        strike.trigger(slx, sly);
+                                        let slx = world.fighter.x + ndx * (rush_distance * 0.5);
+                                        let sly = world.fighter.y + ndy * (rush_distance * 0.5);
+                                        world.strike.trigger(slx, sly);
                                         let original_angle = (-dy).atan2(dx).to_degrees();
-                                        strike.angle = original_angle + 90.0 + safe_gen_range(-10.0, 10.0, "Strike angle variation");
-                                        strike.timer = 0.2;
+                                        world.strike.angle = original_angle + 90.0 + safe_gen_range_cosmetic(-10.0, 10.0, "Strike angle variation");
+                                        world.strike.timer = 0.2;
                                         movement_active = false;
                                         backpedal_active = false;
                                         current_movement_direction = MovementDirection::None;
@@ -8959,46 +7683,46 @@ fn main() {
                                         kinetic_rush_lines.push(KineticRushLine {
                                             start_x: ix,
                                             start_y: iy,
-                                            end_x: fighter.x,
-                                            end_y: fighter.y,
+                                            end_x: world.fighter.x,
+                                            end_y: world.fighter.y,
                                             lifetime: 0.25,
                                             max_lifetime: 0.25,
                                         });										
  
                                         // Consume blocks and trigger fatigue (same as kinetic_strike)
-                                        block_system.block_count = 0;
-                                        block_system.block_count_float = 0.0;
-                                        block_system.block_fatigue = true;
-                                        block_system.fatigue_timer = 1.5;
-                                        block_system.kinetic_intake_count = 0;
-                                        block_system.deactivate();
+                                        world.block_system.block_count = 0;
+                                        world.block_system.block_count_float = 0.0;
+                                        world.block_system.block_fatigue = true;
+                                        world.block_system.fatigue_timer = 1.5;
+                                        world.block_system.kinetic_intake_count = 0;
+                                        world.block_system.deactivate();
  
-                                        combo_system.start_timer_after_kinetic_strike();
+                                        world.combo_system.start_timer_after_kinetic_strike(world.fighter.fighter_type);
                                     }
-                                } else if rush_cooldown <= 0.0 && !block_system.active {
+                                } else if rush_cooldown <= 0.0 && !world.block_system.active {
                                     // Normal RUSH (original code)
                                     // Apply combat action slowdown when on bike
-                                    if fighter.state == RacerState::OnBike {
-                                        fighter.combat_action_slowdown_timer = 0.25;
+                                    if world.fighter.state == RacerState::OnBike {
+                                        world.fighter.combat_action_slowdown_timer = 0.25;
                                     }
 
-                                    if block_system.active {
-                                        block_system.deactivate();
+                                    if world.block_system.active {
+                                        world.block_system.deactivate();
                                     }
-                                    audio_manager.play_sound_effect("rush").ok();
+                                    world.audio.play_sound_effect("rush").ok();
                                     let (wmx, wmy) = screen_to_world(&camera, mouse_x, mouse_y);
-                                    let dx = wmx - fighter.x;
-                                    let dy = wmy - fighter.y;
+                                    let dx = wmx - world.fighter.x;
+                                    let dy = wmy - world.fighter.y;
                                     let dist = (dx * dx + dy * dy).sqrt();
                                     if dist > 0.0 {
-                                        let ix = fighter.x;
-                                        let iy = fighter.y;
+                                        let ix = world.fighter.x;
+                                        let iy = world.fighter.y;
                                         let ndx = dx / dist;
                                         let ndy = dy / dist;
 
                                         let base_rush_distance =
-                                            crate::config::movement::RUSH_DISTANCE;
-                                        let rush_distance = match fighter.fighter_type {
+                                            game_config.movement.rush_distance;
+                                        let rush_distance = match world.fighter.fighter_type {
                                             FighterType::Racer => base_rush_distance,
                                             FighterType::Raptor => base_rush_distance * 0.85,
                                             FighterType::Soldier => base_rush_distance * 0.65,
@@ -9011,42 +7735,45 @@ fn main() {
                                         if !is_paused {
                                             let cex = ix + ndx * (rush_distance * 1.5);
                                             let cey = iy + ndy * (rush_distance * 1.5);
+                                            let rush_hitbox = Hitbox::line(ix, iy, cex, cey);
+                                            hitbox::record(rush_hitbox);
 
-                                            if CPU_ENABLED {
-                                                for cpu in cpu_entities.iter_mut() {
+                                            if game_config.cpu_enabled {
+                                                for cpu in world.cpu_entities.iter_mut() {
                                                     if cpu.entity_state == EntityState::Friendly {
                                                         continue;
                                                     }													
 													
-                                                    if check_line_collision(
-                                                        ix, iy, cex, cey, cpu.x, cpu.y,
-                                                    ) {
-														let mut rush_damage = fighter.melee_damage;
+                                                    let hurtbox = Hurtbox::for_cpu(cpu);
+                                                    if rush_hitbox.hits(&hurtbox) {
+														hitbox::record_hit(&hurtbox);
+														let mut rush_damage = world.fighter.melee_damage;
 														// 25% damage boost while ATOMIC-STATE is active
-														if fighter.invincible_timer > 1.0 {
+														if world.fighter.invincible_timer > 1.0 {
 															rush_damage *= 1.25;
 														}												
-                                                        cpu.current_hp -= rush_damage;
+                                                        let hit = damage::resolve(
+                                                            &damage::Attack::with_stats(rush_damage, DamageType::Slash, &world.fighter.stats.attack),
+                                                            &damage::Defense::cpu(cpu),
+                                                        );
 
                                                         // Add damage text for rush attack
-                                                        damage_texts.push(DamageText {
-                                                            text: format!("{:.0}", rush_damage),
-                                                            x: cpu.x,
-                                                            y: cpu.y - 50.0,
-                                                            color: if fighter.invincible_timer > 1.0 { [0.7, 1.0, 0.0, 1.0] } else { [1.0, 1.0, 0.0, 1.0] }, // Cyan in ATOMIC-STATE, else Yellow
-                                                            lifetime: 0.25,
-                                                        });
+                                                        world.damage_texts.push(hit.text(cpu.x, cpu.y - 50.0));
+                                                        if !hit.landed() {
+                                                            continue;
+                                                        }
+                                                        cpu.current_hp -= hit.amount;
+                                                        cpu.threat.add(ThreatTarget::Player, hit.threat);
 
                                                         // Apply bleed effect
-                                                        use crate::entities::cpu_entity::BleedEffect;
-                                                        cpu.bleed_effect =
-                                                            Some(BleedEffect::new(50.0));
-                                                        damage_texts.push(DamageText {
+                                                        cpu.status_effects.apply(StatusEffect::bleed(50.0));
+                                                        world.damage_texts.push(DamageText {
                                                             text: "BLEED".to_string(),
                                                             x: cpu.x,
                                                             y: cpu.y - 70.0,
                                                             color: [1.0, 0.0, 0.0, 1.0], // Red
                                                             lifetime: 0.5,
+                                                            style: TextStyle::Normal,
                                                         });
 
                                                         // Death is handled in the main update loop.
@@ -9057,36 +7784,43 @@ fn main() {
                                                     }
                                                 }
 												
-                                                if sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
-                                                    for cr in cpu_racers.iter_mut() {
+                                                if world.map_system.current_field_id == SbrxFieldId(0, 0) {
+                                                    for cr in world.cpu_racers.iter_mut() {
                                                         if cr.is_crashed { continue; }
                                                         if cr.entity_state == EntityState::Friendly { continue; }
-                                                        if check_line_collision(ix, iy, cex, cey, cr.x, cr.y) {
+                                                        let hurtbox = Hurtbox::for_racer(cr);
+                                                        if rush_hitbox.hits(&hurtbox) {
+                                                            hitbox::record_hit(&hurtbox);
                                                             if cr.entity_state == EntityState::Neutral {
                                                                 cr.entity_state = EntityState::Hostile;
                                                             }
-                                                            let mut rush_damage = fighter.melee_damage;
-                                                            if fighter.invincible_timer > 1.0 {
+                                                            if cr.block_fighter_hit(&mut world.fighter, &world.audio, world.game_time) {
+                                                                world.damage_texts.push(damage::Hit::blocked(DamageType::Slash).text(cr.x, cr.y - 50.0));
+                                                                continue;
+                                                            }
+                                                            let mut rush_damage = world.fighter.melee_damage;
+                                                            if world.fighter.invincible_timer > 1.0 {
                                                                 rush_damage *= 1.25;
                                                             }
-                                                            cr.current_hp -= rush_damage;
+                                                            let hit = damage::resolve(
+                                                                &damage::Attack::with_stats(rush_damage, DamageType::Slash, &world.fighter.stats.attack),
+                                                                &damage::Defense::cpu_racer(cr),
+                                                            );
  
-                                                            damage_texts.push(DamageText {
-                                                                text: format!("{:.0}", rush_damage),
-                                                                x: cr.x,
-                                                                y: cr.y - 50.0,
-                                                                color: if fighter.invincible_timer > 1.0 { [0.7, 1.0, 0.0, 1.0] } else { [1.0, 1.0, 0.0, 1.0] },
-                                                                lifetime: 0.25,
-                                                            });
+                                                            world.damage_texts.push(hit.text(cr.x, cr.y - 50.0));
+                                                            if !hit.landed() {
+                                                                continue;
+                                                            }
+                                                            cr.current_hp -= hit.amount;
  
-                                                            use crate::entities::cpu_entity::BleedEffect;
-                                                            cr.bleed_effect = Some(BleedEffect::new(50.0));
-                                                            damage_texts.push(DamageText {
+                                                            cr.status_effects.apply(StatusEffect::bleed(50.0));
+                                                            world.damage_texts.push(DamageText {
                                                                 text: "BLEED".to_string(),
                                                                 x: cr.x,
                                                                 y: cr.y - 70.0,
                                                                 color: [1.0, 0.0, 0.0, 1.0],
                                                                 lifetime: 0.5,
+                                                                style: TextStyle::Normal,
                                                             });
  
                                                             if cr.current_hp > 0.0 {
@@ -9104,66 +7838,71 @@ fn main() {
                                                     }
                                                 }																							
                                             }
-                                            fighter.x = rex;
-                                            fighter.y = rey;
+                                            world.fighter.x = rex;
+                                            world.fighter.y = rey;
                                         }
                                         current_racer_texture = current_rush_texture;
+                                        // Rush cancels the strike; rapid strikes resume once it ends.
+                                        if strike_animation_timer > 0.0 && cancels(CombatPhase::Striking, swing_elapsed(strike_animation_timer), CombatInput::Rush) {
+                                            strike_animation_timer = 0.0;
+                                            world.melee_rapid_fire_timer = world.melee_rapid_fire_timer.max(rush_duration);
+                                        }
                                         rush_active = true;
                                         rush_timer = rush_duration;
                                         // rush cooldown
-                                        rush_cooldown = if fighter.state == RacerState::OnBike {
+                                        rush_cooldown = if world.fighter.state == RacerState::OnBike {
                                             0.75
                                         } else {
                                             0.5
                                         };
-                                        let slx = fighter.x + ndx * (rush_distance * 0.5);
-                                        let sly = fighter.y + ndy * (rush_distance * 0.5);
-                                        strike.trigger(slx, sly);
+                                        let slx = world.fighter.x + ndx * (rush_distance * 0.5);
+                                        let sly = world.fighter.y + ndy * (rush_distance * 0.5);
+                                        world.strike.trigger(slx, sly);
                                         let original_angle = (-dy).atan2(dx).to_degrees();
-                                        strike.angle = original_angle
+                                        world.strike.angle = original_angle
                                             + 90.0
-                                            + safe_gen_range(-10.0, 10.0, "Strike angle variation");
-                                        strike.timer = 0.2;
+                                            + safe_gen_range_cosmetic(-10.0, 10.0, "Strike angle variation");
+                                        world.strike.timer = 0.2;
                                         movement_active = false;
                                         backpedal_active = false;
                                         current_movement_direction = MovementDirection::None;
                                     }
                                 }
                             }
-                            Key::F1 => {
+                            Some(Action::SwitchRacer) => {
                                 if !is_paused
-                                    && !block_system.active
-                                    && !block_system.rmb_held
-                                    && fighter.fighter_type != FighterType::Racer
+                                    && !world.block_system.active
+                                    && !world.block_system.rmb_held
+                                    && world.fighter.fighter_type != FighterType::Racer
                                     && !downed_fighters.contains(&FighterType::Racer)
-                                    && fighter.state != RacerState::OnBike
+                                    && world.fighter.state != RacerState::OnBike
                                 {
-                                    fighter_hp_map.insert(fighter.fighter_type, fighter.current_hp);
+                                    fighter_hp_map.insert(world.fighter.fighter_type, world.fighter.current_hp);
                                     // Store previous combat mode before switching
                                     let new_radius =
-                                        fighter.switch_fighter_type(FighterType::Racer);
-                                    fixed_crater.radius = new_radius;
-                                    fighter.stats =
+                                        world.fighter.switch_fighter_type(FighterType::Racer);
+                                    world.fixed_crater.radius = new_radius;
+                                    world.fighter.stats =
 										fighter_stats_map
 											.get(&FighterType::Racer)
 											.copied()
 											.unwrap_or(combat::stats::RACER_LVL1_STATS);
-                                    fighter.max_hp = fighter.stats.defense.hp;
-                                    fighter.melee_damage = fighter.stats.attack.melee_damage;
-                                    fighter.ranged_damage = fighter.stats.attack.ranged_damage;
-                                    fighter.run_speed = fighter.stats.speed.run_speed;
-                                    fighter.current_hp = *fighter_hp_map
+                                    world.fighter.max_hp = world.fighter.stats.defense.hp;
+                                    world.fighter.melee_damage = world.fighter.stats.attack.melee_damage;
+                                    world.fighter.ranged_damage = world.fighter.stats.attack.ranged_damage;
+                                    world.fighter.run_speed = world.fighter.stats.speed.run_speed;
+                                    world.fighter.current_hp = *fighter_hp_map
                                         .entry(FighterType::Racer)
-                                        .or_insert(fighter.max_hp);
+                                        .or_insert(world.fighter.max_hp);
                                     // Reset combo stun state when switching to Racer
-                                    combo_system.is_combo3_stun_disabled = false;
+                                    world.combo_system.is_finisher_stun_disabled = false;
                                     // For RACER, switch to CLOSE COMBAT mode
-                                    fighter.combat_mode = CombatMode::CloseCombat;
+                                    world.fighter.combat_mode = CombatMode::CloseCombat;
                                     // chatbox.add_interaction(vec![("COMBAT MODE: CLOSE COMBAT", MessageType::Info)]);
                                     shift_override_active = false;
 
                                     update_current_textures(
-                                        &fighter,
+                                        &world.fighter,
                                         &racer_textures,
                                         &mut current_idle_texture,
                                         &mut current_fwd_texture,
@@ -9182,13 +7921,13 @@ fn main() {
                                     }
                                     // --- FIX: Reset and re-check level up state for the new fighter ---
                                     lvl_up_state = LvlUpState::None; // Always reset UI state on switch
-                                    let points_for_new_fighter = *fighter
+                                    let points_for_new_fighter = *world.fighter
                                         .stat_points_to_spend
-                                        .get(&fighter.fighter_type)
+                                        .get(&world.fighter.fighter_type)
                                         .unwrap_or(&0);
                                     if points_for_new_fighter > 0 {
                                         lvl_up_state = LvlUpState::PendingTab {
-                                            fighter_type: fighter.fighter_type,
+                                            fighter_type: world.fighter.fighter_type,
                                         };
                                         let fighter_name = "RACER"; // We know it's Racer here
                                         chatbox.add_interaction(vec![(
@@ -9202,41 +7941,84 @@ fn main() {
                                     // --- END FIX ---
                                 }
                             }
-                            Key::F2 => {
+                            Some(action @ (Action::SaveSlot1 | Action::SaveSlot2 | Action::SaveSlot3)) => {
+                                // Manual save to slot 1-3. Only in the open field, since a save resumes
+                                // at a field position and cannot restore bunker or area state, and
+                                // never from practice mode.
+                                let slot = match action {
+                                    Action::SaveSlot1 => 1,
+                                    Action::SaveSlot2 => 2,
+                                    _ => 3,
+                                };
+                                if training_mode.is_some()
+                                    || world.current_area.is_some()
+                                    || world.fighter.state == RacerState::OnBike
+                                {
+                                    chatbox.add_interaction(vec![(
+                                        "CANNOT SAVE HERE",
+                                        MessageType::Warning,
+                                    )]);
+                                } else {
+                                    let data = save::SaveData::capture(
+                                        &world.fighter,
+                                        &base_fighter_stats_map,
+                                        &world.task_system,
+                                        world.map_system.current_field_id,
+                                        &completed_bunker_waves,
+                                        &fog_of_war,
+                                        soldier_has_joined,
+                                        raptor_has_joined,
+                                    );
+                                    match save::write_slot(&saves_dir, slot, &data) {
+                                        Ok(()) => chatbox.add_interaction(vec![(
+                                            &format!("GAME SAVED TO SLOT {}", slot),
+                                            MessageType::Notification,
+                                        )]),
+                                        Err(err) => {
+                                            eprintln!("[SAVE] {}", err);
+                                            chatbox.add_interaction(vec![(
+                                                "SAVE FAILED",
+                                                MessageType::Warning,
+                                            )]);
+                                        }
+                                    }
+                                }
+                            }
+                            Some(Action::SwitchSoldier) => {
                                 if !is_paused
                                     && soldier_has_joined
-                                    && !block_system.active
-                                    && !block_system.rmb_held
-                                    && fighter.fighter_type != FighterType::Soldier
+                                    && !world.block_system.active
+                                    && !world.block_system.rmb_held
+                                    && world.fighter.fighter_type != FighterType::Soldier
                                     && !downed_fighters.contains(&FighterType::Soldier)
-                                    && fighter.state != RacerState::OnBike
+                                    && world.fighter.state != RacerState::OnBike
                                 {
-                                    fighter_hp_map.insert(fighter.fighter_type, fighter.current_hp);
+                                    fighter_hp_map.insert(world.fighter.fighter_type, world.fighter.current_hp);
                                     // Store previous combat mode before switching
                                     let new_radius =
-                                        fighter.switch_fighter_type(FighterType::Soldier);
-                                    fixed_crater.radius = new_radius;
-                                    fighter.stats =
+                                        world.fighter.switch_fighter_type(FighterType::Soldier);
+                                    world.fixed_crater.radius = new_radius;
+                                    world.fighter.stats =
 										fighter_stats_map
 											.get(&FighterType::Soldier)
 											.copied()
 											.unwrap_or(combat::stats::SOLDIER_LVL1_STATS);
-                                    fighter.max_hp = fighter.stats.defense.hp;
-                                    fighter.melee_damage = fighter.stats.attack.melee_damage;
-                                    fighter.ranged_damage = fighter.stats.attack.ranged_damage;
-                                    fighter.run_speed = fighter.stats.speed.run_speed;
-                                    fighter.current_hp = *fighter_hp_map
+                                    world.fighter.max_hp = world.fighter.stats.defense.hp;
+                                    world.fighter.melee_damage = world.fighter.stats.attack.melee_damage;
+                                    world.fighter.ranged_damage = world.fighter.stats.attack.ranged_damage;
+                                    world.fighter.run_speed = world.fighter.stats.speed.run_speed;
+                                    world.fighter.current_hp = *fighter_hp_map
                                         .entry(FighterType::Soldier)
-                                        .or_insert(fighter.max_hp);
+                                        .or_insert(world.fighter.max_hp);
                                     // Reset combo stun state when switching to Soldier
-                                    combo_system.is_combo3_stun_disabled = false;
+                                    world.combo_system.is_finisher_stun_disabled = false;
                                     // For SOLDIER, switch to CLOSE COMBAT mode
-                                    fighter.combat_mode = CombatMode::CloseCombat;
+                                    world.fighter.combat_mode = CombatMode::CloseCombat;
                                     // chatbox.add_interaction(vec![("COMBAT MODE: CLOSE COMBAT", MessageType::Info)]);
                                     shift_override_active = false;
 
                                     update_current_textures(
-                                        &fighter,
+                                        &world.fighter,
                                         &soldier_textures,
                                         &mut current_idle_texture,
                                         &mut current_fwd_texture,
@@ -9256,13 +8038,13 @@ fn main() {
 
                                     // --- FIX: Reset and re-check level up state for the new fighter ---
                                     lvl_up_state = LvlUpState::None; // Always reset UI state on switch
-                                    let points_for_new_fighter = *fighter
+                                    let points_for_new_fighter = *world.fighter
                                         .stat_points_to_spend
-                                        .get(&fighter.fighter_type)
+                                        .get(&world.fighter.fighter_type)
                                         .unwrap_or(&0);
                                     if points_for_new_fighter > 0 {
                                         lvl_up_state = LvlUpState::PendingTab {
-                                            fighter_type: fighter.fighter_type,
+                                            fighter_type: world.fighter.fighter_type,
                                         };
                                         let fighter_name = "SOLDIER"; // We know it's Soldier here
                                         chatbox.add_interaction(vec![(
@@ -9276,41 +8058,41 @@ fn main() {
                                     // --- END FIX ---
                                 }
                             }
-                            Key::F3 => {
+                            Some(Action::SwitchRaptor) => {
                                 if !is_paused
                                     && raptor_has_joined
-                                    && !block_system.active
-                                    && !block_system.rmb_held
-                                    && fighter.fighter_type != FighterType::Raptor
+                                    && !world.block_system.active
+                                    && !world.block_system.rmb_held
+                                    && world.fighter.fighter_type != FighterType::Raptor
                                     && !downed_fighters.contains(&FighterType::Raptor)
-                                    && fighter.state != RacerState::OnBike
+                                    && world.fighter.state != RacerState::OnBike
                                 {
-                                    fighter_hp_map.insert(fighter.fighter_type, fighter.current_hp);
+                                    fighter_hp_map.insert(world.fighter.fighter_type, world.fighter.current_hp);
                                     let new_radius =
-                                        fighter.switch_fighter_type(FighterType::Raptor);
-                                    fixed_crater.radius = new_radius;
-                                    fighter.stats =
+                                        world.fighter.switch_fighter_type(FighterType::Raptor);
+                                    world.fixed_crater.radius = new_radius;
+                                    world.fighter.stats =
 										fighter_stats_map
 											.get(&FighterType::Raptor)
 											.copied()
 											.unwrap_or(combat::stats::RAPTOR_LVL1_STATS);
-                                    fighter.max_hp = fighter.stats.defense.hp;
-                                    fighter.melee_damage = fighter.stats.attack.melee_damage;
-                                    fighter.ranged_damage = fighter.stats.attack.ranged_damage;
-                                    fighter.run_speed = fighter.stats.speed.run_speed;
-                                    fighter.current_hp = *fighter_hp_map
+                                    world.fighter.max_hp = world.fighter.stats.defense.hp;
+                                    world.fighter.melee_damage = world.fighter.stats.attack.melee_damage;
+                                    world.fighter.ranged_damage = world.fighter.stats.attack.ranged_damage;
+                                    world.fighter.run_speed = world.fighter.stats.speed.run_speed;
+                                    world.fighter.current_hp = *fighter_hp_map
                                         .entry(FighterType::Raptor)
-                                        .or_insert(fighter.max_hp);
+                                        .or_insert(world.fighter.max_hp);
                                     // Reset combo stun state when switching to raptor
-                                    combo_system.is_combo3_stun_disabled = false;
+                                    world.combo_system.is_finisher_stun_disabled = false;
 
                                     // Force raptor to use Close Combat mode
-                                    fighter.combat_mode = CombatMode::CloseCombat;
+                                    world.fighter.combat_mode = CombatMode::CloseCombat;
                                     // chatbox.add_interaction(vec![("raptor COMBAT MODE: CLOSE COMBAT [LOCKED]", MessageType::Info)]);
                                     shift_override_active = false;
 
                                     update_current_textures(
-                                        &fighter,
+                                        &world.fighter,
                                         &raptor_textures,
                                         &mut current_idle_texture,
                                         &mut current_fwd_texture,
@@ -9329,13 +8111,13 @@ fn main() {
                                     }
                                     // --- FIX: Reset and re-check level up state for the new fighter ---
                                     lvl_up_state = LvlUpState::None; // Always reset UI state on switch
-                                    let points_for_new_fighter = *fighter
+                                    let points_for_new_fighter = *world.fighter
                                         .stat_points_to_spend
-                                        .get(&fighter.fighter_type)
+                                        .get(&world.fighter.fighter_type)
                                         .unwrap_or(&0);
                                     if points_for_new_fighter > 0 {
                                         lvl_up_state = LvlUpState::PendingTab {
-                                            fighter_type: fighter.fighter_type,
+                                            fighter_type: world.fighter.fighter_type,
                                         };
                                         let fighter_name = "RAPTOR"; 
                                         chatbox.add_interaction(vec![(
@@ -9349,20 +8131,55 @@ fn main() {
                                     // --- END FIX ---
                                 }
                             }
-                            Key::E => {
+                            Some(action @ (Action::Skill1 | Action::Skill2)) => {
+                                let slot = if action == Action::Skill1 { 0 } else { 1 };
+                                if !is_paused && !world.block_system.is_stun_locked() && world.fighter.stun_timer <= 0.0 {
+                                    if let Some(skill_type) = world.fighter.ready_skill(slot) {
+                                        let def = skill_data::get().definition(skill_type);
+                                        let target = if def.shape.needs_target() {
+                                            skill_runner::nearest_cpu((world.fighter.x, world.fighter.y), def.range, &world.cpu_entities)
+                                        } else {
+                                            Some(screen_to_world(&camera, mouse_x, mouse_y))
+                                        };
+                                        // Blinking or dashing off the bike would leave it behind
+                                        let moves_fighter = matches!(def.shape, SkillShape::Teleport | SkillShape::Dash);
+                                        let can_cast = (!moves_fighter || world.fighter.state == RacerState::OnFoot)
+                                            && world.fighter.resources.can_afford(ResourceAction::Skill, 1.0);
+                                        if let Some(target) = target.filter(|_| can_cast) {
+                                            let cast = skill_runner::fighter_cast(skill_type, &world.fighter, target, world.movement_bounds());
+                                            world.fighter.skill_manager.trigger_skill(skill_type);
+                                            world.fighter.resources.spend(ResourceAction::Skill, 1.0);
+                                            cast.apply_player_cast(&mut world.fighter, &mut world.cpu_entities, &mut world.damage_texts);
+                                            cast.spawn(&mut world.pulse_orbs, &mut world.turrets, &mut world.skill_effects);
+                                            if def.visual == SkillVisual::Flicker {
+                                                world.strike.trigger(cast.to.0, cast.to.1);
+                                                world.strike.timer = 0.15;
+                                                active_visual_effects.push(FlickerStrikeEffectInstance {
+                                                    x: cast.from.0,
+                                                    y: cast.from.1,
+                                                    lifetime: 0.2,
+                                                    max_lifetime: 0.2,
+                                                });
+                                            }
+                                            world.audio.play_sound_effect(cast.sound()).ok();
+                                        }
+                                    }
+                                }
+                            }
+                            Some(Action::Interact) => {
                                 // If waiting for bunker entry choice, pressing E again cancels it.
                                 if bunker_entry_choice == BunkerEntryChoice::AwaitingInput {
                                     bunker_entry_choice = BunkerEntryChoice::None;
                                 } else if !is_paused && show_survivor_interaction_prompt {
                                     if let Some(index) = nearby_survivor_index {
                                         if let Some(field_survivors) =
-                                            survivors.get_mut(&sbrx_map_system.current_field_id)
+                                            world.survivors.get_mut(&world.map_system.current_field_id)
                                         {
                                             if let Some(survivor) = field_survivors.get_mut(index) {
                                                 if !survivor.is_rescued {
                                                     survivor.is_rescued = true;
 
-                                                    match task_system.survivors_found {
+                                                    match world.task_system.survivors_found {
                                                         0 => {
                                                             // First survivor
                                                             chatbox.add_interaction(vec![
@@ -9395,8 +8212,8 @@ fn main() {
                                                         }
                                                     }
 
-                                                    task_system.survivors_found += 1;
-                                                    if task_system.survivors_found >= 10 {
+                                                    world.task_system.survivors_found += 1;
+                                                    if world.task_system.survivors_found >= 10 {
                                                         chatbox.add_interaction(vec![
 														("-SURVIVOR-", MessageType::Info), 
 														("LOOK OUT!", MessageType::Dialogue), 
@@ -9411,7 +8228,7 @@ fn main() {
                                 } else if !is_paused && show_grand_commander_prompt {
                                     show_grand_commander_prompt = false;
                                     grand_commander_dialogue_triggered = true;
-                                    task_system.mark_grand_commander_spoken_to();
+                                    world.task_system.mark_grand_commander_spoken_to();
 
                                     // Revive all downed fighters
                                     if !downed_fighters.is_empty() {
@@ -9578,7 +8395,7 @@ fn main() {
                                         .or_insert(RAPTOR_LVL1_STATS.defense.hp);
                                     show_raptor_in_nest_graphic = false; // Hide raptor graphic on rescue
                                     raptor_is_trapped_in_nest = false;
-                                    task_system.populate_taskbar3();
+                                    world.task_system.populate_taskbar3();
                                     chatbox.add_interaction(vec![
 										("THE HURT RAPTOR LIFTS ITS HEAD AND 
 										LOOKS AT YOU.", MessageType::Notification),
@@ -9603,7 +8420,7 @@ fn main() {
 										("RAPTOR HAS JOINED THE GROUP. KEY F3 TO SWITCH", MessageType::Warning),
                                     ]);
                                 } else if !is_paused && show_info_post_prompt {
-                                    task_system.populate_taskbar1();
+                                    world.task_system.populate_taskbar1();
 									
                                     chatbox.add_interaction(vec![
 										("DUE TO THE HOSTILE WILDLIFE, THE RACE 
@@ -9650,7 +8467,7 @@ fn main() {
                                         .entry(FighterType::Soldier)
                                         .or_insert(SOLDIER_LVL1_STATS.defense.hp);
                                     soldier_visible = false;
-                                    task_system.populate_taskbar2();									
+                                    world.task_system.populate_taskbar2();									
 									
 									
                                     chatbox.add_interaction(vec![
@@ -9666,10 +8483,10 @@ fn main() {
                                     ]);
 									
 									
-                                } else if let Some(area_state) = &current_area.clone() {
+                                } else if let Some(area_state) = &world.current_area.clone() {
                                     // Clone to avoid borrow issues
-                                    if area_state.is_player_at_world_exit(fighter.x, fighter.y)
-                                        && !wave_manager.is_active()
+                                    if area_state.is_player_at_world_exit(world.fighter.x, world.fighter.y)
+                                        && !world.wave_manager.is_active()
                                     {
                                         // Capture the area type before we drop the borrow
                                         let exiting_area_type = area_state.area_type;
@@ -9684,40 +8501,46 @@ fn main() {
                                                     "Exiting Bunker area. Resetting wave system."
                                                 );
                                                 // --- FIX: Reset wave state on exit ---
-                                                wave_manager.reset();
+                                                world.wave_manager.reset();
                                                 completed_bunker_waves.clear();
 												explored_bunker_floors.clear();
                                                 // --- END FIX ---
                                                 SbrxFieldId(-25, 25)
                                             }
                                         };
-                                        current_area = None;
-                                        cpu_entities.clear();
-                                        sbrx_map_system.current_field_id = exit_field;
+                                        world.current_area = None;
+                                        world.cpu_entities.clear();
+                                        if exit_field != world.map_system.current_field_id {
+                                            world.game_events.publish(GameEvent::FieldChanged {
+                                                from: world.map_system.current_field_id,
+                                                to: exit_field,
+                                            });
+                                        }
+                                        world.map_system.current_field_id = exit_field;
                                         // Return player to entrance position
-                                        fighter.x = area_entrance_x;
-                                        fighter.y = area_entrance_y;
-                                        camera.x = fighter.x;
-                                        camera.y = fighter.y;
+                                        world.fighter.x = area_entrance_x;
+                                        world.fighter.y = area_entrance_y;
+                                        camera.x = world.fighter.x;
+                                        camera.y = world.fighter.y;
                                         show_raptor_in_nest_graphic = false;
 
                                         // Respawn appropriate enemies for the field when exiting area
-                                        if exiting_area_type == AreaType::RaptorNest && CPU_ENABLED
+                                        if exiting_area_type == AreaType::RaptorNest && game_config.cpu_enabled
                                         {
-                                            if !task_system
+                                            if !world.task_system
                                                 .is_task_complete("CLEAR RAPTOR NEST: FIELD[X1 Y0]")
                                             {
                                                 // Nest not cleared yet - respawn the 2 guard raptors
                                                 println!("Respawning guard raptors in field x1 y0 (nest not cleared)");
                                                 if let Some(nest) = raptor_nests.first() {
-                                                    if cpu_entities.len() < 10 {
-                                                        cpu_entities.push(CpuEntity::new_raptor(
+                                                    if world.cpu_entities.len() < 10 {
+                                                        world.cpu_entities.push(CpuEntity::new_raptor(
                                                             nest.x - 75.0,
                                                             nest.y,
                                                         ));
                                                     }
-                                                    if cpu_entities.len() < 10 {
-                                                        cpu_entities.push(CpuEntity::new_raptor(
+                                                    if world.cpu_entities.len() < 10 {
+                                                        world.cpu_entities.push(CpuEntity::new_raptor(
                                                             nest.x + 75.0,
                                                             nest.y,
                                                         ));
@@ -9730,29 +8553,29 @@ fn main() {
                                                 );
 
                                                 // Spawn Giant Mantis
-                                                if cpu_entities.len() < 10 {
-                                                    cpu_entities
+                                                if world.cpu_entities.len() < 10 {
+                                                    world.cpu_entities
                                                         .push(CpuEntity::new_giant_mantis(line_y));
                                                 }
 
                                                 // Spawn 3 Rattlesnakes (standard for non-origin fields)
                                                 for _ in 0..3 {
-                                                    if cpu_entities.len() < 10 {
-                                                        cpu_entities.push(
+                                                    if world.cpu_entities.len() < 10 {
+                                                        world.cpu_entities.push(
                                                             CpuEntity::new_rattlesnake(line_y),
                                                         );
                                                     }
                                                 }
 
                                                 // Spawn Giant Rattlesnakes based on score
-                                                let current_score = fighter.score;
+                                                let current_score = world.fighter.score;
                                                 let mut score_tier = 5;
                                                 while score_tier <= current_score {
                                                     if spawned_giant_rattlesnake_scores
                                                         .contains(&score_tier)
                                                     {
-                                                        if cpu_entities.len() < 10 {
-                                                            cpu_entities.push(
+                                                        if world.cpu_entities.len() < 10 {
+                                                            world.cpu_entities.push(
                                                                 CpuEntity::new_giant_rattlesnake(
                                                                     line_y,
                                                                 ),
@@ -9770,13 +8593,13 @@ fn main() {
                                             && exiting_area_type == AreaType::RaptorNest
                                         {
                                             println!("Spawning T-REX!");
-                                            task_system.populate_taskbar4();
-                                            if cpu_entities.len() < 10 {
+                                            world.task_system.populate_taskbar4();
+                                            if world.cpu_entities.len() < 10 {
                                                 let t_rex_x =
                                                     safe_gen_range(MIN_X, MAX_X, "T-Rex spawn x");
                                                 let t_rex_y =
                                                     safe_gen_range(MIN_Y, MAX_Y, "T-Rex spawn y");
-                                                cpu_entities
+                                                world.cpu_entities
                                                     .push(CpuEntity::new_t_rex(t_rex_x, t_rex_y));
                                             }
                                             t_rex_spawn_pending = false;
@@ -9789,12 +8612,12 @@ fn main() {
                                         // Spawn T-Rex if already active (subsequent exits)
                                         else if t_rex_is_active
                                             && exiting_area_type == AreaType::RaptorNest
-                                            && CPU_ENABLED
+                                            && game_config.cpu_enabled
                                         {
-                                            let t_rex_exists = cpu_entities.iter().any(|e| {
+                                            let t_rex_exists = world.cpu_entities.iter().any(|e| {
                                                 e.variant == CpuVariant::TRex && !e.is_dead()
                                             });
-                                            if !t_rex_exists && cpu_entities.len() < 10 {
+                                            if !t_rex_exists && world.cpu_entities.len() < 10 {
                                                 let t_rex_x = safe_gen_range(
                                                     MIN_X,
                                                     MAX_X,
@@ -9805,7 +8628,7 @@ fn main() {
                                                     MAX_Y,
                                                     "T-Rex nest exit y",
                                                 );
-                                                cpu_entities
+                                                world.cpu_entities
                                                     .push(CpuEntity::new_t_rex(t_rex_x, t_rex_y));
                                                 println!("Respawned T-Rex when exiting raptor nest (already active)");
                                                 chatbox.add_interaction(vec![(
@@ -9815,7 +8638,7 @@ fn main() {
                                             }
                                         }
                                     } else if show_bunker_floor_transition_prompt
-                                        && !wave_manager.is_active()
+                                        && !world.wave_manager.is_active()
                                     {
                                         if let Some(target_floor) = target_floor_from_prompt {
                                             let current_floor = area_state.floor;
@@ -9839,14 +8662,14 @@ fn main() {
                                                 && !is_peaceful_mode;
 
                                             if waves_for_target_floor > 0 && !is_peaceful_mode {
-                                                wave_manager
+                                                world.wave_manager
                                                     .start_encounter(waves_for_target_floor);
                                             } else {
-                                                wave_manager.reset();
+                                                world.wave_manager.reset();
                                             }
                                             // --- END WAVE TRIGGER ---
 
-                                            cpu_entities.clear();
+                                            world.cpu_entities.clear();
 
                                             // Create a new area state for the new floor
                                             let (new_area, _player_start_pos) = AreaState::new(
@@ -9864,9 +8687,9 @@ fn main() {
                                                     .iter()
                                                     .find(|t| t.target_floor > target_floor)
                                                 {
-                                                    fighter.x = ascent_point.rect.x
+                                                    world.fighter.x = ascent_point.rect.x
                                                         + ascent_point.rect.width / 2.0;
-                                                    fighter.y = ascent_point.rect.y + 50.0;
+                                                    world.fighter.y = ascent_point.rect.y + 50.0;
                                                     // Spawn just below the point
                                                 }
                                             } else {
@@ -9877,15 +8700,15 @@ fn main() {
                                                     .iter()
                                                     .find(|t| t.target_floor < target_floor)
                                                 {
-                                                    fighter.x = descent_point.rect.x - 50.0; // Spawn just to the left
-                                                    fighter.y = descent_point.rect.y
+                                                    world.fighter.x = descent_point.rect.x - 50.0; // Spawn just to the left
+                                                    world.fighter.y = descent_point.rect.y
                                                         + descent_point.rect.height / 2.0;
                                                 }
                                             }
 
                                             // Spawn Night Reavers on the new floor
                                             if new_area.spawn_night_reavers
-                                                && CPU_ENABLED
+                                                && game_config.cpu_enabled
                                                 && target_floor != -3
                                                 && !completed_bunker_waves.contains(&target_floor)
                                                 && !is_peaceful_mode
@@ -9895,7 +8718,7 @@ fn main() {
                                                     target_floor
                                                 );
                                                 for _ in 0..4 {
-                                                    if cpu_entities.len() < 10 {
+                                                    if world.cpu_entities.len() < 10 {
                                                         let reaver_x = safe_gen_range(
                                                             BUNKER_ORIGIN_X + 50.0,
                                                             BUNKER_ORIGIN_X + BUNKER_WIDTH - 50.0,
@@ -9907,23 +8730,23 @@ fn main() {
                                                             "NightReaver y",
                                                         );
                                                         let mut reaver = CpuEntity::new_night_reaver(reaver_x, reaver_y);
-                                                        let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+                                                        let aptitude = get_field_aptitude(world.map_system.current_field_id);
                                                         reaver.max_hp *= aptitude;
                                                         reaver.current_hp = reaver.max_hp;
                                                         reaver.damage_value *= aptitude;
                                                         reaver.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                                        cpu_entities.push(reaver);
+                                                        world.cpu_entities.push(reaver);
                                                     }
                                                 }
                                             }
 
                                             // Spawn RazorFiend on floor -3
                                             if target_floor == -3
-                                                && task_system.razor_fiend_defeated == 0
+                                                && world.task_system.razor_fiend_defeated == 0
                                                 && !is_peaceful_mode
                                             {
                                                 println!("Spawning RazorFiend in bunker floor: -3");
-                                                if cpu_entities.len() < 10 {
+                                                if world.cpu_entities.len() < 10 {
                                                     // Check entity limit
                                                     let fiend_x = safe_gen_range(
                                                         BUNKER_ORIGIN_X + 50.0,
@@ -9936,12 +8759,12 @@ fn main() {
                                                         "RazorFiend y",
                                                     );
                                                     let mut boss = CpuEntity::new_razor_fiend(fiend_x, fiend_y);
-                                                    let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+                                                    let aptitude = get_field_aptitude(world.map_system.current_field_id);
                                                     boss.max_hp *= aptitude;
                                                     boss.current_hp = boss.max_hp;
                                                     boss.damage_value *= aptitude;
                                                     boss.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                                    cpu_entities.push(boss);
+                                                    world.cpu_entities.push(boss);
                                                     chatbox.add_interaction(vec![(
                                                         "DANGER! DEADLY ENCOUNTER",
                                                         MessageType::Warning,
@@ -9949,10 +8772,10 @@ fn main() {
                                                 }
                                             }
                                             // Must clone here due to borrow checker
-                                            current_area = Some(new_area.clone());
+                                            world.current_area = Some(new_area.clone());
 											
                                             // --- FOG OF WAR: Reset fog for unexplored bunker floors ---
-                                            if sbrx_map_system.current_field_id == SbrxFieldId(-25, 25)
+                                            if world.map_system.current_field_id == SbrxFieldId(-25, 25)
                                                 && !explored_bunker_floors.contains(&target_floor)
                                             {
                                                 fog_of_war.reset_field_fog(SbrxFieldId(-25, 25));
@@ -9963,14 +8786,14 @@ fn main() {
                                                 );
                                             }											
 
-                                            camera.x = fighter.x;
-                                            camera.y = fighter.y;
-                                            println!("Transitioned to bunker floor: {}. Player spawned at ({}, {})", target_floor, fighter.x, fighter.y);
+                                            camera.x = world.fighter.x;
+                                            camera.y = world.fighter.y;
+                                            println!("Transitioned to bunker floor: {}. Player spawned at ({}, {})", target_floor, world.fighter.x, world.fighter.y);
                                         }
                                     }
                                 } else if !is_paused && show_fighter_jet_prompt {
                                     // Check if VoidTempest is alive and blocking
-                                    let void_tempest_alive = cpu_entities.iter().any(|e| {
+                                    let void_tempest_alive = world.cpu_entities.iter().any(|e| {
                                         e.variant == CpuVariant::VoidTempest && !e.is_dead()
                                     });
 
@@ -9996,22 +8819,19 @@ fn main() {
                                             sink.stop();
                                         }
 
-                                        if task_system.mark_fighter_jet_as_boarded() {
-											let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
-                                            award_kill_score(
-                                                &mut fighter,
-                                                10,
-                                                &mut chatbox,
-                                                &mut lvl_up_state,
-                                                "completing BOARD THE FIGHTERJET task",
-												aptitude,
-                                            );
+                                        if world.task_system.mark_fighter_jet_as_boarded() {
+											let aptitude = get_field_aptitude(world.map_system.current_field_id);
+                                            if world.award_kill_score(10, aptitude) {
+                                                lvl_up_state = LvlUpState::PendingTab {
+                                                    fighter_type: world.fighter.fighter_type,
+                                                };
+                                            }
                                         }
 
                                         game_state = GameState::LoadingFirmament;
                                     }
                                 } else if !is_paused && show_raptor_nest_prompt {
-                                    if fighter.state == RacerState::OnBike {
+                                    if world.fighter.state == RacerState::OnBike {
                                         chatbox.add_interaction(vec![(
                                             "YOU MUST DISMOUNT TO ENTER",
                                             MessageType::Notification,
@@ -10019,8 +8839,8 @@ fn main() {
                                     } else {
                                         println!("Key E pressed near raptor nest. Entering...");
                                         // Save entrance position before entering
-                                        area_entrance_x = fighter.x;
-                                        area_entrance_y = fighter.y;
+                                        area_entrance_x = world.fighter.x;
+                                        area_entrance_y = world.fighter.y;
                                         // Set raptor as trapped when first entering nest (if not already joined)
                                         if !raptor_has_joined {
                                             raptor_is_trapped_in_nest = true;
@@ -10030,12 +8850,12 @@ fn main() {
                                             !raptor_has_joined && raptor_is_trapped_in_nest;
                                         let (new_area, player_start_pos) =
                                             AreaState::new(AreaType::RaptorNest, 1, false, false); // No waves in raptor nest
-                                        current_area = Some(new_area);
-                                        fighter.x = player_start_pos.0;
-                                        fighter.y = player_start_pos.1;
-                                        camera.x = fighter.x;
-                                        camera.y = fighter.y;
-                                        cpu_entities.clear();
+                                        world.current_area = Some(new_area);
+                                        world.fighter.x = player_start_pos.0;
+                                        world.fighter.y = player_start_pos.1;
+                                        camera.x = world.fighter.x;
+                                        camera.y = world.fighter.y;
+                                        world.cpu_entities.clear();
                                         t_rex_spawn_pending = false;
                                         for _ in 0..12 {
                                             let raptor_x = safe_gen_range(
@@ -10048,22 +8868,22 @@ fn main() {
                                                 AREA_ORIGIN_Y + AREA_HEIGHT - 50.0,
                                                 "raptor y",
                                             );
-                                            if cpu_entities.len() < 10 {
+                                            if world.cpu_entities.len() < 10 {
 												let mut raptor = CpuEntity::new_raptor(raptor_x, raptor_y);
-												let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+												let aptitude = get_field_aptitude(world.map_system.current_field_id);
 												raptor.max_hp *= aptitude;
 												raptor.current_hp = raptor.max_hp;
 												raptor.damage_value *= aptitude;
 												raptor.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-												cpu_entities.push(raptor);
+												world.cpu_entities.push(raptor);
                                             }
                                         }
                                     }
                                 } else if !is_paused && show_bunker_prompt {
-                                    if task_system.is_task_complete("SPEAK TO THE GRAND COMMANDER")
+                                    if world.task_system.is_task_complete("SPEAK TO THE GRAND COMMANDER")
                                     {
                                         bunker_entry_choice = BunkerEntryChoice::AwaitingInput;
-                                    } else if fighter.state == RacerState::OnBike {
+                                    } else if world.fighter.state == RacerState::OnBike {
                                         chatbox.add_interaction(vec![(
                                             "YOU MUST DISMOUNT TO ENTER",
                                             MessageType::Notification,
@@ -10071,8 +8891,8 @@ fn main() {
                                     } else {
                                         println!("Key E pressed near bunker. Entering...");
                                         // save entrance position before entering
-                                        area_entrance_x = fighter.x;
-                                        area_entrance_y = fighter.y;
+                                        area_entrance_x = world.fighter.x;
+                                        area_entrance_y = world.fighter.y;
 
                                         // --- WAVE SYSTEM TRIGGER on first entry ---
                                         let mut bunker_wave_definitions: HashMap<i32, u32> =
@@ -10089,7 +8909,7 @@ fn main() {
                                             waves_for_floor_1 > 0 && !bunker_waves_fully_completed;
 
                                         if waves_for_floor_1 > 0 {
-                                            wave_manager.start_encounter(waves_for_floor_1);
+                                            world.wave_manager.start_encounter(waves_for_floor_1);
                                         }
                                         // --- END WAVE TRIGGER ---
                                         let (new_area, player_start_pos) = AreaState::new(
@@ -10100,21 +8920,21 @@ fn main() {
                                         );
 
                                         let should_spawn_reavers = new_area.spawn_night_reavers;
-                                        current_area = Some(new_area);
-                                        fighter.x = player_start_pos.0;
-                                        fighter.y = player_start_pos.1;
-                                        camera.x = fighter.x;
-                                        camera.y = fighter.y;
-                                        cpu_entities.clear();
+                                        world.current_area = Some(new_area);
+                                        world.fighter.x = player_start_pos.0;
+                                        world.fighter.y = player_start_pos.1;
+                                        camera.x = world.fighter.x;
+                                        camera.y = world.fighter.y;
+                                        world.cpu_entities.clear();
                                         // Spawn Night Reavers in bunker
                                         // Only spawn if waves for floor 1 haven't been completed yet
                                         if should_spawn_reavers
-                                            && CPU_ENABLED
+                                            && game_config.cpu_enabled
                                             && !completed_bunker_waves.contains(&1)
                                         {
                                             println!("Spawning Night Reavers in bunker floor 1");
                                             for _ in 0..4 {
-                                                if cpu_entities.len() < 10 {
+                                                if world.cpu_entities.len() < 10 {
                                                     let reaver_x = safe_gen_range(
                                                         BUNKER_ORIGIN_X + 50.0,
                                                         BUNKER_ORIGIN_X + BUNKER_WIDTH - 50.0,
@@ -10126,12 +8946,12 @@ fn main() {
                                                         "NightReaver y",
                                                     );
 													let mut reaver = CpuEntity::new_night_reaver(reaver_x, reaver_y);
-													let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+													let aptitude = get_field_aptitude(world.map_system.current_field_id);
 													reaver.max_hp *= aptitude;
 													reaver.current_hp = reaver.max_hp;
 													reaver.damage_value *= aptitude;
 													reaver.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-													cpu_entities.push(reaver);
+													world.cpu_entities.push(reaver);
                                                 }
                                             }
                                         }
@@ -10187,14 +9007,14 @@ fn main() {
                     }
                 }
                 if let Some(Button::Keyboard(key)) = e.release_args() {
-                    match key {
-                        Key::W => key_w_pressed = false,
-                        Key::S => key_s_pressed = false,
-                        Key::A => key_a_pressed = false,
-                        Key::D => key_d_pressed = false,
+                    match key_bindings.action_for(key) {
+                        Some(Action::MoveUp) => key_w_pressed = false,
+                        Some(Action::MoveDown) => key_s_pressed = false,
+                        Some(Action::MoveLeft) => key_a_pressed = false,
+                        Some(Action::MoveRight) => key_d_pressed = false,
                         _ => (),
                     }
-                    if fighter.state == RacerState::OnFoot
+                    if world.fighter.state == RacerState::OnFoot
                         && !key_w_pressed
                         && !key_s_pressed
                         && !key_a_pressed
@@ -10203,19 +9023,19 @@ fn main() {
                         && !is_high_priority_animation_active(
                             rush_active,
                             strike_animation_timer,
-                            block_system.active,
-                            block_system.rmb_held,
+                            world.block_system.active,
+                            world.block_system.rmb_held,
                         )
                         && !movement_active
                         && !backpedal_active
                     {
-                        if fighter.fighter_type == FighterType::Soldier && shift_held {
+                        if world.fighter.fighter_type == FighterType::Soldier && shift_held {
                             current_racer_texture = soldier_textures.ranged_aim.as_ref().unwrap_or(current_idle_texture);
                         } else {
                             current_racer_texture = current_idle_texture;
                         }
                         current_movement_direction = MovementDirection::None;
-                    } else if fighter.state == RacerState::OnBike
+                    } else if world.fighter.state == RacerState::OnBike
                         && !key_w_pressed
                         && !key_s_pressed
                         && !key_a_pressed
@@ -10224,11 +9044,11 @@ fn main() {
                         && !is_high_priority_animation_active(
                             rush_active,
                             strike_animation_timer,
-                            block_system.active,
-                            block_system.rmb_held,
+                            world.block_system.active,
+                            world.block_system.rmb_held,
                         )
                     {
-                        if fighter.fighter_type == FighterType::Soldier && shift_held {
+                        if world.fighter.fighter_type == FighterType::Soldier && shift_held {
                             current_racer_texture = soldier_textures.bike_ranged_aim.as_ref().unwrap_or(current_idle_texture);
                         } else {
                             current_racer_texture = current_idle_texture;
@@ -10249,6 +9069,7 @@ fn main() {
                 // Render the death screen
                 if let Some(_) = e.render_args() {
                     window.draw_2d(&e, |c, g, device| {
+                        let c = viewport.apply(c);
                         clear([0.0, 0.0, 0.0, 1.0], g);
 
                         let death_message_text = match death_type {
@@ -10324,54 +9145,55 @@ fn main() {
 					
                     // BUG FIX: Reset lmb_held on respawn to prevent stuck melee strike
                     lmb_held = false;
-                    melee_rapid_fire_timer = 0.0;
+                    world.melee_rapid_fire_timer = 0.0;
                     soldier_rapid_fire_timer = 0.0;					
 
                     // Reset flying saucer defeated flag if died to flying saucer
                     if death_type == DeathType::FlyingSaucer {
                         firmament_boss_defeated = false;
-                        task_system.flying_saucer_defeated = false;
+                        world.task_system.flying_saucer_defeated = false;
                         println!("Reset flying saucer defeated flag after death");
                     }
                     game_state = GameState::Playing;
 
                     // --- PRESERVE PROGRESS ---
-                    let saved_score = fighter.score;
+                    let saved_score = world.fighter.score;
 
                     // --- BUG FIX: Preserve progression data across the reset ---
-                    let saved_kill_counters = fighter.kill_counters.clone();
-                    let saved_levels = fighter.levels.clone();
-                    let saved_stat_points = fighter.stat_points_to_spend.clone();
+                    let saved_kill_counters = world.fighter.kill_counters.clone();
+                    let saved_levels = world.fighter.levels.clone();
+                    let saved_stat_points = world.fighter.stat_points_to_spend.clone();
 
                     // Use fixed spawn point for Racetrack, otherwise use last entry point
-                    let spawn_point = if sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
+                    let spawn_point = if world.map_system.current_field_id == SbrxFieldId(0, 0) {
                         RACETRACK_SPAWN_POINT
                     } else {
                         last_field_entry_point
                     };
-                    fighter = Fighter::new(spawn_point.0, spawn_point.1);
-					fighter.score = saved_score;
+                    world.fighter = Fighter::new(spawn_point.0, spawn_point.1);
+					world.fighter.score = saved_score;
 
-                    fighter.score = saved_score;
-                    fighter.kill_counters = saved_kill_counters;
-                    fighter.levels = saved_levels;
-                    fighter.stat_points_to_spend = saved_stat_points;
+                    world.fighter.score = saved_score;
+                    world.fighter.kill_counters = saved_kill_counters;
+                    world.fighter.levels = saved_levels;
+                    world.fighter.stat_points_to_spend = saved_stat_points;
+                    world.fighter.sync_skills();
 
                     // Reload the Racer's leveled-up stats from the persistent map
 					let racer_stats = base_fighter_stats_map
 						.get(&FighterType::Racer)
 						.copied()
 						.unwrap_or(combat::stats::RACER_LVL1_STATS);
-                    fighter.stats = racer_stats;
-                    fighter.max_hp = racer_stats.defense.hp;
-                    fighter.melee_damage = racer_stats.attack.melee_damage;
-                    fighter.ranged_damage = racer_stats.attack.ranged_damage;
-                    fighter.run_speed = racer_stats.speed.run_speed;
+                    world.fighter.stats = racer_stats;
+                    world.fighter.max_hp = racer_stats.defense.hp;
+                    world.fighter.melee_damage = racer_stats.attack.melee_damage;
+                    world.fighter.ranged_damage = racer_stats.attack.ranged_damage;
+                    world.fighter.run_speed = racer_stats.speed.run_speed;
                     // --- END FIX ---
 
-                    sbrx_bike.respawn(fighter.x + 100.0, fighter.y);
-                    fighter.state = RacerState::OnFoot;
-                    fixed_crater.radius = 125.0;
+                    sbrx_bike.respawn(world.fighter.x + 100.0, world.fighter.y);
+                    world.fighter.state = RacerState::OnFoot;
+                    world.fixed_crater.radius = 125.0;
 
                     if endless_arena_mode_active {
                         // Deactivate SOLDIER and raptor field assets on first defeat
@@ -10414,10 +9236,10 @@ fn main() {
                         FighterType::Raptor,
                         base_fighter_stats_map[&FighterType::Raptor].defense.hp,
                     );
-                    fighter.current_hp = fighter.max_hp;
+                    world.fighter.current_hp = world.fighter.max_hp;
 					
                     update_current_textures(
-                        &fighter,
+                        &world.fighter,
                         &racer_textures,
                         &mut current_idle_texture,
                         &mut current_fwd_texture,
@@ -10433,15 +9255,15 @@ fn main() {
                     );
                     current_racer_texture = current_idle_texture;
 
-                    fixed_crater.x = fighter.x;
-                    fixed_crater.y = fighter.y;
-                    camera.x = fighter.x;
-                    camera.y = fighter.y;
+                    world.fixed_crater.x = world.fighter.x;
+                    world.fixed_crater.y = world.fighter.y;
+                    camera.x = world.fighter.x;
+                    camera.y = world.fighter.y;
 
                     // --- BUG FIX: Restore LVL_UP prompt after full party wipe ---
                     lvl_up_state = LvlUpState::None; // Reset UI state
                                                      // On full wipe, player always respawns as Racer. Check their points.
-                    let points_for_racer = *fighter
+                    let points_for_racer = *world.fighter
                         .stat_points_to_spend
                         .get(&FighterType::Racer)
                         .unwrap_or(&0);
@@ -10456,8 +9278,8 @@ fn main() {
                     }
                     // --- END FIX ---
 					
-                    if sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
-                        for cr in &mut cpu_racers {
+                    if world.map_system.current_field_id == SbrxFieldId(0, 0) {
+                        for cr in &mut world.cpu_racers {
                             cr.current_hp = cr.max_hp;
                             cr.entity_state = EntityState::Neutral;
                             cr.is_crashed = false;
@@ -10477,36 +9299,36 @@ fn main() {
                         }
                     }					
 
-                    cpu_entities.clear();
-                    let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+                    world.cpu_entities.clear();
+                    let aptitude = get_field_aptitude(world.map_system.current_field_id);
  
-                    if CPU_ENABLED {
+                    if game_config.cpu_enabled {
                         if endless_arena_mode_active {
-                            if cpu_entities.len() < 10 {
-                                cpu_entities.push(spawn_random_cpu(line_y, endless_arena_stage, endless_arena_timer));
+                            if world.cpu_entities.len() < 10 {
+                                world.cpu_entities.push(spawn_random_cpu(line_y, endless_arena_stage, endless_arena_timer));
                             }
                         } else {
-                            if cpu_entities.len() < 10 {
+                            if world.cpu_entities.len() < 10 {
                                 let mut cpu = CpuEntity::new_giant_mantis(line_y);
                                 cpu.max_hp *= aptitude;
                                 cpu.current_hp = cpu.max_hp;
                                 cpu.damage_value *= aptitude;
                                 cpu.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                cpu_entities.push(cpu);
+                                world.cpu_entities.push(cpu);
                             }
                         }
                     }
 
                     // Respawn task-dependent entities if they should be present
-                    let current_field = sbrx_map_system.current_field_id;
+                    let current_field = world.map_system.current_field_id;
 
                     // Respawn T-Rex if it should be active
-                    if task_system.is_task_complete("CLEAR RAPTOR NEST: FIELD[X1 Y0]")
-                        && !task_system.is_task_complete("DEFEAT THE T-REX")
+                    if world.task_system.is_task_complete("CLEAR RAPTOR NEST: FIELD[X1 Y0]")
+                        && !world.task_system.is_task_complete("DEFEAT THE T-REX")
                         && current_field == SbrxFieldId(1, 0)
-                        && CPU_ENABLED
+                        && game_config.cpu_enabled
                     {
-                        if cpu_entities.len() < 10 {
+                        if world.cpu_entities.len() < 10 {
                             let t_rex_x = safe_gen_range(MIN_X, MAX_X, "T-Rex respawn x");
                             let t_rex_y = safe_gen_range(MIN_Y, MAX_Y, "T-Rex respawn y");
                             let mut t_rex = CpuEntity::new_t_rex(t_rex_x, t_rex_y);
@@ -10514,36 +9336,36 @@ fn main() {
                             t_rex.current_hp = t_rex.max_hp;
                             t_rex.damage_value *= aptitude;
                             t_rex.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                            cpu_entities.push(t_rex);
+                            world.cpu_entities.push(t_rex);
                             println!("Respawned T-Rex after party wipe in field x1 y0");
                         }
                     }
 
                     // Respawn VoidTempest if it should be active
-                    if task_system.survivors_found >= 10
+                    if world.task_system.survivors_found >= 10
                         && current_field == SbrxFieldId(-2, 5)
-                        && CPU_ENABLED
+                        && game_config.cpu_enabled
                     {
-                        let void_tempest_exists = cpu_entities
+                        let void_tempest_exists = world.cpu_entities
                             .iter()
                             .any(|e| e.variant == CpuVariant::VoidTempest);
-                        if !void_tempest_exists && cpu_entities.len() < 10 {
+                        if !void_tempest_exists && world.cpu_entities.len() < 10 {
                             let (base_hp, base_speed) = (250.0, 150.0);
                             let mut vt = CpuEntity::new_void_tempest(line_y, base_hp, base_speed);
                             vt.max_hp *= aptitude;
                             vt.current_hp = vt.max_hp;
                             vt.damage_value *= aptitude;
                             vt.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                            cpu_entities.push(vt);
+                            world.cpu_entities.push(vt);
                             println!("Respawned VoidTempest after party wipe");
                         }
                     }
 
                     // Respawn Rattlesnakes based on score
-                    if CPU_ENABLED {
+                    if game_config.cpu_enabled {
                         let should_spawn_rattlesnakes = if current_field == SbrxFieldId(0, 0) {
                             // In field (0,0), only spawn if score >= 3 and they were spawned before
-                            fighter.score >= 3 && rattlesnakes_spawned_in_field0_score3
+                            world.fighter.score >= 3 && rattlesnakes_spawned_in_field0_score3
                         } else {
                             // In any other field, always spawn rattlesnakes
                             true
@@ -10555,55 +9377,55 @@ fn main() {
                                 current_field
                             );
                             for _ in 0..3 {
-                                if cpu_entities.len() < 10 {
+                                if world.cpu_entities.len() < 10 {
                                     let mut snake = CpuEntity::new_rattlesnake(line_y);
                                     snake.max_hp *= aptitude;
                                     snake.current_hp = snake.max_hp;
                                     snake.damage_value *= aptitude;
                                     snake.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                    cpu_entities.push(snake);
+                                    world.cpu_entities.push(snake);
                                 }
                             }
                         }
                     }
 
                     // Respawn BLOOD IDOL if in fog-enabled field
-                    if FOG_OF_WAR_ENABLED
+                    if game_config.fog_of_war_enabled
                         && fog_of_war.is_fog_enabled(current_field)
                         && has_blood_idol_fog_spawned_once
-                        && CPU_ENABLED
+                        && game_config.cpu_enabled
                     {
-                        let blood_idol_exists = cpu_entities
+                        let blood_idol_exists = world.cpu_entities
                             .iter()
                             .any(|e| e.variant == CpuVariant::BloodIdol);
-                        if !blood_idol_exists && cpu_entities.len() < 10 {
+                        if !blood_idol_exists && world.cpu_entities.len() < 10 {
                             let (base_hp, base_speed) = (250.0, 150.0);
                             let mut spirit = CpuEntity::new_blood_idol(line_y, base_hp, base_speed);
                             spirit.max_hp *= aptitude;
                             spirit.current_hp = spirit.max_hp;
                             spirit.damage_value *= aptitude;
                             spirit.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                            cpu_entities.push(spirit);
+                            world.cpu_entities.push(spirit);
                             println!("Respawned BloodIdol after party wipe in fog field");
                         }
                     }
 
                     // Respawn Giant Rattlesnakes based on score
-                    if CPU_ENABLED {
-                        let current_score = fighter.score;
+                    if game_config.cpu_enabled {
+                        let current_score = world.fighter.score;
                         let mut score_tier = 5;
                         while score_tier <= current_score {
                             if spawned_giant_rattlesnake_scores.contains(&score_tier) {
-                                let giant_rattlesnake_exists = cpu_entities
+                                let giant_rattlesnake_exists = world.cpu_entities
                                     .iter()
                                     .any(|e| e.variant == CpuVariant::GiantRattlesnake);
-                                if !giant_rattlesnake_exists && cpu_entities.len() < 10 {
+                                if !giant_rattlesnake_exists && world.cpu_entities.len() < 10 {
                                     let mut cpu = CpuEntity::new_giant_rattlesnake(line_y);
                                     cpu.max_hp *= aptitude;
                                     cpu.current_hp = cpu.max_hp;
                                     cpu.damage_value *= aptitude;
                                     cpu.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                    cpu_entities.push(cpu);
+                                    world.cpu_entities.push(cpu);
                                     println!("Respawned Giant Rattlesnake after party wipe for score tier {}", score_tier);
                                     break; // Only spawn one
                                 }
@@ -10613,8 +9435,8 @@ fn main() {
                     }
 
                     // Respawn Raptors if in raptor nest area
-                    if let Some(ref area_state) = current_area {
-                        if area_state.area_type == AreaType::RaptorNest && CPU_ENABLED {
+                    if let Some(ref area_state) = world.current_area {
+                        if area_state.area_type == AreaType::RaptorNest && game_config.cpu_enabled {
                             println!("Respawning raptors after party wipe in raptor nest");
                             for _ in 0..12 {
                                 let raptor_x = safe_gen_range(
@@ -10627,75 +9449,77 @@ fn main() {
                                     AREA_ORIGIN_Y + AREA_HEIGHT - 50.0,
                                     "raptor respawn y",
                                 );
-                                if cpu_entities.len() < 10 {
+                                if world.cpu_entities.len() < 10 {
                                     let mut raptor = CpuEntity::new_raptor(raptor_x, raptor_y);
                                     raptor.max_hp *= aptitude;
                                     raptor.current_hp = raptor.max_hp;
                                     raptor.damage_value *= aptitude;
                                     raptor.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                    cpu_entities.push(raptor);
+                                    world.cpu_entities.push(raptor);
                                 }
                             }
                         }
                     }
                     // Respawn Raptors if in field x1 y0 and nest not cleared
                     else if current_field == SbrxFieldId(1, 0)
-                        && !task_system.is_task_complete("CLEAR RAPTOR NEST: FIELD[X1 Y0]")
-                        && CPU_ENABLED
+                        && !world.task_system.is_task_complete("CLEAR RAPTOR NEST: FIELD[X1 Y0]")
+                        && game_config.cpu_enabled
                     {
-						let aptitude = get_field_aptitude(sbrx_map_system.current_field_id);
+						let aptitude = get_field_aptitude(world.map_system.current_field_id);
                         // Check if there's a raptor nest in the field
                         if !raptor_nests.is_empty() {
                             println!(
                                 "Respawning raptors after party wipe near nest in field x1 y0"
                             );
                             if let Some(nest) = raptor_nests.first() {
-                                if cpu_entities.len() < 10 {
+                                if world.cpu_entities.len() < 10 {
                                     let mut r1 = CpuEntity::new_raptor(nest.x - 75.0, nest.y);
                                     r1.max_hp *= aptitude;
                                     r1.current_hp = r1.max_hp;
                                     r1.damage_value *= aptitude;
                                     r1.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                    cpu_entities.push(r1);
+                                    world.cpu_entities.push(r1);
                                 }
-                                if cpu_entities.len() < 10 {
+                                if world.cpu_entities.len() < 10 {
                                     let mut r2 = CpuEntity::new_raptor(nest.x + 75.0, nest.y);
                                     r2.max_hp *= aptitude;
                                     r2.current_hp = r2.max_hp;
                                     r2.damage_value *= aptitude;
                                     r2.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                    cpu_entities.push(r2);
+                                    world.cpu_entities.push(r2);
                                 }
                             }
                         }
                     }
 
                     // Reset T-Rex active flag if defeated during party wipe
-                    let t_rex_exists = cpu_entities.iter().any(|e| e.variant == CpuVariant::TRex);
+                    let t_rex_exists = world.cpu_entities.iter().any(|e| e.variant == CpuVariant::TRex);
                     if !t_rex_exists && t_rex_is_active {
                         t_rex_is_active = false;
                     }
 
-                    damage_texts.clear();
-                    current_area = None;
-                    pulse_orbs.clear();
+                    world.damage_texts.clear();
+                    world.current_area = None;
+                    world.pulse_orbs.clear();
+                    world.turrets.clear();
+                    world.skill_effects.clear();
 					spheres.clear();
 
-                    wave_manager.reset(); // BUG FIX: Reset wave state on full party wipe.
+                    world.wave_manager.reset(); // BUG FIX: Reset wave state on full party wipe.
 
                     movement_active = false;
                     movement_timer = 0.0;
                     backpedal_active = false;
                     backpedal_timer = 0.0;
                     current_movement_direction = MovementDirection::None;
-                    block_system = BlockSystem::new(20);
-                    block_system.needs_dismount = false;
+                    world.block_system = BlockSystem::new(20);
+                    world.block_system.needs_dismount = false;
                     block_break_animation_active = false;
 
                     rush_active = false;
                     rush_timer = 0.0;
                     rush_cooldown = 0.0;
-                    combo_system = ComboSystem::new();
+                    world.combo_system = ComboSystem::new();
                     strike_animation_timer = 0.0;
                     strike_frame = 0;
                     key_w_pressed = false;
@@ -10764,17 +9588,17 @@ fn main() {
 								
                                 // BUG FIX: Reset lmb_held on group swap respawn
                                 lmb_held = false;
-                                melee_rapid_fire_timer = 0.0;
+                                world.melee_rapid_fire_timer = 0.0;
                                 soldier_rapid_fire_timer = 0.0;								
 								
-                                audio_manager.play_sound_effect("death").ok();
-                                fighter_hp_map.insert(fighter.fighter_type, fighter.current_hp);
-                                let new_radius = fighter.switch_fighter_type(target_fighter);
-                                fixed_crater.radius = new_radius;
+                                world.audio.play_sound_effect("death").ok();
+                                fighter_hp_map.insert(world.fighter.fighter_type, world.fighter.current_hp);
+                                let new_radius = world.fighter.switch_fighter_type(target_fighter);
+                                world.fixed_crater.radius = new_radius;
 
                                 // --- BUG FIX ---
                                 // Reload the correct, leveled-up stats for the selected fighter.
-								fighter.stats = fighter_stats_map
+								world.fighter.stats = fighter_stats_map
 									.get(&target_fighter)
 									.copied()
 									.unwrap_or_else(|| match target_fighter {
@@ -10782,20 +9606,20 @@ fn main() {
 										FighterType::Soldier => combat::stats::SOLDIER_LVL1_STATS,
 										FighterType::Raptor => combat::stats::RAPTOR_LVL1_STATS,
 									});
-                                fighter.max_hp = fighter.stats.defense.hp;
-                                fighter.melee_damage = fighter.stats.attack.melee_damage;
-                                fighter.ranged_damage = fighter.stats.attack.ranged_damage;
-                                fighter.run_speed = fighter.stats.speed.run_speed;
+                                world.fighter.max_hp = world.fighter.stats.defense.hp;
+                                world.fighter.melee_damage = world.fighter.stats.attack.melee_damage;
+                                world.fighter.ranged_damage = world.fighter.stats.attack.ranged_damage;
+                                world.fighter.run_speed = world.fighter.stats.speed.run_speed;
                                 // --- END FIX ---
 
-								fighter.current_hp = fighter_hp_map
+								world.fighter.current_hp = fighter_hp_map
 									.get(&target_fighter)
 									.copied()
-									.unwrap_or(fighter.max_hp);
+									.unwrap_or(world.fighter.max_hp);
 
                                 // --- BUG FIX: Restore LVL_UP prompt after respawn ---
                                 lvl_up_state = LvlUpState::None; // Reset UI state first
-                                let points_for_new_fighter = *fighter
+                                let points_for_new_fighter = *world.fighter
                                     .stat_points_to_spend
                                     .get(&target_fighter)
                                     .unwrap_or(&0);
@@ -10819,12 +9643,12 @@ fn main() {
                                 // --- END FIX ---
 
                                 // Set combat mode based on fighter type
-                                if fighter.fighter_type == FighterType::Raptor {
-                                    fighter.combat_mode = CombatMode::CloseCombat;
+                                if world.fighter.fighter_type == FighterType::Raptor {
+                                    world.fighter.combat_mode = CombatMode::CloseCombat;
                                 }
 
                                 // BUG FIX: Ensure the new fighter always spawns OnFoot.
-                                fighter.state = RacerState::OnFoot;
+                                world.fighter.state = RacerState::OnFoot;
 
                                 let tex_set = match target_fighter {
                                     FighterType::Racer => &racer_textures,
@@ -10832,7 +9656,7 @@ fn main() {
                                     FighterType::Raptor => &raptor_textures,
                                 };
                                 update_current_textures(
-                                    &fighter,
+                                    &world.fighter,
                                     tex_set,
                                     &mut current_idle_texture,
                                     &mut current_fwd_texture,
@@ -10864,17 +9688,16 @@ fn main() {
                                 backpedal_active = false;
 
                                 for _ in 0..1 {
-                                    let strike_x = fighter.x;
-                                    let strike_y = fighter.y;
-                                    strike.trigger(strike_x, strike_y);
+                                    let strike_x = world.fighter.x;
+                                    let strike_y = world.fighter.y;
+                                    world.strike.trigger(strike_x, strike_y);
 
-                                    if !is_paused && CPU_ENABLED {
-                                        for (_cpu_index, cpu) in cpu_entities.iter_mut().enumerate()
+                                    if !is_paused && game_config.cpu_enabled {
+                                        for (_cpu_index, cpu) in world.cpu_entities.iter_mut().enumerate()
                                         {
-                                            let sdx = strike_x - cpu.x;
-                                            let sdy = strike_y - cpu.y;
-                                            if (sdx * sdx + sdy * sdy).sqrt() < COLLISION_THRESHOLD
-                                            {
+                                            let hurtbox = Hurtbox::for_cpu(cpu);
+                                            if Hitbox::circle(strike_x, strike_y, hitbox::strike_point_radius()).hits(&hurtbox) {
+                                                hitbox::record_hit(&hurtbox);
                                                 // Respawn strike: knockback only, no damage
                                                 cpu.apply_knockback(strike_x, strike_y, 3000.0);
                                             }
@@ -10887,10 +9710,10 @@ fn main() {
                 }
 				
                 // --- JUMP ZONE CHECK FOR CPU_RACERS ---
-                if !is_paused && current_area.is_none() && sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
-                    for cr in &mut cpu_racers {
-                        if let Some(hit) = collision_barrier_manager.check_jump(
-                            &sbrx_map_system.current_field_id,
+                if !is_paused && world.current_area.is_none() && world.map_system.current_field_id == SbrxFieldId(0, 0) {
+                    for cr in &mut world.cpu_racers {
+                        if let Some(hit) = world.collision_barriers.check_jump(
+                            &world.map_system.current_field_id,
                             cr.x,
                             cr.y,
                         ) {
@@ -10942,8 +9765,8 @@ fn main() {
                         }
                         
                         // --- CHAIN ZONE CHECK FOR CPU ---
-                        if let Some(hit) = collision_barrier_manager.check_chain(
-                            &sbrx_map_system.current_field_id,
+                        if let Some(hit) = world.collision_barriers.check_chain(
+                            &world.map_system.current_field_id,
                             cr.x,
                             cr.y,
                         ) {
@@ -10972,16 +9795,16 @@ fn main() {
                 }
  
                 // --- CPU GROUND ASSET & BARRIER COLLISIONS ---
-                if !is_paused && current_area.is_none() && sbrx_map_system.current_field_id == SbrxFieldId(0, 0) {
-                    for cr in &mut cpu_racers {
+                if !is_paused && world.current_area.is_none() && world.map_system.current_field_id == SbrxFieldId(0, 0) {
+                    for cr in &mut world.cpu_racers {
                         if cr.invincible_timer <= 0.0 {
-                            if let Some((barrier_x, barrier_y)) = collision_barrier_manager.check_collision(
-                                &sbrx_map_system.current_field_id,
+                            if let Some((barrier_x, barrier_y)) = world.collision_barriers.check_collision(
+                                &world.map_system.current_field_id,
                                 cr.x,
                                 cr.y,
                                 45.0,
                             ) {
-                                audio_manager.play_sound_effect("death").ok();
+                                world.audio.play_sound_effect("death").ok();
                                 let dx = cr.x - barrier_x;
                                 let dy = cr.y - barrier_y;
                                 let angle = dy.atan2(dx);
@@ -10997,13 +9820,13 @@ fn main() {
                             }
                             
                             // Check ground assets
-                            if let Some(assets) = placed_ground_assets.get(&sbrx_map_system.current_field_id) {
+                            if let Some(assets) = placed_ground_assets.get(&world.map_system.current_field_id) {
                                 for asset in assets {
                                     let dx = cr.x - asset.x;
                                     let dy = cr.y - asset.y;
                                     let dist_sq = dx * dx + dy * dy;
                                     if dist_sq < 45.0 * 45.0 {
-                                        audio_manager.play_sound_effect("death").ok();
+                                        world.audio.play_sound_effect("death").ok();
                                         let angle = dy.atan2(dx);
                                         
                                         cr.current_hp -= 100.0;
@@ -11024,6 +9847,7 @@ fn main() {
 				
                 if let Some(_) = e.render_args() {
                     window.draw_2d(&e, |c, g, device| {
+                        let c = viewport.apply(c);
                         clear([0.0, 0.0, 0.0, 1.0], g);
 
                         let downed_fighter_name = match downed_fighter_type {
@@ -11181,6 +10005,7 @@ fn main() {
                 // This state's only job is to draw the loading screen and then request the load.
                 if let Some(_) = e.render_args() {
                     window.draw_2d(&e, |c, g, device| {
+                        let c = viewport.apply(c);
                         clear([0.0, 0.0, 0.0, 1.0], g);
                         let (w, h) = (
                             loading_screen_texture.get_width() as f64,
@@ -11197,6 +10022,10 @@ fn main() {
 
             GameState::FirmamentMode(ref mut firmament_game) => {
                 if let Some(args) = e.update_args() {
+                    if game_config.gamepad.enabled {
+                        let (turn, thrust) = gamepad.jet_controls(&game_config.gamepad);
+                        firmament_game.set_analog_controls(turn, thrust);
+                    }
                     firmament_game.update(args.dt);
 					chatbox.update(args.dt, enter_key_held);
 					
@@ -11213,7 +10042,7 @@ fn main() {
                         match ambient_track_state {
                             AmbientTrackState::Background => {
                                 // FIRMAMENT audio now uses the same shared playlist logic as standard PLAYING mode
-                                if let Some(track_name) = handle_ambient_playlist(&audio_manager, &mut current_bgm_sink, &mut ambient_playlist_index) {
+                                if let Some(track_name) = handle_ambient_playlist(&world.audio, &mut current_bgm_sink, &mut ambient_playlist_index) {
                                     track_notification = Some(TrackNotification {
                                         track_name,
                                         lifetime: 3.0,
@@ -11227,7 +10056,7 @@ fn main() {
                                     }
                                 }
                                 if crickets_sound_sink.is_none() {
-                                    match audio_manager.play_sfx_loop("crickets") {
+                                    match world.audio.play_sfx_loop("crickets") {
                                         Ok(sink) => crickets_sound_sink = Some(sink),
                                         Err(e) => eprintln!("Failed to play crickets sound in Firmament: {}", e),
                                     }
@@ -11245,17 +10074,17 @@ fn main() {
                     }					
 
                     // Sync taskbar state from firmament and reset timer if opened
-                    let prev_open = task_system.open;
-                    task_system.open = firmament_game.task_bar_open;
-                    if task_system.open && !prev_open {
-                        task_system.auto_close_timer = 7.0; // Reset timer when taskbar is opened
+                    let prev_open = world.task_system.open;
+                    world.task_system.open = firmament_game.task_bar_open;
+                    if world.task_system.open && !prev_open {
+                        world.task_system.auto_close_timer = 7.0; // Reset timer when taskbar is opened
                     }
 
                     // Check for boss defeat to update tasks and state
                     if !firmament_boss_defeated && firmament_game.is_boss_defeated() {
                         println!("Flying saucer defeated! Updating task system and main state.");
                         firmament_boss_defeated = true;
-                        task_system.flying_saucer_defeated = true;
+                        world.task_system.flying_saucer_defeated = true;
                     }
 
                     // Check if entered Fort Silo field in FIRMAMENT
@@ -11280,21 +10109,23 @@ fn main() {
                     // Check if fighter_jet has entered Fort Silo field
                     let current_fm_field = firmament_game.get_current_field_id();
                     if current_fm_field.0 == -25 && current_fm_field.1 == 25 {
-                        if !task_system.fort_silo_reached {
+                        if !world.task_system.fort_silo_reached {
                             println!("fighter_jet entered Fort Silo airfield! Marking task complete.");
-                            task_system.mark_fort_silo_reached();
+                            world.task_system.mark_fort_silo_reached();
                         }
                     }
 
                     // Update task system in firmament mode
-                    task_system.update();
-					task_system.update_timer(args.dt);
+                    world.task_system.update();
+					world.task_system.update_timer(args.dt);
 					// Sync back: if timer closed the taskbar, update firmament's state too
-					firmament_game.task_bar_open = task_system.open;					
+					firmament_game.task_bar_open = world.task_system.open;					
                 }
                 if let Some(_args) = e.render_args() {
                     window.draw_2d(&e, |c, g, device| {
+                        let c = viewport.apply(c);
                         firmament_game.render(c, g, &mut glyphs);
+                        viewport.cover_bars(&c, g);
                         if is_paused {
                             let (w, h) = (
                                 pause_screen_texture.get_width() as f64,
@@ -11306,10 +10137,10 @@ fn main() {
                         }
 
                         // Draw task system in Firmament mode
-                        task_system.draw(c, g, &mut glyphs);
+                        world.task_system.draw(c, g, &mut glyphs);
 
                         // Draw fighter group icons in Firmament mode
-                        fighter_hp_map.insert(fighter.fighter_type, fighter.current_hp);
+                        fighter_hp_map.insert(world.fighter.fighter_type, world.fighter.current_hp);
                         let mut current_x = 25.0;
                         let start_y = 70.0;
                         let padding = 10.0;
@@ -11330,8 +10161,8 @@ fn main() {
                             };
 
                             if has_joined && !downed_fighters.contains(ft) {
-                                let is_selected = fighter.fighter_type == *ft;
-                                let icon_to_draw = if *ft == FighterType::Racer && fighter.racer_variant == 1 {
+                                let is_selected = world.fighter.fighter_type == *ft;
+                                let icon_to_draw = if *ft == FighterType::Racer && world.fighter.racer_variant == 1 {
                                     if is_selected { Some(&racer2_group_icon_selected) } else { Some(&racer2_group_icon) }
                                 } else {
                                     if is_selected { group_icons_selected.get(ft) } else { group_icons.get(ft) }
@@ -11344,7 +10175,7 @@ fn main() {
                                     image(icon, c.transform.trans(current_x, start_y), g);
 
                                     // Draw '+' sign if level up points are available
-                                    if fighter.stat_points_to_spend.get(ft).unwrap_or(&0) > &0 {
+                                    if world.fighter.stat_points_to_spend.get(ft).unwrap_or(&0) > &0 {
                                         let plus_text = "+";
                                         let plus_font_size = 20;
                                         let plus_color = [1.0, 0.5, 0.0, 1.0]; // Orange
@@ -11444,28 +10275,28 @@ fn main() {
                      new_fighter_jet_world_x: f64,
                      new_fighter_jet_world_y: f64,
                      new_next_firmament_entry_id: firmament_lib::FieldId3D| {
-                        sbrx_map_system.current_field_id = target_sbrx_field_for_player;
+                        world.map_system.current_field_id = target_sbrx_field_for_player;
                         last_field_entry_point = (player_bike_landing_x, player_bike_landing_y);
                         rattlesnakes_spawned_in_field0_score3 = false;
-                        fighter.x = player_bike_landing_x;
-                        fighter.y = player_bike_landing_y;
-                        fighter.state = RacerState::OnFoot;
-                        fixed_crater.x = fighter.x;
-                        fixed_crater.y = fighter.y;
-                        camera.x = fighter.x;
-                        camera.y = fighter.y;
+                        world.fighter.x = player_bike_landing_x;
+                        world.fighter.y = player_bike_landing_y;
+                        world.fighter.state = RacerState::OnFoot;
+                        world.fixed_crater.x = world.fighter.x;
+                        world.fixed_crater.y = world.fighter.y;
+                        camera.x = world.fighter.x;
+                        camera.y = world.fighter.y;
                         sbrx_bike.respawn(player_bike_landing_x, player_bike_landing_y);
                         fighter_jet_current_sbrx_location = new_fighter_jet_sbrx_location;
                         fighter_jet_world_x = new_fighter_jet_world_x;
                         fighter_jet_world_y = new_fighter_jet_world_y;
                         next_firmament_entry_field_id = new_next_firmament_entry_id;
-                        let tex_set = match fighter.fighter_type {
+                        let tex_set = match world.fighter.fighter_type {
                             FighterType::Racer => &racer_textures,
                             FighterType::Soldier => &soldier_textures,
                             FighterType::Raptor => &raptor_textures,
                         };
                         update_current_textures(
-                            &fighter,
+                            &world.fighter,
                             tex_set,
                             &mut current_idle_texture,
                             &mut current_fwd_texture,
@@ -11492,11 +10323,11 @@ fn main() {
                         key_s_pressed = false;
                         key_a_pressed = false;
                         key_d_pressed = false;
-                        block_system.deactivate();
+                        world.block_system.deactivate();
 						
                         // Apply aptitude to CPUs in the landing field immediately
                         let aptitude = get_field_aptitude(target_sbrx_field_for_player);
-                        for cpu in &mut cpu_entities {
+                        for cpu in &mut world.cpu_entities {
                             cpu.max_hp *= aptitude;
                             cpu.current_hp = cpu.max_hp;
                             cpu.damage_value *= aptitude;
@@ -11504,9 +10335,9 @@ fn main() {
                         }						
 
                         // Spawn Light Reavers when landing at Fort Silo from Firmament
-                        if target_sbrx_field_for_player == SbrxFieldId(-25, 25) && CPU_ENABLED {
+                        if target_sbrx_field_for_player == SbrxFieldId(-25, 25) && game_config.cpu_enabled {
                             println!("Spawning Light Reavers in Fort Silo field x-25 y25 (from Firmament landing)");
-                            cpu_entities.clear(); // Clear any existing entities first
+                            world.cpu_entities.clear(); // Clear any existing entities first
 							let aptitude = get_field_aptitude(target_sbrx_field_for_player);
                             for _ in 0..2 {
                                 let reaver_x = safe_gen_range(MIN_X, MAX_X, "LightReaver x");
@@ -11516,14 +10347,14 @@ fn main() {
                                 reaver.current_hp = reaver.max_hp;
                                 reaver.damage_value *= aptitude;
                                 reaver.speed *= 1.0 + (aptitude - 1.0) * 0.2;
-                                cpu_entities.push(reaver);
+                                world.cpu_entities.push(reaver);
                             }
                         }
 						
                         // Heal Racer for the finale if landing on Racetrack
                         if target_sbrx_field_for_player == SbrxFieldId(0, 0) {
-                             fighter.current_hp = fighter.max_hp;
-                             fighter_hp_map.insert(FighterType::Racer, fighter.max_hp);
+                             world.fighter.current_hp = world.fighter.max_hp;
+                             fighter_hp_map.insert(FighterType::Racer, world.fighter.max_hp);
 
 							// Ensure Racer is not marked as downed
 							if downed_fighters.contains(&FighterType::Racer) {
@@ -11552,16 +10383,16 @@ fn main() {
                                     || firmament_game.is_boss_defeated()) =>
                         {
                             println!("F5 (EJECT) pressed in FirmamentMode.");
-							audio_manager.play_sound_effect("death").ok();
+							world.audio.play_sound_effect("death").ok();
                             let current_fm_field = firmament_game.get_current_field_id();		
 							
                             // Special Case: Ejecting on Racetrack during Finale Task acts as Landing (F1 behavior)
                             if current_fm_field.0 == 0 && current_fm_field.1 == 0
-                                && task_system.has_task("LAND THE FIGHTERJET ON THE RACETRACK")
-                                && !task_system.is_task_complete("LAND THE FIGHTERJET ON THE RACETRACK")
+                                && world.task_system.has_task("LAND THE FIGHTERJET ON THE RACETRACK")
+                                && !world.task_system.is_task_complete("LAND THE FIGHTERJET ON THE RACETRACK")
                             {
                                 println!("F5 pressed on Racetrack during Finale. Treating as Landing.");
-                                task_system.mark_racer_returned_to_racetrack();
+                                world.task_system.mark_racer_returned_to_racetrack();
                                 
                                 let target_sbrx_field_for_all = SbrxFieldId(0, 0);
                                 // Specific landing zone for the Racetrack (top-left corner)
@@ -11583,9 +10414,9 @@ fn main() {
 							
                             // Check for ejecting at Fort Silo to complete task
                             if current_fm_field.0 == -25 && current_fm_field.1 == 25 {
-                                if task_system.has_task("LAND ON FORT SILO") {
+                                if world.task_system.has_task("LAND ON FORT SILO") {
                                     println!("Ejected onto Fort Silo. Updating task system.");
-                                    task_system.landed_on_fort_silo = true;
+                                    world.task_system.landed_on_fort_silo = true;
                                 }
                             }
                             let firmament_score = firmament_game.get_score();
@@ -11643,19 +10474,19 @@ fn main() {
 
                             // Check for landing at Fort Silo to complete task
                             if current_fm_field.0 == -25 && current_fm_field.1 == 25 {
-                                if task_system.has_task("LAND ON FORT SILO") {
+                                if world.task_system.has_task("LAND ON FORT SILO") {
                                     println!("Landed on Fort Silo. Updating task system.");
-                                    task_system.landed_on_fort_silo = true;
+                                    world.task_system.landed_on_fort_silo = true;
                                 }
                             }
 
                             // Check for returning to racetrack task
-                            if task_system.has_task("LAND THE FIGHTERJET ON THE RACETRACK")
-                                && !task_system.is_task_complete("LAND THE FIGHTERJET ON THE RACETRACK")
+                            if world.task_system.has_task("LAND THE FIGHTERJET ON THE RACETRACK")
+                                && !world.task_system.is_task_complete("LAND THE FIGHTERJET ON THE RACETRACK")
                                 && target_sbrx_field_for_all == SbrxFieldId(0, 0)
                             {
                                 println!("Landed on Racetrack. Completing task.");
-                                task_system.mark_racer_returned_to_racetrack();								
+                                world.task_system.mark_racer_returned_to_racetrack();								
                             }
 
                             let firmament_score = firmament_game.get_score();
@@ -11707,8 +10538,13 @@ fn main() {
                         == Some(firmament_lib::FirmamentDeathCause::FlyingSaucer)
                     {
                         game_state = GameState::DeathScreen(DeathType::FlyingSaucer);
+                        world.game_events.publish(GameEvent::FighterDowned {
+                            fighter_type: world.fighter.fighter_type,
+                            death_type: DeathType::FlyingSaucer,
+                            group_continues: false,
+                        });
                         death_screen_cooldown = DEATH_SCREEN_COOLDOWN_TIME;
-                        audio_manager.play_sound_effect("death").ok();
+                        world.audio.play_sound_effect("death").ok();
                         if let Some(sink) = bike_accelerate_sound_sink.take() {
                             sink.stop();
                         }
@@ -11723,7 +10559,7 @@ fn main() {
                         continue; // Skip the rest of the exit logic for this frame
                     }
 
-                    audio_manager.play_sound_effect("death").ok();
+                    world.audio.play_sound_effect("death").ok();
                     println!(
                         "Firmament game over. Returning to SBRX (fighter_jet destroyed scenario)."
                     );
@@ -11765,13 +10601,14 @@ fn main() {
 		g: &mut G2d,
 		glyphs: &mut Glyphs,
 		pressed_keys: &HashSet<Key>,
+		key_bindings: &KeyBindings,
 		mouse_lmb_pressed: bool,
 		mouse_rmb_pressed: bool,
 	) {
 		let w_cell = 45.0;
 		let h_cell = 45.0;
-		let base_x = 1920.0 - (7.0 * w_cell) - 50.0;
-		let base_y = 1080.0 - (5.0 * h_cell) - 50.0;
+		let base_x = WIDTH - (7.0 * w_cell) - 50.0;
+		let base_y = HEIGHT - (5.0 * h_cell) - 50.0;
 
 		// Translucent background
 		piston_window::rectangle(
@@ -11782,7 +10619,7 @@ fn main() {
 		);
 
 		struct KeyBlock {
-			text: &'static str,
+			text: String,
 			keys: Vec<Key>,
 			col_start: usize,
 			row_start: usize,
@@ -11790,38 +10627,53 @@ fn main() {
 			row_span: usize,
 		}
 
+		// Bound blocks show and highlight whatever key the action is bound to; the fixed
+		// F5, number and CTRL blocks keep their physical keys.
+		let fixed = |text: &str, key_list: Vec<Key>, col_start: usize, row_start: usize, col_span: usize| KeyBlock {
+			text: text.to_string(), keys: key_list, col_start, row_start, col_span, row_span: 1,
+		};
+		let bound = |action: Action, col_start: usize, row_start: usize, col_span: usize| {
+			let keys = key_bindings.keys(action).to_vec();
+			let text = match keys.as_slice() {
+				[] => String::new(),
+				[Key::LShift, Key::RShift] | [Key::LShift] | [Key::RShift] => "SHIFT".to_string(),
+				[first, ..] => game::input_handler::key_name(*first).to_uppercase(),
+			};
+			KeyBlock { text, keys, col_start, row_start, col_span, row_span: 1 }
+		};
+
 		let key_blocks = vec![
 			// Row 0: F-keys
-			KeyBlock { text: "F1", keys: vec![Key::F1], col_start: 0, row_start: 0, col_span: 1, row_span: 1 },
-			KeyBlock { text: "F2", keys: vec![Key::F2], col_start: 1, row_start: 0, col_span: 1, row_span: 1 },
-			KeyBlock { text: "F3", keys: vec![Key::F3], col_start: 2, row_start: 0, col_span: 1, row_span: 1 },
-			KeyBlock { text: "F4", keys: vec![Key::F4], col_start: 3, row_start: 0, col_span: 1, row_span: 1 },
-			KeyBlock { text: "F5", keys: vec![Key::F5], col_start: 4, row_start: 0, col_span: 1, row_span: 1 },
+			bound(Action::SwitchRacer, 0, 0, 1),
+			bound(Action::SwitchSoldier, 1, 0, 1),
+			bound(Action::SwitchRaptor, 2, 0, 1),
+			bound(Action::CycleWindowMode, 3, 0, 1),
+			fixed("F5", vec![Key::F5], 4, 0, 1),
 			
 			// Row 1: Numbers
-			KeyBlock { text: "1", keys: vec![Key::D1], col_start: 0, row_start: 1, col_span: 1, row_span: 1 },
-			KeyBlock { text: "2", keys: vec![Key::D2], col_start: 1, row_start: 1, col_span: 1, row_span: 1 },
-			KeyBlock { text: "3", keys: vec![Key::D3], col_start: 2, row_start: 1, col_span: 1, row_span: 1 },
-			KeyBlock { text: "4", keys: vec![Key::D4], col_start: 3, row_start: 1, col_span: 1, row_span: 1 },
-			KeyBlock { text: "5", keys: vec![Key::D5], col_start: 4, row_start: 1, col_span: 1, row_span: 1 },
+			fixed("1", vec![Key::D1], 0, 1, 1),
+			fixed("2", vec![Key::D2], 1, 1, 1),
+			fixed("3", vec![Key::D3], 2, 1, 1),
+			fixed("4", vec![Key::D4], 3, 1, 1),
+			fixed("5", vec![Key::D5], 4, 1, 1),
 			
 			// Row 2: Shift, W, empty slots
-			KeyBlock { text: "SHIFT", keys: vec![Key::LShift, Key::RShift], col_start: 0, row_start: 2, col_span: 2, row_span: 1 },
-			KeyBlock { text: "W", keys: vec![Key::W, Key::Up], col_start: 2, row_start: 2, col_span: 1, row_span: 1 },
-			KeyBlock { text: "", keys: vec![], col_start: 3, row_start: 2, col_span: 1, row_span: 1 },
-			KeyBlock { text: "", keys: vec![], col_start: 4, row_start: 2, col_span: 1, row_span: 1 },
+			bound(Action::Stance, 0, 2, 2),
+			bound(Action::MoveUp, 2, 2, 1),
+			fixed("", vec![], 3, 2, 1),
+			fixed("", vec![], 4, 2, 1),
 			
 			// Row 3: Blank, A, S, D, F
-			KeyBlock { text: "", keys: vec![], col_start: 0, row_start: 3, col_span: 1, row_span: 1 },
-			KeyBlock { text: "A", keys: vec![Key::A, Key::Left], col_start: 1, row_start: 3, col_span: 1, row_span: 1 },
-			KeyBlock { text: "S", keys: vec![Key::S, Key::Down], col_start: 2, row_start: 3, col_span: 1, row_span: 1 },
-			KeyBlock { text: "D", keys: vec![Key::D, Key::Right], col_start: 3, row_start: 3, col_span: 1, row_span: 1 },
-			KeyBlock { text: "F", keys: vec![Key::F], col_start: 4, row_start: 3, col_span: 1, row_span: 1 },
+			fixed("", vec![], 0, 3, 1),
+			bound(Action::MoveLeft, 1, 3, 1),
+			bound(Action::MoveDown, 2, 3, 1),
+			bound(Action::MoveRight, 3, 3, 1),
+			bound(Action::Boost, 4, 3, 1),
 			
 			// Row 4: Ctrl, Space, V
-			KeyBlock { text: "CTRL", keys: vec![Key::LCtrl, Key::RCtrl], col_start: 0, row_start: 4, col_span: 1, row_span: 1 },
-			KeyBlock { text: "SPACE", keys: vec![Key::Space], col_start: 1, row_start: 4, col_span: 3, row_span: 1 },
-			KeyBlock { text: "V", keys: vec![Key::V], col_start: 4, row_start: 4, col_span: 1, row_span: 1 },
+			fixed("CTRL", vec![Key::LCtrl, Key::RCtrl], 0, 4, 1),
+			bound(Action::Rush, 1, 4, 3),
+			bound(Action::Mount, 4, 4, 1),
 		];
 
 		let border_color = [0.7, 1.0, 0.0, 1.0];
@@ -11850,12 +10702,12 @@ fn main() {
 
 			if !block.text.is_empty() {
 				let font_size = 14;
-				let text_width = glyphs.width(font_size, block.text).unwrap_or(0.0);
+				let text_width = glyphs.width(font_size, &block.text).unwrap_or(0.0);
 				let text_x = x + (w - text_width) / 2.0;
 				let text_y = y + (h + font_size as f64) / 2.0 - 2.0;
 				text::Text::new_color(border_color, font_size)
 					.draw(
-						block.text,
+						&block.text,
 						glyphs,
 						&c.draw_state,
 						c.transform.trans(text_x, text_y),
@@ -11931,5 +10783,11 @@ fn main() {
 		text::Text::new_color(border_color, font_size).draw(rmb_text, glyphs, &c.draw_state, c.transform.trans(text_x, text_y), g).ok();
 	}	
 	
+    if let Some(recorder) = replay_recorder.as_mut() {
+        if let Err(e) = recorder.finish() {
+            eprintln!("{}", e);
+        }
+    }
+    println!("[STATS] Session: {}", game_stats.summary());
     println!("sbrx0.2.22 Game loop ended.");
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 // src/mechanics/mod.rs
 
 pub mod lvl_up;
+pub mod training;
 pub mod wave;
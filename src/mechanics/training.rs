@@ -0,0 +1,195 @@
+// mechanics/training.rs
+
+use crate::combat::combo::ComboSystem;
+use crate::combat::combo_data;
+use crate::config;
+use crate::entities::cpu_entity::CpuEntity;
+use crate::simulation::World;
+use piston_window::*;
+
+/// Practice mode started from the title screen: a single dummy CPU that heals instead of
+/// dying, and an overlay showing the live combo timing and kinetic intake.
+pub struct TrainingMode {
+    idle_timer: f64,           // time since the dummy last lost HP
+    damage_dealt: f64,         // since the dummy last healed
+    last_dummy_hp: f64,
+    last_strike_count: u32,
+    strike_marks: Vec<f64>,    // elapsed time of each strike in the current window
+}
+
+impl TrainingMode {
+    pub fn new() -> Self {
+        TrainingMode {
+            idle_timer: 0.0,
+            damage_dealt: 0.0,
+            last_dummy_hp: 0.0,
+            last_strike_count: 0,
+            strike_marks: Vec::new(),
+        }
+    }
+
+    /// Keeps exactly one dummy in the field (anything else that spawns is removed), heals it
+    /// after `training.dummy_reset_delay` seconds without a hit, and records strike timings.
+    pub fn update(
+        &mut self,
+        dt: f64,
+        cpu_entities: &mut Vec<CpuEntity>,
+        combo_system: &ComboSystem,
+        fighter_x: f64,
+        fighter_y: f64,
+        line_y: f64,
+    ) {
+        let settings = &config::get().training;
+        cpu_entities.retain(|cpu| cpu.is_training_dummy);
+        if cpu_entities.is_empty() {
+            let mut dummy = CpuEntity::new_variant(settings.dummy_variant, fighter_x + 300.0, fighter_y, line_y);
+            dummy.is_training_dummy = true;
+            dummy.max_hp = settings.dummy_hp;
+            dummy.current_hp = settings.dummy_hp;
+            self.last_dummy_hp = dummy.current_hp;
+            cpu_entities.push(dummy);
+            println!("[TRAINING] Spawned {} dummy.", settings.dummy_variant.config_name());
+        }
+
+        let dummy = &mut cpu_entities[0];
+        if dummy.current_hp < self.last_dummy_hp {
+            self.damage_dealt += self.last_dummy_hp - dummy.current_hp;
+            self.idle_timer = 0.0;
+        } else {
+            self.idle_timer += dt;
+        }
+        if dummy.current_hp <= 0.0
+            || (dummy.current_hp < dummy.max_hp && self.idle_timer >= settings.dummy_reset_delay)
+        {
+            dummy.current_hp = dummy.max_hp;
+            dummy.status_effects.clear();
+            self.damage_dealt = 0.0;
+        }
+        self.last_dummy_hp = dummy.current_hp;
+
+        // A strike count of 1 means a new window was opened.
+        let strike_count = combo_system.strike_count();
+        if strike_count != self.last_strike_count {
+            if strike_count <= 1 {
+                self.strike_marks.clear();
+            }
+            if strike_count > 0 && combo_system.timer > 0.0 {
+                self.strike_marks.push(combo_system.window() - combo_system.timer);
+            }
+            self.last_strike_count = strike_count;
+        }
+    }
+
+    /// Draws the combo timeline panel at the top of the screen, in screen coordinates.
+    pub fn draw(
+        &self,
+        c: Context,
+        g: &mut G2d,
+        glyphs: &mut Glyphs,
+        screen_width: f64,
+        world: &World,
+    ) {
+        let combo_system = &world.combo_system;
+        let block_system = &world.block_system;
+        let fighter_type = world.fighter.fighter_type;
+        let chain = combo_data::get().chain(fighter_type);
+        let tier_index = combo_system.tier_index().min(chain.tiers.len() - 1);
+        let tier = &chain.tiers[tier_index];
+
+        let white = [1.0, 1.0, 1.0, 1.0];
+        let green = [0.0, 1.0, 0.2, 1.0];
+        let grey = [0.5, 0.5, 0.5, 1.0];
+        let panel_w = 720.0;
+        let panel_h = 210.0;
+        let x = (screen_width - panel_w) / 2.0;
+        let y = 20.0;
+        rectangle([0.0, 0.0, 0.0, 0.75], [x, y, panel_w, panel_h], c.transform, g);
+
+        let draw_text = |glyphs: &mut Glyphs, g: &mut G2d, text: &str, color: [f32; 4], size: u32, tx: f64, ty: f64| {
+            text::Text::new_color(color, size)
+                .draw(text, glyphs, &c.draw_state, c.transform.trans(tx, ty), g)
+                .ok();
+        };
+
+        draw_text(glyphs, g, "TRAINING", green, 20, x + 20.0, y + 30.0);
+
+        // Tier boxes: one per tier in the chain, the current one highlighted.
+        for (i, t) in chain.tiers.iter().enumerate() {
+            let bx = x + 160.0 + i as f64 * 70.0;
+            let active = i == tier_index;
+            let color = if active { green } else { grey };
+            Rectangle::new_border(color, 1.5).draw([bx, y + 10.0, 60.0, 28.0], &c.draw_state, c.transform, g);
+            draw_text(glyphs, g, &format!("{}-HIT", t.strikes), color, 14, bx + 8.0, y + 30.0);
+        }
+
+        // Timeline: strike zone vs rest zone across the tier's window.
+        let bar_x = x + 20.0;
+        let bar_y = y + 60.0;
+        let bar_w = panel_w - 40.0;
+        let bar_h = 30.0;
+        let zone_end = (tier.strike_zone / tier.window).min(1.0);
+        rectangle([0.0, 0.6, 0.15, 0.8], [bar_x, bar_y, bar_w * zone_end, bar_h], c.transform, g);
+        rectangle(
+            [0.25, 0.25, 0.35, 0.8],
+            [bar_x + bar_w * zone_end, bar_y, bar_w * (1.0 - zone_end), bar_h],
+            c.transform,
+            g,
+        );
+        draw_text(glyphs, g, "STRIKE", white, 14, bar_x + 6.0, bar_y + 20.0);
+        if zone_end < 1.0 {
+            draw_text(glyphs, g, "REST", white, 14, bar_x + bar_w * zone_end + 6.0, bar_y + 20.0);
+        }
+        draw_text(glyphs, g, &format!("{:.2}s", tier.window), grey, 14, bar_x + bar_w - 40.0, bar_y + 48.0);
+        for mark in &self.strike_marks {
+            let mx = bar_x + bar_w * (mark / tier.window).min(1.0);
+            line(white, 2.0, [mx, bar_y - 4.0, mx, bar_y + bar_h + 4.0], c.transform, g);
+        }
+        if combo_system.timer > 0.0 {
+            let elapsed = (combo_system.window() - combo_system.timer) / combo_system.window();
+            let cx = bar_x + bar_w * elapsed.clamp(0.0, 1.0);
+            line([1.0, 0.9, 0.0, 1.0], 3.0, [cx, bar_y - 8.0, cx, bar_y + bar_h + 8.0], c.transform, g);
+        }
+
+        // Strikes landed vs accepted.
+        let strikes_y = y + 125.0;
+        draw_text(glyphs, g, "STRIKES", white, 14, x + 20.0, strikes_y + 5.0);
+        for i in 0..tier.strikes {
+            let cx = x + 110.0 + i as f64 * 26.0;
+            let landed = i < combo_system.strike_count();
+            let color = if landed { green } else { grey };
+            ellipse(color, [cx, strikes_y - 10.0, 18.0, 18.0], c.transform, g);
+        }
+        let state = if combo_system.strike_wait() > 0.0 && !combo_system.is_in_rest_period {
+            "COOLDOWN"
+        } else if combo_system.is_in_rest_period {
+            "REST - WAIT FOR THE NEXT TIER"
+        } else if combo_system.timer > 0.0 {
+            "STRIKE"
+        } else {
+            "READY"
+        };
+        draw_text(glyphs, g, state, white, 14, x + 260.0, strikes_y + 5.0);
+
+        // Kinetic intake and damage since the dummy last healed.
+        let info_y = y + 170.0;
+        draw_text(
+            glyphs,
+            g,
+            &format!("KINETIC INTAKE: {} / {}", block_system.kinetic_intake_count, block_system.max_block_count),
+            white,
+            14,
+            x + 20.0,
+            info_y,
+        );
+        draw_text(glyphs, g, &format!("DAMAGE: {:.0}", self.damage_dealt), white, 14, x + 300.0, info_y);
+        draw_text(
+            glyphs,
+            g,
+            &format!("DMG INTAKE x{:.2}", combo_system.get_damage_intake_multiplier(fighter_type)),
+            grey,
+            14,
+            x + 480.0,
+            info_y,
+        );
+    }
+}
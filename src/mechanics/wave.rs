@@ -94,7 +94,7 @@ impl WaveManager {
         let total_weight: u32 = table.iter().map(|(_, w)| w).sum();
         if total_weight == 0 { return crate::entities::cpu_entity::CpuVariant::NightReaver; } // Safe fallback
         
-        let mut rng = rand::rng();
+        let mut rng = crate::utils::rng::gameplay_rng();
         let mut choice = rng.random_range(0..total_weight);
         
         for (variant, weight) in table {
@@ -107,6 +107,28 @@ impl WaveManager {
  	    table.last().map_or(crate::entities::cpu_entity::CpuVariant::NightReaver, |t| t.0)
     }	
 
+    /// Builds a wave enemy of the given variant at (x, y) with the current wave's
+    /// modifiers applied. Variants that never appear in bunker waves fall back to a Night Reaver.
+    pub fn spawn_wave_cpu(&self, variant: crate::entities::cpu_entity::CpuVariant, x: f64, y: f64, line_y: f64) -> CpuEntity {
+        use crate::entities::cpu_entity::CpuVariant;
+        let mut new_cpu = match variant {
+            CpuVariant::NightReaver => CpuEntity::new_night_reaver(x, y),
+            CpuVariant::LightReaver => CpuEntity::new_light_reaver(x, y),
+            CpuVariant::VoidTempest => {
+                // VoidTempest's constructor takes (line_y, hp, speed); override the position afterwards.
+                let mut vt = CpuEntity::new_void_tempest(line_y, 250.0, 150.0);
+                vt.x = x;
+                vt.y = y;
+                vt
+            },
+            CpuVariant::RazorFiend => CpuEntity::new_razor_fiend(x, y),
+            // Fallback for types not usually in bunker waves but safe to handle
+            _ => CpuEntity::new_night_reaver(x, y),
+        };
+        self.apply_modifiers_to_new_cpu(&mut new_cpu);
+        new_cpu
+    }
+
     pub fn start_encounter(&mut self, total_waves: u32) {
         if self.is_active() {
             return;
@@ -0,0 +1,464 @@
+// File: src/replay.rs
+
+use piston_window::{
+    Button, ButtonArgs, ButtonState, ControllerAxisArgs, ControllerAxisEvent, Event, Input, Key, Loop, Motion,
+    MouseButton, PistonWindow, PressEvent, ReleaseEvent, UpdateArgs, UpdateEvent, MouseCursorEvent, MouseScrollEvent,
+};
+use std::collections::{HashSet, VecDeque};
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+const REPLAY_HEADER: &str = "SBRX_REPLAY";
+const REPLAY_VERSION: u32 = 2;
+// Version 1 frames have no gamepad field; they still load with the pad at rest.
+const OLDEST_REPLAY_VERSION: u32 = 1;
+
+/// Input state sampled on one update tick.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputSnapshot {
+    pub dt: f64,
+    pub keys: Vec<Key>,
+    pub mouse_x: f64,
+    pub mouse_y: f64,
+    pub lmb: bool,
+    pub rmb: bool,
+    // Buttons pressed and released again between two update ticks.
+    pub taps: Vec<Button>,
+    // Raw gamepad axes (sticks and triggers), indexed like game::gamepad::axis. Analog
+    // movement, the aim reticle and jet steering read these directly rather than keys.
+    pub pad_axes: [f64; 6],
+}
+
+impl InputSnapshot {
+    fn to_line(&self) -> String {
+        let keys: Vec<String> = self.keys.iter().map(|k| format!("{}", k.code())).collect();
+        let taps: Vec<String> = self.taps.iter().filter_map(|b| button_token(*b)).collect();
+        let pad_axes = if self.pad_axes.iter().all(|a| *a == 0.0) {
+            "-".to_string()
+        } else {
+            self.pad_axes.iter().map(|a| a.to_string()).collect::<Vec<_>>().join(",")
+        };
+        format!(
+            "{} {} {} {} {} {} {} {}",
+            self.dt,
+            self.mouse_x,
+            self.mouse_y,
+            self.lmb as u8,
+            self.rmb as u8,
+            if keys.is_empty() { "-".to_string() } else { keys.join(",") },
+            if taps.is_empty() { "-".to_string() } else { taps.join(",") },
+            pad_axes,
+        )
+    }
+
+    fn from_line(line: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() != 7 && parts.len() != 8 {
+            return Err(format!("expected 7 or 8 fields, found {}", parts.len()));
+        }
+        let parse_f64 = |s: &str| s.parse::<f64>().map_err(|e| format!("'{}': {}", s, e));
+        let keys = if parts[5] == "-" {
+            Vec::new()
+        } else {
+            parts[5]
+                .split(',')
+                .map(|c| c.parse::<u32>().map(Key::from).map_err(|e| format!("key '{}': {}", c, e)))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let taps = if parts[6] == "-" {
+            Vec::new()
+        } else {
+            parts[6]
+                .split(',')
+                .map(|t| parse_button_token(t).ok_or_else(|| format!("tap '{}'", t)))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let mut pad_axes = [0.0; 6];
+        if let Some(field) = parts.get(7).filter(|f| **f != "-") {
+            let values = field.split(',').map(parse_f64).collect::<Result<Vec<_>, _>>()?;
+            if values.len() != pad_axes.len() {
+                return Err(format!("expected {} gamepad axes, found {}", pad_axes.len(), values.len()));
+            }
+            pad_axes.copy_from_slice(&values);
+        }
+        Ok(InputSnapshot {
+            dt: parse_f64(parts[0])?,
+            mouse_x: parse_f64(parts[1])?,
+            mouse_y: parse_f64(parts[2])?,
+            lmb: parts[3] == "1",
+            rmb: parts[4] == "1",
+            keys,
+            taps,
+            pad_axes,
+        })
+    }
+}
+
+fn button_token(button: Button) -> Option<String> {
+    match button {
+        Button::Keyboard(key) => Some(format!("k{}", key.code())),
+        Button::Mouse(MouseButton::Left) => Some("mL".to_string()),
+        Button::Mouse(MouseButton::Right) => Some("mR".to_string()),
+        _ => None,
+    }
+}
+
+fn parse_button_token(token: &str) -> Option<Button> {
+    match token {
+        "mL" => Some(Button::Mouse(MouseButton::Left)),
+        "mR" => Some(Button::Mouse(MouseButton::Right)),
+        _ => token
+            .strip_prefix('k')
+            .and_then(|code| code.parse::<u32>().ok())
+            .map(|code| Button::Keyboard(Key::from(code))),
+    }
+}
+
+/// Frames buffered before the recorder flushes to disk.
+const FLUSH_INTERVAL: usize = 60;
+
+/// Captures per-frame input plus the RNG seed into a replay file. Frames are streamed to
+/// disk as they are recorded and flushed every FLUSH_INTERVAL frames and on drop, so a
+/// crash or early exit still leaves a playable replay up to that point.
+pub struct ReplayRecorder {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    frame_count: usize,
+    pressed_since_last_frame: Vec<Button>,
+}
+
+impl ReplayRecorder {
+    pub fn create(path: PathBuf, seed: u64) -> Result<Self, String> {
+        let file = File::create(&path)
+            .map_err(|e| format!("Failed to create replay file {:?}: {}", path, e))?;
+        let mut writer = BufWriter::new(file);
+        writeln!(writer, "{} {}\nseed {}", REPLAY_HEADER, REPLAY_VERSION, seed)
+            .and_then(|_| writer.flush())
+            .map_err(|e| format!("Failed to write replay file {:?}: {}", path, e))?;
+        println!("[REPLAY] Recording input to {:?} (seed {})", path, seed);
+        Ok(ReplayRecorder {
+            path,
+            writer,
+            frame_count: 0,
+            pressed_since_last_frame: Vec::new(),
+        })
+    }
+
+    /// Call for every press event so that sub-frame taps are not lost.
+    pub fn note_press(&mut self, button: Button) {
+        self.pressed_since_last_frame.push(button);
+    }
+
+    /// Call once per update tick, after the event loop has applied this frame's input.
+    pub fn record_frame(
+        &mut self,
+        dt: f64,
+        pressed_keys: &HashSet<Key>,
+        (mouse_x, mouse_y): (f64, f64),
+        lmb: bool,
+        rmb: bool,
+        pad_axes: [f64; 6],
+    ) {
+        let mut keys: Vec<Key> = pressed_keys.iter().copied().collect();
+        keys.sort_by_key(|k| k.code());
+        let taps = self
+            .pressed_since_last_frame
+            .drain(..)
+            .filter(|b| match b {
+                Button::Keyboard(key) => !pressed_keys.contains(key),
+                Button::Mouse(MouseButton::Left) => !lmb,
+                Button::Mouse(MouseButton::Right) => !rmb,
+                _ => false,
+            })
+            .collect();
+        let frame = InputSnapshot {
+            dt,
+            keys,
+            mouse_x,
+            mouse_y,
+            lmb,
+            rmb,
+            taps,
+            pad_axes,
+        };
+        let written = writeln!(self.writer, "{}", frame.to_line()).and_then(|_| {
+            if (self.frame_count + 1).is_multiple_of(FLUSH_INTERVAL) {
+                self.writer.flush()
+            } else {
+                Ok(())
+            }
+        });
+        match written {
+            Ok(()) => self.frame_count += 1,
+            Err(e) => eprintln!("[REPLAY] Failed to write frame to {:?}: {}", self.path, e),
+        }
+    }
+
+    /// Flushes any buffered frames. Also runs on drop.
+    pub fn finish(&mut self) -> Result<(), String> {
+        self.writer
+            .flush()
+            .map_err(|e| format!("Failed to write replay file {:?}: {}", self.path, e))?;
+        println!("[REPLAY] Saved {} frames to {:?}", self.frame_count, self.path);
+        Ok(())
+    }
+}
+
+impl Drop for ReplayRecorder {
+    fn drop(&mut self) {
+        if let Err(e) = self.writer.flush() {
+            eprintln!("[REPLAY] Failed to flush {:?}: {}", self.path, e);
+        }
+    }
+}
+
+/// Feeds a recorded replay back into the event loop in place of live input.
+pub struct ReplayPlayer {
+    pub seed: u64,
+    frames: VecDeque<InputSnapshot>,
+    previous: InputSnapshot,
+}
+
+impl ReplayPlayer {
+    pub fn load(path: &PathBuf) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read replay file {:?}: {}", path, e))?;
+        let mut lines = contents.lines();
+
+        let header = lines.next().unwrap_or_default();
+        let version = header
+            .strip_prefix(REPLAY_HEADER)
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .ok_or_else(|| format!("{:?} is not a replay file", path))?;
+        if !(OLDEST_REPLAY_VERSION..=REPLAY_VERSION).contains(&version) {
+            return Err(format!(
+                "Replay {:?} has version {}, expected {} to {}",
+                path, version, OLDEST_REPLAY_VERSION, REPLAY_VERSION
+            ));
+        }
+        let seed = lines
+            .next()
+            .and_then(|l| l.strip_prefix("seed "))
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .ok_or_else(|| format!("Replay {:?} is missing its seed line", path))?;
+
+        let mut frames = VecDeque::new();
+        for (i, line) in lines.enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let frame = InputSnapshot::from_line(line)
+                .map_err(|e| format!("Replay {:?} frame {}: {}", path, i + 1, e))?;
+            frames.push_back(frame);
+        }
+        println!(
+            "[REPLAY] Loaded {} frames from {:?} (seed {})",
+            frames.len(),
+            path,
+            seed
+        );
+        Ok(ReplayPlayer {
+            seed,
+            frames,
+            previous: InputSnapshot::default(),
+        })
+    }
+
+    /// Builds the input events that reproduce the next recorded frame, ending with
+    /// its update tick. Returns None once the replay is exhausted.
+    pub fn next_frame_events(&mut self) -> Option<Vec<Event>> {
+        let frame = self.frames.pop_front()?;
+        let mut events = Vec::new();
+        let button_event = |button: Button, state: ButtonState| {
+            Event::Input(
+                Input::Button(ButtonArgs {
+                    state,
+                    button,
+                    scancode: None,
+                }),
+                None,
+            )
+        };
+
+        for key in &self.previous.keys {
+            if !frame.keys.contains(key) {
+                events.push(button_event(Button::Keyboard(*key), ButtonState::Release));
+            }
+        }
+        if frame.mouse_x != self.previous.mouse_x || frame.mouse_y != self.previous.mouse_y {
+            events.push(Event::Input(
+                Input::Move(Motion::MouseCursor([frame.mouse_x, frame.mouse_y])),
+                None,
+            ));
+        }
+        for (axis, (was, is)) in self.previous.pad_axes.iter().zip(frame.pad_axes.iter()).enumerate() {
+            if was != is {
+                events.push(Event::Input(
+                    Input::Move(Motion::ControllerAxis(ControllerAxisArgs::new(0, axis as u8, *is))),
+                    None,
+                ));
+            }
+        }
+        for key in &frame.keys {
+            if !self.previous.keys.contains(key) {
+                events.push(button_event(Button::Keyboard(*key), ButtonState::Press));
+            }
+        }
+        for (was, is, button) in [
+            (self.previous.lmb, frame.lmb, MouseButton::Left),
+            (self.previous.rmb, frame.rmb, MouseButton::Right),
+        ] {
+            if was != is {
+                let state = if is { ButtonState::Press } else { ButtonState::Release };
+                events.push(button_event(Button::Mouse(button), state));
+            }
+        }
+        for tap in &frame.taps {
+            events.push(button_event(*tap, ButtonState::Press));
+            events.push(button_event(*tap, ButtonState::Release));
+        }
+        events.push(Event::Loop(Loop::Update(UpdateArgs { dt: frame.dt })));
+
+        self.previous = frame;
+        Some(events)
+    }
+}
+
+/// Pulls the next event for the main loop. During playback, live keyboard/mouse/gamepad
+/// input is dropped and each window update tick is replaced by the next recorded frame;
+/// render and window events still come from the window.
+pub fn next_event(
+    window: &mut PistonWindow,
+    player: &mut Option<ReplayPlayer>,
+    pending: &mut VecDeque<Event>,
+) -> Option<Event> {
+    loop {
+        if let Some(e) = pending.pop_front() {
+            return Some(e);
+        }
+        let e = window.next()?;
+        let Some(replay) = player.as_mut() else {
+            return Some(e);
+        };
+        if e.update_args().is_some() {
+            match replay.next_frame_events() {
+                Some(events) => {
+                    pending.extend(events);
+                    continue;
+                }
+                None => {
+                    println!("[REPLAY] Playback finished. Returning control to live input.");
+                    *player = None;
+                    return Some(e);
+                }
+            }
+        }
+        let is_live_input = e.press_args().is_some()
+            || e.release_args().is_some()
+            || e.mouse_cursor_args().is_some()
+            || e.mouse_scroll_args().is_some()
+            || e.controller_axis_args().is_some();
+        if !is_live_input {
+            return Some(e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(test_name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("sbrx_test_{}_{}.replay", std::process::id(), test_name))
+    }
+
+    #[test]
+    fn snapshot_line_round_trip() {
+        let frame = InputSnapshot {
+            dt: 1.0 / 60.0,
+            keys: vec![Key::A, Key::LShift],
+            mouse_x: 640.5,
+            mouse_y: -12.0,
+            lmb: true,
+            rmb: false,
+            taps: vec![Button::Keyboard(Key::Space), Button::Mouse(MouseButton::Right)],
+            pad_axes: [0.5, -0.25, 0.0, 1.0, 0.0, 0.75],
+        };
+        assert_eq!(InputSnapshot::from_line(&frame.to_line()), Ok(frame));
+
+        let idle = InputSnapshot { dt: 0.5, ..InputSnapshot::default() };
+        assert_eq!(idle.to_line(), "0.5 0 0 0 0 - - -");
+        assert_eq!(InputSnapshot::from_line(&idle.to_line()), Ok(idle));
+    }
+
+    #[test]
+    fn version_1_frames_load_with_the_pad_at_rest() {
+        let line = format!("0.016 10 20 1 0 {} -", Key::A.code());
+        let frame = InputSnapshot::from_line(&line).expect("7-field frame should parse");
+        assert_eq!(frame.keys, vec![Key::A]);
+        assert_eq!(frame.pad_axes, [0.0; 6]);
+    }
+
+    #[test]
+    fn rejects_bad_frames() {
+        assert!(InputSnapshot::from_line("0.016 10 20 1 0").is_err());
+        assert!(InputSnapshot::from_line("fast 10 20 1 0 - - -").is_err());
+        assert!(InputSnapshot::from_line("0.016 10 20 1 0 - x9 -").is_err());
+        assert!(InputSnapshot::from_line("0.016 10 20 1 0 - - 0.5,0.5").is_err());
+    }
+
+    #[test]
+    fn recorded_file_plays_back() {
+        let path = temp_path("replay_round_trip");
+        let mut recorder = ReplayRecorder::create(path.clone(), 99).expect("replay should be created");
+        let held = HashSet::from([Key::W]);
+        recorder.note_press(Button::Keyboard(Key::W));
+        recorder.note_press(Button::Keyboard(Key::Space));
+        recorder.record_frame(0.016, &held, (100.0, 200.0), false, true, [0.0, -1.0, 0.0, 0.0, 0.0, 0.0]);
+        recorder.record_frame(0.016, &HashSet::new(), (100.0, 200.0), false, false, [0.0; 6]);
+        recorder.finish().expect("replay should flush");
+        drop(recorder);
+
+        let player = ReplayPlayer::load(&path);
+        fs::remove_file(&path).ok();
+        let mut player = player.expect("replay should load");
+        assert_eq!(player.seed, 99);
+
+        let first = player.next_frame_events().expect("first frame");
+        let pressed: Vec<Button> = first.iter().filter_map(|e| e.press_args()).collect();
+        // W is held, so only Space counts as a tap.
+        assert_eq!(
+            pressed,
+            vec![Button::Keyboard(Key::W), Button::Mouse(MouseButton::Right), Button::Keyboard(Key::Space)]
+        );
+        let axes: Vec<(u8, f64)> = first.iter().filter_map(|e| e.controller_axis_args()).map(|a| (a.axis, a.position)).collect();
+        assert_eq!(axes, vec![(1, -1.0)]);
+        assert_eq!(first.iter().filter_map(|e| e.mouse_cursor_args()).collect::<Vec<_>>(), vec![[100.0, 200.0]]);
+        assert_eq!(first.last().and_then(|e| e.update_args()).map(|a| a.dt), Some(0.016));
+
+        let second = player.next_frame_events().expect("second frame");
+        let released: Vec<Button> = second.iter().filter_map(|e| e.release_args()).collect();
+        assert_eq!(released, vec![Button::Keyboard(Key::W), Button::Mouse(MouseButton::Right)]);
+        let axes: Vec<(u8, f64)> = second.iter().filter_map(|e| e.controller_axis_args()).map(|a| (a.axis, a.position)).collect();
+        assert_eq!(axes, vec![(1, 0.0)]);
+        assert!(player.next_frame_events().is_none());
+    }
+
+    #[test]
+    fn rejects_bad_files() {
+        let cases = [
+            ("replay_not_replay", "SBRX_SAVE 1\nseed 1\n".to_string()),
+            ("replay_newer", format!("{} {}\nseed 1\n", REPLAY_HEADER, REPLAY_VERSION + 1)),
+            ("replay_no_seed", format!("{} {}\n0.016 0 0 0 0 - - -\n", REPLAY_HEADER, REPLAY_VERSION)),
+            ("replay_bad_frame", format!("{} {}\nseed 1\n0.016 0 0\n", REPLAY_HEADER, REPLAY_VERSION)),
+        ];
+        for (name, contents) in cases {
+            let path = temp_path(name);
+            fs::write(&path, contents).expect("temp replay should be writable");
+            let loaded = ReplayPlayer::load(&path);
+            fs::remove_file(&path).ok();
+            assert!(loaded.is_err(), "{}", name);
+        }
+    }
+}
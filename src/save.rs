@@ -0,0 +1,499 @@
+// File: src/save.rs
+
+use crate::combat::stats::Stats;
+use crate::entities::fighter::Fighter;
+use crate::fog_of_war::FogOfWar;
+use crate::game_state::FighterType;
+use crate::map_system::FieldId;
+use crate::task::{Task, TaskSystem};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const SAVE_HEADER: &str = "SBRX_SAVE";
+pub const SAVE_VERSION: u32 = 1;
+
+/// Slot 0 is written automatically on field transitions; 1..=MANUAL_SLOTS are player slots.
+pub const AUTOSAVE_SLOT: u32 = 0;
+pub const MANUAL_SLOTS: u32 = 3;
+
+const FIGHTER_TYPES: [FighterType; 3] = [FighterType::Racer, FighterType::Soldier, FighterType::Raptor];
+
+/// Upgrades the raw entries of a save from version `index + 1` to `index + 2`.
+/// Append one function here whenever SAVE_VERSION is bumped.
+type Migration = fn(&mut HashMap<String, String>);
+const MIGRATIONS: &[Migration] = &[];
+
+/// Progress per fighter type.
+#[derive(Debug, Clone, Copy)]
+pub struct FighterProgress {
+    pub level: u32,
+    pub kills: u32,
+    pub stat_points: u32,
+    // Permanent (level-up) stats, without temporary buffs.
+    pub base_stats: Stats,
+}
+
+/// Everything needed to resume a campaign.
+pub struct SaveData {
+    pub field: FieldId,
+    pub position: (f64, f64),
+    pub fighter_type: FighterType,
+    pub score: u32,
+    pub soldier_has_joined: bool,
+    pub raptor_has_joined: bool,
+    pub fighters: HashMap<FighterType, FighterProgress>,
+    pub tasks: Vec<Task>,
+    pub task_counters: Vec<(String, u32)>,
+    pub task_flags: Vec<(String, bool)>,
+    pub completed_bunker_floors: HashSet<i32>,
+    // Explored tiles per fog-enabled field, row-major.
+    pub fog: Vec<(FieldId, usize, usize, Vec<bool>)>,
+}
+
+impl SaveData {
+    #[allow(clippy::too_many_arguments)]
+    pub fn capture(
+        fighter: &Fighter,
+        base_fighter_stats_map: &HashMap<FighterType, Stats>,
+        task_system: &TaskSystem,
+        field: FieldId,
+        completed_bunker_waves: &HashSet<i32>,
+        fog_of_war: &FogOfWar,
+        soldier_has_joined: bool,
+        raptor_has_joined: bool,
+    ) -> Self {
+        let mut fighters = HashMap::new();
+        for ft in FIGHTER_TYPES {
+            let Some(base_stats) = base_fighter_stats_map.get(&ft).copied() else {
+                continue;
+            };
+            fighters.insert(
+                ft,
+                FighterProgress {
+                    level: fighter.levels.get(&ft).copied().unwrap_or(1),
+                    kills: fighter.kill_counters.get(&ft).copied().unwrap_or(0),
+                    stat_points: fighter.stat_points_to_spend.get(&ft).copied().unwrap_or(0),
+                    base_stats,
+                },
+            );
+        }
+
+        let mut fog: Vec<(FieldId, usize, usize, Vec<bool>)> = fog_of_war
+            .grids
+            .iter()
+            .map(|(id, grid)| {
+                let explored = grid.tiles.iter().flat_map(|row| row.iter().map(|t| t.explored)).collect();
+                (*id, grid.width, grid.height, explored)
+            })
+            .collect();
+        fog.sort_by_key(|(id, _, _, _)| (id.0, id.1));
+
+        SaveData {
+            field,
+            position: (fighter.x, fighter.y),
+            fighter_type: fighter.fighter_type,
+            score: fighter.score,
+            soldier_has_joined,
+            raptor_has_joined,
+            fighters,
+            tasks: task_system
+                .tasks()
+                .iter()
+                .map(|t| Task { description: t.description.clone(), completed: t.completed })
+                .collect(),
+            task_counters: task_system.counters().iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+            task_flags: task_system.flags().iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+            completed_bunker_floors: completed_bunker_waves.clone(),
+            fog,
+        }
+    }
+
+    /// Writes the per-fighter progress back onto the fighter and both stat maps.
+    /// Temporary buffs are dropped: current stats restart from the saved base stats.
+    pub fn apply_fighter_progress(
+        &self,
+        fighter: &mut Fighter,
+        fighter_stats_map: &mut HashMap<FighterType, Stats>,
+        base_fighter_stats_map: &mut HashMap<FighterType, Stats>,
+    ) {
+        for (ft, progress) in &self.fighters {
+            fighter.levels.insert(*ft, progress.level);
+            fighter.kill_counters.insert(*ft, progress.kills);
+            fighter.stat_points_to_spend.insert(*ft, progress.stat_points);
+            base_fighter_stats_map.insert(*ft, progress.base_stats);
+            fighter_stats_map.insert(*ft, progress.base_stats);
+        }
+        fighter.score = self.score;
+        fighter.x = self.position.0;
+        fighter.y = self.position.1;
+    }
+
+    pub fn apply_task_progress(&self, task_system: &mut TaskSystem) {
+        task_system.restore_tasks(
+            self.tasks
+                .iter()
+                .map(|t| Task { description: t.description.clone(), completed: t.completed })
+                .collect(),
+        );
+        for (name, value) in &self.task_counters {
+            task_system.set_counter(name, *value);
+        }
+        for (name, value) in &self.task_flags {
+            task_system.set_flag(name, *value);
+        }
+    }
+
+    pub fn apply_fog(&self, fog_of_war: &mut FogOfWar) {
+        for (field, width, height, explored) in &self.fog {
+            fog_of_war.restore_explored(*field, *width, *height, explored);
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let mut out = format!("{} {}\n", SAVE_HEADER, SAVE_VERSION);
+        let mut put = |key: &str, value: String| {
+            out.push_str(key);
+            out.push('=');
+            out.push_str(&value);
+            out.push('\n');
+        };
+        put("field", format!("{},{}", self.field.0, self.field.1));
+        put("position", format!("{},{}", self.position.0, self.position.1));
+        put("fighter_type", fighter_type_name(self.fighter_type).to_string());
+        put("score", self.score.to_string());
+        put("soldier_joined", (self.soldier_has_joined as u8).to_string());
+        put("raptor_joined", (self.raptor_has_joined as u8).to_string());
+        for ft in FIGHTER_TYPES {
+            let Some(p) = self.fighters.get(&ft) else { continue };
+            let name = fighter_type_name(ft);
+            put(&format!("fighter.{}.level", name), p.level.to_string());
+            put(&format!("fighter.{}.kills", name), p.kills.to_string());
+            put(&format!("fighter.{}.stat_points", name), p.stat_points.to_string());
+            let s = p.base_stats;
+            put(
+                &format!("fighter.{}.base_stats", name),
+                format!(
+                    "{},{},{},{},{},{}",
+                    s.defense.hp,
+                    s.defense.auto_block,
+                    s.defense.auto_dodge,
+                    s.attack.melee_damage,
+                    s.attack.ranged_damage,
+                    s.speed.run_speed
+                ),
+            );
+        }
+        put("task_count", self.tasks.len().to_string());
+        for (i, task) in self.tasks.iter().enumerate() {
+            put(&format!("task.{}", i), format!("{}|{}", task.completed as u8, task.description));
+        }
+        for (name, value) in &self.task_counters {
+            put(&format!("counter.{}", name), value.to_string());
+        }
+        for (name, value) in &self.task_flags {
+            put(&format!("flag.{}", name), (*value as u8).to_string());
+        }
+        let mut floors: Vec<i32> = self.completed_bunker_floors.iter().copied().collect();
+        floors.sort();
+        put(
+            "bunker_floors",
+            floors.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(","),
+        );
+        for (field, width, height, explored) in &self.fog {
+            let bits: String = explored.iter().map(|e| if *e { '1' } else { '0' }).collect();
+            put(&format!("fog.{},{}", field.0, field.1), format!("{}x{}:{}", width, height, bits));
+        }
+        out
+    }
+
+    fn from_text(contents: &str) -> Result<Self, String> {
+        let mut lines = contents.lines();
+        let header = lines.next().unwrap_or_default();
+        let version = header
+            .strip_prefix(SAVE_HEADER)
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .ok_or_else(|| "not a save file".to_string())?;
+        if version == 0 || version > SAVE_VERSION {
+            return Err(format!(
+                "save version {} is not supported (this build reads up to {})",
+                version, SAVE_VERSION
+            ));
+        }
+
+        let mut entries: HashMap<String, String> = HashMap::new();
+        for line in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("malformed line '{}'", line))?;
+            entries.insert(key.to_string(), value.to_string());
+        }
+
+        for from in version..SAVE_VERSION {
+            println!("[SAVE] Migrating save from version {} to {}", from, from + 1);
+            MIGRATIONS[from as usize - 1](&mut entries);
+        }
+
+        let get = |key: &str| entries.get(key).map(|s| s.as_str());
+        let parse_pair = |key: &str| -> Result<Option<(f64, f64)>, String> {
+            let Some(v) = get(key) else { return Ok(None) };
+            let (a, b) = v.split_once(',').ok_or_else(|| format!("{}: expected 'a,b'", key))?;
+            let a = a.trim().parse::<f64>().map_err(|e| format!("{}: {}", key, e))?;
+            let b = b.trim().parse::<f64>().map_err(|e| format!("{}: {}", key, e))?;
+            Ok(Some((a, b)))
+        };
+        let parse_u32 = |key: &str, default: u32| -> Result<u32, String> {
+            get(key)
+                .map(|v| v.trim().parse::<u32>().map_err(|e| format!("{}: {}", key, e)))
+                .unwrap_or(Ok(default))
+        };
+        let parse_bool = |key: &str| get(key).map(|v| v.trim() == "1").unwrap_or(false);
+
+        let field = parse_pair("field")?
+            .map(|(x, y)| FieldId(x as i32, y as i32))
+            .ok_or_else(|| "missing 'field'".to_string())?;
+        let position = parse_pair("position")?.ok_or_else(|| "missing 'position'".to_string())?;
+        let fighter_type = get("fighter_type")
+            .and_then(parse_fighter_type)
+            .unwrap_or(FighterType::Racer);
+
+        let mut fighters = HashMap::new();
+        for ft in FIGHTER_TYPES {
+            let name = fighter_type_name(ft);
+            let Some(stats_text) = get(&format!("fighter.{}.base_stats", name)) else {
+                continue;
+            };
+            let values = stats_text
+                .split(',')
+                .map(|v| v.trim().parse::<f64>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("fighter.{}.base_stats: {}", name, e))?;
+            if values.len() != 6 {
+                return Err(format!("fighter.{}.base_stats: expected 6 values", name));
+            }
+            let mut base_stats = default_stats(ft);
+            base_stats.defense.hp = values[0];
+            base_stats.defense.auto_block = values[1];
+            base_stats.defense.auto_dodge = values[2];
+            base_stats.attack.melee_damage = values[3];
+            base_stats.attack.ranged_damage = values[4];
+            base_stats.speed.run_speed = values[5];
+            fighters.insert(
+                ft,
+                FighterProgress {
+                    level: parse_u32(&format!("fighter.{}.level", name), 1)?,
+                    kills: parse_u32(&format!("fighter.{}.kills", name), 0)?,
+                    stat_points: parse_u32(&format!("fighter.{}.stat_points", name), 0)?,
+                    base_stats,
+                },
+            );
+        }
+
+        let mut tasks = Vec::new();
+        for i in 0..parse_u32("task_count", 0)? {
+            let key = format!("task.{}", i);
+            let value = get(&key).ok_or_else(|| format!("missing '{}'", key))?;
+            let (done, description) = value
+                .split_once('|')
+                .ok_or_else(|| format!("{}: expected 'done|description'", key))?;
+            tasks.push(Task { description: description.to_string(), completed: done == "1" });
+        }
+
+        let mut task_counters = Vec::new();
+        let mut task_flags = Vec::new();
+        let mut fog = Vec::new();
+        let mut keys: Vec<&String> = entries.keys().collect();
+        keys.sort();
+        for key in keys {
+            if let Some(name) = key.strip_prefix("counter.") {
+                task_counters.push((name.to_string(), parse_u32(key, 0)?));
+            } else if let Some(name) = key.strip_prefix("flag.") {
+                task_flags.push((name.to_string(), parse_bool(key)));
+            } else if let Some(field_text) = key.strip_prefix("fog.") {
+                fog.push(parse_fog_entry(field_text, &entries[key]).map_err(|e| format!("{}: {}", key, e))?);
+            }
+        }
+
+        let completed_bunker_floors = get("bunker_floors")
+            .unwrap_or_default()
+            .split(',')
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| s.trim().parse::<i32>().map_err(|e| format!("bunker_floors: {}", e)))
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        Ok(SaveData {
+            field,
+            position,
+            fighter_type,
+            score: parse_u32("score", 0)?,
+            soldier_has_joined: parse_bool("soldier_joined"),
+            raptor_has_joined: parse_bool("raptor_joined"),
+            fighters,
+            tasks,
+            task_counters,
+            task_flags,
+            completed_bunker_floors,
+            fog,
+        })
+    }
+}
+
+fn parse_fog_entry(field_text: &str, value: &str) -> Result<(FieldId, usize, usize, Vec<bool>), String> {
+    let (fx, fy) = field_text.split_once(',').ok_or("expected field 'x,y'")?;
+    let field = FieldId(
+        fx.parse::<i32>().map_err(|e| e.to_string())?,
+        fy.parse::<i32>().map_err(|e| e.to_string())?,
+    );
+    let (size, bits) = value.split_once(':').ok_or("expected 'WxH:bits'")?;
+    let (w, h) = size.split_once('x').ok_or("expected 'WxH'")?;
+    let width = w.parse::<usize>().map_err(|e| e.to_string())?;
+    let height = h.parse::<usize>().map_err(|e| e.to_string())?;
+    if bits.len() != width * height {
+        return Err(format!("expected {} tiles, found {}", width * height, bits.len()));
+    }
+    Ok((field, width, height, bits.chars().map(|c| c == '1').collect()))
+}
+
+fn fighter_type_name(ft: FighterType) -> &'static str {
+    match ft {
+        FighterType::Racer => "racer",
+        FighterType::Soldier => "soldier",
+        FighterType::Raptor => "raptor",
+    }
+}
+
+fn parse_fighter_type(name: &str) -> Option<FighterType> {
+    FIGHTER_TYPES.into_iter().find(|ft| fighter_type_name(*ft) == name.trim())
+}
+
+fn default_stats(ft: FighterType) -> Stats {
+    match ft {
+        FighterType::Racer => crate::combat::stats::RACER_LVL1_STATS,
+        FighterType::Soldier => crate::combat::stats::SOLDIER_LVL1_STATS,
+        FighterType::Raptor => crate::combat::stats::RAPTOR_LVL1_STATS,
+    }
+}
+
+/// Save files live in a `saves` folder next to the executable.
+pub fn saves_dir(exe_dir: &Path) -> PathBuf {
+    exe_dir.join("saves")
+}
+
+pub fn slot_path(saves_dir: &Path, slot: u32) -> PathBuf {
+    if slot == AUTOSAVE_SLOT {
+        saves_dir.join("autosave.sav")
+    } else {
+        saves_dir.join(format!("slot{}.sav", slot))
+    }
+}
+
+pub fn write_slot(saves_dir: &Path, slot: u32, data: &SaveData) -> Result<(), String> {
+    fs::create_dir_all(saves_dir)
+        .map_err(|e| format!("Failed to create save folder {:?}: {}", saves_dir, e))?;
+    let path = slot_path(saves_dir, slot);
+    fs::write(&path, data.to_text()).map_err(|e| format!("Failed to write save {:?}: {}", path, e))?;
+    println!("[SAVE] Saved slot {} to {:?}", slot, path);
+    Ok(())
+}
+
+pub fn read_slot(saves_dir: &Path, slot: u32) -> Result<SaveData, String> {
+    let path = slot_path(saves_dir, slot);
+    let contents =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read save {:?}: {}", path, e))?;
+    let data = SaveData::from_text(&contents).map_err(|e| format!("Save {:?}: {}", path, e))?;
+    println!("[SAVE] Loaded slot {} from {:?}", slot, path);
+    Ok(data)
+}
+
+/// The most recently written slot (autosave included), if any save exists.
+pub fn latest_slot(saves_dir: &Path) -> Option<u32> {
+    (AUTOSAVE_SLOT..=MANUAL_SLOTS)
+        .filter_map(|slot| {
+            let modified = fs::metadata(slot_path(saves_dir, slot)).ok()?.modified().ok()?;
+            Some((modified, slot))
+        })
+        .max()
+        .map(|(_, slot)| slot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> SaveData {
+        let mut soldier_stats = default_stats(FighterType::Soldier);
+        soldier_stats.attack.melee_damage = 42.5;
+        SaveData {
+            field: FieldId(-1, 2),
+            position: (1234.5, 987.25),
+            fighter_type: FighterType::Soldier,
+            score: 77,
+            soldier_has_joined: true,
+            raptor_has_joined: false,
+            fighters: HashMap::from([
+                (FighterType::Racer, FighterProgress { level: 3, kills: 9, stat_points: 1, base_stats: default_stats(FighterType::Racer) }),
+                (FighterType::Soldier, FighterProgress { level: 5, kills: 20, stat_points: 0, base_stats: soldier_stats }),
+            ]),
+            tasks: vec![
+                Task { description: "REACH THE RACETRACK".to_string(), completed: true },
+                Task { description: "DEFEAT 10 CPUS | FAST".to_string(), completed: false },
+            ],
+            task_counters: vec![("kills".to_string(), 4)],
+            task_flags: vec![("met_soldier".to_string(), true)],
+            completed_bunker_floors: HashSet::from([1, 3]),
+            fog: vec![(FieldId(0, 1), 3, 2, vec![true, false, false, true, true, false])],
+        }
+    }
+
+    #[test]
+    fn slot_round_trip() {
+        let dir = std::env::temp_dir().join(format!("sbrx_test_{}_save_round_trip", std::process::id()));
+        let saved = sample();
+        write_slot(&dir, 2, &saved).expect("save should be written");
+        let loaded = read_slot(&dir, 2);
+        let latest = latest_slot(&dir);
+        fs::remove_dir_all(&dir).ok();
+        let loaded = loaded.expect("save should be read back");
+
+        assert_eq!(latest, Some(2));
+        assert_eq!(loaded.field, saved.field);
+        assert_eq!(loaded.position, saved.position);
+        assert_eq!(loaded.fighter_type, FighterType::Soldier);
+        assert_eq!(loaded.score, 77);
+        assert!(loaded.soldier_has_joined && !loaded.raptor_has_joined);
+        assert_eq!(loaded.fighters.len(), 2);
+        let soldier = loaded.fighters[&FighterType::Soldier];
+        assert_eq!((soldier.level, soldier.kills, soldier.stat_points), (5, 20, 0));
+        assert_eq!(soldier.base_stats.attack.melee_damage, 42.5);
+        let tasks: Vec<(&str, bool)> = loaded.tasks.iter().map(|t| (t.description.as_str(), t.completed)).collect();
+        assert_eq!(tasks, vec![("REACH THE RACETRACK", true), ("DEFEAT 10 CPUS | FAST", false)]);
+        assert_eq!(loaded.task_counters, saved.task_counters);
+        assert_eq!(loaded.task_flags, saved.task_flags);
+        assert_eq!(loaded.completed_bunker_floors, saved.completed_bunker_floors);
+        assert_eq!(loaded.fog, saved.fog);
+    }
+
+    #[test]
+    fn reports_broken_saves() {
+        let text = sample().to_text();
+        assert!(SaveData::from_text("SBRX_REPLAY 1\nseed 1\n").is_err());
+        assert!(SaveData::from_text(&text.replace("field=-1,2\n", "")).is_err());
+        assert!(SaveData::from_text(&format!("{}not a key value pair\n", text)).is_err());
+        assert!(SaveData::from_text(&text.replace("3x2:", "2x2:")).is_err());
+        assert!(SaveData::from_text(&text.replace("score=77", "score=many")).is_err());
+        assert!(read_slot(&std::env::temp_dir().join("sbrx_test_no_saves_here"), 1).is_err());
+    }
+
+    #[test]
+    fn checks_the_save_version() {
+        let text = |version: u32| format!("SBRX_SAVE {}\nfield=0,0\nposition=0,0\n", version);
+        assert_eq!(MIGRATIONS.len() as u32, SAVE_VERSION - 1);
+        assert!(SaveData::from_text(&text(SAVE_VERSION)).is_ok());
+        assert!(SaveData::from_text(&text(0)).is_err());
+        let newer = SaveData::from_text(&text(SAVE_VERSION + 1)).err().expect("newer saves are rejected");
+        assert!(newer.contains("not supported"), "{}", newer);
+    }
+}
@@ -0,0 +1,1169 @@
+// File: src/simulation.rs
+
+use std::collections::HashMap;
+
+use crate::area::area::{AreaState, AreaType, AREA_HEIGHT, AREA_ORIGIN_X, AREA_ORIGIN_Y, AREA_WIDTH};
+use crate::area::area::{BUNKER_HEIGHT, BUNKER_ORIGIN_X, BUNKER_ORIGIN_Y, BUNKER_WIDTH};
+use crate::audio::AudioManager;
+use crate::combat::block::BlockSystem;
+use crate::combat::combo::{ComboSystem, StrikeResult};
+use crate::combat::damage::{self, DamageType, TextStyle};
+use crate::combat::hitbox::{self, Hurtbox};
+use crate::combat::melee::HitTargets;
+use crate::combat::resources::ResourceAction;
+use crate::combat::skill_data::{SkillShape, SkillVisual};
+use crate::combat::skill_runner::{self, SkillCast, SkillEffect};
+use crate::combat::skills::FLICKER_STRIKE_RADIUS;
+use crate::combat::status_effects::StatusEffect;
+use crate::combat::threat::{self, ThreatTarget};
+use crate::config::boundaries::{MAX_X, MAX_Y, MIN_X, MIN_Y};
+use crate::config::resolution::HORIZON_LINE;
+use crate::config;
+use crate::entities::collision_barriers::CollisionBarrierManager;
+use crate::entities::cpu_entity::{CpuEntity, CpuVariant};
+use crate::entities::cpu_racer::CpuRacer;
+use crate::entities::fighter::Fighter;
+use crate::entities::fixed_crater::FixedCrater;
+use crate::entities::pulse_orb::PulseOrb;
+use crate::entities::shoot::Shoot;
+use crate::entities::strike::Strike;
+use crate::entities::turret::Turret;
+use crate::game_event::{GameEvent, GameEventListener, GameEvents};
+use crate::game_state::{DeathType, EntityState, FighterType, RacerState};
+use crate::map_system::{FieldId, MapSystem};
+use crate::mechanics::wave::{WaveManager, WaveState};
+use crate::task::TaskSystem;
+use crate::utils::math::safe_gen_range;
+use crate::utils::spatial_hash::SpatialHash;
+use crate::{DamageText, Survivor};
+
+/// One frame of player intent, decoupled from piston events.
+/// Aim coordinates are in world space.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InputFrame {
+    pub move_up: bool,
+    pub move_down: bool,
+    pub move_left: bool,
+    pub move_right: bool,
+    pub aim_x: f64,
+    pub aim_y: f64,
+    pub strike: bool,
+    pub shoot: bool,
+    pub block: bool,
+}
+
+/// Mode switches that change how a tick plays out.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WorldRules {
+    pub cpus_frozen: bool,    // racetrack race: CPUs neither think nor attack
+    pub endless_arena: bool,  // defeated field monsters are removed instead of respawned
+}
+
+/// Something that happened during `World::update` that the windowed game has to present.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WorldEvent {
+    WaveFrenzy,
+    WavesCleared { floor: Option<i32> },
+    Avoided(damage::Outcome), // a melee hit on the fighter was dodged or blocked
+    Dismounted { announce: bool },
+    Flicker { x: f64, y: f64 }, // a CPU Flicker Strike left from here
+    CpuKilled { variant: CpuVariant, x: f64, y: f64 },
+    LeveledUp,
+    FighterDowned(DeathType),
+}
+
+/// A melee strike the combo system took, and where it landed.
+pub struct MeleeSwing {
+    pub result: StrikeResult,
+    pub x: f64,
+    pub y: f64,
+    pub special: bool, // a combo finisher or a strike inside a combo
+}
+
+/// Gameplay state shared by the windowed game and the headless runner. Both go through
+/// the same fighter actions (`melee_strike`, `fire_shot`, `walk`, `update_attacks`) and
+/// the same `update` tick; the windowed game maps its events onto the actions, `step`
+/// maps an `InputFrame` for scenarios and balance sweeps (see `run_headless`).
+pub struct World {
+    pub fighter: Fighter,
+    pub cpu_entities: Vec<CpuEntity>,
+    pub cpu_racers: Vec<CpuRacer>,
+    pub cpu_grid: SpatialHash,
+    pub cpu_grid_hits: Vec<usize>,
+    pub threat_targets: Vec<threat::Candidate>,
+    pub wave_manager: WaveManager,
+    pub task_system: TaskSystem,
+    pub map_system: MapSystem,
+    pub combo_system: ComboSystem,
+    pub block_system: BlockSystem,
+    pub collision_barriers: CollisionBarrierManager,
+    pub fixed_crater: FixedCrater,
+    pub strike: Strike,
+    pub shoot: Shoot,
+    pub pulse_orbs: Vec<PulseOrb>,
+    pub turrets: Vec<Turret>,
+    pub skill_effects: Vec<SkillEffect>,
+    pub damage_texts: Vec<DamageText>,
+    pub current_area: Option<AreaState>,
+    pub survivors: HashMap<FieldId, Vec<Survivor>>,
+    pub audio: AudioManager,
+    pub game_events: GameEvents,
+    pub game_time: f64,
+    pub line_y: f64,
+    pub melee_rapid_fire_timer: f64,
+    pub kills: u32,
+    pub death: Option<DeathType>,
+    events: Vec<WorldEvent>,
+    fighter_down: bool, // the fighter went down this tick
+}
+
+impl World {
+    /// Builds the same opening state as a new game on the racetrack field.
+    pub fn new(audio: AudioManager) -> Self {
+        let line_y = HORIZON_LINE;
+        let spawn = crate::RACETRACK_SPAWN_POINT;
+        let fighter = Fighter::new(spawn.0, spawn.1);
+        let fixed_crater = FixedCrater {
+            x: fighter.x,
+            y: fighter.y,
+            radius: 125.0,
+        };
+        let strike = Strike::new(fixed_crater.radius, 10.0);
+
+        let mut world = World {
+            fighter,
+            cpu_entities: Vec::new(),
+            cpu_racers: vec![CpuRacer::new(spawn.0, spawn.1 - 150.0)],
+            cpu_grid: SpatialHash::for_world(),
+            cpu_grid_hits: Vec::new(),
+            threat_targets: Vec::new(),
+            wave_manager: WaveManager::new(),
+            task_system: TaskSystem::new(),
+            map_system: MapSystem::new("FLATLINE".to_string(), FieldId(0, 0)),
+            combo_system: ComboSystem::new(),
+            block_system: BlockSystem::new(20),
+            collision_barriers: CollisionBarrierManager::new(),
+            fixed_crater,
+            strike,
+            shoot: Shoot::new(10.0),
+            pulse_orbs: Vec::new(),
+            turrets: Vec::new(),
+            skill_effects: Vec::new(),
+            damage_texts: Vec::new(),
+            current_area: None,
+            survivors: HashMap::new(),
+            audio,
+            game_events: GameEvents::new(),
+            game_time: 0.0,
+            line_y,
+            melee_rapid_fire_timer: 0.0,
+            kills: 0,
+            death: None,
+            events: Vec::new(),
+            fighter_down: false,
+        };
+        if config::get().cpu_enabled {
+            let mantis = CpuEntity::new_giant_mantis(line_y);
+            world.add_cpu(mantis);
+        }
+        world
+    }
+
+    /// Adds a CPU with the current field's aptitude scaling applied.
+    pub fn add_cpu(&mut self, mut cpu: CpuEntity) {
+        Self::apply_aptitude(&mut cpu, self.map_system.current_field_id);
+        self.cpu_entities.push(cpu);
+    }
+
+    fn apply_aptitude(cpu: &mut CpuEntity, field: FieldId) {
+        let aptitude = crate::get_field_aptitude(field);
+        cpu.max_hp *= aptitude;
+        cpu.current_hp = cpu.max_hp;
+        cpu.damage_value *= aptitude;
+        // Speed scales more slowly to keep gameplay manageable (20% of aptitude growth)
+        cpu.speed *= 1.0 + (aptitude - 1.0) * 0.2;
+    }
+
+    /// Moves the fighter into a bunker floor and starts a wave encounter there.
+    pub fn enter_bunker(&mut self, floor: i32, total_waves: u32) {
+        let (area, (start_x, start_y)) = AreaState::new(AreaType::Bunker, floor, true, false);
+        self.current_area = Some(area);
+        self.cpu_entities.clear();
+        self.pulse_orbs.clear();
+        self.turrets.clear();
+        self.fighter.x = start_x;
+        self.fighter.y = start_y;
+        self.wave_manager.reset();
+        self.wave_manager.start_encounter(total_waves);
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.death.is_some()
+    }
+
+    /// Takes what happened since the last call, in order.
+    pub fn drain_events(&mut self) -> Vec<WorldEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Adds kill score (scaled by field aptitude) to the active fighter. Returns true if
+    /// that gained a level.
+    pub fn award_kill_score(&mut self, points: u32, aptitude: f64) -> bool {
+        let scaled_points = (points as f64 * aptitude).round() as u32;
+        println!("Awarded {} kill score points for {}.", scaled_points, aptitude);
+        self.add_kill_score(scaled_points)
+    }
+
+    fn add_kill_score(&mut self, points: u32) -> bool {
+        if self.fighter.add_kill_score(points) == 0 {
+            return false;
+        }
+        let stat_points = self
+            .fighter
+            .stat_points_to_spend
+            .get(&self.fighter.fighter_type)
+            .copied()
+            .unwrap_or(0);
+        self.game_events.publish(GameEvent::LevelUp {
+            fighter_type: self.fighter.fighter_type,
+            stat_points,
+        });
+        true
+    }
+
+    /// (min_x, max_x, min_y, max_y) of the current area, or of the whole field outside one.
+    pub fn area_bounds(&self) -> (f64, f64, f64, f64) {
+        if let Some(ref area_state) = self.current_area {
+            let (width, height, origin_x, origin_y) = match area_state.area_type {
+                AreaType::RaptorNest => (AREA_WIDTH, AREA_HEIGHT, AREA_ORIGIN_X, AREA_ORIGIN_Y),
+                AreaType::Bunker => (BUNKER_WIDTH, BUNKER_HEIGHT, BUNKER_ORIGIN_X, BUNKER_ORIGIN_Y),
+            };
+            (origin_x, origin_x + width, origin_y, origin_y + height)
+        } else {
+            (MIN_X, MAX_X, MIN_Y, MAX_Y)
+        }
+    }
+
+    /// Where the fighter may go: the area bounds, kept below the horizon on the bike.
+    pub fn movement_bounds(&self) -> (f64, f64, f64, f64) {
+        let (min_x, max_x, min_y, max_y) = self.area_bounds();
+        if self.current_area.is_none() && self.fighter.state == RacerState::OnBike {
+            (min_x, max_x, self.line_y, max_y)
+        } else {
+            (min_x, max_x, min_y, max_y)
+        }
+    }
+
+    /// Advances the simulation by `dt` seconds using the given input.
+    pub fn step(&mut self, dt: f64, input: &InputFrame) {
+        if self.death.is_some() {
+            return;
+        }
+        self.game_time += dt;
+
+        self.fighter.update(dt, self.line_y);
+        self.task_system.update_timer(dt);
+
+        // --- Block (RMB) ---
+        if input.block {
+            if !self.block_system.active
+                && !self.block_system.is_stun_locked()
+                && self.fighter.stun_timer <= 0.0
+            {
+                self.block_system.activate(&self.audio);
+            }
+        } else if self.block_system.active || self.block_system.rmb_held {
+            self.block_system.deactivate();
+        }
+
+        // --- Combo timers ---
+        self.combo_system.update(dt, self.fighter.fighter_type);
+
+        // --- Melee / ranged (LMB) ---
+        self.melee_rapid_fire_timer -= dt;
+        if input.strike
+            && !self.block_system.active
+            && !self.fighter.is_reloading()
+            && self.melee_rapid_fire_timer <= 0.0
+        {
+            self.melee_strike(input.aim_x, input.aim_y, false);
+            self.melee_rapid_fire_timer = crate::MELEE_RAPID_FIRE_RATE;
+        } else if input.shoot
+            && !input.strike
+            && !self.block_system.active
+            && self.fighter.fighter_type != FighterType::Raptor
+            && !self.fighter.is_reloading()
+            && self.shoot.cooldown <= 0.0
+        {
+            if self.fire_shot(input.aim_x, input.aim_y, self.fighter.get_shoot_radius()) {
+                self.shoot.cooldown = crate::RACER_RANGED_COOLDOWN;
+            } else {
+                self.fighter.trigger_reload(&self.audio);
+            }
+        }
+        self.update_attacks(dt, false);
+
+        // --- On-foot movement ---
+        if self.fighter.state == RacerState::OnFoot
+            && !self.block_system.active
+            && self.fighter.knockback_duration <= 0.0
+            && !self.block_system.is_stun_locked()
+        {
+            let dx = input.move_right as i32 - input.move_left as i32;
+            let dy = input.move_down as i32 - input.move_up as i32;
+            if dx != 0 || dy != 0 {
+                self.walk(dx as f64, dy as f64, input.aim_x, 1.0, dt);
+            }
+        }
+        self.fixed_crater.x = self.fighter.x;
+        self.fixed_crater.y = self.fighter.y;
+        self.age_damage_texts(dt);
+
+        self.update(dt, &WorldRules::default());
+        for event in self.drain_events() {
+            match event {
+                WorldEvent::CpuKilled { .. } => self.kills += 1,
+                WorldEvent::FighterDowned(death_type) => self.death = Some(death_type),
+                _ => {}
+            }
+        }
+        for event in self.game_events.drain() {
+            self.task_system.on_game_event(&event);
+        }
+        self.task_system.update();
+    }
+
+    /// Swings at `aim` (world space), clamped to the strike zone, if the combo system takes
+    /// the strike. Hits land on CPUs unless `paused`.
+    pub fn melee_strike(&mut self, aim_x: f64, aim_y: f64, paused: bool) -> Option<MeleeSwing> {
+        let (x, y) = crate::combat::melee::clamp_strike_point(
+            &self.fighter,
+            self.fixed_crater.x,
+            self.fixed_crater.y,
+            self.fixed_crater.radius,
+            aim_x,
+            aim_y,
+        );
+        let result = self.combo_system.handle_strike_for_fighter(self.fighter.fighter_type)?;
+        self.audio
+            .play_sound_effect(if result.is_combo_finisher { "slash_combo" } else { "melee" })
+            .ok();
+        self.strike.trigger(x, y);
+        let special = result.is_combo_finisher || self.combo_system.is_combo_strike_active();
+        if config::get().cpu_enabled && !paused {
+            self.rebuild_cpu_grid();
+            crate::combat::melee::apply_melee_hits(
+                &mut self.fighter,
+                &result,
+                x,
+                y,
+                self.fixed_crater.radius,
+                &mut self.combo_system,
+                HitTargets {
+                    cpu_entities: &mut self.cpu_entities,
+                    cpu_grid: &self.cpu_grid,
+                    cpu_racers: &mut self.cpu_racers,
+                    damage_texts: &mut self.damage_texts,
+                    current_field: self.map_system.current_field_id,
+                    audio_manager: &self.audio,
+                    current_time: self.game_time,
+                },
+            );
+        }
+        Some(MeleeSwing { result, x, y, special })
+    }
+
+    /// Pays the shoot cost and fires toward `aim`, cut short at `max_range`. Returns false,
+    /// without firing, if the fighter can't pay.
+    pub fn fire_shot(&mut self, aim_x: f64, aim_y: f64, max_range: f64) -> bool {
+        if !self.fighter.resources.spend(ResourceAction::Shoot, 1.0) {
+            return false;
+        }
+        let sound = if self.fighter.fighter_type == FighterType::Soldier { "firearm" } else { "ranged" };
+        self.audio.play_sound_effect(sound).ok();
+        let dx = aim_x - self.fighter.x;
+        let dy = aim_y - self.fighter.y;
+        let dist = (dx * dx + dy * dy).sqrt();
+        let (target_x, target_y) = if dist > max_range {
+            (self.fighter.x + dx / dist * max_range, self.fighter.y + dy / dist * max_range)
+        } else {
+            (aim_x, aim_y)
+        };
+        self.shoot.trigger(self.fighter.x, self.fighter.y, target_x, target_y);
+        true
+    }
+
+    /// Walks the fighter along `(move_x, move_y)`: a key direction, or a stick tilt that
+    /// moves slower when only partly tilted. Backing away from `aim_x` halves the speed,
+    /// ATOMIC-STATE doubles it and `speed_mult` stacks on top. Returns whether the fighter
+    /// walked forward.
+    pub fn walk(&mut self, move_x: f64, move_y: f64, aim_x: f64, speed_mult: f64, dt: f64) -> bool {
+        let forward = if move_x < 0.0 {
+            aim_x < self.fighter.x
+        } else if move_x > 0.0 {
+            aim_x > self.fighter.x
+        } else {
+            true
+        };
+        let mag = (move_x * move_x + move_y * move_y).sqrt();
+        let (move_x, move_y) = if mag > 1.0 { (move_x / mag, move_y / mag) } else { (move_x, move_y) };
+        let backpedal_mult = if forward { 1.0 } else { 0.5 };
+        let atomic_mult = if self.fighter.invincible_timer > 1.0 { 2.0 } else { 1.0 };
+        let status_mult = self.fighter.status_effects.move_speed_multiplier();
+        let speed = self.fighter.run_speed * backpedal_mult * atomic_mult * status_mult * speed_mult;
+        let (min_x, max_x, min_y, max_y) = self.movement_bounds();
+        self.fighter.x = (self.fighter.x + move_x * speed * dt).clamp(min_x, max_x);
+        self.fighter.y = (self.fighter.y + move_y * speed * dt).clamp(min_y, max_y);
+        forward
+    }
+
+    /// Advances the block, the strike and shots in flight.
+    pub fn update_attacks(&mut self, dt: f64, paused: bool) {
+        self.block_system.follow_profile(self.fighter.fighter_type);
+        self.block_system.update(dt, self.game_time);
+        self.fighter.resources.sync_block(&self.block_system);
+        self.strike.update(dt);
+        self.rebuild_cpu_grid();
+        self.shoot.update(
+            dt,
+            HitTargets {
+                cpu_entities: &mut self.cpu_entities,
+                cpu_grid: &self.cpu_grid,
+                cpu_racers: &mut self.cpu_racers,
+                damage_texts: &mut self.damage_texts,
+                current_field: self.map_system.current_field_id,
+                audio_manager: &self.audio,
+                current_time: self.game_time,
+            },
+            &mut self.fighter,
+            paused,
+        );
+    }
+
+    /// One tick of everything that happens to the fighter rather than by them: waves,
+    /// CPU racers, CPU AI and skills, projectiles, contact damage and defeated CPUs.
+    pub fn update(&mut self, dt: f64, rules: &WorldRules) {
+        self.fighter_down = false;
+        self.update_waves(dt);
+        self.update_cpu_racers(dt);
+        self.update_cpus(dt, rules);
+        self.update_projectiles(dt);
+        if config::get().cpu_enabled && !rules.cpus_frozen {
+            self.resolve_cpu_contacts();
+            self.process_dead_cpus(rules);
+        }
+    }
+
+    pub fn age_damage_texts(&mut self, dt: f64) {
+        for text in &mut self.damage_texts {
+            text.lifetime -= dt;
+            match text.style {
+                TextStyle::Dodged => text.x += 60.0 * dt,
+                TextStyle::Blocked => {}
+                _ => text.y -= 30.0 * dt,
+            }
+        }
+        self.damage_texts.retain(|t| t.lifetime > 0.0);
+    }
+
+    fn update_waves(&mut self, dt: f64) {
+        // End wave progress if not in a bunker.
+        let in_bunker = matches!(&self.current_area, Some(area) if area.area_type == AreaType::Bunker);
+        if !in_bunker && self.wave_manager.is_active() {
+            self.wave_manager.reset();
+        }
+        if !self.wave_manager.is_active() {
+            return;
+        }
+        if self.wave_manager.update(dt) && self.cpu_entities.len() < 10 {
+            let current_floor = self.current_area.as_ref().map_or(1, |area| area.floor);
+            let spawn_table = WaveManager::get_spawn_table_for_floor(current_floor);
+            let variant = WaveManager::pick_random_variant(&spawn_table);
+            let x = safe_gen_range(BUNKER_ORIGIN_X, BUNKER_ORIGIN_X + BUNKER_WIDTH, "wave spawn x");
+            let y = safe_gen_range(BUNKER_ORIGIN_Y, BUNKER_ORIGIN_Y + BUNKER_HEIGHT, "wave spawn y");
+            let new_cpu = self.wave_manager.spawn_wave_cpu(variant, x, y, self.line_y);
+            self.add_cpu(new_cpu);
+            self.wave_manager.notify_enemy_spawned();
+        }
+        if self.wave_manager.state == WaveState::Frenzy && !self.wave_manager.enrage_buff_applied {
+            for cpu in self.cpu_entities.iter_mut() {
+                cpu.damage_value *= 2.0;
+                cpu.speed *= 2.0;
+            }
+            self.wave_manager.enrage_buff_applied = true;
+            self.events.push(WorldEvent::WaveFrenzy);
+        }
+        // The encounter just ended this tick
+        if !self.wave_manager.is_active() {
+            let floor = self.current_area.as_mut().map(|area| {
+                area.waves_active = false;
+                area.floor
+            });
+            self.events.push(WorldEvent::WavesCleared { floor });
+        }
+    }
+
+    fn update_cpu_racers(&mut self, dt: f64) {
+        let current_field = self.map_system.current_field_id;
+        let is_on_rt = current_field == FieldId(0, 0);
+        for cr in &mut self.cpu_racers {
+            let in_rut_zone = self.collision_barriers.check_rut(&current_field, cr.x, cr.y);
+            let rut_mult = if in_rut_zone { 0.85 } else { 1.0 };
+            cr.update(dt, rut_mult, self.fighter.x, self.fighter.y, &self.audio, is_on_rt);
+        }
+        // Player collision, damage and attack triggers stay locked to the racetrack field
+        if !is_on_rt {
+            return;
+        }
+
+        let collision_threshold = config::get().gameplay.collision_threshold;
+        for index in 0..self.cpu_racers.len() {
+            let cr = &mut self.cpu_racers[index];
+            let dx = self.fighter.x - cr.x;
+            let dy = self.fighter.y - cr.y;
+            let dist = (dx * dx + dy * dy).sqrt();
+            let can_attack = cr.stun_timer <= 0.0 && !cr.is_crashed && !cr.rush_active && cr.entity_state == EntityState::Hostile;
+
+            // RUSH attack trigger
+            if dist < 400.0 && cr.rush_cooldown <= 0.0 && can_attack {
+                cr.rush_active = true;
+                cr.rush_timer = 0.25;
+                cr.rush_cooldown = 3.0;
+                cr.rush_has_hit = false;
+                if dist > 0.0 {
+                    cr.rush_dir_x = dx / dist;
+                    cr.rush_dir_y = dy / dist;
+                    cr.facing_left = cr.rush_dir_x < 0.0;
+                } else {
+                    cr.rush_dir_x = 1.0;
+                    cr.rush_dir_y = 0.0;
+                }
+                self.audio.play_sound_effect("rush").ok();
+            }
+            if cr.rush_active && !cr.rush_has_hit && dist < collision_threshold {
+                cr.rush_has_hit = true;
+                let amount = cr.stats.attack.melee_damage;
+                self.racer_strikes_fighter(index, amount, DamageType::Slash);
+            }
+
+            // Ranged attack trigger
+            let cr = &mut self.cpu_racers[index];
+            let can_attack = cr.stun_timer <= 0.0 && !cr.is_crashed && !cr.rush_active && cr.entity_state == EntityState::Hostile;
+            if (100.0..800.0).contains(&dist) && cr.ranged_cooldown <= 0.0 && can_attack {
+                cr.ranged_cooldown = 1.5;
+                cr.ranged_shoot_visible = true;
+                cr.ranged_shoot_timer = 0.1;
+                cr.ranged_shoot_start_x = cr.x;
+                cr.ranged_shoot_start_y = cr.y;
+                cr.ranged_shoot_target_x = self.fighter.x;
+                cr.ranged_shoot_target_y = self.fighter.y;
+                cr.ranged_animation_timer = 0.25;
+                self.audio.play_sound_effect("ranged").ok();
+                let amount = cr.stats.attack.ranged_damage;
+                self.racer_strikes_fighter(index, amount, DamageType::Ballistic);
+            }
+
+            let cr = &mut self.cpu_racers[index];
+            if !self.fighter_down && cr.check_collision(&Hurtbox::for_fighter(&self.fighter), self.block_system.active, &self.audio) {
+                self.block_system.process_racer_attack(&mut self.fighter, cr, &self.audio, self.game_time);
+            }
+        }
+
+        for index in 0..self.cpu_racers.len() {
+            if self.fighter_down {
+                break;
+            }
+            let cr = &mut self.cpu_racers[index];
+            if !cr.check_collision(&Hurtbox::for_fighter(&self.fighter), self.block_system.active, &self.audio) {
+                continue;
+            }
+            let attack_negated = self.block_system.process_racer_attack(&mut self.fighter, cr, &self.audio, self.game_time);
+            if attack_negated || cr.damage_display_cooldown > 0.0 || self.fighter.invincible_timer > 0.0 {
+                continue;
+            }
+            // Dodge -> block -> armor -> crit; a broken guard can't auto-dodge or auto-block
+            let mut attack = damage::Attack::with_stats(cr.stats.attack.melee_damage, DamageType::Slash, &cr.stats.attack);
+            attack.avoidable = !self.block_system.block_broken;
+            let hit = damage::resolve(&attack, &incoming_defense(&self.fighter, &self.block_system, &self.combo_system));
+            cr.damage_display_cooldown = 0.125 / cr.status_effects.attack_speed_multiplier();
+            if !hit.landed() {
+                if let Some(sound) = hit.sound() {
+                    self.audio.play_sound_effect(sound).ok();
+                }
+                self.damage_texts.push(hit.text(self.fighter.x, self.fighter.y - 90.0));
+                self.events.push(WorldEvent::Avoided(hit.outcome));
+                continue;
+            }
+            self.fighter.current_hp -= hit.amount;
+            self.damage_texts.push(hit.text(self.fighter.x, self.fighter.y - 70.0));
+            if self.fighter.current_hp <= 0.0 {
+                self.fighter_downed(DeathType::Crashed);
+            }
+        }
+    }
+
+    /// A hostile racer's rush or ranged shot: negated by a held block like a projectile,
+    /// and a landed hit makes the fighter bleed.
+    fn racer_strikes_fighter(&mut self, racer: usize, amount: f64, damage_type: DamageType) {
+        let blocking = self.block_system.active && !self.block_system.block_broken && !self.block_system.block_fatigue;
+        if blocking && self.block_system.process_projectile_block(&mut self.fighter, &self.audio, self.game_time) {
+            return;
+        }
+        if self.fighter_down || self.fighter.invincible_timer > 0.0 {
+            return;
+        }
+        // Dodge -> block -> armor -> crit; a broken guard can't auto-dodge or auto-block
+        let mut attack = damage::Attack::with_stats(amount, damage_type, &self.cpu_racers[racer].stats.attack);
+        attack.avoidable = !self.block_system.block_broken;
+        let hit = damage::resolve(&attack, &incoming_defense(&self.fighter, &self.block_system, &self.combo_system));
+        if !hit.landed() {
+            if let Some(sound) = hit.sound() {
+                self.audio.play_sound_effect(sound).ok();
+            }
+            self.damage_texts.push(hit.text(self.fighter.x, self.fighter.y - 90.0));
+            return;
+        }
+        self.fighter.current_hp -= hit.amount;
+        self.damage_texts.push(hit.text(self.fighter.x, self.fighter.y - 70.0));
+        self.fighter.status_effects.apply(StatusEffect::bleed(50.0));
+        self.damage_texts.push(DamageText {
+            text: "BLEED".to_string(),
+            x: self.fighter.x,
+            y: self.fighter.y - 90.0,
+            color: [1.0, 0.0, 0.0, 1.0],
+            lifetime: 0.5,
+            style: TextStyle::Normal,
+        });
+        if self.fighter.current_hp <= 0.0 {
+            self.fighter_downed(DeathType::Crashed);
+        }
+    }
+
+    fn rebuild_cpu_grid(&mut self) {
+        self.cpu_grid
+            .rebuild(self.cpu_entities.iter().map(|cpu| (cpu.x, cpu.y)));
+    }
+
+    fn update_cpus(&mut self, dt: f64, rules: &WorldRules) {
+        // CPUs close enough to the player to use Flicker Strike this tick
+        self.rebuild_cpu_grid();
+        self.cpu_grid.query_radius(self.fighter.x, self.fighter.y, FLICKER_STRIKE_RADIUS, &mut self.cpu_grid_hits);
+        self.threat_targets.clear();
+        self.threat_targets.push(threat::Candidate::player(self.fighter.x, self.fighter.y));
+        let current_field = self.map_system.current_field_id;
+        if current_field == FieldId(0, 0) {
+            threat::push_racers(&self.cpu_racers, &mut self.threat_targets);
+        }
+        // Survivors wait out on the field, not inside areas.
+        if let (None, Some(survivors)) = (&self.current_area, self.survivors.get(&current_field)) {
+            for (index, survivor) in survivors.iter().enumerate().filter(|(_, s)| !s.is_rescued) {
+                self.threat_targets.push(threat::Candidate::survivor(index, survivor.x, survivor.y));
+            }
+        }
+        if rules.cpus_frozen {
+            return;
+        }
+
+        let bounds = self.area_bounds();
+        for cpu_index in 0..self.cpu_entities.len() {
+            let near_player = self.cpu_grid_hits.binary_search(&cpu_index).is_ok();
+            let cpu = &mut self.cpu_entities[cpu_index];
+            let result = cpu.update(&self.threat_targets, dt, self.line_y, bounds, &self.audio, near_player);
+            if let Some(ThreatTarget::Racer(index)) = cpu.threat.current {
+                threat::strike_racer(cpu, &mut self.cpu_racers[index], &mut self.damage_texts);
+            }
+            if let Some(cast) = result.skill_cast {
+                let death_type = cpu.variant.death_type();
+                let target = cpu.threat.current;
+                self.resolve_cpu_cast(&cast, target, death_type);
+            }
+        }
+    }
+
+    /// Resolves a skill a CPU cast at its threat target and spawns whatever it leaves behind.
+    fn resolve_cpu_cast(&mut self, cast: &SkillCast, target: Option<ThreatTarget>, death_type: DeathType) {
+        match target {
+            Some(ThreatTarget::Racer(index)) => cast.apply_to_racer(&mut self.cpu_racers[index], &mut self.damage_texts),
+            // Survivors can't be hurt, but a fighter standing by one still takes the cast.
+            Some(ThreatTarget::Player | ThreatTarget::Survivor(_)) | None => self.cpu_cast_at_fighter(cast, death_type),
+        }
+
+        cast.spawn(&mut self.pulse_orbs, &mut self.turrets, &mut self.skill_effects);
+        if cast.definition().visual == SkillVisual::Flicker {
+            // A quick strike flash at the destination
+            self.strike.trigger(cast.to.0, cast.to.1);
+            self.strike.timer = 0.15;
+            self.events.push(WorldEvent::Flicker { x: cast.from.0, y: cast.from.1 });
+        }
+        if cast.definition().shape == SkillShape::Projectile {
+            self.audio.play_sound_effect("firearm").ok();
+        }
+    }
+
+    /// A CPU cast landing on the fighter, unless a held block or invincibility stops it.
+    fn cpu_cast_at_fighter(&mut self, cast: &SkillCast, death_type: DeathType) {
+        if !cast.hits_fighter(&self.fighter) || self.fighter_down {
+            return;
+        }
+        hitbox::record_hit(&Hurtbox::for_fighter(&self.fighter));
+        let blocking = self.block_system.active && !self.block_system.block_broken && !self.block_system.block_fatigue;
+        if blocking {
+            // A held block negates skill damage outright
+            self.audio.play_sound_effect("block").ok();
+        } else if self.fighter.invincible_timer <= 0.0 {
+            let hit = damage::resolve(
+                &damage::Attack::cpu(cast.damage, cast.damage_type),
+                &damage::Defense::fighter(&self.fighter),
+            );
+            if !hit.landed() {
+                if let Some(sound) = hit.sound() {
+                    self.audio.play_sound_effect(sound).ok();
+                }
+                self.damage_texts.push(hit.text(self.fighter.x, self.fighter.y - 90.0));
+            } else {
+                self.fighter.current_hp -= hit.amount;
+                cast.afflict_fighter(&mut self.fighter);
+                self.damage_texts.push(hit.text(self.fighter.x, self.fighter.y - 70.0));
+                if self.fighter.state == RacerState::OnBike {
+                    println!("Dismounted by enemy skill damage!");
+                    self.fighter.state = RacerState::OnFoot;
+                    self.events.push(WorldEvent::Dismounted { announce: false });
+                }
+                if self.fighter.current_hp <= 0.0 {
+                    self.fighter_downed(death_type);
+                }
+            }
+        }
+    }
+
+    fn update_projectiles(&mut self, dt: f64) {
+        // Deployed turrets fire pulse orbs at the nearest target in range
+        for turret in &mut self.turrets {
+            let target = if turret.from_player {
+                skill_runner::nearest_cpu((turret.x, turret.y), turret.range, &self.cpu_entities)
+            } else {
+                Some((self.fighter.x, self.fighter.y))
+            };
+            if let Some(orb) = turret.update(dt, target) {
+                self.pulse_orbs.push(orb);
+            }
+        }
+        self.turrets.retain(Turret::is_active);
+
+        for index in 0..self.pulse_orbs.len() {
+            let orb = &mut self.pulse_orbs[index];
+            orb.update(dt);
+            // Player-fired orbs hit CPUs instead of the player
+            if orb.from_player {
+                if orb.active {
+                    skill_runner::projectile_hits_cpus(orb, &self.fighter.stats.attack, &mut self.cpu_entities, &mut self.damage_texts);
+                }
+                continue;
+            }
+            let dx = orb.x - self.fighter.x;
+            let dy = orb.y - self.fighter.y;
+            let collision_radius = orb.radius + 25.0; // Approx player radius
+            if !orb.active || self.fighter_down || dx * dx + dy * dy >= collision_radius * collision_radius {
+                continue;
+            }
+            let projectile_blocked = self.block_system.process_projectile_block(&mut self.fighter, &self.audio, self.game_time);
+            if !projectile_blocked && self.fighter.invincible_timer > 0.0 {
+                continue;
+            }
+            orb.active = false;
+            if projectile_blocked {
+                continue;
+            }
+            let hit = damage::resolve(
+                &damage::Attack::cpu(orb.damage, orb.damage_type),
+                &damage::Defense::fighter(&self.fighter),
+            );
+            if !hit.landed() {
+                if let Some(sound) = hit.sound() {
+                    self.audio.play_sound_effect(sound).ok();
+                }
+                self.damage_texts.push(hit.text(self.fighter.x, self.fighter.y - 90.0));
+                continue;
+            }
+            self.fighter.current_hp -= hit.amount;
+            if let Some(status) = &orb.status {
+                self.fighter.status_effects.apply(status.clone());
+            }
+            self.audio.play_sound_effect("hit").ok();
+            self.damage_texts.push(hit.text(self.fighter.x, self.fighter.y - 70.0));
+            if self.fighter.current_hp <= 0.0 {
+                // Pulse orbs are a Night Reaver skill
+                self.fighter_downed(DeathType::NightReaver);
+            }
+        }
+        self.pulse_orbs.retain(|orb| orb.active);
+    }
+
+    fn resolve_cpu_contacts(&mut self) {
+        for index in 0..self.cpu_entities.len() {
+            if self.fighter_down {
+                break;
+            }
+            let cpu_entity = &mut self.cpu_entities[index];
+            if !cpu_entity.check_collision(&Hurtbox::for_fighter(&self.fighter), self.block_system.active, &self.audio) {
+                continue;
+            }
+            let attack_negated = self.block_system.process_attack(&mut self.fighter, cpu_entity, &self.audio, self.game_time);
+            if attack_negated || cpu_entity.damage_display_cooldown > 0.0 {
+                continue;
+            }
+            cpu_entity.damage_display_cooldown = 0.125 / cpu_entity.status_effects.attack_speed_multiplier();
+            if self.fighter.invincible_timer > 0.0 {
+                continue;
+            }
+            // Dodge -> block -> armor -> crit; a broken guard or a stunned attacker can't be auto-avoided
+            let mut attack = damage::Attack::cpu(cpu_entity.damage_value, damage::cpu_attack_type(cpu_entity.variant));
+            attack.avoidable = !self.block_system.block_broken && cpu_entity.stun_timer <= 0.0;
+            let hit = damage::resolve(&attack, &incoming_defense(&self.fighter, &self.block_system, &self.combo_system));
+            if !hit.landed() {
+                if let Some(sound) = hit.sound() {
+                    self.audio.play_sound_effect(sound).ok();
+                }
+                self.damage_texts.push(hit.text(self.fighter.x, self.fighter.y - 90.0));
+                self.events.push(WorldEvent::Avoided(hit.outcome));
+                continue;
+            }
+            self.fighter.current_hp -= hit.amount;
+            self.damage_texts.push(hit.text(self.fighter.x, self.fighter.y - 70.0));
+            if self.fighter.state == RacerState::OnBike {
+                println!("Dismounted by enemy damage!");
+                self.fighter.state = RacerState::OnFoot;
+                self.events.push(WorldEvent::Dismounted { announce: true });
+            }
+            if self.fighter.current_hp <= 0.0 {
+                let death_type = cpu_entity.variant.death_type();
+                self.fighter_downed(death_type);
+            }
+        }
+    }
+
+    fn process_dead_cpus(&mut self, rules: &WorldRules) {
+        let current_field = self.map_system.current_field_id;
+        let aptitude = crate::get_field_aptitude(current_field);
+        let mut index = 0;
+        while index < self.cpu_entities.len() {
+            let cpu = &self.cpu_entities[index];
+            if !cpu.is_dead() {
+                index += 1;
+                continue;
+            }
+            let (variant, x, y) = (cpu.variant, cpu.x, cpu.y);
+            if self.wave_manager.is_active() {
+                self.wave_manager.notify_enemy_defeated();
+            }
+            self.game_events.publish(GameEvent::EnemyKilled { variant, x, y });
+
+            let score_value = variant.score_value();
+            self.fighter.score = (self.fighter.score + score_value).min(999999);
+            let mut leveled_up = self.add_kill_score(score_value);
+            leveled_up |= self.award_kill_score(score_value, aptitude);
+            self.fighter.current_hp = (self.fighter.current_hp + 25.0).min(self.fighter.max_hp);
+            self.fighter.resources.gain(ResourceAction::Kill);
+            self.events.push(WorldEvent::CpuKilled { variant, x, y });
+            if leveled_up {
+                self.events.push(WorldEvent::LeveledUp);
+            }
+
+            // Field monsters respawn; bosses, nest and wave enemies are gone for good. The
+            // endless arena and Rocketbay (whose spawner adds Night Reavers) remove them all.
+            let respawns = !matches!(
+                variant,
+                CpuVariant::Raptor
+                    | CpuVariant::TRex
+                    | CpuVariant::BloodIdol
+                    | CpuVariant::VoidTempest
+                    | CpuVariant::LightReaver
+                    | CpuVariant::NightReaver
+                    | CpuVariant::RazorFiend
+            ) && !rules.endless_arena
+                && current_field != FieldId(-2, 5);
+            if respawns {
+                let cpu = &mut self.cpu_entities[index];
+                cpu.respawn(self.line_y);
+                Self::apply_aptitude(cpu, current_field);
+                index += 1;
+            } else {
+                self.cpu_entities.remove(index);
+            }
+        }
+    }
+
+    fn fighter_downed(&mut self, death_type: DeathType) {
+        if self.fighter_down {
+            return;
+        }
+        self.fighter_down = true;
+        self.melee_rapid_fire_timer = 0.0;
+        self.block_system = BlockSystem::new(20);
+        self.audio.play_sound_effect("death").ok();
+        self.events.push(WorldEvent::FighterDowned(death_type));
+    }
+
+    /// Index of the closest living hostile CPU entity, if any.
+    pub fn nearest_hostile(&self) -> Option<usize> {
+        self.cpu_entities
+            .iter()
+            .enumerate()
+            .filter(|(_, cpu)| !cpu.is_dead() && cpu.entity_state != EntityState::Friendly)
+            .min_by(|(_, a), (_, b)| {
+                let da = (a.x - self.fighter.x).powi(2) + (a.y - self.fighter.y).powi(2);
+                let db = (b.x - self.fighter.x).powi(2) + (b.y - self.fighter.y).powi(2);
+                da.total_cmp(&db)
+            })
+            .map(|(i, _)| i)
+    }
+
+    /// Simple scripted player: walk towards the nearest hostile and strike when in range.
+    pub fn autopilot_input(&self) -> InputFrame {
+        let mut input = InputFrame {
+            aim_x: self.fighter.x,
+            aim_y: self.fighter.y,
+            ..Default::default()
+        };
+        if let Some(cpu) = self.nearest_hostile().and_then(|i| self.cpu_entities.get(i)) {
+            input.aim_x = cpu.x;
+            input.aim_y = cpu.y;
+            let dx = cpu.x - self.fighter.x;
+            let dy = cpu.y - self.fighter.y;
+            if (dx * dx + dy * dy).sqrt() > self.fixed_crater.radius * 0.75 {
+                input.move_left = dx < -10.0;
+                input.move_right = dx > 10.0;
+                input.move_up = dy < -10.0;
+                input.move_down = dy > 10.0;
+            } else {
+                input.strike = true;
+            }
+        }
+        input
+    }
+}
+
+/// The fighter's defense against a melee-range hit: block and combo damage reduction apply.
+fn incoming_defense(fighter: &Fighter, block_system: &BlockSystem, combo_system: &ComboSystem) -> damage::Defense {
+    let combo_dr_multiplier = combo_system.get_damage_intake_multiplier(fighter.fighter_type);
+    damage::Defense::fighter(fighter).taking(block_system.get_damage_multiplier() * combo_dr_multiplier)
+}
+
+/// Runs the autopilot for `seconds` of game time at 60 steps per second and prints a summary.
+pub fn run_headless(seconds: f64, bunker_floor: Option<i32>) {
+    let dt = 1.0 / 60.0;
+    let mut world = World::new(AudioManager::silent());
+    if let Some(floor) = bunker_floor {
+        world.enter_bunker(floor, 3);
+    }
+    let total_steps = (seconds / dt).ceil() as u64;
+    let mut steps_run = 0;
+    for _ in 0..total_steps {
+        let input = world.autopilot_input();
+        world.step(dt, &input);
+        steps_run += 1;
+        if world.is_over() {
+            break;
+        }
+    }
+    let level = world
+        .fighter
+        .levels
+        .get(&world.fighter.fighter_type)
+        .copied()
+        .unwrap_or(1);
+    println!(
+        "[HEADLESS] Simulated {:.2}s ({} steps) with seed {}",
+        steps_run as f64 * dt,
+        steps_run,
+        crate::utils::rng::current_seed()
+    );
+    println!(
+        "[HEADLESS] Kills: {}  Score: {}  Level: {}  HP: {:.0}/{:.0}",
+        world.kills, world.fighter.score, level, world.fighter.current_hp, world.fighter.max_hp
+    );
+    if let Some(death) = world.death {
+        println!("[HEADLESS] Fighter defeated: {:?}", death);
+    }
+    if let Some(wave_text) = world.wave_manager.get_ui_text() {
+        println!("[HEADLESS] {}", wave_text);
+    }
+}
+
+/// Compares full scans against spatial hash queries for the hit, skill and barrier
+/// checks, with `entity_count` CPUs spread over the field. Prints timings and verifies
+/// both paths find the same hits.
+pub fn run_spatial_benchmark(entity_count: usize) {
+    use crate::entities::collision_barriers::CollisionBarrierManager;
+    use crate::utils::collision::{check_line_collision, LINE_COLLISION_WIDTH};
+    use std::time::Instant;
+
+    const QUERIES: usize = 2000;
+    let reach = config::get().gameplay.collision_threshold;
+    let crater_radius = 150.0;
+
+    let positions: Vec<(f64, f64)> = (0..entity_count)
+        .map(|_| {
+            (
+                safe_gen_range(MIN_X, MAX_X, "bench cpu x"),
+                safe_gen_range(MIN_Y, MAX_Y, "bench cpu y"),
+            )
+        })
+        .collect();
+    let probes: Vec<(f64, f64, f64, f64)> = (0..QUERIES)
+        .map(|_| {
+            let x = safe_gen_range(MIN_X, MAX_X, "bench probe x");
+            let y = safe_gen_range(MIN_Y, MAX_Y, "bench probe y");
+            let angle = safe_gen_range(0.0, std::f64::consts::TAU, "bench probe angle");
+            (x, y, x + angle.cos() * 600.0, y + angle.sin() * 600.0)
+        })
+        .collect();
+
+    let mut grid = SpatialHash::for_world();
+    let mut candidates = Vec::new();
+    println!(
+        "[BENCH] {} entities, {} queries per check (seed {})",
+        entity_count,
+        QUERIES,
+        crate::utils::rng::current_seed()
+    );
+
+    // Melee strike: everything within the crater radius of the strike point.
+    let start = Instant::now();
+    let mut scan_hits = 0;
+    for &(x, y, _, _) in &probes {
+        for &(cx, cy) in &positions {
+            if (cx - x).powi(2) + (cy - y).powi(2) <= crater_radius * crater_radius {
+                scan_hits += 1;
+            }
+        }
+    }
+    let scan_time = start.elapsed();
+    let start = Instant::now();
+    let mut grid_hits = 0;
+    for &(x, y, _, _) in &probes {
+        // Melee rebuilds once per strike, so the rebuild is part of the cost.
+        grid.rebuild(positions.iter().copied());
+        grid.candidates_in_radius(x, y, crater_radius.max(reach), positions.len(), &mut candidates);
+        for &index in &candidates {
+            let (cx, cy) = positions[index];
+            if (cx - x).powi(2) + (cy - y).powi(2) <= crater_radius * crater_radius {
+                grid_hits += 1;
+            }
+        }
+    }
+    report_bench("melee", scan_time, grid.item_count(), grid_hits, scan_hits, start.elapsed());
+
+    // Shoot / skill lines: CPUs within LINE_COLLISION_WIDTH of a segment.
+    grid.rebuild(positions.iter().copied());
+    let start = Instant::now();
+    let mut scan_hits = 0;
+    for &(x1, y1, x2, y2) in &probes {
+        for &(cx, cy) in &positions {
+            if check_line_collision(x1, y1, x2, y2, cx, cy) {
+                scan_hits += 1;
+            }
+        }
+    }
+    let scan_time = start.elapsed();
+    let start = Instant::now();
+    let mut grid_hits = 0;
+    for &(x1, y1, x2, y2) in &probes {
+        grid.candidates_along_segment((x1, y1), (x2, y2), LINE_COLLISION_WIDTH, positions.len(), &mut candidates);
+        for &index in &candidates {
+            let (cx, cy) = positions[index];
+            if check_line_collision(x1, y1, x2, y2, cx, cy) {
+                grid_hits += 1;
+            }
+        }
+    }
+    report_bench("shoot", scan_time, grid.item_count(), grid_hits, scan_hits, start.elapsed());
+
+    // Barrier checks: every CPU against the racetrack walls.
+    let manager = CollisionBarrierManager::new();
+    let Some(barriers) = manager.get_barriers(&FieldId(0, 0)) else {
+        return;
+    };
+    let threshold = 30.0;
+    let start = Instant::now();
+    let mut scan_hits = 0;
+    for &(cx, cy) in &positions {
+        if barriers.lines.iter().any(|line| line.check_collision(cx, cy, threshold)) {
+            scan_hits += 1;
+        }
+    }
+    let scan_time = start.elapsed();
+    let start = Instant::now();
+    let grid_hits = positions
+        .iter()
+        .filter(|&&(cx, cy)| barriers.check_point_collision(cx, cy, threshold).is_some())
+        .count();
+    report_bench("barrier", scan_time, barriers.lines.len(), grid_hits, scan_hits, start.elapsed());
+}
+
+fn report_bench(
+    name: &str,
+    scan_time: std::time::Duration,
+    indexed: usize,
+    grid_hits: usize,
+    scan_hits: usize,
+    grid_time: std::time::Duration,
+) {
+    let speedup = scan_time.as_secs_f64() / grid_time.as_secs_f64().max(1e-9);
+    println!(
+        "[BENCH] {:<8} scan {:>9.3}ms  grid {:>9.3}ms  x{:.1}  ({} indexed, {} hits{})",
+        name,
+        scan_time.as_secs_f64() * 1000.0,
+        grid_time.as_secs_f64() * 1000.0,
+        speedup,
+        indexed,
+        grid_hits,
+        if grid_hits == scan_hits { "" } else { ", MISMATCH with full scan" }
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world_on_foot() -> World {
+        let mut world = World::new(AudioManager::silent());
+        world.cpu_entities.clear();
+        world.fighter.state = RacerState::OnFoot;
+        world
+    }
+
+    #[test]
+    fn walking_away_from_the_aim_is_half_speed() {
+        let mut world = world_on_foot();
+        let start = world.fighter.x;
+        assert!(world.walk(1.0, 0.0, start + 500.0, 1.0, 0.5));
+        let forward = world.fighter.x - start;
+
+        let start = world.fighter.x;
+        assert!(!world.walk(1.0, 0.0, start - 500.0, 1.0, 0.5));
+        let backward = world.fighter.x - start;
+        assert!((backward * 2.0 - forward).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stepping_with_input_walks_and_strikes_like_the_game() {
+        let mut world = world_on_foot();
+        let mut cpu = CpuEntity::new_giant_mantis(world.line_y);
+        cpu.x = world.fighter.x + 80.0;
+        cpu.y = world.fighter.y;
+        world.cpu_entities.push(cpu);
+
+        let aim_x = world.fighter.x + 80.0;
+        let aim_y = world.fighter.y;
+        let input = InputFrame { strike: true, aim_x, aim_y, ..InputFrame::default() };
+        world.step(1.0 / 60.0, &input);
+        assert!(world.strike.visible);
+        assert!(!world.damage_texts.is_empty(), "the strike should reach the CPU in front");
+
+        let start = world.fighter.x;
+        let input = InputFrame { move_right: true, aim_x: start + 500.0, aim_y, ..InputFrame::default() };
+        world.step(0.1, &input);
+        assert!(world.fighter.x > start);
+    }
+}
@@ -1,6 +1,7 @@
 // File: src/task.rs
 
 use crate::entities::cpu_entity::CpuVariant;
+use crate::game_event::{GameEvent, GameEventListener};
 use piston_window::*;
 
 const RED_ORANGE: [f32; 4] = [1.0, 0.27, 0.0, 1.0];
@@ -169,6 +170,83 @@ impl TaskSystem {
             .any(|t| t.description == description && t.completed)
     }
 
+    pub fn tasks(&self) -> &[Task] {
+        &self.tasks
+    }
+
+    /// Replaces the task list when loading a save. Does not pop the task panel open.
+    pub fn restore_tasks(&mut self, tasks: Vec<Task>) {
+        self.tasks = tasks;
+    }
+
+    /// Named kill counters, as written to save files.
+    pub fn counters(&self) -> [(&'static str, u32); 11] {
+        [
+            ("giant_mantis_defeated", self.giant_mantis_defeated),
+            ("rattlesnake_defeated", self.rattlesnake_defeated),
+            ("giant_rattlesnake_defeated", self.giant_rattlesnake_defeated),
+            ("raptor_defeated", self.raptor_defeated),
+            ("t_rex_defeated", self.t_rex_defeated),
+            ("blood_idol_defeated", self.blood_idol_defeated),
+            ("void_tempest_defeated", self.void_tempest_defeated),
+            ("light_reaver_defeated", self.light_reaver_defeated),
+            ("night_reaver_defeated", self.night_reaver_defeated),
+            ("razor_fiend_defeated", self.razor_fiend_defeated),
+            ("survivors_found", self.survivors_found),
+        ]
+    }
+
+    /// Named progress flags, as written to save files.
+    pub fn flags(&self) -> [(&'static str, bool); 7] {
+        [
+            ("raptor_nest_cleared", self.raptor_nest_cleared),
+            ("rocketbay_found", self.rocketbay_found),
+            ("fort_silo_reached", self.fort_silo_reached),
+            ("flying_saucer_defeated", self.flying_saucer_defeated),
+            ("landed_on_fort_silo", self.landed_on_fort_silo),
+            ("grand_commander_spoken_to", self.grand_commander_spoken_to),
+            ("racer_returned_to_racetrack", self.racer_returned_to_racetrack),
+        ]
+    }
+
+    pub fn set_counter(&mut self, name: &str, value: u32) {
+        let counter = match name {
+            "giant_mantis_defeated" => &mut self.giant_mantis_defeated,
+            "rattlesnake_defeated" => &mut self.rattlesnake_defeated,
+            "giant_rattlesnake_defeated" => &mut self.giant_rattlesnake_defeated,
+            "raptor_defeated" => &mut self.raptor_defeated,
+            "t_rex_defeated" => &mut self.t_rex_defeated,
+            "blood_idol_defeated" => &mut self.blood_idol_defeated,
+            "void_tempest_defeated" => &mut self.void_tempest_defeated,
+            "light_reaver_defeated" => &mut self.light_reaver_defeated,
+            "night_reaver_defeated" => &mut self.night_reaver_defeated,
+            "razor_fiend_defeated" => &mut self.razor_fiend_defeated,
+            "survivors_found" => &mut self.survivors_found,
+            _ => {
+                println!("[TASK] Ignoring unknown counter '{}'", name);
+                return;
+            }
+        };
+        *counter = value;
+    }
+
+    pub fn set_flag(&mut self, name: &str, value: bool) {
+        let flag = match name {
+            "raptor_nest_cleared" => &mut self.raptor_nest_cleared,
+            "rocketbay_found" => &mut self.rocketbay_found,
+            "fort_silo_reached" => &mut self.fort_silo_reached,
+            "flying_saucer_defeated" => &mut self.flying_saucer_defeated,
+            "landed_on_fort_silo" => &mut self.landed_on_fort_silo,
+            "grand_commander_spoken_to" => &mut self.grand_commander_spoken_to,
+            "racer_returned_to_racetrack" => &mut self.racer_returned_to_racetrack,
+            _ => {
+                println!("[TASK] Ignoring unknown flag '{}'", name);
+                return;
+            }
+        };
+        *flag = value;
+    }
+
     pub fn update(&mut self) -> u32 {
         let mut tasks_to_add = Vec::new();
         let mut task_was_completed = false;
@@ -377,4 +455,12 @@ impl TaskSystem {
                 .ok();
         }
     }
-}
\ No newline at end of file
+}
+
+impl GameEventListener for TaskSystem {
+    fn on_game_event(&mut self, event: &GameEvent) {
+        if let GameEvent::EnemyKilled { variant, .. } = event {
+            self.increment_kill_count(*variant);
+        }
+    }
+}
@@ -1,5 +1,8 @@
 // utils/collision.rs
 
+/// How close a point must be to a line to count as hit by it.
+pub const LINE_COLLISION_WIDTH: f64 = 50.0;
+
 /// Checks if a point is close enough to a line segment to be considered a collision.
 ///
 /// # Arguments
@@ -42,5 +45,5 @@ pub fn check_line_collision(
     let distance = (dx * dx + dy * dy).sqrt();
 
     // Check if distance is within collision threshold
-    distance < 50.0 // Adjust this value to change collision width
+    distance < LINE_COLLISION_WIDTH
 }
@@ -1,10 +1,20 @@
 // utils/math.rs
 
+use crate::utils::rng::{self, RngStream};
 use rand::Rng;
 
-/// Safely generates a random number in the given range.
+/// Safely generates a random number in the given range from the gameplay stream.
 /// Logs a warning if min >= max and returns min in that case.
 pub fn safe_gen_range(min: f64, max: f64, context: &str) -> f64 {
+    safe_gen_range_in(RngStream::Gameplay, min, max, context)
+}
+
+/// Same as `safe_gen_range`, drawing from the cosmetic stream (scenery, effects).
+pub fn safe_gen_range_cosmetic(min: f64, max: f64, context: &str) -> f64 {
+    safe_gen_range_in(RngStream::Cosmetic, min, max, context)
+}
+
+fn safe_gen_range_in(stream: RngStream, min: f64, max: f64, context: &str) -> f64 {
     if min >= max {
         println!(
             "WARNING: Empty range detected in {}: min={}, max={}",
@@ -12,7 +22,6 @@ pub fn safe_gen_range(min: f64, max: f64, context: &str) -> f64 {
         );
         min // Return min value if range is empty to avoid crashing
     } else {
-        let mut rng = rand::rng();
-        rng.random_range(min..max)
+        rng::with_stream(stream, |rng| rng.random_range(min..max))
     }
 }
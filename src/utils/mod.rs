@@ -3,4 +3,7 @@
 pub mod animation_queue;
 pub mod collision;
 pub mod math;
+pub mod rng;
+pub mod spatial_hash;
+pub mod timestep;
 pub mod vec2d;
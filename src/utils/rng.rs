@@ -0,0 +1,82 @@
+// utils/rng.rs
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::{Mutex, OnceLock};
+
+/// Which stream a roll is drawn from.
+/// Gameplay rolls (spawns, AI, passive defense) must replay identically for a seed.
+/// Cosmetic rolls (particles, stars, scenery) run on their own stream so that
+/// frame-rate dependent visuals never shift the gameplay sequence.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RngStream {
+    Gameplay,
+    Cosmetic,
+}
+
+/// Game-wide seedable RNG with one generator per stream.
+pub struct GameRng {
+    seed: u64,
+    gameplay: StdRng,
+    cosmetic: StdRng,
+}
+
+impl GameRng {
+    // Keeps the cosmetic stream independent from the gameplay stream for the same seed.
+    const COSMETIC_SEED_SALT: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    pub fn new(seed: u64) -> Self {
+        GameRng {
+            seed,
+            gameplay: StdRng::seed_from_u64(seed),
+            cosmetic: StdRng::seed_from_u64(seed ^ Self::COSMETIC_SEED_SALT),
+        }
+    }
+
+    pub fn stream(&mut self, stream: RngStream) -> &mut StdRng {
+        match stream {
+            RngStream::Gameplay => &mut self.gameplay,
+            RngStream::Cosmetic => &mut self.cosmetic,
+        }
+    }
+}
+
+static GAME_RNG: OnceLock<Mutex<GameRng>> = OnceLock::new();
+
+fn service() -> &'static Mutex<GameRng> {
+    // Unseeded runs still get a recorded seed so they can be reproduced later.
+    GAME_RNG.get_or_init(|| Mutex::new(GameRng::new(rand::rng().random())))
+}
+
+/// Resets both streams from `seed`. Call before any gameplay state is created.
+pub fn reseed(seed: u64) {
+    let mut rng = service().lock().unwrap_or_else(|e| e.into_inner());
+    *rng = GameRng::new(seed);
+}
+
+/// The seed the current streams were created from.
+pub fn current_seed() -> u64 {
+    service().lock().unwrap_or_else(|e| e.into_inner()).seed
+}
+
+/// Runs `f` with exclusive access to the given stream.
+pub fn with_stream<T>(stream: RngStream, f: impl FnOnce(&mut StdRng) -> T) -> T {
+    let mut rng = service().lock().unwrap_or_else(|e| e.into_inner());
+    f(rng.stream(stream))
+}
+
+/// Returns a generator forked from the given stream. The fork is seeded from the
+/// stream itself, so the sequence stays deterministic for a given game seed.
+pub fn fork(stream: RngStream) -> StdRng {
+    with_stream(stream, |rng| StdRng::seed_from_u64(rng.random()))
+}
+
+/// Forked generator for gameplay-affecting rolls.
+pub fn gameplay_rng() -> StdRng {
+    fork(RngStream::Gameplay)
+}
+
+/// Forked generator for purely visual rolls.
+pub fn cosmetic_rng() -> StdRng {
+    fork(RngStream::Cosmetic)
+}
@@ -0,0 +1,252 @@
+// utils/spatial_hash.rs
+
+use crate::config::boundaries::{MAX_X, MAX_Y, MIN_X, MIN_Y};
+
+/// Cell edge length used for entity grids. Roughly the size of a melee crater, so
+/// most radius queries touch a 3x3 block of cells.
+pub const DEFAULT_CELL_SIZE: f64 = 200.0;
+
+/// Uniform grid over world coordinates for broad-phase queries. Items are stored by
+/// their index in the caller's Vec; queries return candidate indices in ascending order
+/// so callers visit them in the same order as a full scan would.
+///
+/// Positions outside the grid bounds are clamped into the edge cells (areas and bunkers
+/// sit outside the field bounds), so queries stay correct there, just less selective.
+#[derive(Debug, Clone)]
+pub struct SpatialHash {
+    cell_size: f64,
+    origin_x: f64,
+    origin_y: f64,
+    cols: usize,
+    rows: usize,
+    cells: Vec<Vec<usize>>,
+    item_count: usize,
+}
+
+impl SpatialHash {
+    /// `bounds` is (min_x, max_x, min_y, max_y), like `FogGrid::world_bounds`.
+    pub fn new(bounds: (f64, f64, f64, f64), cell_size: f64) -> Self {
+        let cols = (((bounds.1 - bounds.0) / cell_size).ceil() as usize).max(1);
+        let rows = (((bounds.3 - bounds.2) / cell_size).ceil() as usize).max(1);
+        SpatialHash {
+            cell_size,
+            origin_x: bounds.0,
+            origin_y: bounds.2,
+            cols,
+            rows,
+            cells: vec![Vec::new(); cols * rows],
+            item_count: 0,
+        }
+    }
+
+    /// Grid covering the field boundaries (MIN_X..MAX_X, MIN_Y..MAX_Y).
+    pub fn for_world() -> Self {
+        SpatialHash::new((MIN_X, MAX_X, MIN_Y, MAX_Y), DEFAULT_CELL_SIZE)
+    }
+
+    pub fn clear(&mut self) {
+        for cell in &mut self.cells {
+            cell.clear();
+        }
+        self.item_count = 0;
+    }
+
+    /// Clears the grid and inserts one point per item, indexed by position in `points`.
+    pub fn rebuild<I: IntoIterator<Item = (f64, f64)>>(&mut self, points: I) {
+        self.clear();
+        for (index, (x, y)) in points.into_iter().enumerate() {
+            self.insert_point(index, x, y);
+        }
+    }
+
+    pub fn insert_point(&mut self, index: usize, x: f64, y: f64) {
+        let cell = self.cell_index(self.col(x), self.row(y));
+        self.cells[cell].push(index);
+        self.item_count = self.item_count.max(index + 1);
+    }
+
+    /// Inserts an item that covers a rectangle (e.g. a line segment's bounding box).
+    pub fn insert_rect(&mut self, index: usize, min_x: f64, min_y: f64, max_x: f64, max_y: f64) {
+        for row in self.row(min_y)..=self.row(max_y) {
+            for col in self.col(min_x)..=self.col(max_x) {
+                let cell = self.cell_index(col, row);
+                self.cells[cell].push(index);
+            }
+        }
+        self.item_count = self.item_count.max(index + 1);
+    }
+
+    /// Number of items the grid was built for. When this no longer matches the caller's
+    /// Vec (something spawned or died since the rebuild), the query helpers fall back to
+    /// returning every index.
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    /// Candidates whose cells overlap the rectangle. `out` is cleared first.
+    pub fn query_rect(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64, out: &mut Vec<usize>) {
+        out.clear();
+        let (col_start, col_end) = (self.col(min_x), self.col(max_x));
+        let (row_start, row_end) = (self.row(min_y), self.row(max_y));
+        for row in row_start..=row_end {
+            for col in col_start..=col_end {
+                out.extend_from_slice(&self.cells[self.cell_index(col, row)]);
+            }
+        }
+        out.sort_unstable();
+        out.dedup();
+    }
+
+    /// Candidates that may lie within `radius` of (x, y).
+    pub fn query_radius(&self, x: f64, y: f64, radius: f64, out: &mut Vec<usize>) {
+        self.query_rect(x - radius, y - radius, x + radius, y + radius, out);
+    }
+
+    /// Candidates that may lie within `padding` of the segment (x1, y1)-(x2, y2).
+    pub fn query_segment(&self, x1: f64, y1: f64, x2: f64, y2: f64, padding: f64, out: &mut Vec<usize>) {
+        self.query_rect(
+            x1.min(x2) - padding,
+            y1.min(y2) - padding,
+            x1.max(x2) + padding,
+            y1.max(y2) + padding,
+            out,
+        );
+    }
+
+    /// `query_rect` for a Vec of `item_count` items, or every index if the grid is stale.
+    pub fn candidates_in_rect(
+        &self,
+        min: (f64, f64),
+        max: (f64, f64),
+        item_count: usize,
+        out: &mut Vec<usize>,
+    ) {
+        if self.item_count == item_count {
+            self.query_rect(min.0, min.1, max.0, max.1, out);
+        } else {
+            out.clear();
+            out.extend(0..item_count);
+        }
+    }
+
+    /// `query_radius` for a Vec of `item_count` items, or every index if the grid is stale.
+    pub fn candidates_in_radius(&self, x: f64, y: f64, radius: f64, item_count: usize, out: &mut Vec<usize>) {
+        self.candidates_in_rect((x - radius, y - radius), (x + radius, y + radius), item_count, out);
+    }
+
+    /// `query_segment` for a Vec of `item_count` items, or every index if the grid is stale.
+    pub fn candidates_along_segment(
+        &self,
+        (x1, y1): (f64, f64),
+        (x2, y2): (f64, f64),
+        padding: f64,
+        item_count: usize,
+        out: &mut Vec<usize>,
+    ) {
+        if self.item_count == item_count {
+            self.query_segment(x1, y1, x2, y2, padding, out);
+        } else {
+            out.clear();
+            out.extend(0..item_count);
+        }
+    }
+
+    fn col(&self, x: f64) -> usize {
+        let col = ((x - self.origin_x) / self.cell_size).floor();
+        if col.is_nan() || col < 0.0 {
+            0
+        } else {
+            (col as usize).min(self.cols - 1)
+        }
+    }
+
+    fn row(&self, y: f64) -> usize {
+        let row = ((y - self.origin_y) / self.cell_size).floor();
+        if row.is_nan() || row < 0.0 {
+            0
+        } else {
+            (row as usize).min(self.rows - 1)
+        }
+    }
+
+    fn cell_index(&self, col: usize, row: usize) -> usize {
+        row * self.cols + col
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> SpatialHash {
+        // 4x4 cells of 100 over (0..400, 0..400)
+        SpatialHash::new((0.0, 400.0, 0.0, 400.0), 100.0)
+    }
+
+    #[test]
+    fn rebuild_replaces_previous_items() {
+        let mut hash = grid();
+        hash.rebuild(vec![(50.0, 50.0), (350.0, 350.0), (150.0, 50.0)]);
+        assert_eq!(hash.item_count(), 3);
+
+        hash.rebuild(vec![(350.0, 350.0)]);
+        assert_eq!(hash.item_count(), 1);
+        let mut out = Vec::new();
+        hash.query_rect(0.0, 0.0, 400.0, 400.0, &mut out);
+        assert_eq!(out, vec![0]);
+    }
+
+    #[test]
+    fn query_radius_returns_nearby_cells_in_order() {
+        let mut hash = grid();
+        hash.rebuild(vec![(350.0, 350.0), (50.0, 50.0), (150.0, 50.0), (60.0, 40.0)]);
+        let mut out = vec![99];
+        hash.query_radius(50.0, 50.0, 20.0, &mut out);
+        assert_eq!(out, vec![1, 3]);
+
+        hash.query_radius(100.0, 50.0, 20.0, &mut out);
+        assert_eq!(out, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn points_on_cell_edges_belong_to_the_upper_cell() {
+        let mut hash = grid();
+        hash.rebuild(vec![(100.0, 100.0), (99.9, 99.9)]);
+        let mut out = Vec::new();
+        hash.query_rect(100.0, 100.0, 199.0, 199.0, &mut out);
+        assert_eq!(out, vec![0]);
+        hash.query_rect(0.0, 0.0, 99.0, 99.0, &mut out);
+        assert_eq!(out, vec![1]);
+    }
+
+    #[test]
+    fn out_of_bounds_points_clamp_to_edge_cells() {
+        let mut hash = grid();
+        hash.rebuild(vec![(-500.0, -500.0), (900.0, 900.0), (f64::NAN, 50.0)]);
+        let mut out = Vec::new();
+        hash.query_radius(10.0, 10.0, 5.0, &mut out);
+        assert_eq!(out, vec![0, 2]);
+        hash.query_radius(390.0, 390.0, 5.0, &mut out);
+        assert_eq!(out, vec![1]);
+    }
+
+    #[test]
+    fn query_segment_covers_the_padded_bounding_box() {
+        let mut hash = grid();
+        hash.rebuild(vec![(50.0, 50.0), (250.0, 250.0), (350.0, 50.0)]);
+        let mut out = Vec::new();
+        hash.query_segment(250.0, 250.0, 40.0, 40.0, 5.0, &mut out);
+        assert_eq!(out, vec![0, 1]);
+    }
+
+    #[test]
+    fn stale_grid_falls_back_to_every_index() {
+        let mut hash = grid();
+        hash.rebuild(vec![(50.0, 50.0)]);
+        let mut out = Vec::new();
+        hash.candidates_in_radius(350.0, 350.0, 10.0, 3, &mut out);
+        assert_eq!(out, vec![0, 1, 2]);
+        hash.candidates_along_segment((350.0, 350.0), (360.0, 360.0), 1.0, 1, &mut out);
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+// utils/timestep.rs
+
+use crate::entities::cpu_entity::CpuEntity;
+use crate::entities::fighter::Fighter;
+use crate::graphics::camera::Camera;
+use std::time::Instant;
+
+/// Length of one gameplay step. Every update tick handed to gameplay code uses this dt,
+/// so combo windows, knockback and replays behave the same at any frame rate.
+pub const FIXED_DT: f64 = 1.0 / 120.0;
+
+/// Upper bound on real time consumed per window tick (e.g. after a stall or while the
+/// window is dragged), so the game slows down instead of running hundreds of catch-up steps.
+const MAX_FRAME_TIME: f64 = 0.25;
+
+/// Accumulates real elapsed time and converts it into whole FIXED_DT steps.
+pub struct FixedTimestep {
+    accumulator: f64,
+    last_tick: Option<Instant>,
+}
+
+impl FixedTimestep {
+    pub fn new() -> Self {
+        FixedTimestep {
+            accumulator: 0.0,
+            last_tick: None,
+        }
+    }
+
+    /// Call once per window update tick. Returns how many fixed steps to run now.
+    pub fn advance(&mut self) -> u32 {
+        let now = Instant::now();
+        let frame_time = match self.last_tick {
+            Some(last) => now.duration_since(last).as_secs_f64().min(MAX_FRAME_TIME),
+            None => FIXED_DT,
+        };
+        self.last_tick = Some(now);
+        self.accumulator += frame_time;
+        let steps = (self.accumulator / FIXED_DT).floor();
+        self.accumulator -= steps * FIXED_DT;
+        steps as u32
+    }
+
+    /// How far the current frame is between the last step and the next, 0..1.
+    pub fn alpha(&self) -> f64 {
+        (self.accumulator / FIXED_DT).clamp(0.0, 1.0)
+    }
+}
+
+/// Positions from before the latest fixed step. Rendering draws a blend of these and the
+/// current positions so motion stays smooth when the display rate and step rate differ.
+pub struct RenderInterpolation {
+    fighter: (f64, f64),
+    camera: (f64, f64),
+    cpu_entities: Vec<(f64, f64)>,
+}
+
+/// Where things are drawn this frame. The simulation positions are left untouched.
+pub struct RenderView {
+    pub fighter: (f64, f64),
+    pub camera: Camera,
+    cpu_entities: Vec<(f64, f64)>, // empty when CPUs spawned or died since the last step
+}
+
+impl RenderInterpolation {
+    pub fn new() -> Self {
+        RenderInterpolation {
+            fighter: (0.0, 0.0),
+            camera: (0.0, 0.0),
+            cpu_entities: Vec::new(),
+        }
+    }
+
+    /// Call right before each fixed step.
+    pub fn capture(&mut self, fighter: &Fighter, camera: &Camera, cpu_entities: &[CpuEntity]) {
+        self.fighter = (fighter.x, fighter.y);
+        self.camera = (camera.x, camera.y);
+        self.cpu_entities.clear();
+        self.cpu_entities.extend(cpu_entities.iter().map(|cpu| (cpu.x, cpu.y)));
+    }
+
+    /// Interpolated positions for drawing, `alpha` of the way from the last step to now.
+    pub fn view(&self, alpha: f64, fighter: &Fighter, camera: &Camera, cpu_entities: &[CpuEntity]) -> RenderView {
+        let (camera_x, camera_y) = lerp(self.camera, (camera.x, camera.y), alpha);
+        // Spawns/removals since the last step shift indices; draw those frames uninterpolated.
+        let cpu_entities = if self.cpu_entities.len() == cpu_entities.len() {
+            cpu_entities
+                .iter()
+                .zip(&self.cpu_entities)
+                .map(|(cpu, previous)| lerp(*previous, (cpu.x, cpu.y), alpha))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        RenderView {
+            fighter: lerp(self.fighter, (fighter.x, fighter.y), alpha),
+            camera: Camera { x: camera_x, y: camera_y, zoom: camera.zoom },
+            cpu_entities,
+        }
+    }
+}
+
+impl RenderView {
+    /// How far the fighter is drawn from its simulation position.
+    pub fn fighter_offset(&self, fighter: &Fighter) -> (f64, f64) {
+        (self.fighter.0 - fighter.x, self.fighter.1 - fighter.y)
+    }
+
+    /// How far the CPU at `index` is drawn from its simulation position.
+    pub fn cpu_offset(&self, index: usize, cpu: &CpuEntity) -> (f64, f64) {
+        match self.cpu_entities.get(index) {
+            Some(&(x, y)) => (x - cpu.x, y - cpu.y),
+            None => (0.0, 0.0),
+        }
+    }
+}
+
+/// Moves further than this in one step are teleports (field changes, respawns) and are not blended.
+const MAX_INTERPOLATED_DISTANCE: f64 = 200.0;
+
+fn lerp(from: (f64, f64), to: (f64, f64), alpha: f64) -> (f64, f64) {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    if dx * dx + dy * dy > MAX_INTERPOLATED_DISTANCE * MAX_INTERPOLATED_DISTANCE {
+        return to;
+    }
+    (from.0 + dx * alpha, from.1 + dy * alpha)
+}